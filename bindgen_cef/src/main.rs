@@ -1,13 +1,16 @@
 use bindgen::builder;
+use regex::Regex;
 use std::env;
+use std::fs;
 use std::iter;
 use std::path::Path;
 fn main() {
     let include_path = env::args()
-        .skip(1)
-        .next()
+        .nth(1)
         .expect("Must pass extracted CEF distribution path as first argument");
     let include_path = dunce::canonicalize(Path::new(&*include_path)).unwrap();
+    // An optional target triple, for generating the bindings of another architecture.
+    let target = env::args().nth(2);
 
     let include_paths = vec![
         include_path.to_string_lossy().into_owned(),
@@ -25,12 +28,16 @@ fn main() {
         include,
         include_paths
             .into_iter()
-            .chain(iter::once(env::var(include).unwrap_or(String::new())))
+            .chain(iter::once(env::var(include).unwrap_or_default()))
             .map(|s| s + separator)
             .collect::<String>(),
     );
 
-    let bindings = builder()
+    let mut builder = builder();
+    if let Some(target) = target {
+        builder = builder.clang_arg(format!("--target={}", target));
+    }
+    let bindings = builder
         .header_contents("everything.h", include_str!("../everything.h"))
         .whitelist_type("_?cef_.*")
         .whitelist_function("_?cef_.*")
@@ -58,5 +65,14 @@ fn main() {
         .size_t_is_usize(true)
         .generate()
         .unwrap();
-    bindings.write_to_file("./bindings.rs").unwrap();
+
+    // The layout tests of this bindgen version get field offsets by dereferencing null pointers,
+    // which panics in debug builds, so they're rewritten to use `offset_of!`.
+    let null_deref = Regex::new(
+        r"unsafe \{\s*&\(\*\(::std::ptr::null::<(\w+)>\(\)\)\)\s*\.(\w+)\s+as\s+\*const\s+_\s+as\s+usize\s*\}",
+    )
+    .unwrap();
+    let bindings = bindings.to_string();
+    let bindings = null_deref.replace_all(&bindings, "::std::mem::offset_of!($1, $2)");
+    fs::write("./bindings.rs", &*bindings).unwrap();
 }
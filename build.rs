@@ -1,30 +1,189 @@
 use std::path::{Path, PathBuf};
 use std::fs;
 use std::io::{Read, Write, Seek, SeekFrom};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+// The version whose headers the bindings in src/ were generated from.
+const BINDINGS_CEF_VERSION: &str = "84.3.10+ga46056b+chromium-84.0.4147.105";
 
 fn main() {
     let target_os = std::env::var("CARGO_CFG_TARGET_OS");
     let target_os = target_os.as_ref().map(|x| &**x);
+    // `msvc` or `gnu` on Windows. This is a build script, so `cfg!` and `#[cfg]` describe the host,
+    // which differs from the target when cross-compiling.
+    let target_env = std::env::var("CARGO_CFG_TARGET_ENV").unwrap_or_default();
 
     let lib_dir_env_var = "CARGO_CEF_SYS_LIB_OUT_DIR";
     let archive_dir_env_var = "CARGO_CEF_SYS_ARCHIVE_OUT_DIR";
     let unpack_sentinel_env_var = "CARGO_CEF_SYS_UNPACK_SENTINEL";
     let cmake_dir_env_var = "CARGO_CEF_SYS_MACOS_CMAKE_PROJECT_DIR";
+    // Cargo only reruns build scripts when variables they declare change, so declare each one read.
+    for env_var in &[lib_dir_env_var, archive_dir_env_var, unpack_sentinel_env_var, cmake_dir_env_var] {
+        println!("cargo:rerun-if-env-changed={}", env_var);
+    }
     let lib_dir_env = std::env::var(lib_dir_env_var).ok();
     let archive_dir_env_var = std::env::var(archive_dir_env_var).ok();
     let unpack_sentinel_env_var = std::env::var(unpack_sentinel_env_var).ok();
     let cmake_dir_env_var = std::env::var(cmake_dir_env_var).ok();
+    let cdn_url = std::env::var("CEF_SYS_MIRROR").ok();
+    println!("cargo:rerun-if-env-changed=CEF_SYS_MIRROR");
+    // An already extracted distribution to install from, instead of downloading the archive.
+    let cef_root = std::env::var("CEF_ROOT").ok();
+    let symlink_cef_root = std::env::var("CEF_SYS_SYMLINK").is_ok();
+    println!("cargo:rerun-if-env-changed=CEF_ROOT");
+    println!("cargo:rerun-if-env-changed=CEF_SYS_SYMLINK");
+    // Pins the SHA1 of the archive instead of trusting the one published in the CDN index.
+    let cef_archive_sha1 = std::env::var("CEF_SYS_ARCHIVE_SHA1").ok();
+    println!("cargo:rerun-if-env-changed=CEF_SYS_ARCHIVE_SHA1");
+    // For private mirrors: a header like `Authorization: Bearer <token>` to send with every request,
+    // and PEM files with extra root certificates, separated like `PATH`. Proxies are taken from
+    // `HTTPS_PROXY`/`NO_PROXY`.
+    let auth_header = std::env::var("CEF_SYS_AUTH_HEADER").ok();
+    let root_certificates = std::env::var_os("CEF_SYS_ROOT_CERTIFICATES");
+    println!("cargo:rerun-if-env-changed=CEF_SYS_AUTH_HEADER");
+    println!("cargo:rerun-if-env-changed=CEF_SYS_ROOT_CERTIFICATES");
+    for proxy_var in &["HTTPS_PROXY", "HTTP_PROXY", "ALL_PROXY", "NO_PROXY"] {
+        println!("cargo:rerun-if-env-changed={}", proxy_var);
+        println!("cargo:rerun-if-env-changed={}", proxy_var.to_lowercase());
+    }
+    // Comma-separated locales to install the resources of, e.g. `de,fr`; en-US is always included.
+    let locales = std::env::var("CEF_SYS_LOCALES").ok();
+    println!("cargo:rerun-if-env-changed=CEF_SYS_LOCALES");
+    // `minimal` skips the Debug build and the samples, which makes the download a lot smaller.
+    let distribution = std::env::var("CEF_SYS_DISTRIBUTION").ok();
+    println!("cargo:rerun-if-env-changed=CEF_SYS_DISTRIBUTION");
+    // Where to extract the symbols archive matching the libraries to, if anywhere.
+    let symbols_dir = std::env::var("CEF_SYS_SYMBOLS_DIR").ok();
+    println!("cargo:rerun-if-env-changed=CEF_SYS_SYMBOLS_DIR");
+    // Archives and libraries are kept in a per-user cache shared by all target dirs unless disabled.
+    let cache_root = std::env::var("CEF_SYS_CACHE_DIR").ok();
+    let no_cache = std::env::var("CEF_SYS_NO_CACHE").is_ok();
+    println!("cargo:rerun-if-env-changed=CEF_SYS_CACHE_DIR");
+    println!("cargo:rerun-if-env-changed=CEF_SYS_NO_CACHE");
+    // Where the per-user cache is on Linux.
+    println!("cargo:rerun-if-env-changed=XDG_CACHE_HOME");
+    // Another version with the same API as the bindings, e.g. `84` or `latest stable` for the newest
+    // build of it, or a full version.
+    let version_spec = std::env::var("CEF_SYS_VERSION").ok();
+    println!("cargo:rerun-if-env-changed=CEF_SYS_VERSION");
+    // `debug` or `release` for the CEF build to link, or `profile` to follow the Cargo profile. The
+    // `debug_cef` feature selects the Debug build, too.
+    let opt_level_name = std::env::var("CEF_SYS_OPT_LEVEL").ok().filter(|name| !name.is_empty());
+    println!("cargo:rerun-if-env-changed=CEF_SYS_OPT_LEVEL");
+    // A directory relative to the executables, e.g. `lib`, that the `rpath` feature adds to their
+    // RUNPATH and the `stage_runtime` feature stages the runtime files into.
+    let runtime_subdir = std::env::var("CEF_SYS_RUNTIME_SUBDIR").ok().filter(|dir| !dir.is_empty());
+    println!("cargo:rerun-if-env-changed=CEF_SYS_RUNTIME_SUBDIR");
+    if runtime_subdir.as_deref().is_some_and(|dir| !Path::new(dir).is_relative()) {
+        eprintln!("error: CEF_SYS_RUNTIME_SUBDIR must be relative to the executables");
+        std::process::exit(1);
+    }
 
-
-    let cef_version = "84.3.10+ga46056b+chromium-84.0.4147.105";
-    let cef_platform = match std::env::var("CARGO_CFG_TARGET_OS").unwrap().as_str() {
-        "windows" => cef_installer::Platform::Windows,
-        "linux" => cef_installer::Platform::Linux,
-        "macos" => cef_installer::Platform::MacOS,
-        p => panic!("platform {} unsupported", p),
+    let cef_platform = cef_installer::Platform::from_target_os(target_os.unwrap())
+        .unwrap_or_else(|err| fail(err));
+    let cef_arch = cef_installer::Arch::from_target_arch(&std::env::var("CARGO_CFG_TARGET_ARCH").unwrap())
+        .unwrap_or_else(|err| fail(err));
+    // There are only bindings for 64-bit targets, so fail before anything is downloaded.
+    if std::env::var("CARGO_CFG_TARGET_POINTER_WIDTH").unwrap() != "64" {
+        eprintln!(
+            "error: cef-sys has no bindings for {} {}, only for 64-bit targets",
+            target_os.unwrap(),
+            std::env::var("CARGO_CFG_TARGET_ARCH").unwrap(),
+        );
+        std::process::exit(1);
+    }
+    let opt_level = match opt_level_name.as_deref() {
+        Some("profile") if std::env::var("PROFILE").as_deref() == Ok("debug") => cef_installer::OptLevel::Debug,
+        Some("profile") => cef_installer::OptLevel::Release,
+        Some(name) => cef_installer::OptLevel::from_name(name).unwrap_or_else(|err| fail(err)),
+        None if cfg!(feature = "debug_cef") => cef_installer::OptLevel::Debug,
+        None => cef_installer::OptLevel::Release,
     };
-    let opt_level = cef_installer::OptLevel::Release;
+    let cef_distribution = distribution.as_deref()
+        .map(cef_installer::Distribution::from_name)
+        .unwrap_or(Ok(cef_installer::Distribution::Standard))
+        .unwrap_or_else(|err| fail(err));
+    if opt_level == cef_installer::OptLevel::Debug && cef_distribution == cef_installer::Distribution::Minimal {
+        eprintln!("error: the minimal CEF distribution has no Debug build, unset CEF_SYS_DISTRIBUTION to use it");
+        std::process::exit(1);
+    }
+    // `CefLibrary` in src/library.rs gets its functions from the bindings for the target.
+    if cfg!(feature = "dynamic-loading") {
+        let bindings_path = bindings_path(cef_platform);
+        println!("cargo:rerun-if-changed={}", bindings_path);
+        let bindings = fs::read_to_string(bindings_path).expect("could not read the bindings");
+        let out_dir = PathBuf::from(std::env::var("OUT_DIR").unwrap());
+        fs::write(out_dir.join("cef_library.rs"), function_table(&bindings)).unwrap();
+    }
+
+    // Lets Linux executables find libcef.so next to them, or in the runtime subdir, without
+    // LD_LIBRARY_PATH. Link arguments only apply to this crate's own tests and examples, so
+    // dependent crates get the RUNPATH as `DEP_CEF_RPATH` to pass on in their build scripts.
+    // It doesn't depend on CEF being installed, so it's emitted in `no_download` mode, too.
+    if cfg!(feature = "rpath") && cef_platform == cef_installer::Platform::Linux {
+        let mut rpath = "$ORIGIN".to_owned();
+        if let Some(subdir) = &runtime_subdir {
+            rpath = format!("{}:$ORIGIN/{}", rpath, subdir.trim_end_matches('/'));
+        }
+        println!("cargo:rustc-link-arg=-Wl,--enable-new-dtags,-rpath,{}", rpath);
+        println!("cargo:rpath={}", rpath);
+    }
+
+    // Neither download nor link CEF, so that the bindings can be type-checked and documented
+    // without network access, e.g. on docs.rs. Building executables fails in this mode.
+    let no_download = cfg!(feature = "no_download")
+        || std::env::var_os("CEF_SYS_NO_DOWNLOAD").is_some()
+        || std::env::var_os("DOCS_RS").is_some();
+    println!("cargo:rerun-if-env-changed=CEF_SYS_NO_DOWNLOAD");
+    println!("cargo:rerun-if-env-changed=DOCS_RS");
+    if no_download {
+        println!("cargo:version={}", BINDINGS_CEF_VERSION);
+        if cef_platform == cef_installer::Platform::MacOS {
+            // For `MACOS_FRAMEWORK_PATH`, which isn't there to point to.
+            println!("cargo:rustc-env=CEF_SYS_FRAMEWORK_PATH=");
+        }
+        return;
+    }
+    // These targets can't link CEF, but the bindings can still be checked for them.
+    if cfg!(feature = "sandbox") && cef_platform == cef_installer::Platform::Windows && target_env != "msvc" {
+        eprintln!("error: cef_sandbox.lib is built with MSVC, so the sandbox feature requires an MSVC target");
+        std::process::exit(1);
+    }
+    if cef_platform == cef_installer::Platform::Linux && target_env != "gnu" {
+        eprintln!("error: CEF is built against glibc, so it can't be used on {} targets", target_env);
+        std::process::exit(1);
+    }
     let out_dir = PathBuf::from(std::env::var("OUT_DIR").unwrap());
+    let mut http = cef_installer::HttpOptions::from_env();
+    if let Some(header) = &auth_header {
+        let (name, value) = header.split_once(':').unwrap_or_else(|| {
+            eprintln!("error: CEF_SYS_AUTH_HEADER must look like `Name: value`");
+            std::process::exit(1)
+        });
+        http = http.header(name.trim(), value.trim());
+    }
+    for path in root_certificates.iter().flat_map(std::env::split_paths) {
+        http = http.root_certificate(path);
+    }
+    let cef_version = match &version_spec {
+        Some(spec) => {
+            let spec = spec.parse().unwrap_or_else(|err| fail(err));
+            let cdn_url = cdn_url.as_deref().unwrap_or(cef_installer::DEFAULT_CDN_URL);
+            let version = cef_installer::resolve_version(cdn_url, &http, &spec, cef_platform, cef_arch)
+                .unwrap_or_else(|err| fail(err));
+            cef_installer::check_bindings_version(&version, BINDINGS_CEF_VERSION).unwrap_or_else(|err| fail(err));
+            version
+        },
+        None => BINDINGS_CEF_VERSION.to_owned(),
+    };
+    let cef_version = &*cef_version;
+    let cache_dir = match cache_root {
+        _ if no_cache => None,
+        Some(root) => Some(PathBuf::from(root).join(cef_version).join(cef_platform.index_key(cef_arch))),
+        None => cef_installer::cache_dir(cef_version, cef_platform, cef_arch),
+    };
 
     let mut unpack_cef = true;
 
@@ -38,17 +197,29 @@ fn main() {
             std::fs::create_dir_all(&dir).expect("could not create targz dir");
             dunce::canonicalize(PathBuf::from(&dir)).expect("could not canonicalize archive dir")
         },
-        None => out_dir.clone()
+        None => match &cache_dir {
+            Some(cache_dir) => cache_dir.clone(),
+            None => out_dir.clone(),
+        },
     };
     let lib_dir = match lib_dir_env {
         Some(dir) => {
             std::fs::create_dir_all(&dir).expect("could not create lib dir");
             dunce::canonicalize(PathBuf::from(&dir)).expect("could not canonicalize lib dir")
         },
-        None => out_dir.clone(),
+        // Builds installing different locales can't share their libraries.
+        None => match (&cache_dir, &locales) {
+            (Some(cache_dir), Some(locales)) => {
+                let mut locales: Vec<&str> = locales.split(',').map(str::trim).filter(|l| !l.is_empty()).collect();
+                locales.sort_unstable();
+                cache_dir.join(format!("{:?}-locales-{}", opt_level, locales.join("_")))
+            },
+            (Some(cache_dir), None) => cache_dir.join(format!("{:?}", opt_level)),
+            (None, _) => out_dir.clone(),
+        },
     };
     let libcef_dll_project_dir: Option<PathBuf>;
-    let header_dir: Option<PathBuf>;
+    let header_dir: PathBuf;
     let libcef_dll_src_dir: Option<PathBuf>;
     let cmake_macros_dir: Option<PathBuf>;
     if target_os == Ok("macos") {
@@ -60,57 +231,165 @@ fn main() {
             None => out_dir.join("libcef_dll"),
         };
 
-        header_dir = Some(project_dir.join("include"));
+        header_dir = project_dir.join("include");
         libcef_dll_src_dir = Some(project_dir.join("libcef_dll"));
         cmake_macros_dir = Some(project_dir.join("cmake"));
         libcef_dll_project_dir = Some(project_dir);
     } else {
         libcef_dll_project_dir = None;
-        header_dir = None;
+        // Only for dependent crates, see `cargo:include_dir` below.
+        header_dir = match &cache_dir {
+            Some(cache_dir) => cache_dir.join("include"),
+            None => out_dir.join("include"),
+        };
         libcef_dll_src_dir = None;
         cmake_macros_dir = None;
     }
 
     let unpack_sentinel_file_contents = format!(
-        "{};{};{};{}",
+        "{};{:?};{:?};{:?};{};{};{};{};{};{};{}",
         cef_version,
+        cef_arch,
+        opt_level,
+        cef_distribution,
+        locales.as_deref().unwrap_or_default(),
+        symbols_dir.as_deref().unwrap_or_default(),
+        cef_root.as_deref().unwrap_or_default(),
         targz_dir.display(),
         lib_dir.display(),
+        header_dir.display(),
         libcef_dll_project_dir.as_deref()
             .filter(|_| target_os == Ok("macos"))
             .map(|d| d.display().to_string()).unwrap_or_default(),
     );
     if let Ok(actual_contents) = fs::read_to_string(&unpack_sentinel_path) {
-        if unpack_sentinel_file_contents == actual_contents {
+        // Files that went missing since are installed again.
+        if unpack_sentinel_file_contents == actual_contents
+            && cef_installer::validate_layout(&lib_dir, cef_platform).is_ok()
+        {
             unpack_cef = false;
         }
     }
+    // Cargo only reruns this script for the variables above, so watch the library as well, e.g. for
+    // when the cache was cleared.
+    let main_library = match cef_platform {
+        cef_installer::Platform::Windows => lib_dir.join("libcef.dll"),
+        cef_installer::Platform::Linux => lib_dir.join("libcef.so"),
+        cef_installer::Platform::MacOS => {
+            lib_dir.join("Chromium Embedded Framework.framework").join("Chromium Embedded Framework")
+        },
+    };
+    println!("cargo:rerun-if-changed={}", main_library.display());
 
     // we ignore the unpack sentinel on macos because it needs to unpack the archive to build
     // ibcef_dll_wrapper
     if unpack_cef {
-        cef_installer::download_cef(
-            cef_version,
-            cef_platform,
-            opt_level,
-            Some(&targz_dir),
-            Some(&lib_dir),
-            header_dir.as_deref(),
-            libcef_dll_src_dir.as_deref(),
-            cmake_macros_dir.as_deref(),
-            false,
-        ).unwrap();
+        let mut installer = cef_installer::Installer::new(cef_version, cef_platform)
+            .arch(cef_arch)
+            .opt_level(opt_level)
+            .distribution(cef_distribution)
+            .http(http.clone())
+            .archive_dir(&targz_dir)
+            .libs(&lib_dir)
+            .resources(&lib_dir)
+            .headers(&header_dir);
+        let download_stats = Arc::new(DownloadStats::default());
+        installer = installer.progress(download_stats.clone());
+        if let Some(cdn_url) = &cdn_url {
+            installer = installer.cdn_url(cdn_url);
+        }
+        if let Some(sha1) = cef_archive_sha1 {
+            installer = installer.sha1(sha1);
+        }
+        if let Some(locales) = &locales {
+            installer = installer.locales(locales.split(',').map(str::trim).filter(|locale| !locale.is_empty()));
+        }
+        if let Some(cef_root) = &cef_root {
+            // The per-user cache is shared by other builds, which must not depend on a CEF_ROOT that
+            // may be changed or removed, so it always gets copies.
+            if symlink_cef_root && cache_dir.is_some() {
+                println!("cargo:warning=CEF_SYS_SYMLINK is ignored unless CEF_SYS_NO_CACHE is set");
+            }
+            installer = installer.cef_root(cef_root).symlink(symlink_cef_root && cache_dir.is_none());
+        }
+        if let (Some(libcef_dll_src_dir), Some(cmake_macros_dir)) = (&libcef_dll_src_dir, &cmake_macros_dir) {
+            installer = installer.libcef_dll(libcef_dll_src_dir).cmake(cmake_macros_dir);
+        }
+        let report = installer.install().unwrap_or_else(|err| fail(err));
+        summarize(&format!("CEF {}", cef_version), &report, &download_stats, &lib_dir);
 
+        if let Some(symbols_dir) = &symbols_dir {
+            let symbols_distribution = match opt_level {
+                cef_installer::OptLevel::Debug => cef_installer::Distribution::DebugSymbols,
+                cef_installer::OptLevel::Release => cef_installer::Distribution::ReleaseSymbols,
+            };
+            let download_stats = Arc::new(DownloadStats::default());
+            let mut installer = cef_installer::Installer::new(cef_version, cef_platform)
+                .arch(cef_arch)
+                .opt_level(opt_level)
+                .distribution(symbols_distribution)
+                .http(http)
+                .archive_dir(&targz_dir)
+                .symbols(symbols_dir)
+                .progress(download_stats.clone());
+            if let Some(cdn_url) = &cdn_url {
+                installer = installer.cdn_url(cdn_url);
+            }
+            let report = installer.install().unwrap_or_else(|err| fail(err));
+            summarize(&format!("CEF {} symbols", cef_version), &report, &download_stats, Path::new(symbols_dir));
+        }
+
+        // Fail here rather than have `cef_initialize` crash on whatever is missing.
+        let layout = cef_installer::validate_layout(&lib_dir, cef_platform);
+        if !layout.is_ok() {
+            eprintln!("error: the CEF installation in {} is incomplete:", lib_dir.display());
+            for problem in layout.problems() {
+                eprintln!("  {}", problem);
+            }
+            std::process::exit(1);
+        }
         fs::write(&unpack_sentinel_path, &unpack_sentinel_file_contents).ok();
     }
 
+    // Next to the executables in target/<profile>, so that `cargo run` works without copying them.
+    // macOS apps load the framework from their bundle or MACOS_FRAMEWORK_PATH instead.
+    if cfg!(feature = "stage_runtime") && cef_platform != cef_installer::Platform::MacOS {
+        // OUT_DIR is target/<profile>/build/cef-sys-<hash>/out.
+        let profile_dir = out_dir.ancestors().nth(3).expect("OUT_DIR is inside the profile directory");
+        let runtime_dir = profile_dir.join(runtime_subdir.as_deref().unwrap_or_default());
+        let staged = cef_installer::stage_runtime_files(&lib_dir, &runtime_dir).unwrap_or_else(|err| fail(err));
+        if !staged.is_empty() {
+            println!("cargo:warning=staged {} CEF runtime files into {}", staged.len(), runtime_dir.display());
+        }
+        // Stage the files again if they were deleted.
+        let library = if cef_platform == cef_installer::Platform::Windows { "libcef.dll" } else { "libcef.so" };
+        println!("cargo:rerun-if-changed={}", runtime_dir.join(library).display());
+    }
+
+    // Available to the build scripts of dependent crates as `DEP_CEF_<KEY>`, e.g. to copy the
+    // runtime files next to their executables.
+    let resources_dir = match cef_platform {
+        cef_installer::Platform::MacOS => lib_dir.join("Chromium Embedded Framework.framework").join("Resources"),
+        _ => lib_dir.clone(),
+    };
+    println!("cargo:version={}", cef_version);
+    println!("cargo:lib_dir={}", lib_dir.display());
+    println!("cargo:resources_dir={}", resources_dir.display());
+    println!("cargo:include_dir={}", header_dir.display());
+    if let Some(api_hash) = cef_installer::read_api_hash(&header_dir, cef_platform) {
+        println!("cargo:api_hash={}", api_hash);
+    }
+
     match target_os {
         Ok("windows") => {
             #[cfg(feature = "sandbox")]
             {
                 println!("cargo:rustc-link-lib=cef_sandbox");
             }
-            println!("cargo:rustc-link-lib=libcef");
+            // MSVC and MinGW linkers both resolve this to the import library libcef.lib.
+            if !cfg!(feature = "dynamic-loading") {
+                println!("cargo:rustc-link-lib=libcef");
+            }
 
             // These two libraries are winapi libs, but they aren't available through winapi so we
             // link them here.
@@ -120,9 +399,12 @@ fn main() {
             println!("cargo:rustc-link-search={}", lib_dir.display());
         },
         Ok("linux") => {
-            println!("cargo:rustc-link-lib=cef");
-            println!("cargo:rustc-link-lib=EGL");
-            println!("cargo:rustc-link-lib=GLESv2");
+            // With dynamic loading, libcef.so pulls in the GL libraries itself once it's loaded.
+            if !cfg!(feature = "dynamic-loading") {
+                println!("cargo:rustc-link-lib=cef");
+                println!("cargo:rustc-link-lib=EGL");
+                println!("cargo:rustc-link-lib=GLESv2");
+            }
 
             println!("cargo:rustc-link-search={}", lib_dir.display());
         }
@@ -147,7 +429,7 @@ fn main() {
                 libcef_dll_project_dir.display().to_string().replace("\\", "/"),
                 libcef_dll_src_dir.display().to_string().replace("\\", "/"),
             );
-            fs::write(&libcef_dll_project_dir.join("CMakeLists.txt"), &cmake_file).unwrap();
+            fs::write(libcef_dll_project_dir.join("CMakeLists.txt"), &cmake_file).unwrap();
 
             let dst = cmake::Config::new(&libcef_dll_project_dir).generator("Ninja").build();
             println!("cargo:rustc-link-search=native={}", dst.display());
@@ -161,6 +443,87 @@ fn main() {
     }
 }
 
+// Cargo only shows build script output on failure, so summarize what happened as warnings.
+fn summarize(what: &str, report: &cef_installer::InstallReport, download_stats: &DownloadStats, dir: &Path) {
+    if let Some(url) = &report.downloaded_from {
+        println!(
+            "cargo:warning=downloaded {} ({:.1} MiB in {:.1}s) from {}",
+            what,
+            download_stats.size.load(Ordering::Relaxed) as f64 / (1 << 20) as f64,
+            download_stats.elapsed.lock().unwrap().as_secs_f64(),
+            url,
+        );
+    }
+    for warning in &report.warnings {
+        println!("cargo:warning={}", warning);
+    }
+    let written = report.files.iter().filter(|file| file.written).count();
+    if written > 0 {
+        println!("cargo:warning=installed {} {} files into {}", written, what, dir.display());
+    }
+}
+
+#[derive(Default)]
+struct DownloadStats {
+    started: Mutex<Option<Instant>>,
+    size: AtomicU64,
+    elapsed: Mutex<Duration>,
+}
+
+impl cef_installer::Progress for DownloadStats {
+    fn download_started(&self, _url: &str, _resumed_from: u64, _total: u64) {
+        *self.started.lock().unwrap() = Some(Instant::now());
+    }
+
+    fn download_finished(&self, _url: &str, size: u64) {
+        self.size.store(size, Ordering::Relaxed);
+        if let Some(started) = *self.started.lock().unwrap() {
+            *self.elapsed.lock().unwrap() = started.elapsed();
+        }
+    }
+}
+
+fn fail(err: cef_installer::Error) -> ! {
+    eprintln!("error: could not install CEF: {}", err);
+    if let cef_installer::Error::Network { .. } | cef_installer::Error::HttpStatus { .. } = err {
+        eprintln!("note: set CEF_SYS_MIRROR to download CEF from a different server");
+    }
+    std::process::exit(1);
+}
+
+// The bindings src/lib.rs includes for the target.
+fn bindings_path(platform: cef_installer::Platform) -> &'static str {
+    match platform {
+        cef_installer::Platform::Windows => "src/bindings_windows.rs",
+        cef_installer::Platform::Linux => "src/bindings_linux.rs",
+        cef_installer::Platform::MacOS => "src/bindings_macos.rs",
+    }
+}
+
+// Functions declared in the headers that libcef doesn't export: the sandbox ones are in
+// cef_sandbox.lib or cef_sandbox.a, and the macOS library loader is in libcef_dll_wrapper.
+const NOT_IN_LIBCEF: &[&str] = &["cef_sandbox_", "cef_load_library", "cef_unload_library"];
+
+// Passes the `extern "C"` declarations of the bindings to the `cef_library!` macro in
+// src/library.rs, as in `cef_library! { cef_initialize(args: *const cef_main_args_t, ...) -> c_int; }`.
+fn function_table(bindings: &str) -> String {
+    let mut table = String::from("cef_library! {\n");
+    for block in bindings.split("extern \"C\" {\n").skip(1) {
+        let block = &block[..block.find("\n}\n").expect("unterminated extern block")];
+        for declaration in block.split(';').map(str::trim).filter(|d| !d.is_empty()) {
+            let declaration = declaration.strip_prefix("pub fn ").expect("only functions are declared");
+            if NOT_IN_LIBCEF.iter().any(|name| declaration.starts_with(name)) {
+                continue;
+            }
+            let declaration = declaration.split_whitespace().collect::<Vec<_>>().join(" ")
+                .replace("( ", "(")
+                .replace(", )", ")");
+            table += &format!("    {};\n", declaration);
+        }
+    }
+    table + "}\n"
+}
+
 fn remove_find_package_dep(path: &Path) {
     let mut cmake_macros_file = fs::OpenOptions::new()
         .read(true)
@@ -170,18 +533,22 @@ fn remove_find_package_dep(path: &Path) {
     let mut cmake_macros_str = String::new();
     cmake_macros_file.read_to_string(&mut cmake_macros_str).unwrap();
 
-    #[cfg(target_os = "windows")]
-    { cmake_macros_str = cmake_macros_str.replace("\r\n", "\n"); }
+    // The line endings are whatever the archive has, regardless of the host.
+    let crlf = cmake_macros_str.contains("\r\n");
+    if crlf {
+        cmake_macros_str = cmake_macros_str.replace("\r\n", "\n");
+    }
 
     let new_str = cmake_macros_str.replace(CEF_MACROS_REMOVE, "");
     cmake_macros_str = new_str;
 
-    #[cfg(target_os = "windows")]
-    { cmake_macros_str = cmake_macros_str.replace("\n", "\r\n"); }
+    if crlf {
+        cmake_macros_str = cmake_macros_str.replace("\n", "\r\n");
+    }
 
     cmake_macros_file.seek(SeekFrom::Start(0)).unwrap();
     cmake_macros_file.set_len(cmake_macros_str.len() as u64).unwrap();
-    cmake_macros_file.write(cmake_macros_str.as_bytes()).unwrap();
+    cmake_macros_file.write_all(cmake_macros_str.as_bytes()).unwrap();
 }
 
 const CEF_MACROS_REMOVE: &str = "if(NOT DEFINED _CEF_ROOT_EXPLICIT)
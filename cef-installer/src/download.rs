@@ -0,0 +1,148 @@
+use crate::{Error, HttpOptions, Index, Progress, Result};
+use std::{
+    fs,
+    io::{self, BufReader, Read, Seek, SeekFrom, Write},
+    path::{Path, PathBuf},
+};
+
+// How often a download that broke off is resumed before giving up.
+const MAX_DOWNLOAD_ATTEMPTS: usize = 5;
+
+pub(crate) fn join_url(base: &str, file: &str) -> String {
+    format!("{}/{}", base.trim_end_matches('/'), file)
+}
+
+pub(crate) fn fetch(url: &str, http: &HttpOptions) -> Result<Box<dyn Read>> {
+    fetch_range(url, 0, http).map(|(reader, _)| reader)
+}
+
+// Requests `url` starting at byte `offset`, returning whether the server honored the range.
+//
+// `file://` URLs are read straight from disk so that a local directory laid out like the CDN can
+// stand in for it.
+fn fetch_range(url: &str, offset: u64, http: &HttpOptions) -> Result<(Box<dyn Read>, bool)> {
+    if let Some(path) = url.strip_prefix("file://") {
+        let path = urlencoding::decode(path).unwrap_or_else(|_| path.to_owned());
+        let mut file = fs::File::open(&path).map_err(Error::io(&path))?;
+        file.seek(SeekFrom::Start(offset)).map_err(Error::io(&path))?;
+        return Ok((Box::new(BufReader::new(file)), true));
+    }
+
+    let mut request = http.get(url)?;
+    if offset > 0 {
+        request.set("Range", &format!("bytes={}-", offset));
+    }
+    let response = request.call();
+    if let Some(err) = response.synthetic_error() {
+        return Err(Error::Network { url: url.to_owned(), message: err.to_string() });
+    }
+    match response.status() {
+        200 => Ok((Box::new(response.into_reader()), false)),
+        206 if offset > 0 => Ok((Box::new(response.into_reader()), true)),
+        status => Err(Error::HttpStatus {
+            url: url.to_owned(),
+            status,
+            status_text: response.status_text().to_owned(),
+        }),
+    }
+}
+
+/// Streams `url` into the file at `path`. If the file already exists, it's taken to be the start
+/// of an interrupted download and only the rest is requested, and downloads that break off are
+/// resumed the same way. `size` is the expected size of the whole file, or 0 if unknown.
+pub(crate) fn download_file(
+    url: &str,
+    path: &Path,
+    size: u64,
+    http: &HttpOptions,
+    progress: &dyn Progress,
+) -> Result<()> {
+    let network_error = |err: io::Error| Error::Network { url: url.to_owned(), message: err.to_string() };
+    let mut attempt = 0;
+    loop {
+        attempt += 1;
+        let offset = fs::metadata(path).map(|metadata| metadata.len()).unwrap_or(0);
+        if attempt == 1 {
+            progress.download_started(url, offset, size);
+        }
+        if size > 0 && offset == size {
+            return Ok(());
+        }
+
+        let (mut reader, resumed) = match fetch_range(url, offset, http) {
+            Ok(response) => response,
+            // The partial file is larger than what the server has, so it's garbage.
+            Err(Error::HttpStatus { status: 416, .. }) if attempt < MAX_DOWNLOAD_ATTEMPTS => {
+                fs::remove_file(path).map_err(Error::io(path))?;
+                continue;
+            },
+            Err(Error::Network { .. }) if attempt < MAX_DOWNLOAD_ATTEMPTS => continue,
+            Err(err) => return Err(err),
+        };
+        let mut file = fs::OpenOptions::new()
+            .create(true)
+            .write(true)
+            .append(resumed)
+            .truncate(!resumed)
+            .open(path)
+            .map_err(Error::io(path))?;
+
+        let mut downloaded = if resumed { offset } else { 0 };
+        progress.downloaded(downloaded, size);
+        let mut buf = vec![0; 256 * 1024];
+        let result = loop {
+            match reader.read(&mut buf) {
+                Ok(0) => break Ok(()),
+                Ok(n) => {
+                    file.write_all(&buf[..n]).map_err(Error::io(path))?;
+                    downloaded += n as u64;
+                    progress.downloaded(downloaded, size);
+                },
+                Err(err) if err.kind() == io::ErrorKind::Interrupted => (),
+                Err(err) => break Err(err),
+            }
+        };
+        file.flush().map_err(Error::io(path))?;
+
+        let downloaded = file.metadata().map_err(Error::io(path))?.len();
+        match result {
+            Ok(()) if size == 0 || downloaded >= size => return Ok(()),
+            _ if attempt < MAX_DOWNLOAD_ATTEMPTS => (),
+            Ok(()) => return Err(network_error(io::Error::new(
+                io::ErrorKind::UnexpectedEof,
+                format!("connection closed after {} of {} bytes", downloaded, size),
+            ))),
+            Err(err) => return Err(network_error(err)),
+        }
+    }
+}
+
+pub(crate) fn read_url(url: &str, http: &HttpOptions) -> Result<Vec<u8>> {
+    let mut buf = Vec::new();
+    fetch(url, http)?.read_to_end(&mut buf)
+        .map_err(|err| Error::Network { url: url.to_owned(), message: err.to_string() })?;
+    Ok(buf)
+}
+
+pub(crate) fn sha1_path(archive_path: &Path) -> PathBuf {
+    let mut file_name = archive_path.file_name().unwrap_or_default().to_owned();
+    file_name.push(".sha1");
+    archive_path.with_file_name(file_name)
+}
+
+pub(crate) fn sha1_hex(mut reader: impl Read) -> io::Result<String> {
+    let mut hasher = sha1::Sha1::new();
+    let mut buf = [0; 64 * 1024];
+    loop {
+        match reader.read(&mut buf)? {
+            0 => return Ok(hasher.digest().to_string()),
+            n => hasher.update(&buf[..n]),
+        }
+    }
+}
+
+pub fn fetch_index(cdn_url: &str, http: &HttpOptions) -> Result<Index> {
+    let url = join_url(cdn_url, "index.json");
+    let buf = read_url(&url, http)?;
+    Index::from_slice(&buf).map_err(|source| Error::InvalidIndex { url, source })
+}
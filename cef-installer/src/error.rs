@@ -0,0 +1,121 @@
+use std::{fmt, io, path::PathBuf};
+
+#[derive(Debug)]
+pub enum Error {
+    /// The server could not be reached or the connection broke off.
+    Network { url: String, message: String },
+    /// The server answered with something other than `200 OK`.
+    HttpStatus { url: String, status: u16, status_text: String },
+    /// `index.json` could not be parsed.
+    InvalidIndex { url: String, source: serde_json::Error },
+    /// The requested version isn't published for the requested platform.
+    NotInIndex { version: String, platform: String, cdn_url: String },
+    /// The archive downloaded from `url` doesn't have the expected SHA1.
+    Checksum { url: String, expected: String, actual: String },
+    /// The archive could not be decompressed or isn't a valid tarball. `path` is the cached
+    /// archive, if the data came from one.
+    Decompression { path: Option<PathBuf>, source: io::Error },
+    /// An archive entry would be written outside its destination, or is of an unsupported type.
+    UnsafeEntry { path: Option<PathBuf>, entry: String, reason: &'static str },
+    UnsupportedPlatform(String),
+    UnknownDistribution(String),
+    UnknownOptLevel(String),
+    UnknownChannel(String),
+    /// A version spec is neither a version, a version prefix nor `latest`.
+    InvalidVersionSpec(String),
+    /// The version doesn't have the API the bindings were generated from.
+    IncompatibleVersion { version: String, bindings_version: String },
+    /// An extracted distribution is of a different version than the one requested.
+    VersionMismatch { path: PathBuf, expected: String, found: String },
+    Io { path: PathBuf, source: io::Error },
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+impl Error {
+    pub(crate) fn io(path: impl Into<PathBuf>) -> impl FnOnce(io::Error) -> Error {
+        let path = path.into();
+        move |source| Error::Io { path, source }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Network { url, message } => write!(f, "could not fetch {}: {}", url, message),
+            Error::HttpStatus { url, status, status_text } => {
+                write!(f, "server responded to {} with HTTP {} {}", url, status, status_text)
+            },
+            Error::InvalidIndex { url, source } => write!(f, "{} is not a valid CEF build index: {}", url, source),
+            Error::NotInIndex { version, platform, cdn_url } => write!(
+                f,
+                "CEF {} for {} is not listed in the index at {}",
+                version,
+                platform,
+                cdn_url,
+            ),
+            Error::Checksum { url, expected, actual } => write!(
+                f,
+                "checksum mismatch for {}: got SHA1 {}, expected {}",
+                url,
+                actual,
+                expected,
+            ),
+            Error::Decompression { path: Some(path), source } => write!(
+                f,
+                "CEF archive at {} is corrupt, delete it and try again: {}",
+                path.display(),
+                source,
+            ),
+            Error::Decompression { path: None, source } => write!(f, "downloaded CEF archive is corrupt: {}", source),
+            Error::UnsafeEntry { path: Some(path), entry, reason } => write!(
+                f,
+                "refusing to extract {} from {}: {}",
+                entry,
+                path.display(),
+                reason,
+            ),
+            Error::UnsafeEntry { path: None, entry, reason } => {
+                write!(f, "refusing to extract {} from downloaded archive: {}", entry, reason)
+            },
+            Error::UnsupportedPlatform(platform) => write!(f, "platform {} is not supported by CEF", platform),
+            Error::UnknownDistribution(name) => write!(
+                f,
+                "unknown CEF distribution {}, expected standard, minimal, client, debug_symbols or release_symbols",
+                name,
+            ),
+            Error::UnknownOptLevel(name) => write!(f, "unknown CEF opt level {}, expected debug or release", name),
+            Error::UnknownChannel(name) => write!(f, "unknown CEF channel {}, expected stable or beta", name),
+            Error::InvalidVersionSpec(spec) => write!(
+                f,
+                "invalid CEF version {:?}, expected a version like 84.3.10+ga46056b+chromium-84.0.4147.105, a prefix \
+                 like 84 or `latest`, optionally followed by stable or beta",
+                spec,
+            ),
+            Error::IncompatibleVersion { version, bindings_version } => write!(
+                f,
+                "CEF {} has a different API than CEF {}, which the bindings were generated from",
+                version,
+                bindings_version,
+            ),
+            Error::VersionMismatch { path, expected, found } => write!(
+                f,
+                "{} contains CEF {}, expected {}",
+                path.display(),
+                found,
+                expected,
+            ),
+            Error::Io { path, source } => write!(f, "{}: {}", path.display(), source),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::InvalidIndex { source, .. } => Some(source),
+            Error::Decompression { source, .. } | Error::Io { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,226 @@
+use crate::{Component, OptLevel, Platform};
+use regex::Regex;
+use std::{
+    cell::Cell,
+    collections::BTreeMap,
+    fs,
+    io::{self, BufRead, Read},
+    path::{Component as PathComponent, Path, PathBuf},
+    rc::Rc,
+};
+
+// `bzip2::read::BzDecoder` reports a truncated stream as a regular EOF, which would let a partial
+// archive extract "successfully", so we drive the decompressor ourselves. It also remembers whether
+// reading failed, so that errors from `Entry::unpack` can be told apart from errors writing the
+// destination file.
+pub(crate) struct BzDecoder<R> {
+    inner: R,
+    data: bzip2::Decompress,
+    done: bool,
+    failed: Rc<Cell<bool>>,
+}
+
+impl<R: BufRead> BzDecoder<R> {
+    pub(crate) fn new(inner: R, failed: Rc<Cell<bool>>) -> Self {
+        BzDecoder { inner, data: bzip2::Decompress::new(false), done: false, failed }
+    }
+
+    fn decompress(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if self.done || buf.is_empty() {
+            return Ok(0);
+        }
+        loop {
+            let (read, consumed, eof, status);
+            {
+                let input = self.inner.fill_buf()?;
+                eof = input.is_empty();
+                let before_out = self.data.total_out();
+                let before_in = self.data.total_in();
+                status = self.data.decompress(input, buf);
+                read = (self.data.total_out() - before_out) as usize;
+                consumed = (self.data.total_in() - before_in) as usize;
+            }
+            self.inner.consume(consumed);
+
+            match status.map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))? {
+                bzip2::Status::StreamEnd => {
+                    self.done = true;
+                    return Ok(read);
+                },
+                _ if read > 0 => return Ok(read),
+                _ if eof => return Err(io::Error::new(
+                    io::ErrorKind::UnexpectedEof,
+                    "bzip2 stream ended unexpectedly",
+                )),
+                _ => (),
+            }
+        }
+    }
+}
+
+impl<R: BufRead> Read for BzDecoder<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let result = self.decompress(buf);
+        if result.is_err() {
+            self.failed.set(true);
+        }
+        result
+    }
+}
+
+// Maps paths inside the distribution (starting with the top-level `cef_binary_*` directory) to
+// where they're installed.
+pub(crate) struct Mappings<'a> {
+    maps: Vec<(Regex, &'static str, Component, &'a Path)>,
+}
+
+impl<'a> Mappings<'a> {
+    pub(crate) fn new(
+        platform: Platform,
+        opt_level: OptLevel,
+        components: &'a BTreeMap<Component, PathBuf>,
+    ) -> Mappings<'a> {
+        let mut maps = Vec::new();
+        for (&component, dir) in components {
+            for (src, dest) in mappings(platform, opt_level, component) {
+                maps.push((Regex::new(&src).unwrap(), dest, component, &**dir));
+            }
+        }
+        Mappings { maps }
+    }
+
+    /// Returns the component `path` belongs to, the component's directory, and where below that
+    /// directory it goes.
+    pub(crate) fn destination(&self, path: &str) -> Option<(Component, &'a Path, PathBuf)> {
+        self.maps.iter()
+            .find(|(regex, ..)| regex.is_match(path))
+            .map(|(regex, dest, component, dir)| (*component, *dir, PathBuf::from(&*regex.replace(path, *dest))))
+    }
+}
+
+// Lists the files and symlinks below `dir`, without following symlinks.
+pub(crate) fn walk_dir(dir: &Path, files: &mut Vec<PathBuf>) -> io::Result<()> {
+    for entry in fs::read_dir(dir)? {
+        let entry = entry?;
+        if entry.file_type()?.is_dir() {
+            walk_dir(&entry.path(), files)?;
+        } else {
+            files.push(entry.path());
+        }
+    }
+    Ok(())
+}
+
+// Regexes matching paths inside the archive, and the path relative to the component's directory
+// each match is written to.
+fn mappings(platform: Platform, opt_level: OptLevel, component: Component) -> Vec<(String, &'static str)> {
+    match (component, platform) {
+        (Component::Libs, Platform::Windows) => vec![
+            (format!(r"^[^/]+/{:?}/([^/]+\.(lib|dll|bin))$", opt_level), "${1}"),
+            (format!(r"^[^/]+/{:?}/(swiftshader/[^/]+\.dll)$", opt_level), "${1}"),
+        ],
+        (Component::Libs, Platform::Linux) => vec![
+            (format!(r"^[^/]+/{:?}/([^/]+\.(so|bin)|chrome-sandbox)$", opt_level), "${1}"),
+            (format!(r"^[^/]+/{:?}/(swiftshader/[^/]+\.so)$", opt_level), "${1}"),
+        ],
+        (Component::Libs, Platform::MacOS) => vec![
+            (format!(r"^[^/]+/{:?}/(Chromium Embedded Framework\.framework/.+)$", opt_level), "${1}"),
+        ],
+        // The framework carries its own resources on macOS.
+        (Component::Resources, Platform::MacOS) => vec![],
+        (Component::Resources, _) => vec![
+            (r"^[^/]+/Resources/icudtl\.dat$".to_owned(), "icudtl.dat"),
+            (r"^[^/]+/Resources/((locales/)?[^/]+\.pak)$".to_owned(), "${1}"),
+        ],
+        (Component::Headers, _) => vec![(r"^[^/]+/include/(.+\.h)$".to_owned(), "${1}")],
+        (Component::LibcefDll, _) => vec![(r"^[^/]+/libcef_dll/(.+)$".to_owned(), "${1}")],
+        (Component::Cmake, _) => vec![(r"^[^/]+/cmake/(.+)$".to_owned(), "${1}")],
+        (Component::Tests, _) => vec![(r"^[^/]+/tests/(.+)$".to_owned(), "${1}")],
+        // The symbols distributions have their files at the top level.
+        (Component::Symbols, _) => vec![
+            (format!(r"^[^/]+/({:?}/)?([^/]+\.(pdb|debug))$", opt_level), "${2}"),
+            (format!(r"^[^/]+/({:?}/)?([^/]+\.dSYM/.+)$", opt_level), "${2}"),
+        ],
+    }
+}
+
+/// The locale a file of `component` at `relative` is the resources of, e.g. `de` for
+/// `locales/de.pak`.
+pub(crate) fn locale_of(component: Component, relative: &Path) -> Option<&str> {
+    if component != Component::Resources || relative.parent() != Some(Path::new("locales")) {
+        return None;
+    }
+    match relative.extension() {
+        Some(extension) if extension == "pak" => relative.file_stem()?.to_str(),
+        _ => None,
+    }
+}
+
+/// Why an archive entry with this path must not be extracted, if it must not.
+pub(crate) fn check_entry_path(path: &Path) -> Option<&'static str> {
+    for component in path.components() {
+        match component {
+            PathComponent::Normal(_) | PathComponent::CurDir => (),
+            PathComponent::ParentDir => return Some("path contains `..`"),
+            PathComponent::RootDir | PathComponent::Prefix(_) => return Some("path is absolute"),
+        }
+    }
+    None
+}
+
+/// Why a symlink at `link` (relative to the directory it's extracted to) pointing to `target` must
+/// not be extracted, if it must not. Symlinks must stay inside that directory. Their targets must
+/// also go up before going down, since a `..` after a name that is itself a symlink goes up from
+/// wherever that symlink points to.
+pub(crate) fn check_symlink_target(link: &Path, target: &Path) -> Option<&'static str> {
+    let mut depth = link.parent().map_or(0, |parent| {
+        parent.components().filter(|c| matches!(c, PathComponent::Normal(_))).count()
+    });
+    let mut went_down = false;
+    for component in target.components() {
+        match component {
+            PathComponent::Normal(_) => {
+                depth += 1;
+                went_down = true;
+            },
+            PathComponent::CurDir => (),
+            PathComponent::ParentDir if went_down => return Some("symlink target has `..` after a name"),
+            PathComponent::ParentDir if depth > 0 => depth -= 1,
+            PathComponent::ParentDir => return Some("symlink points outside the destination"),
+            PathComponent::RootDir | PathComponent::Prefix(_) => return Some("symlink target is absolute"),
+        }
+    }
+    None
+}
+
+// Reads `CEF_VERSION` from the headers of an extracted distribution.
+pub(crate) fn read_cef_version(cef_root: &Path) -> Option<String> {
+    let header = fs::read_to_string(cef_root.join("include").join("cef_version.h")).ok()?;
+    header.lines()
+        .filter_map(|line| line.trim().strip_prefix("#define CEF_VERSION "))
+        .map(|version| version.trim().trim_matches('"').to_owned())
+        .next()
+}
+
+/// Reads the API hash of `platform`, as returned by `cef_api_hash(0)`, from `cef_api_hash.h` in
+/// the headers installed into `include_dir`.
+pub fn read_api_hash(include_dir: &Path, platform: Platform) -> Option<String> {
+    let header = fs::read_to_string(include_dir.join("cef_api_hash.h")).ok()?;
+    let os_macros: &[&str] = match platform {
+        Platform::Windows => &["OS_WIN"],
+        Platform::Linux => &["OS_LINUX"],
+        Platform::MacOS => &["OS_MACOSX", "OS_MAC"],
+    };
+    // The hashes of the platforms are defined in an `#if defined(OS_WIN)`/`#elif` chain.
+    let mut in_platform = false;
+    for line in header.lines().map(str::trim) {
+        if let Some(condition) = line.strip_prefix("#if ").or_else(|| line.strip_prefix("#elif ")) {
+            in_platform = os_macros.iter().any(|os| condition.trim() == format!("defined({})", os));
+        } else if line.starts_with("#endif") {
+            in_platform = false;
+        } else if let Some(hash) = line.strip_prefix("#define CEF_API_HASH_PLATFORM ").filter(|_| in_platform) {
+            return Some(hash.trim().trim_matches('"').to_owned());
+        }
+    }
+    None
+}
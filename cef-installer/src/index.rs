@@ -0,0 +1,58 @@
+use serde::Deserialize;
+use std::collections::HashMap;
+
+/// The `index.json` file published at the root of a CEF build CDN.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(transparent)]
+pub struct Index {
+    pub platforms: HashMap<String, IndexPlatform>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct IndexPlatform {
+    pub versions: Vec<IndexVersion>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct IndexVersion {
+    pub cef_version: String,
+    #[serde(default)]
+    pub channel: String,
+    #[serde(default)]
+    pub chromium_version: String,
+    pub files: Vec<IndexFile>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct IndexFile {
+    #[serde(rename = "type")]
+    pub file_type: String,
+    pub name: String,
+    #[serde(default)]
+    pub sha1: String,
+    #[serde(default)]
+    pub size: u64,
+    #[serde(default)]
+    pub last_modified: String,
+}
+
+impl Index {
+    pub fn from_slice(bytes: &[u8]) -> Result<Index, serde_json::Error> {
+        serde_json::from_slice(bytes)
+    }
+
+    pub fn version(&self, platform_key: &str, version: &str) -> Option<&IndexVersion> {
+        self.platforms
+            .get(platform_key)?
+            .versions
+            .iter()
+            .find(|v| v.cef_version == version)
+    }
+
+    pub fn find_file(&self, platform_key: &str, version: &str, file_type: &str) -> Option<&IndexFile> {
+        self.version(platform_key, version)?
+            .files
+            .iter()
+            .find(|f| f.file_type == file_type)
+    }
+}
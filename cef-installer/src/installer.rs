@@ -0,0 +1,679 @@
+use crate::{
+    download::{download_file, fetch_index, join_url, sha1_hex, sha1_path},
+    extract::{
+        check_entry_path, check_symlink_target, locale_of, read_cef_version, walk_dir, BzDecoder, Mappings,
+    },
+    lock::DirLocks,
+    staging::Staging,
+    Arch, Distribution, Error, HttpOptions, OptLevel, Platform, Progress, Result, DEFAULT_CDN_URL,
+};
+use std::{
+    cell::Cell,
+    collections::{BTreeMap, BTreeSet},
+    fs,
+    io::{self, BufRead, BufReader},
+    path::{Path, PathBuf},
+    rc::Rc,
+    sync::Arc,
+};
+use tar::EntryType;
+use urlencoding::encode as urlencode;
+
+/// A part of the CEF distribution that can be extracted to its own directory.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Component {
+    /// The shared libraries and V8 snapshots, or the framework on macOS.
+    Libs,
+    /// `icudtl.dat` and the `.pak` files.
+    Resources,
+    Headers,
+    /// Sources of `libcef_dll_wrapper`.
+    LibcefDll,
+    Cmake,
+    /// Sources of `cefclient`, `cefsimple` and the unit tests.
+    Tests,
+    Symbols,
+}
+
+#[derive(Debug, Clone)]
+pub struct Installer {
+    version: String,
+    platform: Platform,
+    arch: Arch,
+    opt_level: OptLevel,
+    distribution: Distribution,
+    cdn_url: String,
+    http: HttpOptions,
+    archive_dir: Option<PathBuf>,
+    sha1: Option<String>,
+    cef_root: Option<PathBuf>,
+    symlink: bool,
+    components: BTreeMap<Component, PathBuf>,
+    locales: Option<BTreeSet<String>>,
+    quiet: bool,
+    progress: Arc<dyn Progress + Send + Sync>,
+}
+
+// CEF loads this locale when the one in `cef_settings_t.locale` isn't available.
+const FALLBACK_LOCALE: &str = "en-US";
+
+#[derive(Debug, Clone, Default)]
+pub struct InstallReport {
+    /// The archive that was extracted, if it's kept on disk.
+    pub archive: Option<PathBuf>,
+    /// The URL the archive was downloaded from, if it wasn't cached.
+    pub downloaded_from: Option<String>,
+    pub files: Vec<InstalledFile>,
+    /// Files left over from another CEF version, or of locales that are no longer wanted, that were
+    /// deleted.
+    pub removed: Vec<PathBuf>,
+    /// Problems with the installed files that don't prevent using them.
+    pub warnings: Vec<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct InstalledFile {
+    pub component: Component,
+    pub path: PathBuf,
+    /// Whether the file was actually written, as opposed to already existing.
+    pub written: bool,
+}
+
+/// The state of the cached archive, as reported by [`Installer::archive_status`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ArchiveStatus {
+    /// No archive dir is set, or the archive hasn't been downloaded yet.
+    Missing,
+    /// The archive exists but no checksum is known to compare it against.
+    Unverified { path: PathBuf },
+    Verified { path: PathBuf, sha1: String },
+    Corrupt { path: PathBuf, expected: String, actual: String },
+}
+
+impl InstallReport {
+    pub fn files(&self, component: Component) -> impl Iterator<Item = &Path> {
+        self.files.iter()
+            .filter(move |file| file.component == component)
+            .map(|file| &*file.path)
+    }
+}
+
+impl Installer {
+    pub fn new(version: impl Into<String>, platform: Platform) -> Installer {
+        Installer {
+            version: version.into(),
+            platform,
+            arch: Arch::X64,
+            opt_level: OptLevel::Release,
+            distribution: Distribution::Standard,
+            cdn_url: DEFAULT_CDN_URL.to_owned(),
+            http: HttpOptions::from_env(),
+            archive_dir: None,
+            sha1: None,
+            cef_root: None,
+            symlink: false,
+            components: BTreeMap::new(),
+            locales: None,
+            quiet: false,
+            progress: Arc::new(()),
+        }
+    }
+
+    pub fn arch(mut self, arch: Arch) -> Self {
+        self.arch = arch;
+        self
+    }
+
+    pub fn opt_level(mut self, opt_level: OptLevel) -> Self {
+        self.opt_level = opt_level;
+        self
+    }
+
+    pub fn distribution(mut self, distribution: Distribution) -> Self {
+        self.distribution = distribution;
+        self
+    }
+
+    pub fn cdn_url(mut self, cdn_url: impl Into<String>) -> Self {
+        self.cdn_url = cdn_url.into();
+        self
+    }
+
+    /// Proxy, certificate and header settings for downloads. Defaults to
+    /// [`HttpOptions::from_env`].
+    pub fn http(mut self, http: HttpOptions) -> Self {
+        self.http = http;
+        self
+    }
+
+    /// Where the downloaded archive is kept, so that it doesn't have to be downloaded again.
+    pub fn archive_dir(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.archive_dir = Some(dir.into());
+        self
+    }
+
+    /// Pins the SHA1 of the archive instead of trusting the one published in the index.
+    pub fn sha1(mut self, sha1: impl Into<String>) -> Self {
+        self.sha1 = Some(sha1.into());
+        self
+    }
+
+    /// Installs from an already extracted `cef_binary_*` directory instead of an archive.
+    pub fn cef_root(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.cef_root = Some(dir.into());
+        self
+    }
+
+    /// Symlinks files from the [`cef_root`](Installer::cef_root) instead of copying them.
+    pub fn symlink(mut self, symlink: bool) -> Self {
+        self.symlink = symlink;
+        self
+    }
+
+    pub fn component(mut self, component: Component, dir: impl Into<PathBuf>) -> Self {
+        self.components.insert(component, dir.into());
+        self
+    }
+
+    pub fn libs(self, dir: impl Into<PathBuf>) -> Self {
+        self.component(Component::Libs, dir)
+    }
+
+    pub fn resources(self, dir: impl Into<PathBuf>) -> Self {
+        self.component(Component::Resources, dir)
+    }
+
+    pub fn headers(self, dir: impl Into<PathBuf>) -> Self {
+        self.component(Component::Headers, dir)
+    }
+
+    pub fn libcef_dll(self, dir: impl Into<PathBuf>) -> Self {
+        self.component(Component::LibcefDll, dir)
+    }
+
+    pub fn cmake(self, dir: impl Into<PathBuf>) -> Self {
+        self.component(Component::Cmake, dir)
+    }
+
+    pub fn tests(self, dir: impl Into<PathBuf>) -> Self {
+        self.component(Component::Tests, dir)
+    }
+
+    pub fn symbols(self, dir: impl Into<PathBuf>) -> Self {
+        self.component(Component::Symbols, dir)
+    }
+
+    /// Only installs the `.pak` files of these locales, e.g. `de` or `pt-BR`, and of `en-US`, which
+    /// CEF falls back to. All locales are installed by default. macOS frameworks always carry all of
+    /// them.
+    pub fn locales<I>(mut self, locales: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<String>,
+    {
+        let mut locales: BTreeSet<String> = locales.into_iter().map(Into::into).collect();
+        locales.insert(FALLBACK_LOCALE.to_owned());
+        self.locales = Some(locales);
+        self
+    }
+
+    pub fn quiet(mut self, quiet: bool) -> Self {
+        self.quiet = quiet;
+        self
+    }
+
+    pub fn progress(mut self, progress: impl Progress + Send + Sync + 'static) -> Self {
+        self.progress = Arc::new(progress);
+        self
+    }
+
+    pub fn archive_file_name(&self) -> String {
+        self.distribution.archive_file_name(&self.version, &self.platform.index_key(self.arch))
+    }
+
+    fn index_key(&self) -> Result<String> {
+        if !self.platform.supports(self.arch) {
+            return Err(Error::UnsupportedPlatform(format!("{:?} on {:?}", self.platform, self.arch)));
+        }
+        Ok(self.platform.index_key(self.arch))
+    }
+
+    pub fn install(&self) -> Result<InstallReport> {
+        let mut report = InstallReport::default();
+        let archive_dir = self.archive_dir.as_deref().filter(|_| self.cef_root.is_none());
+        let _locks = DirLocks::acquire(
+            self.components.values().map(|dir| &**dir).chain(archive_dir),
+            self.quiet,
+        )?;
+        if let Some(cef_root) = &self.cef_root {
+            self.install_from_dir(cef_root, &mut report)?;
+        } else {
+            let (reader, _temp_archive) = self.open_archive(&mut report)?;
+            self.extract(reader, &mut report)?;
+        }
+
+        let symbols = self.distribution == Distribution::DebugSymbols
+            || self.distribution == Distribution::ReleaseSymbols;
+        if symbols && !self.components.contains_key(&Component::Symbols) {
+            report.warnings.push("nothing was installed from the symbols archive since no symbols dir is set".to_owned());
+        }
+        // macOS frameworks carry their locales inside.
+        match self.components.get(&Component::Resources) {
+            Some(dir) if self.platform != Platform::MacOS && !symbols => {
+                let fallback = dir.join("locales").join(format!("{}.pak", FALLBACK_LOCALE));
+                if !fallback.exists() {
+                    report.warnings.push(format!(
+                        "{} is missing, so CEF fails to start unless the locale in cef_settings_t is installed",
+                        fallback.display(),
+                    ));
+                }
+            },
+            _ => (),
+        }
+        Ok(report)
+    }
+
+    // The Debug and Release builds have the same file names, so switching between them must replace
+    // the libraries and symbols like switching versions does. Release builds keep the plain version,
+    // as do directories only getting files both builds share.
+    fn staging(&self) -> Result<Staging> {
+        let mut dirs: BTreeMap<&Path, String> = BTreeMap::new();
+        for (component, dir) in &self.components {
+            let version = match (component, self.opt_level) {
+                (Component::Libs, OptLevel::Debug) | (Component::Symbols, OptLevel::Debug) => {
+                    format!("{} (Debug)", self.version)
+                },
+                _ => self.version.clone(),
+            };
+            let entry = dirs.entry(dir).or_insert_with(|| version.clone());
+            if *entry == self.version {
+                *entry = version;
+            }
+        }
+        Staging::new(dirs)
+    }
+
+    fn wants(&self, component: Component, relative: &Path) -> bool {
+        match (&self.locales, locale_of(component, relative)) {
+            (Some(locales), Some(locale)) => locales.contains(locale),
+            _ => true,
+        }
+    }
+
+    // Without an archive dir, the archive is downloaded to a temporary file that's deleted once the
+    // returned guard is dropped.
+    fn open_archive(&self, report: &mut InstallReport) -> Result<(Box<dyn BufRead>, Option<RemoveOnDrop>)> {
+        if let Some((path, file)) = self.open_cached_archive()? {
+            report.archive = Some(path);
+            return Ok((Box::new(BufReader::new(file)), None));
+        }
+
+        let index_key = self.index_key()?;
+        let index = fetch_index(&self.cdn_url, &self.http)?;
+        let index_file = index
+            .find_file(&index_key, &self.version, self.distribution.file_type())
+            .ok_or_else(|| Error::NotInIndex {
+                version: self.version.clone(),
+                platform: index_key.clone(),
+                cdn_url: self.cdn_url.clone(),
+            })?;
+        let url = join_url(&self.cdn_url, &urlencode(&index_file.name));
+
+        // Downloading to a separate file first means that an interrupted download never leaves a
+        // truncated archive under the final name, and can be resumed next time.
+        let (targz_path, part_path, temp_archive) = match self.archive_path() {
+            Some(targz_path) => {
+                let targz_dir = targz_path.parent().unwrap();
+                fs::create_dir_all(targz_dir).map_err(Error::io(targz_dir))?;
+                let part_path = targz_path.with_extension("bz2.part");
+                (Some(targz_path), part_path, None)
+            },
+            None => {
+                let part_path = std::env::temp_dir()
+                    .join(format!("{}.{}.part", self.archive_file_name(), std::process::id()));
+                (None, part_path.clone(), Some(RemoveOnDrop(part_path)))
+            },
+        };
+
+        if !self.quiet {
+            if part_path.exists() {
+                eprintln!("Resuming download from {}", url);
+            } else {
+                eprintln!("Fetching from {}", url);
+            }
+        }
+        download_file(&url, &part_path, index_file.size, &self.http, &*self.progress)?;
+
+        let actual_sha1 = fs::File::open(&part_path)
+            .and_then(sha1_hex)
+            .map_err(Error::io(&part_path))?;
+        let expected_sha1 = self.sha1.as_deref().unwrap_or(&index_file.sha1);
+        if !expected_sha1.is_empty() && !expected_sha1.eq_ignore_ascii_case(&actual_sha1) {
+            let _ = fs::remove_file(&part_path);
+            return Err(Error::Checksum {
+                url,
+                expected: expected_sha1.to_owned(),
+                actual: actual_sha1,
+            });
+        }
+
+        let archive_path = match targz_path {
+            Some(targz_path) => {
+                fs::rename(&part_path, &targz_path).map_err(Error::io(&targz_path))?;
+                let sha1_path = sha1_path(&targz_path);
+                fs::write(&sha1_path, &actual_sha1).map_err(Error::io(sha1_path))?;
+                report.archive = Some(targz_path.clone());
+                targz_path
+            },
+            None => part_path,
+        };
+        let size = fs::metadata(&archive_path).map_err(Error::io(&archive_path))?.len();
+        self.progress.download_finished(&url, size);
+        report.downloaded_from = Some(url);
+
+        let file = fs::File::open(&archive_path).map_err(Error::io(&archive_path))?;
+        Ok((Box::new(BufReader::new(file)), temp_archive))
+    }
+
+    pub fn archive_path(&self) -> Option<PathBuf> {
+        self.archive_dir.as_ref().map(|dir| dir.join(self.archive_file_name()))
+    }
+
+    /// Checks the cached archive against the pinned checksum, the one stored next to it when it
+    /// was downloaded or the one published in the index, in that order.
+    pub fn archive_status(&self) -> Result<ArchiveStatus> {
+        let index_key = self.index_key()?;
+        let path = match self.archive_path().filter(|path| path.exists()) {
+            Some(path) => path,
+            None => return Ok(ArchiveStatus::Missing),
+        };
+
+        // Archives we downloaded ourselves have their checksum stored next to them, older ones can
+        // still be checked against the index if it's reachable.
+        let expected_sha1 = self.sha1.clone()
+            .or_else(|| fs::read_to_string(sha1_path(&path)).ok())
+            .or_else(|| fetch_index(&self.cdn_url, &self.http).ok().and_then(|index| {
+                index.find_file(&index_key, &self.version, self.distribution.file_type())
+                    .map(|file| file.sha1.clone())
+            }))
+            .map(|sha1| sha1.trim().to_owned())
+            .filter(|sha1| !sha1.is_empty());
+        let actual_sha1 = fs::File::open(&path)
+            .and_then(sha1_hex)
+            .map_err(Error::io(&path))?;
+
+        Ok(match expected_sha1 {
+            Some(expected) if !expected.eq_ignore_ascii_case(&actual_sha1) => {
+                ArchiveStatus::Corrupt { path, expected, actual: actual_sha1 }
+            },
+            Some(_) => ArchiveStatus::Verified { path, sha1: actual_sha1 },
+            None => ArchiveStatus::Unverified { path },
+        })
+    }
+
+    // Returns `None` if the cached archive was corrupt and has been deleted.
+    fn open_cached_archive(&self) -> Result<Option<(PathBuf, fs::File)>> {
+        let path = match self.archive_status()? {
+            ArchiveStatus::Missing => return Ok(None),
+            ArchiveStatus::Corrupt { path, expected, actual } => {
+                if !self.quiet {
+                    eprintln!(
+                        "CEF archive {} is corrupt (SHA1 {}, expected {}), deleting it",
+                        path.display(),
+                        actual,
+                        expected,
+                    );
+                }
+                fs::remove_file(&path).map_err(Error::io(&path))?;
+                let _ = fs::remove_file(sha1_path(&path));
+                return Ok(None);
+            },
+            ArchiveStatus::Unverified { path } => {
+                if !self.quiet {
+                    eprintln!("No checksum is known for {}, using it unverified", path.display());
+                }
+                path
+            },
+            ArchiveStatus::Verified { path, .. } => path,
+        };
+        if !self.quiet {
+            eprintln!("CEF archive {} already exists", path.display());
+        }
+        let file = fs::File::open(&path).map_err(Error::io(&path))?;
+        Ok(Some((path, file)))
+    }
+
+    /// Makes sure the archive is in the archive dir without extracting anything.
+    pub fn download(&self) -> Result<InstallReport> {
+        let mut report = InstallReport::default();
+        let _locks = DirLocks::acquire(self.archive_dir.as_deref(), self.quiet)?;
+        self.open_archive(&mut report)?;
+        Ok(report)
+    }
+
+    /// Deletes the cached archive of this version, returning the paths that were removed.
+    pub fn clean(&self) -> Result<Vec<PathBuf>> {
+        let mut removed = Vec::new();
+        let _locks = DirLocks::acquire(self.archive_dir.as_deref(), self.quiet)?;
+        if let Some(path) = self.archive_path() {
+            for path in [sha1_path(&path), path.with_extension("bz2.part"), path].iter() {
+                if path.exists() {
+                    fs::remove_file(path).map_err(Error::io(path))?;
+                    removed.push(path.clone());
+                }
+            }
+        }
+        Ok(removed)
+    }
+
+    fn extract(&self, reader: Box<dyn BufRead>, report: &mut InstallReport) -> Result<()> {
+        let read_failed = Rc::new(Cell::new(false));
+        let tar_file = BzDecoder::new(reader, read_failed.clone());
+        let mut archive = tar::Archive::new(tar_file);
+        let cached_path = report.archive.clone();
+        let corrupt = |source| Error::Decompression { path: cached_path.clone(), source };
+
+        let mappings = Mappings::new(self.platform, self.opt_level, &self.components);
+        let mut staging = self.staging()?;
+
+        for entry in archive.entries().map_err(corrupt)? {
+            let mut entry = entry.map_err(corrupt)?;
+            let entry_path = entry.path().map_err(corrupt)?.into_owned();
+            let path_string = entry_path.to_string_lossy().to_string();
+            let unsafe_entry = |reason| Error::UnsafeEntry {
+                path: cached_path.clone(),
+                entry: path_string.clone(),
+                reason,
+            };
+            if let Some(reason) = check_entry_path(&entry_path) {
+                return Err(unsafe_entry(reason));
+            }
+            let destination = mappings.destination(&path_string);
+            let (component, dir, relative) = match destination {
+                Some(destination) => destination,
+                None => continue,
+            };
+            if !self.wants(component, &relative) {
+                staging.remove(dir, &relative);
+                continue;
+            }
+
+            if staging.is_redirected(dir, &relative) {
+                return Err(unsafe_entry("path goes through a symlink"));
+            }
+
+            let path = dir.join(&relative);
+            let written = !staging.is_current(dir, &relative);
+            if !written {
+                if !self.quiet {
+                    eprintln!("{} is up to date", path.display());
+                }
+                report.files.push(InstalledFile { component, path, written });
+                continue;
+            }
+
+            let entry_type = entry.header().entry_type();
+            let link_name = entry.link_name().map_err(corrupt)?.map(|name| name.into_owned());
+            // Hard links name another entry of the archive, which must have been extracted already.
+            let mut hard_link_source = None;
+            match (entry_type, &link_name) {
+                (EntryType::Regular, _) | (EntryType::Continuous, _) | (EntryType::GNUSparse, _) => (),
+                (EntryType::Symlink, Some(target)) => {
+                    if let Some(reason) = check_symlink_target(&relative, target) {
+                        return Err(unsafe_entry(reason));
+                    }
+                },
+                (EntryType::Link, Some(target)) => {
+                    match mappings.destination(&target.to_string_lossy()) {
+                        Some((_, target_dir, target_relative))
+                            if check_entry_path(target).is_none() && target_dir == dir =>
+                        {
+                            // A hard link to a symlink is a copy of it, which may point elsewhere
+                            // from where the copy is.
+                            let source = staging.current_path(target_dir, &target_relative);
+                            let is_symlink = fs::symlink_metadata(&source)
+                                .is_ok_and(|metadata| metadata.file_type().is_symlink());
+                            if is_symlink || staging.is_redirected(target_dir, &target_relative) {
+                                return Err(unsafe_entry("hard link to a symlink or through one"));
+                            }
+                            hard_link_source = Some(source);
+                        },
+                        _ => return Err(unsafe_entry("hard link to a file that isn't extracted alongside it")),
+                    }
+                },
+                _ => return Err(unsafe_entry("unsupported entry type")),
+            }
+
+            self.progress.extracting(component, &path_string, &path);
+            self.log_write(&path, "Writing");
+            let staged = staging.stage(dir, &relative)?;
+            if let Some(source) = hard_link_source {
+                fs::hard_link(&source, &staged)
+                    .or_else(|_| fs::copy(&source, &staged).map(|_| ()))
+                    .map_err(Error::io(&staged))?;
+            } else {
+                // Only the permission bits are kept, so setuid bits from the archive are dropped.
+                entry.set_preserve_permissions(false);
+                entry.unpack(&staged).map_err(|source| match source.kind() {
+                    _ if read_failed.get() => corrupt(source),
+                    io::ErrorKind::UnexpectedEof | io::ErrorKind::InvalidData => corrupt(source),
+                    _ => Error::Io { path: staged.clone(), source },
+                })?;
+            }
+            report.files.push(InstalledFile { component, path, written });
+        }
+
+        report.removed = staging.commit()?;
+        Ok(())
+    }
+
+    fn install_from_dir(&self, cef_root: &Path, report: &mut InstallReport) -> Result<()> {
+        if let Some(found) = read_cef_version(cef_root) {
+            if found != self.version {
+                return Err(Error::VersionMismatch {
+                    path: cef_root.to_owned(),
+                    expected: self.version.clone(),
+                    found,
+                });
+            }
+        }
+
+        let mappings = Mappings::new(self.platform, self.opt_level, &self.components);
+        let mut staging = self.staging()?;
+        let mut sources = Vec::new();
+        walk_dir(cef_root, &mut sources).map_err(Error::io(cef_root))?;
+
+        // The mappings expect paths to start with the archive's top-level directory.
+        let root_name = cef_root.file_name().unwrap_or_default().to_string_lossy();
+        for source in sources {
+            let relative = source.strip_prefix(cef_root).unwrap_or(&source);
+            let path_string = format!(
+                "{}/{}",
+                root_name,
+                relative.to_string_lossy().replace('\\', "/"),
+            );
+            if let Some((component, dir, relative)) = mappings.destination(&path_string) {
+                if !self.wants(component, &relative) {
+                    staging.remove(dir, &relative);
+                    continue;
+                }
+                let path = dir.join(&relative);
+                let written = !staging.is_current(dir, &relative);
+                if written {
+                    self.progress.extracting(component, &path_string, &path);
+                    self.log_write(&path, if self.symlink { "Linking" } else { "Copying" });
+                    let staged = staging.stage(dir, &relative)?;
+                    copy_or_link(&source, &staged, self.symlink).map_err(Error::io(&staged))?;
+                } else if !self.quiet {
+                    eprintln!("{} is up to date", path.display());
+                }
+                report.files.push(InstalledFile { component, path, written });
+            }
+        }
+
+        report.removed = staging.commit()?;
+        Ok(())
+    }
+
+    fn log_write(&self, path: &Path, action: &str) {
+        if self.quiet {
+            return;
+        }
+        if fs::symlink_metadata(path).is_ok() {
+            eprintln!("Replacing {} from another CEF version...", path.display());
+        } else {
+            eprintln!("{} {}...", action, path.display());
+        }
+    }
+}
+
+struct RemoveOnDrop(PathBuf);
+
+impl Drop for RemoveOnDrop {
+    fn drop(&mut self) {
+        let _ = fs::remove_file(&self.0);
+    }
+}
+
+fn copy_or_link(source: &Path, dest: &Path, symlink: bool) -> io::Result<()> {
+    let source_type = fs::symlink_metadata(source)?.file_type();
+    if symlink {
+        let source = dunce::canonicalize(source)?;
+        return symlink_file(&source, dest);
+    }
+    if source_type.is_symlink() {
+        // Keep symlinks inside the distribution (e.g. in the macOS framework) as they are.
+        symlink_file(&fs::read_link(source)?, dest)
+    } else {
+        fs::copy(source, dest).map(|_| ())
+    }
+}
+
+#[cfg(unix)]
+fn symlink_file(target: &Path, link: &Path) -> io::Result<()> {
+    std::os::unix::fs::symlink(target, link)
+}
+
+#[cfg(windows)]
+fn symlink_file(target: &Path, link: &Path) -> io::Result<()> {
+    // Creating symlinks needs developer mode or admin rights on Windows, so fall back to copying.
+    std::os::windows::fs::symlink_file(target, link)
+        .or_else(|_| fs::copy(target, link).map(|_| ()))
+}
+
+/// Deletes every CEF archive in `dir`, along with stored checksums and partial downloads.
+pub fn clean_archive_dir(dir: &Path) -> Result<Vec<PathBuf>> {
+    let mut removed = Vec::new();
+    let _locks = DirLocks::acquire(Some(dir), true)?;
+    for entry in fs::read_dir(dir).map_err(Error::io(dir))? {
+        let path = entry.map_err(Error::io(dir))?.path();
+        let file_name = path.file_name().unwrap_or_default().to_string_lossy();
+        let is_archive = (file_name.starts_with("cef_binary_") || file_name.starts_with("cef_client_"))
+            && [".tar.bz2", ".tar.bz2.sha1", ".tar.bz2.part"].iter().any(|ext| file_name.ends_with(ext));
+        if is_archive && path.is_file() {
+            fs::remove_file(&path).map_err(Error::io(&path))?;
+            removed.push(path);
+        }
+    }
+    Ok(removed)
+}
@@ -0,0 +1,126 @@
+use crate::{
+    staging::{manifest_key, read_manifest},
+    Platform,
+};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+/// What [`validate_layout`] found wrong with a directory CEF is to be loaded from.
+#[derive(Debug, Clone, Default)]
+pub struct LayoutReport {
+    /// The CEF version the library in the directory was installed from, if it was installed by
+    /// this crate.
+    pub version: Option<String>,
+    /// Files CEF needs at runtime that don't exist. A directory stands for files expected in it.
+    pub missing: Vec<PathBuf>,
+    /// Files installed from another CEF version than the library, with that version. Only files
+    /// listed in the directory's manifest are checked.
+    pub mismatched: Vec<(PathBuf, String)>,
+}
+
+impl LayoutReport {
+    pub fn is_ok(&self) -> bool {
+        self.missing.is_empty() && self.mismatched.is_empty()
+    }
+
+    /// One line per problem, for printing.
+    pub fn problems(&self) -> Vec<String> {
+        let missing = self.missing.iter().map(|path| format!("{} is missing", path.display()));
+        let mismatched = self.mismatched.iter().map(|(path, version)| {
+            format!(
+                "{} is from CEF {}, but the library from CEF {}",
+                path.display(),
+                version,
+                self.version.as_deref().unwrap_or_default(),
+            )
+        });
+        missing.chain(mismatched).collect()
+    }
+}
+
+// Relative to the directory the libraries and resources were installed into, the main library
+// first.
+fn runtime_files(platform: Platform) -> &'static [&'static str] {
+    match platform {
+        Platform::Windows => &[
+            "libcef.dll",
+            "chrome_elf.dll",
+            "icudtl.dat",
+            "snapshot_blob.bin",
+            "v8_context_snapshot.bin",
+            "resources.pak",
+            "chrome_100_percent.pak",
+            "chrome_200_percent.pak",
+            "swiftshader/libEGL.dll",
+            "swiftshader/libGLESv2.dll",
+        ],
+        Platform::Linux => &[
+            "libcef.so",
+            "icudtl.dat",
+            "snapshot_blob.bin",
+            "v8_context_snapshot.bin",
+            "resources.pak",
+            "chrome_100_percent.pak",
+            "chrome_200_percent.pak",
+            "swiftshader/libEGL.so",
+            "swiftshader/libGLESv2.so",
+        ],
+        Platform::MacOS => &[
+            "Chromium Embedded Framework.framework/Chromium Embedded Framework",
+            "Chromium Embedded Framework.framework/Resources/icudtl.dat",
+            "Chromium Embedded Framework.framework/Resources/snapshot_blob.bin",
+            "Chromium Embedded Framework.framework/Resources/v8_context_snapshot.bin",
+            "Chromium Embedded Framework.framework/Resources/resources.pak",
+            "Chromium Embedded Framework.framework/Resources/chrome_100_percent.pak",
+            "Chromium Embedded Framework.framework/Resources/chrome_200_percent.pak",
+            "Chromium Embedded Framework.framework/Resources/en.lproj/locale.pak",
+            "Chromium Embedded Framework.framework/Libraries/libswiftshader_libEGL.dylib",
+            "Chromium Embedded Framework.framework/Libraries/libswiftshader_libGLESv2.dylib",
+        ],
+    }
+}
+
+/// Checks that `dir`, with the libraries and resources installed into it, has everything CEF needs
+/// at runtime, and that it all comes from the same CEF version. Missing files otherwise only show
+/// up as crashes in `cef_initialize`.
+pub fn validate_layout(dir: &Path, platform: Platform) -> LayoutReport {
+    let manifest = read_manifest(dir);
+    let files = runtime_files(platform);
+    let mut report = LayoutReport {
+        version: manifest.get(&manifest_key(Path::new(files[0]))).cloned(),
+        ..LayoutReport::default()
+    };
+
+    for file in files {
+        // Following symlinks, which may point into a CEF_ROOT that's gone.
+        if fs::metadata(dir.join(file)).is_err() {
+            report.missing.push(dir.join(file));
+        }
+    }
+    if let Some(expected) = &report.version {
+        for (key, version) in &manifest {
+            if version != expected && fs::symlink_metadata(dir.join(key)).is_ok() {
+                report.mismatched.push((dir.join(key), version.clone()));
+            }
+        }
+    }
+
+    // Which locales are installed is up to the user, but CEF needs at least one.
+    if platform != Platform::MacOS {
+        let locales = dir.join("locales");
+        let has_locale = fs::read_dir(&locales)
+            .map(|entries| {
+                entries.filter_map(|entry| entry.ok()).any(|entry| {
+                    entry.path().extension().is_some_and(|extension| extension == "pak")
+                        && fs::metadata(entry.path()).is_ok()
+                })
+            })
+            .unwrap_or(false);
+        if !has_locale {
+            report.missing.push(locales);
+        }
+    }
+    report
+}
@@ -1,13 +1,101 @@
-use regex::Regex;
-use urlencoding::encode as urlencode;
-use std::{path::Path, fs, io::{Cursor, Read, BufReader}};
+mod download;
+mod error;
+mod extract;
+mod http;
+mod index;
+mod installer;
+mod layout;
+mod lock;
+mod progress;
+mod runtime;
+mod staging;
+mod version;
+pub use download::fetch_index;
+pub use error::{Error, Result};
+pub use extract::read_api_hash;
+pub use http::HttpOptions;
+pub use index::{Index, IndexFile, IndexPlatform, IndexVersion};
+pub use installer::{clean_archive_dir, ArchiveStatus, Component, InstallReport, InstalledFile, Installer};
+pub use layout::{validate_layout, LayoutReport};
+pub use progress::Progress;
+pub use runtime::stage_runtime_files;
+pub use version::{check_bindings_version, resolve_version, Channel, VersionSpec};
+use std::path::PathBuf;
+
+pub const DEFAULT_CDN_URL: &str = "https://cef-builds.spotifycdn.com/";
+
+/// The per-user directory shared by all builds to keep archives and extracted files of `version` in,
+/// e.g. `$XDG_CACHE_HOME/cef-sys/<version>/linux64` on Linux. Installs into it are locked, so it's
+/// safe to use from parallel builds.
+pub fn cache_dir(version: &str, platform: Platform, arch: Arch) -> Option<PathBuf> {
+    Some(dirs::cache_dir()?.join("cef-sys").join(version).join(platform.index_key(arch)))
+}
 
+/// Which of the builds in the distribution to install. Debug builds have DCHECKs enabled, which
+/// catch misuse of the API, but are only in the standard distribution.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub enum OptLevel {
     Debug,
     Release
 }
 
+impl OptLevel {
+    pub fn from_name(name: &str) -> Result<OptLevel> {
+        match name {
+            "debug" | "Debug" => Ok(OptLevel::Debug),
+            "release" | "Release" => Ok(OptLevel::Release),
+            o => Err(Error::UnknownOptLevel(o.to_owned())),
+        }
+    }
+}
+
+/// The archives published for each version and platform.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Distribution {
+    Standard,
+    /// The standard distribution without the Debug build and the sample applications.
+    Minimal,
+    /// The prebuilt `cefclient` sample application.
+    Client,
+    /// Symbols of the Debug build, to be installed with [`Component::Symbols`].
+    DebugSymbols,
+    /// Symbols of the Release build, to be installed with [`Component::Symbols`].
+    ReleaseSymbols,
+}
+
+impl Distribution {
+    pub fn from_name(name: &str) -> Result<Distribution> {
+        match name {
+            "standard" => Ok(Distribution::Standard),
+            "minimal" => Ok(Distribution::Minimal),
+            "client" => Ok(Distribution::Client),
+            "debug_symbols" | "debug-symbols" => Ok(Distribution::DebugSymbols),
+            "release_symbols" | "release-symbols" => Ok(Distribution::ReleaseSymbols),
+            d => Err(Error::UnknownDistribution(d.to_owned())),
+        }
+    }
+
+    /// The distribution's file type in the CDN index.
+    pub fn file_type(self) -> &'static str {
+        match self {
+            Distribution::Standard => "standard",
+            Distribution::Minimal => "minimal",
+            Distribution::Client => "client",
+            Distribution::DebugSymbols => "debug_symbols",
+            Distribution::ReleaseSymbols => "release_symbols",
+        }
+    }
+
+    /// The name of the archive, as published on the CDN.
+    pub fn archive_file_name(self, version: &str, index_key: &str) -> String {
+        match self {
+            Distribution::Standard => format!("cef_binary_{}_{}.tar.bz2", version, index_key),
+            Distribution::Client => format!("cef_client_{}_{}.tar.bz2", version, index_key),
+            d => format!("cef_binary_{}_{}_{}.tar.bz2", version, index_key, d.file_type()),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub enum Platform {
     Windows,
@@ -16,6 +104,15 @@ pub enum Platform {
 }
 
 impl Platform {
+    pub fn from_target_os(target_os: &str) -> Result<Platform> {
+        match target_os {
+            "windows" => Ok(Platform::Windows),
+            "linux" => Ok(Platform::Linux),
+            "macos" => Ok(Platform::MacOS),
+            p => Err(Error::UnsupportedPlatform(p.to_owned())),
+        }
+    }
+
     fn str(self) -> &'static str {
         match self {
             Platform::Windows => "windows",
@@ -23,136 +120,47 @@ impl Platform {
             Platform::MacOS => "macosx",
         }
     }
-}
 
-pub fn download_cef(
-    version: &str,
-    platform: Platform,
-    opt_level: OptLevel,
-    targz_dir: Option<&Path>,
-    lib_dir: Option<&Path>,
-    header_dir: Option<&Path>,
-    libcef_dll_src_dir: Option<&Path>,
-    cmake_macros_dir: Option<&Path>,
-    quiet: bool,
-) -> Result<(), Box<dyn std::error::Error>> {
-    let file_name = format!("cef_binary_{}_{}64.tar.bz2", &version, &platform.str());
-
-    let reader: Box<dyn Read> = {
-        let targz_path = targz_dir
-            .map(|path| path.join(&file_name));
-        let targz_file = targz_path.as_ref()
-            .and_then(|path| fs::File::open(path).ok());
-
-        match targz_file {
-            Some(file) => {
-                if !quiet {
-                    eprintln!("CEF archive {} already exists", targz_path.unwrap().display());
-                }
-                Box::new(BufReader::new(file))
-            },
-            None => {
-                let url = format!(
-                    "http://opensource.spotify.com/cefbuilds/{}",
-                    urlencode(&file_name),
-                );
-
-                if !quiet {
-                    eprintln!("Fetching from {}", url);
-                }
-
-                let response = ureq::get(&url).call();
-                if response.status() != 200 {
-                    panic!("Server responded with HTTP result {}", response.status());
-                }
-                let mut buf = Vec::new();
-                response.into_reader().read_to_end(&mut buf)?;
-
-                if let Some(targz_dir) = targz_dir {
-                    fs::create_dir_all(targz_dir).ok();
-                }
-                if let Some(targz_path) = targz_path {
-                    fs::write(targz_path, &buf).ok();
-                }
-
-                Box::new(Cursor::new(buf))
-            }
+    /// Whether CEF publishes builds of this platform for `arch`.
+    pub fn supports(self, arch: Arch) -> bool {
+        match self {
+            Platform::Linux => true,
+            Platform::Windows => arch != Arch::Arm,
+            Platform::MacOS => arch == Arch::X64 || arch == Arch::Arm64,
         }
-    };
-    let tar_file = bzip2::read::BzDecoder::new(reader);
-    let mut archive = tar::Archive::new(tar_file);
-
-    let mut mappings: Vec<(Regex, String)> = Vec::new();
-    if let Some(lib_dir) = lib_dir {
-        let maps = match platform {
-            Platform::Windows => vec![
-                (format!(r"^[^/]+/{:?}/([^/]+\.(lib|dll|bin))$", opt_level), "${1}"),
-                (format!(r"^[^/]+/{:?}/(swiftshader/[^/]+\.dll)$", opt_level), "${1}"),
-                (r"^[^/]+/Resources/icudtl\.dat$".to_owned(), "icudtl.dat"),
-                (r"^[^/]+/Resources/((locales/)?[^/]+\.pak)$".to_owned(), "${1}"),
-            ],
-            Platform::Linux => vec![
-                (format!(r"^[^/]+/{:?}/([^/]+\.(so|bin))$", opt_level), "${1}"),
-                (format!(r"^[^/]+/{:?}/(swiftshader/[^/]+\.so)$", opt_level), "${1}"),
-                (r"^[^/]+/Resources/icudtl\.dat$".to_owned(), "icudtl.dat"),
-                (r"^[^/]+/Resources/((locales/)?[^/]+\.pak)$".to_owned(), "${1}"),
-            ],
-            Platform::MacOS => vec![(
-                format!(r"^[^/]+/{:?}/(Chromium Embedded Framework\.framework/.+)$", opt_level),
-                "${1}",
-            )],
-        };
-        mappings.extend(maps.into_iter().map(|(src, dest)| (Regex::new(&src).unwrap(), format!("{}/{}", lib_dir.display(), dest))));
     }
 
-    if let Some(header_dir) = header_dir {
-        mappings.push((
-            Regex::new(r#"^[^/]+/include/(.+\.h)"#).unwrap(),
-            format!("{}/{}", header_dir.display(), "${1}")
-        ));
+    /// The platform's name in the CDN index and in archive names, e.g. `linuxarm64`.
+    pub fn index_key(self, arch: Arch) -> String {
+        format!("{}{}", self.str(), arch.str())
     }
+}
 
-    if let Some(libcef_dll_src_dir) = libcef_dll_src_dir {
-        mappings.push((
-            Regex::new(r#"^[^/]+/libcef_dll/(.+)"#).unwrap(),
-            format!("{}/{}", libcef_dll_src_dir.display(), "${1}")
-        ));
-    }
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Arch {
+    X86,
+    X64,
+    Arm,
+    Arm64,
+}
 
-    if let Some(cmake_macros_dir) = cmake_macros_dir {
-        mappings.push((
-            Regex::new(r#"^[^/]+/cmake/(.+)"#).unwrap(),
-            format!("{}/{}", cmake_macros_dir.display(), "${1}")
-        ));
+impl Arch {
+    pub fn from_target_arch(target_arch: &str) -> Result<Arch> {
+        match target_arch {
+            "x86" => Ok(Arch::X86),
+            "x86_64" => Ok(Arch::X64),
+            "arm" => Ok(Arch::Arm),
+            "aarch64" => Ok(Arch::Arm64),
+            a => Err(Error::UnsupportedPlatform(a.to_owned())),
+        }
     }
 
-    for entry in archive.entries()? {
-        if let Ok(mut entry) = entry {
-            if let Ok(path) = entry.path() {
-                let path_string = path.to_string_lossy().to_string();
-                for (regex, destination) in mappings.iter() {
-                    if regex.is_match(&path_string) {
-                        let filename = regex
-                            .replace(&path_string, destination.as_str())
-                            .to_string();
-                        let path = std::path::Path::new(&filename);
-                        if let Some(folder) = path.parent() {
-                            let _ = std::fs::create_dir_all(folder);
-                        }
-                        if !path.exists() {
-                            if !quiet {
-                                eprintln!("Writing {}...", path.display());
-                            }
-                            entry.unpack(&filename)?;
-                        } else if !quiet {
-                            eprintln!("{} already exists", path.display());
-                        }
-                        break;
-                    }
-                }
-            }
+    fn str(self) -> &'static str {
+        match self {
+            Arch::X86 => "32",
+            Arch::X64 => "64",
+            Arch::Arm => "arm",
+            Arch::Arm64 => "arm64",
         }
     }
-
-    Ok(())
 }
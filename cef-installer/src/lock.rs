@@ -0,0 +1,41 @@
+use crate::{Error, Result};
+use fs2::FileExt;
+use std::{
+    collections::BTreeSet,
+    fs,
+    path::{Path, PathBuf},
+};
+
+const LOCK_FILE_NAME: &str = ".cef-installer.lock";
+
+/// Exclusive locks on directories, held by other processes installing into the same directories
+/// (e.g. parallel build scripts sharing the cache) until dropped.
+pub(crate) struct DirLocks {
+    _files: Vec<fs::File>,
+}
+
+impl DirLocks {
+    pub(crate) fn acquire<'a>(dirs: impl IntoIterator<Item = &'a Path>, quiet: bool) -> Result<DirLocks> {
+        // Always locking in the same order keeps two processes from waiting on each other.
+        let dirs: BTreeSet<PathBuf> = dirs.into_iter().map(Path::to_owned).collect();
+        let mut files = Vec::new();
+        for dir in dirs {
+            fs::create_dir_all(&dir).map_err(Error::io(&dir))?;
+            let path = dir.join(LOCK_FILE_NAME);
+            let file = fs::OpenOptions::new()
+                .create(true)
+                .write(true)
+                .truncate(false)
+                .open(&path)
+                .map_err(Error::io(&path))?;
+            if file.try_lock_exclusive().is_err() {
+                if !quiet {
+                    eprintln!("Waiting for another process installing into {}...", dir.display());
+                }
+                file.lock_exclusive().map_err(Error::io(&path))?;
+            }
+            files.push(file);
+        }
+        Ok(DirLocks { _files: files })
+    }
+}
@@ -0,0 +1,392 @@
+use cef_installer::{
+    Arch, ArchiveStatus, Channel, Component, Distribution, HttpOptions, Installer, OptLevel, Platform, Progress,
+    VersionSpec,
+};
+use std::{
+    io::{self, Write},
+    path::PathBuf,
+    process,
+    sync::Mutex,
+};
+use structopt::StructOpt;
+
+#[derive(Debug, StructOpt)]
+#[structopt(about = "Downloads CEF binary distributions and extracts them in a platform-specific layout")]
+struct Opt {
+    /// Base URL of the server publishing `index.json` and the archives; `file://` URLs are read
+    /// from disk
+    #[structopt(long, global = true, env = "CEF_SYS_MIRROR", default_value = cef_installer::DEFAULT_CDN_URL)]
+    cdn_url: String,
+    /// Don't print what's being done
+    #[structopt(short, long, global = true)]
+    quiet: bool,
+    /// Proxy to connect through, e.g. http://proxy:3128; defaults to HTTPS_PROXY and HTTP_PROXY
+    #[structopt(long, global = true)]
+    proxy: Option<String>,
+    /// Comma-separated hosts to connect to without the proxy; defaults to NO_PROXY
+    #[structopt(long, global = true)]
+    no_proxy: Option<String>,
+    /// PEM file with certificates to trust in addition to the usual root certificates
+    #[structopt(long, global = true, number_of_values = 1)]
+    root_certificate: Vec<PathBuf>,
+    /// Header to send with every request, e.g. "Authorization: Bearer <token>"
+    #[structopt(long, global = true, number_of_values = 1, parse(try_from_str = parse_header))]
+    header: Vec<(String, String)>,
+    #[structopt(subcommand)]
+    command: Command,
+}
+
+#[derive(Debug, StructOpt)]
+struct Target {
+    /// Full CEF version, e.g. 84.3.10+ga46056b+chromium-84.0.4147.105, or a spec like 84 or
+    /// "latest beta" to look up in the index
+    #[structopt(long)]
+    version: VersionSpec,
+    /// windows, linux or macos; defaults to the current platform
+    #[structopt(long, parse(try_from_str = Platform::from_target_os))]
+    platform: Option<Platform>,
+    /// x86, x86_64, arm or aarch64; defaults to the current architecture
+    #[structopt(long, parse(try_from_str = Arch::from_target_arch))]
+    arch: Option<Arch>,
+    /// debug or release
+    #[structopt(long, default_value = "release", parse(try_from_str = OptLevel::from_name))]
+    opt_level: OptLevel,
+    /// standard, minimal, client, debug-symbols or release-symbols
+    #[structopt(long, default_value = "standard", parse(try_from_str = Distribution::from_name))]
+    distribution: Distribution,
+    /// SHA1 the archive must have, instead of the one published in the index
+    #[structopt(long)]
+    sha1: Option<String>,
+    /// Where the archive is downloaded to and looked for
+    #[structopt(long, default_value = ".")]
+    archive_dir: PathBuf,
+}
+
+#[derive(Debug, StructOpt)]
+struct Destinations {
+    #[structopt(long)]
+    lib_dir: Option<PathBuf>,
+    /// Defaults to --lib-dir
+    #[structopt(long)]
+    resources_dir: Option<PathBuf>,
+    #[structopt(long)]
+    include_dir: Option<PathBuf>,
+    #[structopt(long)]
+    libcef_dll_dir: Option<PathBuf>,
+    #[structopt(long)]
+    cmake_dir: Option<PathBuf>,
+    #[structopt(long)]
+    tests_dir: Option<PathBuf>,
+    #[structopt(long)]
+    symbols_dir: Option<PathBuf>,
+}
+
+#[derive(Debug, StructOpt)]
+#[allow(clippy::large_enum_variant)]
+enum Command {
+    /// Downloads the archive into the archive dir without extracting it
+    Download {
+        #[structopt(flatten)]
+        target: Target,
+    },
+    /// Extracts the archive, downloading it first if it isn't in the archive dir
+    Extract {
+        #[structopt(flatten)]
+        target: Target,
+        #[structopt(flatten)]
+        destinations: Destinations,
+        /// Install from this extracted `cef_binary_*` directory instead of the archive
+        #[structopt(long)]
+        cef_root: Option<PathBuf>,
+        /// Symlink files from --cef-root instead of copying them
+        #[structopt(long, requires = "cef-root")]
+        symlink: bool,
+        /// Only install the resources of these locales, e.g. de,fr; en-US is always installed
+        #[structopt(long, use_delimiter = true)]
+        locales: Option<Vec<String>>,
+    },
+    /// Lists the versions published for a platform, newest first
+    ListVersions {
+        /// windows, linux or macos; defaults to the current platform
+        #[structopt(long, parse(try_from_str = Platform::from_target_os))]
+        platform: Option<Platform>,
+        /// x86, x86_64, arm or aarch64; defaults to the current architecture
+        #[structopt(long, parse(try_from_str = Arch::from_target_arch))]
+        arch: Option<Arch>,
+        /// Only list the versions of this channel, stable or beta
+        #[structopt(long, parse(try_from_str = Channel::from_name))]
+        channel: Option<Channel>,
+    },
+    /// Prints the full version a spec like 84, "84 beta" or "latest stable" resolves to
+    Resolve {
+        spec: VersionSpec,
+        /// windows, linux or macos; defaults to the current platform
+        #[structopt(long, parse(try_from_str = Platform::from_target_os))]
+        platform: Option<Platform>,
+        /// x86, x86_64, arm or aarch64; defaults to the current architecture
+        #[structopt(long, parse(try_from_str = Arch::from_target_arch))]
+        arch: Option<Arch>,
+        /// Fail unless the version has the same API as this one, e.g. the version bindings were
+        /// generated from
+        #[structopt(long)]
+        bindings_version: Option<String>,
+    },
+    /// Checks the archive in the archive dir against its checksum
+    Verify {
+        #[structopt(flatten)]
+        target: Target,
+    },
+    /// Checks that a dir the libraries and resources were extracted to has everything CEF needs at
+    /// runtime, from a single CEF version
+    Validate {
+        dir: PathBuf,
+        /// windows, linux or macos; defaults to the current platform
+        #[structopt(long, parse(try_from_str = Platform::from_target_os))]
+        platform: Option<Platform>,
+    },
+    /// Deletes downloaded archives
+    Clean {
+        /// Only delete the archives of this version
+        #[structopt(long)]
+        version: Option<String>,
+        #[structopt(long, parse(try_from_str = Platform::from_target_os))]
+        platform: Option<Platform>,
+        #[structopt(long, parse(try_from_str = Arch::from_target_arch))]
+        arch: Option<Arch>,
+        #[structopt(long, default_value = ".")]
+        archive_dir: PathBuf,
+    },
+}
+
+// Draws the download progress as a single line on stderr.
+#[derive(Default)]
+struct ProgressBar {
+    drawn: Mutex<Option<u64>>,
+}
+
+const BAR_WIDTH: u64 = 40;
+
+impl Progress for ProgressBar {
+    fn downloaded(&self, downloaded: u64, total: u64) {
+        // Only redraw when something visible changes.
+        let step = (downloaded * 1000).checked_div(total).unwrap_or(downloaded >> 20);
+        if self.drawn.lock().unwrap().replace(step) == Some(step) {
+            return;
+        }
+        let mib = |bytes: u64| bytes as f64 / (1 << 20) as f64;
+        let mut stderr = io::stderr();
+        if let Some(filled) = (downloaded * BAR_WIDTH).checked_div(total) {
+            let _ = write!(
+                stderr,
+                "\r[{:<width$}] {:5.1}% {:.1}/{:.1} MiB",
+                "=".repeat(filled as usize),
+                downloaded as f64 * 100.0 / total as f64,
+                mib(downloaded),
+                mib(total),
+                width = BAR_WIDTH as usize,
+            );
+        } else {
+            let _ = write!(stderr, "\r{:.1} MiB", mib(downloaded));
+        }
+        let _ = stderr.flush();
+    }
+
+    fn download_finished(&self, _url: &str, _size: u64) {
+        if self.drawn.lock().unwrap().take().is_some() {
+            eprintln!();
+        }
+    }
+}
+
+fn parse_header(header: &str) -> Result<(String, String), String> {
+    match header.split_once(':') {
+        Some((name, value)) => Ok((name.trim().to_owned(), value.trim().to_owned())),
+        None => Err("expected a header like `Name: value`".to_owned()),
+    }
+}
+
+impl Opt {
+    fn http_options(&self) -> HttpOptions {
+        let mut http = HttpOptions::from_env();
+        if let Some(proxy) = &self.proxy {
+            http = http.proxy(&**proxy);
+        }
+        if let Some(no_proxy) = &self.no_proxy {
+            http = http.no_proxy(no_proxy);
+        }
+        for path in &self.root_certificate {
+            http = http.root_certificate(path);
+        }
+        for (name, value) in &self.header {
+            http = http.header(&**name, &**value);
+        }
+        http
+    }
+}
+
+fn host_platform(platform: Option<Platform>) -> cef_installer::Result<Platform> {
+    platform.map(Ok).unwrap_or_else(|| Platform::from_target_os(std::env::consts::OS))
+}
+
+fn host_arch(arch: Option<Arch>) -> cef_installer::Result<Arch> {
+    arch.map(Ok).unwrap_or_else(|| Arch::from_target_arch(std::env::consts::ARCH))
+}
+
+impl Target {
+    fn installer(&self, opt: &Opt) -> cef_installer::Result<Installer> {
+        let (platform, arch) = (host_platform(self.platform)?, host_arch(self.arch)?);
+        let version = cef_installer::resolve_version(&opt.cdn_url, &opt.http_options(), &self.version, platform, arch)?;
+        let mut installer = Installer::new(version, platform)
+            .arch(arch)
+            .opt_level(self.opt_level)
+            .distribution(self.distribution)
+            .cdn_url(&*opt.cdn_url)
+            .http(opt.http_options())
+            .archive_dir(&self.archive_dir)
+            .quiet(opt.quiet);
+        if !opt.quiet {
+            installer = installer.progress(ProgressBar::default());
+        }
+        if let Some(sha1) = &self.sha1 {
+            installer = installer.sha1(&**sha1);
+        }
+        Ok(installer)
+    }
+}
+
+fn run(opt: &Opt) -> cef_installer::Result<bool> {
+    match &opt.command {
+        Command::Download { target } => {
+            let report = target.installer(opt)?.download()?;
+            if let Some(archive) = report.archive {
+                println!("{}", archive.display());
+            }
+        },
+        Command::Extract { target, destinations, cef_root, symlink, locales } => {
+            let d = destinations;
+            let components = [
+                (Component::Libs, &d.lib_dir),
+                (Component::Resources, if d.resources_dir.is_some() { &d.resources_dir } else { &d.lib_dir }),
+                (Component::Headers, &d.include_dir),
+                (Component::LibcefDll, &d.libcef_dll_dir),
+                (Component::Cmake, &d.cmake_dir),
+                (Component::Tests, &d.tests_dir),
+                (Component::Symbols, &d.symbols_dir),
+            ];
+            let mut installer = target.installer(opt)?.symlink(*symlink);
+            if let Some(cef_root) = cef_root {
+                installer = installer.cef_root(cef_root);
+            }
+            if let Some(locales) = locales {
+                installer = installer.locales(locales);
+            }
+            for (component, dir) in components.iter() {
+                if let Some(dir) = dir {
+                    installer = installer.component(*component, dir);
+                }
+            }
+            let report = installer.install()?;
+            for warning in &report.warnings {
+                eprintln!("warning: {}", warning);
+            }
+            if !opt.quiet {
+                let written = report.files.iter().filter(|file| file.written).count();
+                eprintln!(
+                    "{} files written, {} up to date, {} removed",
+                    written,
+                    report.files.len() - written,
+                    report.removed.len(),
+                );
+            }
+        },
+        Command::ListVersions { platform, arch, channel } => {
+            let index_key = host_platform(*platform)?.index_key(host_arch(*arch)?);
+            let index = cef_installer::fetch_index(&opt.cdn_url, &opt.http_options())?;
+            for version in index.versions(&index_key, *channel) {
+                println!("{}\t{}", version.cef_version, version.channel);
+            }
+        },
+        Command::Resolve { spec, platform, arch, bindings_version } => {
+            let (platform, arch) = (host_platform(*platform)?, host_arch(*arch)?);
+            let version = cef_installer::resolve_version(&opt.cdn_url, &opt.http_options(), spec, platform, arch)?;
+            if let Some(bindings_version) = bindings_version {
+                cef_installer::check_bindings_version(&version, bindings_version)?;
+            }
+            println!("{}", version);
+        },
+        Command::Verify { target } => {
+            let installer = target.installer(opt)?;
+            match installer.archive_status()? {
+                ArchiveStatus::Missing => {
+                    eprintln!("{} is not in {}", installer.archive_file_name(), target.archive_dir.display());
+                    return Ok(false);
+                },
+                ArchiveStatus::Unverified { path } => {
+                    eprintln!("no checksum is known for {}", path.display());
+                    return Ok(false);
+                },
+                ArchiveStatus::Verified { path, sha1 } => println!("{}: OK ({})", path.display(), sha1),
+                ArchiveStatus::Corrupt { path, expected, actual } => {
+                    println!("{}: FAILED (SHA1 {}, expected {})", path.display(), actual, expected);
+                    return Ok(false);
+                },
+            }
+        },
+        Command::Validate { dir, platform } => {
+            let report = cef_installer::validate_layout(dir, host_platform(*platform)?);
+            for problem in report.problems() {
+                println!("{}", problem);
+            }
+            if !report.is_ok() {
+                return Ok(false);
+            }
+            if !opt.quiet {
+                match &report.version {
+                    Some(version) => eprintln!("{}: OK (CEF {})", dir.display(), version),
+                    None => eprintln!("{}: OK", dir.display()),
+                }
+            }
+        },
+        Command::Clean { version, platform, arch, archive_dir } => {
+            let removed = match version {
+                Some(version) => {
+                    let distributions = [
+                        Distribution::Standard,
+                        Distribution::Minimal,
+                        Distribution::Client,
+                        Distribution::DebugSymbols,
+                        Distribution::ReleaseSymbols,
+                    ];
+                    let mut removed = Vec::new();
+                    for distribution in distributions.iter() {
+                        removed.extend(Installer::new(&**version, host_platform(*platform)?)
+                            .arch(host_arch(*arch)?)
+                            .distribution(*distribution)
+                            .archive_dir(archive_dir)
+                            .clean()?);
+                    }
+                    removed
+                },
+                None => cef_installer::clean_archive_dir(archive_dir)?,
+            };
+            if !opt.quiet {
+                for path in removed {
+                    eprintln!("Removed {}", path.display());
+                }
+            }
+        },
+    }
+    Ok(true)
+}
+
+fn main() {
+    let opt = Opt::from_args();
+    match run(&opt) {
+        Ok(true) => (),
+        Ok(false) => process::exit(1),
+        Err(err) => {
+            eprintln!("error: {}", err);
+            process::exit(1);
+        },
+    }
+}
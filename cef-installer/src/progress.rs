@@ -0,0 +1,54 @@
+use crate::Component;
+use std::{fmt, path::Path, sync::Arc};
+
+/// Receives progress updates from an [`Installer`](crate::Installer). All methods do nothing by
+/// default, so implementors only need to override the ones they care about. The installer takes
+/// ones that are `Send` and `Sync`, so that it can be moved to another thread.
+pub trait Progress {
+    /// A download from `url` is starting. `resumed_from` bytes were already downloaded by an
+    /// earlier attempt, and `total` is the size of the whole file, or 0 if it isn't known.
+    fn download_started(&self, url: &str, resumed_from: u64, total: u64) {
+        let _ = (url, resumed_from, total);
+    }
+
+    /// `downloaded` bytes out of `total` are on disk now.
+    fn downloaded(&self, downloaded: u64, total: u64) {
+        let _ = (downloaded, total);
+    }
+
+    /// The download completed and `size` bytes were verified.
+    fn download_finished(&self, url: &str, size: u64) {
+        let _ = (url, size);
+    }
+
+    /// `path` is about to be written, from the archive entry or source file `entry`.
+    fn extracting(&self, component: Component, entry: &str, path: &Path) {
+        let _ = (component, entry, path);
+    }
+}
+
+impl Progress for () {}
+
+impl<P: Progress + ?Sized> Progress for Arc<P> {
+    fn download_started(&self, url: &str, resumed_from: u64, total: u64) {
+        (**self).download_started(url, resumed_from, total)
+    }
+
+    fn downloaded(&self, downloaded: u64, total: u64) {
+        (**self).downloaded(downloaded, total)
+    }
+
+    fn download_finished(&self, url: &str, size: u64) {
+        (**self).download_finished(url, size)
+    }
+
+    fn extracting(&self, component: Component, entry: &str, path: &Path) {
+        (**self).extracting(component, entry, path)
+    }
+}
+
+impl fmt::Debug for dyn Progress + Send + Sync {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Progress")
+    }
+}
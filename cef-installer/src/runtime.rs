@@ -0,0 +1,52 @@
+use crate::{staging::read_manifest, Error, Result};
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+/// Makes the files installed into `lib_dir` available in `dest`, e.g. next to an executable so that
+/// it starts without further setup. Files are hard linked if possible and copied otherwise, and
+/// those that are already up to date are skipped. Returns the files that were written.
+pub fn stage_runtime_files(lib_dir: &Path, dest: &Path) -> Result<Vec<PathBuf>> {
+    let mut written = Vec::new();
+    // Only what was installed, not the lock file, the manifest or anything else in there.
+    for key in read_manifest(lib_dir).keys() {
+        // Import libraries are only needed for linking.
+        if key.ends_with(".lib") {
+            continue;
+        }
+        // Installs from CEF_ROOT may have symlinked the files.
+        let source = match fs::canonicalize(lib_dir.join(key)) {
+            Ok(source) => source,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => continue,
+            Err(err) => return Err(Error::io(lib_dir.join(key))(err)),
+        };
+        let path = dest.join(key);
+        if is_up_to_date(&source, &path) {
+            continue;
+        }
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).map_err(Error::io(parent))?;
+        }
+        match fs::remove_file(&path) {
+            Err(err) if err.kind() != io::ErrorKind::NotFound => return Err(Error::io(&path)(err)),
+            _ => (),
+        }
+        if fs::hard_link(&source, &path).is_err() {
+            fs::copy(&source, &path).map_err(Error::io(&path))?;
+        }
+        written.push(path);
+    }
+    Ok(written)
+}
+
+// Hard links share the modification time, while copies are newer than their source.
+fn is_up_to_date(source: &Path, path: &Path) -> bool {
+    match (fs::metadata(source), fs::metadata(path)) {
+        (Ok(source), Ok(staged)) => {
+            source.len() == staged.len()
+                && matches!((source.modified(), staged.modified()), (Ok(a), Ok(b)) if b >= a)
+        },
+        _ => false,
+    }
+}
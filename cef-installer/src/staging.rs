@@ -0,0 +1,177 @@
+use crate::{Error, Result};
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+/// Name of the file in each destination directory recording which CEF version every file in it
+/// was installed from.
+pub(crate) const MANIFEST_FILE_NAME: &str = ".cef-manifest.json";
+const STAGING_DIR_NAME: &str = ".cef-staging";
+
+// Files are first written below a staging directory inside each destination directory, and only
+// moved into place once everything was written, so that a failed install never leaves a mix of
+// versions behind. The manifest is updated last: if the process dies while moving files, the
+// manifest still lists the old version for them and they're replaced by the next install.
+pub(crate) struct Staging {
+    dirs: BTreeMap<PathBuf, StagingDir>,
+}
+
+struct StagingDir {
+    /// What the manifest records the staged files with, usually the CEF version.
+    version: String,
+    staging: PathBuf,
+    manifest: BTreeMap<String, String>,
+    staged: BTreeSet<String>,
+    unwanted: BTreeSet<String>,
+}
+
+impl Staging {
+    pub(crate) fn new<'a>(dirs: impl IntoIterator<Item = (&'a Path, String)>) -> Result<Staging> {
+        let mut staging = Staging { dirs: BTreeMap::new() };
+        for (dir, version) in dirs {
+            let staging_dir = dir.join(STAGING_DIR_NAME);
+            // Left over from an install that was interrupted.
+            if staging_dir.exists() {
+                fs::remove_dir_all(&staging_dir).map_err(Error::io(&staging_dir))?;
+            }
+            fs::create_dir_all(&staging_dir).map_err(Error::io(&staging_dir))?;
+            staging.dirs.insert(dir.to_owned(), StagingDir {
+                version,
+                staging: staging_dir,
+                manifest: read_manifest(dir),
+                staged: BTreeSet::new(),
+                unwanted: BTreeSet::new(),
+            });
+        }
+        Ok(staging)
+    }
+
+    /// Whether `relative` in `dir` exists and was installed from the version being installed. Symlinks
+    /// are followed, so that ones into a `CEF_ROOT` that has since been deleted are replaced.
+    pub(crate) fn is_current(&self, dir: &Path, relative: &Path) -> bool {
+        let entry = &self.dirs[dir];
+        entry.manifest.get(&manifest_key(relative)) == Some(&entry.version)
+            && fs::metadata(dir.join(relative)).is_ok()
+    }
+
+    /// Whether writing `relative` in `dir` would go through a symlink, staged or installed earlier,
+    /// or replace a staged directory, whose files would then be moved through whatever replaced it
+    /// on commit. Either could put files outside of `dir`.
+    pub(crate) fn is_redirected(&self, dir: &Path, relative: &Path) -> bool {
+        let entry = &self.dirs[dir];
+        let is_symlink = |path: PathBuf| {
+            fs::symlink_metadata(path).is_ok_and(|metadata| metadata.file_type().is_symlink())
+        };
+        let through_symlink = relative.ancestors()
+            .skip(1)
+            .filter(|parent| !parent.as_os_str().is_empty())
+            .any(|parent| is_symlink(entry.staging.join(parent)) || is_symlink(dir.join(parent)));
+        through_symlink || fs::symlink_metadata(entry.staging.join(relative)).is_ok_and(|m| m.is_dir())
+    }
+
+    /// Returns the path `relative` in `dir` should be written to until the install is committed.
+    pub(crate) fn stage(&mut self, dir: &Path, relative: &Path) -> Result<PathBuf> {
+        let entry = self.dirs.get_mut(dir).unwrap();
+        let path = entry.staging.join(relative);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).map_err(Error::io(parent))?;
+        }
+        entry.staged.insert(manifest_key(relative));
+        Ok(path)
+    }
+
+    /// Marks `relative` in `dir` to be deleted on commit, if it was installed by an earlier install.
+    pub(crate) fn remove(&mut self, dir: &Path, relative: &Path) {
+        self.dirs.get_mut(dir).unwrap().unwanted.insert(manifest_key(relative));
+    }
+
+    /// Where `relative` in `dir` currently is: in staging if it was staged, in `dir` otherwise.
+    pub(crate) fn current_path(&self, dir: &Path, relative: &Path) -> PathBuf {
+        let entry = &self.dirs[dir];
+        if entry.staged.contains(&manifest_key(relative)) {
+            entry.staging.join(relative)
+        } else {
+            dir.join(relative)
+        }
+    }
+
+    /// Moves the staged files into place, deletes files the manifest lists from other versions that
+    /// weren't replaced or that were marked for removal, and returns the deleted files.
+    pub(crate) fn commit(mut self) -> Result<Vec<PathBuf>> {
+        let mut removed = Vec::new();
+        for (dir, entry) in &mut self.dirs {
+            for key in &entry.staged {
+                let path = dir.join(key);
+                if let Some(parent) = path.parent() {
+                    fs::create_dir_all(parent).map_err(Error::io(parent))?;
+                }
+                remove_existing(&path).map_err(Error::io(&path))?;
+                fs::rename(entry.staging.join(key), &path).map_err(Error::io(&path))?;
+            }
+
+            let stale: Vec<String> = entry.manifest.iter()
+                .filter(|&(key, file_version)| {
+                    (*file_version != entry.version && !entry.staged.contains(key)) || entry.unwanted.contains(key)
+                })
+                .map(|(key, _)| key.clone())
+                .collect();
+            for key in stale {
+                let path = dir.join(&key);
+                match fs::remove_file(&path) {
+                    Ok(()) => removed.push(path),
+                    Err(err) if err.kind() == io::ErrorKind::NotFound => (),
+                    Err(err) => return Err(Error::io(path)(err)),
+                }
+                entry.manifest.remove(&key);
+            }
+
+            for key in &entry.staged {
+                entry.manifest.insert(key.clone(), entry.version.clone());
+            }
+            write_manifest(dir, &entry.manifest)?;
+            let _ = fs::remove_dir_all(&entry.staging);
+        }
+        Ok(removed)
+    }
+}
+
+impl Drop for Staging {
+    fn drop(&mut self) {
+        for entry in self.dirs.values() {
+            let _ = fs::remove_dir_all(&entry.staging);
+        }
+    }
+}
+
+pub(crate) fn manifest_key(relative: &Path) -> String {
+    relative.to_string_lossy().replace('\\', "/")
+}
+
+// A missing or unreadable manifest is treated as empty, so every file gets replaced.
+pub(crate) fn read_manifest(dir: &Path) -> BTreeMap<String, String> {
+    fs::read(dir.join(MANIFEST_FILE_NAME))
+        .ok()
+        .and_then(|bytes| serde_json::from_slice(&bytes).ok())
+        .unwrap_or_default()
+}
+
+fn write_manifest(dir: &Path, manifest: &BTreeMap<String, String>) -> Result<()> {
+    let path = dir.join(MANIFEST_FILE_NAME);
+    let temp_path = dir.join(format!("{}.tmp", MANIFEST_FILE_NAME));
+    let json = serde_json::to_vec_pretty(manifest).expect("manifest is always serializable");
+    fs::write(&temp_path, json).map_err(Error::io(&temp_path))?;
+    fs::rename(&temp_path, &path).map_err(Error::io(&path))
+}
+
+// Renaming replaces files, but not directories, and Windows refuses to replace read-only files.
+fn remove_existing(path: &Path) -> io::Result<()> {
+    match fs::symlink_metadata(path) {
+        Ok(metadata) if metadata.is_dir() => fs::remove_dir_all(path),
+        Ok(metadata) if cfg!(windows) && metadata.permissions().readonly() => fs::remove_file(path),
+        Ok(_) => Ok(()),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(()),
+        Err(err) => Err(err),
+    }
+}
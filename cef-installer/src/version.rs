@@ -0,0 +1,274 @@
+use crate::{download::fetch_index, Arch, Error, HttpOptions, Index, IndexVersion, Platform, Result};
+use std::{cmp::Reverse, fmt, str::FromStr};
+
+/// The release channels CEF builds are published in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Channel {
+    Stable,
+    Beta,
+}
+
+impl Channel {
+    pub fn from_name(name: &str) -> Result<Channel> {
+        match name {
+            "stable" => Ok(Channel::Stable),
+            "beta" => Ok(Channel::Beta),
+            c => Err(Error::UnknownChannel(c.to_owned())),
+        }
+    }
+
+    /// The channel's name in the CDN index.
+    pub fn name(self) -> &'static str {
+        match self {
+            Channel::Stable => "stable",
+            Channel::Beta => "beta",
+        }
+    }
+}
+
+/// A version to look up in the index: a full version like `84.3.10+ga46056b+chromium-84.0.4147.105`,
+/// a prefix of its number like `84` or `84.3`, or `latest`, each optionally with a channel, as in
+/// `latest beta` or `84 stable`. Everything but full versions resolves to the newest matching
+/// build of the stable channel, unless another channel is given.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VersionSpec {
+    version: Option<String>,
+    channel: Option<Channel>,
+}
+
+impl VersionSpec {
+    pub fn parse(spec: &str) -> Result<VersionSpec> {
+        let invalid = || Error::InvalidVersionSpec(spec.to_owned());
+        let mut parsed = VersionSpec { version: None, channel: None };
+        let mut latest = false;
+        for word in spec.split_whitespace() {
+            if word == "latest" && !latest {
+                latest = true;
+                continue;
+            }
+            if let Ok(channel) = Channel::from_name(word) {
+                if parsed.channel.replace(channel).is_some() {
+                    return Err(invalid());
+                }
+                continue;
+            }
+            let number = word.split('+').next().unwrap_or_default();
+            let is_number = !number.is_empty()
+                && number.split('.').all(|part| !part.is_empty() && part.bytes().all(|b| b.is_ascii_digit()));
+            if !is_number || parsed.version.replace(word.to_owned()).is_some() {
+                return Err(invalid());
+            }
+        }
+        // "latest 84" is fine, but something must be given.
+        if !latest && parsed.version.is_none() && parsed.channel.is_none() {
+            return Err(invalid());
+        }
+        Ok(parsed)
+    }
+
+    /// Whether this is a full version, which can be used without looking it up in the index.
+    pub fn full_version(&self) -> Option<&str> {
+        self.version.as_deref().filter(|version| version.contains('+'))
+    }
+
+    pub fn matches(&self, version: &IndexVersion) -> bool {
+        if let Some(full_version) = self.full_version() {
+            return version.cef_version == full_version;
+        }
+        let channel = self.channel.unwrap_or(Channel::Stable);
+        let number = version_number(&version.cef_version);
+        version.channel == channel.name()
+            && self.version.as_ref().map_or(true, |prefix| {
+                number == prefix || number.starts_with(&format!("{}.", prefix))
+            })
+    }
+}
+
+impl FromStr for VersionSpec {
+    type Err = Error;
+
+    fn from_str(spec: &str) -> Result<VersionSpec> {
+        VersionSpec::parse(spec)
+    }
+}
+
+impl fmt::Display for VersionSpec {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(full_version) = self.full_version() {
+            return write!(f, "{}", full_version);
+        }
+        let channel = self.channel.unwrap_or(Channel::Stable).name();
+        match &self.version {
+            Some(prefix) => write!(f, "{} {}", prefix, channel),
+            None => write!(f, "latest {}", channel),
+        }
+    }
+}
+
+impl Index {
+    /// The versions published for a platform, newest first, optionally only those of `channel`.
+    pub fn versions(&self, platform_key: &str, channel: Option<Channel>) -> Vec<&IndexVersion> {
+        let mut versions: Vec<&IndexVersion> = self.platforms
+            .get(platform_key)
+            .map(|platform| platform.versions.iter().collect())
+            .unwrap_or_default();
+        versions.retain(|version| channel.map_or(true, |channel| version.channel == channel.name()));
+        versions.sort_by_key(|version| Reverse(version_key(&version.cef_version)));
+        versions
+    }
+
+    /// The newest version published for a platform that matches `spec`.
+    pub fn resolve(&self, platform_key: &str, spec: &VersionSpec) -> Option<&IndexVersion> {
+        self.versions(platform_key, None).into_iter().find(|version| spec.matches(version))
+    }
+}
+
+/// Resolves `spec` to a full version using the index at `cdn_url`. Full versions are returned as
+/// they are, without fetching the index.
+pub fn resolve_version(
+    cdn_url: &str,
+    http: &HttpOptions,
+    spec: &VersionSpec,
+    platform: Platform,
+    arch: Arch,
+) -> Result<String> {
+    if let Some(full_version) = spec.full_version() {
+        return Ok(full_version.to_owned());
+    }
+    let index_key = platform.index_key(arch);
+    let index = fetch_index(cdn_url, http)?;
+    match index.resolve(&index_key, spec) {
+        Some(version) => Ok(version.cef_version.clone()),
+        None => Err(Error::NotInIndex { version: spec.to_string(), platform: index_key, cdn_url: cdn_url.to_owned() }),
+    }
+}
+
+/// Checks that `version` can be used with bindings generated from the headers of
+/// `bindings_version`. CEF only changes its API when the minor version changes, so the versions must
+/// agree up to it.
+pub fn check_bindings_version(version: &str, bindings_version: &str) -> Result<()> {
+    let api_version = |version: &str| version_key(version).into_iter().take(2).collect::<Vec<_>>();
+    if api_version(version) == api_version(bindings_version) {
+        Ok(())
+    } else {
+        Err(Error::IncompatibleVersion { version: version.to_owned(), bindings_version: bindings_version.to_owned() })
+    }
+}
+
+// The part of a CEF version before the commit hash, e.g. `84.3.10`.
+fn version_number(version: &str) -> &str {
+    version.split('+').next().unwrap_or_default()
+}
+
+fn version_key(version: &str) -> Vec<u64> {
+    version_number(version).split('.').map(|part| part.parse().unwrap_or(0)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn index(versions: &[(&str, &str)]) -> Index {
+        let versions: Vec<_> = versions.iter()
+            .map(|(version, channel)| serde_json::json!({ "cef_version": version, "channel": channel, "files": [] }))
+            .collect();
+        serde_json::from_value(serde_json::json!({ "linux64": { "versions": versions } })).unwrap()
+    }
+
+    fn spec(spec: &str) -> VersionSpec {
+        VersionSpec::parse(spec).unwrap()
+    }
+
+    #[test]
+    fn parses_specs() {
+        let parsed = |version: Option<&str>, channel| VersionSpec { version: version.map(str::to_owned), channel };
+        assert_eq!(spec("84"), parsed(Some("84"), None));
+        assert_eq!(spec("84.3"), parsed(Some("84.3"), None));
+        assert_eq!(spec("latest"), parsed(None, None));
+        assert_eq!(spec("beta"), parsed(None, Some(Channel::Beta)));
+        assert_eq!(spec("latest beta"), parsed(None, Some(Channel::Beta)));
+        assert_eq!(spec("latest 84 beta"), parsed(Some("84"), Some(Channel::Beta)));
+        assert_eq!(spec("  84   stable "), parsed(Some("84"), Some(Channel::Stable)));
+        assert_eq!(spec("84.3.10+ga46056b+chromium-84.0.4147.105").full_version(), Some("84.3.10+ga46056b+chromium-84.0.4147.105"));
+        assert_eq!(spec("84.3").full_version(), None);
+
+        for invalid in &["", "  ", "stable beta", "beta beta", "latest latest", "84 85", "nightly", "84.", "84..3", ".3", "v84", "+gabc"] {
+            match VersionSpec::parse(invalid) {
+                Err(Error::InvalidVersionSpec(spec)) => assert_eq!(spec, *invalid),
+                other => panic!("{:?} parsed as {:?}", invalid, other),
+            }
+        }
+    }
+
+    #[test]
+    fn displays_specs() {
+        assert_eq!(spec("84").to_string(), "84 stable");
+        assert_eq!(spec("latest").to_string(), "latest stable");
+        assert_eq!(spec("beta latest").to_string(), "latest beta");
+        assert_eq!(spec("1.2.3+gabc+chromium-1.0 beta").to_string(), "1.2.3+gabc+chromium-1.0");
+    }
+
+    #[test]
+    fn resolves_prefixes_by_number() {
+        let index = index(&[
+            ("84.3.10+gabc+chromium-84.0.4147.105", "stable"),
+            ("84.30.1+gabc+chromium-84.0.4147.105", "stable"),
+            ("840.1.1+gabc+chromium-840.0.0.0", "stable"),
+            ("85.0.1+gabc+chromium-85.0.0.0", "beta"),
+        ]);
+        let resolve = |s| index.resolve("linux64", &spec(s)).map(|version| &*version.cef_version);
+        // `84` isn't a prefix of `840`, nor `84.3` of `84.30`.
+        assert_eq!(resolve("84"), Some("84.30.1+gabc+chromium-84.0.4147.105"));
+        assert_eq!(resolve("84.3"), Some("84.3.10+gabc+chromium-84.0.4147.105"));
+        assert_eq!(resolve("84.3.10"), Some("84.3.10+gabc+chromium-84.0.4147.105"));
+        assert_eq!(resolve("84.3.1"), None);
+        // Only stable builds, unless another channel is asked for.
+        assert_eq!(resolve("latest"), Some("840.1.1+gabc+chromium-840.0.0.0"));
+        assert_eq!(resolve("85"), None);
+        assert_eq!(resolve("85 beta"), Some("85.0.1+gabc+chromium-85.0.0.0"));
+        assert_eq!(resolve("latest 84 beta"), None);
+        // Full versions match regardless of their channel.
+        assert_eq!(resolve("85.0.1+gabc+chromium-85.0.0.0"), Some("85.0.1+gabc+chromium-85.0.0.0"));
+        assert_eq!(resolve("85.0.1+gdef+chromium-85.0.0.0"), None);
+        assert_eq!(index.resolve("windows64", &spec("latest")).map(|v| &*v.cef_version), None);
+    }
+
+    #[test]
+    fn lists_versions_newest_first() {
+        let index = index(&[
+            ("9.1.0+gabc+chromium-9.0.0.0", "stable"),
+            ("84.3.9+gabc+chromium-84.0.0.0", "stable"),
+            ("84.3.10+gabc+chromium-84.0.0.0", "beta"),
+            ("10.0.0+gabc+chromium-10.0.0.0", "stable"),
+        ]);
+        let versions = |channel| -> Vec<&str> {
+            index.versions("linux64", channel).iter().map(|version| &*version.cef_version).collect()
+        };
+        // Compared by number, not as strings.
+        assert_eq!(versions(None), vec![
+            "84.3.10+gabc+chromium-84.0.0.0",
+            "84.3.9+gabc+chromium-84.0.0.0",
+            "10.0.0+gabc+chromium-10.0.0.0",
+            "9.1.0+gabc+chromium-9.0.0.0",
+        ]);
+        assert_eq!(versions(Some(Channel::Beta)), vec!["84.3.10+gabc+chromium-84.0.0.0"]);
+        assert_eq!(versions(Some(Channel::Stable)).len(), 3);
+        assert!(index.versions("macosx64", None).is_empty());
+    }
+
+    #[test]
+    fn checks_versions_against_the_bindings() {
+        let bindings = "84.3.10+ga46056b+chromium-84.0.4147.105";
+        assert!(check_bindings_version(bindings, bindings).is_ok());
+        assert!(check_bindings_version("84.3.12+gabc+chromium-84.0.4147.135", bindings).is_ok());
+        assert!(check_bindings_version("84.3", bindings).is_ok());
+        for version in &["84.4.1+gabc+chromium-84.0.4147.135", "85.3.10+gabc+chromium-85.0.0.0", "84", "8.3.10"] {
+            match check_bindings_version(version, bindings) {
+                Err(Error::IncompatibleVersion { version: v, bindings_version }) => {
+                    assert_eq!((&*v, &*bindings_version), (*version, bindings));
+                },
+                other => panic!("{} was accepted: {:?}", version, other),
+            }
+        }
+    }
+}
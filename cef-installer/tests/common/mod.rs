@@ -0,0 +1,276 @@
+// Helpers shared by the integration tests: crafted archives, and stand-ins for the CDN and for
+// proxies.
+#![allow(dead_code)]
+
+use cef_installer::{Installer, Platform};
+use std::{
+    collections::HashMap,
+    fs,
+    io::{self, BufReader, Read, Write},
+    net::{Shutdown, TcpListener, TcpStream},
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex},
+    thread,
+};
+use tar::{EntryType, Header};
+
+pub const VERSION: &str = "1.0.0+gabc+chromium-1.0.0.0";
+
+pub enum Entry {
+    File(&'static str, u32, &'static [u8]),
+    Symlink(&'static str, &'static str),
+    HardLink(&'static str, &'static str),
+}
+
+// Writes names into the header directly, because `tar::Builder` refuses the malicious ones.
+pub fn archive(entries: &[Entry]) -> Vec<u8> {
+    let mut builder = tar::Builder::new(Vec::new());
+    for entry in entries {
+        let mut header = Header::new_gnu();
+        let (name, data) = match *entry {
+            Entry::File(name, mode, data) => {
+                header.set_entry_type(EntryType::Regular);
+                header.set_mode(mode);
+                (name, data)
+            },
+            Entry::Symlink(name, target) | Entry::HardLink(name, target) => {
+                let entry_type = if let Entry::Symlink(..) = entry { EntryType::Symlink } else { EntryType::Link };
+                header.set_entry_type(entry_type);
+                header.set_mode(0o777);
+                let link_name = &mut header.as_gnu_mut().unwrap().linkname;
+                link_name[..target.len()].copy_from_slice(target.as_bytes());
+                (name, &[][..])
+            },
+        };
+        header.as_gnu_mut().unwrap().name[..name.len()].copy_from_slice(name.as_bytes());
+        header.set_size(data.len() as u64);
+        header.set_cksum();
+        builder.append(&header, data).unwrap();
+    }
+    let tar = builder.into_inner().unwrap();
+
+    let mut encoder = bzip2::write::BzEncoder::new(Vec::new(), bzip2::Compression::Default);
+    std::io::Write::write_all(&mut encoder, &tar).unwrap();
+    encoder.finish().unwrap()
+}
+
+/// An installer of `version` for linux64 into `out/lib` and `out/include` under `root`. `entries`
+/// are put into the archive dir as its archive, and their checksum is pinned, so nothing is
+/// downloaded.
+pub fn archive_installer(root: &Path, version: &str, entries: &[Entry]) -> Installer {
+    let archive_dir = root.join("archives");
+    let installer = Installer::new(version, Platform::Linux)
+        .archive_dir(&archive_dir)
+        .libs(root.join("out/lib"))
+        .headers(root.join("out/include"))
+        .quiet(true);
+
+    let bytes = archive(entries);
+    fs::create_dir_all(&archive_dir).unwrap();
+    fs::write(archive_dir.join(installer.archive_file_name()), &bytes).unwrap();
+    let sha1 = sha1::Sha1::from(&bytes).digest().to_string();
+    installer.sha1(sha1)
+}
+
+pub fn fixture(name: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures").join(name)
+}
+
+/// The files of a CDN publishing `archive` as the standard distribution of [`VERSION`] for
+/// linux64.
+pub fn cdn_files(archive: Vec<u8>) -> HashMap<String, Vec<u8>> {
+    let name = format!("cef_binary_{}_linux64.tar.bz2", VERSION);
+    let index = serde_json::json!({
+        "linux64": {
+            "versions": [{
+                "cef_version": VERSION,
+                "channel": "stable",
+                "files": [{
+                    "type": "standard",
+                    "name": name,
+                    "sha1": sha1::Sha1::from(&archive).digest().to_string(),
+                    "size": archive.len(),
+                }],
+            }],
+        },
+    });
+    let mut files = HashMap::new();
+    files.insert("index.json".to_owned(), serde_json::to_vec(&index).unwrap());
+    // Requested with the `+`s in the version percent-encoded.
+    files.insert(name.replace('+', "%2B"), archive);
+    files
+}
+
+#[derive(Debug, Clone)]
+pub struct Request {
+    pub path: String,
+    pub headers: Vec<(String, String)>,
+}
+
+impl Request {
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers.iter()
+            .find(|(n, _)| n.eq_ignore_ascii_case(name))
+            .map(|(_, value)| &**value)
+    }
+}
+
+/// A minimal HTTP server standing in for the CDN. It answers every request with a file, `404`, or
+/// `401` if the required header is missing, and closes the connection.
+pub struct Server {
+    pub url: String,
+    pub requests: Arc<Mutex<Vec<Request>>>,
+}
+
+impl Server {
+    pub fn start(files: HashMap<String, Vec<u8>>, required_header: Option<(&str, &str)>) -> Server {
+        Server::start_inner(files, required_header, false)
+    }
+
+    /// Serves HTTPS as `localhost`, with a certificate issued by `tests/fixtures/ca.pem`.
+    pub fn start_tls(files: HashMap<String, Vec<u8>>) -> Server {
+        Server::start_inner(files, None, true)
+    }
+
+    fn start_inner(files: HashMap<String, Vec<u8>>, required_header: Option<(&str, &str)>, tls: bool) -> Server {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let url = if tls { format!("https://localhost:{}", port) } else { format!("http://127.0.0.1:{}", port) };
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let files = Arc::new(files);
+        let required_header = required_header.map(|(name, value)| (name.to_owned(), value.to_owned()));
+        let tls_config = if tls { Some(Arc::new(tls_server_config())) } else { None };
+
+        let log = requests.clone();
+        thread::spawn(move || {
+            for stream in listener.incoming() {
+                let stream = match stream {
+                    Ok(stream) => stream,
+                    Err(_) => continue,
+                };
+                let (files, log, required_header) = (files.clone(), log.clone(), required_header.clone());
+                let tls_config = tls_config.clone();
+                thread::spawn(move || {
+                    let result = match tls_config {
+                        Some(config) => {
+                            let session = rustls::ServerSession::new(&config);
+                            let mut stream = rustls::StreamOwned::new(session, stream);
+                            respond(&mut stream, &files, &log, &required_header)
+                        },
+                        None => {
+                            let mut stream = stream;
+                            respond(&mut stream, &files, &log, &required_header)
+                        },
+                    };
+                    // Failed TLS handshakes are expected when testing untrusted certificates.
+                    let _ = result;
+                });
+            }
+        });
+        Server { url, requests }
+    }
+
+    pub fn requests(&self) -> Vec<Request> {
+        self.requests.lock().unwrap().clone()
+    }
+}
+
+fn tls_server_config() -> rustls::ServerConfig {
+    let mut certs = BufReader::new(fs::File::open(fixture("localhost.pem")).unwrap());
+    let mut keys = BufReader::new(fs::File::open(fixture("localhost.key")).unwrap());
+    let certs = rustls::internal::pemfile::certs(&mut certs).unwrap();
+    let key = rustls::internal::pemfile::pkcs8_private_keys(&mut keys).unwrap().remove(0);
+    let mut config = rustls::ServerConfig::new(rustls::NoClientAuth::new());
+    config.set_single_cert(certs, key).unwrap();
+    config
+}
+
+// Reads up to the end of the request head, returning its lines.
+fn read_head(stream: &mut impl Read) -> io::Result<Vec<String>> {
+    let mut head = Vec::new();
+    let mut byte = [0];
+    while !head.ends_with(b"\r\n\r\n") {
+        if stream.read(&mut byte)? == 0 {
+            return Err(io::ErrorKind::UnexpectedEof.into());
+        }
+        head.push(byte[0]);
+    }
+    Ok(String::from_utf8_lossy(&head).lines().map(str::to_owned).collect())
+}
+
+fn respond(
+    stream: &mut (impl Read + Write),
+    files: &HashMap<String, Vec<u8>>,
+    log: &Mutex<Vec<Request>>,
+    required_header: &Option<(String, String)>,
+) -> io::Result<()> {
+    let head = read_head(stream)?;
+    let path = head[0].split(' ').nth(1).unwrap_or_default().trim_start_matches('/').to_owned();
+    let headers = head[1..].iter()
+        .filter_map(|line| line.split_once(':'))
+        .map(|(name, value)| (name.trim().to_owned(), value.trim().to_owned()))
+        .collect();
+    let request = Request { path, headers };
+    log.lock().unwrap().push(request.clone());
+
+    let authorized = match &required_header {
+        Some((name, value)) => request.header(name) == Some(value),
+        None => true,
+    };
+    let (status, body) = match files.get(&request.path) {
+        _ if !authorized => ("401 Unauthorized", &[][..]),
+        Some(body) => ("200 OK", &body[..]),
+        None => ("404 Not Found", &[][..]),
+    };
+    write!(stream, "HTTP/1.1 {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n", status, body.len())?;
+    stream.write_all(body)?;
+    stream.flush()
+}
+
+/// A stand-in for an HTTP proxy, tunnelling `CONNECT` requests and recording their targets.
+pub struct Proxy {
+    pub url: String,
+    pub targets: Arc<Mutex<Vec<String>>>,
+}
+
+impl Proxy {
+    pub fn start() -> Proxy {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://127.0.0.1:{}", listener.local_addr().unwrap().port());
+        let targets = Arc::new(Mutex::new(Vec::new()));
+        let log = targets.clone();
+        thread::spawn(move || {
+            for client in listener.incoming().flatten() {
+                let log = log.clone();
+                thread::spawn(move || tunnel(client, &log));
+            }
+        });
+        Proxy { url, targets }
+    }
+
+    pub fn targets(&self) -> Vec<String> {
+        self.targets.lock().unwrap().clone()
+    }
+}
+
+fn tunnel(mut client: TcpStream, log: &Mutex<Vec<String>>) -> io::Result<()> {
+    let head = read_head(&mut client)?;
+    let mut request_line = head[0].split(' ');
+    if request_line.next() != Some("CONNECT") {
+        return client.write_all(b"HTTP/1.1 405 Method Not Allowed\r\n\r\n");
+    }
+    let target = request_line.next().unwrap_or_default().to_owned();
+    log.lock().unwrap().push(target.clone());
+    let mut server = TcpStream::connect(&*target)?;
+    client.write_all(b"HTTP/1.1 200 Connection established\r\n\r\n")?;
+
+    let (mut client_reader, mut server_writer) = (client.try_clone()?, server.try_clone()?);
+    let upstream = thread::spawn(move || {
+        let _ = io::copy(&mut client_reader, &mut server_writer);
+        let _ = server_writer.shutdown(Shutdown::Write);
+    });
+    let _ = io::copy(&mut server, &mut client);
+    let _ = client.shutdown(Shutdown::Write);
+    let _ = upstream.join();
+    Ok(())
+}
@@ -0,0 +1,157 @@
+// Extraction of crafted archives. The archive is put into the archive dir with its checksum pinned,
+// so nothing is downloaded.
+
+mod common;
+
+use cef_installer::{Error, Installer};
+use common::{archive_installer, Entry, VERSION};
+use std::{fs, path::Path};
+use tempfile::TempDir;
+
+struct Setup {
+    root: TempDir,
+    installer: Installer,
+}
+
+impl Setup {
+    fn new(entries: &[Entry]) -> Setup {
+        let root = tempfile::tempdir().unwrap();
+        let installer = archive_installer(root.path(), VERSION, entries);
+        Setup { installer, root }
+    }
+
+    fn path(&self, path: &str) -> std::path::PathBuf {
+        self.root.path().join(path)
+    }
+
+    fn assert_rejected(&self) {
+        match self.installer.install() {
+            Err(Error::UnsafeEntry { .. }) => (),
+            other => panic!("expected the archive to be rejected, got {:?}", other.map(|_| ())),
+        }
+        // Nothing may have been moved into place, not even the harmless entries.
+        assert!(!self.path("out/include/ok.h").exists());
+        assert!(!self.path("escaped.h").exists());
+    }
+}
+
+fn symlink_metadata(path: &Path) -> Option<fs::Metadata> {
+    fs::symlink_metadata(path).ok()
+}
+
+#[test]
+fn rejects_parent_dir_components() {
+    let setup = Setup::new(&[
+        Entry::File("cef/include/ok.h", 0o644, b"ok"),
+        Entry::File("cef/include/../../../escaped.h", 0o644, b"evil"),
+    ]);
+    setup.assert_rejected();
+}
+
+#[test]
+fn rejects_absolute_paths() {
+    let setup = Setup::new(&[
+        Entry::File("cef/include/ok.h", 0o644, b"ok"),
+        Entry::File("/tmp/cef-installer-test-absolute.h", 0o644, b"evil"),
+    ]);
+    setup.assert_rejected();
+    assert!(!Path::new("/tmp/cef-installer-test-absolute.h").exists());
+}
+
+#[test]
+fn rejects_escaping_symlinks() {
+    for target in &["../../../escaped.h", "/etc/passwd", "sub/../../.."] {
+        let setup = Setup::new(&[
+            Entry::File("cef/include/ok.h", 0o644, b"ok"),
+            Entry::Symlink("cef/include/link.h", target),
+        ]);
+        setup.assert_rejected();
+        assert!(symlink_metadata(&setup.path("out/include/link.h")).is_none());
+    }
+}
+
+#[test]
+fn rejects_paths_through_symlinks() {
+    // Each symlink stays inside the headers dir on its own, but `l.h` goes up from where `d.h`
+    // points to.
+    let setup = Setup::new(&[
+        Entry::File("cef/include/ok.h", 0o644, b"ok"),
+        Entry::Symlink("cef/include/x/y/d.h", "../.."),
+        Entry::Symlink("cef/include/l.h", "x/y/d.h/../.."),
+        Entry::File("cef/include/l.h/escaped.h", 0o644, b"evil"),
+    ]);
+    setup.assert_rejected();
+    assert!(!setup.path("out/escaped.h").exists());
+
+    let setup = Setup::new(&[
+        Entry::File("cef/include/ok.h", 0o644, b"ok"),
+        Entry::Symlink("cef/include/x/y/d.h", "../.."),
+        Entry::File("cef/include/x/y/d.h/escaped.h", 0o644, b"evil"),
+    ]);
+    setup.assert_rejected();
+
+    // A symlink replacing a directory would take the files staged in it along on commit.
+    let setup = Setup::new(&[
+        Entry::File("cef/include/ok.h", 0o644, b"ok"),
+        Entry::File("cef/include/x.h/y.h/escaped.h", 0o644, b"evil"),
+        Entry::Symlink("cef/include/x.h/y.h", ".."),
+    ]);
+    setup.assert_rejected();
+}
+
+#[test]
+fn rejects_hard_links_to_symlinks() {
+    // The copy would point to `../ok.h` from the headers dir itself.
+    let setup = Setup::new(&[
+        Entry::File("cef/include/ok.h", 0o644, b"ok"),
+        Entry::Symlink("cef/include/internal/alias.h", "../ok.h"),
+        Entry::HardLink("cef/include/copy.h", "cef/include/internal/alias.h"),
+    ]);
+    setup.assert_rejected();
+
+    let setup = Setup::new(&[
+        Entry::File("cef/include/ok.h", 0o644, b"ok"),
+        Entry::Symlink("cef/include/internal.h", "."),
+        Entry::HardLink("cef/include/copy.h", "cef/include/internal.h/ok.h"),
+    ]);
+    setup.assert_rejected();
+}
+
+#[test]
+fn rejects_hard_links_to_outside_files() {
+    for target in &["/etc/passwd", "../escaped.h", "cef/Release/libcef.so"] {
+        let setup = Setup::new(&[
+            Entry::File("cef/include/ok.h", 0o644, b"ok"),
+            Entry::HardLink("cef/include/link.h", target),
+        ]);
+        setup.assert_rejected();
+    }
+}
+
+#[cfg(unix)]
+#[test]
+fn preserves_symlinks_and_modes() {
+    use std::os::unix::fs::PermissionsExt;
+
+    let setup = Setup::new(&[
+        Entry::File("cef/include/ok.h", 0o644, b"ok"),
+        Entry::Symlink("cef/include/internal/alias.h", "../ok.h"),
+        Entry::HardLink("cef/include/copy.h", "cef/include/ok.h"),
+        Entry::File("cef/Release/libcef.so", 0o755, b"lib"),
+        Entry::File("cef/Release/chrome-sandbox", 0o4755, b"sandbox"),
+    ]);
+    setup.installer.install().unwrap();
+
+    let alias = setup.path("out/include/internal/alias.h");
+    assert!(symlink_metadata(&alias).unwrap().file_type().is_symlink());
+    assert_eq!(fs::read_link(&alias).unwrap(), Path::new("../ok.h"));
+    assert_eq!(fs::read(&alias).unwrap(), b"ok");
+    assert_eq!(fs::read(setup.path("out/include/copy.h")).unwrap(), b"ok");
+
+    let mode = |path| fs::metadata(setup.path(path)).unwrap().permissions().mode() & 0o7777;
+    assert_eq!(mode("out/include/ok.h"), 0o644);
+    assert_eq!(mode("out/lib/libcef.so"), 0o755);
+    // Setuid must be granted explicitly, not by whoever made the archive.
+    assert_eq!(mode("out/lib/chrome-sandbox"), 0o755);
+}
+
@@ -0,0 +1,76 @@
+// Downloads from a local stand-in for the CDN. The options are always given explicitly, so that
+// proxies configured in the environment running the tests don't interfere.
+
+mod common;
+
+use cef_installer::{Error, HttpOptions, Installer, Platform};
+use common::{archive, cdn_files, fixture, Entry, Proxy, Server, VERSION};
+use tempfile::TempDir;
+
+fn cdn() -> std::collections::HashMap<String, Vec<u8>> {
+    cdn_files(archive(&[Entry::File("cef/Release/libcef.so", 0o755, b"lib")]))
+}
+
+fn install(server: &Server, http: HttpOptions) -> (TempDir, cef_installer::Result<()>) {
+    let root = tempfile::tempdir().unwrap();
+    let result = Installer::new(VERSION, Platform::Linux)
+        .cdn_url(&*server.url)
+        .http(http)
+        .archive_dir(root.path().join("archives"))
+        .libs(root.path().join("lib"))
+        .quiet(true)
+        .install()
+        .map(|_| ());
+    (root, result)
+}
+
+#[test]
+fn sends_the_auth_header() {
+    let server = Server::start(cdn(), Some(("Authorization", "Bearer secret")));
+
+    match install(&server, HttpOptions::new()).1 {
+        Err(Error::HttpStatus { status: 401, .. }) => (),
+        other => panic!("expected HTTP 401, got {:?}", other),
+    }
+
+    let (root, result) = install(&server, HttpOptions::new().header("Authorization", "Bearer secret"));
+    result.unwrap();
+    assert!(root.path().join("lib/libcef.so").exists());
+    let requests = server.requests();
+    let authorized: Vec<_> = requests.iter().filter(|r| r.header("Authorization").is_some()).collect();
+    assert_eq!(authorized.len(), 2, "index and archive requests, got {:?}", requests);
+}
+
+#[test]
+fn connects_through_the_proxy() {
+    let server = Server::start(cdn(), None);
+    let proxy = Proxy::start();
+    let server_address = server.url.trim_start_matches("http://").to_owned();
+
+    // Proxy URLs often come with a trailing slash.
+    install(&server, HttpOptions::new().proxy(format!("{}/", proxy.url))).1.unwrap();
+    assert_eq!(proxy.targets(), vec![server_address.clone(), server_address]);
+
+    let http = HttpOptions::new().proxy(&*proxy.url).no_proxy("example.com, 127.0.0.1");
+    install(&server, http).1.unwrap();
+    assert_eq!(proxy.targets().len(), 2, "NO_PROXY hosts must be connected to directly");
+}
+
+#[test]
+fn trusts_extra_root_certificates() {
+    let server = Server::start_tls(cdn());
+
+    match install(&server, HttpOptions::new()).1 {
+        Err(Error::Network { .. }) => (),
+        other => panic!("expected the certificate to be rejected, got {:?}", other),
+    }
+
+    let (root, result) = install(&server, HttpOptions::new().root_certificate(fixture("ca.pem")));
+    result.unwrap();
+    assert!(root.path().join("lib/libcef.so").exists());
+
+    match install(&server, HttpOptions::new().root_certificate(fixture("localhost.key"))).1 {
+        Err(Error::Io { path, .. }) => assert_eq!(path, fixture("localhost.key")),
+        other => panic!("expected the certificate file to be rejected, got {:?}", other),
+    }
+}
@@ -0,0 +1,39 @@
+// Proxy settings from the environment. This is its own test binary since it changes the process's
+// environment, which would leak into tests running in parallel.
+
+mod common;
+
+use cef_installer::{HttpOptions, Installer, Platform};
+use common::{archive, cdn_files, fixture, Entry, Proxy, Server, VERSION};
+use std::env;
+
+#[test]
+fn honors_https_proxy_and_no_proxy() {
+    let server = Server::start_tls(cdn_files(archive(&[Entry::File("cef/Release/libcef.so", 0o755, b"lib")])));
+    let proxy = Proxy::start();
+    let install = || {
+        let root = tempfile::tempdir().unwrap();
+        Installer::new(VERSION, Platform::Linux)
+            .cdn_url(&*server.url)
+            .http(HttpOptions::from_env().root_certificate(fixture("ca.pem")))
+            .archive_dir(root.path().join("archives"))
+            .libs(root.path().join("lib"))
+            .quiet(true)
+            .install()
+            .unwrap();
+    };
+
+    // The lowercase variants take precedence.
+    for name in &["https_proxy", "http_proxy", "all_proxy", "no_proxy", "HTTP_PROXY", "ALL_PROXY"] {
+        env::remove_var(name);
+    }
+    env::set_var("HTTPS_PROXY", &proxy.url);
+    env::set_var("NO_PROXY", "example.com");
+    install();
+    let server_address = server.url.trim_start_matches("https://");
+    assert_eq!(proxy.targets(), vec![server_address, server_address]);
+
+    env::set_var("NO_PROXY", ".example.com,localhost");
+    install();
+    assert_eq!(proxy.targets().len(), 2, "NO_PROXY hosts must be connected to directly");
+}
@@ -0,0 +1,158 @@
+// Installing over earlier installs: the manifest records which version every file is from, and files
+// of other versions are replaced or removed. Archives are put into the archive dir with their
+// checksum pinned, so nothing is downloaded.
+
+mod common;
+
+use cef_installer::{InstallReport, Installer, OptLevel, Platform};
+use common::{archive_installer, Entry, VERSION};
+use serde_json::json;
+use std::{fs, path::{Path, PathBuf}};
+use tempfile::TempDir;
+
+const NEW_VERSION: &str = "2.0.0+gdef+chromium-2.0.0.0";
+
+struct Setup {
+    root: TempDir,
+}
+
+impl Setup {
+    fn new() -> Setup {
+        Setup { root: tempfile::tempdir().unwrap() }
+    }
+
+    fn path(&self, path: &str) -> PathBuf {
+        self.root.path().join(path)
+    }
+
+    fn install(&self, version: &str, opt_level: OptLevel, entries: &[Entry]) -> InstallReport {
+        archive_installer(self.root.path(), version, entries).opt_level(opt_level).install().unwrap()
+    }
+
+    fn manifest(&self, dir: &str) -> serde_json::Value {
+        serde_json::from_slice(&fs::read(self.path(dir).join(".cef-manifest.json")).unwrap()).unwrap()
+    }
+
+    fn read(&self, path: &str) -> Vec<u8> {
+        fs::read(self.path(path)).unwrap()
+    }
+}
+
+fn written(report: &InstallReport) -> Vec<&Path> {
+    report.files.iter().filter(|file| file.written).map(|file| &*file.path).collect()
+}
+
+#[test]
+fn replaces_files_of_other_versions() {
+    let setup = Setup::new();
+    setup.install(VERSION, OptLevel::Release, &[
+        Entry::File("cef/include/a.h", 0o644, b"a1"),
+        Entry::File("cef/Release/libcef.so", 0o755, b"lib1"),
+    ]);
+    assert_eq!(setup.manifest("out/include"), json!({ "a.h": VERSION }));
+
+    // Installing the same version again writes nothing.
+    let report = setup.install(VERSION, OptLevel::Release, &[
+        Entry::File("cef/include/a.h", 0o644, b"a1"),
+        Entry::File("cef/Release/libcef.so", 0o755, b"lib1"),
+    ]);
+    assert!(written(&report).is_empty(), "{:?}", written(&report));
+
+    let report = setup.install(NEW_VERSION, OptLevel::Release, &[
+        Entry::File("cef/include/a.h", 0o644, b"a2"),
+        Entry::File("cef/Release/libcef.so", 0o755, b"lib2"),
+    ]);
+    assert_eq!(written(&report).len(), 2);
+    assert_eq!(setup.read("out/include/a.h"), b"a2");
+    assert_eq!(setup.read("out/lib/libcef.so"), b"lib2");
+    assert_eq!(setup.manifest("out/include"), json!({ "a.h": NEW_VERSION }));
+    assert_eq!(setup.manifest("out/lib"), json!({ "libcef.so": NEW_VERSION }));
+}
+
+#[test]
+fn removes_files_the_new_version_lacks() {
+    let setup = Setup::new();
+    setup.install(VERSION, OptLevel::Release, &[
+        Entry::File("cef/include/a.h", 0o644, b"a1"),
+        Entry::File("cef/include/internal/b.h", 0o644, b"b1"),
+    ]);
+    // Files that aren't in the manifest aren't ours to delete.
+    fs::write(setup.path("out/include/mine.h"), b"mine").unwrap();
+
+    let report = setup.install(NEW_VERSION, OptLevel::Release, &[Entry::File("cef/include/a.h", 0o644, b"a2")]);
+    assert_eq!(report.removed, vec![setup.path("out/include/internal/b.h")]);
+    assert!(!setup.path("out/include/internal/b.h").exists());
+    assert_eq!(setup.read("out/include/mine.h"), b"mine");
+    assert_eq!(setup.manifest("out/include"), json!({ "a.h": NEW_VERSION }));
+}
+
+#[test]
+fn recovers_from_an_interrupted_install() {
+    let setup = Setup::new();
+    setup.install(VERSION, OptLevel::Release, &[Entry::File("cef/include/a.h", 0o644, b"a1")]);
+
+    // As if the process died after moving the new file into place, but before writing the manifest,
+    // with a staged file still waiting.
+    fs::write(setup.path("out/include/a.h"), b"a2").unwrap();
+    fs::create_dir_all(setup.path("out/include/.cef-staging")).unwrap();
+    fs::write(setup.path("out/include/.cef-staging/b.h"), b"b2").unwrap();
+
+    let report = setup.install(NEW_VERSION, OptLevel::Release, &[Entry::File("cef/include/a.h", 0o644, b"a2")]);
+    assert_eq!(written(&report), vec![setup.path("out/include/a.h")]);
+    assert!(!setup.path("out/include/.cef-staging").exists());
+    assert!(!setup.path("out/include/b.h").exists());
+    assert_eq!(setup.manifest("out/include"), json!({ "a.h": NEW_VERSION }));
+}
+
+#[test]
+fn switches_between_debug_and_release() {
+    let entries = [
+        Entry::File("cef/include/a.h", 0o644, b"a"),
+        Entry::File("cef/Debug/libcef.so", 0o755, b"debug"),
+        Entry::File("cef/Release/libcef.so", 0o755, b"release"),
+    ];
+    let setup = Setup::new();
+    setup.install(VERSION, OptLevel::Release, &entries);
+    assert_eq!(setup.read("out/lib/libcef.so"), b"release");
+
+    // Only the libraries differ between the builds, so the headers stay.
+    let report = setup.install(VERSION, OptLevel::Debug, &entries);
+    assert_eq!(written(&report), vec![setup.path("out/lib/libcef.so")]);
+    assert_eq!(setup.read("out/lib/libcef.so"), b"debug");
+    assert_eq!(setup.manifest("out/lib"), json!({ "libcef.so": format!("{} (Debug)", VERSION) }));
+    assert_eq!(setup.manifest("out/include"), json!({ "a.h": VERSION }));
+
+    let report = setup.install(VERSION, OptLevel::Release, &entries);
+    assert_eq!(written(&report), vec![setup.path("out/lib/libcef.so")]);
+    assert_eq!(setup.read("out/lib/libcef.so"), b"release");
+    assert_eq!(setup.manifest("out/lib"), json!({ "libcef.so": VERSION }));
+}
+
+#[cfg(unix)]
+#[test]
+fn relinks_files_whose_cef_root_is_gone() {
+    let setup = Setup::new();
+    let install_from = |root: &str| {
+        let root = setup.path(root);
+        fs::create_dir_all(root.join("include")).unwrap();
+        fs::create_dir_all(root.join("Release")).unwrap();
+        fs::write(root.join("include/a.h"), b"a").unwrap();
+        fs::write(root.join("Release/libcef.so"), b"lib").unwrap();
+        Installer::new(VERSION, Platform::Linux)
+            .cef_root(&root)
+            .symlink(true)
+            .libs(setup.path("out/lib"))
+            .headers(setup.path("out/include"))
+            .quiet(true)
+            .install()
+            .unwrap()
+    };
+    install_from("old/cef_binary");
+    fs::remove_dir_all(setup.path("old")).unwrap();
+    assert!(fs::metadata(setup.path("out/lib/libcef.so")).is_err());
+
+    let report = install_from("new/cef_binary");
+    assert_eq!(written(&report).len(), 2);
+    assert_eq!(fs::read_link(setup.path("out/lib/libcef.so")).unwrap(), setup.path("new/cef_binary/Release/libcef.so"));
+    assert_eq!(setup.read("out/lib/libcef.so"), b"lib");
+}
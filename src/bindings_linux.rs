@@ -35,7 +35,7 @@ fn bindgen_test_layout__cef_string_wide_t() {
         concat!("Alignment of ", stringify!(_cef_string_wide_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_string_wide_t>())).str_ as *const _ as usize },
+        ::std::mem::offset_of!(_cef_string_wide_t, str_),
         0usize,
         concat!(
             "Offset of field: ",
@@ -45,7 +45,7 @@ fn bindgen_test_layout__cef_string_wide_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_string_wide_t>())).length as *const _ as usize },
+        ::std::mem::offset_of!(_cef_string_wide_t, length),
         8usize,
         concat!(
             "Offset of field: ",
@@ -55,7 +55,7 @@ fn bindgen_test_layout__cef_string_wide_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_string_wide_t>())).dtor as *const _ as usize },
+        ::std::mem::offset_of!(_cef_string_wide_t, dtor),
         16usize,
         concat!(
             "Offset of field: ",
@@ -86,7 +86,7 @@ fn bindgen_test_layout__cef_string_utf8_t() {
         concat!("Alignment of ", stringify!(_cef_string_utf8_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_string_utf8_t>())).str_ as *const _ as usize },
+        ::std::mem::offset_of!(_cef_string_utf8_t, str_),
         0usize,
         concat!(
             "Offset of field: ",
@@ -96,7 +96,7 @@ fn bindgen_test_layout__cef_string_utf8_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_string_utf8_t>())).length as *const _ as usize },
+        ::std::mem::offset_of!(_cef_string_utf8_t, length),
         8usize,
         concat!(
             "Offset of field: ",
@@ -106,7 +106,7 @@ fn bindgen_test_layout__cef_string_utf8_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_string_utf8_t>())).dtor as *const _ as usize },
+        ::std::mem::offset_of!(_cef_string_utf8_t, dtor),
         16usize,
         concat!(
             "Offset of field: ",
@@ -137,7 +137,7 @@ fn bindgen_test_layout__cef_string_utf16_t() {
         concat!("Alignment of ", stringify!(_cef_string_utf16_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_string_utf16_t>())).str_ as *const _ as usize },
+        ::std::mem::offset_of!(_cef_string_utf16_t, str_),
         0usize,
         concat!(
             "Offset of field: ",
@@ -147,7 +147,7 @@ fn bindgen_test_layout__cef_string_utf16_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_string_utf16_t>())).length as *const _ as usize },
+        ::std::mem::offset_of!(_cef_string_utf16_t, length),
         8usize,
         concat!(
             "Offset of field: ",
@@ -157,7 +157,7 @@ fn bindgen_test_layout__cef_string_utf16_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_string_utf16_t>())).dtor as *const _ as usize },
+        ::std::mem::offset_of!(_cef_string_utf16_t, dtor),
         16usize,
         concat!(
             "Offset of field: ",
@@ -455,7 +455,7 @@ fn bindgen_test_layout__cef_time_t() {
         concat!("Alignment of ", stringify!(_cef_time_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_time_t>())).year as *const _ as usize },
+        ::std::mem::offset_of!(_cef_time_t, year),
         0usize,
         concat!(
             "Offset of field: ",
@@ -465,7 +465,7 @@ fn bindgen_test_layout__cef_time_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_time_t>())).month as *const _ as usize },
+        ::std::mem::offset_of!(_cef_time_t, month),
         4usize,
         concat!(
             "Offset of field: ",
@@ -475,7 +475,7 @@ fn bindgen_test_layout__cef_time_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_time_t>())).day_of_week as *const _ as usize },
+        ::std::mem::offset_of!(_cef_time_t, day_of_week),
         8usize,
         concat!(
             "Offset of field: ",
@@ -485,7 +485,7 @@ fn bindgen_test_layout__cef_time_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_time_t>())).day_of_month as *const _ as usize },
+        ::std::mem::offset_of!(_cef_time_t, day_of_month),
         12usize,
         concat!(
             "Offset of field: ",
@@ -495,7 +495,7 @@ fn bindgen_test_layout__cef_time_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_time_t>())).hour as *const _ as usize },
+        ::std::mem::offset_of!(_cef_time_t, hour),
         16usize,
         concat!(
             "Offset of field: ",
@@ -505,7 +505,7 @@ fn bindgen_test_layout__cef_time_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_time_t>())).minute as *const _ as usize },
+        ::std::mem::offset_of!(_cef_time_t, minute),
         20usize,
         concat!(
             "Offset of field: ",
@@ -515,7 +515,7 @@ fn bindgen_test_layout__cef_time_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_time_t>())).second as *const _ as usize },
+        ::std::mem::offset_of!(_cef_time_t, second),
         24usize,
         concat!(
             "Offset of field: ",
@@ -525,7 +525,7 @@ fn bindgen_test_layout__cef_time_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_time_t>())).millisecond as *const _ as usize },
+        ::std::mem::offset_of!(_cef_time_t, millisecond),
         28usize,
         concat!(
             "Offset of field: ",
@@ -598,7 +598,7 @@ fn bindgen_test_layout__cef_main_args_t() {
         concat!("Alignment of ", stringify!(_cef_main_args_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_main_args_t>())).argc as *const _ as usize },
+        ::std::mem::offset_of!(_cef_main_args_t, argc),
         0usize,
         concat!(
             "Offset of field: ",
@@ -608,7 +608,7 @@ fn bindgen_test_layout__cef_main_args_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_main_args_t>())).argv as *const _ as usize },
+        ::std::mem::offset_of!(_cef_main_args_t, argv),
         8usize,
         concat!(
             "Offset of field: ",
@@ -646,7 +646,7 @@ fn bindgen_test_layout__cef_window_info_t() {
         concat!("Alignment of ", stringify!(_cef_window_info_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_window_info_t>())).window_name as *const _ as usize },
+        ::std::mem::offset_of!(_cef_window_info_t, window_name),
         0usize,
         concat!(
             "Offset of field: ",
@@ -656,7 +656,7 @@ fn bindgen_test_layout__cef_window_info_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_window_info_t>())).x as *const _ as usize },
+        ::std::mem::offset_of!(_cef_window_info_t, x),
         24usize,
         concat!(
             "Offset of field: ",
@@ -666,7 +666,7 @@ fn bindgen_test_layout__cef_window_info_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_window_info_t>())).y as *const _ as usize },
+        ::std::mem::offset_of!(_cef_window_info_t, y),
         28usize,
         concat!(
             "Offset of field: ",
@@ -676,7 +676,7 @@ fn bindgen_test_layout__cef_window_info_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_window_info_t>())).width as *const _ as usize },
+        ::std::mem::offset_of!(_cef_window_info_t, width),
         32usize,
         concat!(
             "Offset of field: ",
@@ -686,7 +686,7 @@ fn bindgen_test_layout__cef_window_info_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_window_info_t>())).height as *const _ as usize },
+        ::std::mem::offset_of!(_cef_window_info_t, height),
         36usize,
         concat!(
             "Offset of field: ",
@@ -696,9 +696,7 @@ fn bindgen_test_layout__cef_window_info_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_window_info_t>())).parent_window as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_window_info_t, parent_window),
         40usize,
         concat!(
             "Offset of field: ",
@@ -708,10 +706,7 @@ fn bindgen_test_layout__cef_window_info_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_window_info_t>())).windowless_rendering_enabled as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_window_info_t, windowless_rendering_enabled),
         48usize,
         concat!(
             "Offset of field: ",
@@ -721,10 +716,7 @@ fn bindgen_test_layout__cef_window_info_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_window_info_t>())).shared_texture_enabled as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_window_info_t, shared_texture_enabled),
         52usize,
         concat!(
             "Offset of field: ",
@@ -734,10 +726,7 @@ fn bindgen_test_layout__cef_window_info_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_window_info_t>())).external_begin_frame_enabled as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_window_info_t, external_begin_frame_enabled),
         56usize,
         concat!(
             "Offset of field: ",
@@ -747,7 +736,7 @@ fn bindgen_test_layout__cef_window_info_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_window_info_t>())).window as *const _ as usize },
+        ::std::mem::offset_of!(_cef_window_info_t, window),
         64usize,
         concat!(
             "Offset of field: ",
@@ -822,7 +811,7 @@ fn bindgen_test_layout__cef_settings_t() {
         concat!("Alignment of ", stringify!(_cef_settings_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_settings_t>())).size as *const _ as usize },
+        ::std::mem::offset_of!(_cef_settings_t, size),
         0usize,
         concat!(
             "Offset of field: ",
@@ -832,7 +821,7 @@ fn bindgen_test_layout__cef_settings_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_settings_t>())).no_sandbox as *const _ as usize },
+        ::std::mem::offset_of!(_cef_settings_t, no_sandbox),
         8usize,
         concat!(
             "Offset of field: ",
@@ -842,9 +831,7 @@ fn bindgen_test_layout__cef_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_settings_t>())).browser_subprocess_path as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_settings_t, browser_subprocess_path),
         16usize,
         concat!(
             "Offset of field: ",
@@ -854,9 +841,7 @@ fn bindgen_test_layout__cef_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_settings_t>())).framework_dir_path as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_settings_t, framework_dir_path),
         40usize,
         concat!(
             "Offset of field: ",
@@ -866,9 +851,7 @@ fn bindgen_test_layout__cef_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_settings_t>())).main_bundle_path as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_settings_t, main_bundle_path),
         64usize,
         concat!(
             "Offset of field: ",
@@ -878,10 +861,7 @@ fn bindgen_test_layout__cef_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_settings_t>())).multi_threaded_message_loop as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_settings_t, multi_threaded_message_loop),
         88usize,
         concat!(
             "Offset of field: ",
@@ -891,9 +871,7 @@ fn bindgen_test_layout__cef_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_settings_t>())).external_message_pump as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_settings_t, external_message_pump),
         92usize,
         concat!(
             "Offset of field: ",
@@ -903,10 +881,7 @@ fn bindgen_test_layout__cef_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_settings_t>())).windowless_rendering_enabled as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_settings_t, windowless_rendering_enabled),
         96usize,
         concat!(
             "Offset of field: ",
@@ -916,10 +891,7 @@ fn bindgen_test_layout__cef_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_settings_t>())).command_line_args_disabled as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_settings_t, command_line_args_disabled),
         100usize,
         concat!(
             "Offset of field: ",
@@ -929,7 +901,7 @@ fn bindgen_test_layout__cef_settings_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_settings_t>())).cache_path as *const _ as usize },
+        ::std::mem::offset_of!(_cef_settings_t, cache_path),
         104usize,
         concat!(
             "Offset of field: ",
@@ -939,7 +911,7 @@ fn bindgen_test_layout__cef_settings_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_settings_t>())).root_cache_path as *const _ as usize },
+        ::std::mem::offset_of!(_cef_settings_t, root_cache_path),
         128usize,
         concat!(
             "Offset of field: ",
@@ -949,7 +921,7 @@ fn bindgen_test_layout__cef_settings_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_settings_t>())).user_data_path as *const _ as usize },
+        ::std::mem::offset_of!(_cef_settings_t, user_data_path),
         152usize,
         concat!(
             "Offset of field: ",
@@ -959,9 +931,7 @@ fn bindgen_test_layout__cef_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_settings_t>())).persist_session_cookies as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_settings_t, persist_session_cookies),
         176usize,
         concat!(
             "Offset of field: ",
@@ -971,10 +941,7 @@ fn bindgen_test_layout__cef_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_settings_t>())).persist_user_preferences as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_settings_t, persist_user_preferences),
         180usize,
         concat!(
             "Offset of field: ",
@@ -984,7 +951,7 @@ fn bindgen_test_layout__cef_settings_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_settings_t>())).user_agent as *const _ as usize },
+        ::std::mem::offset_of!(_cef_settings_t, user_agent),
         184usize,
         concat!(
             "Offset of field: ",
@@ -994,7 +961,7 @@ fn bindgen_test_layout__cef_settings_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_settings_t>())).product_version as *const _ as usize },
+        ::std::mem::offset_of!(_cef_settings_t, product_version),
         208usize,
         concat!(
             "Offset of field: ",
@@ -1004,7 +971,7 @@ fn bindgen_test_layout__cef_settings_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_settings_t>())).locale as *const _ as usize },
+        ::std::mem::offset_of!(_cef_settings_t, locale),
         232usize,
         concat!(
             "Offset of field: ",
@@ -1014,7 +981,7 @@ fn bindgen_test_layout__cef_settings_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_settings_t>())).log_file as *const _ as usize },
+        ::std::mem::offset_of!(_cef_settings_t, log_file),
         256usize,
         concat!(
             "Offset of field: ",
@@ -1024,7 +991,7 @@ fn bindgen_test_layout__cef_settings_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_settings_t>())).log_severity as *const _ as usize },
+        ::std::mem::offset_of!(_cef_settings_t, log_severity),
         280usize,
         concat!(
             "Offset of field: ",
@@ -1034,9 +1001,7 @@ fn bindgen_test_layout__cef_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_settings_t>())).javascript_flags as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_settings_t, javascript_flags),
         288usize,
         concat!(
             "Offset of field: ",
@@ -1046,9 +1011,7 @@ fn bindgen_test_layout__cef_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_settings_t>())).resources_dir_path as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_settings_t, resources_dir_path),
         312usize,
         concat!(
             "Offset of field: ",
@@ -1058,9 +1021,7 @@ fn bindgen_test_layout__cef_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_settings_t>())).locales_dir_path as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_settings_t, locales_dir_path),
         336usize,
         concat!(
             "Offset of field: ",
@@ -1070,9 +1031,7 @@ fn bindgen_test_layout__cef_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_settings_t>())).pack_loading_disabled as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_settings_t, pack_loading_disabled),
         360usize,
         concat!(
             "Offset of field: ",
@@ -1082,9 +1041,7 @@ fn bindgen_test_layout__cef_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_settings_t>())).remote_debugging_port as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_settings_t, remote_debugging_port),
         364usize,
         concat!(
             "Offset of field: ",
@@ -1094,10 +1051,7 @@ fn bindgen_test_layout__cef_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_settings_t>())).uncaught_exception_stack_size as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_settings_t, uncaught_exception_stack_size),
         368usize,
         concat!(
             "Offset of field: ",
@@ -1107,10 +1061,7 @@ fn bindgen_test_layout__cef_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_settings_t>())).ignore_certificate_errors as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_settings_t, ignore_certificate_errors),
         372usize,
         concat!(
             "Offset of field: ",
@@ -1120,9 +1071,7 @@ fn bindgen_test_layout__cef_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_settings_t>())).background_color as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_settings_t, background_color),
         376usize,
         concat!(
             "Offset of field: ",
@@ -1132,9 +1081,7 @@ fn bindgen_test_layout__cef_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_settings_t>())).accept_language_list as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_settings_t, accept_language_list),
         384usize,
         concat!(
             "Offset of field: ",
@@ -1144,10 +1091,7 @@ fn bindgen_test_layout__cef_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_settings_t>())).application_client_id_for_file_scanning
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_settings_t, application_client_id_for_file_scanning),
         408usize,
         concat!(
             "Offset of field: ",
@@ -1181,9 +1125,7 @@ fn bindgen_test_layout__cef_request_context_settings_t() {
         concat!("Alignment of ", stringify!(_cef_request_context_settings_t))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_request_context_settings_t>())).size as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_request_context_settings_t, size),
         0usize,
         concat!(
             "Offset of field: ",
@@ -1193,10 +1135,7 @@ fn bindgen_test_layout__cef_request_context_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_request_context_settings_t>())).cache_path as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_request_context_settings_t, cache_path),
         8usize,
         concat!(
             "Offset of field: ",
@@ -1206,10 +1145,7 @@ fn bindgen_test_layout__cef_request_context_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_request_context_settings_t>())).persist_session_cookies
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_request_context_settings_t, persist_session_cookies),
         32usize,
         concat!(
             "Offset of field: ",
@@ -1219,10 +1155,7 @@ fn bindgen_test_layout__cef_request_context_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_request_context_settings_t>())).persist_user_preferences
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_request_context_settings_t, persist_user_preferences),
         36usize,
         concat!(
             "Offset of field: ",
@@ -1232,10 +1165,7 @@ fn bindgen_test_layout__cef_request_context_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_request_context_settings_t>())).ignore_certificate_errors
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_request_context_settings_t, ignore_certificate_errors),
         40usize,
         concat!(
             "Offset of field: ",
@@ -1245,10 +1175,7 @@ fn bindgen_test_layout__cef_request_context_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_request_context_settings_t>())).accept_language_list
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_request_context_settings_t, accept_language_list),
         48usize,
         concat!(
             "Offset of field: ",
@@ -1308,7 +1235,7 @@ fn bindgen_test_layout__cef_browser_settings_t() {
         concat!("Alignment of ", stringify!(_cef_browser_settings_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_browser_settings_t>())).size as *const _ as usize },
+        ::std::mem::offset_of!(_cef_browser_settings_t, size),
         0usize,
         concat!(
             "Offset of field: ",
@@ -1318,10 +1245,7 @@ fn bindgen_test_layout__cef_browser_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_settings_t>())).windowless_frame_rate as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_settings_t, windowless_frame_rate),
         8usize,
         concat!(
             "Offset of field: ",
@@ -1331,10 +1255,7 @@ fn bindgen_test_layout__cef_browser_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_settings_t>())).standard_font_family as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_settings_t, standard_font_family),
         16usize,
         concat!(
             "Offset of field: ",
@@ -1344,10 +1265,7 @@ fn bindgen_test_layout__cef_browser_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_settings_t>())).fixed_font_family as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_settings_t, fixed_font_family),
         40usize,
         concat!(
             "Offset of field: ",
@@ -1357,10 +1275,7 @@ fn bindgen_test_layout__cef_browser_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_settings_t>())).serif_font_family as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_settings_t, serif_font_family),
         64usize,
         concat!(
             "Offset of field: ",
@@ -1370,10 +1285,7 @@ fn bindgen_test_layout__cef_browser_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_settings_t>())).sans_serif_font_family as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_settings_t, sans_serif_font_family),
         88usize,
         concat!(
             "Offset of field: ",
@@ -1383,10 +1295,7 @@ fn bindgen_test_layout__cef_browser_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_settings_t>())).cursive_font_family as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_settings_t, cursive_font_family),
         112usize,
         concat!(
             "Offset of field: ",
@@ -1396,10 +1305,7 @@ fn bindgen_test_layout__cef_browser_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_settings_t>())).fantasy_font_family as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_settings_t, fantasy_font_family),
         136usize,
         concat!(
             "Offset of field: ",
@@ -1409,10 +1315,7 @@ fn bindgen_test_layout__cef_browser_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_settings_t>())).default_font_size as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_settings_t, default_font_size),
         160usize,
         concat!(
             "Offset of field: ",
@@ -1422,10 +1325,7 @@ fn bindgen_test_layout__cef_browser_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_settings_t>())).default_fixed_font_size as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_settings_t, default_fixed_font_size),
         164usize,
         concat!(
             "Offset of field: ",
@@ -1435,10 +1335,7 @@ fn bindgen_test_layout__cef_browser_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_settings_t>())).minimum_font_size as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_settings_t, minimum_font_size),
         168usize,
         concat!(
             "Offset of field: ",
@@ -1448,10 +1345,7 @@ fn bindgen_test_layout__cef_browser_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_settings_t>())).minimum_logical_font_size
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_settings_t, minimum_logical_font_size),
         172usize,
         concat!(
             "Offset of field: ",
@@ -1461,10 +1355,7 @@ fn bindgen_test_layout__cef_browser_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_settings_t>())).default_encoding as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_settings_t, default_encoding),
         176usize,
         concat!(
             "Offset of field: ",
@@ -1474,9 +1365,7 @@ fn bindgen_test_layout__cef_browser_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_settings_t>())).remote_fonts as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_settings_t, remote_fonts),
         200usize,
         concat!(
             "Offset of field: ",
@@ -1486,9 +1375,7 @@ fn bindgen_test_layout__cef_browser_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_settings_t>())).javascript as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_settings_t, javascript),
         204usize,
         concat!(
             "Offset of field: ",
@@ -1498,10 +1385,7 @@ fn bindgen_test_layout__cef_browser_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_settings_t>())).javascript_close_windows as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_settings_t, javascript_close_windows),
         208usize,
         concat!(
             "Offset of field: ",
@@ -1511,10 +1395,7 @@ fn bindgen_test_layout__cef_browser_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_settings_t>())).javascript_access_clipboard
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_settings_t, javascript_access_clipboard),
         212usize,
         concat!(
             "Offset of field: ",
@@ -1524,10 +1405,7 @@ fn bindgen_test_layout__cef_browser_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_settings_t>())).javascript_dom_paste as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_settings_t, javascript_dom_paste),
         216usize,
         concat!(
             "Offset of field: ",
@@ -1537,7 +1415,7 @@ fn bindgen_test_layout__cef_browser_settings_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_browser_settings_t>())).plugins as *const _ as usize },
+        ::std::mem::offset_of!(_cef_browser_settings_t, plugins),
         220usize,
         concat!(
             "Offset of field: ",
@@ -1547,10 +1425,7 @@ fn bindgen_test_layout__cef_browser_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_settings_t>())).universal_access_from_file_urls
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_settings_t, universal_access_from_file_urls),
         224usize,
         concat!(
             "Offset of field: ",
@@ -1560,10 +1435,7 @@ fn bindgen_test_layout__cef_browser_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_settings_t>())).file_access_from_file_urls
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_settings_t, file_access_from_file_urls),
         228usize,
         concat!(
             "Offset of field: ",
@@ -1573,9 +1445,7 @@ fn bindgen_test_layout__cef_browser_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_settings_t>())).web_security as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_settings_t, web_security),
         232usize,
         concat!(
             "Offset of field: ",
@@ -1585,9 +1455,7 @@ fn bindgen_test_layout__cef_browser_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_settings_t>())).image_loading as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_settings_t, image_loading),
         236usize,
         concat!(
             "Offset of field: ",
@@ -1597,10 +1465,7 @@ fn bindgen_test_layout__cef_browser_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_settings_t>())).image_shrink_standalone_to_fit
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_settings_t, image_shrink_standalone_to_fit),
         240usize,
         concat!(
             "Offset of field: ",
@@ -1610,10 +1475,7 @@ fn bindgen_test_layout__cef_browser_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_settings_t>())).text_area_resize as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_settings_t, text_area_resize),
         244usize,
         concat!(
             "Offset of field: ",
@@ -1623,9 +1485,7 @@ fn bindgen_test_layout__cef_browser_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_settings_t>())).tab_to_links as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_settings_t, tab_to_links),
         248usize,
         concat!(
             "Offset of field: ",
@@ -1635,9 +1495,7 @@ fn bindgen_test_layout__cef_browser_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_settings_t>())).local_storage as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_settings_t, local_storage),
         252usize,
         concat!(
             "Offset of field: ",
@@ -1647,9 +1505,7 @@ fn bindgen_test_layout__cef_browser_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_settings_t>())).databases as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_settings_t, databases),
         256usize,
         concat!(
             "Offset of field: ",
@@ -1659,10 +1515,7 @@ fn bindgen_test_layout__cef_browser_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_settings_t>())).application_cache as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_settings_t, application_cache),
         260usize,
         concat!(
             "Offset of field: ",
@@ -1672,7 +1525,7 @@ fn bindgen_test_layout__cef_browser_settings_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_browser_settings_t>())).webgl as *const _ as usize },
+        ::std::mem::offset_of!(_cef_browser_settings_t, webgl),
         264usize,
         concat!(
             "Offset of field: ",
@@ -1682,10 +1535,7 @@ fn bindgen_test_layout__cef_browser_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_settings_t>())).background_color as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_settings_t, background_color),
         268usize,
         concat!(
             "Offset of field: ",
@@ -1695,10 +1545,7 @@ fn bindgen_test_layout__cef_browser_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_settings_t>())).accept_language_list as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_settings_t, accept_language_list),
         272usize,
         concat!(
             "Offset of field: ",
@@ -1742,7 +1589,7 @@ fn bindgen_test_layout__cef_urlparts_t() {
         concat!("Alignment of ", stringify!(_cef_urlparts_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_urlparts_t>())).spec as *const _ as usize },
+        ::std::mem::offset_of!(_cef_urlparts_t, spec),
         0usize,
         concat!(
             "Offset of field: ",
@@ -1752,7 +1599,7 @@ fn bindgen_test_layout__cef_urlparts_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_urlparts_t>())).scheme as *const _ as usize },
+        ::std::mem::offset_of!(_cef_urlparts_t, scheme),
         24usize,
         concat!(
             "Offset of field: ",
@@ -1762,7 +1609,7 @@ fn bindgen_test_layout__cef_urlparts_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_urlparts_t>())).username as *const _ as usize },
+        ::std::mem::offset_of!(_cef_urlparts_t, username),
         48usize,
         concat!(
             "Offset of field: ",
@@ -1772,7 +1619,7 @@ fn bindgen_test_layout__cef_urlparts_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_urlparts_t>())).password as *const _ as usize },
+        ::std::mem::offset_of!(_cef_urlparts_t, password),
         72usize,
         concat!(
             "Offset of field: ",
@@ -1782,7 +1629,7 @@ fn bindgen_test_layout__cef_urlparts_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_urlparts_t>())).host as *const _ as usize },
+        ::std::mem::offset_of!(_cef_urlparts_t, host),
         96usize,
         concat!(
             "Offset of field: ",
@@ -1792,7 +1639,7 @@ fn bindgen_test_layout__cef_urlparts_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_urlparts_t>())).port as *const _ as usize },
+        ::std::mem::offset_of!(_cef_urlparts_t, port),
         120usize,
         concat!(
             "Offset of field: ",
@@ -1802,7 +1649,7 @@ fn bindgen_test_layout__cef_urlparts_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_urlparts_t>())).origin as *const _ as usize },
+        ::std::mem::offset_of!(_cef_urlparts_t, origin),
         144usize,
         concat!(
             "Offset of field: ",
@@ -1812,7 +1659,7 @@ fn bindgen_test_layout__cef_urlparts_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_urlparts_t>())).path as *const _ as usize },
+        ::std::mem::offset_of!(_cef_urlparts_t, path),
         168usize,
         concat!(
             "Offset of field: ",
@@ -1822,7 +1669,7 @@ fn bindgen_test_layout__cef_urlparts_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_urlparts_t>())).query as *const _ as usize },
+        ::std::mem::offset_of!(_cef_urlparts_t, query),
         192usize,
         concat!(
             "Offset of field: ",
@@ -1832,7 +1679,7 @@ fn bindgen_test_layout__cef_urlparts_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_urlparts_t>())).fragment as *const _ as usize },
+        ::std::mem::offset_of!(_cef_urlparts_t, fragment),
         216usize,
         concat!(
             "Offset of field: ",
@@ -1885,7 +1732,7 @@ fn bindgen_test_layout__cef_cookie_t() {
         concat!("Alignment of ", stringify!(_cef_cookie_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_cookie_t>())).name as *const _ as usize },
+        ::std::mem::offset_of!(_cef_cookie_t, name),
         0usize,
         concat!(
             "Offset of field: ",
@@ -1895,7 +1742,7 @@ fn bindgen_test_layout__cef_cookie_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_cookie_t>())).value as *const _ as usize },
+        ::std::mem::offset_of!(_cef_cookie_t, value),
         24usize,
         concat!(
             "Offset of field: ",
@@ -1905,7 +1752,7 @@ fn bindgen_test_layout__cef_cookie_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_cookie_t>())).domain as *const _ as usize },
+        ::std::mem::offset_of!(_cef_cookie_t, domain),
         48usize,
         concat!(
             "Offset of field: ",
@@ -1915,7 +1762,7 @@ fn bindgen_test_layout__cef_cookie_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_cookie_t>())).path as *const _ as usize },
+        ::std::mem::offset_of!(_cef_cookie_t, path),
         72usize,
         concat!(
             "Offset of field: ",
@@ -1925,7 +1772,7 @@ fn bindgen_test_layout__cef_cookie_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_cookie_t>())).secure as *const _ as usize },
+        ::std::mem::offset_of!(_cef_cookie_t, secure),
         96usize,
         concat!(
             "Offset of field: ",
@@ -1935,7 +1782,7 @@ fn bindgen_test_layout__cef_cookie_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_cookie_t>())).httponly as *const _ as usize },
+        ::std::mem::offset_of!(_cef_cookie_t, httponly),
         100usize,
         concat!(
             "Offset of field: ",
@@ -1945,7 +1792,7 @@ fn bindgen_test_layout__cef_cookie_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_cookie_t>())).creation as *const _ as usize },
+        ::std::mem::offset_of!(_cef_cookie_t, creation),
         104usize,
         concat!(
             "Offset of field: ",
@@ -1955,7 +1802,7 @@ fn bindgen_test_layout__cef_cookie_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_cookie_t>())).last_access as *const _ as usize },
+        ::std::mem::offset_of!(_cef_cookie_t, last_access),
         136usize,
         concat!(
             "Offset of field: ",
@@ -1965,7 +1812,7 @@ fn bindgen_test_layout__cef_cookie_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_cookie_t>())).has_expires as *const _ as usize },
+        ::std::mem::offset_of!(_cef_cookie_t, has_expires),
         168usize,
         concat!(
             "Offset of field: ",
@@ -1975,7 +1822,7 @@ fn bindgen_test_layout__cef_cookie_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_cookie_t>())).expires as *const _ as usize },
+        ::std::mem::offset_of!(_cef_cookie_t, expires),
         172usize,
         concat!(
             "Offset of field: ",
@@ -1985,7 +1832,7 @@ fn bindgen_test_layout__cef_cookie_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_cookie_t>())).same_site as *const _ as usize },
+        ::std::mem::offset_of!(_cef_cookie_t, same_site),
         204usize,
         concat!(
             "Offset of field: ",
@@ -1995,7 +1842,7 @@ fn bindgen_test_layout__cef_cookie_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_cookie_t>())).priority as *const _ as usize },
+        ::std::mem::offset_of!(_cef_cookie_t, priority),
         208usize,
         concat!(
             "Offset of field: ",
@@ -2698,7 +2545,7 @@ fn bindgen_test_layout__cef_point_t() {
         concat!("Alignment of ", stringify!(_cef_point_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_point_t>())).x as *const _ as usize },
+        ::std::mem::offset_of!(_cef_point_t, x),
         0usize,
         concat!(
             "Offset of field: ",
@@ -2708,7 +2555,7 @@ fn bindgen_test_layout__cef_point_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_point_t>())).y as *const _ as usize },
+        ::std::mem::offset_of!(_cef_point_t, y),
         4usize,
         concat!(
             "Offset of field: ",
@@ -2740,7 +2587,7 @@ fn bindgen_test_layout__cef_rect_t() {
         concat!("Alignment of ", stringify!(_cef_rect_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_rect_t>())).x as *const _ as usize },
+        ::std::mem::offset_of!(_cef_rect_t, x),
         0usize,
         concat!(
             "Offset of field: ",
@@ -2750,7 +2597,7 @@ fn bindgen_test_layout__cef_rect_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_rect_t>())).y as *const _ as usize },
+        ::std::mem::offset_of!(_cef_rect_t, y),
         4usize,
         concat!(
             "Offset of field: ",
@@ -2760,7 +2607,7 @@ fn bindgen_test_layout__cef_rect_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_rect_t>())).width as *const _ as usize },
+        ::std::mem::offset_of!(_cef_rect_t, width),
         8usize,
         concat!(
             "Offset of field: ",
@@ -2770,7 +2617,7 @@ fn bindgen_test_layout__cef_rect_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_rect_t>())).height as *const _ as usize },
+        ::std::mem::offset_of!(_cef_rect_t, height),
         12usize,
         concat!(
             "Offset of field: ",
@@ -2800,7 +2647,7 @@ fn bindgen_test_layout__cef_size_t() {
         concat!("Alignment of ", stringify!(_cef_size_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_size_t>())).width as *const _ as usize },
+        ::std::mem::offset_of!(_cef_size_t, width),
         0usize,
         concat!(
             "Offset of field: ",
@@ -2810,7 +2657,7 @@ fn bindgen_test_layout__cef_size_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_size_t>())).height as *const _ as usize },
+        ::std::mem::offset_of!(_cef_size_t, height),
         4usize,
         concat!(
             "Offset of field: ",
@@ -2840,7 +2687,7 @@ fn bindgen_test_layout__cef_range_t() {
         concat!("Alignment of ", stringify!(_cef_range_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_range_t>())).from as *const _ as usize },
+        ::std::mem::offset_of!(_cef_range_t, from),
         0usize,
         concat!(
             "Offset of field: ",
@@ -2850,7 +2697,7 @@ fn bindgen_test_layout__cef_range_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_range_t>())).to as *const _ as usize },
+        ::std::mem::offset_of!(_cef_range_t, to),
         4usize,
         concat!(
             "Offset of field: ",
@@ -2882,7 +2729,7 @@ fn bindgen_test_layout__cef_insets_t() {
         concat!("Alignment of ", stringify!(_cef_insets_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_insets_t>())).top as *const _ as usize },
+        ::std::mem::offset_of!(_cef_insets_t, top),
         0usize,
         concat!(
             "Offset of field: ",
@@ -2892,7 +2739,7 @@ fn bindgen_test_layout__cef_insets_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_insets_t>())).left as *const _ as usize },
+        ::std::mem::offset_of!(_cef_insets_t, left),
         4usize,
         concat!(
             "Offset of field: ",
@@ -2902,7 +2749,7 @@ fn bindgen_test_layout__cef_insets_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_insets_t>())).bottom as *const _ as usize },
+        ::std::mem::offset_of!(_cef_insets_t, bottom),
         8usize,
         concat!(
             "Offset of field: ",
@@ -2912,7 +2759,7 @@ fn bindgen_test_layout__cef_insets_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_insets_t>())).right as *const _ as usize },
+        ::std::mem::offset_of!(_cef_insets_t, right),
         12usize,
         concat!(
             "Offset of field: ",
@@ -2942,7 +2789,7 @@ fn bindgen_test_layout__cef_draggable_region_t() {
         concat!("Alignment of ", stringify!(_cef_draggable_region_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_draggable_region_t>())).bounds as *const _ as usize },
+        ::std::mem::offset_of!(_cef_draggable_region_t, bounds),
         0usize,
         concat!(
             "Offset of field: ",
@@ -2952,9 +2799,7 @@ fn bindgen_test_layout__cef_draggable_region_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_draggable_region_t>())).draggable as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_draggable_region_t, draggable),
         16usize,
         concat!(
             "Offset of field: ",
@@ -3041,9 +2886,7 @@ fn bindgen_test_layout__cef_screen_info_t() {
         concat!("Alignment of ", stringify!(_cef_screen_info_t))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_screen_info_t>())).device_scale_factor as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_screen_info_t, device_scale_factor),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3053,7 +2896,7 @@ fn bindgen_test_layout__cef_screen_info_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_screen_info_t>())).depth as *const _ as usize },
+        ::std::mem::offset_of!(_cef_screen_info_t, depth),
         4usize,
         concat!(
             "Offset of field: ",
@@ -3063,9 +2906,7 @@ fn bindgen_test_layout__cef_screen_info_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_screen_info_t>())).depth_per_component as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_screen_info_t, depth_per_component),
         8usize,
         concat!(
             "Offset of field: ",
@@ -3075,9 +2916,7 @@ fn bindgen_test_layout__cef_screen_info_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_screen_info_t>())).is_monochrome as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_screen_info_t, is_monochrome),
         12usize,
         concat!(
             "Offset of field: ",
@@ -3087,7 +2926,7 @@ fn bindgen_test_layout__cef_screen_info_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_screen_info_t>())).rect as *const _ as usize },
+        ::std::mem::offset_of!(_cef_screen_info_t, rect),
         16usize,
         concat!(
             "Offset of field: ",
@@ -3097,9 +2936,7 @@ fn bindgen_test_layout__cef_screen_info_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_screen_info_t>())).available_rect as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_screen_info_t, available_rect),
         32usize,
         concat!(
             "Offset of field: ",
@@ -3166,7 +3003,7 @@ fn bindgen_test_layout__cef_mouse_event_t() {
         concat!("Alignment of ", stringify!(_cef_mouse_event_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_mouse_event_t>())).x as *const _ as usize },
+        ::std::mem::offset_of!(_cef_mouse_event_t, x),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3176,7 +3013,7 @@ fn bindgen_test_layout__cef_mouse_event_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_mouse_event_t>())).y as *const _ as usize },
+        ::std::mem::offset_of!(_cef_mouse_event_t, y),
         4usize,
         concat!(
             "Offset of field: ",
@@ -3186,7 +3023,7 @@ fn bindgen_test_layout__cef_mouse_event_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_mouse_event_t>())).modifiers as *const _ as usize },
+        ::std::mem::offset_of!(_cef_mouse_event_t, modifiers),
         8usize,
         concat!(
             "Offset of field: ",
@@ -3239,7 +3076,7 @@ fn bindgen_test_layout__cef_touch_event_t() {
         concat!("Alignment of ", stringify!(_cef_touch_event_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_touch_event_t>())).id as *const _ as usize },
+        ::std::mem::offset_of!(_cef_touch_event_t, id),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3249,7 +3086,7 @@ fn bindgen_test_layout__cef_touch_event_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_touch_event_t>())).x as *const _ as usize },
+        ::std::mem::offset_of!(_cef_touch_event_t, x),
         4usize,
         concat!(
             "Offset of field: ",
@@ -3259,7 +3096,7 @@ fn bindgen_test_layout__cef_touch_event_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_touch_event_t>())).y as *const _ as usize },
+        ::std::mem::offset_of!(_cef_touch_event_t, y),
         8usize,
         concat!(
             "Offset of field: ",
@@ -3269,7 +3106,7 @@ fn bindgen_test_layout__cef_touch_event_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_touch_event_t>())).radius_x as *const _ as usize },
+        ::std::mem::offset_of!(_cef_touch_event_t, radius_x),
         12usize,
         concat!(
             "Offset of field: ",
@@ -3279,7 +3116,7 @@ fn bindgen_test_layout__cef_touch_event_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_touch_event_t>())).radius_y as *const _ as usize },
+        ::std::mem::offset_of!(_cef_touch_event_t, radius_y),
         16usize,
         concat!(
             "Offset of field: ",
@@ -3289,9 +3126,7 @@ fn bindgen_test_layout__cef_touch_event_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_touch_event_t>())).rotation_angle as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_touch_event_t, rotation_angle),
         20usize,
         concat!(
             "Offset of field: ",
@@ -3301,7 +3136,7 @@ fn bindgen_test_layout__cef_touch_event_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_touch_event_t>())).pressure as *const _ as usize },
+        ::std::mem::offset_of!(_cef_touch_event_t, pressure),
         24usize,
         concat!(
             "Offset of field: ",
@@ -3311,7 +3146,7 @@ fn bindgen_test_layout__cef_touch_event_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_touch_event_t>())).type_ as *const _ as usize },
+        ::std::mem::offset_of!(_cef_touch_event_t, type_),
         28usize,
         concat!(
             "Offset of field: ",
@@ -3321,7 +3156,7 @@ fn bindgen_test_layout__cef_touch_event_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_touch_event_t>())).modifiers as *const _ as usize },
+        ::std::mem::offset_of!(_cef_touch_event_t, modifiers),
         32usize,
         concat!(
             "Offset of field: ",
@@ -3331,7 +3166,7 @@ fn bindgen_test_layout__cef_touch_event_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_touch_event_t>())).pointer_type as *const _ as usize },
+        ::std::mem::offset_of!(_cef_touch_event_t, pointer_type),
         36usize,
         concat!(
             "Offset of field: ",
@@ -3666,7 +3501,7 @@ fn bindgen_test_layout__cef_key_event_t() {
         concat!("Alignment of ", stringify!(_cef_key_event_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_key_event_t>())).type_ as *const _ as usize },
+        ::std::mem::offset_of!(_cef_key_event_t, type_),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3676,7 +3511,7 @@ fn bindgen_test_layout__cef_key_event_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_key_event_t>())).modifiers as *const _ as usize },
+        ::std::mem::offset_of!(_cef_key_event_t, modifiers),
         4usize,
         concat!(
             "Offset of field: ",
@@ -3686,9 +3521,7 @@ fn bindgen_test_layout__cef_key_event_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_key_event_t>())).windows_key_code as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_key_event_t, windows_key_code),
         8usize,
         concat!(
             "Offset of field: ",
@@ -3698,9 +3531,7 @@ fn bindgen_test_layout__cef_key_event_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_key_event_t>())).native_key_code as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_key_event_t, native_key_code),
         12usize,
         concat!(
             "Offset of field: ",
@@ -3710,7 +3541,7 @@ fn bindgen_test_layout__cef_key_event_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_key_event_t>())).is_system_key as *const _ as usize },
+        ::std::mem::offset_of!(_cef_key_event_t, is_system_key),
         16usize,
         concat!(
             "Offset of field: ",
@@ -3720,7 +3551,7 @@ fn bindgen_test_layout__cef_key_event_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_key_event_t>())).character as *const _ as usize },
+        ::std::mem::offset_of!(_cef_key_event_t, character),
         20usize,
         concat!(
             "Offset of field: ",
@@ -3730,9 +3561,7 @@ fn bindgen_test_layout__cef_key_event_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_key_event_t>())).unmodified_character as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_key_event_t, unmodified_character),
         22usize,
         concat!(
             "Offset of field: ",
@@ -3742,10 +3571,7 @@ fn bindgen_test_layout__cef_key_event_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_key_event_t>())).focus_on_editable_field as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_key_event_t, focus_on_editable_field),
         24usize,
         concat!(
             "Offset of field: ",
@@ -3821,7 +3647,7 @@ fn bindgen_test_layout__cef_popup_features_t() {
         concat!("Alignment of ", stringify!(_cef_popup_features_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_popup_features_t>())).x as *const _ as usize },
+        ::std::mem::offset_of!(_cef_popup_features_t, x),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3831,7 +3657,7 @@ fn bindgen_test_layout__cef_popup_features_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_popup_features_t>())).xSet as *const _ as usize },
+        ::std::mem::offset_of!(_cef_popup_features_t, xSet),
         4usize,
         concat!(
             "Offset of field: ",
@@ -3841,7 +3667,7 @@ fn bindgen_test_layout__cef_popup_features_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_popup_features_t>())).y as *const _ as usize },
+        ::std::mem::offset_of!(_cef_popup_features_t, y),
         8usize,
         concat!(
             "Offset of field: ",
@@ -3851,7 +3677,7 @@ fn bindgen_test_layout__cef_popup_features_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_popup_features_t>())).ySet as *const _ as usize },
+        ::std::mem::offset_of!(_cef_popup_features_t, ySet),
         12usize,
         concat!(
             "Offset of field: ",
@@ -3861,7 +3687,7 @@ fn bindgen_test_layout__cef_popup_features_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_popup_features_t>())).width as *const _ as usize },
+        ::std::mem::offset_of!(_cef_popup_features_t, width),
         16usize,
         concat!(
             "Offset of field: ",
@@ -3871,7 +3697,7 @@ fn bindgen_test_layout__cef_popup_features_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_popup_features_t>())).widthSet as *const _ as usize },
+        ::std::mem::offset_of!(_cef_popup_features_t, widthSet),
         20usize,
         concat!(
             "Offset of field: ",
@@ -3881,7 +3707,7 @@ fn bindgen_test_layout__cef_popup_features_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_popup_features_t>())).height as *const _ as usize },
+        ::std::mem::offset_of!(_cef_popup_features_t, height),
         24usize,
         concat!(
             "Offset of field: ",
@@ -3891,7 +3717,7 @@ fn bindgen_test_layout__cef_popup_features_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_popup_features_t>())).heightSet as *const _ as usize },
+        ::std::mem::offset_of!(_cef_popup_features_t, heightSet),
         28usize,
         concat!(
             "Offset of field: ",
@@ -3901,9 +3727,7 @@ fn bindgen_test_layout__cef_popup_features_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_popup_features_t>())).menuBarVisible as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_popup_features_t, menuBarVisible),
         32usize,
         concat!(
             "Offset of field: ",
@@ -3913,9 +3737,7 @@ fn bindgen_test_layout__cef_popup_features_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_popup_features_t>())).statusBarVisible as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_popup_features_t, statusBarVisible),
         36usize,
         concat!(
             "Offset of field: ",
@@ -3925,9 +3747,7 @@ fn bindgen_test_layout__cef_popup_features_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_popup_features_t>())).toolBarVisible as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_popup_features_t, toolBarVisible),
         40usize,
         concat!(
             "Offset of field: ",
@@ -3937,9 +3757,7 @@ fn bindgen_test_layout__cef_popup_features_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_popup_features_t>())).scrollbarsVisible as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_popup_features_t, scrollbarsVisible),
         44usize,
         concat!(
             "Offset of field: ",
@@ -4154,7 +3972,7 @@ fn bindgen_test_layout__cef_cursor_info_t() {
         concat!("Alignment of ", stringify!(_cef_cursor_info_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_cursor_info_t>())).hotspot as *const _ as usize },
+        ::std::mem::offset_of!(_cef_cursor_info_t, hotspot),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4164,9 +3982,7 @@ fn bindgen_test_layout__cef_cursor_info_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_cursor_info_t>())).image_scale_factor as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_cursor_info_t, image_scale_factor),
         8usize,
         concat!(
             "Offset of field: ",
@@ -4176,7 +3992,7 @@ fn bindgen_test_layout__cef_cursor_info_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_cursor_info_t>())).buffer as *const _ as usize },
+        ::std::mem::offset_of!(_cef_cursor_info_t, buffer),
         16usize,
         concat!(
             "Offset of field: ",
@@ -4186,7 +4002,7 @@ fn bindgen_test_layout__cef_cursor_info_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_cursor_info_t>())).size as *const _ as usize },
+        ::std::mem::offset_of!(_cef_cursor_info_t, size),
         24usize,
         concat!(
             "Offset of field: ",
@@ -4375,10 +4191,7 @@ fn bindgen_test_layout__cef_pdf_print_settings_t() {
         concat!("Alignment of ", stringify!(_cef_pdf_print_settings_t))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_pdf_print_settings_t>())).header_footer_title as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_pdf_print_settings_t, header_footer_title),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4388,10 +4201,7 @@ fn bindgen_test_layout__cef_pdf_print_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_pdf_print_settings_t>())).header_footer_url as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_pdf_print_settings_t, header_footer_url),
         24usize,
         concat!(
             "Offset of field: ",
@@ -4401,9 +4211,7 @@ fn bindgen_test_layout__cef_pdf_print_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_pdf_print_settings_t>())).page_width as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_pdf_print_settings_t, page_width),
         48usize,
         concat!(
             "Offset of field: ",
@@ -4413,9 +4221,7 @@ fn bindgen_test_layout__cef_pdf_print_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_pdf_print_settings_t>())).page_height as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_pdf_print_settings_t, page_height),
         52usize,
         concat!(
             "Offset of field: ",
@@ -4425,9 +4231,7 @@ fn bindgen_test_layout__cef_pdf_print_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_pdf_print_settings_t>())).scale_factor as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_pdf_print_settings_t, scale_factor),
         56usize,
         concat!(
             "Offset of field: ",
@@ -4437,9 +4241,7 @@ fn bindgen_test_layout__cef_pdf_print_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_pdf_print_settings_t>())).margin_top as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_pdf_print_settings_t, margin_top),
         60usize,
         concat!(
             "Offset of field: ",
@@ -4449,9 +4251,7 @@ fn bindgen_test_layout__cef_pdf_print_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_pdf_print_settings_t>())).margin_right as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_pdf_print_settings_t, margin_right),
         64usize,
         concat!(
             "Offset of field: ",
@@ -4461,9 +4261,7 @@ fn bindgen_test_layout__cef_pdf_print_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_pdf_print_settings_t>())).margin_bottom as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_pdf_print_settings_t, margin_bottom),
         68usize,
         concat!(
             "Offset of field: ",
@@ -4473,9 +4271,7 @@ fn bindgen_test_layout__cef_pdf_print_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_pdf_print_settings_t>())).margin_left as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_pdf_print_settings_t, margin_left),
         72usize,
         concat!(
             "Offset of field: ",
@@ -4485,9 +4281,7 @@ fn bindgen_test_layout__cef_pdf_print_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_pdf_print_settings_t>())).margin_type as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_pdf_print_settings_t, margin_type),
         76usize,
         concat!(
             "Offset of field: ",
@@ -4497,10 +4291,7 @@ fn bindgen_test_layout__cef_pdf_print_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_pdf_print_settings_t>())).header_footer_enabled as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_pdf_print_settings_t, header_footer_enabled),
         80usize,
         concat!(
             "Offset of field: ",
@@ -4510,10 +4301,7 @@ fn bindgen_test_layout__cef_pdf_print_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_pdf_print_settings_t>())).selection_only as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_pdf_print_settings_t, selection_only),
         84usize,
         concat!(
             "Offset of field: ",
@@ -4523,9 +4311,7 @@ fn bindgen_test_layout__cef_pdf_print_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_pdf_print_settings_t>())).landscape as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_pdf_print_settings_t, landscape),
         88usize,
         concat!(
             "Offset of field: ",
@@ -4535,10 +4321,7 @@ fn bindgen_test_layout__cef_pdf_print_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_pdf_print_settings_t>())).backgrounds_enabled as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_pdf_print_settings_t, backgrounds_enabled),
         92usize,
         concat!(
             "Offset of field: ",
@@ -4646,9 +4429,7 @@ fn bindgen_test_layout__cef_box_layout_settings_t() {
         concat!("Alignment of ", stringify!(_cef_box_layout_settings_t))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_box_layout_settings_t>())).horizontal as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_box_layout_settings_t, horizontal),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4658,10 +4439,7 @@ fn bindgen_test_layout__cef_box_layout_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_box_layout_settings_t>())).inside_border_horizontal_spacing
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_box_layout_settings_t, inside_border_horizontal_spacing),
         4usize,
         concat!(
             "Offset of field: ",
@@ -4671,10 +4449,7 @@ fn bindgen_test_layout__cef_box_layout_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_box_layout_settings_t>())).inside_border_vertical_spacing
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_box_layout_settings_t, inside_border_vertical_spacing),
         8usize,
         concat!(
             "Offset of field: ",
@@ -4684,10 +4459,7 @@ fn bindgen_test_layout__cef_box_layout_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_box_layout_settings_t>())).inside_border_insets as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_box_layout_settings_t, inside_border_insets),
         12usize,
         concat!(
             "Offset of field: ",
@@ -4697,10 +4469,7 @@ fn bindgen_test_layout__cef_box_layout_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_box_layout_settings_t>())).between_child_spacing as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_box_layout_settings_t, between_child_spacing),
         28usize,
         concat!(
             "Offset of field: ",
@@ -4710,10 +4479,7 @@ fn bindgen_test_layout__cef_box_layout_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_box_layout_settings_t>())).main_axis_alignment as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_box_layout_settings_t, main_axis_alignment),
         32usize,
         concat!(
             "Offset of field: ",
@@ -4723,10 +4489,7 @@ fn bindgen_test_layout__cef_box_layout_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_box_layout_settings_t>())).cross_axis_alignment as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_box_layout_settings_t, cross_axis_alignment),
         36usize,
         concat!(
             "Offset of field: ",
@@ -4736,10 +4499,7 @@ fn bindgen_test_layout__cef_box_layout_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_box_layout_settings_t>())).minimum_cross_axis_size
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_box_layout_settings_t, minimum_cross_axis_size),
         40usize,
         concat!(
             "Offset of field: ",
@@ -4749,9 +4509,7 @@ fn bindgen_test_layout__cef_box_layout_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_box_layout_settings_t>())).default_flex as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_box_layout_settings_t, default_flex),
         44usize,
         concat!(
             "Offset of field: ",
@@ -4931,9 +4689,7 @@ fn bindgen_test_layout__cef_composition_underline_t() {
         concat!("Alignment of ", stringify!(_cef_composition_underline_t))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_composition_underline_t>())).range as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_composition_underline_t, range),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4943,9 +4699,7 @@ fn bindgen_test_layout__cef_composition_underline_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_composition_underline_t>())).color as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_composition_underline_t, color),
         8usize,
         concat!(
             "Offset of field: ",
@@ -4955,10 +4709,7 @@ fn bindgen_test_layout__cef_composition_underline_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_composition_underline_t>())).background_color as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_composition_underline_t, background_color),
         12usize,
         concat!(
             "Offset of field: ",
@@ -4968,9 +4719,7 @@ fn bindgen_test_layout__cef_composition_underline_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_composition_underline_t>())).thick as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_composition_underline_t, thick),
         16usize,
         concat!(
             "Offset of field: ",
@@ -4980,9 +4729,7 @@ fn bindgen_test_layout__cef_composition_underline_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_composition_underline_t>())).style as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_composition_underline_t, style),
         20usize,
         concat!(
             "Offset of field: ",
@@ -5050,9 +4797,7 @@ fn bindgen_test_layout__cef_audio_parameters_t() {
         concat!("Alignment of ", stringify!(_cef_audio_parameters_t))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_audio_parameters_t>())).channel_layout as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_audio_parameters_t, channel_layout),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5062,9 +4807,7 @@ fn bindgen_test_layout__cef_audio_parameters_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_audio_parameters_t>())).sample_rate as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_audio_parameters_t, sample_rate),
         4usize,
         concat!(
             "Offset of field: ",
@@ -5074,10 +4817,7 @@ fn bindgen_test_layout__cef_audio_parameters_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_audio_parameters_t>())).frames_per_buffer as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_audio_parameters_t, frames_per_buffer),
         8usize,
         concat!(
             "Offset of field: ",
@@ -5141,10 +4881,7 @@ fn bindgen_test_layout__cef_media_sink_device_info_t() {
         concat!("Alignment of ", stringify!(_cef_media_sink_device_info_t))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_media_sink_device_info_t>())).ip_address as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_media_sink_device_info_t, ip_address),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5154,9 +4891,7 @@ fn bindgen_test_layout__cef_media_sink_device_info_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_media_sink_device_info_t>())).port as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_media_sink_device_info_t, port),
         24usize,
         concat!(
             "Offset of field: ",
@@ -5166,10 +4901,7 @@ fn bindgen_test_layout__cef_media_sink_device_info_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_media_sink_device_info_t>())).model_name as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_media_sink_device_info_t, model_name),
         32usize,
         concat!(
             "Offset of field: ",
@@ -5217,7 +4949,7 @@ fn bindgen_test_layout__cef_base_ref_counted_t() {
         concat!("Alignment of ", stringify!(_cef_base_ref_counted_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_base_ref_counted_t>())).size as *const _ as usize },
+        ::std::mem::offset_of!(_cef_base_ref_counted_t, size),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5227,7 +4959,7 @@ fn bindgen_test_layout__cef_base_ref_counted_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_base_ref_counted_t>())).add_ref as *const _ as usize },
+        ::std::mem::offset_of!(_cef_base_ref_counted_t, add_ref),
         8usize,
         concat!(
             "Offset of field: ",
@@ -5237,7 +4969,7 @@ fn bindgen_test_layout__cef_base_ref_counted_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_base_ref_counted_t>())).release as *const _ as usize },
+        ::std::mem::offset_of!(_cef_base_ref_counted_t, release),
         16usize,
         concat!(
             "Offset of field: ",
@@ -5247,9 +4979,7 @@ fn bindgen_test_layout__cef_base_ref_counted_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_base_ref_counted_t>())).has_one_ref as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_base_ref_counted_t, has_one_ref),
         24usize,
         concat!(
             "Offset of field: ",
@@ -5259,10 +4989,7 @@ fn bindgen_test_layout__cef_base_ref_counted_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_base_ref_counted_t>())).has_at_least_one_ref as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_base_ref_counted_t, has_at_least_one_ref),
         32usize,
         concat!(
             "Offset of field: ",
@@ -5292,7 +5019,7 @@ fn bindgen_test_layout__cef_base_scoped_t() {
         concat!("Alignment of ", stringify!(_cef_base_scoped_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_base_scoped_t>())).size as *const _ as usize },
+        ::std::mem::offset_of!(_cef_base_scoped_t, size),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5302,7 +5029,7 @@ fn bindgen_test_layout__cef_base_scoped_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_base_scoped_t>())).del as *const _ as usize },
+        ::std::mem::offset_of!(_cef_base_scoped_t, del),
         8usize,
         concat!(
             "Offset of field: ",
@@ -5418,7 +5145,7 @@ fn bindgen_test_layout__cef_value_t() {
         concat!("Alignment of ", stringify!(_cef_value_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_value_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_value_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5428,7 +5155,7 @@ fn bindgen_test_layout__cef_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_value_t>())).is_valid as *const _ as usize },
+        ::std::mem::offset_of!(_cef_value_t, is_valid),
         40usize,
         concat!(
             "Offset of field: ",
@@ -5438,7 +5165,7 @@ fn bindgen_test_layout__cef_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_value_t>())).is_owned as *const _ as usize },
+        ::std::mem::offset_of!(_cef_value_t, is_owned),
         48usize,
         concat!(
             "Offset of field: ",
@@ -5448,7 +5175,7 @@ fn bindgen_test_layout__cef_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_value_t>())).is_read_only as *const _ as usize },
+        ::std::mem::offset_of!(_cef_value_t, is_read_only),
         56usize,
         concat!(
             "Offset of field: ",
@@ -5458,7 +5185,7 @@ fn bindgen_test_layout__cef_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_value_t>())).is_same as *const _ as usize },
+        ::std::mem::offset_of!(_cef_value_t, is_same),
         64usize,
         concat!(
             "Offset of field: ",
@@ -5468,7 +5195,7 @@ fn bindgen_test_layout__cef_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_value_t>())).is_equal as *const _ as usize },
+        ::std::mem::offset_of!(_cef_value_t, is_equal),
         72usize,
         concat!(
             "Offset of field: ",
@@ -5478,7 +5205,7 @@ fn bindgen_test_layout__cef_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_value_t>())).copy as *const _ as usize },
+        ::std::mem::offset_of!(_cef_value_t, copy),
         80usize,
         concat!(
             "Offset of field: ",
@@ -5488,7 +5215,7 @@ fn bindgen_test_layout__cef_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_value_t>())).get_type as *const _ as usize },
+        ::std::mem::offset_of!(_cef_value_t, get_type),
         88usize,
         concat!(
             "Offset of field: ",
@@ -5498,7 +5225,7 @@ fn bindgen_test_layout__cef_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_value_t>())).get_bool as *const _ as usize },
+        ::std::mem::offset_of!(_cef_value_t, get_bool),
         96usize,
         concat!(
             "Offset of field: ",
@@ -5508,7 +5235,7 @@ fn bindgen_test_layout__cef_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_value_t>())).get_int as *const _ as usize },
+        ::std::mem::offset_of!(_cef_value_t, get_int),
         104usize,
         concat!(
             "Offset of field: ",
@@ -5518,7 +5245,7 @@ fn bindgen_test_layout__cef_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_value_t>())).get_double as *const _ as usize },
+        ::std::mem::offset_of!(_cef_value_t, get_double),
         112usize,
         concat!(
             "Offset of field: ",
@@ -5528,7 +5255,7 @@ fn bindgen_test_layout__cef_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_value_t>())).get_string as *const _ as usize },
+        ::std::mem::offset_of!(_cef_value_t, get_string),
         120usize,
         concat!(
             "Offset of field: ",
@@ -5538,7 +5265,7 @@ fn bindgen_test_layout__cef_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_value_t>())).get_binary as *const _ as usize },
+        ::std::mem::offset_of!(_cef_value_t, get_binary),
         128usize,
         concat!(
             "Offset of field: ",
@@ -5548,7 +5275,7 @@ fn bindgen_test_layout__cef_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_value_t>())).get_dictionary as *const _ as usize },
+        ::std::mem::offset_of!(_cef_value_t, get_dictionary),
         136usize,
         concat!(
             "Offset of field: ",
@@ -5558,7 +5285,7 @@ fn bindgen_test_layout__cef_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_value_t>())).get_list as *const _ as usize },
+        ::std::mem::offset_of!(_cef_value_t, get_list),
         144usize,
         concat!(
             "Offset of field: ",
@@ -5568,7 +5295,7 @@ fn bindgen_test_layout__cef_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_value_t>())).set_null as *const _ as usize },
+        ::std::mem::offset_of!(_cef_value_t, set_null),
         152usize,
         concat!(
             "Offset of field: ",
@@ -5578,7 +5305,7 @@ fn bindgen_test_layout__cef_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_value_t>())).set_bool as *const _ as usize },
+        ::std::mem::offset_of!(_cef_value_t, set_bool),
         160usize,
         concat!(
             "Offset of field: ",
@@ -5588,7 +5315,7 @@ fn bindgen_test_layout__cef_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_value_t>())).set_int as *const _ as usize },
+        ::std::mem::offset_of!(_cef_value_t, set_int),
         168usize,
         concat!(
             "Offset of field: ",
@@ -5598,7 +5325,7 @@ fn bindgen_test_layout__cef_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_value_t>())).set_double as *const _ as usize },
+        ::std::mem::offset_of!(_cef_value_t, set_double),
         176usize,
         concat!(
             "Offset of field: ",
@@ -5608,7 +5335,7 @@ fn bindgen_test_layout__cef_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_value_t>())).set_string as *const _ as usize },
+        ::std::mem::offset_of!(_cef_value_t, set_string),
         184usize,
         concat!(
             "Offset of field: ",
@@ -5618,7 +5345,7 @@ fn bindgen_test_layout__cef_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_value_t>())).set_binary as *const _ as usize },
+        ::std::mem::offset_of!(_cef_value_t, set_binary),
         192usize,
         concat!(
             "Offset of field: ",
@@ -5628,7 +5355,7 @@ fn bindgen_test_layout__cef_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_value_t>())).set_dictionary as *const _ as usize },
+        ::std::mem::offset_of!(_cef_value_t, set_dictionary),
         200usize,
         concat!(
             "Offset of field: ",
@@ -5638,7 +5365,7 @@ fn bindgen_test_layout__cef_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_value_t>())).set_list as *const _ as usize },
+        ::std::mem::offset_of!(_cef_value_t, set_list),
         208usize,
         concat!(
             "Offset of field: ",
@@ -5701,7 +5428,7 @@ fn bindgen_test_layout__cef_binary_value_t() {
         concat!("Alignment of ", stringify!(_cef_binary_value_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_binary_value_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_binary_value_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5711,7 +5438,7 @@ fn bindgen_test_layout__cef_binary_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_binary_value_t>())).is_valid as *const _ as usize },
+        ::std::mem::offset_of!(_cef_binary_value_t, is_valid),
         40usize,
         concat!(
             "Offset of field: ",
@@ -5721,7 +5448,7 @@ fn bindgen_test_layout__cef_binary_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_binary_value_t>())).is_owned as *const _ as usize },
+        ::std::mem::offset_of!(_cef_binary_value_t, is_owned),
         48usize,
         concat!(
             "Offset of field: ",
@@ -5731,7 +5458,7 @@ fn bindgen_test_layout__cef_binary_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_binary_value_t>())).is_same as *const _ as usize },
+        ::std::mem::offset_of!(_cef_binary_value_t, is_same),
         56usize,
         concat!(
             "Offset of field: ",
@@ -5741,7 +5468,7 @@ fn bindgen_test_layout__cef_binary_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_binary_value_t>())).is_equal as *const _ as usize },
+        ::std::mem::offset_of!(_cef_binary_value_t, is_equal),
         64usize,
         concat!(
             "Offset of field: ",
@@ -5751,7 +5478,7 @@ fn bindgen_test_layout__cef_binary_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_binary_value_t>())).copy as *const _ as usize },
+        ::std::mem::offset_of!(_cef_binary_value_t, copy),
         72usize,
         concat!(
             "Offset of field: ",
@@ -5761,7 +5488,7 @@ fn bindgen_test_layout__cef_binary_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_binary_value_t>())).get_size as *const _ as usize },
+        ::std::mem::offset_of!(_cef_binary_value_t, get_size),
         80usize,
         concat!(
             "Offset of field: ",
@@ -5771,7 +5498,7 @@ fn bindgen_test_layout__cef_binary_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_binary_value_t>())).get_data as *const _ as usize },
+        ::std::mem::offset_of!(_cef_binary_value_t, get_data),
         88usize,
         concat!(
             "Offset of field: ",
@@ -5969,7 +5696,7 @@ fn bindgen_test_layout__cef_dictionary_value_t() {
         concat!("Alignment of ", stringify!(_cef_dictionary_value_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_dictionary_value_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_dictionary_value_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5979,9 +5706,7 @@ fn bindgen_test_layout__cef_dictionary_value_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_dictionary_value_t>())).is_valid as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_dictionary_value_t, is_valid),
         40usize,
         concat!(
             "Offset of field: ",
@@ -5991,9 +5716,7 @@ fn bindgen_test_layout__cef_dictionary_value_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_dictionary_value_t>())).is_owned as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_dictionary_value_t, is_owned),
         48usize,
         concat!(
             "Offset of field: ",
@@ -6003,9 +5726,7 @@ fn bindgen_test_layout__cef_dictionary_value_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_dictionary_value_t>())).is_read_only as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_dictionary_value_t, is_read_only),
         56usize,
         concat!(
             "Offset of field: ",
@@ -6015,7 +5736,7 @@ fn bindgen_test_layout__cef_dictionary_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_dictionary_value_t>())).is_same as *const _ as usize },
+        ::std::mem::offset_of!(_cef_dictionary_value_t, is_same),
         64usize,
         concat!(
             "Offset of field: ",
@@ -6025,9 +5746,7 @@ fn bindgen_test_layout__cef_dictionary_value_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_dictionary_value_t>())).is_equal as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_dictionary_value_t, is_equal),
         72usize,
         concat!(
             "Offset of field: ",
@@ -6037,7 +5756,7 @@ fn bindgen_test_layout__cef_dictionary_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_dictionary_value_t>())).copy as *const _ as usize },
+        ::std::mem::offset_of!(_cef_dictionary_value_t, copy),
         80usize,
         concat!(
             "Offset of field: ",
@@ -6047,9 +5766,7 @@ fn bindgen_test_layout__cef_dictionary_value_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_dictionary_value_t>())).get_size as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_dictionary_value_t, get_size),
         88usize,
         concat!(
             "Offset of field: ",
@@ -6059,7 +5776,7 @@ fn bindgen_test_layout__cef_dictionary_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_dictionary_value_t>())).clear as *const _ as usize },
+        ::std::mem::offset_of!(_cef_dictionary_value_t, clear),
         96usize,
         concat!(
             "Offset of field: ",
@@ -6069,7 +5786,7 @@ fn bindgen_test_layout__cef_dictionary_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_dictionary_value_t>())).has_key as *const _ as usize },
+        ::std::mem::offset_of!(_cef_dictionary_value_t, has_key),
         104usize,
         concat!(
             "Offset of field: ",
@@ -6079,9 +5796,7 @@ fn bindgen_test_layout__cef_dictionary_value_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_dictionary_value_t>())).get_keys as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_dictionary_value_t, get_keys),
         112usize,
         concat!(
             "Offset of field: ",
@@ -6091,7 +5806,7 @@ fn bindgen_test_layout__cef_dictionary_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_dictionary_value_t>())).remove as *const _ as usize },
+        ::std::mem::offset_of!(_cef_dictionary_value_t, remove),
         120usize,
         concat!(
             "Offset of field: ",
@@ -6101,9 +5816,7 @@ fn bindgen_test_layout__cef_dictionary_value_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_dictionary_value_t>())).get_type as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_dictionary_value_t, get_type),
         128usize,
         concat!(
             "Offset of field: ",
@@ -6113,9 +5826,7 @@ fn bindgen_test_layout__cef_dictionary_value_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_dictionary_value_t>())).get_value as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_dictionary_value_t, get_value),
         136usize,
         concat!(
             "Offset of field: ",
@@ -6125,9 +5836,7 @@ fn bindgen_test_layout__cef_dictionary_value_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_dictionary_value_t>())).get_bool as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_dictionary_value_t, get_bool),
         144usize,
         concat!(
             "Offset of field: ",
@@ -6137,7 +5846,7 @@ fn bindgen_test_layout__cef_dictionary_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_dictionary_value_t>())).get_int as *const _ as usize },
+        ::std::mem::offset_of!(_cef_dictionary_value_t, get_int),
         152usize,
         concat!(
             "Offset of field: ",
@@ -6147,9 +5856,7 @@ fn bindgen_test_layout__cef_dictionary_value_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_dictionary_value_t>())).get_double as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_dictionary_value_t, get_double),
         160usize,
         concat!(
             "Offset of field: ",
@@ -6159,9 +5866,7 @@ fn bindgen_test_layout__cef_dictionary_value_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_dictionary_value_t>())).get_string as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_dictionary_value_t, get_string),
         168usize,
         concat!(
             "Offset of field: ",
@@ -6171,9 +5876,7 @@ fn bindgen_test_layout__cef_dictionary_value_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_dictionary_value_t>())).get_binary as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_dictionary_value_t, get_binary),
         176usize,
         concat!(
             "Offset of field: ",
@@ -6183,9 +5886,7 @@ fn bindgen_test_layout__cef_dictionary_value_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_dictionary_value_t>())).get_dictionary as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_dictionary_value_t, get_dictionary),
         184usize,
         concat!(
             "Offset of field: ",
@@ -6195,9 +5896,7 @@ fn bindgen_test_layout__cef_dictionary_value_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_dictionary_value_t>())).get_list as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_dictionary_value_t, get_list),
         192usize,
         concat!(
             "Offset of field: ",
@@ -6207,9 +5906,7 @@ fn bindgen_test_layout__cef_dictionary_value_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_dictionary_value_t>())).set_value as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_dictionary_value_t, set_value),
         200usize,
         concat!(
             "Offset of field: ",
@@ -6219,9 +5916,7 @@ fn bindgen_test_layout__cef_dictionary_value_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_dictionary_value_t>())).set_null as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_dictionary_value_t, set_null),
         208usize,
         concat!(
             "Offset of field: ",
@@ -6231,9 +5926,7 @@ fn bindgen_test_layout__cef_dictionary_value_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_dictionary_value_t>())).set_bool as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_dictionary_value_t, set_bool),
         216usize,
         concat!(
             "Offset of field: ",
@@ -6243,7 +5936,7 @@ fn bindgen_test_layout__cef_dictionary_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_dictionary_value_t>())).set_int as *const _ as usize },
+        ::std::mem::offset_of!(_cef_dictionary_value_t, set_int),
         224usize,
         concat!(
             "Offset of field: ",
@@ -6253,9 +5946,7 @@ fn bindgen_test_layout__cef_dictionary_value_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_dictionary_value_t>())).set_double as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_dictionary_value_t, set_double),
         232usize,
         concat!(
             "Offset of field: ",
@@ -6265,9 +5956,7 @@ fn bindgen_test_layout__cef_dictionary_value_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_dictionary_value_t>())).set_string as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_dictionary_value_t, set_string),
         240usize,
         concat!(
             "Offset of field: ",
@@ -6277,9 +5966,7 @@ fn bindgen_test_layout__cef_dictionary_value_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_dictionary_value_t>())).set_binary as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_dictionary_value_t, set_binary),
         248usize,
         concat!(
             "Offset of field: ",
@@ -6289,9 +5976,7 @@ fn bindgen_test_layout__cef_dictionary_value_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_dictionary_value_t>())).set_dictionary as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_dictionary_value_t, set_dictionary),
         256usize,
         concat!(
             "Offset of field: ",
@@ -6301,9 +5986,7 @@ fn bindgen_test_layout__cef_dictionary_value_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_dictionary_value_t>())).set_list as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_dictionary_value_t, set_list),
         264usize,
         concat!(
             "Offset of field: ",
@@ -6462,7 +6145,7 @@ fn bindgen_test_layout__cef_list_value_t() {
         concat!("Alignment of ", stringify!(_cef_list_value_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_list_value_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_list_value_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -6472,7 +6155,7 @@ fn bindgen_test_layout__cef_list_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_list_value_t>())).is_valid as *const _ as usize },
+        ::std::mem::offset_of!(_cef_list_value_t, is_valid),
         40usize,
         concat!(
             "Offset of field: ",
@@ -6482,7 +6165,7 @@ fn bindgen_test_layout__cef_list_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_list_value_t>())).is_owned as *const _ as usize },
+        ::std::mem::offset_of!(_cef_list_value_t, is_owned),
         48usize,
         concat!(
             "Offset of field: ",
@@ -6492,7 +6175,7 @@ fn bindgen_test_layout__cef_list_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_list_value_t>())).is_read_only as *const _ as usize },
+        ::std::mem::offset_of!(_cef_list_value_t, is_read_only),
         56usize,
         concat!(
             "Offset of field: ",
@@ -6502,7 +6185,7 @@ fn bindgen_test_layout__cef_list_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_list_value_t>())).is_same as *const _ as usize },
+        ::std::mem::offset_of!(_cef_list_value_t, is_same),
         64usize,
         concat!(
             "Offset of field: ",
@@ -6512,7 +6195,7 @@ fn bindgen_test_layout__cef_list_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_list_value_t>())).is_equal as *const _ as usize },
+        ::std::mem::offset_of!(_cef_list_value_t, is_equal),
         72usize,
         concat!(
             "Offset of field: ",
@@ -6522,7 +6205,7 @@ fn bindgen_test_layout__cef_list_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_list_value_t>())).copy as *const _ as usize },
+        ::std::mem::offset_of!(_cef_list_value_t, copy),
         80usize,
         concat!(
             "Offset of field: ",
@@ -6532,7 +6215,7 @@ fn bindgen_test_layout__cef_list_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_list_value_t>())).set_size as *const _ as usize },
+        ::std::mem::offset_of!(_cef_list_value_t, set_size),
         88usize,
         concat!(
             "Offset of field: ",
@@ -6542,7 +6225,7 @@ fn bindgen_test_layout__cef_list_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_list_value_t>())).get_size as *const _ as usize },
+        ::std::mem::offset_of!(_cef_list_value_t, get_size),
         96usize,
         concat!(
             "Offset of field: ",
@@ -6552,7 +6235,7 @@ fn bindgen_test_layout__cef_list_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_list_value_t>())).clear as *const _ as usize },
+        ::std::mem::offset_of!(_cef_list_value_t, clear),
         104usize,
         concat!(
             "Offset of field: ",
@@ -6562,7 +6245,7 @@ fn bindgen_test_layout__cef_list_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_list_value_t>())).remove as *const _ as usize },
+        ::std::mem::offset_of!(_cef_list_value_t, remove),
         112usize,
         concat!(
             "Offset of field: ",
@@ -6572,7 +6255,7 @@ fn bindgen_test_layout__cef_list_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_list_value_t>())).get_type as *const _ as usize },
+        ::std::mem::offset_of!(_cef_list_value_t, get_type),
         120usize,
         concat!(
             "Offset of field: ",
@@ -6582,7 +6265,7 @@ fn bindgen_test_layout__cef_list_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_list_value_t>())).get_value as *const _ as usize },
+        ::std::mem::offset_of!(_cef_list_value_t, get_value),
         128usize,
         concat!(
             "Offset of field: ",
@@ -6592,7 +6275,7 @@ fn bindgen_test_layout__cef_list_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_list_value_t>())).get_bool as *const _ as usize },
+        ::std::mem::offset_of!(_cef_list_value_t, get_bool),
         136usize,
         concat!(
             "Offset of field: ",
@@ -6602,7 +6285,7 @@ fn bindgen_test_layout__cef_list_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_list_value_t>())).get_int as *const _ as usize },
+        ::std::mem::offset_of!(_cef_list_value_t, get_int),
         144usize,
         concat!(
             "Offset of field: ",
@@ -6612,7 +6295,7 @@ fn bindgen_test_layout__cef_list_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_list_value_t>())).get_double as *const _ as usize },
+        ::std::mem::offset_of!(_cef_list_value_t, get_double),
         152usize,
         concat!(
             "Offset of field: ",
@@ -6622,7 +6305,7 @@ fn bindgen_test_layout__cef_list_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_list_value_t>())).get_string as *const _ as usize },
+        ::std::mem::offset_of!(_cef_list_value_t, get_string),
         160usize,
         concat!(
             "Offset of field: ",
@@ -6632,7 +6315,7 @@ fn bindgen_test_layout__cef_list_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_list_value_t>())).get_binary as *const _ as usize },
+        ::std::mem::offset_of!(_cef_list_value_t, get_binary),
         168usize,
         concat!(
             "Offset of field: ",
@@ -6642,9 +6325,7 @@ fn bindgen_test_layout__cef_list_value_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_list_value_t>())).get_dictionary as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_list_value_t, get_dictionary),
         176usize,
         concat!(
             "Offset of field: ",
@@ -6654,7 +6335,7 @@ fn bindgen_test_layout__cef_list_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_list_value_t>())).get_list as *const _ as usize },
+        ::std::mem::offset_of!(_cef_list_value_t, get_list),
         184usize,
         concat!(
             "Offset of field: ",
@@ -6664,7 +6345,7 @@ fn bindgen_test_layout__cef_list_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_list_value_t>())).set_value as *const _ as usize },
+        ::std::mem::offset_of!(_cef_list_value_t, set_value),
         192usize,
         concat!(
             "Offset of field: ",
@@ -6674,7 +6355,7 @@ fn bindgen_test_layout__cef_list_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_list_value_t>())).set_null as *const _ as usize },
+        ::std::mem::offset_of!(_cef_list_value_t, set_null),
         200usize,
         concat!(
             "Offset of field: ",
@@ -6684,7 +6365,7 @@ fn bindgen_test_layout__cef_list_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_list_value_t>())).set_bool as *const _ as usize },
+        ::std::mem::offset_of!(_cef_list_value_t, set_bool),
         208usize,
         concat!(
             "Offset of field: ",
@@ -6694,7 +6375,7 @@ fn bindgen_test_layout__cef_list_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_list_value_t>())).set_int as *const _ as usize },
+        ::std::mem::offset_of!(_cef_list_value_t, set_int),
         216usize,
         concat!(
             "Offset of field: ",
@@ -6704,7 +6385,7 @@ fn bindgen_test_layout__cef_list_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_list_value_t>())).set_double as *const _ as usize },
+        ::std::mem::offset_of!(_cef_list_value_t, set_double),
         224usize,
         concat!(
             "Offset of field: ",
@@ -6714,7 +6395,7 @@ fn bindgen_test_layout__cef_list_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_list_value_t>())).set_string as *const _ as usize },
+        ::std::mem::offset_of!(_cef_list_value_t, set_string),
         232usize,
         concat!(
             "Offset of field: ",
@@ -6724,7 +6405,7 @@ fn bindgen_test_layout__cef_list_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_list_value_t>())).set_binary as *const _ as usize },
+        ::std::mem::offset_of!(_cef_list_value_t, set_binary),
         240usize,
         concat!(
             "Offset of field: ",
@@ -6734,9 +6415,7 @@ fn bindgen_test_layout__cef_list_value_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_list_value_t>())).set_dictionary as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_list_value_t, set_dictionary),
         248usize,
         concat!(
             "Offset of field: ",
@@ -6746,7 +6425,7 @@ fn bindgen_test_layout__cef_list_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_list_value_t>())).set_list as *const _ as usize },
+        ::std::mem::offset_of!(_cef_list_value_t, set_list),
         256usize,
         concat!(
             "Offset of field: ",
@@ -6784,9 +6463,7 @@ fn bindgen_test_layout__cef_accessibility_handler_t() {
         concat!("Alignment of ", stringify!(_cef_accessibility_handler_t))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_accessibility_handler_t>())).base as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_accessibility_handler_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -6796,10 +6473,7 @@ fn bindgen_test_layout__cef_accessibility_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_accessibility_handler_t>())).on_accessibility_tree_change
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_accessibility_handler_t, on_accessibility_tree_change),
         40usize,
         concat!(
             "Offset of field: ",
@@ -6809,10 +6483,7 @@ fn bindgen_test_layout__cef_accessibility_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_accessibility_handler_t>()))
-                .on_accessibility_location_change as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_accessibility_handler_t, on_accessibility_location_change),
         48usize,
         concat!(
             "Offset of field: ",
@@ -6913,7 +6584,7 @@ fn bindgen_test_layout__cef_command_line_t() {
         concat!("Alignment of ", stringify!(_cef_command_line_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_command_line_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_command_line_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -6923,7 +6594,7 @@ fn bindgen_test_layout__cef_command_line_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_command_line_t>())).is_valid as *const _ as usize },
+        ::std::mem::offset_of!(_cef_command_line_t, is_valid),
         40usize,
         concat!(
             "Offset of field: ",
@@ -6933,9 +6604,7 @@ fn bindgen_test_layout__cef_command_line_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_command_line_t>())).is_read_only as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_command_line_t, is_read_only),
         48usize,
         concat!(
             "Offset of field: ",
@@ -6945,7 +6614,7 @@ fn bindgen_test_layout__cef_command_line_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_command_line_t>())).copy as *const _ as usize },
+        ::std::mem::offset_of!(_cef_command_line_t, copy),
         56usize,
         concat!(
             "Offset of field: ",
@@ -6955,9 +6624,7 @@ fn bindgen_test_layout__cef_command_line_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_command_line_t>())).init_from_argv as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_command_line_t, init_from_argv),
         64usize,
         concat!(
             "Offset of field: ",
@@ -6967,9 +6634,7 @@ fn bindgen_test_layout__cef_command_line_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_command_line_t>())).init_from_string as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_command_line_t, init_from_string),
         72usize,
         concat!(
             "Offset of field: ",
@@ -6979,7 +6644,7 @@ fn bindgen_test_layout__cef_command_line_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_command_line_t>())).reset as *const _ as usize },
+        ::std::mem::offset_of!(_cef_command_line_t, reset),
         80usize,
         concat!(
             "Offset of field: ",
@@ -6989,7 +6654,7 @@ fn bindgen_test_layout__cef_command_line_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_command_line_t>())).get_argv as *const _ as usize },
+        ::std::mem::offset_of!(_cef_command_line_t, get_argv),
         88usize,
         concat!(
             "Offset of field: ",
@@ -6999,10 +6664,7 @@ fn bindgen_test_layout__cef_command_line_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_command_line_t>())).get_command_line_string as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_command_line_t, get_command_line_string),
         96usize,
         concat!(
             "Offset of field: ",
@@ -7012,7 +6674,7 @@ fn bindgen_test_layout__cef_command_line_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_command_line_t>())).get_program as *const _ as usize },
+        ::std::mem::offset_of!(_cef_command_line_t, get_program),
         104usize,
         concat!(
             "Offset of field: ",
@@ -7022,7 +6684,7 @@ fn bindgen_test_layout__cef_command_line_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_command_line_t>())).set_program as *const _ as usize },
+        ::std::mem::offset_of!(_cef_command_line_t, set_program),
         112usize,
         concat!(
             "Offset of field: ",
@@ -7032,9 +6694,7 @@ fn bindgen_test_layout__cef_command_line_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_command_line_t>())).has_switches as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_command_line_t, has_switches),
         120usize,
         concat!(
             "Offset of field: ",
@@ -7044,7 +6704,7 @@ fn bindgen_test_layout__cef_command_line_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_command_line_t>())).has_switch as *const _ as usize },
+        ::std::mem::offset_of!(_cef_command_line_t, has_switch),
         128usize,
         concat!(
             "Offset of field: ",
@@ -7054,9 +6714,7 @@ fn bindgen_test_layout__cef_command_line_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_command_line_t>())).get_switch_value as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_command_line_t, get_switch_value),
         136usize,
         concat!(
             "Offset of field: ",
@@ -7066,9 +6724,7 @@ fn bindgen_test_layout__cef_command_line_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_command_line_t>())).get_switches as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_command_line_t, get_switches),
         144usize,
         concat!(
             "Offset of field: ",
@@ -7078,9 +6734,7 @@ fn bindgen_test_layout__cef_command_line_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_command_line_t>())).append_switch as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_command_line_t, append_switch),
         152usize,
         concat!(
             "Offset of field: ",
@@ -7090,10 +6744,7 @@ fn bindgen_test_layout__cef_command_line_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_command_line_t>())).append_switch_with_value as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_command_line_t, append_switch_with_value),
         160usize,
         concat!(
             "Offset of field: ",
@@ -7103,9 +6754,7 @@ fn bindgen_test_layout__cef_command_line_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_command_line_t>())).has_arguments as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_command_line_t, has_arguments),
         168usize,
         concat!(
             "Offset of field: ",
@@ -7115,9 +6764,7 @@ fn bindgen_test_layout__cef_command_line_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_command_line_t>())).get_arguments as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_command_line_t, get_arguments),
         176usize,
         concat!(
             "Offset of field: ",
@@ -7127,9 +6774,7 @@ fn bindgen_test_layout__cef_command_line_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_command_line_t>())).append_argument as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_command_line_t, append_argument),
         184usize,
         concat!(
             "Offset of field: ",
@@ -7139,9 +6784,7 @@ fn bindgen_test_layout__cef_command_line_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_command_line_t>())).prepend_wrapper as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_command_line_t, prepend_wrapper),
         192usize,
         concat!(
             "Offset of field: ",
@@ -7218,9 +6861,7 @@ fn bindgen_test_layout__cef_dev_tools_message_observer_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_dev_tools_message_observer_t>())).base as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_dev_tools_message_observer_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -7230,10 +6871,7 @@ fn bindgen_test_layout__cef_dev_tools_message_observer_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_dev_tools_message_observer_t>())).on_dev_tools_message
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_dev_tools_message_observer_t, on_dev_tools_message),
         40usize,
         concat!(
             "Offset of field: ",
@@ -7243,10 +6881,7 @@ fn bindgen_test_layout__cef_dev_tools_message_observer_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_dev_tools_message_observer_t>())).on_dev_tools_method_result
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_dev_tools_message_observer_t, on_dev_tools_method_result),
         48usize,
         concat!(
             "Offset of field: ",
@@ -7256,10 +6891,7 @@ fn bindgen_test_layout__cef_dev_tools_message_observer_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_dev_tools_message_observer_t>())).on_dev_tools_event
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_dev_tools_message_observer_t, on_dev_tools_event),
         56usize,
         concat!(
             "Offset of field: ",
@@ -7269,10 +6901,7 @@ fn bindgen_test_layout__cef_dev_tools_message_observer_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_dev_tools_message_observer_t>()))
-                .on_dev_tools_agent_attached as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_dev_tools_message_observer_t, on_dev_tools_agent_attached),
         64usize,
         concat!(
             "Offset of field: ",
@@ -7282,10 +6911,7 @@ fn bindgen_test_layout__cef_dev_tools_message_observer_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_dev_tools_message_observer_t>()))
-                .on_dev_tools_agent_detached as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_dev_tools_message_observer_t, on_dev_tools_agent_detached),
         72usize,
         concat!(
             "Offset of field: ",
@@ -7396,7 +7022,7 @@ fn bindgen_test_layout__cef_image_t() {
         concat!("Alignment of ", stringify!(_cef_image_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_image_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_image_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -7406,7 +7032,7 @@ fn bindgen_test_layout__cef_image_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_image_t>())).is_empty as *const _ as usize },
+        ::std::mem::offset_of!(_cef_image_t, is_empty),
         40usize,
         concat!(
             "Offset of field: ",
@@ -7416,7 +7042,7 @@ fn bindgen_test_layout__cef_image_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_image_t>())).is_same as *const _ as usize },
+        ::std::mem::offset_of!(_cef_image_t, is_same),
         48usize,
         concat!(
             "Offset of field: ",
@@ -7426,7 +7052,7 @@ fn bindgen_test_layout__cef_image_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_image_t>())).add_bitmap as *const _ as usize },
+        ::std::mem::offset_of!(_cef_image_t, add_bitmap),
         56usize,
         concat!(
             "Offset of field: ",
@@ -7436,7 +7062,7 @@ fn bindgen_test_layout__cef_image_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_image_t>())).add_png as *const _ as usize },
+        ::std::mem::offset_of!(_cef_image_t, add_png),
         64usize,
         concat!(
             "Offset of field: ",
@@ -7446,7 +7072,7 @@ fn bindgen_test_layout__cef_image_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_image_t>())).add_jpeg as *const _ as usize },
+        ::std::mem::offset_of!(_cef_image_t, add_jpeg),
         72usize,
         concat!(
             "Offset of field: ",
@@ -7456,7 +7082,7 @@ fn bindgen_test_layout__cef_image_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_image_t>())).get_width as *const _ as usize },
+        ::std::mem::offset_of!(_cef_image_t, get_width),
         80usize,
         concat!(
             "Offset of field: ",
@@ -7466,7 +7092,7 @@ fn bindgen_test_layout__cef_image_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_image_t>())).get_height as *const _ as usize },
+        ::std::mem::offset_of!(_cef_image_t, get_height),
         88usize,
         concat!(
             "Offset of field: ",
@@ -7476,7 +7102,7 @@ fn bindgen_test_layout__cef_image_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_image_t>())).has_representation as *const _ as usize },
+        ::std::mem::offset_of!(_cef_image_t, has_representation),
         96usize,
         concat!(
             "Offset of field: ",
@@ -7486,9 +7112,7 @@ fn bindgen_test_layout__cef_image_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_image_t>())).remove_representation as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_image_t, remove_representation),
         104usize,
         concat!(
             "Offset of field: ",
@@ -7498,9 +7122,7 @@ fn bindgen_test_layout__cef_image_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_image_t>())).get_representation_info as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_image_t, get_representation_info),
         112usize,
         concat!(
             "Offset of field: ",
@@ -7510,7 +7132,7 @@ fn bindgen_test_layout__cef_image_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_image_t>())).get_as_bitmap as *const _ as usize },
+        ::std::mem::offset_of!(_cef_image_t, get_as_bitmap),
         120usize,
         concat!(
             "Offset of field: ",
@@ -7520,7 +7142,7 @@ fn bindgen_test_layout__cef_image_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_image_t>())).get_as_png as *const _ as usize },
+        ::std::mem::offset_of!(_cef_image_t, get_as_png),
         128usize,
         concat!(
             "Offset of field: ",
@@ -7530,7 +7152,7 @@ fn bindgen_test_layout__cef_image_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_image_t>())).get_as_jpeg as *const _ as usize },
+        ::std::mem::offset_of!(_cef_image_t, get_as_jpeg),
         136usize,
         concat!(
             "Offset of field: ",
@@ -7584,7 +7206,7 @@ fn bindgen_test_layout__cef_read_handler_t() {
         concat!("Alignment of ", stringify!(_cef_read_handler_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_read_handler_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_read_handler_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -7594,7 +7216,7 @@ fn bindgen_test_layout__cef_read_handler_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_read_handler_t>())).read as *const _ as usize },
+        ::std::mem::offset_of!(_cef_read_handler_t, read),
         40usize,
         concat!(
             "Offset of field: ",
@@ -7604,7 +7226,7 @@ fn bindgen_test_layout__cef_read_handler_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_read_handler_t>())).seek as *const _ as usize },
+        ::std::mem::offset_of!(_cef_read_handler_t, seek),
         48usize,
         concat!(
             "Offset of field: ",
@@ -7614,7 +7236,7 @@ fn bindgen_test_layout__cef_read_handler_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_read_handler_t>())).tell as *const _ as usize },
+        ::std::mem::offset_of!(_cef_read_handler_t, tell),
         56usize,
         concat!(
             "Offset of field: ",
@@ -7624,7 +7246,7 @@ fn bindgen_test_layout__cef_read_handler_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_read_handler_t>())).eof as *const _ as usize },
+        ::std::mem::offset_of!(_cef_read_handler_t, eof),
         64usize,
         concat!(
             "Offset of field: ",
@@ -7634,7 +7256,7 @@ fn bindgen_test_layout__cef_read_handler_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_read_handler_t>())).may_block as *const _ as usize },
+        ::std::mem::offset_of!(_cef_read_handler_t, may_block),
         72usize,
         concat!(
             "Offset of field: ",
@@ -7686,7 +7308,7 @@ fn bindgen_test_layout__cef_stream_reader_t() {
         concat!("Alignment of ", stringify!(_cef_stream_reader_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_stream_reader_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_stream_reader_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -7696,7 +7318,7 @@ fn bindgen_test_layout__cef_stream_reader_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_stream_reader_t>())).read as *const _ as usize },
+        ::std::mem::offset_of!(_cef_stream_reader_t, read),
         40usize,
         concat!(
             "Offset of field: ",
@@ -7706,7 +7328,7 @@ fn bindgen_test_layout__cef_stream_reader_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_stream_reader_t>())).seek as *const _ as usize },
+        ::std::mem::offset_of!(_cef_stream_reader_t, seek),
         48usize,
         concat!(
             "Offset of field: ",
@@ -7716,7 +7338,7 @@ fn bindgen_test_layout__cef_stream_reader_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_stream_reader_t>())).tell as *const _ as usize },
+        ::std::mem::offset_of!(_cef_stream_reader_t, tell),
         56usize,
         concat!(
             "Offset of field: ",
@@ -7726,7 +7348,7 @@ fn bindgen_test_layout__cef_stream_reader_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_stream_reader_t>())).eof as *const _ as usize },
+        ::std::mem::offset_of!(_cef_stream_reader_t, eof),
         64usize,
         concat!(
             "Offset of field: ",
@@ -7736,7 +7358,7 @@ fn bindgen_test_layout__cef_stream_reader_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_stream_reader_t>())).may_block as *const _ as usize },
+        ::std::mem::offset_of!(_cef_stream_reader_t, may_block),
         72usize,
         concat!(
             "Offset of field: ",
@@ -7804,7 +7426,7 @@ fn bindgen_test_layout__cef_write_handler_t() {
         concat!("Alignment of ", stringify!(_cef_write_handler_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_write_handler_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_write_handler_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -7814,7 +7436,7 @@ fn bindgen_test_layout__cef_write_handler_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_write_handler_t>())).write as *const _ as usize },
+        ::std::mem::offset_of!(_cef_write_handler_t, write),
         40usize,
         concat!(
             "Offset of field: ",
@@ -7824,7 +7446,7 @@ fn bindgen_test_layout__cef_write_handler_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_write_handler_t>())).seek as *const _ as usize },
+        ::std::mem::offset_of!(_cef_write_handler_t, seek),
         48usize,
         concat!(
             "Offset of field: ",
@@ -7834,7 +7456,7 @@ fn bindgen_test_layout__cef_write_handler_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_write_handler_t>())).tell as *const _ as usize },
+        ::std::mem::offset_of!(_cef_write_handler_t, tell),
         56usize,
         concat!(
             "Offset of field: ",
@@ -7844,7 +7466,7 @@ fn bindgen_test_layout__cef_write_handler_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_write_handler_t>())).flush as *const _ as usize },
+        ::std::mem::offset_of!(_cef_write_handler_t, flush),
         64usize,
         concat!(
             "Offset of field: ",
@@ -7854,7 +7476,7 @@ fn bindgen_test_layout__cef_write_handler_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_write_handler_t>())).may_block as *const _ as usize },
+        ::std::mem::offset_of!(_cef_write_handler_t, may_block),
         72usize,
         concat!(
             "Offset of field: ",
@@ -7906,7 +7528,7 @@ fn bindgen_test_layout__cef_stream_writer_t() {
         concat!("Alignment of ", stringify!(_cef_stream_writer_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_stream_writer_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_stream_writer_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -7916,7 +7538,7 @@ fn bindgen_test_layout__cef_stream_writer_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_stream_writer_t>())).write as *const _ as usize },
+        ::std::mem::offset_of!(_cef_stream_writer_t, write),
         40usize,
         concat!(
             "Offset of field: ",
@@ -7926,7 +7548,7 @@ fn bindgen_test_layout__cef_stream_writer_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_stream_writer_t>())).seek as *const _ as usize },
+        ::std::mem::offset_of!(_cef_stream_writer_t, seek),
         48usize,
         concat!(
             "Offset of field: ",
@@ -7936,7 +7558,7 @@ fn bindgen_test_layout__cef_stream_writer_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_stream_writer_t>())).tell as *const _ as usize },
+        ::std::mem::offset_of!(_cef_stream_writer_t, tell),
         56usize,
         concat!(
             "Offset of field: ",
@@ -7946,7 +7568,7 @@ fn bindgen_test_layout__cef_stream_writer_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_stream_writer_t>())).flush as *const _ as usize },
+        ::std::mem::offset_of!(_cef_stream_writer_t, flush),
         64usize,
         concat!(
             "Offset of field: ",
@@ -7956,7 +7578,7 @@ fn bindgen_test_layout__cef_stream_writer_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_stream_writer_t>())).may_block as *const _ as usize },
+        ::std::mem::offset_of!(_cef_stream_writer_t, may_block),
         72usize,
         concat!(
             "Offset of field: ",
@@ -8078,7 +7700,7 @@ fn bindgen_test_layout__cef_drag_data_t() {
         concat!("Alignment of ", stringify!(_cef_drag_data_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_drag_data_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_drag_data_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -8088,7 +7710,7 @@ fn bindgen_test_layout__cef_drag_data_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_drag_data_t>())).clone as *const _ as usize },
+        ::std::mem::offset_of!(_cef_drag_data_t, clone),
         40usize,
         concat!(
             "Offset of field: ",
@@ -8098,7 +7720,7 @@ fn bindgen_test_layout__cef_drag_data_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_drag_data_t>())).is_read_only as *const _ as usize },
+        ::std::mem::offset_of!(_cef_drag_data_t, is_read_only),
         48usize,
         concat!(
             "Offset of field: ",
@@ -8108,7 +7730,7 @@ fn bindgen_test_layout__cef_drag_data_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_drag_data_t>())).is_link as *const _ as usize },
+        ::std::mem::offset_of!(_cef_drag_data_t, is_link),
         56usize,
         concat!(
             "Offset of field: ",
@@ -8118,7 +7740,7 @@ fn bindgen_test_layout__cef_drag_data_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_drag_data_t>())).is_fragment as *const _ as usize },
+        ::std::mem::offset_of!(_cef_drag_data_t, is_fragment),
         64usize,
         concat!(
             "Offset of field: ",
@@ -8128,7 +7750,7 @@ fn bindgen_test_layout__cef_drag_data_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_drag_data_t>())).is_file as *const _ as usize },
+        ::std::mem::offset_of!(_cef_drag_data_t, is_file),
         72usize,
         concat!(
             "Offset of field: ",
@@ -8138,7 +7760,7 @@ fn bindgen_test_layout__cef_drag_data_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_drag_data_t>())).get_link_url as *const _ as usize },
+        ::std::mem::offset_of!(_cef_drag_data_t, get_link_url),
         80usize,
         concat!(
             "Offset of field: ",
@@ -8148,7 +7770,7 @@ fn bindgen_test_layout__cef_drag_data_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_drag_data_t>())).get_link_title as *const _ as usize },
+        ::std::mem::offset_of!(_cef_drag_data_t, get_link_title),
         88usize,
         concat!(
             "Offset of field: ",
@@ -8158,9 +7780,7 @@ fn bindgen_test_layout__cef_drag_data_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_drag_data_t>())).get_link_metadata as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_drag_data_t, get_link_metadata),
         96usize,
         concat!(
             "Offset of field: ",
@@ -8170,9 +7790,7 @@ fn bindgen_test_layout__cef_drag_data_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_drag_data_t>())).get_fragment_text as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_drag_data_t, get_fragment_text),
         104usize,
         concat!(
             "Offset of field: ",
@@ -8182,9 +7800,7 @@ fn bindgen_test_layout__cef_drag_data_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_drag_data_t>())).get_fragment_html as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_drag_data_t, get_fragment_html),
         112usize,
         concat!(
             "Offset of field: ",
@@ -8194,9 +7810,7 @@ fn bindgen_test_layout__cef_drag_data_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_drag_data_t>())).get_fragment_base_url as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_drag_data_t, get_fragment_base_url),
         120usize,
         concat!(
             "Offset of field: ",
@@ -8206,7 +7820,7 @@ fn bindgen_test_layout__cef_drag_data_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_drag_data_t>())).get_file_name as *const _ as usize },
+        ::std::mem::offset_of!(_cef_drag_data_t, get_file_name),
         128usize,
         concat!(
             "Offset of field: ",
@@ -8216,9 +7830,7 @@ fn bindgen_test_layout__cef_drag_data_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_drag_data_t>())).get_file_contents as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_drag_data_t, get_file_contents),
         136usize,
         concat!(
             "Offset of field: ",
@@ -8228,7 +7840,7 @@ fn bindgen_test_layout__cef_drag_data_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_drag_data_t>())).get_file_names as *const _ as usize },
+        ::std::mem::offset_of!(_cef_drag_data_t, get_file_names),
         144usize,
         concat!(
             "Offset of field: ",
@@ -8238,7 +7850,7 @@ fn bindgen_test_layout__cef_drag_data_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_drag_data_t>())).set_link_url as *const _ as usize },
+        ::std::mem::offset_of!(_cef_drag_data_t, set_link_url),
         152usize,
         concat!(
             "Offset of field: ",
@@ -8248,7 +7860,7 @@ fn bindgen_test_layout__cef_drag_data_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_drag_data_t>())).set_link_title as *const _ as usize },
+        ::std::mem::offset_of!(_cef_drag_data_t, set_link_title),
         160usize,
         concat!(
             "Offset of field: ",
@@ -8258,9 +7870,7 @@ fn bindgen_test_layout__cef_drag_data_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_drag_data_t>())).set_link_metadata as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_drag_data_t, set_link_metadata),
         168usize,
         concat!(
             "Offset of field: ",
@@ -8270,9 +7880,7 @@ fn bindgen_test_layout__cef_drag_data_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_drag_data_t>())).set_fragment_text as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_drag_data_t, set_fragment_text),
         176usize,
         concat!(
             "Offset of field: ",
@@ -8282,9 +7890,7 @@ fn bindgen_test_layout__cef_drag_data_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_drag_data_t>())).set_fragment_html as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_drag_data_t, set_fragment_html),
         184usize,
         concat!(
             "Offset of field: ",
@@ -8294,9 +7900,7 @@ fn bindgen_test_layout__cef_drag_data_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_drag_data_t>())).set_fragment_base_url as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_drag_data_t, set_fragment_base_url),
         192usize,
         concat!(
             "Offset of field: ",
@@ -8306,9 +7910,7 @@ fn bindgen_test_layout__cef_drag_data_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_drag_data_t>())).reset_file_contents as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_drag_data_t, reset_file_contents),
         200usize,
         concat!(
             "Offset of field: ",
@@ -8318,7 +7920,7 @@ fn bindgen_test_layout__cef_drag_data_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_drag_data_t>())).add_file as *const _ as usize },
+        ::std::mem::offset_of!(_cef_drag_data_t, add_file),
         208usize,
         concat!(
             "Offset of field: ",
@@ -8328,7 +7930,7 @@ fn bindgen_test_layout__cef_drag_data_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_drag_data_t>())).get_image as *const _ as usize },
+        ::std::mem::offset_of!(_cef_drag_data_t, get_image),
         216usize,
         concat!(
             "Offset of field: ",
@@ -8338,9 +7940,7 @@ fn bindgen_test_layout__cef_drag_data_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_drag_data_t>())).get_image_hotspot as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_drag_data_t, get_image_hotspot),
         224usize,
         concat!(
             "Offset of field: ",
@@ -8350,7 +7950,7 @@ fn bindgen_test_layout__cef_drag_data_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_drag_data_t>())).has_image as *const _ as usize },
+        ::std::mem::offset_of!(_cef_drag_data_t, has_image),
         232usize,
         concat!(
             "Offset of field: ",
@@ -8385,7 +7985,7 @@ fn bindgen_test_layout__cef_domvisitor_t() {
         concat!("Alignment of ", stringify!(_cef_domvisitor_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_domvisitor_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_domvisitor_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -8395,7 +7995,7 @@ fn bindgen_test_layout__cef_domvisitor_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_domvisitor_t>())).visit as *const _ as usize },
+        ::std::mem::offset_of!(_cef_domvisitor_t, visit),
         40usize,
         concat!(
             "Offset of field: ",
@@ -8472,7 +8072,7 @@ fn bindgen_test_layout__cef_domdocument_t() {
         concat!("Alignment of ", stringify!(_cef_domdocument_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_domdocument_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_domdocument_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -8482,7 +8082,7 @@ fn bindgen_test_layout__cef_domdocument_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_domdocument_t>())).get_type as *const _ as usize },
+        ::std::mem::offset_of!(_cef_domdocument_t, get_type),
         40usize,
         concat!(
             "Offset of field: ",
@@ -8492,7 +8092,7 @@ fn bindgen_test_layout__cef_domdocument_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_domdocument_t>())).get_document as *const _ as usize },
+        ::std::mem::offset_of!(_cef_domdocument_t, get_document),
         48usize,
         concat!(
             "Offset of field: ",
@@ -8502,7 +8102,7 @@ fn bindgen_test_layout__cef_domdocument_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_domdocument_t>())).get_body as *const _ as usize },
+        ::std::mem::offset_of!(_cef_domdocument_t, get_body),
         56usize,
         concat!(
             "Offset of field: ",
@@ -8512,7 +8112,7 @@ fn bindgen_test_layout__cef_domdocument_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_domdocument_t>())).get_head as *const _ as usize },
+        ::std::mem::offset_of!(_cef_domdocument_t, get_head),
         64usize,
         concat!(
             "Offset of field: ",
@@ -8522,7 +8122,7 @@ fn bindgen_test_layout__cef_domdocument_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_domdocument_t>())).get_title as *const _ as usize },
+        ::std::mem::offset_of!(_cef_domdocument_t, get_title),
         72usize,
         concat!(
             "Offset of field: ",
@@ -8532,9 +8132,7 @@ fn bindgen_test_layout__cef_domdocument_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_domdocument_t>())).get_element_by_id as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_domdocument_t, get_element_by_id),
         80usize,
         concat!(
             "Offset of field: ",
@@ -8544,9 +8142,7 @@ fn bindgen_test_layout__cef_domdocument_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_domdocument_t>())).get_focused_node as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_domdocument_t, get_focused_node),
         88usize,
         concat!(
             "Offset of field: ",
@@ -8556,9 +8152,7 @@ fn bindgen_test_layout__cef_domdocument_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_domdocument_t>())).has_selection as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_domdocument_t, has_selection),
         96usize,
         concat!(
             "Offset of field: ",
@@ -8568,10 +8162,7 @@ fn bindgen_test_layout__cef_domdocument_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_domdocument_t>())).get_selection_start_offset as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_domdocument_t, get_selection_start_offset),
         104usize,
         concat!(
             "Offset of field: ",
@@ -8581,10 +8172,7 @@ fn bindgen_test_layout__cef_domdocument_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_domdocument_t>())).get_selection_end_offset as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_domdocument_t, get_selection_end_offset),
         112usize,
         concat!(
             "Offset of field: ",
@@ -8594,10 +8182,7 @@ fn bindgen_test_layout__cef_domdocument_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_domdocument_t>())).get_selection_as_markup as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_domdocument_t, get_selection_as_markup),
         120usize,
         concat!(
             "Offset of field: ",
@@ -8607,10 +8192,7 @@ fn bindgen_test_layout__cef_domdocument_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_domdocument_t>())).get_selection_as_text as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_domdocument_t, get_selection_as_text),
         128usize,
         concat!(
             "Offset of field: ",
@@ -8620,7 +8202,7 @@ fn bindgen_test_layout__cef_domdocument_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_domdocument_t>())).get_base_url as *const _ as usize },
+        ::std::mem::offset_of!(_cef_domdocument_t, get_base_url),
         136usize,
         concat!(
             "Offset of field: ",
@@ -8630,9 +8212,7 @@ fn bindgen_test_layout__cef_domdocument_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_domdocument_t>())).get_complete_url as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_domdocument_t, get_complete_url),
         144usize,
         concat!(
             "Offset of field: ",
@@ -8754,7 +8334,7 @@ fn bindgen_test_layout__cef_domnode_t() {
         concat!("Alignment of ", stringify!(_cef_domnode_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_domnode_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_domnode_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -8764,7 +8344,7 @@ fn bindgen_test_layout__cef_domnode_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_domnode_t>())).get_type as *const _ as usize },
+        ::std::mem::offset_of!(_cef_domnode_t, get_type),
         40usize,
         concat!(
             "Offset of field: ",
@@ -8774,7 +8354,7 @@ fn bindgen_test_layout__cef_domnode_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_domnode_t>())).is_text as *const _ as usize },
+        ::std::mem::offset_of!(_cef_domnode_t, is_text),
         48usize,
         concat!(
             "Offset of field: ",
@@ -8784,7 +8364,7 @@ fn bindgen_test_layout__cef_domnode_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_domnode_t>())).is_element as *const _ as usize },
+        ::std::mem::offset_of!(_cef_domnode_t, is_element),
         56usize,
         concat!(
             "Offset of field: ",
@@ -8794,7 +8374,7 @@ fn bindgen_test_layout__cef_domnode_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_domnode_t>())).is_editable as *const _ as usize },
+        ::std::mem::offset_of!(_cef_domnode_t, is_editable),
         64usize,
         concat!(
             "Offset of field: ",
@@ -8804,9 +8384,7 @@ fn bindgen_test_layout__cef_domnode_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_domnode_t>())).is_form_control_element as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_domnode_t, is_form_control_element),
         72usize,
         concat!(
             "Offset of field: ",
@@ -8816,10 +8394,7 @@ fn bindgen_test_layout__cef_domnode_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_domnode_t>())).get_form_control_element_type as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_domnode_t, get_form_control_element_type),
         80usize,
         concat!(
             "Offset of field: ",
@@ -8829,7 +8404,7 @@ fn bindgen_test_layout__cef_domnode_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_domnode_t>())).is_same as *const _ as usize },
+        ::std::mem::offset_of!(_cef_domnode_t, is_same),
         88usize,
         concat!(
             "Offset of field: ",
@@ -8839,7 +8414,7 @@ fn bindgen_test_layout__cef_domnode_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_domnode_t>())).get_name as *const _ as usize },
+        ::std::mem::offset_of!(_cef_domnode_t, get_name),
         96usize,
         concat!(
             "Offset of field: ",
@@ -8849,7 +8424,7 @@ fn bindgen_test_layout__cef_domnode_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_domnode_t>())).get_value as *const _ as usize },
+        ::std::mem::offset_of!(_cef_domnode_t, get_value),
         104usize,
         concat!(
             "Offset of field: ",
@@ -8859,7 +8434,7 @@ fn bindgen_test_layout__cef_domnode_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_domnode_t>())).set_value as *const _ as usize },
+        ::std::mem::offset_of!(_cef_domnode_t, set_value),
         112usize,
         concat!(
             "Offset of field: ",
@@ -8869,7 +8444,7 @@ fn bindgen_test_layout__cef_domnode_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_domnode_t>())).get_as_markup as *const _ as usize },
+        ::std::mem::offset_of!(_cef_domnode_t, get_as_markup),
         120usize,
         concat!(
             "Offset of field: ",
@@ -8879,7 +8454,7 @@ fn bindgen_test_layout__cef_domnode_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_domnode_t>())).get_document as *const _ as usize },
+        ::std::mem::offset_of!(_cef_domnode_t, get_document),
         128usize,
         concat!(
             "Offset of field: ",
@@ -8889,7 +8464,7 @@ fn bindgen_test_layout__cef_domnode_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_domnode_t>())).get_parent as *const _ as usize },
+        ::std::mem::offset_of!(_cef_domnode_t, get_parent),
         136usize,
         concat!(
             "Offset of field: ",
@@ -8899,9 +8474,7 @@ fn bindgen_test_layout__cef_domnode_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_domnode_t>())).get_previous_sibling as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_domnode_t, get_previous_sibling),
         144usize,
         concat!(
             "Offset of field: ",
@@ -8911,7 +8484,7 @@ fn bindgen_test_layout__cef_domnode_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_domnode_t>())).get_next_sibling as *const _ as usize },
+        ::std::mem::offset_of!(_cef_domnode_t, get_next_sibling),
         152usize,
         concat!(
             "Offset of field: ",
@@ -8921,7 +8494,7 @@ fn bindgen_test_layout__cef_domnode_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_domnode_t>())).has_children as *const _ as usize },
+        ::std::mem::offset_of!(_cef_domnode_t, has_children),
         160usize,
         concat!(
             "Offset of field: ",
@@ -8931,7 +8504,7 @@ fn bindgen_test_layout__cef_domnode_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_domnode_t>())).get_first_child as *const _ as usize },
+        ::std::mem::offset_of!(_cef_domnode_t, get_first_child),
         168usize,
         concat!(
             "Offset of field: ",
@@ -8941,7 +8514,7 @@ fn bindgen_test_layout__cef_domnode_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_domnode_t>())).get_last_child as *const _ as usize },
+        ::std::mem::offset_of!(_cef_domnode_t, get_last_child),
         176usize,
         concat!(
             "Offset of field: ",
@@ -8951,9 +8524,7 @@ fn bindgen_test_layout__cef_domnode_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_domnode_t>())).get_element_tag_name as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_domnode_t, get_element_tag_name),
         184usize,
         concat!(
             "Offset of field: ",
@@ -8963,9 +8534,7 @@ fn bindgen_test_layout__cef_domnode_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_domnode_t>())).has_element_attributes as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_domnode_t, has_element_attributes),
         192usize,
         concat!(
             "Offset of field: ",
@@ -8975,9 +8544,7 @@ fn bindgen_test_layout__cef_domnode_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_domnode_t>())).has_element_attribute as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_domnode_t, has_element_attribute),
         200usize,
         concat!(
             "Offset of field: ",
@@ -8987,9 +8554,7 @@ fn bindgen_test_layout__cef_domnode_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_domnode_t>())).get_element_attribute as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_domnode_t, get_element_attribute),
         208usize,
         concat!(
             "Offset of field: ",
@@ -8999,9 +8564,7 @@ fn bindgen_test_layout__cef_domnode_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_domnode_t>())).get_element_attributes as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_domnode_t, get_element_attributes),
         216usize,
         concat!(
             "Offset of field: ",
@@ -9011,9 +8574,7 @@ fn bindgen_test_layout__cef_domnode_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_domnode_t>())).set_element_attribute as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_domnode_t, set_element_attribute),
         224usize,
         concat!(
             "Offset of field: ",
@@ -9023,9 +8584,7 @@ fn bindgen_test_layout__cef_domnode_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_domnode_t>())).get_element_inner_text as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_domnode_t, get_element_inner_text),
         232usize,
         concat!(
             "Offset of field: ",
@@ -9035,9 +8594,7 @@ fn bindgen_test_layout__cef_domnode_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_domnode_t>())).get_element_bounds as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_domnode_t, get_element_bounds),
         240usize,
         concat!(
             "Offset of field: ",
@@ -9081,7 +8638,7 @@ fn bindgen_test_layout__cef_process_message_t() {
         concat!("Alignment of ", stringify!(_cef_process_message_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_process_message_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_process_message_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -9091,7 +8648,7 @@ fn bindgen_test_layout__cef_process_message_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_process_message_t>())).is_valid as *const _ as usize },
+        ::std::mem::offset_of!(_cef_process_message_t, is_valid),
         40usize,
         concat!(
             "Offset of field: ",
@@ -9101,9 +8658,7 @@ fn bindgen_test_layout__cef_process_message_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_process_message_t>())).is_read_only as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_process_message_t, is_read_only),
         48usize,
         concat!(
             "Offset of field: ",
@@ -9113,7 +8668,7 @@ fn bindgen_test_layout__cef_process_message_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_process_message_t>())).copy as *const _ as usize },
+        ::std::mem::offset_of!(_cef_process_message_t, copy),
         56usize,
         concat!(
             "Offset of field: ",
@@ -9123,7 +8678,7 @@ fn bindgen_test_layout__cef_process_message_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_process_message_t>())).get_name as *const _ as usize },
+        ::std::mem::offset_of!(_cef_process_message_t, get_name),
         64usize,
         concat!(
             "Offset of field: ",
@@ -9133,10 +8688,7 @@ fn bindgen_test_layout__cef_process_message_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_process_message_t>())).get_argument_list as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_process_message_t, get_argument_list),
         72usize,
         concat!(
             "Offset of field: ",
@@ -9251,7 +8803,7 @@ fn bindgen_test_layout__cef_request_t() {
         concat!("Alignment of ", stringify!(_cef_request_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_request_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_request_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -9261,7 +8813,7 @@ fn bindgen_test_layout__cef_request_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_request_t>())).is_read_only as *const _ as usize },
+        ::std::mem::offset_of!(_cef_request_t, is_read_only),
         40usize,
         concat!(
             "Offset of field: ",
@@ -9271,7 +8823,7 @@ fn bindgen_test_layout__cef_request_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_request_t>())).get_url as *const _ as usize },
+        ::std::mem::offset_of!(_cef_request_t, get_url),
         48usize,
         concat!(
             "Offset of field: ",
@@ -9281,7 +8833,7 @@ fn bindgen_test_layout__cef_request_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_request_t>())).set_url as *const _ as usize },
+        ::std::mem::offset_of!(_cef_request_t, set_url),
         56usize,
         concat!(
             "Offset of field: ",
@@ -9291,7 +8843,7 @@ fn bindgen_test_layout__cef_request_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_request_t>())).get_method as *const _ as usize },
+        ::std::mem::offset_of!(_cef_request_t, get_method),
         64usize,
         concat!(
             "Offset of field: ",
@@ -9301,7 +8853,7 @@ fn bindgen_test_layout__cef_request_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_request_t>())).set_method as *const _ as usize },
+        ::std::mem::offset_of!(_cef_request_t, set_method),
         72usize,
         concat!(
             "Offset of field: ",
@@ -9311,7 +8863,7 @@ fn bindgen_test_layout__cef_request_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_request_t>())).set_referrer as *const _ as usize },
+        ::std::mem::offset_of!(_cef_request_t, set_referrer),
         80usize,
         concat!(
             "Offset of field: ",
@@ -9321,7 +8873,7 @@ fn bindgen_test_layout__cef_request_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_request_t>())).get_referrer_url as *const _ as usize },
+        ::std::mem::offset_of!(_cef_request_t, get_referrer_url),
         88usize,
         concat!(
             "Offset of field: ",
@@ -9331,9 +8883,7 @@ fn bindgen_test_layout__cef_request_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_request_t>())).get_referrer_policy as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_request_t, get_referrer_policy),
         96usize,
         concat!(
             "Offset of field: ",
@@ -9343,7 +8893,7 @@ fn bindgen_test_layout__cef_request_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_request_t>())).get_post_data as *const _ as usize },
+        ::std::mem::offset_of!(_cef_request_t, get_post_data),
         104usize,
         concat!(
             "Offset of field: ",
@@ -9353,7 +8903,7 @@ fn bindgen_test_layout__cef_request_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_request_t>())).set_post_data as *const _ as usize },
+        ::std::mem::offset_of!(_cef_request_t, set_post_data),
         112usize,
         concat!(
             "Offset of field: ",
@@ -9363,7 +8913,7 @@ fn bindgen_test_layout__cef_request_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_request_t>())).get_header_map as *const _ as usize },
+        ::std::mem::offset_of!(_cef_request_t, get_header_map),
         120usize,
         concat!(
             "Offset of field: ",
@@ -9373,7 +8923,7 @@ fn bindgen_test_layout__cef_request_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_request_t>())).set_header_map as *const _ as usize },
+        ::std::mem::offset_of!(_cef_request_t, set_header_map),
         128usize,
         concat!(
             "Offset of field: ",
@@ -9383,9 +8933,7 @@ fn bindgen_test_layout__cef_request_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_request_t>())).get_header_by_name as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_request_t, get_header_by_name),
         136usize,
         concat!(
             "Offset of field: ",
@@ -9395,9 +8943,7 @@ fn bindgen_test_layout__cef_request_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_request_t>())).set_header_by_name as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_request_t, set_header_by_name),
         144usize,
         concat!(
             "Offset of field: ",
@@ -9407,7 +8953,7 @@ fn bindgen_test_layout__cef_request_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_request_t>())).set as *const _ as usize },
+        ::std::mem::offset_of!(_cef_request_t, set),
         152usize,
         concat!(
             "Offset of field: ",
@@ -9417,7 +8963,7 @@ fn bindgen_test_layout__cef_request_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_request_t>())).get_flags as *const _ as usize },
+        ::std::mem::offset_of!(_cef_request_t, get_flags),
         160usize,
         concat!(
             "Offset of field: ",
@@ -9427,7 +8973,7 @@ fn bindgen_test_layout__cef_request_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_request_t>())).set_flags as *const _ as usize },
+        ::std::mem::offset_of!(_cef_request_t, set_flags),
         168usize,
         concat!(
             "Offset of field: ",
@@ -9437,10 +8983,7 @@ fn bindgen_test_layout__cef_request_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_request_t>())).get_first_party_for_cookies as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_request_t, get_first_party_for_cookies),
         176usize,
         concat!(
             "Offset of field: ",
@@ -9450,10 +8993,7 @@ fn bindgen_test_layout__cef_request_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_request_t>())).set_first_party_for_cookies as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_request_t, set_first_party_for_cookies),
         184usize,
         concat!(
             "Offset of field: ",
@@ -9463,9 +9003,7 @@ fn bindgen_test_layout__cef_request_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_request_t>())).get_resource_type as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_request_t, get_resource_type),
         192usize,
         concat!(
             "Offset of field: ",
@@ -9475,9 +9013,7 @@ fn bindgen_test_layout__cef_request_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_request_t>())).get_transition_type as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_request_t, get_transition_type),
         200usize,
         concat!(
             "Offset of field: ",
@@ -9487,7 +9023,7 @@ fn bindgen_test_layout__cef_request_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_request_t>())).get_identifier as *const _ as usize },
+        ::std::mem::offset_of!(_cef_request_t, get_identifier),
         208usize,
         concat!(
             "Offset of field: ",
@@ -9547,7 +9083,7 @@ fn bindgen_test_layout__cef_post_data_t() {
         concat!("Alignment of ", stringify!(_cef_post_data_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_post_data_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_post_data_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -9557,7 +9093,7 @@ fn bindgen_test_layout__cef_post_data_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_post_data_t>())).is_read_only as *const _ as usize },
+        ::std::mem::offset_of!(_cef_post_data_t, is_read_only),
         40usize,
         concat!(
             "Offset of field: ",
@@ -9567,9 +9103,7 @@ fn bindgen_test_layout__cef_post_data_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_post_data_t>())).has_excluded_elements as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_post_data_t, has_excluded_elements),
         48usize,
         concat!(
             "Offset of field: ",
@@ -9579,9 +9113,7 @@ fn bindgen_test_layout__cef_post_data_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_post_data_t>())).get_element_count as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_post_data_t, get_element_count),
         56usize,
         concat!(
             "Offset of field: ",
@@ -9591,7 +9123,7 @@ fn bindgen_test_layout__cef_post_data_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_post_data_t>())).get_elements as *const _ as usize },
+        ::std::mem::offset_of!(_cef_post_data_t, get_elements),
         64usize,
         concat!(
             "Offset of field: ",
@@ -9601,7 +9133,7 @@ fn bindgen_test_layout__cef_post_data_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_post_data_t>())).remove_element as *const _ as usize },
+        ::std::mem::offset_of!(_cef_post_data_t, remove_element),
         72usize,
         concat!(
             "Offset of field: ",
@@ -9611,7 +9143,7 @@ fn bindgen_test_layout__cef_post_data_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_post_data_t>())).add_element as *const _ as usize },
+        ::std::mem::offset_of!(_cef_post_data_t, add_element),
         80usize,
         concat!(
             "Offset of field: ",
@@ -9621,9 +9153,7 @@ fn bindgen_test_layout__cef_post_data_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_post_data_t>())).remove_elements as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_post_data_t, remove_elements),
         88usize,
         concat!(
             "Offset of field: ",
@@ -9687,7 +9217,7 @@ fn bindgen_test_layout__cef_post_data_element_t() {
         concat!("Alignment of ", stringify!(_cef_post_data_element_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_post_data_element_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_post_data_element_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -9697,9 +9227,7 @@ fn bindgen_test_layout__cef_post_data_element_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_post_data_element_t>())).is_read_only as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_post_data_element_t, is_read_only),
         40usize,
         concat!(
             "Offset of field: ",
@@ -9709,9 +9237,7 @@ fn bindgen_test_layout__cef_post_data_element_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_post_data_element_t>())).set_to_empty as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_post_data_element_t, set_to_empty),
         48usize,
         concat!(
             "Offset of field: ",
@@ -9721,9 +9247,7 @@ fn bindgen_test_layout__cef_post_data_element_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_post_data_element_t>())).set_to_file as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_post_data_element_t, set_to_file),
         56usize,
         concat!(
             "Offset of field: ",
@@ -9733,9 +9257,7 @@ fn bindgen_test_layout__cef_post_data_element_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_post_data_element_t>())).set_to_bytes as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_post_data_element_t, set_to_bytes),
         64usize,
         concat!(
             "Offset of field: ",
@@ -9745,9 +9267,7 @@ fn bindgen_test_layout__cef_post_data_element_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_post_data_element_t>())).get_type as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_post_data_element_t, get_type),
         72usize,
         concat!(
             "Offset of field: ",
@@ -9757,9 +9277,7 @@ fn bindgen_test_layout__cef_post_data_element_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_post_data_element_t>())).get_file as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_post_data_element_t, get_file),
         80usize,
         concat!(
             "Offset of field: ",
@@ -9769,10 +9287,7 @@ fn bindgen_test_layout__cef_post_data_element_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_post_data_element_t>())).get_bytes_count as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_post_data_element_t, get_bytes_count),
         88usize,
         concat!(
             "Offset of field: ",
@@ -9782,9 +9297,7 @@ fn bindgen_test_layout__cef_post_data_element_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_post_data_element_t>())).get_bytes as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_post_data_element_t, get_bytes),
         96usize,
         concat!(
             "Offset of field: ",
@@ -9819,7 +9332,7 @@ fn bindgen_test_layout__cef_string_visitor_t() {
         concat!("Alignment of ", stringify!(_cef_string_visitor_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_string_visitor_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_string_visitor_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -9829,7 +9342,7 @@ fn bindgen_test_layout__cef_string_visitor_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_string_visitor_t>())).visit as *const _ as usize },
+        ::std::mem::offset_of!(_cef_string_visitor_t, visit),
         40usize,
         concat!(
             "Offset of field: ",
@@ -9928,7 +9441,7 @@ fn bindgen_test_layout__cef_frame_t() {
         concat!("Alignment of ", stringify!(_cef_frame_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_frame_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_frame_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -9938,7 +9451,7 @@ fn bindgen_test_layout__cef_frame_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_frame_t>())).is_valid as *const _ as usize },
+        ::std::mem::offset_of!(_cef_frame_t, is_valid),
         40usize,
         concat!(
             "Offset of field: ",
@@ -9948,7 +9461,7 @@ fn bindgen_test_layout__cef_frame_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_frame_t>())).undo as *const _ as usize },
+        ::std::mem::offset_of!(_cef_frame_t, undo),
         48usize,
         concat!(
             "Offset of field: ",
@@ -9958,7 +9471,7 @@ fn bindgen_test_layout__cef_frame_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_frame_t>())).redo as *const _ as usize },
+        ::std::mem::offset_of!(_cef_frame_t, redo),
         56usize,
         concat!(
             "Offset of field: ",
@@ -9968,7 +9481,7 @@ fn bindgen_test_layout__cef_frame_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_frame_t>())).cut as *const _ as usize },
+        ::std::mem::offset_of!(_cef_frame_t, cut),
         64usize,
         concat!(
             "Offset of field: ",
@@ -9978,7 +9491,7 @@ fn bindgen_test_layout__cef_frame_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_frame_t>())).copy as *const _ as usize },
+        ::std::mem::offset_of!(_cef_frame_t, copy),
         72usize,
         concat!(
             "Offset of field: ",
@@ -9988,7 +9501,7 @@ fn bindgen_test_layout__cef_frame_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_frame_t>())).paste as *const _ as usize },
+        ::std::mem::offset_of!(_cef_frame_t, paste),
         80usize,
         concat!(
             "Offset of field: ",
@@ -9998,7 +9511,7 @@ fn bindgen_test_layout__cef_frame_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_frame_t>())).del as *const _ as usize },
+        ::std::mem::offset_of!(_cef_frame_t, del),
         88usize,
         concat!(
             "Offset of field: ",
@@ -10008,7 +9521,7 @@ fn bindgen_test_layout__cef_frame_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_frame_t>())).select_all as *const _ as usize },
+        ::std::mem::offset_of!(_cef_frame_t, select_all),
         96usize,
         concat!(
             "Offset of field: ",
@@ -10018,7 +9531,7 @@ fn bindgen_test_layout__cef_frame_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_frame_t>())).view_source as *const _ as usize },
+        ::std::mem::offset_of!(_cef_frame_t, view_source),
         104usize,
         concat!(
             "Offset of field: ",
@@ -10028,7 +9541,7 @@ fn bindgen_test_layout__cef_frame_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_frame_t>())).get_source as *const _ as usize },
+        ::std::mem::offset_of!(_cef_frame_t, get_source),
         112usize,
         concat!(
             "Offset of field: ",
@@ -10038,7 +9551,7 @@ fn bindgen_test_layout__cef_frame_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_frame_t>())).get_text as *const _ as usize },
+        ::std::mem::offset_of!(_cef_frame_t, get_text),
         120usize,
         concat!(
             "Offset of field: ",
@@ -10048,7 +9561,7 @@ fn bindgen_test_layout__cef_frame_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_frame_t>())).load_request as *const _ as usize },
+        ::std::mem::offset_of!(_cef_frame_t, load_request),
         128usize,
         concat!(
             "Offset of field: ",
@@ -10058,7 +9571,7 @@ fn bindgen_test_layout__cef_frame_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_frame_t>())).load_url as *const _ as usize },
+        ::std::mem::offset_of!(_cef_frame_t, load_url),
         136usize,
         concat!(
             "Offset of field: ",
@@ -10068,9 +9581,7 @@ fn bindgen_test_layout__cef_frame_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_frame_t>())).execute_java_script as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_frame_t, execute_java_script),
         144usize,
         concat!(
             "Offset of field: ",
@@ -10080,7 +9591,7 @@ fn bindgen_test_layout__cef_frame_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_frame_t>())).is_main as *const _ as usize },
+        ::std::mem::offset_of!(_cef_frame_t, is_main),
         152usize,
         concat!(
             "Offset of field: ",
@@ -10090,7 +9601,7 @@ fn bindgen_test_layout__cef_frame_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_frame_t>())).is_focused as *const _ as usize },
+        ::std::mem::offset_of!(_cef_frame_t, is_focused),
         160usize,
         concat!(
             "Offset of field: ",
@@ -10100,7 +9611,7 @@ fn bindgen_test_layout__cef_frame_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_frame_t>())).get_name as *const _ as usize },
+        ::std::mem::offset_of!(_cef_frame_t, get_name),
         168usize,
         concat!(
             "Offset of field: ",
@@ -10110,7 +9621,7 @@ fn bindgen_test_layout__cef_frame_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_frame_t>())).get_identifier as *const _ as usize },
+        ::std::mem::offset_of!(_cef_frame_t, get_identifier),
         176usize,
         concat!(
             "Offset of field: ",
@@ -10120,7 +9631,7 @@ fn bindgen_test_layout__cef_frame_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_frame_t>())).get_parent as *const _ as usize },
+        ::std::mem::offset_of!(_cef_frame_t, get_parent),
         184usize,
         concat!(
             "Offset of field: ",
@@ -10130,7 +9641,7 @@ fn bindgen_test_layout__cef_frame_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_frame_t>())).get_url as *const _ as usize },
+        ::std::mem::offset_of!(_cef_frame_t, get_url),
         192usize,
         concat!(
             "Offset of field: ",
@@ -10140,7 +9651,7 @@ fn bindgen_test_layout__cef_frame_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_frame_t>())).get_browser as *const _ as usize },
+        ::std::mem::offset_of!(_cef_frame_t, get_browser),
         200usize,
         concat!(
             "Offset of field: ",
@@ -10150,7 +9661,7 @@ fn bindgen_test_layout__cef_frame_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_frame_t>())).get_v8context as *const _ as usize },
+        ::std::mem::offset_of!(_cef_frame_t, get_v8context),
         208usize,
         concat!(
             "Offset of field: ",
@@ -10160,7 +9671,7 @@ fn bindgen_test_layout__cef_frame_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_frame_t>())).visit_dom as *const _ as usize },
+        ::std::mem::offset_of!(_cef_frame_t, visit_dom),
         216usize,
         concat!(
             "Offset of field: ",
@@ -10170,7 +9681,7 @@ fn bindgen_test_layout__cef_frame_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_frame_t>())).create_urlrequest as *const _ as usize },
+        ::std::mem::offset_of!(_cef_frame_t, create_urlrequest),
         224usize,
         concat!(
             "Offset of field: ",
@@ -10180,9 +9691,7 @@ fn bindgen_test_layout__cef_frame_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_frame_t>())).send_process_message as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_frame_t, send_process_message),
         232usize,
         concat!(
             "Offset of field: ",
@@ -10238,7 +9747,7 @@ fn bindgen_test_layout__cef_x509cert_principal_t() {
         concat!("Alignment of ", stringify!(_cef_x509cert_principal_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_x509cert_principal_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_x509cert_principal_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -10248,10 +9757,7 @@ fn bindgen_test_layout__cef_x509cert_principal_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_x509cert_principal_t>())).get_display_name as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_x509cert_principal_t, get_display_name),
         40usize,
         concat!(
             "Offset of field: ",
@@ -10261,10 +9767,7 @@ fn bindgen_test_layout__cef_x509cert_principal_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_x509cert_principal_t>())).get_common_name as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_x509cert_principal_t, get_common_name),
         48usize,
         concat!(
             "Offset of field: ",
@@ -10274,10 +9777,7 @@ fn bindgen_test_layout__cef_x509cert_principal_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_x509cert_principal_t>())).get_locality_name as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_x509cert_principal_t, get_locality_name),
         56usize,
         concat!(
             "Offset of field: ",
@@ -10287,10 +9787,7 @@ fn bindgen_test_layout__cef_x509cert_principal_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_x509cert_principal_t>())).get_state_or_province_name
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_x509cert_principal_t, get_state_or_province_name),
         64usize,
         concat!(
             "Offset of field: ",
@@ -10300,10 +9797,7 @@ fn bindgen_test_layout__cef_x509cert_principal_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_x509cert_principal_t>())).get_country_name as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_x509cert_principal_t, get_country_name),
         72usize,
         concat!(
             "Offset of field: ",
@@ -10313,10 +9807,7 @@ fn bindgen_test_layout__cef_x509cert_principal_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_x509cert_principal_t>())).get_street_addresses as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_x509cert_principal_t, get_street_addresses),
         80usize,
         concat!(
             "Offset of field: ",
@@ -10326,10 +9817,7 @@ fn bindgen_test_layout__cef_x509cert_principal_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_x509cert_principal_t>())).get_organization_names as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_x509cert_principal_t, get_organization_names),
         88usize,
         concat!(
             "Offset of field: ",
@@ -10339,10 +9827,7 @@ fn bindgen_test_layout__cef_x509cert_principal_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_x509cert_principal_t>())).get_organization_unit_names
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_x509cert_principal_t, get_organization_unit_names),
         96usize,
         concat!(
             "Offset of field: ",
@@ -10352,10 +9837,7 @@ fn bindgen_test_layout__cef_x509cert_principal_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_x509cert_principal_t>())).get_domain_components as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_x509cert_principal_t, get_domain_components),
         104usize,
         concat!(
             "Offset of field: ",
@@ -10421,7 +9903,7 @@ fn bindgen_test_layout__cef_x509certificate_t() {
         concat!("Alignment of ", stringify!(_cef_x509certificate_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_x509certificate_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_x509certificate_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -10431,9 +9913,7 @@ fn bindgen_test_layout__cef_x509certificate_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_x509certificate_t>())).get_subject as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_x509certificate_t, get_subject),
         40usize,
         concat!(
             "Offset of field: ",
@@ -10443,9 +9923,7 @@ fn bindgen_test_layout__cef_x509certificate_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_x509certificate_t>())).get_issuer as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_x509certificate_t, get_issuer),
         48usize,
         concat!(
             "Offset of field: ",
@@ -10455,10 +9933,7 @@ fn bindgen_test_layout__cef_x509certificate_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_x509certificate_t>())).get_serial_number as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_x509certificate_t, get_serial_number),
         56usize,
         concat!(
             "Offset of field: ",
@@ -10468,9 +9943,7 @@ fn bindgen_test_layout__cef_x509certificate_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_x509certificate_t>())).get_valid_start as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_x509certificate_t, get_valid_start),
         64usize,
         concat!(
             "Offset of field: ",
@@ -10480,9 +9953,7 @@ fn bindgen_test_layout__cef_x509certificate_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_x509certificate_t>())).get_valid_expiry as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_x509certificate_t, get_valid_expiry),
         72usize,
         concat!(
             "Offset of field: ",
@@ -10492,9 +9963,7 @@ fn bindgen_test_layout__cef_x509certificate_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_x509certificate_t>())).get_derencoded as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_x509certificate_t, get_derencoded),
         80usize,
         concat!(
             "Offset of field: ",
@@ -10504,9 +9973,7 @@ fn bindgen_test_layout__cef_x509certificate_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_x509certificate_t>())).get_pemencoded as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_x509certificate_t, get_pemencoded),
         88usize,
         concat!(
             "Offset of field: ",
@@ -10516,10 +9983,7 @@ fn bindgen_test_layout__cef_x509certificate_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_x509certificate_t>())).get_issuer_chain_size as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_x509certificate_t, get_issuer_chain_size),
         96usize,
         concat!(
             "Offset of field: ",
@@ -10529,10 +9993,7 @@ fn bindgen_test_layout__cef_x509certificate_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_x509certificate_t>())).get_derencoded_issuer_chain
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_x509certificate_t, get_derencoded_issuer_chain),
         104usize,
         concat!(
             "Offset of field: ",
@@ -10542,10 +10003,7 @@ fn bindgen_test_layout__cef_x509certificate_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_x509certificate_t>())).get_pemencoded_issuer_chain
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_x509certificate_t, get_pemencoded_issuer_chain),
         112usize,
         concat!(
             "Offset of field: ",
@@ -10589,7 +10047,7 @@ fn bindgen_test_layout__cef_sslstatus_t() {
         concat!("Alignment of ", stringify!(_cef_sslstatus_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_sslstatus_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_sslstatus_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -10599,9 +10057,7 @@ fn bindgen_test_layout__cef_sslstatus_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_sslstatus_t>())).is_secure_connection as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_sslstatus_t, is_secure_connection),
         40usize,
         concat!(
             "Offset of field: ",
@@ -10611,9 +10067,7 @@ fn bindgen_test_layout__cef_sslstatus_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_sslstatus_t>())).get_cert_status as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_sslstatus_t, get_cert_status),
         48usize,
         concat!(
             "Offset of field: ",
@@ -10623,7 +10077,7 @@ fn bindgen_test_layout__cef_sslstatus_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_sslstatus_t>())).get_sslversion as *const _ as usize },
+        ::std::mem::offset_of!(_cef_sslstatus_t, get_sslversion),
         56usize,
         concat!(
             "Offset of field: ",
@@ -10633,9 +10087,7 @@ fn bindgen_test_layout__cef_sslstatus_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_sslstatus_t>())).get_content_status as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_sslstatus_t, get_content_status),
         64usize,
         concat!(
             "Offset of field: ",
@@ -10645,9 +10097,7 @@ fn bindgen_test_layout__cef_sslstatus_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_sslstatus_t>())).get_x509certificate as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_sslstatus_t, get_x509certificate),
         72usize,
         concat!(
             "Offset of field: ",
@@ -10706,7 +10156,7 @@ fn bindgen_test_layout__cef_navigation_entry_t() {
         concat!("Alignment of ", stringify!(_cef_navigation_entry_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_navigation_entry_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_navigation_entry_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -10716,9 +10166,7 @@ fn bindgen_test_layout__cef_navigation_entry_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_navigation_entry_t>())).is_valid as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_navigation_entry_t, is_valid),
         40usize,
         concat!(
             "Offset of field: ",
@@ -10728,7 +10176,7 @@ fn bindgen_test_layout__cef_navigation_entry_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_navigation_entry_t>())).get_url as *const _ as usize },
+        ::std::mem::offset_of!(_cef_navigation_entry_t, get_url),
         48usize,
         concat!(
             "Offset of field: ",
@@ -10738,9 +10186,7 @@ fn bindgen_test_layout__cef_navigation_entry_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_navigation_entry_t>())).get_display_url as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_navigation_entry_t, get_display_url),
         56usize,
         concat!(
             "Offset of field: ",
@@ -10750,10 +10196,7 @@ fn bindgen_test_layout__cef_navigation_entry_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_navigation_entry_t>())).get_original_url as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_navigation_entry_t, get_original_url),
         64usize,
         concat!(
             "Offset of field: ",
@@ -10763,9 +10206,7 @@ fn bindgen_test_layout__cef_navigation_entry_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_navigation_entry_t>())).get_title as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_navigation_entry_t, get_title),
         72usize,
         concat!(
             "Offset of field: ",
@@ -10775,10 +10216,7 @@ fn bindgen_test_layout__cef_navigation_entry_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_navigation_entry_t>())).get_transition_type as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_navigation_entry_t, get_transition_type),
         80usize,
         concat!(
             "Offset of field: ",
@@ -10788,9 +10226,7 @@ fn bindgen_test_layout__cef_navigation_entry_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_navigation_entry_t>())).has_post_data as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_navigation_entry_t, has_post_data),
         88usize,
         concat!(
             "Offset of field: ",
@@ -10800,10 +10236,7 @@ fn bindgen_test_layout__cef_navigation_entry_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_navigation_entry_t>())).get_completion_time as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_navigation_entry_t, get_completion_time),
         96usize,
         concat!(
             "Offset of field: ",
@@ -10813,10 +10246,7 @@ fn bindgen_test_layout__cef_navigation_entry_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_navigation_entry_t>())).get_http_status_code as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_navigation_entry_t, get_http_status_code),
         104usize,
         concat!(
             "Offset of field: ",
@@ -10826,9 +10256,7 @@ fn bindgen_test_layout__cef_navigation_entry_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_navigation_entry_t>())).get_sslstatus as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_navigation_entry_t, get_sslstatus),
         112usize,
         concat!(
             "Offset of field: ",
@@ -10857,7 +10285,7 @@ fn bindgen_test_layout__cef_registration_t() {
         concat!("Alignment of ", stringify!(_cef_registration_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_registration_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_registration_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -10888,7 +10316,7 @@ fn bindgen_test_layout__cef_callback_t() {
         concat!("Alignment of ", stringify!(_cef_callback_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_callback_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_callback_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -10898,7 +10326,7 @@ fn bindgen_test_layout__cef_callback_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_callback_t>())).cont as *const _ as usize },
+        ::std::mem::offset_of!(_cef_callback_t, cont),
         40usize,
         concat!(
             "Offset of field: ",
@@ -10908,7 +10336,7 @@ fn bindgen_test_layout__cef_callback_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_callback_t>())).cancel as *const _ as usize },
+        ::std::mem::offset_of!(_cef_callback_t, cancel),
         48usize,
         concat!(
             "Offset of field: ",
@@ -10939,7 +10367,7 @@ fn bindgen_test_layout__cef_completion_callback_t() {
         concat!("Alignment of ", stringify!(_cef_completion_callback_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_completion_callback_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_completion_callback_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -10949,9 +10377,7 @@ fn bindgen_test_layout__cef_completion_callback_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_completion_callback_t>())).on_complete as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_completion_callback_t, on_complete),
         40usize,
         concat!(
             "Offset of field: ",
@@ -11024,7 +10450,7 @@ fn bindgen_test_layout__cef_cookie_manager_t() {
         concat!("Alignment of ", stringify!(_cef_cookie_manager_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_cookie_manager_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_cookie_manager_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -11034,10 +10460,7 @@ fn bindgen_test_layout__cef_cookie_manager_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_cookie_manager_t>())).set_supported_schemes as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_cookie_manager_t, set_supported_schemes),
         40usize,
         concat!(
             "Offset of field: ",
@@ -11047,9 +10470,7 @@ fn bindgen_test_layout__cef_cookie_manager_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_cookie_manager_t>())).visit_all_cookies as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_cookie_manager_t, visit_all_cookies),
         48usize,
         concat!(
             "Offset of field: ",
@@ -11059,9 +10480,7 @@ fn bindgen_test_layout__cef_cookie_manager_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_cookie_manager_t>())).visit_url_cookies as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_cookie_manager_t, visit_url_cookies),
         56usize,
         concat!(
             "Offset of field: ",
@@ -11071,9 +10490,7 @@ fn bindgen_test_layout__cef_cookie_manager_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_cookie_manager_t>())).set_cookie as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_cookie_manager_t, set_cookie),
         64usize,
         concat!(
             "Offset of field: ",
@@ -11083,9 +10500,7 @@ fn bindgen_test_layout__cef_cookie_manager_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_cookie_manager_t>())).delete_cookies as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_cookie_manager_t, delete_cookies),
         72usize,
         concat!(
             "Offset of field: ",
@@ -11095,9 +10510,7 @@ fn bindgen_test_layout__cef_cookie_manager_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_cookie_manager_t>())).flush_store as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_cookie_manager_t, flush_store),
         80usize,
         concat!(
             "Offset of field: ",
@@ -11140,7 +10553,7 @@ fn bindgen_test_layout__cef_cookie_visitor_t() {
         concat!("Alignment of ", stringify!(_cef_cookie_visitor_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_cookie_visitor_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_cookie_visitor_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -11150,7 +10563,7 @@ fn bindgen_test_layout__cef_cookie_visitor_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_cookie_visitor_t>())).visit as *const _ as usize },
+        ::std::mem::offset_of!(_cef_cookie_visitor_t, visit),
         40usize,
         concat!(
             "Offset of field: ",
@@ -11185,7 +10598,7 @@ fn bindgen_test_layout__cef_set_cookie_callback_t() {
         concat!("Alignment of ", stringify!(_cef_set_cookie_callback_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_set_cookie_callback_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_set_cookie_callback_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -11195,9 +10608,7 @@ fn bindgen_test_layout__cef_set_cookie_callback_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_set_cookie_callback_t>())).on_complete as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_set_cookie_callback_t, on_complete),
         40usize,
         concat!(
             "Offset of field: ",
@@ -11232,9 +10643,7 @@ fn bindgen_test_layout__cef_delete_cookies_callback_t() {
         concat!("Alignment of ", stringify!(_cef_delete_cookies_callback_t))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_delete_cookies_callback_t>())).base as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_delete_cookies_callback_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -11244,10 +10653,7 @@ fn bindgen_test_layout__cef_delete_cookies_callback_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_delete_cookies_callback_t>())).on_complete as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_delete_cookies_callback_t, on_complete),
         40usize,
         concat!(
             "Offset of field: ",
@@ -11301,7 +10707,7 @@ fn bindgen_test_layout__cef_extension_t() {
         concat!("Alignment of ", stringify!(_cef_extension_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_extension_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_extension_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -11311,7 +10717,7 @@ fn bindgen_test_layout__cef_extension_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_extension_t>())).get_identifier as *const _ as usize },
+        ::std::mem::offset_of!(_cef_extension_t, get_identifier),
         40usize,
         concat!(
             "Offset of field: ",
@@ -11321,7 +10727,7 @@ fn bindgen_test_layout__cef_extension_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_extension_t>())).get_path as *const _ as usize },
+        ::std::mem::offset_of!(_cef_extension_t, get_path),
         48usize,
         concat!(
             "Offset of field: ",
@@ -11331,7 +10737,7 @@ fn bindgen_test_layout__cef_extension_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_extension_t>())).get_manifest as *const _ as usize },
+        ::std::mem::offset_of!(_cef_extension_t, get_manifest),
         56usize,
         concat!(
             "Offset of field: ",
@@ -11341,7 +10747,7 @@ fn bindgen_test_layout__cef_extension_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_extension_t>())).is_same as *const _ as usize },
+        ::std::mem::offset_of!(_cef_extension_t, is_same),
         64usize,
         concat!(
             "Offset of field: ",
@@ -11351,7 +10757,7 @@ fn bindgen_test_layout__cef_extension_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_extension_t>())).get_handler as *const _ as usize },
+        ::std::mem::offset_of!(_cef_extension_t, get_handler),
         72usize,
         concat!(
             "Offset of field: ",
@@ -11361,9 +10767,7 @@ fn bindgen_test_layout__cef_extension_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_extension_t>())).get_loader_context as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_extension_t, get_loader_context),
         80usize,
         concat!(
             "Offset of field: ",
@@ -11373,7 +10777,7 @@ fn bindgen_test_layout__cef_extension_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_extension_t>())).is_loaded as *const _ as usize },
+        ::std::mem::offset_of!(_cef_extension_t, is_loaded),
         88usize,
         concat!(
             "Offset of field: ",
@@ -11383,7 +10787,7 @@ fn bindgen_test_layout__cef_extension_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_extension_t>())).unload as *const _ as usize },
+        ::std::mem::offset_of!(_cef_extension_t, unload),
         96usize,
         concat!(
             "Offset of field: ",
@@ -11427,10 +10831,7 @@ fn bindgen_test_layout__cef_get_extension_resource_callback_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_get_extension_resource_callback_t>())).base as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_get_extension_resource_callback_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -11440,10 +10841,7 @@ fn bindgen_test_layout__cef_get_extension_resource_callback_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_get_extension_resource_callback_t>())).cont as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_get_extension_resource_callback_t, cont),
         40usize,
         concat!(
             "Offset of field: ",
@@ -11453,10 +10851,7 @@ fn bindgen_test_layout__cef_get_extension_resource_callback_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_get_extension_resource_callback_t>())).cancel as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_get_extension_resource_callback_t, cancel),
         48usize,
         concat!(
             "Offset of field: ",
@@ -11549,7 +10944,7 @@ fn bindgen_test_layout__cef_extension_handler_t() {
         concat!("Alignment of ", stringify!(_cef_extension_handler_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_extension_handler_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_extension_handler_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -11559,10 +10954,7 @@ fn bindgen_test_layout__cef_extension_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_extension_handler_t>())).on_extension_load_failed
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_extension_handler_t, on_extension_load_failed),
         40usize,
         concat!(
             "Offset of field: ",
@@ -11572,10 +10964,7 @@ fn bindgen_test_layout__cef_extension_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_extension_handler_t>())).on_extension_loaded as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_extension_handler_t, on_extension_loaded),
         48usize,
         concat!(
             "Offset of field: ",
@@ -11585,10 +10974,7 @@ fn bindgen_test_layout__cef_extension_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_extension_handler_t>())).on_extension_unloaded as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_extension_handler_t, on_extension_unloaded),
         56usize,
         concat!(
             "Offset of field: ",
@@ -11598,10 +10984,7 @@ fn bindgen_test_layout__cef_extension_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_extension_handler_t>())).on_before_background_browser
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_extension_handler_t, on_before_background_browser),
         64usize,
         concat!(
             "Offset of field: ",
@@ -11611,10 +10994,7 @@ fn bindgen_test_layout__cef_extension_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_extension_handler_t>())).on_before_browser as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_extension_handler_t, on_before_browser),
         72usize,
         concat!(
             "Offset of field: ",
@@ -11624,10 +11004,7 @@ fn bindgen_test_layout__cef_extension_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_extension_handler_t>())).get_active_browser as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_extension_handler_t, get_active_browser),
         80usize,
         concat!(
             "Offset of field: ",
@@ -11637,10 +11014,7 @@ fn bindgen_test_layout__cef_extension_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_extension_handler_t>())).can_access_browser as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_extension_handler_t, can_access_browser),
         88usize,
         concat!(
             "Offset of field: ",
@@ -11650,10 +11024,7 @@ fn bindgen_test_layout__cef_extension_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_extension_handler_t>())).get_extension_resource as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_extension_handler_t, get_extension_resource),
         96usize,
         concat!(
             "Offset of field: ",
@@ -11706,7 +11077,7 @@ fn bindgen_test_layout__cef_media_router_t() {
         concat!("Alignment of ", stringify!(_cef_media_router_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_media_router_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_media_router_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -11716,9 +11087,7 @@ fn bindgen_test_layout__cef_media_router_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_media_router_t>())).add_observer as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_media_router_t, add_observer),
         40usize,
         concat!(
             "Offset of field: ",
@@ -11728,7 +11097,7 @@ fn bindgen_test_layout__cef_media_router_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_media_router_t>())).get_source as *const _ as usize },
+        ::std::mem::offset_of!(_cef_media_router_t, get_source),
         48usize,
         concat!(
             "Offset of field: ",
@@ -11738,10 +11107,7 @@ fn bindgen_test_layout__cef_media_router_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_media_router_t>())).notify_current_sinks as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_media_router_t, notify_current_sinks),
         56usize,
         concat!(
             "Offset of field: ",
@@ -11751,9 +11117,7 @@ fn bindgen_test_layout__cef_media_router_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_media_router_t>())).create_route as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_media_router_t, create_route),
         64usize,
         concat!(
             "Offset of field: ",
@@ -11763,10 +11127,7 @@ fn bindgen_test_layout__cef_media_router_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_media_router_t>())).notify_current_routes as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_media_router_t, notify_current_routes),
         72usize,
         concat!(
             "Offset of field: ",
@@ -11827,7 +11188,7 @@ fn bindgen_test_layout__cef_media_observer_t() {
         concat!("Alignment of ", stringify!(_cef_media_observer_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_media_observer_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_media_observer_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -11837,7 +11198,7 @@ fn bindgen_test_layout__cef_media_observer_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_media_observer_t>())).on_sinks as *const _ as usize },
+        ::std::mem::offset_of!(_cef_media_observer_t, on_sinks),
         40usize,
         concat!(
             "Offset of field: ",
@@ -11847,7 +11208,7 @@ fn bindgen_test_layout__cef_media_observer_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_media_observer_t>())).on_routes as *const _ as usize },
+        ::std::mem::offset_of!(_cef_media_observer_t, on_routes),
         48usize,
         concat!(
             "Offset of field: ",
@@ -11857,10 +11218,7 @@ fn bindgen_test_layout__cef_media_observer_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_media_observer_t>())).on_route_state_changed as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_media_observer_t, on_route_state_changed),
         56usize,
         concat!(
             "Offset of field: ",
@@ -11870,10 +11228,7 @@ fn bindgen_test_layout__cef_media_observer_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_media_observer_t>())).on_route_message_received as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_media_observer_t, on_route_message_received),
         64usize,
         concat!(
             "Offset of field: ",
@@ -11919,7 +11274,7 @@ fn bindgen_test_layout__cef_media_route_t() {
         concat!("Alignment of ", stringify!(_cef_media_route_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_media_route_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_media_route_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -11929,7 +11284,7 @@ fn bindgen_test_layout__cef_media_route_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_media_route_t>())).get_id as *const _ as usize },
+        ::std::mem::offset_of!(_cef_media_route_t, get_id),
         40usize,
         concat!(
             "Offset of field: ",
@@ -11939,7 +11294,7 @@ fn bindgen_test_layout__cef_media_route_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_media_route_t>())).get_source as *const _ as usize },
+        ::std::mem::offset_of!(_cef_media_route_t, get_source),
         48usize,
         concat!(
             "Offset of field: ",
@@ -11949,7 +11304,7 @@ fn bindgen_test_layout__cef_media_route_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_media_route_t>())).get_sink as *const _ as usize },
+        ::std::mem::offset_of!(_cef_media_route_t, get_sink),
         56usize,
         concat!(
             "Offset of field: ",
@@ -11959,9 +11314,7 @@ fn bindgen_test_layout__cef_media_route_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_media_route_t>())).send_route_message as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_media_route_t, send_route_message),
         64usize,
         concat!(
             "Offset of field: ",
@@ -11971,7 +11324,7 @@ fn bindgen_test_layout__cef_media_route_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_media_route_t>())).terminate as *const _ as usize },
+        ::std::mem::offset_of!(_cef_media_route_t, terminate),
         72usize,
         concat!(
             "Offset of field: ",
@@ -12011,9 +11364,7 @@ fn bindgen_test_layout__cef_media_route_create_callback_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_media_route_create_callback_t>())).base as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_media_route_create_callback_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -12023,10 +11374,7 @@ fn bindgen_test_layout__cef_media_route_create_callback_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_media_route_create_callback_t>()))
-                .on_media_route_create_finished as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_media_route_create_callback_t, on_media_route_create_finished),
         40usize,
         concat!(
             "Offset of field: ",
@@ -12088,7 +11436,7 @@ fn bindgen_test_layout__cef_media_sink_t() {
         concat!("Alignment of ", stringify!(_cef_media_sink_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_media_sink_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_media_sink_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -12098,7 +11446,7 @@ fn bindgen_test_layout__cef_media_sink_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_media_sink_t>())).get_id as *const _ as usize },
+        ::std::mem::offset_of!(_cef_media_sink_t, get_id),
         40usize,
         concat!(
             "Offset of field: ",
@@ -12108,7 +11456,7 @@ fn bindgen_test_layout__cef_media_sink_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_media_sink_t>())).is_valid as *const _ as usize },
+        ::std::mem::offset_of!(_cef_media_sink_t, is_valid),
         48usize,
         concat!(
             "Offset of field: ",
@@ -12118,7 +11466,7 @@ fn bindgen_test_layout__cef_media_sink_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_media_sink_t>())).get_name as *const _ as usize },
+        ::std::mem::offset_of!(_cef_media_sink_t, get_name),
         56usize,
         concat!(
             "Offset of field: ",
@@ -12128,9 +11476,7 @@ fn bindgen_test_layout__cef_media_sink_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_media_sink_t>())).get_description as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_media_sink_t, get_description),
         64usize,
         concat!(
             "Offset of field: ",
@@ -12140,7 +11486,7 @@ fn bindgen_test_layout__cef_media_sink_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_media_sink_t>())).get_icon_type as *const _ as usize },
+        ::std::mem::offset_of!(_cef_media_sink_t, get_icon_type),
         72usize,
         concat!(
             "Offset of field: ",
@@ -12150,9 +11496,7 @@ fn bindgen_test_layout__cef_media_sink_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_media_sink_t>())).get_device_info as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_media_sink_t, get_device_info),
         80usize,
         concat!(
             "Offset of field: ",
@@ -12162,7 +11506,7 @@ fn bindgen_test_layout__cef_media_sink_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_media_sink_t>())).is_cast_sink as *const _ as usize },
+        ::std::mem::offset_of!(_cef_media_sink_t, is_cast_sink),
         88usize,
         concat!(
             "Offset of field: ",
@@ -12172,7 +11516,7 @@ fn bindgen_test_layout__cef_media_sink_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_media_sink_t>())).is_dial_sink as *const _ as usize },
+        ::std::mem::offset_of!(_cef_media_sink_t, is_dial_sink),
         96usize,
         concat!(
             "Offset of field: ",
@@ -12182,9 +11526,7 @@ fn bindgen_test_layout__cef_media_sink_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_media_sink_t>())).is_compatible_with as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_media_sink_t, is_compatible_with),
         104usize,
         concat!(
             "Offset of field: ",
@@ -12225,10 +11567,7 @@ fn bindgen_test_layout__cef_media_sink_device_info_callback_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_media_sink_device_info_callback_t>())).base as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_media_sink_device_info_callback_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -12238,10 +11577,7 @@ fn bindgen_test_layout__cef_media_sink_device_info_callback_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_media_sink_device_info_callback_t>()))
-                .on_media_sink_device_info as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_media_sink_device_info_callback_t, on_media_sink_device_info),
         40usize,
         concat!(
             "Offset of field: ",
@@ -12282,7 +11618,7 @@ fn bindgen_test_layout__cef_media_source_t() {
         concat!("Alignment of ", stringify!(_cef_media_source_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_media_source_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_media_source_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -12292,7 +11628,7 @@ fn bindgen_test_layout__cef_media_source_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_media_source_t>())).get_id as *const _ as usize },
+        ::std::mem::offset_of!(_cef_media_source_t, get_id),
         40usize,
         concat!(
             "Offset of field: ",
@@ -12302,7 +11638,7 @@ fn bindgen_test_layout__cef_media_source_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_media_source_t>())).is_valid as *const _ as usize },
+        ::std::mem::offset_of!(_cef_media_source_t, is_valid),
         48usize,
         concat!(
             "Offset of field: ",
@@ -12312,9 +11648,7 @@ fn bindgen_test_layout__cef_media_source_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_media_source_t>())).is_cast_source as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_media_source_t, is_cast_source),
         56usize,
         concat!(
             "Offset of field: ",
@@ -12324,9 +11658,7 @@ fn bindgen_test_layout__cef_media_source_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_media_source_t>())).is_dial_source as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_media_source_t, is_dial_source),
         64usize,
         concat!(
             "Offset of field: ",
@@ -12362,7 +11694,7 @@ fn bindgen_test_layout__cef_resolve_callback_t() {
         concat!("Alignment of ", stringify!(_cef_resolve_callback_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_resolve_callback_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_resolve_callback_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -12372,10 +11704,7 @@ fn bindgen_test_layout__cef_resolve_callback_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_resolve_callback_t>())).on_resolve_completed as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_resolve_callback_t, on_resolve_completed),
         40usize,
         concat!(
             "Offset of field: ",
@@ -12542,7 +11871,7 @@ fn bindgen_test_layout__cef_request_context_t() {
         concat!("Alignment of ", stringify!(_cef_request_context_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_request_context_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_request_context_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -12552,7 +11881,7 @@ fn bindgen_test_layout__cef_request_context_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_request_context_t>())).is_same as *const _ as usize },
+        ::std::mem::offset_of!(_cef_request_context_t, is_same),
         40usize,
         concat!(
             "Offset of field: ",
@@ -12562,9 +11891,7 @@ fn bindgen_test_layout__cef_request_context_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_request_context_t>())).is_sharing_with as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_request_context_t, is_sharing_with),
         48usize,
         concat!(
             "Offset of field: ",
@@ -12574,9 +11901,7 @@ fn bindgen_test_layout__cef_request_context_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_request_context_t>())).is_global as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_request_context_t, is_global),
         56usize,
         concat!(
             "Offset of field: ",
@@ -12586,9 +11911,7 @@ fn bindgen_test_layout__cef_request_context_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_request_context_t>())).get_handler as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_request_context_t, get_handler),
         64usize,
         concat!(
             "Offset of field: ",
@@ -12598,9 +11921,7 @@ fn bindgen_test_layout__cef_request_context_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_request_context_t>())).get_cache_path as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_request_context_t, get_cache_path),
         72usize,
         concat!(
             "Offset of field: ",
@@ -12610,10 +11931,7 @@ fn bindgen_test_layout__cef_request_context_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_request_context_t>())).get_cookie_manager as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_request_context_t, get_cookie_manager),
         80usize,
         concat!(
             "Offset of field: ",
@@ -12623,10 +11941,7 @@ fn bindgen_test_layout__cef_request_context_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_request_context_t>())).register_scheme_handler_factory
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_request_context_t, register_scheme_handler_factory),
         88usize,
         concat!(
             "Offset of field: ",
@@ -12636,10 +11951,7 @@ fn bindgen_test_layout__cef_request_context_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_request_context_t>())).clear_scheme_handler_factories
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_request_context_t, clear_scheme_handler_factories),
         96usize,
         concat!(
             "Offset of field: ",
@@ -12649,10 +11961,7 @@ fn bindgen_test_layout__cef_request_context_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_request_context_t>())).purge_plugin_list_cache as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_request_context_t, purge_plugin_list_cache),
         104usize,
         concat!(
             "Offset of field: ",
@@ -12662,9 +11971,7 @@ fn bindgen_test_layout__cef_request_context_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_request_context_t>())).has_preference as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_request_context_t, has_preference),
         112usize,
         concat!(
             "Offset of field: ",
@@ -12674,9 +11981,7 @@ fn bindgen_test_layout__cef_request_context_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_request_context_t>())).get_preference as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_request_context_t, get_preference),
         120usize,
         concat!(
             "Offset of field: ",
@@ -12686,10 +11991,7 @@ fn bindgen_test_layout__cef_request_context_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_request_context_t>())).get_all_preferences as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_request_context_t, get_all_preferences),
         128usize,
         concat!(
             "Offset of field: ",
@@ -12699,10 +12001,7 @@ fn bindgen_test_layout__cef_request_context_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_request_context_t>())).can_set_preference as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_request_context_t, can_set_preference),
         136usize,
         concat!(
             "Offset of field: ",
@@ -12712,9 +12011,7 @@ fn bindgen_test_layout__cef_request_context_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_request_context_t>())).set_preference as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_request_context_t, set_preference),
         144usize,
         concat!(
             "Offset of field: ",
@@ -12724,10 +12021,7 @@ fn bindgen_test_layout__cef_request_context_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_request_context_t>())).clear_certificate_exceptions
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_request_context_t, clear_certificate_exceptions),
         152usize,
         concat!(
             "Offset of field: ",
@@ -12737,10 +12031,7 @@ fn bindgen_test_layout__cef_request_context_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_request_context_t>())).clear_http_auth_credentials
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_request_context_t, clear_http_auth_credentials),
         160usize,
         concat!(
             "Offset of field: ",
@@ -12750,10 +12041,7 @@ fn bindgen_test_layout__cef_request_context_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_request_context_t>())).close_all_connections as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_request_context_t, close_all_connections),
         168usize,
         concat!(
             "Offset of field: ",
@@ -12763,9 +12051,7 @@ fn bindgen_test_layout__cef_request_context_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_request_context_t>())).resolve_host as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_request_context_t, resolve_host),
         176usize,
         concat!(
             "Offset of field: ",
@@ -12775,9 +12061,7 @@ fn bindgen_test_layout__cef_request_context_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_request_context_t>())).load_extension as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_request_context_t, load_extension),
         184usize,
         concat!(
             "Offset of field: ",
@@ -12787,10 +12071,7 @@ fn bindgen_test_layout__cef_request_context_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_request_context_t>())).did_load_extension as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_request_context_t, did_load_extension),
         192usize,
         concat!(
             "Offset of field: ",
@@ -12800,9 +12081,7 @@ fn bindgen_test_layout__cef_request_context_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_request_context_t>())).has_extension as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_request_context_t, has_extension),
         200usize,
         concat!(
             "Offset of field: ",
@@ -12812,9 +12091,7 @@ fn bindgen_test_layout__cef_request_context_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_request_context_t>())).get_extensions as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_request_context_t, get_extensions),
         208usize,
         concat!(
             "Offset of field: ",
@@ -12824,9 +12101,7 @@ fn bindgen_test_layout__cef_request_context_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_request_context_t>())).get_extension as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_request_context_t, get_extension),
         216usize,
         concat!(
             "Offset of field: ",
@@ -12836,9 +12111,7 @@ fn bindgen_test_layout__cef_request_context_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_request_context_t>())).get_media_router as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_request_context_t, get_media_router),
         224usize,
         concat!(
             "Offset of field: ",
@@ -12942,7 +12215,7 @@ fn bindgen_test_layout__cef_browser_t() {
         concat!("Alignment of ", stringify!(_cef_browser_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_browser_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_browser_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -12952,7 +12225,7 @@ fn bindgen_test_layout__cef_browser_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_browser_t>())).get_host as *const _ as usize },
+        ::std::mem::offset_of!(_cef_browser_t, get_host),
         40usize,
         concat!(
             "Offset of field: ",
@@ -12962,7 +12235,7 @@ fn bindgen_test_layout__cef_browser_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_browser_t>())).can_go_back as *const _ as usize },
+        ::std::mem::offset_of!(_cef_browser_t, can_go_back),
         48usize,
         concat!(
             "Offset of field: ",
@@ -12972,7 +12245,7 @@ fn bindgen_test_layout__cef_browser_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_browser_t>())).go_back as *const _ as usize },
+        ::std::mem::offset_of!(_cef_browser_t, go_back),
         56usize,
         concat!(
             "Offset of field: ",
@@ -12982,7 +12255,7 @@ fn bindgen_test_layout__cef_browser_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_browser_t>())).can_go_forward as *const _ as usize },
+        ::std::mem::offset_of!(_cef_browser_t, can_go_forward),
         64usize,
         concat!(
             "Offset of field: ",
@@ -12992,7 +12265,7 @@ fn bindgen_test_layout__cef_browser_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_browser_t>())).go_forward as *const _ as usize },
+        ::std::mem::offset_of!(_cef_browser_t, go_forward),
         72usize,
         concat!(
             "Offset of field: ",
@@ -13002,7 +12275,7 @@ fn bindgen_test_layout__cef_browser_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_browser_t>())).is_loading as *const _ as usize },
+        ::std::mem::offset_of!(_cef_browser_t, is_loading),
         80usize,
         concat!(
             "Offset of field: ",
@@ -13012,7 +12285,7 @@ fn bindgen_test_layout__cef_browser_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_browser_t>())).reload as *const _ as usize },
+        ::std::mem::offset_of!(_cef_browser_t, reload),
         88usize,
         concat!(
             "Offset of field: ",
@@ -13022,9 +12295,7 @@ fn bindgen_test_layout__cef_browser_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_t>())).reload_ignore_cache as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_t, reload_ignore_cache),
         96usize,
         concat!(
             "Offset of field: ",
@@ -13034,7 +12305,7 @@ fn bindgen_test_layout__cef_browser_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_browser_t>())).stop_load as *const _ as usize },
+        ::std::mem::offset_of!(_cef_browser_t, stop_load),
         104usize,
         concat!(
             "Offset of field: ",
@@ -13044,7 +12315,7 @@ fn bindgen_test_layout__cef_browser_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_browser_t>())).get_identifier as *const _ as usize },
+        ::std::mem::offset_of!(_cef_browser_t, get_identifier),
         112usize,
         concat!(
             "Offset of field: ",
@@ -13054,7 +12325,7 @@ fn bindgen_test_layout__cef_browser_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_browser_t>())).is_same as *const _ as usize },
+        ::std::mem::offset_of!(_cef_browser_t, is_same),
         120usize,
         concat!(
             "Offset of field: ",
@@ -13064,7 +12335,7 @@ fn bindgen_test_layout__cef_browser_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_browser_t>())).is_popup as *const _ as usize },
+        ::std::mem::offset_of!(_cef_browser_t, is_popup),
         128usize,
         concat!(
             "Offset of field: ",
@@ -13074,7 +12345,7 @@ fn bindgen_test_layout__cef_browser_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_browser_t>())).has_document as *const _ as usize },
+        ::std::mem::offset_of!(_cef_browser_t, has_document),
         136usize,
         concat!(
             "Offset of field: ",
@@ -13084,7 +12355,7 @@ fn bindgen_test_layout__cef_browser_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_browser_t>())).get_main_frame as *const _ as usize },
+        ::std::mem::offset_of!(_cef_browser_t, get_main_frame),
         144usize,
         concat!(
             "Offset of field: ",
@@ -13094,9 +12365,7 @@ fn bindgen_test_layout__cef_browser_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_t>())).get_focused_frame as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_t, get_focused_frame),
         152usize,
         concat!(
             "Offset of field: ",
@@ -13106,9 +12375,7 @@ fn bindgen_test_layout__cef_browser_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_t>())).get_frame_byident as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_t, get_frame_byident),
         160usize,
         concat!(
             "Offset of field: ",
@@ -13118,7 +12385,7 @@ fn bindgen_test_layout__cef_browser_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_browser_t>())).get_frame as *const _ as usize },
+        ::std::mem::offset_of!(_cef_browser_t, get_frame),
         168usize,
         concat!(
             "Offset of field: ",
@@ -13128,7 +12395,7 @@ fn bindgen_test_layout__cef_browser_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_browser_t>())).get_frame_count as *const _ as usize },
+        ::std::mem::offset_of!(_cef_browser_t, get_frame_count),
         176usize,
         concat!(
             "Offset of field: ",
@@ -13138,9 +12405,7 @@ fn bindgen_test_layout__cef_browser_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_t>())).get_frame_identifiers as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_t, get_frame_identifiers),
         184usize,
         concat!(
             "Offset of field: ",
@@ -13150,7 +12415,7 @@ fn bindgen_test_layout__cef_browser_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_browser_t>())).get_frame_names as *const _ as usize },
+        ::std::mem::offset_of!(_cef_browser_t, get_frame_names),
         192usize,
         concat!(
             "Offset of field: ",
@@ -13186,9 +12451,7 @@ fn bindgen_test_layout__cef_run_file_dialog_callback_t() {
         concat!("Alignment of ", stringify!(_cef_run_file_dialog_callback_t))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_run_file_dialog_callback_t>())).base as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_run_file_dialog_callback_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -13198,10 +12461,7 @@ fn bindgen_test_layout__cef_run_file_dialog_callback_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_run_file_dialog_callback_t>())).on_file_dialog_dismissed
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_run_file_dialog_callback_t, on_file_dialog_dismissed),
         40usize,
         concat!(
             "Offset of field: ",
@@ -13239,9 +12499,7 @@ fn bindgen_test_layout__cef_navigation_entry_visitor_t() {
         concat!("Alignment of ", stringify!(_cef_navigation_entry_visitor_t))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_navigation_entry_visitor_t>())).base as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_navigation_entry_visitor_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -13251,9 +12509,7 @@ fn bindgen_test_layout__cef_navigation_entry_visitor_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_navigation_entry_visitor_t>())).visit as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_navigation_entry_visitor_t, visit),
         40usize,
         concat!(
             "Offset of field: ",
@@ -13289,7 +12545,7 @@ fn bindgen_test_layout__cef_pdf_print_callback_t() {
         concat!("Alignment of ", stringify!(_cef_pdf_print_callback_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_pdf_print_callback_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_pdf_print_callback_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -13299,10 +12555,7 @@ fn bindgen_test_layout__cef_pdf_print_callback_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_pdf_print_callback_t>())).on_pdf_print_finished as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_pdf_print_callback_t, on_pdf_print_finished),
         40usize,
         concat!(
             "Offset of field: ",
@@ -13339,9 +12592,7 @@ fn bindgen_test_layout__cef_download_image_callback_t() {
         concat!("Alignment of ", stringify!(_cef_download_image_callback_t))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_download_image_callback_t>())).base as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_download_image_callback_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -13351,10 +12602,7 @@ fn bindgen_test_layout__cef_download_image_callback_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_download_image_callback_t>())).on_download_image_finished
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_download_image_callback_t, on_download_image_finished),
         40usize,
         concat!(
             "Offset of field: ",
@@ -13663,7 +12911,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         concat!("Alignment of ", stringify!(_cef_browser_host_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_browser_host_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_browser_host_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -13673,7 +12921,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_browser_host_t>())).get_browser as *const _ as usize },
+        ::std::mem::offset_of!(_cef_browser_host_t, get_browser),
         40usize,
         concat!(
             "Offset of field: ",
@@ -13683,9 +12931,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_host_t>())).close_browser as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_host_t, close_browser),
         48usize,
         concat!(
             "Offset of field: ",
@@ -13695,9 +12941,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_host_t>())).try_close_browser as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_host_t, try_close_browser),
         56usize,
         concat!(
             "Offset of field: ",
@@ -13707,7 +12951,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_browser_host_t>())).set_focus as *const _ as usize },
+        ::std::mem::offset_of!(_cef_browser_host_t, set_focus),
         64usize,
         concat!(
             "Offset of field: ",
@@ -13717,9 +12961,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_host_t>())).get_window_handle as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_host_t, get_window_handle),
         72usize,
         concat!(
             "Offset of field: ",
@@ -13729,10 +12971,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_host_t>())).get_opener_window_handle as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_host_t, get_opener_window_handle),
         80usize,
         concat!(
             "Offset of field: ",
@@ -13742,7 +12981,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_browser_host_t>())).has_view as *const _ as usize },
+        ::std::mem::offset_of!(_cef_browser_host_t, has_view),
         88usize,
         concat!(
             "Offset of field: ",
@@ -13752,7 +12991,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_browser_host_t>())).get_client as *const _ as usize },
+        ::std::mem::offset_of!(_cef_browser_host_t, get_client),
         96usize,
         concat!(
             "Offset of field: ",
@@ -13762,9 +13001,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_host_t>())).get_request_context as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_host_t, get_request_context),
         104usize,
         concat!(
             "Offset of field: ",
@@ -13774,9 +13011,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_host_t>())).get_zoom_level as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_host_t, get_zoom_level),
         112usize,
         concat!(
             "Offset of field: ",
@@ -13786,9 +13021,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_host_t>())).set_zoom_level as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_host_t, set_zoom_level),
         120usize,
         concat!(
             "Offset of field: ",
@@ -13798,9 +13031,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_host_t>())).run_file_dialog as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_host_t, run_file_dialog),
         128usize,
         concat!(
             "Offset of field: ",
@@ -13810,9 +13041,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_host_t>())).start_download as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_host_t, start_download),
         136usize,
         concat!(
             "Offset of field: ",
@@ -13822,9 +13051,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_host_t>())).download_image as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_host_t, download_image),
         144usize,
         concat!(
             "Offset of field: ",
@@ -13834,7 +13061,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_browser_host_t>())).print as *const _ as usize },
+        ::std::mem::offset_of!(_cef_browser_host_t, print),
         152usize,
         concat!(
             "Offset of field: ",
@@ -13844,9 +13071,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_host_t>())).print_to_pdf as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_host_t, print_to_pdf),
         160usize,
         concat!(
             "Offset of field: ",
@@ -13856,7 +13081,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_browser_host_t>())).find as *const _ as usize },
+        ::std::mem::offset_of!(_cef_browser_host_t, find),
         168usize,
         concat!(
             "Offset of field: ",
@@ -13866,9 +13091,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_host_t>())).stop_finding as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_host_t, stop_finding),
         176usize,
         concat!(
             "Offset of field: ",
@@ -13878,9 +13101,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_host_t>())).show_dev_tools as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_host_t, show_dev_tools),
         184usize,
         concat!(
             "Offset of field: ",
@@ -13890,9 +13111,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_host_t>())).close_dev_tools as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_host_t, close_dev_tools),
         192usize,
         concat!(
             "Offset of field: ",
@@ -13902,9 +13121,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_host_t>())).has_dev_tools as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_host_t, has_dev_tools),
         200usize,
         concat!(
             "Offset of field: ",
@@ -13914,10 +13131,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_host_t>())).send_dev_tools_message as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_host_t, send_dev_tools_message),
         208usize,
         concat!(
             "Offset of field: ",
@@ -13927,10 +13141,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_host_t>())).execute_dev_tools_method as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_host_t, execute_dev_tools_method),
         216usize,
         concat!(
             "Offset of field: ",
@@ -13940,10 +13151,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_host_t>())).add_dev_tools_message_observer
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_host_t, add_dev_tools_message_observer),
         224usize,
         concat!(
             "Offset of field: ",
@@ -13953,10 +13161,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_host_t>())).get_navigation_entries as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_host_t, get_navigation_entries),
         232usize,
         concat!(
             "Offset of field: ",
@@ -13966,10 +13171,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_host_t>())).set_mouse_cursor_change_disabled
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_host_t, set_mouse_cursor_change_disabled),
         240usize,
         concat!(
             "Offset of field: ",
@@ -13979,10 +13181,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_host_t>())).is_mouse_cursor_change_disabled
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_host_t, is_mouse_cursor_change_disabled),
         248usize,
         concat!(
             "Offset of field: ",
@@ -13992,9 +13191,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_host_t>())).replace_misspelling as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_host_t, replace_misspelling),
         256usize,
         concat!(
             "Offset of field: ",
@@ -14004,10 +13201,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_host_t>())).add_word_to_dictionary as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_host_t, add_word_to_dictionary),
         264usize,
         concat!(
             "Offset of field: ",
@@ -14017,10 +13211,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_host_t>())).is_window_rendering_disabled as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_host_t, is_window_rendering_disabled),
         272usize,
         concat!(
             "Offset of field: ",
@@ -14030,7 +13221,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_browser_host_t>())).was_resized as *const _ as usize },
+        ::std::mem::offset_of!(_cef_browser_host_t, was_resized),
         280usize,
         concat!(
             "Offset of field: ",
@@ -14040,7 +13231,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_browser_host_t>())).was_hidden as *const _ as usize },
+        ::std::mem::offset_of!(_cef_browser_host_t, was_hidden),
         288usize,
         concat!(
             "Offset of field: ",
@@ -14050,10 +13241,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_host_t>())).notify_screen_info_changed as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_host_t, notify_screen_info_changed),
         296usize,
         concat!(
             "Offset of field: ",
@@ -14063,7 +13251,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_browser_host_t>())).invalidate as *const _ as usize },
+        ::std::mem::offset_of!(_cef_browser_host_t, invalidate),
         304usize,
         concat!(
             "Offset of field: ",
@@ -14073,10 +13261,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_host_t>())).send_external_begin_frame as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_host_t, send_external_begin_frame),
         312usize,
         concat!(
             "Offset of field: ",
@@ -14086,9 +13271,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_host_t>())).send_key_event as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_host_t, send_key_event),
         320usize,
         concat!(
             "Offset of field: ",
@@ -14098,10 +13281,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_host_t>())).send_mouse_click_event as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_host_t, send_mouse_click_event),
         328usize,
         concat!(
             "Offset of field: ",
@@ -14111,10 +13291,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_host_t>())).send_mouse_move_event as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_host_t, send_mouse_move_event),
         336usize,
         concat!(
             "Offset of field: ",
@@ -14124,10 +13301,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_host_t>())).send_mouse_wheel_event as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_host_t, send_mouse_wheel_event),
         344usize,
         concat!(
             "Offset of field: ",
@@ -14137,9 +13311,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_host_t>())).send_touch_event as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_host_t, send_touch_event),
         352usize,
         concat!(
             "Offset of field: ",
@@ -14149,9 +13321,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_host_t>())).send_focus_event as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_host_t, send_focus_event),
         360usize,
         concat!(
             "Offset of field: ",
@@ -14161,10 +13331,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_host_t>())).send_capture_lost_event as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_host_t, send_capture_lost_event),
         368usize,
         concat!(
             "Offset of field: ",
@@ -14174,10 +13341,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_host_t>())).notify_move_or_resize_started
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_host_t, notify_move_or_resize_started),
         376usize,
         concat!(
             "Offset of field: ",
@@ -14187,10 +13351,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_host_t>())).get_windowless_frame_rate as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_host_t, get_windowless_frame_rate),
         384usize,
         concat!(
             "Offset of field: ",
@@ -14200,10 +13361,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_host_t>())).set_windowless_frame_rate as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_host_t, set_windowless_frame_rate),
         392usize,
         concat!(
             "Offset of field: ",
@@ -14213,9 +13371,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_host_t>())).ime_set_composition as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_host_t, ime_set_composition),
         400usize,
         concat!(
             "Offset of field: ",
@@ -14225,9 +13381,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_host_t>())).ime_commit_text as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_host_t, ime_commit_text),
         408usize,
         concat!(
             "Offset of field: ",
@@ -14237,10 +13391,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_host_t>())).ime_finish_composing_text as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_host_t, ime_finish_composing_text),
         416usize,
         concat!(
             "Offset of field: ",
@@ -14250,10 +13401,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_host_t>())).ime_cancel_composition as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_host_t, ime_cancel_composition),
         424usize,
         concat!(
             "Offset of field: ",
@@ -14263,10 +13411,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_host_t>())).drag_target_drag_enter as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_host_t, drag_target_drag_enter),
         432usize,
         concat!(
             "Offset of field: ",
@@ -14276,10 +13421,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_host_t>())).drag_target_drag_over as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_host_t, drag_target_drag_over),
         440usize,
         concat!(
             "Offset of field: ",
@@ -14289,10 +13431,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_host_t>())).drag_target_drag_leave as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_host_t, drag_target_drag_leave),
         448usize,
         concat!(
             "Offset of field: ",
@@ -14302,9 +13441,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_host_t>())).drag_target_drop as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_host_t, drag_target_drop),
         456usize,
         concat!(
             "Offset of field: ",
@@ -14314,10 +13451,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_host_t>())).drag_source_ended_at as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_host_t, drag_source_ended_at),
         464usize,
         concat!(
             "Offset of field: ",
@@ -14327,10 +13461,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_host_t>())).drag_source_system_drag_ended
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_host_t, drag_source_system_drag_ended),
         472usize,
         concat!(
             "Offset of field: ",
@@ -14340,10 +13471,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_host_t>())).get_visible_navigation_entry as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_host_t, get_visible_navigation_entry),
         480usize,
         concat!(
             "Offset of field: ",
@@ -14353,10 +13481,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_host_t>())).set_accessibility_state as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_host_t, set_accessibility_state),
         488usize,
         concat!(
             "Offset of field: ",
@@ -14366,10 +13491,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_host_t>())).set_auto_resize_enabled as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_host_t, set_auto_resize_enabled),
         496usize,
         concat!(
             "Offset of field: ",
@@ -14379,9 +13501,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_host_t>())).get_extension as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_host_t, get_extension),
         504usize,
         concat!(
             "Offset of field: ",
@@ -14391,9 +13511,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_host_t>())).is_background_host as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_host_t, is_background_host),
         512usize,
         concat!(
             "Offset of field: ",
@@ -14403,9 +13521,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_host_t>())).set_audio_muted as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_host_t, set_audio_muted),
         520usize,
         concat!(
             "Offset of field: ",
@@ -14415,9 +13531,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_host_t>())).is_audio_muted as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_host_t, is_audio_muted),
         528usize,
         concat!(
             "Offset of field: ",
@@ -14547,7 +13661,7 @@ fn bindgen_test_layout__cef_print_settings_t() {
         concat!("Alignment of ", stringify!(_cef_print_settings_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_print_settings_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_print_settings_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -14557,7 +13671,7 @@ fn bindgen_test_layout__cef_print_settings_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_print_settings_t>())).is_valid as *const _ as usize },
+        ::std::mem::offset_of!(_cef_print_settings_t, is_valid),
         40usize,
         concat!(
             "Offset of field: ",
@@ -14567,9 +13681,7 @@ fn bindgen_test_layout__cef_print_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_print_settings_t>())).is_read_only as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_print_settings_t, is_read_only),
         48usize,
         concat!(
             "Offset of field: ",
@@ -14579,9 +13691,7 @@ fn bindgen_test_layout__cef_print_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_print_settings_t>())).set_orientation as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_print_settings_t, set_orientation),
         56usize,
         concat!(
             "Offset of field: ",
@@ -14591,9 +13701,7 @@ fn bindgen_test_layout__cef_print_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_print_settings_t>())).is_landscape as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_print_settings_t, is_landscape),
         64usize,
         concat!(
             "Offset of field: ",
@@ -14603,10 +13711,7 @@ fn bindgen_test_layout__cef_print_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_print_settings_t>())).set_printer_printable_area as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_print_settings_t, set_printer_printable_area),
         72usize,
         concat!(
             "Offset of field: ",
@@ -14616,9 +13721,7 @@ fn bindgen_test_layout__cef_print_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_print_settings_t>())).set_device_name as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_print_settings_t, set_device_name),
         80usize,
         concat!(
             "Offset of field: ",
@@ -14628,9 +13731,7 @@ fn bindgen_test_layout__cef_print_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_print_settings_t>())).get_device_name as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_print_settings_t, get_device_name),
         88usize,
         concat!(
             "Offset of field: ",
@@ -14640,7 +13741,7 @@ fn bindgen_test_layout__cef_print_settings_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_print_settings_t>())).set_dpi as *const _ as usize },
+        ::std::mem::offset_of!(_cef_print_settings_t, set_dpi),
         96usize,
         concat!(
             "Offset of field: ",
@@ -14650,7 +13751,7 @@ fn bindgen_test_layout__cef_print_settings_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_print_settings_t>())).get_dpi as *const _ as usize },
+        ::std::mem::offset_of!(_cef_print_settings_t, get_dpi),
         104usize,
         concat!(
             "Offset of field: ",
@@ -14660,9 +13761,7 @@ fn bindgen_test_layout__cef_print_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_print_settings_t>())).set_page_ranges as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_print_settings_t, set_page_ranges),
         112usize,
         concat!(
             "Offset of field: ",
@@ -14672,10 +13771,7 @@ fn bindgen_test_layout__cef_print_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_print_settings_t>())).get_page_ranges_count as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_print_settings_t, get_page_ranges_count),
         120usize,
         concat!(
             "Offset of field: ",
@@ -14685,9 +13781,7 @@ fn bindgen_test_layout__cef_print_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_print_settings_t>())).get_page_ranges as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_print_settings_t, get_page_ranges),
         128usize,
         concat!(
             "Offset of field: ",
@@ -14697,10 +13791,7 @@ fn bindgen_test_layout__cef_print_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_print_settings_t>())).set_selection_only as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_print_settings_t, set_selection_only),
         136usize,
         concat!(
             "Offset of field: ",
@@ -14710,9 +13801,7 @@ fn bindgen_test_layout__cef_print_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_print_settings_t>())).is_selection_only as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_print_settings_t, is_selection_only),
         144usize,
         concat!(
             "Offset of field: ",
@@ -14722,9 +13811,7 @@ fn bindgen_test_layout__cef_print_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_print_settings_t>())).set_collate as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_print_settings_t, set_collate),
         152usize,
         concat!(
             "Offset of field: ",
@@ -14734,9 +13821,7 @@ fn bindgen_test_layout__cef_print_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_print_settings_t>())).will_collate as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_print_settings_t, will_collate),
         160usize,
         concat!(
             "Offset of field: ",
@@ -14746,9 +13831,7 @@ fn bindgen_test_layout__cef_print_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_print_settings_t>())).set_color_model as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_print_settings_t, set_color_model),
         168usize,
         concat!(
             "Offset of field: ",
@@ -14758,9 +13841,7 @@ fn bindgen_test_layout__cef_print_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_print_settings_t>())).get_color_model as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_print_settings_t, get_color_model),
         176usize,
         concat!(
             "Offset of field: ",
@@ -14770,9 +13851,7 @@ fn bindgen_test_layout__cef_print_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_print_settings_t>())).set_copies as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_print_settings_t, set_copies),
         184usize,
         concat!(
             "Offset of field: ",
@@ -14782,9 +13861,7 @@ fn bindgen_test_layout__cef_print_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_print_settings_t>())).get_copies as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_print_settings_t, get_copies),
         192usize,
         concat!(
             "Offset of field: ",
@@ -14794,9 +13871,7 @@ fn bindgen_test_layout__cef_print_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_print_settings_t>())).set_duplex_mode as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_print_settings_t, set_duplex_mode),
         200usize,
         concat!(
             "Offset of field: ",
@@ -14806,9 +13881,7 @@ fn bindgen_test_layout__cef_print_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_print_settings_t>())).get_duplex_mode as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_print_settings_t, get_duplex_mode),
         208usize,
         concat!(
             "Offset of field: ",
@@ -14848,9 +13921,7 @@ fn bindgen_test_layout__cef_print_dialog_callback_t() {
         concat!("Alignment of ", stringify!(_cef_print_dialog_callback_t))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_print_dialog_callback_t>())).base as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_print_dialog_callback_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -14860,9 +13931,7 @@ fn bindgen_test_layout__cef_print_dialog_callback_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_print_dialog_callback_t>())).cont as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_print_dialog_callback_t, cont),
         40usize,
         concat!(
             "Offset of field: ",
@@ -14872,9 +13941,7 @@ fn bindgen_test_layout__cef_print_dialog_callback_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_print_dialog_callback_t>())).cancel as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_print_dialog_callback_t, cancel),
         48usize,
         concat!(
             "Offset of field: ",
@@ -14904,7 +13971,7 @@ fn bindgen_test_layout__cef_print_job_callback_t() {
         concat!("Alignment of ", stringify!(_cef_print_job_callback_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_print_job_callback_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_print_job_callback_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -14914,7 +13981,7 @@ fn bindgen_test_layout__cef_print_job_callback_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_print_job_callback_t>())).cont as *const _ as usize },
+        ::std::mem::offset_of!(_cef_print_job_callback_t, cont),
         40usize,
         concat!(
             "Offset of field: ",
@@ -14980,7 +14047,7 @@ fn bindgen_test_layout__cef_print_handler_t() {
         concat!("Alignment of ", stringify!(_cef_print_handler_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_print_handler_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_print_handler_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -14990,9 +14057,7 @@ fn bindgen_test_layout__cef_print_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_print_handler_t>())).on_print_start as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_print_handler_t, on_print_start),
         40usize,
         concat!(
             "Offset of field: ",
@@ -15002,9 +14067,7 @@ fn bindgen_test_layout__cef_print_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_print_handler_t>())).on_print_settings as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_print_handler_t, on_print_settings),
         48usize,
         concat!(
             "Offset of field: ",
@@ -15014,9 +14077,7 @@ fn bindgen_test_layout__cef_print_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_print_handler_t>())).on_print_dialog as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_print_handler_t, on_print_dialog),
         56usize,
         concat!(
             "Offset of field: ",
@@ -15026,9 +14087,7 @@ fn bindgen_test_layout__cef_print_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_print_handler_t>())).on_print_job as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_print_handler_t, on_print_job),
         64usize,
         concat!(
             "Offset of field: ",
@@ -15038,9 +14097,7 @@ fn bindgen_test_layout__cef_print_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_print_handler_t>())).on_print_reset as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_print_handler_t, on_print_reset),
         72usize,
         concat!(
             "Offset of field: ",
@@ -15050,9 +14107,7 @@ fn bindgen_test_layout__cef_print_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_print_handler_t>())).get_pdf_paper_size as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_print_handler_t, get_pdf_paper_size),
         80usize,
         concat!(
             "Offset of field: ",
@@ -15103,9 +14158,7 @@ fn bindgen_test_layout__cef_browser_process_handler_t() {
         concat!("Alignment of ", stringify!(_cef_browser_process_handler_t))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_process_handler_t>())).base as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_process_handler_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -15115,10 +14168,7 @@ fn bindgen_test_layout__cef_browser_process_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_process_handler_t>())).on_context_initialized
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_process_handler_t, on_context_initialized),
         40usize,
         concat!(
             "Offset of field: ",
@@ -15128,10 +14178,7 @@ fn bindgen_test_layout__cef_browser_process_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_process_handler_t>()))
-                .on_before_child_process_launch as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_process_handler_t, on_before_child_process_launch),
         48usize,
         concat!(
             "Offset of field: ",
@@ -15141,10 +14188,7 @@ fn bindgen_test_layout__cef_browser_process_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_process_handler_t>()))
-                .on_render_process_thread_created as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_process_handler_t, on_render_process_thread_created),
         56usize,
         concat!(
             "Offset of field: ",
@@ -15154,10 +14198,7 @@ fn bindgen_test_layout__cef_browser_process_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_process_handler_t>())).get_print_handler as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_process_handler_t, get_print_handler),
         64usize,
         concat!(
             "Offset of field: ",
@@ -15167,10 +14208,7 @@ fn bindgen_test_layout__cef_browser_process_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_process_handler_t>())).on_schedule_message_pump_work
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_process_handler_t, on_schedule_message_pump_work),
         72usize,
         concat!(
             "Offset of field: ",
@@ -15234,7 +14272,7 @@ fn bindgen_test_layout__cef_load_handler_t() {
         concat!("Alignment of ", stringify!(_cef_load_handler_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_load_handler_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_load_handler_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -15244,10 +14282,7 @@ fn bindgen_test_layout__cef_load_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_load_handler_t>())).on_loading_state_change as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_load_handler_t, on_loading_state_change),
         40usize,
         concat!(
             "Offset of field: ",
@@ -15257,9 +14292,7 @@ fn bindgen_test_layout__cef_load_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_load_handler_t>())).on_load_start as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_load_handler_t, on_load_start),
         48usize,
         concat!(
             "Offset of field: ",
@@ -15269,7 +14302,7 @@ fn bindgen_test_layout__cef_load_handler_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_load_handler_t>())).on_load_end as *const _ as usize },
+        ::std::mem::offset_of!(_cef_load_handler_t, on_load_end),
         56usize,
         concat!(
             "Offset of field: ",
@@ -15279,9 +14312,7 @@ fn bindgen_test_layout__cef_load_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_load_handler_t>())).on_load_error as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_load_handler_t, on_load_error),
         64usize,
         concat!(
             "Offset of field: ",
@@ -15311,7 +14342,7 @@ fn bindgen_test_layout__cef_task_t() {
         concat!("Alignment of ", stringify!(_cef_task_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_task_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_task_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -15321,7 +14352,7 @@ fn bindgen_test_layout__cef_task_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_task_t>())).execute as *const _ as usize },
+        ::std::mem::offset_of!(_cef_task_t, execute),
         40usize,
         concat!(
             "Offset of field: ",
@@ -15378,7 +14409,7 @@ fn bindgen_test_layout__cef_task_runner_t() {
         concat!("Alignment of ", stringify!(_cef_task_runner_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_task_runner_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_task_runner_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -15388,7 +14419,7 @@ fn bindgen_test_layout__cef_task_runner_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_task_runner_t>())).is_same as *const _ as usize },
+        ::std::mem::offset_of!(_cef_task_runner_t, is_same),
         40usize,
         concat!(
             "Offset of field: ",
@@ -15398,10 +14429,7 @@ fn bindgen_test_layout__cef_task_runner_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_task_runner_t>())).belongs_to_current_thread as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_task_runner_t, belongs_to_current_thread),
         48usize,
         concat!(
             "Offset of field: ",
@@ -15411,9 +14439,7 @@ fn bindgen_test_layout__cef_task_runner_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_task_runner_t>())).belongs_to_thread as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_task_runner_t, belongs_to_thread),
         56usize,
         concat!(
             "Offset of field: ",
@@ -15423,7 +14449,7 @@ fn bindgen_test_layout__cef_task_runner_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_task_runner_t>())).post_task as *const _ as usize },
+        ::std::mem::offset_of!(_cef_task_runner_t, post_task),
         64usize,
         concat!(
             "Offset of field: ",
@@ -15433,9 +14459,7 @@ fn bindgen_test_layout__cef_task_runner_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_task_runner_t>())).post_delayed_task as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_task_runner_t, post_delayed_task),
         72usize,
         concat!(
             "Offset of field: ",
@@ -15525,7 +14549,7 @@ fn bindgen_test_layout__cef_v8context_t() {
         concat!("Alignment of ", stringify!(_cef_v8context_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_v8context_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_v8context_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -15535,9 +14559,7 @@ fn bindgen_test_layout__cef_v8context_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_v8context_t>())).get_task_runner as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_v8context_t, get_task_runner),
         40usize,
         concat!(
             "Offset of field: ",
@@ -15547,7 +14569,7 @@ fn bindgen_test_layout__cef_v8context_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_v8context_t>())).is_valid as *const _ as usize },
+        ::std::mem::offset_of!(_cef_v8context_t, is_valid),
         48usize,
         concat!(
             "Offset of field: ",
@@ -15557,7 +14579,7 @@ fn bindgen_test_layout__cef_v8context_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_v8context_t>())).get_browser as *const _ as usize },
+        ::std::mem::offset_of!(_cef_v8context_t, get_browser),
         56usize,
         concat!(
             "Offset of field: ",
@@ -15567,7 +14589,7 @@ fn bindgen_test_layout__cef_v8context_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_v8context_t>())).get_frame as *const _ as usize },
+        ::std::mem::offset_of!(_cef_v8context_t, get_frame),
         64usize,
         concat!(
             "Offset of field: ",
@@ -15577,7 +14599,7 @@ fn bindgen_test_layout__cef_v8context_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_v8context_t>())).get_global as *const _ as usize },
+        ::std::mem::offset_of!(_cef_v8context_t, get_global),
         72usize,
         concat!(
             "Offset of field: ",
@@ -15587,7 +14609,7 @@ fn bindgen_test_layout__cef_v8context_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_v8context_t>())).enter as *const _ as usize },
+        ::std::mem::offset_of!(_cef_v8context_t, enter),
         80usize,
         concat!(
             "Offset of field: ",
@@ -15597,7 +14619,7 @@ fn bindgen_test_layout__cef_v8context_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_v8context_t>())).exit as *const _ as usize },
+        ::std::mem::offset_of!(_cef_v8context_t, exit),
         88usize,
         concat!(
             "Offset of field: ",
@@ -15607,7 +14629,7 @@ fn bindgen_test_layout__cef_v8context_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_v8context_t>())).is_same as *const _ as usize },
+        ::std::mem::offset_of!(_cef_v8context_t, is_same),
         96usize,
         concat!(
             "Offset of field: ",
@@ -15617,7 +14639,7 @@ fn bindgen_test_layout__cef_v8context_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_v8context_t>())).eval as *const _ as usize },
+        ::std::mem::offset_of!(_cef_v8context_t, eval),
         104usize,
         concat!(
             "Offset of field: ",
@@ -15666,7 +14688,7 @@ fn bindgen_test_layout__cef_v8handler_t() {
         concat!("Alignment of ", stringify!(_cef_v8handler_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_v8handler_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_v8handler_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -15676,7 +14698,7 @@ fn bindgen_test_layout__cef_v8handler_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_v8handler_t>())).execute as *const _ as usize },
+        ::std::mem::offset_of!(_cef_v8handler_t, execute),
         40usize,
         concat!(
             "Offset of field: ",
@@ -15723,7 +14745,7 @@ fn bindgen_test_layout__cef_v8accessor_t() {
         concat!("Alignment of ", stringify!(_cef_v8accessor_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_v8accessor_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_v8accessor_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -15733,7 +14755,7 @@ fn bindgen_test_layout__cef_v8accessor_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_v8accessor_t>())).get as *const _ as usize },
+        ::std::mem::offset_of!(_cef_v8accessor_t, get),
         40usize,
         concat!(
             "Offset of field: ",
@@ -15743,7 +14765,7 @@ fn bindgen_test_layout__cef_v8accessor_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_v8accessor_t>())).set as *const _ as usize },
+        ::std::mem::offset_of!(_cef_v8accessor_t, set),
         48usize,
         concat!(
             "Offset of field: ",
@@ -15808,7 +14830,7 @@ fn bindgen_test_layout__cef_v8interceptor_t() {
         concat!("Alignment of ", stringify!(_cef_v8interceptor_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_v8interceptor_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_v8interceptor_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -15818,7 +14840,7 @@ fn bindgen_test_layout__cef_v8interceptor_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_v8interceptor_t>())).get_byname as *const _ as usize },
+        ::std::mem::offset_of!(_cef_v8interceptor_t, get_byname),
         40usize,
         concat!(
             "Offset of field: ",
@@ -15828,9 +14850,7 @@ fn bindgen_test_layout__cef_v8interceptor_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_v8interceptor_t>())).get_byindex as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_v8interceptor_t, get_byindex),
         48usize,
         concat!(
             "Offset of field: ",
@@ -15840,7 +14860,7 @@ fn bindgen_test_layout__cef_v8interceptor_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_v8interceptor_t>())).set_byname as *const _ as usize },
+        ::std::mem::offset_of!(_cef_v8interceptor_t, set_byname),
         56usize,
         concat!(
             "Offset of field: ",
@@ -15850,9 +14870,7 @@ fn bindgen_test_layout__cef_v8interceptor_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_v8interceptor_t>())).set_byindex as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_v8interceptor_t, set_byindex),
         64usize,
         concat!(
             "Offset of field: ",
@@ -15905,7 +14923,7 @@ fn bindgen_test_layout__cef_v8exception_t() {
         concat!("Alignment of ", stringify!(_cef_v8exception_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_v8exception_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_v8exception_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -15915,7 +14933,7 @@ fn bindgen_test_layout__cef_v8exception_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_v8exception_t>())).get_message as *const _ as usize },
+        ::std::mem::offset_of!(_cef_v8exception_t, get_message),
         40usize,
         concat!(
             "Offset of field: ",
@@ -15925,9 +14943,7 @@ fn bindgen_test_layout__cef_v8exception_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_v8exception_t>())).get_source_line as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_v8exception_t, get_source_line),
         48usize,
         concat!(
             "Offset of field: ",
@@ -15937,10 +14953,7 @@ fn bindgen_test_layout__cef_v8exception_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_v8exception_t>())).get_script_resource_name as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_v8exception_t, get_script_resource_name),
         56usize,
         concat!(
             "Offset of field: ",
@@ -15950,9 +14963,7 @@ fn bindgen_test_layout__cef_v8exception_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_v8exception_t>())).get_line_number as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_v8exception_t, get_line_number),
         64usize,
         concat!(
             "Offset of field: ",
@@ -15962,9 +14973,7 @@ fn bindgen_test_layout__cef_v8exception_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_v8exception_t>())).get_start_position as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_v8exception_t, get_start_position),
         72usize,
         concat!(
             "Offset of field: ",
@@ -15974,9 +14983,7 @@ fn bindgen_test_layout__cef_v8exception_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_v8exception_t>())).get_end_position as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_v8exception_t, get_end_position),
         80usize,
         concat!(
             "Offset of field: ",
@@ -15986,9 +14993,7 @@ fn bindgen_test_layout__cef_v8exception_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_v8exception_t>())).get_start_column as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_v8exception_t, get_start_column),
         88usize,
         concat!(
             "Offset of field: ",
@@ -15998,9 +15003,7 @@ fn bindgen_test_layout__cef_v8exception_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_v8exception_t>())).get_end_column as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_v8exception_t, get_end_column),
         96usize,
         concat!(
             "Offset of field: ",
@@ -16041,10 +15044,7 @@ fn bindgen_test_layout__cef_v8array_buffer_release_callback_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_v8array_buffer_release_callback_t>())).base as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_v8array_buffer_release_callback_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -16054,10 +15054,7 @@ fn bindgen_test_layout__cef_v8array_buffer_release_callback_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_v8array_buffer_release_callback_t>())).release_buffer
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_v8array_buffer_release_callback_t, release_buffer),
         40usize,
         concat!(
             "Offset of field: ",
@@ -16283,7 +15280,7 @@ fn bindgen_test_layout__cef_v8value_t() {
         concat!("Alignment of ", stringify!(_cef_v8value_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_v8value_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_v8value_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -16293,7 +15290,7 @@ fn bindgen_test_layout__cef_v8value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_v8value_t>())).is_valid as *const _ as usize },
+        ::std::mem::offset_of!(_cef_v8value_t, is_valid),
         40usize,
         concat!(
             "Offset of field: ",
@@ -16303,7 +15300,7 @@ fn bindgen_test_layout__cef_v8value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_v8value_t>())).is_undefined as *const _ as usize },
+        ::std::mem::offset_of!(_cef_v8value_t, is_undefined),
         48usize,
         concat!(
             "Offset of field: ",
@@ -16313,7 +15310,7 @@ fn bindgen_test_layout__cef_v8value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_v8value_t>())).is_null as *const _ as usize },
+        ::std::mem::offset_of!(_cef_v8value_t, is_null),
         56usize,
         concat!(
             "Offset of field: ",
@@ -16323,7 +15320,7 @@ fn bindgen_test_layout__cef_v8value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_v8value_t>())).is_bool as *const _ as usize },
+        ::std::mem::offset_of!(_cef_v8value_t, is_bool),
         64usize,
         concat!(
             "Offset of field: ",
@@ -16333,7 +15330,7 @@ fn bindgen_test_layout__cef_v8value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_v8value_t>())).is_int as *const _ as usize },
+        ::std::mem::offset_of!(_cef_v8value_t, is_int),
         72usize,
         concat!(
             "Offset of field: ",
@@ -16343,7 +15340,7 @@ fn bindgen_test_layout__cef_v8value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_v8value_t>())).is_uint as *const _ as usize },
+        ::std::mem::offset_of!(_cef_v8value_t, is_uint),
         80usize,
         concat!(
             "Offset of field: ",
@@ -16353,7 +15350,7 @@ fn bindgen_test_layout__cef_v8value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_v8value_t>())).is_double as *const _ as usize },
+        ::std::mem::offset_of!(_cef_v8value_t, is_double),
         88usize,
         concat!(
             "Offset of field: ",
@@ -16363,7 +15360,7 @@ fn bindgen_test_layout__cef_v8value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_v8value_t>())).is_date as *const _ as usize },
+        ::std::mem::offset_of!(_cef_v8value_t, is_date),
         96usize,
         concat!(
             "Offset of field: ",
@@ -16373,7 +15370,7 @@ fn bindgen_test_layout__cef_v8value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_v8value_t>())).is_string as *const _ as usize },
+        ::std::mem::offset_of!(_cef_v8value_t, is_string),
         104usize,
         concat!(
             "Offset of field: ",
@@ -16383,7 +15380,7 @@ fn bindgen_test_layout__cef_v8value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_v8value_t>())).is_object as *const _ as usize },
+        ::std::mem::offset_of!(_cef_v8value_t, is_object),
         112usize,
         concat!(
             "Offset of field: ",
@@ -16393,7 +15390,7 @@ fn bindgen_test_layout__cef_v8value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_v8value_t>())).is_array as *const _ as usize },
+        ::std::mem::offset_of!(_cef_v8value_t, is_array),
         120usize,
         concat!(
             "Offset of field: ",
@@ -16403,7 +15400,7 @@ fn bindgen_test_layout__cef_v8value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_v8value_t>())).is_array_buffer as *const _ as usize },
+        ::std::mem::offset_of!(_cef_v8value_t, is_array_buffer),
         128usize,
         concat!(
             "Offset of field: ",
@@ -16413,7 +15410,7 @@ fn bindgen_test_layout__cef_v8value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_v8value_t>())).is_function as *const _ as usize },
+        ::std::mem::offset_of!(_cef_v8value_t, is_function),
         136usize,
         concat!(
             "Offset of field: ",
@@ -16423,7 +15420,7 @@ fn bindgen_test_layout__cef_v8value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_v8value_t>())).is_same as *const _ as usize },
+        ::std::mem::offset_of!(_cef_v8value_t, is_same),
         144usize,
         concat!(
             "Offset of field: ",
@@ -16433,7 +15430,7 @@ fn bindgen_test_layout__cef_v8value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_v8value_t>())).get_bool_value as *const _ as usize },
+        ::std::mem::offset_of!(_cef_v8value_t, get_bool_value),
         152usize,
         concat!(
             "Offset of field: ",
@@ -16443,7 +15440,7 @@ fn bindgen_test_layout__cef_v8value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_v8value_t>())).get_int_value as *const _ as usize },
+        ::std::mem::offset_of!(_cef_v8value_t, get_int_value),
         160usize,
         concat!(
             "Offset of field: ",
@@ -16453,7 +15450,7 @@ fn bindgen_test_layout__cef_v8value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_v8value_t>())).get_uint_value as *const _ as usize },
+        ::std::mem::offset_of!(_cef_v8value_t, get_uint_value),
         168usize,
         concat!(
             "Offset of field: ",
@@ -16463,7 +15460,7 @@ fn bindgen_test_layout__cef_v8value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_v8value_t>())).get_double_value as *const _ as usize },
+        ::std::mem::offset_of!(_cef_v8value_t, get_double_value),
         176usize,
         concat!(
             "Offset of field: ",
@@ -16473,7 +15470,7 @@ fn bindgen_test_layout__cef_v8value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_v8value_t>())).get_date_value as *const _ as usize },
+        ::std::mem::offset_of!(_cef_v8value_t, get_date_value),
         184usize,
         concat!(
             "Offset of field: ",
@@ -16483,7 +15480,7 @@ fn bindgen_test_layout__cef_v8value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_v8value_t>())).get_string_value as *const _ as usize },
+        ::std::mem::offset_of!(_cef_v8value_t, get_string_value),
         192usize,
         concat!(
             "Offset of field: ",
@@ -16493,7 +15490,7 @@ fn bindgen_test_layout__cef_v8value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_v8value_t>())).is_user_created as *const _ as usize },
+        ::std::mem::offset_of!(_cef_v8value_t, is_user_created),
         200usize,
         concat!(
             "Offset of field: ",
@@ -16503,7 +15500,7 @@ fn bindgen_test_layout__cef_v8value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_v8value_t>())).has_exception as *const _ as usize },
+        ::std::mem::offset_of!(_cef_v8value_t, has_exception),
         208usize,
         concat!(
             "Offset of field: ",
@@ -16513,7 +15510,7 @@ fn bindgen_test_layout__cef_v8value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_v8value_t>())).get_exception as *const _ as usize },
+        ::std::mem::offset_of!(_cef_v8value_t, get_exception),
         216usize,
         concat!(
             "Offset of field: ",
@@ -16523,7 +15520,7 @@ fn bindgen_test_layout__cef_v8value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_v8value_t>())).clear_exception as *const _ as usize },
+        ::std::mem::offset_of!(_cef_v8value_t, clear_exception),
         224usize,
         concat!(
             "Offset of field: ",
@@ -16533,9 +15530,7 @@ fn bindgen_test_layout__cef_v8value_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_v8value_t>())).will_rethrow_exceptions as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_v8value_t, will_rethrow_exceptions),
         232usize,
         concat!(
             "Offset of field: ",
@@ -16545,9 +15540,7 @@ fn bindgen_test_layout__cef_v8value_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_v8value_t>())).set_rethrow_exceptions as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_v8value_t, set_rethrow_exceptions),
         240usize,
         concat!(
             "Offset of field: ",
@@ -16557,7 +15550,7 @@ fn bindgen_test_layout__cef_v8value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_v8value_t>())).has_value_bykey as *const _ as usize },
+        ::std::mem::offset_of!(_cef_v8value_t, has_value_bykey),
         248usize,
         concat!(
             "Offset of field: ",
@@ -16567,9 +15560,7 @@ fn bindgen_test_layout__cef_v8value_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_v8value_t>())).has_value_byindex as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_v8value_t, has_value_byindex),
         256usize,
         concat!(
             "Offset of field: ",
@@ -16579,9 +15570,7 @@ fn bindgen_test_layout__cef_v8value_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_v8value_t>())).delete_value_bykey as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_v8value_t, delete_value_bykey),
         264usize,
         concat!(
             "Offset of field: ",
@@ -16591,9 +15580,7 @@ fn bindgen_test_layout__cef_v8value_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_v8value_t>())).delete_value_byindex as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_v8value_t, delete_value_byindex),
         272usize,
         concat!(
             "Offset of field: ",
@@ -16603,7 +15590,7 @@ fn bindgen_test_layout__cef_v8value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_v8value_t>())).get_value_bykey as *const _ as usize },
+        ::std::mem::offset_of!(_cef_v8value_t, get_value_bykey),
         280usize,
         concat!(
             "Offset of field: ",
@@ -16613,9 +15600,7 @@ fn bindgen_test_layout__cef_v8value_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_v8value_t>())).get_value_byindex as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_v8value_t, get_value_byindex),
         288usize,
         concat!(
             "Offset of field: ",
@@ -16625,7 +15610,7 @@ fn bindgen_test_layout__cef_v8value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_v8value_t>())).set_value_bykey as *const _ as usize },
+        ::std::mem::offset_of!(_cef_v8value_t, set_value_bykey),
         296usize,
         concat!(
             "Offset of field: ",
@@ -16635,9 +15620,7 @@ fn bindgen_test_layout__cef_v8value_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_v8value_t>())).set_value_byindex as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_v8value_t, set_value_byindex),
         304usize,
         concat!(
             "Offset of field: ",
@@ -16647,9 +15630,7 @@ fn bindgen_test_layout__cef_v8value_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_v8value_t>())).set_value_byaccessor as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_v8value_t, set_value_byaccessor),
         312usize,
         concat!(
             "Offset of field: ",
@@ -16659,7 +15640,7 @@ fn bindgen_test_layout__cef_v8value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_v8value_t>())).get_keys as *const _ as usize },
+        ::std::mem::offset_of!(_cef_v8value_t, get_keys),
         320usize,
         concat!(
             "Offset of field: ",
@@ -16669,7 +15650,7 @@ fn bindgen_test_layout__cef_v8value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_v8value_t>())).set_user_data as *const _ as usize },
+        ::std::mem::offset_of!(_cef_v8value_t, set_user_data),
         328usize,
         concat!(
             "Offset of field: ",
@@ -16679,7 +15660,7 @@ fn bindgen_test_layout__cef_v8value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_v8value_t>())).get_user_data as *const _ as usize },
+        ::std::mem::offset_of!(_cef_v8value_t, get_user_data),
         336usize,
         concat!(
             "Offset of field: ",
@@ -16689,10 +15670,7 @@ fn bindgen_test_layout__cef_v8value_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_v8value_t>())).get_externally_allocated_memory as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_v8value_t, get_externally_allocated_memory),
         344usize,
         concat!(
             "Offset of field: ",
@@ -16702,10 +15680,7 @@ fn bindgen_test_layout__cef_v8value_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_v8value_t>())).adjust_externally_allocated_memory
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_v8value_t, adjust_externally_allocated_memory),
         352usize,
         concat!(
             "Offset of field: ",
@@ -16715,7 +15690,7 @@ fn bindgen_test_layout__cef_v8value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_v8value_t>())).get_array_length as *const _ as usize },
+        ::std::mem::offset_of!(_cef_v8value_t, get_array_length),
         360usize,
         concat!(
             "Offset of field: ",
@@ -16725,10 +15700,7 @@ fn bindgen_test_layout__cef_v8value_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_v8value_t>())).get_array_buffer_release_callback as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_v8value_t, get_array_buffer_release_callback),
         368usize,
         concat!(
             "Offset of field: ",
@@ -16738,9 +15710,7 @@ fn bindgen_test_layout__cef_v8value_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_v8value_t>())).neuter_array_buffer as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_v8value_t, neuter_array_buffer),
         376usize,
         concat!(
             "Offset of field: ",
@@ -16750,9 +15720,7 @@ fn bindgen_test_layout__cef_v8value_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_v8value_t>())).get_function_name as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_v8value_t, get_function_name),
         384usize,
         concat!(
             "Offset of field: ",
@@ -16762,9 +15730,7 @@ fn bindgen_test_layout__cef_v8value_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_v8value_t>())).get_function_handler as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_v8value_t, get_function_handler),
         392usize,
         concat!(
             "Offset of field: ",
@@ -16774,7 +15740,7 @@ fn bindgen_test_layout__cef_v8value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_v8value_t>())).execute_function as *const _ as usize },
+        ::std::mem::offset_of!(_cef_v8value_t, execute_function),
         400usize,
         concat!(
             "Offset of field: ",
@@ -16784,10 +15750,7 @@ fn bindgen_test_layout__cef_v8value_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_v8value_t>())).execute_function_with_context as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_v8value_t, execute_function_with_context),
         408usize,
         concat!(
             "Offset of field: ",
@@ -16874,7 +15837,7 @@ fn bindgen_test_layout__cef_v8stack_trace_t() {
         concat!("Alignment of ", stringify!(_cef_v8stack_trace_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_v8stack_trace_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_v8stack_trace_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -16884,7 +15847,7 @@ fn bindgen_test_layout__cef_v8stack_trace_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_v8stack_trace_t>())).is_valid as *const _ as usize },
+        ::std::mem::offset_of!(_cef_v8stack_trace_t, is_valid),
         40usize,
         concat!(
             "Offset of field: ",
@@ -16894,9 +15857,7 @@ fn bindgen_test_layout__cef_v8stack_trace_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_v8stack_trace_t>())).get_frame_count as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_v8stack_trace_t, get_frame_count),
         48usize,
         concat!(
             "Offset of field: ",
@@ -16906,7 +15867,7 @@ fn bindgen_test_layout__cef_v8stack_trace_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_v8stack_trace_t>())).get_frame as *const _ as usize },
+        ::std::mem::offset_of!(_cef_v8stack_trace_t, get_frame),
         56usize,
         concat!(
             "Offset of field: ",
@@ -16964,7 +15925,7 @@ fn bindgen_test_layout__cef_v8stack_frame_t() {
         concat!("Alignment of ", stringify!(_cef_v8stack_frame_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_v8stack_frame_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_v8stack_frame_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -16974,7 +15935,7 @@ fn bindgen_test_layout__cef_v8stack_frame_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_v8stack_frame_t>())).is_valid as *const _ as usize },
+        ::std::mem::offset_of!(_cef_v8stack_frame_t, is_valid),
         40usize,
         concat!(
             "Offset of field: ",
@@ -16984,9 +15945,7 @@ fn bindgen_test_layout__cef_v8stack_frame_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_v8stack_frame_t>())).get_script_name as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_v8stack_frame_t, get_script_name),
         48usize,
         concat!(
             "Offset of field: ",
@@ -16996,10 +15955,7 @@ fn bindgen_test_layout__cef_v8stack_frame_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_v8stack_frame_t>())).get_script_name_or_source_url
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_v8stack_frame_t, get_script_name_or_source_url),
         56usize,
         concat!(
             "Offset of field: ",
@@ -17009,9 +15965,7 @@ fn bindgen_test_layout__cef_v8stack_frame_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_v8stack_frame_t>())).get_function_name as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_v8stack_frame_t, get_function_name),
         64usize,
         concat!(
             "Offset of field: ",
@@ -17021,9 +15975,7 @@ fn bindgen_test_layout__cef_v8stack_frame_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_v8stack_frame_t>())).get_line_number as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_v8stack_frame_t, get_line_number),
         72usize,
         concat!(
             "Offset of field: ",
@@ -17033,7 +15985,7 @@ fn bindgen_test_layout__cef_v8stack_frame_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_v8stack_frame_t>())).get_column as *const _ as usize },
+        ::std::mem::offset_of!(_cef_v8stack_frame_t, get_column),
         80usize,
         concat!(
             "Offset of field: ",
@@ -17043,7 +15995,7 @@ fn bindgen_test_layout__cef_v8stack_frame_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_v8stack_frame_t>())).is_eval as *const _ as usize },
+        ::std::mem::offset_of!(_cef_v8stack_frame_t, is_eval),
         88usize,
         concat!(
             "Offset of field: ",
@@ -17053,9 +16005,7 @@ fn bindgen_test_layout__cef_v8stack_frame_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_v8stack_frame_t>())).is_constructor as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_v8stack_frame_t, is_constructor),
         96usize,
         concat!(
             "Offset of field: ",
@@ -17158,9 +16108,7 @@ fn bindgen_test_layout__cef_render_process_handler_t() {
         concat!("Alignment of ", stringify!(_cef_render_process_handler_t))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_render_process_handler_t>())).base as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_render_process_handler_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -17170,10 +16118,7 @@ fn bindgen_test_layout__cef_render_process_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_render_process_handler_t>())).on_render_thread_created
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_render_process_handler_t, on_render_thread_created),
         40usize,
         concat!(
             "Offset of field: ",
@@ -17183,10 +16128,7 @@ fn bindgen_test_layout__cef_render_process_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_render_process_handler_t>())).on_web_kit_initialized
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_render_process_handler_t, on_web_kit_initialized),
         48usize,
         concat!(
             "Offset of field: ",
@@ -17196,10 +16138,7 @@ fn bindgen_test_layout__cef_render_process_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_render_process_handler_t>())).on_browser_created as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_render_process_handler_t, on_browser_created),
         56usize,
         concat!(
             "Offset of field: ",
@@ -17209,10 +16148,7 @@ fn bindgen_test_layout__cef_render_process_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_render_process_handler_t>())).on_browser_destroyed
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_render_process_handler_t, on_browser_destroyed),
         64usize,
         concat!(
             "Offset of field: ",
@@ -17222,10 +16158,7 @@ fn bindgen_test_layout__cef_render_process_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_render_process_handler_t>())).get_load_handler as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_render_process_handler_t, get_load_handler),
         72usize,
         concat!(
             "Offset of field: ",
@@ -17235,10 +16168,7 @@ fn bindgen_test_layout__cef_render_process_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_render_process_handler_t>())).on_context_created as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_render_process_handler_t, on_context_created),
         80usize,
         concat!(
             "Offset of field: ",
@@ -17248,10 +16178,7 @@ fn bindgen_test_layout__cef_render_process_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_render_process_handler_t>())).on_context_released
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_render_process_handler_t, on_context_released),
         88usize,
         concat!(
             "Offset of field: ",
@@ -17261,10 +16188,7 @@ fn bindgen_test_layout__cef_render_process_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_render_process_handler_t>())).on_uncaught_exception
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_render_process_handler_t, on_uncaught_exception),
         96usize,
         concat!(
             "Offset of field: ",
@@ -17274,10 +16198,7 @@ fn bindgen_test_layout__cef_render_process_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_render_process_handler_t>())).on_focused_node_changed
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_render_process_handler_t, on_focused_node_changed),
         104usize,
         concat!(
             "Offset of field: ",
@@ -17287,10 +16208,7 @@ fn bindgen_test_layout__cef_render_process_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_render_process_handler_t>())).on_process_message_received
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_render_process_handler_t, on_process_message_received),
         112usize,
         concat!(
             "Offset of field: ",
@@ -17343,9 +16261,7 @@ fn bindgen_test_layout__cef_resource_bundle_handler_t() {
         concat!("Alignment of ", stringify!(_cef_resource_bundle_handler_t))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_resource_bundle_handler_t>())).base as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_resource_bundle_handler_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -17355,10 +16271,7 @@ fn bindgen_test_layout__cef_resource_bundle_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_resource_bundle_handler_t>())).get_localized_string
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_resource_bundle_handler_t, get_localized_string),
         40usize,
         concat!(
             "Offset of field: ",
@@ -17368,10 +16281,7 @@ fn bindgen_test_layout__cef_resource_bundle_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_resource_bundle_handler_t>())).get_data_resource as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_resource_bundle_handler_t, get_data_resource),
         48usize,
         concat!(
             "Offset of field: ",
@@ -17381,10 +16291,7 @@ fn bindgen_test_layout__cef_resource_bundle_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_resource_bundle_handler_t>())).get_data_resource_for_scale
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_resource_bundle_handler_t, get_data_resource_for_scale),
         56usize,
         concat!(
             "Offset of field: ",
@@ -17472,7 +16379,7 @@ fn bindgen_test_layout__cef_response_t() {
         concat!("Alignment of ", stringify!(_cef_response_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_response_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_response_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -17482,7 +16389,7 @@ fn bindgen_test_layout__cef_response_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_response_t>())).is_read_only as *const _ as usize },
+        ::std::mem::offset_of!(_cef_response_t, is_read_only),
         40usize,
         concat!(
             "Offset of field: ",
@@ -17492,7 +16399,7 @@ fn bindgen_test_layout__cef_response_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_response_t>())).get_error as *const _ as usize },
+        ::std::mem::offset_of!(_cef_response_t, get_error),
         48usize,
         concat!(
             "Offset of field: ",
@@ -17502,7 +16409,7 @@ fn bindgen_test_layout__cef_response_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_response_t>())).set_error as *const _ as usize },
+        ::std::mem::offset_of!(_cef_response_t, set_error),
         56usize,
         concat!(
             "Offset of field: ",
@@ -17512,7 +16419,7 @@ fn bindgen_test_layout__cef_response_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_response_t>())).get_status as *const _ as usize },
+        ::std::mem::offset_of!(_cef_response_t, get_status),
         64usize,
         concat!(
             "Offset of field: ",
@@ -17522,7 +16429,7 @@ fn bindgen_test_layout__cef_response_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_response_t>())).set_status as *const _ as usize },
+        ::std::mem::offset_of!(_cef_response_t, set_status),
         72usize,
         concat!(
             "Offset of field: ",
@@ -17532,7 +16439,7 @@ fn bindgen_test_layout__cef_response_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_response_t>())).get_status_text as *const _ as usize },
+        ::std::mem::offset_of!(_cef_response_t, get_status_text),
         80usize,
         concat!(
             "Offset of field: ",
@@ -17542,7 +16449,7 @@ fn bindgen_test_layout__cef_response_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_response_t>())).set_status_text as *const _ as usize },
+        ::std::mem::offset_of!(_cef_response_t, set_status_text),
         88usize,
         concat!(
             "Offset of field: ",
@@ -17552,7 +16459,7 @@ fn bindgen_test_layout__cef_response_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_response_t>())).get_mime_type as *const _ as usize },
+        ::std::mem::offset_of!(_cef_response_t, get_mime_type),
         96usize,
         concat!(
             "Offset of field: ",
@@ -17562,7 +16469,7 @@ fn bindgen_test_layout__cef_response_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_response_t>())).set_mime_type as *const _ as usize },
+        ::std::mem::offset_of!(_cef_response_t, set_mime_type),
         104usize,
         concat!(
             "Offset of field: ",
@@ -17572,7 +16479,7 @@ fn bindgen_test_layout__cef_response_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_response_t>())).get_charset as *const _ as usize },
+        ::std::mem::offset_of!(_cef_response_t, get_charset),
         112usize,
         concat!(
             "Offset of field: ",
@@ -17582,7 +16489,7 @@ fn bindgen_test_layout__cef_response_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_response_t>())).set_charset as *const _ as usize },
+        ::std::mem::offset_of!(_cef_response_t, set_charset),
         120usize,
         concat!(
             "Offset of field: ",
@@ -17592,9 +16499,7 @@ fn bindgen_test_layout__cef_response_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_response_t>())).get_header_by_name as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_response_t, get_header_by_name),
         128usize,
         concat!(
             "Offset of field: ",
@@ -17604,9 +16509,7 @@ fn bindgen_test_layout__cef_response_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_response_t>())).set_header_by_name as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_response_t, set_header_by_name),
         136usize,
         concat!(
             "Offset of field: ",
@@ -17616,7 +16519,7 @@ fn bindgen_test_layout__cef_response_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_response_t>())).get_header_map as *const _ as usize },
+        ::std::mem::offset_of!(_cef_response_t, get_header_map),
         144usize,
         concat!(
             "Offset of field: ",
@@ -17626,7 +16529,7 @@ fn bindgen_test_layout__cef_response_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_response_t>())).set_header_map as *const _ as usize },
+        ::std::mem::offset_of!(_cef_response_t, set_header_map),
         152usize,
         concat!(
             "Offset of field: ",
@@ -17636,7 +16539,7 @@ fn bindgen_test_layout__cef_response_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_response_t>())).get_url as *const _ as usize },
+        ::std::mem::offset_of!(_cef_response_t, get_url),
         160usize,
         concat!(
             "Offset of field: ",
@@ -17646,7 +16549,7 @@ fn bindgen_test_layout__cef_response_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_response_t>())).set_url as *const _ as usize },
+        ::std::mem::offset_of!(_cef_response_t, set_url),
         168usize,
         concat!(
             "Offset of field: ",
@@ -17681,9 +16584,7 @@ fn bindgen_test_layout__cef_resource_skip_callback_t() {
         concat!("Alignment of ", stringify!(_cef_resource_skip_callback_t))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_resource_skip_callback_t>())).base as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_resource_skip_callback_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -17693,9 +16594,7 @@ fn bindgen_test_layout__cef_resource_skip_callback_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_resource_skip_callback_t>())).cont as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_resource_skip_callback_t, cont),
         40usize,
         concat!(
             "Offset of field: ",
@@ -17730,9 +16629,7 @@ fn bindgen_test_layout__cef_resource_read_callback_t() {
         concat!("Alignment of ", stringify!(_cef_resource_read_callback_t))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_resource_read_callback_t>())).base as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_resource_read_callback_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -17742,9 +16639,7 @@ fn bindgen_test_layout__cef_resource_read_callback_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_resource_read_callback_t>())).cont as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_resource_read_callback_t, cont),
         40usize,
         concat!(
             "Offset of field: ",
@@ -17823,7 +16718,7 @@ fn bindgen_test_layout__cef_resource_handler_t() {
         concat!("Alignment of ", stringify!(_cef_resource_handler_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_resource_handler_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_resource_handler_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -17833,7 +16728,7 @@ fn bindgen_test_layout__cef_resource_handler_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_resource_handler_t>())).open as *const _ as usize },
+        ::std::mem::offset_of!(_cef_resource_handler_t, open),
         40usize,
         concat!(
             "Offset of field: ",
@@ -17843,9 +16738,7 @@ fn bindgen_test_layout__cef_resource_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_resource_handler_t>())).process_request as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_resource_handler_t, process_request),
         48usize,
         concat!(
             "Offset of field: ",
@@ -17855,10 +16748,7 @@ fn bindgen_test_layout__cef_resource_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_resource_handler_t>())).get_response_headers as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_resource_handler_t, get_response_headers),
         56usize,
         concat!(
             "Offset of field: ",
@@ -17868,7 +16758,7 @@ fn bindgen_test_layout__cef_resource_handler_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_resource_handler_t>())).skip as *const _ as usize },
+        ::std::mem::offset_of!(_cef_resource_handler_t, skip),
         64usize,
         concat!(
             "Offset of field: ",
@@ -17878,7 +16768,7 @@ fn bindgen_test_layout__cef_resource_handler_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_resource_handler_t>())).read as *const _ as usize },
+        ::std::mem::offset_of!(_cef_resource_handler_t, read),
         72usize,
         concat!(
             "Offset of field: ",
@@ -17888,9 +16778,7 @@ fn bindgen_test_layout__cef_resource_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_resource_handler_t>())).read_response as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_resource_handler_t, read_response),
         80usize,
         concat!(
             "Offset of field: ",
@@ -17900,7 +16788,7 @@ fn bindgen_test_layout__cef_resource_handler_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_resource_handler_t>())).cancel as *const _ as usize },
+        ::std::mem::offset_of!(_cef_resource_handler_t, cancel),
         88usize,
         concat!(
             "Offset of field: ",
@@ -17936,7 +16824,7 @@ fn bindgen_test_layout__cef_scheme_registrar_t() {
         concat!("Alignment of ", stringify!(_cef_scheme_registrar_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_scheme_registrar_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_scheme_registrar_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -17946,10 +16834,7 @@ fn bindgen_test_layout__cef_scheme_registrar_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_scheme_registrar_t>())).add_custom_scheme as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_scheme_registrar_t, add_custom_scheme),
         16usize,
         concat!(
             "Offset of field: ",
@@ -17987,9 +16872,7 @@ fn bindgen_test_layout__cef_scheme_handler_factory_t() {
         concat!("Alignment of ", stringify!(_cef_scheme_handler_factory_t))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_scheme_handler_factory_t>())).base as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_scheme_handler_factory_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -17999,9 +16882,7 @@ fn bindgen_test_layout__cef_scheme_handler_factory_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_scheme_handler_factory_t>())).create as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_scheme_handler_factory_t, create),
         40usize,
         concat!(
             "Offset of field: ",
@@ -18059,7 +16940,7 @@ fn bindgen_test_layout__cef_app_t() {
         concat!("Alignment of ", stringify!(_cef_app_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_app_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_app_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -18069,10 +16950,7 @@ fn bindgen_test_layout__cef_app_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_app_t>())).on_before_command_line_processing as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_app_t, on_before_command_line_processing),
         40usize,
         concat!(
             "Offset of field: ",
@@ -18082,9 +16960,7 @@ fn bindgen_test_layout__cef_app_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_app_t>())).on_register_custom_schemes as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_app_t, on_register_custom_schemes),
         48usize,
         concat!(
             "Offset of field: ",
@@ -18094,9 +16970,7 @@ fn bindgen_test_layout__cef_app_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_app_t>())).get_resource_bundle_handler as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_app_t, get_resource_bundle_handler),
         56usize,
         concat!(
             "Offset of field: ",
@@ -18106,9 +16980,7 @@ fn bindgen_test_layout__cef_app_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_app_t>())).get_browser_process_handler as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_app_t, get_browser_process_handler),
         64usize,
         concat!(
             "Offset of field: ",
@@ -18118,9 +16990,7 @@ fn bindgen_test_layout__cef_app_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_app_t>())).get_render_process_handler as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_app_t, get_render_process_handler),
         72usize,
         concat!(
             "Offset of field: ",
@@ -18190,7 +17060,7 @@ fn bindgen_test_layout__cef_auth_callback_t() {
         concat!("Alignment of ", stringify!(_cef_auth_callback_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_auth_callback_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_auth_callback_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -18200,7 +17070,7 @@ fn bindgen_test_layout__cef_auth_callback_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_auth_callback_t>())).cont as *const _ as usize },
+        ::std::mem::offset_of!(_cef_auth_callback_t, cont),
         40usize,
         concat!(
             "Offset of field: ",
@@ -18210,7 +17080,7 @@ fn bindgen_test_layout__cef_auth_callback_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_auth_callback_t>())).cancel as *const _ as usize },
+        ::std::mem::offset_of!(_cef_auth_callback_t, cancel),
         48usize,
         concat!(
             "Offset of field: ",
@@ -18273,7 +17143,7 @@ fn bindgen_test_layout__cef_audio_handler_t() {
         concat!("Alignment of ", stringify!(_cef_audio_handler_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_audio_handler_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_audio_handler_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -18283,10 +17153,7 @@ fn bindgen_test_layout__cef_audio_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_audio_handler_t>())).get_audio_parameters as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_audio_handler_t, get_audio_parameters),
         40usize,
         concat!(
             "Offset of field: ",
@@ -18296,10 +17163,7 @@ fn bindgen_test_layout__cef_audio_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_audio_handler_t>())).on_audio_stream_started as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_audio_handler_t, on_audio_stream_started),
         48usize,
         concat!(
             "Offset of field: ",
@@ -18309,10 +17173,7 @@ fn bindgen_test_layout__cef_audio_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_audio_handler_t>())).on_audio_stream_packet as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_audio_handler_t, on_audio_stream_packet),
         56usize,
         concat!(
             "Offset of field: ",
@@ -18322,10 +17183,7 @@ fn bindgen_test_layout__cef_audio_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_audio_handler_t>())).on_audio_stream_stopped as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_audio_handler_t, on_audio_stream_stopped),
         64usize,
         concat!(
             "Offset of field: ",
@@ -18335,10 +17193,7 @@ fn bindgen_test_layout__cef_audio_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_audio_handler_t>())).on_audio_stream_error as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_audio_handler_t, on_audio_stream_error),
         72usize,
         concat!(
             "Offset of field: ",
@@ -18415,7 +17270,7 @@ fn bindgen_test_layout__cef_menu_model_delegate_t() {
         concat!("Alignment of ", stringify!(_cef_menu_model_delegate_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_menu_model_delegate_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_menu_model_delegate_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -18425,10 +17280,7 @@ fn bindgen_test_layout__cef_menu_model_delegate_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_menu_model_delegate_t>())).execute_command as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_menu_model_delegate_t, execute_command),
         40usize,
         concat!(
             "Offset of field: ",
@@ -18438,10 +17290,7 @@ fn bindgen_test_layout__cef_menu_model_delegate_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_menu_model_delegate_t>())).mouse_outside_menu as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_menu_model_delegate_t, mouse_outside_menu),
         48usize,
         concat!(
             "Offset of field: ",
@@ -18451,10 +17300,7 @@ fn bindgen_test_layout__cef_menu_model_delegate_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_menu_model_delegate_t>())).unhandled_open_submenu
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_menu_model_delegate_t, unhandled_open_submenu),
         56usize,
         concat!(
             "Offset of field: ",
@@ -18464,10 +17310,7 @@ fn bindgen_test_layout__cef_menu_model_delegate_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_menu_model_delegate_t>())).unhandled_close_submenu
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_menu_model_delegate_t, unhandled_close_submenu),
         64usize,
         concat!(
             "Offset of field: ",
@@ -18477,10 +17320,7 @@ fn bindgen_test_layout__cef_menu_model_delegate_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_menu_model_delegate_t>())).menu_will_show as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_menu_model_delegate_t, menu_will_show),
         72usize,
         concat!(
             "Offset of field: ",
@@ -18490,9 +17330,7 @@ fn bindgen_test_layout__cef_menu_model_delegate_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_menu_model_delegate_t>())).menu_closed as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_menu_model_delegate_t, menu_closed),
         80usize,
         concat!(
             "Offset of field: ",
@@ -18502,9 +17340,7 @@ fn bindgen_test_layout__cef_menu_model_delegate_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_menu_model_delegate_t>())).format_label as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_menu_model_delegate_t, format_label),
         88usize,
         concat!(
             "Offset of field: ",
@@ -18908,7 +17744,7 @@ fn bindgen_test_layout__cef_menu_model_t() {
         concat!("Alignment of ", stringify!(_cef_menu_model_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_menu_model_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_menu_model_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -18918,7 +17754,7 @@ fn bindgen_test_layout__cef_menu_model_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_menu_model_t>())).is_sub_menu as *const _ as usize },
+        ::std::mem::offset_of!(_cef_menu_model_t, is_sub_menu),
         40usize,
         concat!(
             "Offset of field: ",
@@ -18928,7 +17764,7 @@ fn bindgen_test_layout__cef_menu_model_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_menu_model_t>())).clear as *const _ as usize },
+        ::std::mem::offset_of!(_cef_menu_model_t, clear),
         48usize,
         concat!(
             "Offset of field: ",
@@ -18938,7 +17774,7 @@ fn bindgen_test_layout__cef_menu_model_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_menu_model_t>())).get_count as *const _ as usize },
+        ::std::mem::offset_of!(_cef_menu_model_t, get_count),
         56usize,
         concat!(
             "Offset of field: ",
@@ -18948,7 +17784,7 @@ fn bindgen_test_layout__cef_menu_model_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_menu_model_t>())).add_separator as *const _ as usize },
+        ::std::mem::offset_of!(_cef_menu_model_t, add_separator),
         64usize,
         concat!(
             "Offset of field: ",
@@ -18958,7 +17794,7 @@ fn bindgen_test_layout__cef_menu_model_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_menu_model_t>())).add_item as *const _ as usize },
+        ::std::mem::offset_of!(_cef_menu_model_t, add_item),
         72usize,
         concat!(
             "Offset of field: ",
@@ -18968,9 +17804,7 @@ fn bindgen_test_layout__cef_menu_model_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_menu_model_t>())).add_check_item as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_menu_model_t, add_check_item),
         80usize,
         concat!(
             "Offset of field: ",
@@ -18980,9 +17814,7 @@ fn bindgen_test_layout__cef_menu_model_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_menu_model_t>())).add_radio_item as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_menu_model_t, add_radio_item),
         88usize,
         concat!(
             "Offset of field: ",
@@ -18992,7 +17824,7 @@ fn bindgen_test_layout__cef_menu_model_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_menu_model_t>())).add_sub_menu as *const _ as usize },
+        ::std::mem::offset_of!(_cef_menu_model_t, add_sub_menu),
         96usize,
         concat!(
             "Offset of field: ",
@@ -19002,9 +17834,7 @@ fn bindgen_test_layout__cef_menu_model_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_menu_model_t>())).insert_separator_at as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_menu_model_t, insert_separator_at),
         104usize,
         concat!(
             "Offset of field: ",
@@ -19014,9 +17844,7 @@ fn bindgen_test_layout__cef_menu_model_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_menu_model_t>())).insert_item_at as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_menu_model_t, insert_item_at),
         112usize,
         concat!(
             "Offset of field: ",
@@ -19026,9 +17854,7 @@ fn bindgen_test_layout__cef_menu_model_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_menu_model_t>())).insert_check_item_at as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_menu_model_t, insert_check_item_at),
         120usize,
         concat!(
             "Offset of field: ",
@@ -19038,9 +17864,7 @@ fn bindgen_test_layout__cef_menu_model_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_menu_model_t>())).insert_radio_item_at as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_menu_model_t, insert_radio_item_at),
         128usize,
         concat!(
             "Offset of field: ",
@@ -19050,9 +17874,7 @@ fn bindgen_test_layout__cef_menu_model_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_menu_model_t>())).insert_sub_menu_at as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_menu_model_t, insert_sub_menu_at),
         136usize,
         concat!(
             "Offset of field: ",
@@ -19062,7 +17884,7 @@ fn bindgen_test_layout__cef_menu_model_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_menu_model_t>())).remove as *const _ as usize },
+        ::std::mem::offset_of!(_cef_menu_model_t, remove),
         144usize,
         concat!(
             "Offset of field: ",
@@ -19072,7 +17894,7 @@ fn bindgen_test_layout__cef_menu_model_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_menu_model_t>())).remove_at as *const _ as usize },
+        ::std::mem::offset_of!(_cef_menu_model_t, remove_at),
         152usize,
         concat!(
             "Offset of field: ",
@@ -19082,7 +17904,7 @@ fn bindgen_test_layout__cef_menu_model_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_menu_model_t>())).get_index_of as *const _ as usize },
+        ::std::mem::offset_of!(_cef_menu_model_t, get_index_of),
         160usize,
         concat!(
             "Offset of field: ",
@@ -19092,9 +17914,7 @@ fn bindgen_test_layout__cef_menu_model_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_menu_model_t>())).get_command_id_at as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_menu_model_t, get_command_id_at),
         168usize,
         concat!(
             "Offset of field: ",
@@ -19104,9 +17924,7 @@ fn bindgen_test_layout__cef_menu_model_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_menu_model_t>())).set_command_id_at as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_menu_model_t, set_command_id_at),
         176usize,
         concat!(
             "Offset of field: ",
@@ -19116,7 +17934,7 @@ fn bindgen_test_layout__cef_menu_model_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_menu_model_t>())).get_label as *const _ as usize },
+        ::std::mem::offset_of!(_cef_menu_model_t, get_label),
         184usize,
         concat!(
             "Offset of field: ",
@@ -19126,7 +17944,7 @@ fn bindgen_test_layout__cef_menu_model_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_menu_model_t>())).get_label_at as *const _ as usize },
+        ::std::mem::offset_of!(_cef_menu_model_t, get_label_at),
         192usize,
         concat!(
             "Offset of field: ",
@@ -19136,7 +17954,7 @@ fn bindgen_test_layout__cef_menu_model_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_menu_model_t>())).set_label as *const _ as usize },
+        ::std::mem::offset_of!(_cef_menu_model_t, set_label),
         200usize,
         concat!(
             "Offset of field: ",
@@ -19146,7 +17964,7 @@ fn bindgen_test_layout__cef_menu_model_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_menu_model_t>())).set_label_at as *const _ as usize },
+        ::std::mem::offset_of!(_cef_menu_model_t, set_label_at),
         208usize,
         concat!(
             "Offset of field: ",
@@ -19156,7 +17974,7 @@ fn bindgen_test_layout__cef_menu_model_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_menu_model_t>())).get_type as *const _ as usize },
+        ::std::mem::offset_of!(_cef_menu_model_t, get_type),
         216usize,
         concat!(
             "Offset of field: ",
@@ -19166,7 +17984,7 @@ fn bindgen_test_layout__cef_menu_model_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_menu_model_t>())).get_type_at as *const _ as usize },
+        ::std::mem::offset_of!(_cef_menu_model_t, get_type_at),
         224usize,
         concat!(
             "Offset of field: ",
@@ -19176,7 +17994,7 @@ fn bindgen_test_layout__cef_menu_model_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_menu_model_t>())).get_group_id as *const _ as usize },
+        ::std::mem::offset_of!(_cef_menu_model_t, get_group_id),
         232usize,
         concat!(
             "Offset of field: ",
@@ -19186,9 +18004,7 @@ fn bindgen_test_layout__cef_menu_model_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_menu_model_t>())).get_group_id_at as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_menu_model_t, get_group_id_at),
         240usize,
         concat!(
             "Offset of field: ",
@@ -19198,7 +18014,7 @@ fn bindgen_test_layout__cef_menu_model_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_menu_model_t>())).set_group_id as *const _ as usize },
+        ::std::mem::offset_of!(_cef_menu_model_t, set_group_id),
         248usize,
         concat!(
             "Offset of field: ",
@@ -19208,9 +18024,7 @@ fn bindgen_test_layout__cef_menu_model_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_menu_model_t>())).set_group_id_at as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_menu_model_t, set_group_id_at),
         256usize,
         concat!(
             "Offset of field: ",
@@ -19220,7 +18034,7 @@ fn bindgen_test_layout__cef_menu_model_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_menu_model_t>())).get_sub_menu as *const _ as usize },
+        ::std::mem::offset_of!(_cef_menu_model_t, get_sub_menu),
         264usize,
         concat!(
             "Offset of field: ",
@@ -19230,9 +18044,7 @@ fn bindgen_test_layout__cef_menu_model_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_menu_model_t>())).get_sub_menu_at as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_menu_model_t, get_sub_menu_at),
         272usize,
         concat!(
             "Offset of field: ",
@@ -19242,7 +18054,7 @@ fn bindgen_test_layout__cef_menu_model_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_menu_model_t>())).is_visible as *const _ as usize },
+        ::std::mem::offset_of!(_cef_menu_model_t, is_visible),
         280usize,
         concat!(
             "Offset of field: ",
@@ -19252,7 +18064,7 @@ fn bindgen_test_layout__cef_menu_model_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_menu_model_t>())).is_visible_at as *const _ as usize },
+        ::std::mem::offset_of!(_cef_menu_model_t, is_visible_at),
         288usize,
         concat!(
             "Offset of field: ",
@@ -19262,7 +18074,7 @@ fn bindgen_test_layout__cef_menu_model_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_menu_model_t>())).set_visible as *const _ as usize },
+        ::std::mem::offset_of!(_cef_menu_model_t, set_visible),
         296usize,
         concat!(
             "Offset of field: ",
@@ -19272,9 +18084,7 @@ fn bindgen_test_layout__cef_menu_model_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_menu_model_t>())).set_visible_at as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_menu_model_t, set_visible_at),
         304usize,
         concat!(
             "Offset of field: ",
@@ -19284,7 +18094,7 @@ fn bindgen_test_layout__cef_menu_model_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_menu_model_t>())).is_enabled as *const _ as usize },
+        ::std::mem::offset_of!(_cef_menu_model_t, is_enabled),
         312usize,
         concat!(
             "Offset of field: ",
@@ -19294,7 +18104,7 @@ fn bindgen_test_layout__cef_menu_model_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_menu_model_t>())).is_enabled_at as *const _ as usize },
+        ::std::mem::offset_of!(_cef_menu_model_t, is_enabled_at),
         320usize,
         concat!(
             "Offset of field: ",
@@ -19304,7 +18114,7 @@ fn bindgen_test_layout__cef_menu_model_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_menu_model_t>())).set_enabled as *const _ as usize },
+        ::std::mem::offset_of!(_cef_menu_model_t, set_enabled),
         328usize,
         concat!(
             "Offset of field: ",
@@ -19314,9 +18124,7 @@ fn bindgen_test_layout__cef_menu_model_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_menu_model_t>())).set_enabled_at as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_menu_model_t, set_enabled_at),
         336usize,
         concat!(
             "Offset of field: ",
@@ -19326,7 +18134,7 @@ fn bindgen_test_layout__cef_menu_model_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_menu_model_t>())).is_checked as *const _ as usize },
+        ::std::mem::offset_of!(_cef_menu_model_t, is_checked),
         344usize,
         concat!(
             "Offset of field: ",
@@ -19336,7 +18144,7 @@ fn bindgen_test_layout__cef_menu_model_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_menu_model_t>())).is_checked_at as *const _ as usize },
+        ::std::mem::offset_of!(_cef_menu_model_t, is_checked_at),
         352usize,
         concat!(
             "Offset of field: ",
@@ -19346,7 +18154,7 @@ fn bindgen_test_layout__cef_menu_model_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_menu_model_t>())).set_checked as *const _ as usize },
+        ::std::mem::offset_of!(_cef_menu_model_t, set_checked),
         360usize,
         concat!(
             "Offset of field: ",
@@ -19356,9 +18164,7 @@ fn bindgen_test_layout__cef_menu_model_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_menu_model_t>())).set_checked_at as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_menu_model_t, set_checked_at),
         368usize,
         concat!(
             "Offset of field: ",
@@ -19368,9 +18174,7 @@ fn bindgen_test_layout__cef_menu_model_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_menu_model_t>())).has_accelerator as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_menu_model_t, has_accelerator),
         376usize,
         concat!(
             "Offset of field: ",
@@ -19380,9 +18184,7 @@ fn bindgen_test_layout__cef_menu_model_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_menu_model_t>())).has_accelerator_at as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_menu_model_t, has_accelerator_at),
         384usize,
         concat!(
             "Offset of field: ",
@@ -19392,9 +18194,7 @@ fn bindgen_test_layout__cef_menu_model_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_menu_model_t>())).set_accelerator as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_menu_model_t, set_accelerator),
         392usize,
         concat!(
             "Offset of field: ",
@@ -19404,9 +18204,7 @@ fn bindgen_test_layout__cef_menu_model_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_menu_model_t>())).set_accelerator_at as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_menu_model_t, set_accelerator_at),
         400usize,
         concat!(
             "Offset of field: ",
@@ -19416,9 +18214,7 @@ fn bindgen_test_layout__cef_menu_model_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_menu_model_t>())).remove_accelerator as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_menu_model_t, remove_accelerator),
         408usize,
         concat!(
             "Offset of field: ",
@@ -19428,9 +18224,7 @@ fn bindgen_test_layout__cef_menu_model_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_menu_model_t>())).remove_accelerator_at as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_menu_model_t, remove_accelerator_at),
         416usize,
         concat!(
             "Offset of field: ",
@@ -19440,9 +18234,7 @@ fn bindgen_test_layout__cef_menu_model_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_menu_model_t>())).get_accelerator as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_menu_model_t, get_accelerator),
         424usize,
         concat!(
             "Offset of field: ",
@@ -19452,9 +18244,7 @@ fn bindgen_test_layout__cef_menu_model_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_menu_model_t>())).get_accelerator_at as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_menu_model_t, get_accelerator_at),
         432usize,
         concat!(
             "Offset of field: ",
@@ -19464,7 +18254,7 @@ fn bindgen_test_layout__cef_menu_model_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_menu_model_t>())).set_color as *const _ as usize },
+        ::std::mem::offset_of!(_cef_menu_model_t, set_color),
         440usize,
         concat!(
             "Offset of field: ",
@@ -19474,7 +18264,7 @@ fn bindgen_test_layout__cef_menu_model_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_menu_model_t>())).set_color_at as *const _ as usize },
+        ::std::mem::offset_of!(_cef_menu_model_t, set_color_at),
         448usize,
         concat!(
             "Offset of field: ",
@@ -19484,7 +18274,7 @@ fn bindgen_test_layout__cef_menu_model_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_menu_model_t>())).get_color as *const _ as usize },
+        ::std::mem::offset_of!(_cef_menu_model_t, get_color),
         456usize,
         concat!(
             "Offset of field: ",
@@ -19494,7 +18284,7 @@ fn bindgen_test_layout__cef_menu_model_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_menu_model_t>())).get_color_at as *const _ as usize },
+        ::std::mem::offset_of!(_cef_menu_model_t, get_color_at),
         464usize,
         concat!(
             "Offset of field: ",
@@ -19504,7 +18294,7 @@ fn bindgen_test_layout__cef_menu_model_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_menu_model_t>())).set_font_list as *const _ as usize },
+        ::std::mem::offset_of!(_cef_menu_model_t, set_font_list),
         472usize,
         concat!(
             "Offset of field: ",
@@ -19514,9 +18304,7 @@ fn bindgen_test_layout__cef_menu_model_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_menu_model_t>())).set_font_list_at as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_menu_model_t, set_font_list_at),
         480usize,
         concat!(
             "Offset of field: ",
@@ -19562,9 +18350,7 @@ fn bindgen_test_layout__cef_run_context_menu_callback_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_run_context_menu_callback_t>())).base as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_run_context_menu_callback_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -19574,9 +18360,7 @@ fn bindgen_test_layout__cef_run_context_menu_callback_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_run_context_menu_callback_t>())).cont as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_run_context_menu_callback_t, cont),
         40usize,
         concat!(
             "Offset of field: ",
@@ -19586,9 +18370,7 @@ fn bindgen_test_layout__cef_run_context_menu_callback_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_run_context_menu_callback_t>())).cancel as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_run_context_menu_callback_t, cancel),
         48usize,
         concat!(
             "Offset of field: ",
@@ -19653,9 +18435,7 @@ fn bindgen_test_layout__cef_context_menu_handler_t() {
         concat!("Alignment of ", stringify!(_cef_context_menu_handler_t))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_context_menu_handler_t>())).base as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_context_menu_handler_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -19665,10 +18445,7 @@ fn bindgen_test_layout__cef_context_menu_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_context_menu_handler_t>())).on_before_context_menu
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_context_menu_handler_t, on_before_context_menu),
         40usize,
         concat!(
             "Offset of field: ",
@@ -19678,10 +18455,7 @@ fn bindgen_test_layout__cef_context_menu_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_context_menu_handler_t>())).run_context_menu as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_context_menu_handler_t, run_context_menu),
         48usize,
         concat!(
             "Offset of field: ",
@@ -19691,10 +18465,7 @@ fn bindgen_test_layout__cef_context_menu_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_context_menu_handler_t>())).on_context_menu_command
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_context_menu_handler_t, on_context_menu_command),
         56usize,
         concat!(
             "Offset of field: ",
@@ -19704,10 +18475,7 @@ fn bindgen_test_layout__cef_context_menu_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_context_menu_handler_t>())).on_context_menu_dismissed
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_context_menu_handler_t, on_context_menu_dismissed),
         64usize,
         concat!(
             "Offset of field: ",
@@ -19810,7 +18578,7 @@ fn bindgen_test_layout__cef_context_menu_params_t() {
         concat!("Alignment of ", stringify!(_cef_context_menu_params_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_context_menu_params_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_context_menu_params_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -19820,9 +18588,7 @@ fn bindgen_test_layout__cef_context_menu_params_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_context_menu_params_t>())).get_xcoord as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_context_menu_params_t, get_xcoord),
         40usize,
         concat!(
             "Offset of field: ",
@@ -19832,9 +18598,7 @@ fn bindgen_test_layout__cef_context_menu_params_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_context_menu_params_t>())).get_ycoord as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_context_menu_params_t, get_ycoord),
         48usize,
         concat!(
             "Offset of field: ",
@@ -19844,10 +18608,7 @@ fn bindgen_test_layout__cef_context_menu_params_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_context_menu_params_t>())).get_type_flags as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_context_menu_params_t, get_type_flags),
         56usize,
         concat!(
             "Offset of field: ",
@@ -19857,9 +18618,7 @@ fn bindgen_test_layout__cef_context_menu_params_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_context_menu_params_t>())).get_link_url as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_context_menu_params_t, get_link_url),
         64usize,
         concat!(
             "Offset of field: ",
@@ -19869,10 +18628,7 @@ fn bindgen_test_layout__cef_context_menu_params_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_context_menu_params_t>())).get_unfiltered_link_url
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_context_menu_params_t, get_unfiltered_link_url),
         72usize,
         concat!(
             "Offset of field: ",
@@ -19882,10 +18638,7 @@ fn bindgen_test_layout__cef_context_menu_params_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_context_menu_params_t>())).get_source_url as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_context_menu_params_t, get_source_url),
         80usize,
         concat!(
             "Offset of field: ",
@@ -19895,10 +18648,7 @@ fn bindgen_test_layout__cef_context_menu_params_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_context_menu_params_t>())).has_image_contents as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_context_menu_params_t, has_image_contents),
         88usize,
         concat!(
             "Offset of field: ",
@@ -19908,10 +18658,7 @@ fn bindgen_test_layout__cef_context_menu_params_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_context_menu_params_t>())).get_title_text as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_context_menu_params_t, get_title_text),
         96usize,
         concat!(
             "Offset of field: ",
@@ -19921,9 +18668,7 @@ fn bindgen_test_layout__cef_context_menu_params_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_context_menu_params_t>())).get_page_url as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_context_menu_params_t, get_page_url),
         104usize,
         concat!(
             "Offset of field: ",
@@ -19933,10 +18678,7 @@ fn bindgen_test_layout__cef_context_menu_params_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_context_menu_params_t>())).get_frame_url as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_context_menu_params_t, get_frame_url),
         112usize,
         concat!(
             "Offset of field: ",
@@ -19946,10 +18688,7 @@ fn bindgen_test_layout__cef_context_menu_params_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_context_menu_params_t>())).get_frame_charset as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_context_menu_params_t, get_frame_charset),
         120usize,
         concat!(
             "Offset of field: ",
@@ -19959,10 +18698,7 @@ fn bindgen_test_layout__cef_context_menu_params_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_context_menu_params_t>())).get_media_type as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_context_menu_params_t, get_media_type),
         128usize,
         concat!(
             "Offset of field: ",
@@ -19972,10 +18708,7 @@ fn bindgen_test_layout__cef_context_menu_params_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_context_menu_params_t>())).get_media_state_flags as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_context_menu_params_t, get_media_state_flags),
         136usize,
         concat!(
             "Offset of field: ",
@@ -19985,10 +18718,7 @@ fn bindgen_test_layout__cef_context_menu_params_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_context_menu_params_t>())).get_selection_text as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_context_menu_params_t, get_selection_text),
         144usize,
         concat!(
             "Offset of field: ",
@@ -19998,10 +18728,7 @@ fn bindgen_test_layout__cef_context_menu_params_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_context_menu_params_t>())).get_misspelled_word as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_context_menu_params_t, get_misspelled_word),
         152usize,
         concat!(
             "Offset of field: ",
@@ -20011,10 +18738,7 @@ fn bindgen_test_layout__cef_context_menu_params_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_context_menu_params_t>())).get_dictionary_suggestions
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_context_menu_params_t, get_dictionary_suggestions),
         160usize,
         concat!(
             "Offset of field: ",
@@ -20024,9 +18748,7 @@ fn bindgen_test_layout__cef_context_menu_params_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_context_menu_params_t>())).is_editable as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_context_menu_params_t, is_editable),
         168usize,
         concat!(
             "Offset of field: ",
@@ -20036,10 +18758,7 @@ fn bindgen_test_layout__cef_context_menu_params_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_context_menu_params_t>())).is_spell_check_enabled
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_context_menu_params_t, is_spell_check_enabled),
         176usize,
         concat!(
             "Offset of field: ",
@@ -20049,10 +18768,7 @@ fn bindgen_test_layout__cef_context_menu_params_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_context_menu_params_t>())).get_edit_state_flags as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_context_menu_params_t, get_edit_state_flags),
         184usize,
         concat!(
             "Offset of field: ",
@@ -20062,10 +18778,7 @@ fn bindgen_test_layout__cef_context_menu_params_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_context_menu_params_t>())).is_custom_menu as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_context_menu_params_t, is_custom_menu),
         192usize,
         concat!(
             "Offset of field: ",
@@ -20075,10 +18788,7 @@ fn bindgen_test_layout__cef_context_menu_params_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_context_menu_params_t>())).is_pepper_menu as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_context_menu_params_t, is_pepper_menu),
         200usize,
         concat!(
             "Offset of field: ",
@@ -20116,9 +18826,7 @@ fn bindgen_test_layout__cef_file_dialog_callback_t() {
         concat!("Alignment of ", stringify!(_cef_file_dialog_callback_t))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_file_dialog_callback_t>())).base as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_file_dialog_callback_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -20128,9 +18836,7 @@ fn bindgen_test_layout__cef_file_dialog_callback_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_file_dialog_callback_t>())).cont as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_file_dialog_callback_t, cont),
         40usize,
         concat!(
             "Offset of field: ",
@@ -20140,9 +18846,7 @@ fn bindgen_test_layout__cef_file_dialog_callback_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_file_dialog_callback_t>())).cancel as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_file_dialog_callback_t, cancel),
         48usize,
         concat!(
             "Offset of field: ",
@@ -20183,7 +18887,7 @@ fn bindgen_test_layout__cef_dialog_handler_t() {
         concat!("Alignment of ", stringify!(_cef_dialog_handler_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_dialog_handler_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_dialog_handler_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -20193,9 +18897,7 @@ fn bindgen_test_layout__cef_dialog_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_dialog_handler_t>())).on_file_dialog as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_dialog_handler_t, on_file_dialog),
         40usize,
         concat!(
             "Offset of field: ",
@@ -20291,7 +18993,7 @@ fn bindgen_test_layout__cef_display_handler_t() {
         concat!("Alignment of ", stringify!(_cef_display_handler_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_display_handler_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_display_handler_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -20301,10 +19003,7 @@ fn bindgen_test_layout__cef_display_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_display_handler_t>())).on_address_change as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_display_handler_t, on_address_change),
         40usize,
         concat!(
             "Offset of field: ",
@@ -20314,9 +19013,7 @@ fn bindgen_test_layout__cef_display_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_display_handler_t>())).on_title_change as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_display_handler_t, on_title_change),
         48usize,
         concat!(
             "Offset of field: ",
@@ -20326,10 +19023,7 @@ fn bindgen_test_layout__cef_display_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_display_handler_t>())).on_favicon_urlchange as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_display_handler_t, on_favicon_urlchange),
         56usize,
         concat!(
             "Offset of field: ",
@@ -20339,10 +19033,7 @@ fn bindgen_test_layout__cef_display_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_display_handler_t>())).on_fullscreen_mode_change as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_display_handler_t, on_fullscreen_mode_change),
         64usize,
         concat!(
             "Offset of field: ",
@@ -20352,9 +19043,7 @@ fn bindgen_test_layout__cef_display_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_display_handler_t>())).on_tooltip as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_display_handler_t, on_tooltip),
         72usize,
         concat!(
             "Offset of field: ",
@@ -20364,10 +19053,7 @@ fn bindgen_test_layout__cef_display_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_display_handler_t>())).on_status_message as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_display_handler_t, on_status_message),
         80usize,
         concat!(
             "Offset of field: ",
@@ -20377,10 +19063,7 @@ fn bindgen_test_layout__cef_display_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_display_handler_t>())).on_console_message as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_display_handler_t, on_console_message),
         88usize,
         concat!(
             "Offset of field: ",
@@ -20390,9 +19073,7 @@ fn bindgen_test_layout__cef_display_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_display_handler_t>())).on_auto_resize as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_display_handler_t, on_auto_resize),
         96usize,
         concat!(
             "Offset of field: ",
@@ -20402,10 +19083,7 @@ fn bindgen_test_layout__cef_display_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_display_handler_t>())).on_loading_progress_change
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_display_handler_t, on_loading_progress_change),
         104usize,
         concat!(
             "Offset of field: ",
@@ -20479,7 +19157,7 @@ fn bindgen_test_layout__cef_download_item_t() {
         concat!("Alignment of ", stringify!(_cef_download_item_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_download_item_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_download_item_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -20489,7 +19167,7 @@ fn bindgen_test_layout__cef_download_item_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_download_item_t>())).is_valid as *const _ as usize },
+        ::std::mem::offset_of!(_cef_download_item_t, is_valid),
         40usize,
         concat!(
             "Offset of field: ",
@@ -20499,9 +19177,7 @@ fn bindgen_test_layout__cef_download_item_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_download_item_t>())).is_in_progress as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_download_item_t, is_in_progress),
         48usize,
         concat!(
             "Offset of field: ",
@@ -20511,9 +19187,7 @@ fn bindgen_test_layout__cef_download_item_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_download_item_t>())).is_complete as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_download_item_t, is_complete),
         56usize,
         concat!(
             "Offset of field: ",
@@ -20523,9 +19197,7 @@ fn bindgen_test_layout__cef_download_item_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_download_item_t>())).is_canceled as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_download_item_t, is_canceled),
         64usize,
         concat!(
             "Offset of field: ",
@@ -20535,9 +19207,7 @@ fn bindgen_test_layout__cef_download_item_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_download_item_t>())).get_current_speed as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_download_item_t, get_current_speed),
         72usize,
         concat!(
             "Offset of field: ",
@@ -20547,10 +19217,7 @@ fn bindgen_test_layout__cef_download_item_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_download_item_t>())).get_percent_complete as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_download_item_t, get_percent_complete),
         80usize,
         concat!(
             "Offset of field: ",
@@ -20560,9 +19227,7 @@ fn bindgen_test_layout__cef_download_item_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_download_item_t>())).get_total_bytes as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_download_item_t, get_total_bytes),
         88usize,
         concat!(
             "Offset of field: ",
@@ -20572,9 +19237,7 @@ fn bindgen_test_layout__cef_download_item_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_download_item_t>())).get_received_bytes as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_download_item_t, get_received_bytes),
         96usize,
         concat!(
             "Offset of field: ",
@@ -20584,9 +19247,7 @@ fn bindgen_test_layout__cef_download_item_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_download_item_t>())).get_start_time as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_download_item_t, get_start_time),
         104usize,
         concat!(
             "Offset of field: ",
@@ -20596,9 +19257,7 @@ fn bindgen_test_layout__cef_download_item_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_download_item_t>())).get_end_time as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_download_item_t, get_end_time),
         112usize,
         concat!(
             "Offset of field: ",
@@ -20608,9 +19267,7 @@ fn bindgen_test_layout__cef_download_item_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_download_item_t>())).get_full_path as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_download_item_t, get_full_path),
         120usize,
         concat!(
             "Offset of field: ",
@@ -20620,7 +19277,7 @@ fn bindgen_test_layout__cef_download_item_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_download_item_t>())).get_id as *const _ as usize },
+        ::std::mem::offset_of!(_cef_download_item_t, get_id),
         128usize,
         concat!(
             "Offset of field: ",
@@ -20630,7 +19287,7 @@ fn bindgen_test_layout__cef_download_item_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_download_item_t>())).get_url as *const _ as usize },
+        ::std::mem::offset_of!(_cef_download_item_t, get_url),
         136usize,
         concat!(
             "Offset of field: ",
@@ -20640,9 +19297,7 @@ fn bindgen_test_layout__cef_download_item_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_download_item_t>())).get_original_url as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_download_item_t, get_original_url),
         144usize,
         concat!(
             "Offset of field: ",
@@ -20652,10 +19307,7 @@ fn bindgen_test_layout__cef_download_item_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_download_item_t>())).get_suggested_file_name as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_download_item_t, get_suggested_file_name),
         152usize,
         concat!(
             "Offset of field: ",
@@ -20665,10 +19317,7 @@ fn bindgen_test_layout__cef_download_item_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_download_item_t>())).get_content_disposition as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_download_item_t, get_content_disposition),
         160usize,
         concat!(
             "Offset of field: ",
@@ -20678,9 +19327,7 @@ fn bindgen_test_layout__cef_download_item_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_download_item_t>())).get_mime_type as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_download_item_t, get_mime_type),
         168usize,
         concat!(
             "Offset of field: ",
@@ -20716,9 +19363,7 @@ fn bindgen_test_layout__cef_before_download_callback_t() {
         concat!("Alignment of ", stringify!(_cef_before_download_callback_t))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_before_download_callback_t>())).base as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_before_download_callback_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -20728,9 +19373,7 @@ fn bindgen_test_layout__cef_before_download_callback_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_before_download_callback_t>())).cont as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_before_download_callback_t, cont),
         40usize,
         concat!(
             "Offset of field: ",
@@ -20765,9 +19408,7 @@ fn bindgen_test_layout__cef_download_item_callback_t() {
         concat!("Alignment of ", stringify!(_cef_download_item_callback_t))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_download_item_callback_t>())).base as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_download_item_callback_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -20777,9 +19418,7 @@ fn bindgen_test_layout__cef_download_item_callback_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_download_item_callback_t>())).cancel as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_download_item_callback_t, cancel),
         40usize,
         concat!(
             "Offset of field: ",
@@ -20789,9 +19428,7 @@ fn bindgen_test_layout__cef_download_item_callback_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_download_item_callback_t>())).pause as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_download_item_callback_t, pause),
         48usize,
         concat!(
             "Offset of field: ",
@@ -20801,9 +19438,7 @@ fn bindgen_test_layout__cef_download_item_callback_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_download_item_callback_t>())).resume as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_download_item_callback_t, resume),
         56usize,
         concat!(
             "Offset of field: ",
@@ -20849,7 +19484,7 @@ fn bindgen_test_layout__cef_download_handler_t() {
         concat!("Alignment of ", stringify!(_cef_download_handler_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_download_handler_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_download_handler_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -20859,10 +19494,7 @@ fn bindgen_test_layout__cef_download_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_download_handler_t>())).on_before_download as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_download_handler_t, on_before_download),
         40usize,
         concat!(
             "Offset of field: ",
@@ -20872,10 +19504,7 @@ fn bindgen_test_layout__cef_download_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_download_handler_t>())).on_download_updated as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_download_handler_t, on_download_updated),
         48usize,
         concat!(
             "Offset of field: ",
@@ -20921,7 +19550,7 @@ fn bindgen_test_layout__cef_drag_handler_t() {
         concat!("Alignment of ", stringify!(_cef_drag_handler_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_drag_handler_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_drag_handler_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -20931,9 +19560,7 @@ fn bindgen_test_layout__cef_drag_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_drag_handler_t>())).on_drag_enter as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_drag_handler_t, on_drag_enter),
         40usize,
         concat!(
             "Offset of field: ",
@@ -20943,10 +19570,7 @@ fn bindgen_test_layout__cef_drag_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_drag_handler_t>())).on_draggable_regions_changed as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_drag_handler_t, on_draggable_regions_changed),
         48usize,
         concat!(
             "Offset of field: ",
@@ -20986,7 +19610,7 @@ fn bindgen_test_layout__cef_find_handler_t() {
         concat!("Alignment of ", stringify!(_cef_find_handler_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_find_handler_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_find_handler_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -20996,9 +19620,7 @@ fn bindgen_test_layout__cef_find_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_find_handler_t>())).on_find_result as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_find_handler_t, on_find_result),
         40usize,
         concat!(
             "Offset of field: ",
@@ -21044,7 +19666,7 @@ fn bindgen_test_layout__cef_focus_handler_t() {
         concat!("Alignment of ", stringify!(_cef_focus_handler_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_focus_handler_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_focus_handler_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -21054,9 +19676,7 @@ fn bindgen_test_layout__cef_focus_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_focus_handler_t>())).on_take_focus as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_focus_handler_t, on_take_focus),
         40usize,
         concat!(
             "Offset of field: ",
@@ -21066,9 +19686,7 @@ fn bindgen_test_layout__cef_focus_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_focus_handler_t>())).on_set_focus as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_focus_handler_t, on_set_focus),
         48usize,
         concat!(
             "Offset of field: ",
@@ -21078,9 +19696,7 @@ fn bindgen_test_layout__cef_focus_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_focus_handler_t>())).on_got_focus as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_focus_handler_t, on_got_focus),
         56usize,
         concat!(
             "Offset of field: ",
@@ -21116,7 +19732,7 @@ fn bindgen_test_layout__cef_jsdialog_callback_t() {
         concat!("Alignment of ", stringify!(_cef_jsdialog_callback_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_jsdialog_callback_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_jsdialog_callback_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -21126,7 +19742,7 @@ fn bindgen_test_layout__cef_jsdialog_callback_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_jsdialog_callback_t>())).cont as *const _ as usize },
+        ::std::mem::offset_of!(_cef_jsdialog_callback_t, cont),
         40usize,
         concat!(
             "Offset of field: ",
@@ -21182,7 +19798,7 @@ fn bindgen_test_layout__cef_jsdialog_handler_t() {
         concat!("Alignment of ", stringify!(_cef_jsdialog_handler_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_jsdialog_handler_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_jsdialog_handler_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -21192,9 +19808,7 @@ fn bindgen_test_layout__cef_jsdialog_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_jsdialog_handler_t>())).on_jsdialog as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_jsdialog_handler_t, on_jsdialog),
         40usize,
         concat!(
             "Offset of field: ",
@@ -21204,10 +19818,7 @@ fn bindgen_test_layout__cef_jsdialog_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_jsdialog_handler_t>())).on_before_unload_dialog as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_jsdialog_handler_t, on_before_unload_dialog),
         48usize,
         concat!(
             "Offset of field: ",
@@ -21217,10 +19828,7 @@ fn bindgen_test_layout__cef_jsdialog_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_jsdialog_handler_t>())).on_reset_dialog_state as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_jsdialog_handler_t, on_reset_dialog_state),
         56usize,
         concat!(
             "Offset of field: ",
@@ -21230,10 +19838,7 @@ fn bindgen_test_layout__cef_jsdialog_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_jsdialog_handler_t>())).on_dialog_closed as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_jsdialog_handler_t, on_dialog_closed),
         64usize,
         concat!(
             "Offset of field: ",
@@ -21279,7 +19884,7 @@ fn bindgen_test_layout__cef_keyboard_handler_t() {
         concat!("Alignment of ", stringify!(_cef_keyboard_handler_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_keyboard_handler_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_keyboard_handler_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -21289,10 +19894,7 @@ fn bindgen_test_layout__cef_keyboard_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_keyboard_handler_t>())).on_pre_key_event as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_keyboard_handler_t, on_pre_key_event),
         40usize,
         concat!(
             "Offset of field: ",
@@ -21302,9 +19904,7 @@ fn bindgen_test_layout__cef_keyboard_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_keyboard_handler_t>())).on_key_event as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_keyboard_handler_t, on_key_event),
         48usize,
         concat!(
             "Offset of field: ",
@@ -21362,7 +19962,7 @@ fn bindgen_test_layout__cef_life_span_handler_t() {
         concat!("Alignment of ", stringify!(_cef_life_span_handler_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_life_span_handler_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_life_span_handler_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -21372,10 +19972,7 @@ fn bindgen_test_layout__cef_life_span_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_life_span_handler_t>())).on_before_popup as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_life_span_handler_t, on_before_popup),
         40usize,
         concat!(
             "Offset of field: ",
@@ -21385,10 +19982,7 @@ fn bindgen_test_layout__cef_life_span_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_life_span_handler_t>())).on_after_created as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_life_span_handler_t, on_after_created),
         48usize,
         concat!(
             "Offset of field: ",
@@ -21398,9 +19992,7 @@ fn bindgen_test_layout__cef_life_span_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_life_span_handler_t>())).do_close as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_life_span_handler_t, do_close),
         56usize,
         concat!(
             "Offset of field: ",
@@ -21410,10 +20002,7 @@ fn bindgen_test_layout__cef_life_span_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_life_span_handler_t>())).on_before_close as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_life_span_handler_t, on_before_close),
         64usize,
         concat!(
             "Offset of field: ",
@@ -21572,7 +20161,7 @@ fn bindgen_test_layout__cef_render_handler_t() {
         concat!("Alignment of ", stringify!(_cef_render_handler_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_render_handler_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_render_handler_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -21582,10 +20171,7 @@ fn bindgen_test_layout__cef_render_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_render_handler_t>())).get_accessibility_handler as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_render_handler_t, get_accessibility_handler),
         40usize,
         concat!(
             "Offset of field: ",
@@ -21595,10 +20181,7 @@ fn bindgen_test_layout__cef_render_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_render_handler_t>())).get_root_screen_rect as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_render_handler_t, get_root_screen_rect),
         48usize,
         concat!(
             "Offset of field: ",
@@ -21608,9 +20191,7 @@ fn bindgen_test_layout__cef_render_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_render_handler_t>())).get_view_rect as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_render_handler_t, get_view_rect),
         56usize,
         concat!(
             "Offset of field: ",
@@ -21620,9 +20201,7 @@ fn bindgen_test_layout__cef_render_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_render_handler_t>())).get_screen_point as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_render_handler_t, get_screen_point),
         64usize,
         concat!(
             "Offset of field: ",
@@ -21632,9 +20211,7 @@ fn bindgen_test_layout__cef_render_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_render_handler_t>())).get_screen_info as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_render_handler_t, get_screen_info),
         72usize,
         concat!(
             "Offset of field: ",
@@ -21644,9 +20221,7 @@ fn bindgen_test_layout__cef_render_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_render_handler_t>())).on_popup_show as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_render_handler_t, on_popup_show),
         80usize,
         concat!(
             "Offset of field: ",
@@ -21656,9 +20231,7 @@ fn bindgen_test_layout__cef_render_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_render_handler_t>())).on_popup_size as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_render_handler_t, on_popup_size),
         88usize,
         concat!(
             "Offset of field: ",
@@ -21668,7 +20241,7 @@ fn bindgen_test_layout__cef_render_handler_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_render_handler_t>())).on_paint as *const _ as usize },
+        ::std::mem::offset_of!(_cef_render_handler_t, on_paint),
         96usize,
         concat!(
             "Offset of field: ",
@@ -21678,10 +20251,7 @@ fn bindgen_test_layout__cef_render_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_render_handler_t>())).on_accelerated_paint as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_render_handler_t, on_accelerated_paint),
         104usize,
         concat!(
             "Offset of field: ",
@@ -21691,9 +20261,7 @@ fn bindgen_test_layout__cef_render_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_render_handler_t>())).on_cursor_change as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_render_handler_t, on_cursor_change),
         112usize,
         concat!(
             "Offset of field: ",
@@ -21703,9 +20271,7 @@ fn bindgen_test_layout__cef_render_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_render_handler_t>())).start_dragging as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_render_handler_t, start_dragging),
         120usize,
         concat!(
             "Offset of field: ",
@@ -21715,10 +20281,7 @@ fn bindgen_test_layout__cef_render_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_render_handler_t>())).update_drag_cursor as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_render_handler_t, update_drag_cursor),
         128usize,
         concat!(
             "Offset of field: ",
@@ -21728,10 +20291,7 @@ fn bindgen_test_layout__cef_render_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_render_handler_t>())).on_scroll_offset_changed as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_render_handler_t, on_scroll_offset_changed),
         136usize,
         concat!(
             "Offset of field: ",
@@ -21741,10 +20301,7 @@ fn bindgen_test_layout__cef_render_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_render_handler_t>())).on_ime_composition_range_changed
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_render_handler_t, on_ime_composition_range_changed),
         144usize,
         concat!(
             "Offset of field: ",
@@ -21754,10 +20311,7 @@ fn bindgen_test_layout__cef_render_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_render_handler_t>())).on_text_selection_changed as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_render_handler_t, on_text_selection_changed),
         152usize,
         concat!(
             "Offset of field: ",
@@ -21767,10 +20321,7 @@ fn bindgen_test_layout__cef_render_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_render_handler_t>())).on_virtual_keyboard_requested
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_render_handler_t, on_virtual_keyboard_requested),
         160usize,
         concat!(
             "Offset of field: ",
@@ -21803,7 +20354,7 @@ fn bindgen_test_layout__cef_request_callback_t() {
         concat!("Alignment of ", stringify!(_cef_request_callback_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_request_callback_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_request_callback_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -21813,7 +20364,7 @@ fn bindgen_test_layout__cef_request_callback_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_request_callback_t>())).cont as *const _ as usize },
+        ::std::mem::offset_of!(_cef_request_callback_t, cont),
         40usize,
         concat!(
             "Offset of field: ",
@@ -21823,7 +20374,7 @@ fn bindgen_test_layout__cef_request_callback_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_request_callback_t>())).cancel as *const _ as usize },
+        ::std::mem::offset_of!(_cef_request_callback_t, cancel),
         48usize,
         concat!(
             "Offset of field: ",
@@ -21866,7 +20417,7 @@ fn bindgen_test_layout__cef_response_filter_t() {
         concat!("Alignment of ", stringify!(_cef_response_filter_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_response_filter_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_response_filter_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -21876,9 +20427,7 @@ fn bindgen_test_layout__cef_response_filter_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_response_filter_t>())).init_filter as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_response_filter_t, init_filter),
         40usize,
         concat!(
             "Offset of field: ",
@@ -21888,7 +20437,7 @@ fn bindgen_test_layout__cef_response_filter_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_response_filter_t>())).filter as *const _ as usize },
+        ::std::mem::offset_of!(_cef_response_filter_t, filter),
         48usize,
         concat!(
             "Offset of field: ",
@@ -21990,9 +20539,7 @@ fn bindgen_test_layout__cef_resource_request_handler_t() {
         concat!("Alignment of ", stringify!(_cef_resource_request_handler_t))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_resource_request_handler_t>())).base as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_resource_request_handler_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -22002,10 +20549,7 @@ fn bindgen_test_layout__cef_resource_request_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_resource_request_handler_t>())).get_cookie_access_filter
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_resource_request_handler_t, get_cookie_access_filter),
         40usize,
         concat!(
             "Offset of field: ",
@@ -22015,10 +20559,7 @@ fn bindgen_test_layout__cef_resource_request_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_resource_request_handler_t>())).on_before_resource_load
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_resource_request_handler_t, on_before_resource_load),
         48usize,
         concat!(
             "Offset of field: ",
@@ -22028,10 +20569,7 @@ fn bindgen_test_layout__cef_resource_request_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_resource_request_handler_t>())).get_resource_handler
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_resource_request_handler_t, get_resource_handler),
         56usize,
         concat!(
             "Offset of field: ",
@@ -22041,10 +20579,7 @@ fn bindgen_test_layout__cef_resource_request_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_resource_request_handler_t>())).on_resource_redirect
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_resource_request_handler_t, on_resource_redirect),
         64usize,
         concat!(
             "Offset of field: ",
@@ -22054,10 +20589,7 @@ fn bindgen_test_layout__cef_resource_request_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_resource_request_handler_t>())).on_resource_response
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_resource_request_handler_t, on_resource_response),
         72usize,
         concat!(
             "Offset of field: ",
@@ -22067,10 +20599,7 @@ fn bindgen_test_layout__cef_resource_request_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_resource_request_handler_t>())).get_resource_response_filter
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_resource_request_handler_t, get_resource_response_filter),
         80usize,
         concat!(
             "Offset of field: ",
@@ -22080,10 +20609,7 @@ fn bindgen_test_layout__cef_resource_request_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_resource_request_handler_t>())).on_resource_load_complete
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_resource_request_handler_t, on_resource_load_complete),
         88usize,
         concat!(
             "Offset of field: ",
@@ -22093,10 +20619,7 @@ fn bindgen_test_layout__cef_resource_request_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_resource_request_handler_t>())).on_protocol_execution
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_resource_request_handler_t, on_protocol_execution),
         96usize,
         concat!(
             "Offset of field: ",
@@ -22144,9 +20667,7 @@ fn bindgen_test_layout__cef_cookie_access_filter_t() {
         concat!("Alignment of ", stringify!(_cef_cookie_access_filter_t))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_cookie_access_filter_t>())).base as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_cookie_access_filter_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -22156,10 +20677,7 @@ fn bindgen_test_layout__cef_cookie_access_filter_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_cookie_access_filter_t>())).can_send_cookie as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_cookie_access_filter_t, can_send_cookie),
         40usize,
         concat!(
             "Offset of field: ",
@@ -22169,10 +20687,7 @@ fn bindgen_test_layout__cef_cookie_access_filter_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_cookie_access_filter_t>())).can_save_cookie as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_cookie_access_filter_t, can_save_cookie),
         48usize,
         concat!(
             "Offset of field: ",
@@ -22207,7 +20722,7 @@ fn bindgen_test_layout__cef_sslinfo_t() {
         concat!("Alignment of ", stringify!(_cef_sslinfo_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_sslinfo_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_sslinfo_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -22217,7 +20732,7 @@ fn bindgen_test_layout__cef_sslinfo_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_sslinfo_t>())).get_cert_status as *const _ as usize },
+        ::std::mem::offset_of!(_cef_sslinfo_t, get_cert_status),
         40usize,
         concat!(
             "Offset of field: ",
@@ -22227,9 +20742,7 @@ fn bindgen_test_layout__cef_sslinfo_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_sslinfo_t>())).get_x509certificate as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_sslinfo_t, get_x509certificate),
         48usize,
         concat!(
             "Offset of field: ",
@@ -22273,10 +20786,7 @@ fn bindgen_test_layout__cef_select_client_certificate_callback_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_select_client_certificate_callback_t>())).base as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_select_client_certificate_callback_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -22286,10 +20796,7 @@ fn bindgen_test_layout__cef_select_client_certificate_callback_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_select_client_certificate_callback_t>())).select as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_select_client_certificate_callback_t, select),
         40usize,
         concat!(
             "Offset of field: ",
@@ -22414,7 +20921,7 @@ fn bindgen_test_layout__cef_request_handler_t() {
         concat!("Alignment of ", stringify!(_cef_request_handler_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_request_handler_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_request_handler_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -22424,9 +20931,7 @@ fn bindgen_test_layout__cef_request_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_request_handler_t>())).on_before_browse as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_request_handler_t, on_before_browse),
         40usize,
         concat!(
             "Offset of field: ",
@@ -22436,10 +20941,7 @@ fn bindgen_test_layout__cef_request_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_request_handler_t>())).on_open_urlfrom_tab as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_request_handler_t, on_open_urlfrom_tab),
         48usize,
         concat!(
             "Offset of field: ",
@@ -22449,10 +20951,7 @@ fn bindgen_test_layout__cef_request_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_request_handler_t>())).get_resource_request_handler
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_request_handler_t, get_resource_request_handler),
         56usize,
         concat!(
             "Offset of field: ",
@@ -22462,10 +20961,7 @@ fn bindgen_test_layout__cef_request_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_request_handler_t>())).get_auth_credentials as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_request_handler_t, get_auth_credentials),
         64usize,
         concat!(
             "Offset of field: ",
@@ -22475,9 +20971,7 @@ fn bindgen_test_layout__cef_request_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_request_handler_t>())).on_quota_request as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_request_handler_t, on_quota_request),
         72usize,
         concat!(
             "Offset of field: ",
@@ -22487,10 +20981,7 @@ fn bindgen_test_layout__cef_request_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_request_handler_t>())).on_certificate_error as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_request_handler_t, on_certificate_error),
         80usize,
         concat!(
             "Offset of field: ",
@@ -22500,10 +20991,7 @@ fn bindgen_test_layout__cef_request_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_request_handler_t>())).on_select_client_certificate
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_request_handler_t, on_select_client_certificate),
         88usize,
         concat!(
             "Offset of field: ",
@@ -22513,10 +21001,7 @@ fn bindgen_test_layout__cef_request_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_request_handler_t>())).on_plugin_crashed as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_request_handler_t, on_plugin_crashed),
         96usize,
         concat!(
             "Offset of field: ",
@@ -22526,10 +21011,7 @@ fn bindgen_test_layout__cef_request_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_request_handler_t>())).on_render_view_ready as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_request_handler_t, on_render_view_ready),
         104usize,
         concat!(
             "Offset of field: ",
@@ -22539,10 +21021,7 @@ fn bindgen_test_layout__cef_request_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_request_handler_t>())).on_render_process_terminated
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_request_handler_t, on_render_process_terminated),
         112usize,
         concat!(
             "Offset of field: ",
@@ -22552,10 +21031,7 @@ fn bindgen_test_layout__cef_request_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_request_handler_t>())).on_document_available_in_main_frame
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_request_handler_t, on_document_available_in_main_frame),
         120usize,
         concat!(
             "Offset of field: ",
@@ -22635,7 +21111,7 @@ fn bindgen_test_layout__cef_client_t() {
         concat!("Alignment of ", stringify!(_cef_client_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_client_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_client_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -22645,7 +21121,7 @@ fn bindgen_test_layout__cef_client_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_client_t>())).get_audio_handler as *const _ as usize },
+        ::std::mem::offset_of!(_cef_client_t, get_audio_handler),
         40usize,
         concat!(
             "Offset of field: ",
@@ -22655,9 +21131,7 @@ fn bindgen_test_layout__cef_client_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_client_t>())).get_context_menu_handler as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_client_t, get_context_menu_handler),
         48usize,
         concat!(
             "Offset of field: ",
@@ -22667,9 +21141,7 @@ fn bindgen_test_layout__cef_client_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_client_t>())).get_dialog_handler as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_client_t, get_dialog_handler),
         56usize,
         concat!(
             "Offset of field: ",
@@ -22679,9 +21151,7 @@ fn bindgen_test_layout__cef_client_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_client_t>())).get_display_handler as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_client_t, get_display_handler),
         64usize,
         concat!(
             "Offset of field: ",
@@ -22691,9 +21161,7 @@ fn bindgen_test_layout__cef_client_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_client_t>())).get_download_handler as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_client_t, get_download_handler),
         72usize,
         concat!(
             "Offset of field: ",
@@ -22703,7 +21171,7 @@ fn bindgen_test_layout__cef_client_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_client_t>())).get_drag_handler as *const _ as usize },
+        ::std::mem::offset_of!(_cef_client_t, get_drag_handler),
         80usize,
         concat!(
             "Offset of field: ",
@@ -22713,7 +21181,7 @@ fn bindgen_test_layout__cef_client_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_client_t>())).get_find_handler as *const _ as usize },
+        ::std::mem::offset_of!(_cef_client_t, get_find_handler),
         88usize,
         concat!(
             "Offset of field: ",
@@ -22723,7 +21191,7 @@ fn bindgen_test_layout__cef_client_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_client_t>())).get_focus_handler as *const _ as usize },
+        ::std::mem::offset_of!(_cef_client_t, get_focus_handler),
         96usize,
         concat!(
             "Offset of field: ",
@@ -22733,9 +21201,7 @@ fn bindgen_test_layout__cef_client_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_client_t>())).get_jsdialog_handler as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_client_t, get_jsdialog_handler),
         104usize,
         concat!(
             "Offset of field: ",
@@ -22745,9 +21211,7 @@ fn bindgen_test_layout__cef_client_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_client_t>())).get_keyboard_handler as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_client_t, get_keyboard_handler),
         112usize,
         concat!(
             "Offset of field: ",
@@ -22757,9 +21221,7 @@ fn bindgen_test_layout__cef_client_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_client_t>())).get_life_span_handler as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_client_t, get_life_span_handler),
         120usize,
         concat!(
             "Offset of field: ",
@@ -22769,7 +21231,7 @@ fn bindgen_test_layout__cef_client_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_client_t>())).get_load_handler as *const _ as usize },
+        ::std::mem::offset_of!(_cef_client_t, get_load_handler),
         128usize,
         concat!(
             "Offset of field: ",
@@ -22779,9 +21241,7 @@ fn bindgen_test_layout__cef_client_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_client_t>())).get_render_handler as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_client_t, get_render_handler),
         136usize,
         concat!(
             "Offset of field: ",
@@ -22791,9 +21251,7 @@ fn bindgen_test_layout__cef_client_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_client_t>())).get_request_handler as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_client_t, get_request_handler),
         144usize,
         concat!(
             "Offset of field: ",
@@ -22803,10 +21261,7 @@ fn bindgen_test_layout__cef_client_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_client_t>())).on_process_message_received as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_client_t, on_process_message_received),
         152usize,
         concat!(
             "Offset of field: ",
@@ -22994,7 +21449,7 @@ fn bindgen_test_layout__cef_web_plugin_info_t() {
         concat!("Alignment of ", stringify!(_cef_web_plugin_info_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_web_plugin_info_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_web_plugin_info_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -23004,7 +21459,7 @@ fn bindgen_test_layout__cef_web_plugin_info_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_web_plugin_info_t>())).get_name as *const _ as usize },
+        ::std::mem::offset_of!(_cef_web_plugin_info_t, get_name),
         40usize,
         concat!(
             "Offset of field: ",
@@ -23014,7 +21469,7 @@ fn bindgen_test_layout__cef_web_plugin_info_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_web_plugin_info_t>())).get_path as *const _ as usize },
+        ::std::mem::offset_of!(_cef_web_plugin_info_t, get_path),
         48usize,
         concat!(
             "Offset of field: ",
@@ -23024,9 +21479,7 @@ fn bindgen_test_layout__cef_web_plugin_info_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_web_plugin_info_t>())).get_version as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_web_plugin_info_t, get_version),
         56usize,
         concat!(
             "Offset of field: ",
@@ -23036,9 +21489,7 @@ fn bindgen_test_layout__cef_web_plugin_info_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_web_plugin_info_t>())).get_description as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_web_plugin_info_t, get_description),
         64usize,
         concat!(
             "Offset of field: ",
@@ -23075,9 +21526,7 @@ fn bindgen_test_layout__cef_web_plugin_info_visitor_t() {
         concat!("Alignment of ", stringify!(_cef_web_plugin_info_visitor_t))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_web_plugin_info_visitor_t>())).base as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_web_plugin_info_visitor_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -23087,9 +21536,7 @@ fn bindgen_test_layout__cef_web_plugin_info_visitor_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_web_plugin_info_visitor_t>())).visit as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_web_plugin_info_visitor_t, visit),
         40usize,
         concat!(
             "Offset of field: ",
@@ -23128,10 +21575,7 @@ fn bindgen_test_layout__cef_web_plugin_unstable_callback_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_web_plugin_unstable_callback_t>())).base as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_web_plugin_unstable_callback_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -23141,10 +21585,7 @@ fn bindgen_test_layout__cef_web_plugin_unstable_callback_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_web_plugin_unstable_callback_t>())).is_unstable as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_web_plugin_unstable_callback_t, is_unstable),
         40usize,
         concat!(
             "Offset of field: ",
@@ -23180,9 +21621,7 @@ fn bindgen_test_layout__cef_register_cdm_callback_t() {
         concat!("Alignment of ", stringify!(_cef_register_cdm_callback_t))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_register_cdm_callback_t>())).base as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_register_cdm_callback_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -23192,10 +21631,7 @@ fn bindgen_test_layout__cef_register_cdm_callback_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_register_cdm_callback_t>())).on_cdm_registration_complete
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_register_cdm_callback_t, on_cdm_registration_complete),
         40usize,
         concat!(
             "Offset of field: ",
@@ -23277,9 +21713,7 @@ fn bindgen_test_layout__cef_request_context_handler_t() {
         concat!("Alignment of ", stringify!(_cef_request_context_handler_t))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_request_context_handler_t>())).base as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_request_context_handler_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -23289,10 +21723,7 @@ fn bindgen_test_layout__cef_request_context_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_request_context_handler_t>()))
-                .on_request_context_initialized as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_request_context_handler_t, on_request_context_initialized),
         40usize,
         concat!(
             "Offset of field: ",
@@ -23302,10 +21733,7 @@ fn bindgen_test_layout__cef_request_context_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_request_context_handler_t>())).on_before_plugin_load
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_request_context_handler_t, on_before_plugin_load),
         48usize,
         concat!(
             "Offset of field: ",
@@ -23315,10 +21743,7 @@ fn bindgen_test_layout__cef_request_context_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_request_context_handler_t>())).get_resource_request_handler
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_request_context_handler_t, get_resource_request_handler),
         56usize,
         concat!(
             "Offset of field: ",
@@ -23366,7 +21791,7 @@ fn bindgen_test_layout__cef_resource_bundle_t() {
         concat!("Alignment of ", stringify!(_cef_resource_bundle_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_resource_bundle_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_resource_bundle_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -23376,10 +21801,7 @@ fn bindgen_test_layout__cef_resource_bundle_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_resource_bundle_t>())).get_localized_string as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_resource_bundle_t, get_localized_string),
         40usize,
         concat!(
             "Offset of field: ",
@@ -23389,10 +21811,7 @@ fn bindgen_test_layout__cef_resource_bundle_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_resource_bundle_t>())).get_data_resource as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_resource_bundle_t, get_data_resource),
         48usize,
         concat!(
             "Offset of field: ",
@@ -23402,10 +21821,7 @@ fn bindgen_test_layout__cef_resource_bundle_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_resource_bundle_t>())).get_data_resource_for_scale
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_resource_bundle_t, get_data_resource_for_scale),
         56usize,
         concat!(
             "Offset of field: ",
@@ -23504,7 +21920,7 @@ fn bindgen_test_layout__cef_server_t() {
         concat!("Alignment of ", stringify!(_cef_server_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_server_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_server_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -23514,7 +21930,7 @@ fn bindgen_test_layout__cef_server_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_server_t>())).get_task_runner as *const _ as usize },
+        ::std::mem::offset_of!(_cef_server_t, get_task_runner),
         40usize,
         concat!(
             "Offset of field: ",
@@ -23524,7 +21940,7 @@ fn bindgen_test_layout__cef_server_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_server_t>())).shutdown as *const _ as usize },
+        ::std::mem::offset_of!(_cef_server_t, shutdown),
         48usize,
         concat!(
             "Offset of field: ",
@@ -23534,7 +21950,7 @@ fn bindgen_test_layout__cef_server_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_server_t>())).is_running as *const _ as usize },
+        ::std::mem::offset_of!(_cef_server_t, is_running),
         56usize,
         concat!(
             "Offset of field: ",
@@ -23544,7 +21960,7 @@ fn bindgen_test_layout__cef_server_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_server_t>())).get_address as *const _ as usize },
+        ::std::mem::offset_of!(_cef_server_t, get_address),
         64usize,
         concat!(
             "Offset of field: ",
@@ -23554,7 +21970,7 @@ fn bindgen_test_layout__cef_server_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_server_t>())).has_connection as *const _ as usize },
+        ::std::mem::offset_of!(_cef_server_t, has_connection),
         72usize,
         concat!(
             "Offset of field: ",
@@ -23564,9 +21980,7 @@ fn bindgen_test_layout__cef_server_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_server_t>())).is_valid_connection as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_server_t, is_valid_connection),
         80usize,
         concat!(
             "Offset of field: ",
@@ -23576,9 +21990,7 @@ fn bindgen_test_layout__cef_server_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_server_t>())).send_http200response as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_server_t, send_http200response),
         88usize,
         concat!(
             "Offset of field: ",
@@ -23588,9 +22000,7 @@ fn bindgen_test_layout__cef_server_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_server_t>())).send_http404response as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_server_t, send_http404response),
         96usize,
         concat!(
             "Offset of field: ",
@@ -23600,9 +22010,7 @@ fn bindgen_test_layout__cef_server_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_server_t>())).send_http500response as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_server_t, send_http500response),
         104usize,
         concat!(
             "Offset of field: ",
@@ -23612,9 +22020,7 @@ fn bindgen_test_layout__cef_server_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_server_t>())).send_http_response as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_server_t, send_http_response),
         112usize,
         concat!(
             "Offset of field: ",
@@ -23624,7 +22030,7 @@ fn bindgen_test_layout__cef_server_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_server_t>())).send_raw_data as *const _ as usize },
+        ::std::mem::offset_of!(_cef_server_t, send_raw_data),
         120usize,
         concat!(
             "Offset of field: ",
@@ -23634,7 +22040,7 @@ fn bindgen_test_layout__cef_server_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_server_t>())).close_connection as *const _ as usize },
+        ::std::mem::offset_of!(_cef_server_t, close_connection),
         128usize,
         concat!(
             "Offset of field: ",
@@ -23644,9 +22050,7 @@ fn bindgen_test_layout__cef_server_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_server_t>())).send_web_socket_message as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_server_t, send_web_socket_message),
         136usize,
         concat!(
             "Offset of field: ",
@@ -23738,7 +22142,7 @@ fn bindgen_test_layout__cef_server_handler_t() {
         concat!("Alignment of ", stringify!(_cef_server_handler_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_server_handler_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_server_handler_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -23748,9 +22152,7 @@ fn bindgen_test_layout__cef_server_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_server_handler_t>())).on_server_created as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_server_handler_t, on_server_created),
         40usize,
         concat!(
             "Offset of field: ",
@@ -23760,10 +22162,7 @@ fn bindgen_test_layout__cef_server_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_server_handler_t>())).on_server_destroyed as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_server_handler_t, on_server_destroyed),
         48usize,
         concat!(
             "Offset of field: ",
@@ -23773,10 +22172,7 @@ fn bindgen_test_layout__cef_server_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_server_handler_t>())).on_client_connected as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_server_handler_t, on_client_connected),
         56usize,
         concat!(
             "Offset of field: ",
@@ -23786,10 +22182,7 @@ fn bindgen_test_layout__cef_server_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_server_handler_t>())).on_client_disconnected as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_server_handler_t, on_client_disconnected),
         64usize,
         concat!(
             "Offset of field: ",
@@ -23799,9 +22192,7 @@ fn bindgen_test_layout__cef_server_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_server_handler_t>())).on_http_request as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_server_handler_t, on_http_request),
         72usize,
         concat!(
             "Offset of field: ",
@@ -23811,10 +22202,7 @@ fn bindgen_test_layout__cef_server_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_server_handler_t>())).on_web_socket_request as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_server_handler_t, on_web_socket_request),
         80usize,
         concat!(
             "Offset of field: ",
@@ -23824,10 +22212,7 @@ fn bindgen_test_layout__cef_server_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_server_handler_t>())).on_web_socket_connected as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_server_handler_t, on_web_socket_connected),
         88usize,
         concat!(
             "Offset of field: ",
@@ -23837,10 +22222,7 @@ fn bindgen_test_layout__cef_server_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_server_handler_t>())).on_web_socket_message as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_server_handler_t, on_web_socket_message),
         96usize,
         concat!(
             "Offset of field: ",
@@ -23888,7 +22270,7 @@ fn bindgen_test_layout__cef_thread_t() {
         concat!("Alignment of ", stringify!(_cef_thread_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_thread_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_thread_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -23898,7 +22280,7 @@ fn bindgen_test_layout__cef_thread_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_thread_t>())).get_task_runner as *const _ as usize },
+        ::std::mem::offset_of!(_cef_thread_t, get_task_runner),
         40usize,
         concat!(
             "Offset of field: ",
@@ -23908,9 +22290,7 @@ fn bindgen_test_layout__cef_thread_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_thread_t>())).get_platform_thread_id as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_thread_t, get_platform_thread_id),
         48usize,
         concat!(
             "Offset of field: ",
@@ -23920,7 +22300,7 @@ fn bindgen_test_layout__cef_thread_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_thread_t>())).stop as *const _ as usize },
+        ::std::mem::offset_of!(_cef_thread_t, stop),
         56usize,
         concat!(
             "Offset of field: ",
@@ -23930,7 +22310,7 @@ fn bindgen_test_layout__cef_thread_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_thread_t>())).is_running as *const _ as usize },
+        ::std::mem::offset_of!(_cef_thread_t, is_running),
         64usize,
         concat!(
             "Offset of field: ",
@@ -23974,9 +22354,7 @@ fn bindgen_test_layout__cef_end_tracing_callback_t() {
         concat!("Alignment of ", stringify!(_cef_end_tracing_callback_t))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_end_tracing_callback_t>())).base as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_end_tracing_callback_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -23986,10 +22364,7 @@ fn bindgen_test_layout__cef_end_tracing_callback_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_end_tracing_callback_t>())).on_end_tracing_complete
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_end_tracing_callback_t, on_end_tracing_complete),
         40usize,
         concat!(
             "Offset of field: ",
@@ -24052,7 +22427,7 @@ fn bindgen_test_layout__cef_urlrequest_t() {
         concat!("Alignment of ", stringify!(_cef_urlrequest_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_urlrequest_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_urlrequest_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -24062,7 +22437,7 @@ fn bindgen_test_layout__cef_urlrequest_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_urlrequest_t>())).get_request as *const _ as usize },
+        ::std::mem::offset_of!(_cef_urlrequest_t, get_request),
         40usize,
         concat!(
             "Offset of field: ",
@@ -24072,7 +22447,7 @@ fn bindgen_test_layout__cef_urlrequest_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_urlrequest_t>())).get_client as *const _ as usize },
+        ::std::mem::offset_of!(_cef_urlrequest_t, get_client),
         48usize,
         concat!(
             "Offset of field: ",
@@ -24082,9 +22457,7 @@ fn bindgen_test_layout__cef_urlrequest_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_urlrequest_t>())).get_request_status as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_urlrequest_t, get_request_status),
         56usize,
         concat!(
             "Offset of field: ",
@@ -24094,9 +22467,7 @@ fn bindgen_test_layout__cef_urlrequest_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_urlrequest_t>())).get_request_error as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_urlrequest_t, get_request_error),
         64usize,
         concat!(
             "Offset of field: ",
@@ -24106,7 +22477,7 @@ fn bindgen_test_layout__cef_urlrequest_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_urlrequest_t>())).get_response as *const _ as usize },
+        ::std::mem::offset_of!(_cef_urlrequest_t, get_response),
         72usize,
         concat!(
             "Offset of field: ",
@@ -24116,9 +22487,7 @@ fn bindgen_test_layout__cef_urlrequest_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_urlrequest_t>())).response_was_cached as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_urlrequest_t, response_was_cached),
         80usize,
         concat!(
             "Offset of field: ",
@@ -24128,7 +22497,7 @@ fn bindgen_test_layout__cef_urlrequest_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_urlrequest_t>())).cancel as *const _ as usize },
+        ::std::mem::offset_of!(_cef_urlrequest_t, cancel),
         88usize,
         concat!(
             "Offset of field: ",
@@ -24202,7 +22571,7 @@ fn bindgen_test_layout__cef_urlrequest_client_t() {
         concat!("Alignment of ", stringify!(_cef_urlrequest_client_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_urlrequest_client_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_urlrequest_client_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -24212,10 +22581,7 @@ fn bindgen_test_layout__cef_urlrequest_client_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_urlrequest_client_t>())).on_request_complete as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_urlrequest_client_t, on_request_complete),
         40usize,
         concat!(
             "Offset of field: ",
@@ -24225,10 +22591,7 @@ fn bindgen_test_layout__cef_urlrequest_client_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_urlrequest_client_t>())).on_upload_progress as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_urlrequest_client_t, on_upload_progress),
         48usize,
         concat!(
             "Offset of field: ",
@@ -24238,10 +22601,7 @@ fn bindgen_test_layout__cef_urlrequest_client_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_urlrequest_client_t>())).on_download_progress as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_urlrequest_client_t, on_download_progress),
         56usize,
         concat!(
             "Offset of field: ",
@@ -24251,10 +22611,7 @@ fn bindgen_test_layout__cef_urlrequest_client_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_urlrequest_client_t>())).on_download_data as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_urlrequest_client_t, on_download_data),
         64usize,
         concat!(
             "Offset of field: ",
@@ -24264,10 +22621,7 @@ fn bindgen_test_layout__cef_urlrequest_client_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_urlrequest_client_t>())).get_auth_credentials as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_urlrequest_client_t, get_auth_credentials),
         72usize,
         concat!(
             "Offset of field: ",
@@ -24308,7 +22662,7 @@ fn bindgen_test_layout__cef_waitable_event_t() {
         concat!("Alignment of ", stringify!(_cef_waitable_event_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_waitable_event_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_waitable_event_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -24318,7 +22672,7 @@ fn bindgen_test_layout__cef_waitable_event_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_waitable_event_t>())).reset as *const _ as usize },
+        ::std::mem::offset_of!(_cef_waitable_event_t, reset),
         40usize,
         concat!(
             "Offset of field: ",
@@ -24328,7 +22682,7 @@ fn bindgen_test_layout__cef_waitable_event_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_waitable_event_t>())).signal as *const _ as usize },
+        ::std::mem::offset_of!(_cef_waitable_event_t, signal),
         48usize,
         concat!(
             "Offset of field: ",
@@ -24338,9 +22692,7 @@ fn bindgen_test_layout__cef_waitable_event_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_waitable_event_t>())).is_signaled as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_waitable_event_t, is_signaled),
         56usize,
         concat!(
             "Offset of field: ",
@@ -24350,7 +22702,7 @@ fn bindgen_test_layout__cef_waitable_event_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_waitable_event_t>())).wait as *const _ as usize },
+        ::std::mem::offset_of!(_cef_waitable_event_t, wait),
         64usize,
         concat!(
             "Offset of field: ",
@@ -24360,9 +22712,7 @@ fn bindgen_test_layout__cef_waitable_event_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_waitable_event_t>())).timed_wait as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_waitable_event_t, timed_wait),
         72usize,
         concat!(
             "Offset of field: ",
@@ -24503,7 +22853,7 @@ fn bindgen_test_layout__cef_xml_reader_t() {
         concat!("Alignment of ", stringify!(_cef_xml_reader_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_xml_reader_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_xml_reader_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -24513,9 +22863,7 @@ fn bindgen_test_layout__cef_xml_reader_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_xml_reader_t>())).move_to_next_node as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_xml_reader_t, move_to_next_node),
         40usize,
         concat!(
             "Offset of field: ",
@@ -24525,7 +22873,7 @@ fn bindgen_test_layout__cef_xml_reader_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_xml_reader_t>())).close as *const _ as usize },
+        ::std::mem::offset_of!(_cef_xml_reader_t, close),
         48usize,
         concat!(
             "Offset of field: ",
@@ -24535,7 +22883,7 @@ fn bindgen_test_layout__cef_xml_reader_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_xml_reader_t>())).has_error as *const _ as usize },
+        ::std::mem::offset_of!(_cef_xml_reader_t, has_error),
         56usize,
         concat!(
             "Offset of field: ",
@@ -24545,7 +22893,7 @@ fn bindgen_test_layout__cef_xml_reader_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_xml_reader_t>())).get_error as *const _ as usize },
+        ::std::mem::offset_of!(_cef_xml_reader_t, get_error),
         64usize,
         concat!(
             "Offset of field: ",
@@ -24555,7 +22903,7 @@ fn bindgen_test_layout__cef_xml_reader_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_xml_reader_t>())).get_type as *const _ as usize },
+        ::std::mem::offset_of!(_cef_xml_reader_t, get_type),
         72usize,
         concat!(
             "Offset of field: ",
@@ -24565,7 +22913,7 @@ fn bindgen_test_layout__cef_xml_reader_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_xml_reader_t>())).get_depth as *const _ as usize },
+        ::std::mem::offset_of!(_cef_xml_reader_t, get_depth),
         80usize,
         concat!(
             "Offset of field: ",
@@ -24575,9 +22923,7 @@ fn bindgen_test_layout__cef_xml_reader_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_xml_reader_t>())).get_local_name as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_xml_reader_t, get_local_name),
         88usize,
         concat!(
             "Offset of field: ",
@@ -24587,7 +22933,7 @@ fn bindgen_test_layout__cef_xml_reader_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_xml_reader_t>())).get_prefix as *const _ as usize },
+        ::std::mem::offset_of!(_cef_xml_reader_t, get_prefix),
         96usize,
         concat!(
             "Offset of field: ",
@@ -24597,9 +22943,7 @@ fn bindgen_test_layout__cef_xml_reader_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_xml_reader_t>())).get_qualified_name as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_xml_reader_t, get_qualified_name),
         104usize,
         concat!(
             "Offset of field: ",
@@ -24609,9 +22953,7 @@ fn bindgen_test_layout__cef_xml_reader_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_xml_reader_t>())).get_namespace_uri as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_xml_reader_t, get_namespace_uri),
         112usize,
         concat!(
             "Offset of field: ",
@@ -24621,7 +22963,7 @@ fn bindgen_test_layout__cef_xml_reader_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_xml_reader_t>())).get_base_uri as *const _ as usize },
+        ::std::mem::offset_of!(_cef_xml_reader_t, get_base_uri),
         120usize,
         concat!(
             "Offset of field: ",
@@ -24631,7 +22973,7 @@ fn bindgen_test_layout__cef_xml_reader_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_xml_reader_t>())).get_xml_lang as *const _ as usize },
+        ::std::mem::offset_of!(_cef_xml_reader_t, get_xml_lang),
         128usize,
         concat!(
             "Offset of field: ",
@@ -24641,9 +22983,7 @@ fn bindgen_test_layout__cef_xml_reader_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_xml_reader_t>())).is_empty_element as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_xml_reader_t, is_empty_element),
         136usize,
         concat!(
             "Offset of field: ",
@@ -24653,7 +22993,7 @@ fn bindgen_test_layout__cef_xml_reader_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_xml_reader_t>())).has_value as *const _ as usize },
+        ::std::mem::offset_of!(_cef_xml_reader_t, has_value),
         144usize,
         concat!(
             "Offset of field: ",
@@ -24663,7 +23003,7 @@ fn bindgen_test_layout__cef_xml_reader_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_xml_reader_t>())).get_value as *const _ as usize },
+        ::std::mem::offset_of!(_cef_xml_reader_t, get_value),
         152usize,
         concat!(
             "Offset of field: ",
@@ -24673,9 +23013,7 @@ fn bindgen_test_layout__cef_xml_reader_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_xml_reader_t>())).has_attributes as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_xml_reader_t, has_attributes),
         160usize,
         concat!(
             "Offset of field: ",
@@ -24685,9 +23023,7 @@ fn bindgen_test_layout__cef_xml_reader_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_xml_reader_t>())).get_attribute_count as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_xml_reader_t, get_attribute_count),
         168usize,
         concat!(
             "Offset of field: ",
@@ -24697,9 +23033,7 @@ fn bindgen_test_layout__cef_xml_reader_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_xml_reader_t>())).get_attribute_byindex as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_xml_reader_t, get_attribute_byindex),
         176usize,
         concat!(
             "Offset of field: ",
@@ -24709,9 +23043,7 @@ fn bindgen_test_layout__cef_xml_reader_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_xml_reader_t>())).get_attribute_byqname as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_xml_reader_t, get_attribute_byqname),
         184usize,
         concat!(
             "Offset of field: ",
@@ -24721,9 +23053,7 @@ fn bindgen_test_layout__cef_xml_reader_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_xml_reader_t>())).get_attribute_bylname as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_xml_reader_t, get_attribute_bylname),
         192usize,
         concat!(
             "Offset of field: ",
@@ -24733,7 +23063,7 @@ fn bindgen_test_layout__cef_xml_reader_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_xml_reader_t>())).get_inner_xml as *const _ as usize },
+        ::std::mem::offset_of!(_cef_xml_reader_t, get_inner_xml),
         200usize,
         concat!(
             "Offset of field: ",
@@ -24743,7 +23073,7 @@ fn bindgen_test_layout__cef_xml_reader_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_xml_reader_t>())).get_outer_xml as *const _ as usize },
+        ::std::mem::offset_of!(_cef_xml_reader_t, get_outer_xml),
         208usize,
         concat!(
             "Offset of field: ",
@@ -24753,9 +23083,7 @@ fn bindgen_test_layout__cef_xml_reader_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_xml_reader_t>())).get_line_number as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_xml_reader_t, get_line_number),
         216usize,
         concat!(
             "Offset of field: ",
@@ -24765,10 +23093,7 @@ fn bindgen_test_layout__cef_xml_reader_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_xml_reader_t>())).move_to_attribute_byindex as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_xml_reader_t, move_to_attribute_byindex),
         224usize,
         concat!(
             "Offset of field: ",
@@ -24778,10 +23103,7 @@ fn bindgen_test_layout__cef_xml_reader_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_xml_reader_t>())).move_to_attribute_byqname as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_xml_reader_t, move_to_attribute_byqname),
         232usize,
         concat!(
             "Offset of field: ",
@@ -24791,10 +23113,7 @@ fn bindgen_test_layout__cef_xml_reader_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_xml_reader_t>())).move_to_attribute_bylname as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_xml_reader_t, move_to_attribute_bylname),
         240usize,
         concat!(
             "Offset of field: ",
@@ -24804,10 +23123,7 @@ fn bindgen_test_layout__cef_xml_reader_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_xml_reader_t>())).move_to_first_attribute as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_xml_reader_t, move_to_first_attribute),
         248usize,
         concat!(
             "Offset of field: ",
@@ -24817,10 +23133,7 @@ fn bindgen_test_layout__cef_xml_reader_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_xml_reader_t>())).move_to_next_attribute as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_xml_reader_t, move_to_next_attribute),
         256usize,
         concat!(
             "Offset of field: ",
@@ -24830,10 +23143,7 @@ fn bindgen_test_layout__cef_xml_reader_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_xml_reader_t>())).move_to_carrying_element as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_xml_reader_t, move_to_carrying_element),
         264usize,
         concat!(
             "Offset of field: ",
@@ -24912,7 +23222,7 @@ fn bindgen_test_layout__cef_zip_reader_t() {
         concat!("Alignment of ", stringify!(_cef_zip_reader_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_zip_reader_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_zip_reader_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -24922,9 +23232,7 @@ fn bindgen_test_layout__cef_zip_reader_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_zip_reader_t>())).move_to_first_file as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_zip_reader_t, move_to_first_file),
         40usize,
         concat!(
             "Offset of field: ",
@@ -24934,9 +23242,7 @@ fn bindgen_test_layout__cef_zip_reader_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_zip_reader_t>())).move_to_next_file as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_zip_reader_t, move_to_next_file),
         48usize,
         concat!(
             "Offset of field: ",
@@ -24946,7 +23252,7 @@ fn bindgen_test_layout__cef_zip_reader_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_zip_reader_t>())).move_to_file as *const _ as usize },
+        ::std::mem::offset_of!(_cef_zip_reader_t, move_to_file),
         56usize,
         concat!(
             "Offset of field: ",
@@ -24956,7 +23262,7 @@ fn bindgen_test_layout__cef_zip_reader_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_zip_reader_t>())).close as *const _ as usize },
+        ::std::mem::offset_of!(_cef_zip_reader_t, close),
         64usize,
         concat!(
             "Offset of field: ",
@@ -24966,7 +23272,7 @@ fn bindgen_test_layout__cef_zip_reader_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_zip_reader_t>())).get_file_name as *const _ as usize },
+        ::std::mem::offset_of!(_cef_zip_reader_t, get_file_name),
         72usize,
         concat!(
             "Offset of field: ",
@@ -24976,7 +23282,7 @@ fn bindgen_test_layout__cef_zip_reader_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_zip_reader_t>())).get_file_size as *const _ as usize },
+        ::std::mem::offset_of!(_cef_zip_reader_t, get_file_size),
         80usize,
         concat!(
             "Offset of field: ",
@@ -24986,10 +23292,7 @@ fn bindgen_test_layout__cef_zip_reader_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_zip_reader_t>())).get_file_last_modified as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_zip_reader_t, get_file_last_modified),
         88usize,
         concat!(
             "Offset of field: ",
@@ -24999,7 +23302,7 @@ fn bindgen_test_layout__cef_zip_reader_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_zip_reader_t>())).open_file as *const _ as usize },
+        ::std::mem::offset_of!(_cef_zip_reader_t, open_file),
         96usize,
         concat!(
             "Offset of field: ",
@@ -25009,7 +23312,7 @@ fn bindgen_test_layout__cef_zip_reader_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_zip_reader_t>())).close_file as *const _ as usize },
+        ::std::mem::offset_of!(_cef_zip_reader_t, close_file),
         104usize,
         concat!(
             "Offset of field: ",
@@ -25019,7 +23322,7 @@ fn bindgen_test_layout__cef_zip_reader_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_zip_reader_t>())).read_file as *const _ as usize },
+        ::std::mem::offset_of!(_cef_zip_reader_t, read_file),
         112usize,
         concat!(
             "Offset of field: ",
@@ -25029,7 +23332,7 @@ fn bindgen_test_layout__cef_zip_reader_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_zip_reader_t>())).tell as *const _ as usize },
+        ::std::mem::offset_of!(_cef_zip_reader_t, tell),
         120usize,
         concat!(
             "Offset of field: ",
@@ -25039,7 +23342,7 @@ fn bindgen_test_layout__cef_zip_reader_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_zip_reader_t>())).eof as *const _ as usize },
+        ::std::mem::offset_of!(_cef_zip_reader_t, eof),
         128usize,
         concat!(
             "Offset of field: ",
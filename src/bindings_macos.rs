@@ -36,9 +36,7 @@ fn bindgen_test_layout___darwin_pthread_handler_rec() {
         concat!("Alignment of ", stringify!(__darwin_pthread_handler_rec))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<__darwin_pthread_handler_rec>())).__routine as *const _ as usize
-        },
+        ::std::mem::offset_of!(__darwin_pthread_handler_rec, __routine),
         0usize,
         concat!(
             "Offset of field: ",
@@ -48,9 +46,7 @@ fn bindgen_test_layout___darwin_pthread_handler_rec() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<__darwin_pthread_handler_rec>())).__arg as *const _ as usize
-        },
+        ::std::mem::offset_of!(__darwin_pthread_handler_rec, __arg),
         8usize,
         concat!(
             "Offset of field: ",
@@ -60,9 +56,7 @@ fn bindgen_test_layout___darwin_pthread_handler_rec() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<__darwin_pthread_handler_rec>())).__next as *const _ as usize
-        },
+        ::std::mem::offset_of!(__darwin_pthread_handler_rec, __next),
         16usize,
         concat!(
             "Offset of field: ",
@@ -92,7 +86,7 @@ fn bindgen_test_layout__opaque_pthread_t() {
         concat!("Alignment of ", stringify!(_opaque_pthread_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_opaque_pthread_t>())).__sig as *const _ as usize },
+        ::std::mem::offset_of!(_opaque_pthread_t, __sig),
         0usize,
         concat!(
             "Offset of field: ",
@@ -102,9 +96,7 @@ fn bindgen_test_layout__opaque_pthread_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_opaque_pthread_t>())).__cleanup_stack as *const _ as usize
-        },
+        ::std::mem::offset_of!(_opaque_pthread_t, __cleanup_stack),
         8usize,
         concat!(
             "Offset of field: ",
@@ -114,7 +106,7 @@ fn bindgen_test_layout__opaque_pthread_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_opaque_pthread_t>())).__opaque as *const _ as usize },
+        ::std::mem::offset_of!(_opaque_pthread_t, __opaque),
         16usize,
         concat!(
             "Offset of field: ",
@@ -152,7 +144,7 @@ fn bindgen_test_layout__cef_string_wide_t() {
         concat!("Alignment of ", stringify!(_cef_string_wide_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_string_wide_t>())).str_ as *const _ as usize },
+        ::std::mem::offset_of!(_cef_string_wide_t, str_),
         0usize,
         concat!(
             "Offset of field: ",
@@ -162,7 +154,7 @@ fn bindgen_test_layout__cef_string_wide_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_string_wide_t>())).length as *const _ as usize },
+        ::std::mem::offset_of!(_cef_string_wide_t, length),
         8usize,
         concat!(
             "Offset of field: ",
@@ -172,7 +164,7 @@ fn bindgen_test_layout__cef_string_wide_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_string_wide_t>())).dtor as *const _ as usize },
+        ::std::mem::offset_of!(_cef_string_wide_t, dtor),
         16usize,
         concat!(
             "Offset of field: ",
@@ -203,7 +195,7 @@ fn bindgen_test_layout__cef_string_utf8_t() {
         concat!("Alignment of ", stringify!(_cef_string_utf8_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_string_utf8_t>())).str_ as *const _ as usize },
+        ::std::mem::offset_of!(_cef_string_utf8_t, str_),
         0usize,
         concat!(
             "Offset of field: ",
@@ -213,7 +205,7 @@ fn bindgen_test_layout__cef_string_utf8_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_string_utf8_t>())).length as *const _ as usize },
+        ::std::mem::offset_of!(_cef_string_utf8_t, length),
         8usize,
         concat!(
             "Offset of field: ",
@@ -223,7 +215,7 @@ fn bindgen_test_layout__cef_string_utf8_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_string_utf8_t>())).dtor as *const _ as usize },
+        ::std::mem::offset_of!(_cef_string_utf8_t, dtor),
         16usize,
         concat!(
             "Offset of field: ",
@@ -254,7 +246,7 @@ fn bindgen_test_layout__cef_string_utf16_t() {
         concat!("Alignment of ", stringify!(_cef_string_utf16_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_string_utf16_t>())).str_ as *const _ as usize },
+        ::std::mem::offset_of!(_cef_string_utf16_t, str_),
         0usize,
         concat!(
             "Offset of field: ",
@@ -264,7 +256,7 @@ fn bindgen_test_layout__cef_string_utf16_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_string_utf16_t>())).length as *const _ as usize },
+        ::std::mem::offset_of!(_cef_string_utf16_t, length),
         8usize,
         concat!(
             "Offset of field: ",
@@ -274,7 +266,7 @@ fn bindgen_test_layout__cef_string_utf16_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_string_utf16_t>())).dtor as *const _ as usize },
+        ::std::mem::offset_of!(_cef_string_utf16_t, dtor),
         16usize,
         concat!(
             "Offset of field: ",
@@ -571,7 +563,7 @@ fn bindgen_test_layout__cef_time_t() {
         concat!("Alignment of ", stringify!(_cef_time_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_time_t>())).year as *const _ as usize },
+        ::std::mem::offset_of!(_cef_time_t, year),
         0usize,
         concat!(
             "Offset of field: ",
@@ -581,7 +573,7 @@ fn bindgen_test_layout__cef_time_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_time_t>())).month as *const _ as usize },
+        ::std::mem::offset_of!(_cef_time_t, month),
         4usize,
         concat!(
             "Offset of field: ",
@@ -591,7 +583,7 @@ fn bindgen_test_layout__cef_time_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_time_t>())).day_of_week as *const _ as usize },
+        ::std::mem::offset_of!(_cef_time_t, day_of_week),
         8usize,
         concat!(
             "Offset of field: ",
@@ -601,7 +593,7 @@ fn bindgen_test_layout__cef_time_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_time_t>())).day_of_month as *const _ as usize },
+        ::std::mem::offset_of!(_cef_time_t, day_of_month),
         12usize,
         concat!(
             "Offset of field: ",
@@ -611,7 +603,7 @@ fn bindgen_test_layout__cef_time_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_time_t>())).hour as *const _ as usize },
+        ::std::mem::offset_of!(_cef_time_t, hour),
         16usize,
         concat!(
             "Offset of field: ",
@@ -621,7 +613,7 @@ fn bindgen_test_layout__cef_time_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_time_t>())).minute as *const _ as usize },
+        ::std::mem::offset_of!(_cef_time_t, minute),
         20usize,
         concat!(
             "Offset of field: ",
@@ -631,7 +623,7 @@ fn bindgen_test_layout__cef_time_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_time_t>())).second as *const _ as usize },
+        ::std::mem::offset_of!(_cef_time_t, second),
         24usize,
         concat!(
             "Offset of field: ",
@@ -641,7 +633,7 @@ fn bindgen_test_layout__cef_time_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_time_t>())).millisecond as *const _ as usize },
+        ::std::mem::offset_of!(_cef_time_t, millisecond),
         28usize,
         concat!(
             "Offset of field: ",
@@ -699,7 +691,7 @@ fn bindgen_test_layout__cef_main_args_t() {
         concat!("Alignment of ", stringify!(_cef_main_args_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_main_args_t>())).argc as *const _ as usize },
+        ::std::mem::offset_of!(_cef_main_args_t, argc),
         0usize,
         concat!(
             "Offset of field: ",
@@ -709,7 +701,7 @@ fn bindgen_test_layout__cef_main_args_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_main_args_t>())).argv as *const _ as usize },
+        ::std::mem::offset_of!(_cef_main_args_t, argv),
         8usize,
         concat!(
             "Offset of field: ",
@@ -748,7 +740,7 @@ fn bindgen_test_layout__cef_window_info_t() {
         concat!("Alignment of ", stringify!(_cef_window_info_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_window_info_t>())).window_name as *const _ as usize },
+        ::std::mem::offset_of!(_cef_window_info_t, window_name),
         0usize,
         concat!(
             "Offset of field: ",
@@ -758,7 +750,7 @@ fn bindgen_test_layout__cef_window_info_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_window_info_t>())).x as *const _ as usize },
+        ::std::mem::offset_of!(_cef_window_info_t, x),
         24usize,
         concat!(
             "Offset of field: ",
@@ -768,7 +760,7 @@ fn bindgen_test_layout__cef_window_info_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_window_info_t>())).y as *const _ as usize },
+        ::std::mem::offset_of!(_cef_window_info_t, y),
         28usize,
         concat!(
             "Offset of field: ",
@@ -778,7 +770,7 @@ fn bindgen_test_layout__cef_window_info_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_window_info_t>())).width as *const _ as usize },
+        ::std::mem::offset_of!(_cef_window_info_t, width),
         32usize,
         concat!(
             "Offset of field: ",
@@ -788,7 +780,7 @@ fn bindgen_test_layout__cef_window_info_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_window_info_t>())).height as *const _ as usize },
+        ::std::mem::offset_of!(_cef_window_info_t, height),
         36usize,
         concat!(
             "Offset of field: ",
@@ -798,7 +790,7 @@ fn bindgen_test_layout__cef_window_info_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_window_info_t>())).hidden as *const _ as usize },
+        ::std::mem::offset_of!(_cef_window_info_t, hidden),
         40usize,
         concat!(
             "Offset of field: ",
@@ -808,7 +800,7 @@ fn bindgen_test_layout__cef_window_info_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_window_info_t>())).parent_view as *const _ as usize },
+        ::std::mem::offset_of!(_cef_window_info_t, parent_view),
         48usize,
         concat!(
             "Offset of field: ",
@@ -818,10 +810,7 @@ fn bindgen_test_layout__cef_window_info_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_window_info_t>())).windowless_rendering_enabled as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_window_info_t, windowless_rendering_enabled),
         56usize,
         concat!(
             "Offset of field: ",
@@ -831,10 +820,7 @@ fn bindgen_test_layout__cef_window_info_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_window_info_t>())).shared_texture_enabled as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_window_info_t, shared_texture_enabled),
         60usize,
         concat!(
             "Offset of field: ",
@@ -844,10 +830,7 @@ fn bindgen_test_layout__cef_window_info_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_window_info_t>())).external_begin_frame_enabled as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_window_info_t, external_begin_frame_enabled),
         64usize,
         concat!(
             "Offset of field: ",
@@ -857,7 +840,7 @@ fn bindgen_test_layout__cef_window_info_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_window_info_t>())).view as *const _ as usize },
+        ::std::mem::offset_of!(_cef_window_info_t, view),
         72usize,
         concat!(
             "Offset of field: ",
@@ -932,7 +915,7 @@ fn bindgen_test_layout__cef_settings_t() {
         concat!("Alignment of ", stringify!(_cef_settings_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_settings_t>())).size as *const _ as usize },
+        ::std::mem::offset_of!(_cef_settings_t, size),
         0usize,
         concat!(
             "Offset of field: ",
@@ -942,7 +925,7 @@ fn bindgen_test_layout__cef_settings_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_settings_t>())).no_sandbox as *const _ as usize },
+        ::std::mem::offset_of!(_cef_settings_t, no_sandbox),
         8usize,
         concat!(
             "Offset of field: ",
@@ -952,9 +935,7 @@ fn bindgen_test_layout__cef_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_settings_t>())).browser_subprocess_path as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_settings_t, browser_subprocess_path),
         16usize,
         concat!(
             "Offset of field: ",
@@ -964,9 +945,7 @@ fn bindgen_test_layout__cef_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_settings_t>())).framework_dir_path as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_settings_t, framework_dir_path),
         40usize,
         concat!(
             "Offset of field: ",
@@ -976,9 +955,7 @@ fn bindgen_test_layout__cef_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_settings_t>())).main_bundle_path as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_settings_t, main_bundle_path),
         64usize,
         concat!(
             "Offset of field: ",
@@ -988,10 +965,7 @@ fn bindgen_test_layout__cef_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_settings_t>())).multi_threaded_message_loop as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_settings_t, multi_threaded_message_loop),
         88usize,
         concat!(
             "Offset of field: ",
@@ -1001,9 +975,7 @@ fn bindgen_test_layout__cef_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_settings_t>())).external_message_pump as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_settings_t, external_message_pump),
         92usize,
         concat!(
             "Offset of field: ",
@@ -1013,10 +985,7 @@ fn bindgen_test_layout__cef_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_settings_t>())).windowless_rendering_enabled as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_settings_t, windowless_rendering_enabled),
         96usize,
         concat!(
             "Offset of field: ",
@@ -1026,10 +995,7 @@ fn bindgen_test_layout__cef_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_settings_t>())).command_line_args_disabled as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_settings_t, command_line_args_disabled),
         100usize,
         concat!(
             "Offset of field: ",
@@ -1039,7 +1005,7 @@ fn bindgen_test_layout__cef_settings_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_settings_t>())).cache_path as *const _ as usize },
+        ::std::mem::offset_of!(_cef_settings_t, cache_path),
         104usize,
         concat!(
             "Offset of field: ",
@@ -1049,7 +1015,7 @@ fn bindgen_test_layout__cef_settings_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_settings_t>())).root_cache_path as *const _ as usize },
+        ::std::mem::offset_of!(_cef_settings_t, root_cache_path),
         128usize,
         concat!(
             "Offset of field: ",
@@ -1059,7 +1025,7 @@ fn bindgen_test_layout__cef_settings_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_settings_t>())).user_data_path as *const _ as usize },
+        ::std::mem::offset_of!(_cef_settings_t, user_data_path),
         152usize,
         concat!(
             "Offset of field: ",
@@ -1069,9 +1035,7 @@ fn bindgen_test_layout__cef_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_settings_t>())).persist_session_cookies as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_settings_t, persist_session_cookies),
         176usize,
         concat!(
             "Offset of field: ",
@@ -1081,10 +1045,7 @@ fn bindgen_test_layout__cef_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_settings_t>())).persist_user_preferences as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_settings_t, persist_user_preferences),
         180usize,
         concat!(
             "Offset of field: ",
@@ -1094,7 +1055,7 @@ fn bindgen_test_layout__cef_settings_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_settings_t>())).user_agent as *const _ as usize },
+        ::std::mem::offset_of!(_cef_settings_t, user_agent),
         184usize,
         concat!(
             "Offset of field: ",
@@ -1104,7 +1065,7 @@ fn bindgen_test_layout__cef_settings_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_settings_t>())).product_version as *const _ as usize },
+        ::std::mem::offset_of!(_cef_settings_t, product_version),
         208usize,
         concat!(
             "Offset of field: ",
@@ -1114,7 +1075,7 @@ fn bindgen_test_layout__cef_settings_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_settings_t>())).locale as *const _ as usize },
+        ::std::mem::offset_of!(_cef_settings_t, locale),
         232usize,
         concat!(
             "Offset of field: ",
@@ -1124,7 +1085,7 @@ fn bindgen_test_layout__cef_settings_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_settings_t>())).log_file as *const _ as usize },
+        ::std::mem::offset_of!(_cef_settings_t, log_file),
         256usize,
         concat!(
             "Offset of field: ",
@@ -1134,7 +1095,7 @@ fn bindgen_test_layout__cef_settings_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_settings_t>())).log_severity as *const _ as usize },
+        ::std::mem::offset_of!(_cef_settings_t, log_severity),
         280usize,
         concat!(
             "Offset of field: ",
@@ -1144,9 +1105,7 @@ fn bindgen_test_layout__cef_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_settings_t>())).javascript_flags as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_settings_t, javascript_flags),
         288usize,
         concat!(
             "Offset of field: ",
@@ -1156,9 +1115,7 @@ fn bindgen_test_layout__cef_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_settings_t>())).resources_dir_path as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_settings_t, resources_dir_path),
         312usize,
         concat!(
             "Offset of field: ",
@@ -1168,9 +1125,7 @@ fn bindgen_test_layout__cef_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_settings_t>())).locales_dir_path as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_settings_t, locales_dir_path),
         336usize,
         concat!(
             "Offset of field: ",
@@ -1180,9 +1135,7 @@ fn bindgen_test_layout__cef_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_settings_t>())).pack_loading_disabled as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_settings_t, pack_loading_disabled),
         360usize,
         concat!(
             "Offset of field: ",
@@ -1192,9 +1145,7 @@ fn bindgen_test_layout__cef_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_settings_t>())).remote_debugging_port as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_settings_t, remote_debugging_port),
         364usize,
         concat!(
             "Offset of field: ",
@@ -1204,10 +1155,7 @@ fn bindgen_test_layout__cef_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_settings_t>())).uncaught_exception_stack_size as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_settings_t, uncaught_exception_stack_size),
         368usize,
         concat!(
             "Offset of field: ",
@@ -1217,10 +1165,7 @@ fn bindgen_test_layout__cef_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_settings_t>())).ignore_certificate_errors as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_settings_t, ignore_certificate_errors),
         372usize,
         concat!(
             "Offset of field: ",
@@ -1230,9 +1175,7 @@ fn bindgen_test_layout__cef_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_settings_t>())).background_color as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_settings_t, background_color),
         376usize,
         concat!(
             "Offset of field: ",
@@ -1242,9 +1185,7 @@ fn bindgen_test_layout__cef_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_settings_t>())).accept_language_list as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_settings_t, accept_language_list),
         384usize,
         concat!(
             "Offset of field: ",
@@ -1254,10 +1195,7 @@ fn bindgen_test_layout__cef_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_settings_t>())).application_client_id_for_file_scanning
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_settings_t, application_client_id_for_file_scanning),
         408usize,
         concat!(
             "Offset of field: ",
@@ -1291,9 +1229,7 @@ fn bindgen_test_layout__cef_request_context_settings_t() {
         concat!("Alignment of ", stringify!(_cef_request_context_settings_t))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_request_context_settings_t>())).size as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_request_context_settings_t, size),
         0usize,
         concat!(
             "Offset of field: ",
@@ -1303,10 +1239,7 @@ fn bindgen_test_layout__cef_request_context_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_request_context_settings_t>())).cache_path as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_request_context_settings_t, cache_path),
         8usize,
         concat!(
             "Offset of field: ",
@@ -1316,10 +1249,7 @@ fn bindgen_test_layout__cef_request_context_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_request_context_settings_t>())).persist_session_cookies
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_request_context_settings_t, persist_session_cookies),
         32usize,
         concat!(
             "Offset of field: ",
@@ -1329,10 +1259,7 @@ fn bindgen_test_layout__cef_request_context_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_request_context_settings_t>())).persist_user_preferences
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_request_context_settings_t, persist_user_preferences),
         36usize,
         concat!(
             "Offset of field: ",
@@ -1342,10 +1269,7 @@ fn bindgen_test_layout__cef_request_context_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_request_context_settings_t>())).ignore_certificate_errors
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_request_context_settings_t, ignore_certificate_errors),
         40usize,
         concat!(
             "Offset of field: ",
@@ -1355,10 +1279,7 @@ fn bindgen_test_layout__cef_request_context_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_request_context_settings_t>())).accept_language_list
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_request_context_settings_t, accept_language_list),
         48usize,
         concat!(
             "Offset of field: ",
@@ -1418,7 +1339,7 @@ fn bindgen_test_layout__cef_browser_settings_t() {
         concat!("Alignment of ", stringify!(_cef_browser_settings_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_browser_settings_t>())).size as *const _ as usize },
+        ::std::mem::offset_of!(_cef_browser_settings_t, size),
         0usize,
         concat!(
             "Offset of field: ",
@@ -1428,10 +1349,7 @@ fn bindgen_test_layout__cef_browser_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_settings_t>())).windowless_frame_rate as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_settings_t, windowless_frame_rate),
         8usize,
         concat!(
             "Offset of field: ",
@@ -1441,10 +1359,7 @@ fn bindgen_test_layout__cef_browser_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_settings_t>())).standard_font_family as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_settings_t, standard_font_family),
         16usize,
         concat!(
             "Offset of field: ",
@@ -1454,10 +1369,7 @@ fn bindgen_test_layout__cef_browser_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_settings_t>())).fixed_font_family as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_settings_t, fixed_font_family),
         40usize,
         concat!(
             "Offset of field: ",
@@ -1467,10 +1379,7 @@ fn bindgen_test_layout__cef_browser_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_settings_t>())).serif_font_family as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_settings_t, serif_font_family),
         64usize,
         concat!(
             "Offset of field: ",
@@ -1480,10 +1389,7 @@ fn bindgen_test_layout__cef_browser_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_settings_t>())).sans_serif_font_family as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_settings_t, sans_serif_font_family),
         88usize,
         concat!(
             "Offset of field: ",
@@ -1493,10 +1399,7 @@ fn bindgen_test_layout__cef_browser_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_settings_t>())).cursive_font_family as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_settings_t, cursive_font_family),
         112usize,
         concat!(
             "Offset of field: ",
@@ -1506,10 +1409,7 @@ fn bindgen_test_layout__cef_browser_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_settings_t>())).fantasy_font_family as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_settings_t, fantasy_font_family),
         136usize,
         concat!(
             "Offset of field: ",
@@ -1519,10 +1419,7 @@ fn bindgen_test_layout__cef_browser_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_settings_t>())).default_font_size as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_settings_t, default_font_size),
         160usize,
         concat!(
             "Offset of field: ",
@@ -1532,10 +1429,7 @@ fn bindgen_test_layout__cef_browser_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_settings_t>())).default_fixed_font_size as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_settings_t, default_fixed_font_size),
         164usize,
         concat!(
             "Offset of field: ",
@@ -1545,10 +1439,7 @@ fn bindgen_test_layout__cef_browser_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_settings_t>())).minimum_font_size as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_settings_t, minimum_font_size),
         168usize,
         concat!(
             "Offset of field: ",
@@ -1558,10 +1449,7 @@ fn bindgen_test_layout__cef_browser_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_settings_t>())).minimum_logical_font_size
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_settings_t, minimum_logical_font_size),
         172usize,
         concat!(
             "Offset of field: ",
@@ -1571,10 +1459,7 @@ fn bindgen_test_layout__cef_browser_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_settings_t>())).default_encoding as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_settings_t, default_encoding),
         176usize,
         concat!(
             "Offset of field: ",
@@ -1584,9 +1469,7 @@ fn bindgen_test_layout__cef_browser_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_settings_t>())).remote_fonts as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_settings_t, remote_fonts),
         200usize,
         concat!(
             "Offset of field: ",
@@ -1596,9 +1479,7 @@ fn bindgen_test_layout__cef_browser_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_settings_t>())).javascript as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_settings_t, javascript),
         204usize,
         concat!(
             "Offset of field: ",
@@ -1608,10 +1489,7 @@ fn bindgen_test_layout__cef_browser_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_settings_t>())).javascript_close_windows as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_settings_t, javascript_close_windows),
         208usize,
         concat!(
             "Offset of field: ",
@@ -1621,10 +1499,7 @@ fn bindgen_test_layout__cef_browser_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_settings_t>())).javascript_access_clipboard
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_settings_t, javascript_access_clipboard),
         212usize,
         concat!(
             "Offset of field: ",
@@ -1634,10 +1509,7 @@ fn bindgen_test_layout__cef_browser_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_settings_t>())).javascript_dom_paste as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_settings_t, javascript_dom_paste),
         216usize,
         concat!(
             "Offset of field: ",
@@ -1647,7 +1519,7 @@ fn bindgen_test_layout__cef_browser_settings_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_browser_settings_t>())).plugins as *const _ as usize },
+        ::std::mem::offset_of!(_cef_browser_settings_t, plugins),
         220usize,
         concat!(
             "Offset of field: ",
@@ -1657,10 +1529,7 @@ fn bindgen_test_layout__cef_browser_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_settings_t>())).universal_access_from_file_urls
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_settings_t, universal_access_from_file_urls),
         224usize,
         concat!(
             "Offset of field: ",
@@ -1670,10 +1539,7 @@ fn bindgen_test_layout__cef_browser_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_settings_t>())).file_access_from_file_urls
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_settings_t, file_access_from_file_urls),
         228usize,
         concat!(
             "Offset of field: ",
@@ -1683,9 +1549,7 @@ fn bindgen_test_layout__cef_browser_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_settings_t>())).web_security as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_settings_t, web_security),
         232usize,
         concat!(
             "Offset of field: ",
@@ -1695,9 +1559,7 @@ fn bindgen_test_layout__cef_browser_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_settings_t>())).image_loading as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_settings_t, image_loading),
         236usize,
         concat!(
             "Offset of field: ",
@@ -1707,10 +1569,7 @@ fn bindgen_test_layout__cef_browser_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_settings_t>())).image_shrink_standalone_to_fit
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_settings_t, image_shrink_standalone_to_fit),
         240usize,
         concat!(
             "Offset of field: ",
@@ -1720,10 +1579,7 @@ fn bindgen_test_layout__cef_browser_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_settings_t>())).text_area_resize as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_settings_t, text_area_resize),
         244usize,
         concat!(
             "Offset of field: ",
@@ -1733,9 +1589,7 @@ fn bindgen_test_layout__cef_browser_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_settings_t>())).tab_to_links as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_settings_t, tab_to_links),
         248usize,
         concat!(
             "Offset of field: ",
@@ -1745,9 +1599,7 @@ fn bindgen_test_layout__cef_browser_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_settings_t>())).local_storage as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_settings_t, local_storage),
         252usize,
         concat!(
             "Offset of field: ",
@@ -1757,9 +1609,7 @@ fn bindgen_test_layout__cef_browser_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_settings_t>())).databases as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_settings_t, databases),
         256usize,
         concat!(
             "Offset of field: ",
@@ -1769,10 +1619,7 @@ fn bindgen_test_layout__cef_browser_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_settings_t>())).application_cache as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_settings_t, application_cache),
         260usize,
         concat!(
             "Offset of field: ",
@@ -1782,7 +1629,7 @@ fn bindgen_test_layout__cef_browser_settings_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_browser_settings_t>())).webgl as *const _ as usize },
+        ::std::mem::offset_of!(_cef_browser_settings_t, webgl),
         264usize,
         concat!(
             "Offset of field: ",
@@ -1792,10 +1639,7 @@ fn bindgen_test_layout__cef_browser_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_settings_t>())).background_color as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_settings_t, background_color),
         268usize,
         concat!(
             "Offset of field: ",
@@ -1805,10 +1649,7 @@ fn bindgen_test_layout__cef_browser_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_settings_t>())).accept_language_list as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_settings_t, accept_language_list),
         272usize,
         concat!(
             "Offset of field: ",
@@ -1852,7 +1693,7 @@ fn bindgen_test_layout__cef_urlparts_t() {
         concat!("Alignment of ", stringify!(_cef_urlparts_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_urlparts_t>())).spec as *const _ as usize },
+        ::std::mem::offset_of!(_cef_urlparts_t, spec),
         0usize,
         concat!(
             "Offset of field: ",
@@ -1862,7 +1703,7 @@ fn bindgen_test_layout__cef_urlparts_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_urlparts_t>())).scheme as *const _ as usize },
+        ::std::mem::offset_of!(_cef_urlparts_t, scheme),
         24usize,
         concat!(
             "Offset of field: ",
@@ -1872,7 +1713,7 @@ fn bindgen_test_layout__cef_urlparts_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_urlparts_t>())).username as *const _ as usize },
+        ::std::mem::offset_of!(_cef_urlparts_t, username),
         48usize,
         concat!(
             "Offset of field: ",
@@ -1882,7 +1723,7 @@ fn bindgen_test_layout__cef_urlparts_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_urlparts_t>())).password as *const _ as usize },
+        ::std::mem::offset_of!(_cef_urlparts_t, password),
         72usize,
         concat!(
             "Offset of field: ",
@@ -1892,7 +1733,7 @@ fn bindgen_test_layout__cef_urlparts_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_urlparts_t>())).host as *const _ as usize },
+        ::std::mem::offset_of!(_cef_urlparts_t, host),
         96usize,
         concat!(
             "Offset of field: ",
@@ -1902,7 +1743,7 @@ fn bindgen_test_layout__cef_urlparts_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_urlparts_t>())).port as *const _ as usize },
+        ::std::mem::offset_of!(_cef_urlparts_t, port),
         120usize,
         concat!(
             "Offset of field: ",
@@ -1912,7 +1753,7 @@ fn bindgen_test_layout__cef_urlparts_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_urlparts_t>())).origin as *const _ as usize },
+        ::std::mem::offset_of!(_cef_urlparts_t, origin),
         144usize,
         concat!(
             "Offset of field: ",
@@ -1922,7 +1763,7 @@ fn bindgen_test_layout__cef_urlparts_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_urlparts_t>())).path as *const _ as usize },
+        ::std::mem::offset_of!(_cef_urlparts_t, path),
         168usize,
         concat!(
             "Offset of field: ",
@@ -1932,7 +1773,7 @@ fn bindgen_test_layout__cef_urlparts_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_urlparts_t>())).query as *const _ as usize },
+        ::std::mem::offset_of!(_cef_urlparts_t, query),
         192usize,
         concat!(
             "Offset of field: ",
@@ -1942,7 +1783,7 @@ fn bindgen_test_layout__cef_urlparts_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_urlparts_t>())).fragment as *const _ as usize },
+        ::std::mem::offset_of!(_cef_urlparts_t, fragment),
         216usize,
         concat!(
             "Offset of field: ",
@@ -1995,7 +1836,7 @@ fn bindgen_test_layout__cef_cookie_t() {
         concat!("Alignment of ", stringify!(_cef_cookie_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_cookie_t>())).name as *const _ as usize },
+        ::std::mem::offset_of!(_cef_cookie_t, name),
         0usize,
         concat!(
             "Offset of field: ",
@@ -2005,7 +1846,7 @@ fn bindgen_test_layout__cef_cookie_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_cookie_t>())).value as *const _ as usize },
+        ::std::mem::offset_of!(_cef_cookie_t, value),
         24usize,
         concat!(
             "Offset of field: ",
@@ -2015,7 +1856,7 @@ fn bindgen_test_layout__cef_cookie_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_cookie_t>())).domain as *const _ as usize },
+        ::std::mem::offset_of!(_cef_cookie_t, domain),
         48usize,
         concat!(
             "Offset of field: ",
@@ -2025,7 +1866,7 @@ fn bindgen_test_layout__cef_cookie_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_cookie_t>())).path as *const _ as usize },
+        ::std::mem::offset_of!(_cef_cookie_t, path),
         72usize,
         concat!(
             "Offset of field: ",
@@ -2035,7 +1876,7 @@ fn bindgen_test_layout__cef_cookie_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_cookie_t>())).secure as *const _ as usize },
+        ::std::mem::offset_of!(_cef_cookie_t, secure),
         96usize,
         concat!(
             "Offset of field: ",
@@ -2045,7 +1886,7 @@ fn bindgen_test_layout__cef_cookie_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_cookie_t>())).httponly as *const _ as usize },
+        ::std::mem::offset_of!(_cef_cookie_t, httponly),
         100usize,
         concat!(
             "Offset of field: ",
@@ -2055,7 +1896,7 @@ fn bindgen_test_layout__cef_cookie_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_cookie_t>())).creation as *const _ as usize },
+        ::std::mem::offset_of!(_cef_cookie_t, creation),
         104usize,
         concat!(
             "Offset of field: ",
@@ -2065,7 +1906,7 @@ fn bindgen_test_layout__cef_cookie_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_cookie_t>())).last_access as *const _ as usize },
+        ::std::mem::offset_of!(_cef_cookie_t, last_access),
         136usize,
         concat!(
             "Offset of field: ",
@@ -2075,7 +1916,7 @@ fn bindgen_test_layout__cef_cookie_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_cookie_t>())).has_expires as *const _ as usize },
+        ::std::mem::offset_of!(_cef_cookie_t, has_expires),
         168usize,
         concat!(
             "Offset of field: ",
@@ -2085,7 +1926,7 @@ fn bindgen_test_layout__cef_cookie_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_cookie_t>())).expires as *const _ as usize },
+        ::std::mem::offset_of!(_cef_cookie_t, expires),
         172usize,
         concat!(
             "Offset of field: ",
@@ -2095,7 +1936,7 @@ fn bindgen_test_layout__cef_cookie_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_cookie_t>())).same_site as *const _ as usize },
+        ::std::mem::offset_of!(_cef_cookie_t, same_site),
         204usize,
         concat!(
             "Offset of field: ",
@@ -2105,7 +1946,7 @@ fn bindgen_test_layout__cef_cookie_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_cookie_t>())).priority as *const _ as usize },
+        ::std::mem::offset_of!(_cef_cookie_t, priority),
         208usize,
         concat!(
             "Offset of field: ",
@@ -2808,7 +2649,7 @@ fn bindgen_test_layout__cef_point_t() {
         concat!("Alignment of ", stringify!(_cef_point_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_point_t>())).x as *const _ as usize },
+        ::std::mem::offset_of!(_cef_point_t, x),
         0usize,
         concat!(
             "Offset of field: ",
@@ -2818,7 +2659,7 @@ fn bindgen_test_layout__cef_point_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_point_t>())).y as *const _ as usize },
+        ::std::mem::offset_of!(_cef_point_t, y),
         4usize,
         concat!(
             "Offset of field: ",
@@ -2850,7 +2691,7 @@ fn bindgen_test_layout__cef_rect_t() {
         concat!("Alignment of ", stringify!(_cef_rect_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_rect_t>())).x as *const _ as usize },
+        ::std::mem::offset_of!(_cef_rect_t, x),
         0usize,
         concat!(
             "Offset of field: ",
@@ -2860,7 +2701,7 @@ fn bindgen_test_layout__cef_rect_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_rect_t>())).y as *const _ as usize },
+        ::std::mem::offset_of!(_cef_rect_t, y),
         4usize,
         concat!(
             "Offset of field: ",
@@ -2870,7 +2711,7 @@ fn bindgen_test_layout__cef_rect_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_rect_t>())).width as *const _ as usize },
+        ::std::mem::offset_of!(_cef_rect_t, width),
         8usize,
         concat!(
             "Offset of field: ",
@@ -2880,7 +2721,7 @@ fn bindgen_test_layout__cef_rect_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_rect_t>())).height as *const _ as usize },
+        ::std::mem::offset_of!(_cef_rect_t, height),
         12usize,
         concat!(
             "Offset of field: ",
@@ -2910,7 +2751,7 @@ fn bindgen_test_layout__cef_size_t() {
         concat!("Alignment of ", stringify!(_cef_size_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_size_t>())).width as *const _ as usize },
+        ::std::mem::offset_of!(_cef_size_t, width),
         0usize,
         concat!(
             "Offset of field: ",
@@ -2920,7 +2761,7 @@ fn bindgen_test_layout__cef_size_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_size_t>())).height as *const _ as usize },
+        ::std::mem::offset_of!(_cef_size_t, height),
         4usize,
         concat!(
             "Offset of field: ",
@@ -2950,7 +2791,7 @@ fn bindgen_test_layout__cef_range_t() {
         concat!("Alignment of ", stringify!(_cef_range_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_range_t>())).from as *const _ as usize },
+        ::std::mem::offset_of!(_cef_range_t, from),
         0usize,
         concat!(
             "Offset of field: ",
@@ -2960,7 +2801,7 @@ fn bindgen_test_layout__cef_range_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_range_t>())).to as *const _ as usize },
+        ::std::mem::offset_of!(_cef_range_t, to),
         4usize,
         concat!(
             "Offset of field: ",
@@ -2992,7 +2833,7 @@ fn bindgen_test_layout__cef_insets_t() {
         concat!("Alignment of ", stringify!(_cef_insets_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_insets_t>())).top as *const _ as usize },
+        ::std::mem::offset_of!(_cef_insets_t, top),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3002,7 +2843,7 @@ fn bindgen_test_layout__cef_insets_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_insets_t>())).left as *const _ as usize },
+        ::std::mem::offset_of!(_cef_insets_t, left),
         4usize,
         concat!(
             "Offset of field: ",
@@ -3012,7 +2853,7 @@ fn bindgen_test_layout__cef_insets_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_insets_t>())).bottom as *const _ as usize },
+        ::std::mem::offset_of!(_cef_insets_t, bottom),
         8usize,
         concat!(
             "Offset of field: ",
@@ -3022,7 +2863,7 @@ fn bindgen_test_layout__cef_insets_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_insets_t>())).right as *const _ as usize },
+        ::std::mem::offset_of!(_cef_insets_t, right),
         12usize,
         concat!(
             "Offset of field: ",
@@ -3052,7 +2893,7 @@ fn bindgen_test_layout__cef_draggable_region_t() {
         concat!("Alignment of ", stringify!(_cef_draggable_region_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_draggable_region_t>())).bounds as *const _ as usize },
+        ::std::mem::offset_of!(_cef_draggable_region_t, bounds),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3062,9 +2903,7 @@ fn bindgen_test_layout__cef_draggable_region_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_draggable_region_t>())).draggable as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_draggable_region_t, draggable),
         16usize,
         concat!(
             "Offset of field: ",
@@ -3151,9 +2990,7 @@ fn bindgen_test_layout__cef_screen_info_t() {
         concat!("Alignment of ", stringify!(_cef_screen_info_t))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_screen_info_t>())).device_scale_factor as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_screen_info_t, device_scale_factor),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3163,7 +3000,7 @@ fn bindgen_test_layout__cef_screen_info_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_screen_info_t>())).depth as *const _ as usize },
+        ::std::mem::offset_of!(_cef_screen_info_t, depth),
         4usize,
         concat!(
             "Offset of field: ",
@@ -3173,9 +3010,7 @@ fn bindgen_test_layout__cef_screen_info_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_screen_info_t>())).depth_per_component as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_screen_info_t, depth_per_component),
         8usize,
         concat!(
             "Offset of field: ",
@@ -3185,9 +3020,7 @@ fn bindgen_test_layout__cef_screen_info_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_screen_info_t>())).is_monochrome as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_screen_info_t, is_monochrome),
         12usize,
         concat!(
             "Offset of field: ",
@@ -3197,7 +3030,7 @@ fn bindgen_test_layout__cef_screen_info_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_screen_info_t>())).rect as *const _ as usize },
+        ::std::mem::offset_of!(_cef_screen_info_t, rect),
         16usize,
         concat!(
             "Offset of field: ",
@@ -3207,9 +3040,7 @@ fn bindgen_test_layout__cef_screen_info_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_screen_info_t>())).available_rect as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_screen_info_t, available_rect),
         32usize,
         concat!(
             "Offset of field: ",
@@ -3276,7 +3107,7 @@ fn bindgen_test_layout__cef_mouse_event_t() {
         concat!("Alignment of ", stringify!(_cef_mouse_event_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_mouse_event_t>())).x as *const _ as usize },
+        ::std::mem::offset_of!(_cef_mouse_event_t, x),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3286,7 +3117,7 @@ fn bindgen_test_layout__cef_mouse_event_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_mouse_event_t>())).y as *const _ as usize },
+        ::std::mem::offset_of!(_cef_mouse_event_t, y),
         4usize,
         concat!(
             "Offset of field: ",
@@ -3296,7 +3127,7 @@ fn bindgen_test_layout__cef_mouse_event_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_mouse_event_t>())).modifiers as *const _ as usize },
+        ::std::mem::offset_of!(_cef_mouse_event_t, modifiers),
         8usize,
         concat!(
             "Offset of field: ",
@@ -3349,7 +3180,7 @@ fn bindgen_test_layout__cef_touch_event_t() {
         concat!("Alignment of ", stringify!(_cef_touch_event_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_touch_event_t>())).id as *const _ as usize },
+        ::std::mem::offset_of!(_cef_touch_event_t, id),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3359,7 +3190,7 @@ fn bindgen_test_layout__cef_touch_event_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_touch_event_t>())).x as *const _ as usize },
+        ::std::mem::offset_of!(_cef_touch_event_t, x),
         4usize,
         concat!(
             "Offset of field: ",
@@ -3369,7 +3200,7 @@ fn bindgen_test_layout__cef_touch_event_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_touch_event_t>())).y as *const _ as usize },
+        ::std::mem::offset_of!(_cef_touch_event_t, y),
         8usize,
         concat!(
             "Offset of field: ",
@@ -3379,7 +3210,7 @@ fn bindgen_test_layout__cef_touch_event_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_touch_event_t>())).radius_x as *const _ as usize },
+        ::std::mem::offset_of!(_cef_touch_event_t, radius_x),
         12usize,
         concat!(
             "Offset of field: ",
@@ -3389,7 +3220,7 @@ fn bindgen_test_layout__cef_touch_event_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_touch_event_t>())).radius_y as *const _ as usize },
+        ::std::mem::offset_of!(_cef_touch_event_t, radius_y),
         16usize,
         concat!(
             "Offset of field: ",
@@ -3399,9 +3230,7 @@ fn bindgen_test_layout__cef_touch_event_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_touch_event_t>())).rotation_angle as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_touch_event_t, rotation_angle),
         20usize,
         concat!(
             "Offset of field: ",
@@ -3411,7 +3240,7 @@ fn bindgen_test_layout__cef_touch_event_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_touch_event_t>())).pressure as *const _ as usize },
+        ::std::mem::offset_of!(_cef_touch_event_t, pressure),
         24usize,
         concat!(
             "Offset of field: ",
@@ -3421,7 +3250,7 @@ fn bindgen_test_layout__cef_touch_event_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_touch_event_t>())).type_ as *const _ as usize },
+        ::std::mem::offset_of!(_cef_touch_event_t, type_),
         28usize,
         concat!(
             "Offset of field: ",
@@ -3431,7 +3260,7 @@ fn bindgen_test_layout__cef_touch_event_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_touch_event_t>())).modifiers as *const _ as usize },
+        ::std::mem::offset_of!(_cef_touch_event_t, modifiers),
         32usize,
         concat!(
             "Offset of field: ",
@@ -3441,7 +3270,7 @@ fn bindgen_test_layout__cef_touch_event_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_touch_event_t>())).pointer_type as *const _ as usize },
+        ::std::mem::offset_of!(_cef_touch_event_t, pointer_type),
         36usize,
         concat!(
             "Offset of field: ",
@@ -3776,7 +3605,7 @@ fn bindgen_test_layout__cef_key_event_t() {
         concat!("Alignment of ", stringify!(_cef_key_event_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_key_event_t>())).type_ as *const _ as usize },
+        ::std::mem::offset_of!(_cef_key_event_t, type_),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3786,7 +3615,7 @@ fn bindgen_test_layout__cef_key_event_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_key_event_t>())).modifiers as *const _ as usize },
+        ::std::mem::offset_of!(_cef_key_event_t, modifiers),
         4usize,
         concat!(
             "Offset of field: ",
@@ -3796,9 +3625,7 @@ fn bindgen_test_layout__cef_key_event_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_key_event_t>())).windows_key_code as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_key_event_t, windows_key_code),
         8usize,
         concat!(
             "Offset of field: ",
@@ -3808,9 +3635,7 @@ fn bindgen_test_layout__cef_key_event_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_key_event_t>())).native_key_code as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_key_event_t, native_key_code),
         12usize,
         concat!(
             "Offset of field: ",
@@ -3820,7 +3645,7 @@ fn bindgen_test_layout__cef_key_event_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_key_event_t>())).is_system_key as *const _ as usize },
+        ::std::mem::offset_of!(_cef_key_event_t, is_system_key),
         16usize,
         concat!(
             "Offset of field: ",
@@ -3830,7 +3655,7 @@ fn bindgen_test_layout__cef_key_event_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_key_event_t>())).character as *const _ as usize },
+        ::std::mem::offset_of!(_cef_key_event_t, character),
         20usize,
         concat!(
             "Offset of field: ",
@@ -3840,9 +3665,7 @@ fn bindgen_test_layout__cef_key_event_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_key_event_t>())).unmodified_character as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_key_event_t, unmodified_character),
         22usize,
         concat!(
             "Offset of field: ",
@@ -3852,10 +3675,7 @@ fn bindgen_test_layout__cef_key_event_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_key_event_t>())).focus_on_editable_field as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_key_event_t, focus_on_editable_field),
         24usize,
         concat!(
             "Offset of field: ",
@@ -3931,7 +3751,7 @@ fn bindgen_test_layout__cef_popup_features_t() {
         concat!("Alignment of ", stringify!(_cef_popup_features_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_popup_features_t>())).x as *const _ as usize },
+        ::std::mem::offset_of!(_cef_popup_features_t, x),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3941,7 +3761,7 @@ fn bindgen_test_layout__cef_popup_features_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_popup_features_t>())).xSet as *const _ as usize },
+        ::std::mem::offset_of!(_cef_popup_features_t, xSet),
         4usize,
         concat!(
             "Offset of field: ",
@@ -3951,7 +3771,7 @@ fn bindgen_test_layout__cef_popup_features_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_popup_features_t>())).y as *const _ as usize },
+        ::std::mem::offset_of!(_cef_popup_features_t, y),
         8usize,
         concat!(
             "Offset of field: ",
@@ -3961,7 +3781,7 @@ fn bindgen_test_layout__cef_popup_features_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_popup_features_t>())).ySet as *const _ as usize },
+        ::std::mem::offset_of!(_cef_popup_features_t, ySet),
         12usize,
         concat!(
             "Offset of field: ",
@@ -3971,7 +3791,7 @@ fn bindgen_test_layout__cef_popup_features_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_popup_features_t>())).width as *const _ as usize },
+        ::std::mem::offset_of!(_cef_popup_features_t, width),
         16usize,
         concat!(
             "Offset of field: ",
@@ -3981,7 +3801,7 @@ fn bindgen_test_layout__cef_popup_features_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_popup_features_t>())).widthSet as *const _ as usize },
+        ::std::mem::offset_of!(_cef_popup_features_t, widthSet),
         20usize,
         concat!(
             "Offset of field: ",
@@ -3991,7 +3811,7 @@ fn bindgen_test_layout__cef_popup_features_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_popup_features_t>())).height as *const _ as usize },
+        ::std::mem::offset_of!(_cef_popup_features_t, height),
         24usize,
         concat!(
             "Offset of field: ",
@@ -4001,7 +3821,7 @@ fn bindgen_test_layout__cef_popup_features_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_popup_features_t>())).heightSet as *const _ as usize },
+        ::std::mem::offset_of!(_cef_popup_features_t, heightSet),
         28usize,
         concat!(
             "Offset of field: ",
@@ -4011,9 +3831,7 @@ fn bindgen_test_layout__cef_popup_features_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_popup_features_t>())).menuBarVisible as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_popup_features_t, menuBarVisible),
         32usize,
         concat!(
             "Offset of field: ",
@@ -4023,9 +3841,7 @@ fn bindgen_test_layout__cef_popup_features_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_popup_features_t>())).statusBarVisible as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_popup_features_t, statusBarVisible),
         36usize,
         concat!(
             "Offset of field: ",
@@ -4035,9 +3851,7 @@ fn bindgen_test_layout__cef_popup_features_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_popup_features_t>())).toolBarVisible as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_popup_features_t, toolBarVisible),
         40usize,
         concat!(
             "Offset of field: ",
@@ -4047,9 +3861,7 @@ fn bindgen_test_layout__cef_popup_features_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_popup_features_t>())).scrollbarsVisible as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_popup_features_t, scrollbarsVisible),
         44usize,
         concat!(
             "Offset of field: ",
@@ -4264,7 +4076,7 @@ fn bindgen_test_layout__cef_cursor_info_t() {
         concat!("Alignment of ", stringify!(_cef_cursor_info_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_cursor_info_t>())).hotspot as *const _ as usize },
+        ::std::mem::offset_of!(_cef_cursor_info_t, hotspot),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4274,9 +4086,7 @@ fn bindgen_test_layout__cef_cursor_info_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_cursor_info_t>())).image_scale_factor as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_cursor_info_t, image_scale_factor),
         8usize,
         concat!(
             "Offset of field: ",
@@ -4286,7 +4096,7 @@ fn bindgen_test_layout__cef_cursor_info_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_cursor_info_t>())).buffer as *const _ as usize },
+        ::std::mem::offset_of!(_cef_cursor_info_t, buffer),
         16usize,
         concat!(
             "Offset of field: ",
@@ -4296,7 +4106,7 @@ fn bindgen_test_layout__cef_cursor_info_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_cursor_info_t>())).size as *const _ as usize },
+        ::std::mem::offset_of!(_cef_cursor_info_t, size),
         24usize,
         concat!(
             "Offset of field: ",
@@ -4485,10 +4295,7 @@ fn bindgen_test_layout__cef_pdf_print_settings_t() {
         concat!("Alignment of ", stringify!(_cef_pdf_print_settings_t))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_pdf_print_settings_t>())).header_footer_title as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_pdf_print_settings_t, header_footer_title),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4498,10 +4305,7 @@ fn bindgen_test_layout__cef_pdf_print_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_pdf_print_settings_t>())).header_footer_url as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_pdf_print_settings_t, header_footer_url),
         24usize,
         concat!(
             "Offset of field: ",
@@ -4511,9 +4315,7 @@ fn bindgen_test_layout__cef_pdf_print_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_pdf_print_settings_t>())).page_width as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_pdf_print_settings_t, page_width),
         48usize,
         concat!(
             "Offset of field: ",
@@ -4523,9 +4325,7 @@ fn bindgen_test_layout__cef_pdf_print_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_pdf_print_settings_t>())).page_height as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_pdf_print_settings_t, page_height),
         52usize,
         concat!(
             "Offset of field: ",
@@ -4535,9 +4335,7 @@ fn bindgen_test_layout__cef_pdf_print_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_pdf_print_settings_t>())).scale_factor as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_pdf_print_settings_t, scale_factor),
         56usize,
         concat!(
             "Offset of field: ",
@@ -4547,9 +4345,7 @@ fn bindgen_test_layout__cef_pdf_print_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_pdf_print_settings_t>())).margin_top as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_pdf_print_settings_t, margin_top),
         60usize,
         concat!(
             "Offset of field: ",
@@ -4559,9 +4355,7 @@ fn bindgen_test_layout__cef_pdf_print_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_pdf_print_settings_t>())).margin_right as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_pdf_print_settings_t, margin_right),
         64usize,
         concat!(
             "Offset of field: ",
@@ -4571,9 +4365,7 @@ fn bindgen_test_layout__cef_pdf_print_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_pdf_print_settings_t>())).margin_bottom as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_pdf_print_settings_t, margin_bottom),
         68usize,
         concat!(
             "Offset of field: ",
@@ -4583,9 +4375,7 @@ fn bindgen_test_layout__cef_pdf_print_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_pdf_print_settings_t>())).margin_left as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_pdf_print_settings_t, margin_left),
         72usize,
         concat!(
             "Offset of field: ",
@@ -4595,9 +4385,7 @@ fn bindgen_test_layout__cef_pdf_print_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_pdf_print_settings_t>())).margin_type as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_pdf_print_settings_t, margin_type),
         76usize,
         concat!(
             "Offset of field: ",
@@ -4607,10 +4395,7 @@ fn bindgen_test_layout__cef_pdf_print_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_pdf_print_settings_t>())).header_footer_enabled as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_pdf_print_settings_t, header_footer_enabled),
         80usize,
         concat!(
             "Offset of field: ",
@@ -4620,10 +4405,7 @@ fn bindgen_test_layout__cef_pdf_print_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_pdf_print_settings_t>())).selection_only as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_pdf_print_settings_t, selection_only),
         84usize,
         concat!(
             "Offset of field: ",
@@ -4633,9 +4415,7 @@ fn bindgen_test_layout__cef_pdf_print_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_pdf_print_settings_t>())).landscape as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_pdf_print_settings_t, landscape),
         88usize,
         concat!(
             "Offset of field: ",
@@ -4645,10 +4425,7 @@ fn bindgen_test_layout__cef_pdf_print_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_pdf_print_settings_t>())).backgrounds_enabled as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_pdf_print_settings_t, backgrounds_enabled),
         92usize,
         concat!(
             "Offset of field: ",
@@ -4756,9 +4533,7 @@ fn bindgen_test_layout__cef_box_layout_settings_t() {
         concat!("Alignment of ", stringify!(_cef_box_layout_settings_t))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_box_layout_settings_t>())).horizontal as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_box_layout_settings_t, horizontal),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4768,10 +4543,7 @@ fn bindgen_test_layout__cef_box_layout_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_box_layout_settings_t>())).inside_border_horizontal_spacing
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_box_layout_settings_t, inside_border_horizontal_spacing),
         4usize,
         concat!(
             "Offset of field: ",
@@ -4781,10 +4553,7 @@ fn bindgen_test_layout__cef_box_layout_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_box_layout_settings_t>())).inside_border_vertical_spacing
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_box_layout_settings_t, inside_border_vertical_spacing),
         8usize,
         concat!(
             "Offset of field: ",
@@ -4794,10 +4563,7 @@ fn bindgen_test_layout__cef_box_layout_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_box_layout_settings_t>())).inside_border_insets as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_box_layout_settings_t, inside_border_insets),
         12usize,
         concat!(
             "Offset of field: ",
@@ -4807,10 +4573,7 @@ fn bindgen_test_layout__cef_box_layout_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_box_layout_settings_t>())).between_child_spacing as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_box_layout_settings_t, between_child_spacing),
         28usize,
         concat!(
             "Offset of field: ",
@@ -4820,10 +4583,7 @@ fn bindgen_test_layout__cef_box_layout_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_box_layout_settings_t>())).main_axis_alignment as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_box_layout_settings_t, main_axis_alignment),
         32usize,
         concat!(
             "Offset of field: ",
@@ -4833,10 +4593,7 @@ fn bindgen_test_layout__cef_box_layout_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_box_layout_settings_t>())).cross_axis_alignment as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_box_layout_settings_t, cross_axis_alignment),
         36usize,
         concat!(
             "Offset of field: ",
@@ -4846,10 +4603,7 @@ fn bindgen_test_layout__cef_box_layout_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_box_layout_settings_t>())).minimum_cross_axis_size
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_box_layout_settings_t, minimum_cross_axis_size),
         40usize,
         concat!(
             "Offset of field: ",
@@ -4859,9 +4613,7 @@ fn bindgen_test_layout__cef_box_layout_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_box_layout_settings_t>())).default_flex as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_box_layout_settings_t, default_flex),
         44usize,
         concat!(
             "Offset of field: ",
@@ -5041,9 +4793,7 @@ fn bindgen_test_layout__cef_composition_underline_t() {
         concat!("Alignment of ", stringify!(_cef_composition_underline_t))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_composition_underline_t>())).range as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_composition_underline_t, range),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5053,9 +4803,7 @@ fn bindgen_test_layout__cef_composition_underline_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_composition_underline_t>())).color as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_composition_underline_t, color),
         8usize,
         concat!(
             "Offset of field: ",
@@ -5065,10 +4813,7 @@ fn bindgen_test_layout__cef_composition_underline_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_composition_underline_t>())).background_color as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_composition_underline_t, background_color),
         12usize,
         concat!(
             "Offset of field: ",
@@ -5078,9 +4823,7 @@ fn bindgen_test_layout__cef_composition_underline_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_composition_underline_t>())).thick as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_composition_underline_t, thick),
         16usize,
         concat!(
             "Offset of field: ",
@@ -5090,9 +4833,7 @@ fn bindgen_test_layout__cef_composition_underline_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_composition_underline_t>())).style as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_composition_underline_t, style),
         20usize,
         concat!(
             "Offset of field: ",
@@ -5160,9 +4901,7 @@ fn bindgen_test_layout__cef_audio_parameters_t() {
         concat!("Alignment of ", stringify!(_cef_audio_parameters_t))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_audio_parameters_t>())).channel_layout as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_audio_parameters_t, channel_layout),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5172,9 +4911,7 @@ fn bindgen_test_layout__cef_audio_parameters_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_audio_parameters_t>())).sample_rate as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_audio_parameters_t, sample_rate),
         4usize,
         concat!(
             "Offset of field: ",
@@ -5184,10 +4921,7 @@ fn bindgen_test_layout__cef_audio_parameters_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_audio_parameters_t>())).frames_per_buffer as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_audio_parameters_t, frames_per_buffer),
         8usize,
         concat!(
             "Offset of field: ",
@@ -5259,7 +4993,7 @@ fn bindgen_test_layout__cef_base_ref_counted_t() {
         concat!("Alignment of ", stringify!(_cef_base_ref_counted_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_base_ref_counted_t>())).size as *const _ as usize },
+        ::std::mem::offset_of!(_cef_base_ref_counted_t, size),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5269,7 +5003,7 @@ fn bindgen_test_layout__cef_base_ref_counted_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_base_ref_counted_t>())).add_ref as *const _ as usize },
+        ::std::mem::offset_of!(_cef_base_ref_counted_t, add_ref),
         8usize,
         concat!(
             "Offset of field: ",
@@ -5279,7 +5013,7 @@ fn bindgen_test_layout__cef_base_ref_counted_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_base_ref_counted_t>())).release as *const _ as usize },
+        ::std::mem::offset_of!(_cef_base_ref_counted_t, release),
         16usize,
         concat!(
             "Offset of field: ",
@@ -5289,9 +5023,7 @@ fn bindgen_test_layout__cef_base_ref_counted_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_base_ref_counted_t>())).has_one_ref as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_base_ref_counted_t, has_one_ref),
         24usize,
         concat!(
             "Offset of field: ",
@@ -5301,10 +5033,7 @@ fn bindgen_test_layout__cef_base_ref_counted_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_base_ref_counted_t>())).has_at_least_one_ref as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_base_ref_counted_t, has_at_least_one_ref),
         32usize,
         concat!(
             "Offset of field: ",
@@ -5334,7 +5063,7 @@ fn bindgen_test_layout__cef_base_scoped_t() {
         concat!("Alignment of ", stringify!(_cef_base_scoped_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_base_scoped_t>())).size as *const _ as usize },
+        ::std::mem::offset_of!(_cef_base_scoped_t, size),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5344,7 +5073,7 @@ fn bindgen_test_layout__cef_base_scoped_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_base_scoped_t>())).del as *const _ as usize },
+        ::std::mem::offset_of!(_cef_base_scoped_t, del),
         8usize,
         concat!(
             "Offset of field: ",
@@ -5460,7 +5189,7 @@ fn bindgen_test_layout__cef_value_t() {
         concat!("Alignment of ", stringify!(_cef_value_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_value_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_value_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5470,7 +5199,7 @@ fn bindgen_test_layout__cef_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_value_t>())).is_valid as *const _ as usize },
+        ::std::mem::offset_of!(_cef_value_t, is_valid),
         40usize,
         concat!(
             "Offset of field: ",
@@ -5480,7 +5209,7 @@ fn bindgen_test_layout__cef_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_value_t>())).is_owned as *const _ as usize },
+        ::std::mem::offset_of!(_cef_value_t, is_owned),
         48usize,
         concat!(
             "Offset of field: ",
@@ -5490,7 +5219,7 @@ fn bindgen_test_layout__cef_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_value_t>())).is_read_only as *const _ as usize },
+        ::std::mem::offset_of!(_cef_value_t, is_read_only),
         56usize,
         concat!(
             "Offset of field: ",
@@ -5500,7 +5229,7 @@ fn bindgen_test_layout__cef_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_value_t>())).is_same as *const _ as usize },
+        ::std::mem::offset_of!(_cef_value_t, is_same),
         64usize,
         concat!(
             "Offset of field: ",
@@ -5510,7 +5239,7 @@ fn bindgen_test_layout__cef_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_value_t>())).is_equal as *const _ as usize },
+        ::std::mem::offset_of!(_cef_value_t, is_equal),
         72usize,
         concat!(
             "Offset of field: ",
@@ -5520,7 +5249,7 @@ fn bindgen_test_layout__cef_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_value_t>())).copy as *const _ as usize },
+        ::std::mem::offset_of!(_cef_value_t, copy),
         80usize,
         concat!(
             "Offset of field: ",
@@ -5530,7 +5259,7 @@ fn bindgen_test_layout__cef_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_value_t>())).get_type as *const _ as usize },
+        ::std::mem::offset_of!(_cef_value_t, get_type),
         88usize,
         concat!(
             "Offset of field: ",
@@ -5540,7 +5269,7 @@ fn bindgen_test_layout__cef_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_value_t>())).get_bool as *const _ as usize },
+        ::std::mem::offset_of!(_cef_value_t, get_bool),
         96usize,
         concat!(
             "Offset of field: ",
@@ -5550,7 +5279,7 @@ fn bindgen_test_layout__cef_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_value_t>())).get_int as *const _ as usize },
+        ::std::mem::offset_of!(_cef_value_t, get_int),
         104usize,
         concat!(
             "Offset of field: ",
@@ -5560,7 +5289,7 @@ fn bindgen_test_layout__cef_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_value_t>())).get_double as *const _ as usize },
+        ::std::mem::offset_of!(_cef_value_t, get_double),
         112usize,
         concat!(
             "Offset of field: ",
@@ -5570,7 +5299,7 @@ fn bindgen_test_layout__cef_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_value_t>())).get_string as *const _ as usize },
+        ::std::mem::offset_of!(_cef_value_t, get_string),
         120usize,
         concat!(
             "Offset of field: ",
@@ -5580,7 +5309,7 @@ fn bindgen_test_layout__cef_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_value_t>())).get_binary as *const _ as usize },
+        ::std::mem::offset_of!(_cef_value_t, get_binary),
         128usize,
         concat!(
             "Offset of field: ",
@@ -5590,7 +5319,7 @@ fn bindgen_test_layout__cef_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_value_t>())).get_dictionary as *const _ as usize },
+        ::std::mem::offset_of!(_cef_value_t, get_dictionary),
         136usize,
         concat!(
             "Offset of field: ",
@@ -5600,7 +5329,7 @@ fn bindgen_test_layout__cef_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_value_t>())).get_list as *const _ as usize },
+        ::std::mem::offset_of!(_cef_value_t, get_list),
         144usize,
         concat!(
             "Offset of field: ",
@@ -5610,7 +5339,7 @@ fn bindgen_test_layout__cef_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_value_t>())).set_null as *const _ as usize },
+        ::std::mem::offset_of!(_cef_value_t, set_null),
         152usize,
         concat!(
             "Offset of field: ",
@@ -5620,7 +5349,7 @@ fn bindgen_test_layout__cef_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_value_t>())).set_bool as *const _ as usize },
+        ::std::mem::offset_of!(_cef_value_t, set_bool),
         160usize,
         concat!(
             "Offset of field: ",
@@ -5630,7 +5359,7 @@ fn bindgen_test_layout__cef_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_value_t>())).set_int as *const _ as usize },
+        ::std::mem::offset_of!(_cef_value_t, set_int),
         168usize,
         concat!(
             "Offset of field: ",
@@ -5640,7 +5369,7 @@ fn bindgen_test_layout__cef_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_value_t>())).set_double as *const _ as usize },
+        ::std::mem::offset_of!(_cef_value_t, set_double),
         176usize,
         concat!(
             "Offset of field: ",
@@ -5650,7 +5379,7 @@ fn bindgen_test_layout__cef_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_value_t>())).set_string as *const _ as usize },
+        ::std::mem::offset_of!(_cef_value_t, set_string),
         184usize,
         concat!(
             "Offset of field: ",
@@ -5660,7 +5389,7 @@ fn bindgen_test_layout__cef_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_value_t>())).set_binary as *const _ as usize },
+        ::std::mem::offset_of!(_cef_value_t, set_binary),
         192usize,
         concat!(
             "Offset of field: ",
@@ -5670,7 +5399,7 @@ fn bindgen_test_layout__cef_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_value_t>())).set_dictionary as *const _ as usize },
+        ::std::mem::offset_of!(_cef_value_t, set_dictionary),
         200usize,
         concat!(
             "Offset of field: ",
@@ -5680,7 +5409,7 @@ fn bindgen_test_layout__cef_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_value_t>())).set_list as *const _ as usize },
+        ::std::mem::offset_of!(_cef_value_t, set_list),
         208usize,
         concat!(
             "Offset of field: ",
@@ -5743,7 +5472,7 @@ fn bindgen_test_layout__cef_binary_value_t() {
         concat!("Alignment of ", stringify!(_cef_binary_value_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_binary_value_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_binary_value_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5753,7 +5482,7 @@ fn bindgen_test_layout__cef_binary_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_binary_value_t>())).is_valid as *const _ as usize },
+        ::std::mem::offset_of!(_cef_binary_value_t, is_valid),
         40usize,
         concat!(
             "Offset of field: ",
@@ -5763,7 +5492,7 @@ fn bindgen_test_layout__cef_binary_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_binary_value_t>())).is_owned as *const _ as usize },
+        ::std::mem::offset_of!(_cef_binary_value_t, is_owned),
         48usize,
         concat!(
             "Offset of field: ",
@@ -5773,7 +5502,7 @@ fn bindgen_test_layout__cef_binary_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_binary_value_t>())).is_same as *const _ as usize },
+        ::std::mem::offset_of!(_cef_binary_value_t, is_same),
         56usize,
         concat!(
             "Offset of field: ",
@@ -5783,7 +5512,7 @@ fn bindgen_test_layout__cef_binary_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_binary_value_t>())).is_equal as *const _ as usize },
+        ::std::mem::offset_of!(_cef_binary_value_t, is_equal),
         64usize,
         concat!(
             "Offset of field: ",
@@ -5793,7 +5522,7 @@ fn bindgen_test_layout__cef_binary_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_binary_value_t>())).copy as *const _ as usize },
+        ::std::mem::offset_of!(_cef_binary_value_t, copy),
         72usize,
         concat!(
             "Offset of field: ",
@@ -5803,7 +5532,7 @@ fn bindgen_test_layout__cef_binary_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_binary_value_t>())).get_size as *const _ as usize },
+        ::std::mem::offset_of!(_cef_binary_value_t, get_size),
         80usize,
         concat!(
             "Offset of field: ",
@@ -5813,7 +5542,7 @@ fn bindgen_test_layout__cef_binary_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_binary_value_t>())).get_data as *const _ as usize },
+        ::std::mem::offset_of!(_cef_binary_value_t, get_data),
         88usize,
         concat!(
             "Offset of field: ",
@@ -6011,7 +5740,7 @@ fn bindgen_test_layout__cef_dictionary_value_t() {
         concat!("Alignment of ", stringify!(_cef_dictionary_value_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_dictionary_value_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_dictionary_value_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -6021,9 +5750,7 @@ fn bindgen_test_layout__cef_dictionary_value_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_dictionary_value_t>())).is_valid as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_dictionary_value_t, is_valid),
         40usize,
         concat!(
             "Offset of field: ",
@@ -6033,9 +5760,7 @@ fn bindgen_test_layout__cef_dictionary_value_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_dictionary_value_t>())).is_owned as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_dictionary_value_t, is_owned),
         48usize,
         concat!(
             "Offset of field: ",
@@ -6045,9 +5770,7 @@ fn bindgen_test_layout__cef_dictionary_value_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_dictionary_value_t>())).is_read_only as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_dictionary_value_t, is_read_only),
         56usize,
         concat!(
             "Offset of field: ",
@@ -6057,7 +5780,7 @@ fn bindgen_test_layout__cef_dictionary_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_dictionary_value_t>())).is_same as *const _ as usize },
+        ::std::mem::offset_of!(_cef_dictionary_value_t, is_same),
         64usize,
         concat!(
             "Offset of field: ",
@@ -6067,9 +5790,7 @@ fn bindgen_test_layout__cef_dictionary_value_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_dictionary_value_t>())).is_equal as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_dictionary_value_t, is_equal),
         72usize,
         concat!(
             "Offset of field: ",
@@ -6079,7 +5800,7 @@ fn bindgen_test_layout__cef_dictionary_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_dictionary_value_t>())).copy as *const _ as usize },
+        ::std::mem::offset_of!(_cef_dictionary_value_t, copy),
         80usize,
         concat!(
             "Offset of field: ",
@@ -6089,9 +5810,7 @@ fn bindgen_test_layout__cef_dictionary_value_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_dictionary_value_t>())).get_size as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_dictionary_value_t, get_size),
         88usize,
         concat!(
             "Offset of field: ",
@@ -6101,7 +5820,7 @@ fn bindgen_test_layout__cef_dictionary_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_dictionary_value_t>())).clear as *const _ as usize },
+        ::std::mem::offset_of!(_cef_dictionary_value_t, clear),
         96usize,
         concat!(
             "Offset of field: ",
@@ -6111,7 +5830,7 @@ fn bindgen_test_layout__cef_dictionary_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_dictionary_value_t>())).has_key as *const _ as usize },
+        ::std::mem::offset_of!(_cef_dictionary_value_t, has_key),
         104usize,
         concat!(
             "Offset of field: ",
@@ -6121,9 +5840,7 @@ fn bindgen_test_layout__cef_dictionary_value_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_dictionary_value_t>())).get_keys as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_dictionary_value_t, get_keys),
         112usize,
         concat!(
             "Offset of field: ",
@@ -6133,7 +5850,7 @@ fn bindgen_test_layout__cef_dictionary_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_dictionary_value_t>())).remove as *const _ as usize },
+        ::std::mem::offset_of!(_cef_dictionary_value_t, remove),
         120usize,
         concat!(
             "Offset of field: ",
@@ -6143,9 +5860,7 @@ fn bindgen_test_layout__cef_dictionary_value_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_dictionary_value_t>())).get_type as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_dictionary_value_t, get_type),
         128usize,
         concat!(
             "Offset of field: ",
@@ -6155,9 +5870,7 @@ fn bindgen_test_layout__cef_dictionary_value_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_dictionary_value_t>())).get_value as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_dictionary_value_t, get_value),
         136usize,
         concat!(
             "Offset of field: ",
@@ -6167,9 +5880,7 @@ fn bindgen_test_layout__cef_dictionary_value_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_dictionary_value_t>())).get_bool as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_dictionary_value_t, get_bool),
         144usize,
         concat!(
             "Offset of field: ",
@@ -6179,7 +5890,7 @@ fn bindgen_test_layout__cef_dictionary_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_dictionary_value_t>())).get_int as *const _ as usize },
+        ::std::mem::offset_of!(_cef_dictionary_value_t, get_int),
         152usize,
         concat!(
             "Offset of field: ",
@@ -6189,9 +5900,7 @@ fn bindgen_test_layout__cef_dictionary_value_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_dictionary_value_t>())).get_double as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_dictionary_value_t, get_double),
         160usize,
         concat!(
             "Offset of field: ",
@@ -6201,9 +5910,7 @@ fn bindgen_test_layout__cef_dictionary_value_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_dictionary_value_t>())).get_string as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_dictionary_value_t, get_string),
         168usize,
         concat!(
             "Offset of field: ",
@@ -6213,9 +5920,7 @@ fn bindgen_test_layout__cef_dictionary_value_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_dictionary_value_t>())).get_binary as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_dictionary_value_t, get_binary),
         176usize,
         concat!(
             "Offset of field: ",
@@ -6225,9 +5930,7 @@ fn bindgen_test_layout__cef_dictionary_value_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_dictionary_value_t>())).get_dictionary as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_dictionary_value_t, get_dictionary),
         184usize,
         concat!(
             "Offset of field: ",
@@ -6237,9 +5940,7 @@ fn bindgen_test_layout__cef_dictionary_value_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_dictionary_value_t>())).get_list as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_dictionary_value_t, get_list),
         192usize,
         concat!(
             "Offset of field: ",
@@ -6249,9 +5950,7 @@ fn bindgen_test_layout__cef_dictionary_value_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_dictionary_value_t>())).set_value as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_dictionary_value_t, set_value),
         200usize,
         concat!(
             "Offset of field: ",
@@ -6261,9 +5960,7 @@ fn bindgen_test_layout__cef_dictionary_value_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_dictionary_value_t>())).set_null as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_dictionary_value_t, set_null),
         208usize,
         concat!(
             "Offset of field: ",
@@ -6273,9 +5970,7 @@ fn bindgen_test_layout__cef_dictionary_value_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_dictionary_value_t>())).set_bool as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_dictionary_value_t, set_bool),
         216usize,
         concat!(
             "Offset of field: ",
@@ -6285,7 +5980,7 @@ fn bindgen_test_layout__cef_dictionary_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_dictionary_value_t>())).set_int as *const _ as usize },
+        ::std::mem::offset_of!(_cef_dictionary_value_t, set_int),
         224usize,
         concat!(
             "Offset of field: ",
@@ -6295,9 +5990,7 @@ fn bindgen_test_layout__cef_dictionary_value_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_dictionary_value_t>())).set_double as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_dictionary_value_t, set_double),
         232usize,
         concat!(
             "Offset of field: ",
@@ -6307,9 +6000,7 @@ fn bindgen_test_layout__cef_dictionary_value_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_dictionary_value_t>())).set_string as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_dictionary_value_t, set_string),
         240usize,
         concat!(
             "Offset of field: ",
@@ -6319,9 +6010,7 @@ fn bindgen_test_layout__cef_dictionary_value_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_dictionary_value_t>())).set_binary as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_dictionary_value_t, set_binary),
         248usize,
         concat!(
             "Offset of field: ",
@@ -6331,9 +6020,7 @@ fn bindgen_test_layout__cef_dictionary_value_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_dictionary_value_t>())).set_dictionary as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_dictionary_value_t, set_dictionary),
         256usize,
         concat!(
             "Offset of field: ",
@@ -6343,9 +6030,7 @@ fn bindgen_test_layout__cef_dictionary_value_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_dictionary_value_t>())).set_list as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_dictionary_value_t, set_list),
         264usize,
         concat!(
             "Offset of field: ",
@@ -6504,7 +6189,7 @@ fn bindgen_test_layout__cef_list_value_t() {
         concat!("Alignment of ", stringify!(_cef_list_value_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_list_value_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_list_value_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -6514,7 +6199,7 @@ fn bindgen_test_layout__cef_list_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_list_value_t>())).is_valid as *const _ as usize },
+        ::std::mem::offset_of!(_cef_list_value_t, is_valid),
         40usize,
         concat!(
             "Offset of field: ",
@@ -6524,7 +6209,7 @@ fn bindgen_test_layout__cef_list_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_list_value_t>())).is_owned as *const _ as usize },
+        ::std::mem::offset_of!(_cef_list_value_t, is_owned),
         48usize,
         concat!(
             "Offset of field: ",
@@ -6534,7 +6219,7 @@ fn bindgen_test_layout__cef_list_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_list_value_t>())).is_read_only as *const _ as usize },
+        ::std::mem::offset_of!(_cef_list_value_t, is_read_only),
         56usize,
         concat!(
             "Offset of field: ",
@@ -6544,7 +6229,7 @@ fn bindgen_test_layout__cef_list_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_list_value_t>())).is_same as *const _ as usize },
+        ::std::mem::offset_of!(_cef_list_value_t, is_same),
         64usize,
         concat!(
             "Offset of field: ",
@@ -6554,7 +6239,7 @@ fn bindgen_test_layout__cef_list_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_list_value_t>())).is_equal as *const _ as usize },
+        ::std::mem::offset_of!(_cef_list_value_t, is_equal),
         72usize,
         concat!(
             "Offset of field: ",
@@ -6564,7 +6249,7 @@ fn bindgen_test_layout__cef_list_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_list_value_t>())).copy as *const _ as usize },
+        ::std::mem::offset_of!(_cef_list_value_t, copy),
         80usize,
         concat!(
             "Offset of field: ",
@@ -6574,7 +6259,7 @@ fn bindgen_test_layout__cef_list_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_list_value_t>())).set_size as *const _ as usize },
+        ::std::mem::offset_of!(_cef_list_value_t, set_size),
         88usize,
         concat!(
             "Offset of field: ",
@@ -6584,7 +6269,7 @@ fn bindgen_test_layout__cef_list_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_list_value_t>())).get_size as *const _ as usize },
+        ::std::mem::offset_of!(_cef_list_value_t, get_size),
         96usize,
         concat!(
             "Offset of field: ",
@@ -6594,7 +6279,7 @@ fn bindgen_test_layout__cef_list_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_list_value_t>())).clear as *const _ as usize },
+        ::std::mem::offset_of!(_cef_list_value_t, clear),
         104usize,
         concat!(
             "Offset of field: ",
@@ -6604,7 +6289,7 @@ fn bindgen_test_layout__cef_list_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_list_value_t>())).remove as *const _ as usize },
+        ::std::mem::offset_of!(_cef_list_value_t, remove),
         112usize,
         concat!(
             "Offset of field: ",
@@ -6614,7 +6299,7 @@ fn bindgen_test_layout__cef_list_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_list_value_t>())).get_type as *const _ as usize },
+        ::std::mem::offset_of!(_cef_list_value_t, get_type),
         120usize,
         concat!(
             "Offset of field: ",
@@ -6624,7 +6309,7 @@ fn bindgen_test_layout__cef_list_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_list_value_t>())).get_value as *const _ as usize },
+        ::std::mem::offset_of!(_cef_list_value_t, get_value),
         128usize,
         concat!(
             "Offset of field: ",
@@ -6634,7 +6319,7 @@ fn bindgen_test_layout__cef_list_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_list_value_t>())).get_bool as *const _ as usize },
+        ::std::mem::offset_of!(_cef_list_value_t, get_bool),
         136usize,
         concat!(
             "Offset of field: ",
@@ -6644,7 +6329,7 @@ fn bindgen_test_layout__cef_list_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_list_value_t>())).get_int as *const _ as usize },
+        ::std::mem::offset_of!(_cef_list_value_t, get_int),
         144usize,
         concat!(
             "Offset of field: ",
@@ -6654,7 +6339,7 @@ fn bindgen_test_layout__cef_list_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_list_value_t>())).get_double as *const _ as usize },
+        ::std::mem::offset_of!(_cef_list_value_t, get_double),
         152usize,
         concat!(
             "Offset of field: ",
@@ -6664,7 +6349,7 @@ fn bindgen_test_layout__cef_list_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_list_value_t>())).get_string as *const _ as usize },
+        ::std::mem::offset_of!(_cef_list_value_t, get_string),
         160usize,
         concat!(
             "Offset of field: ",
@@ -6674,7 +6359,7 @@ fn bindgen_test_layout__cef_list_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_list_value_t>())).get_binary as *const _ as usize },
+        ::std::mem::offset_of!(_cef_list_value_t, get_binary),
         168usize,
         concat!(
             "Offset of field: ",
@@ -6684,9 +6369,7 @@ fn bindgen_test_layout__cef_list_value_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_list_value_t>())).get_dictionary as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_list_value_t, get_dictionary),
         176usize,
         concat!(
             "Offset of field: ",
@@ -6696,7 +6379,7 @@ fn bindgen_test_layout__cef_list_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_list_value_t>())).get_list as *const _ as usize },
+        ::std::mem::offset_of!(_cef_list_value_t, get_list),
         184usize,
         concat!(
             "Offset of field: ",
@@ -6706,7 +6389,7 @@ fn bindgen_test_layout__cef_list_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_list_value_t>())).set_value as *const _ as usize },
+        ::std::mem::offset_of!(_cef_list_value_t, set_value),
         192usize,
         concat!(
             "Offset of field: ",
@@ -6716,7 +6399,7 @@ fn bindgen_test_layout__cef_list_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_list_value_t>())).set_null as *const _ as usize },
+        ::std::mem::offset_of!(_cef_list_value_t, set_null),
         200usize,
         concat!(
             "Offset of field: ",
@@ -6726,7 +6409,7 @@ fn bindgen_test_layout__cef_list_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_list_value_t>())).set_bool as *const _ as usize },
+        ::std::mem::offset_of!(_cef_list_value_t, set_bool),
         208usize,
         concat!(
             "Offset of field: ",
@@ -6736,7 +6419,7 @@ fn bindgen_test_layout__cef_list_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_list_value_t>())).set_int as *const _ as usize },
+        ::std::mem::offset_of!(_cef_list_value_t, set_int),
         216usize,
         concat!(
             "Offset of field: ",
@@ -6746,7 +6429,7 @@ fn bindgen_test_layout__cef_list_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_list_value_t>())).set_double as *const _ as usize },
+        ::std::mem::offset_of!(_cef_list_value_t, set_double),
         224usize,
         concat!(
             "Offset of field: ",
@@ -6756,7 +6439,7 @@ fn bindgen_test_layout__cef_list_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_list_value_t>())).set_string as *const _ as usize },
+        ::std::mem::offset_of!(_cef_list_value_t, set_string),
         232usize,
         concat!(
             "Offset of field: ",
@@ -6766,7 +6449,7 @@ fn bindgen_test_layout__cef_list_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_list_value_t>())).set_binary as *const _ as usize },
+        ::std::mem::offset_of!(_cef_list_value_t, set_binary),
         240usize,
         concat!(
             "Offset of field: ",
@@ -6776,9 +6459,7 @@ fn bindgen_test_layout__cef_list_value_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_list_value_t>())).set_dictionary as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_list_value_t, set_dictionary),
         248usize,
         concat!(
             "Offset of field: ",
@@ -6788,7 +6469,7 @@ fn bindgen_test_layout__cef_list_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_list_value_t>())).set_list as *const _ as usize },
+        ::std::mem::offset_of!(_cef_list_value_t, set_list),
         256usize,
         concat!(
             "Offset of field: ",
@@ -6826,9 +6507,7 @@ fn bindgen_test_layout__cef_accessibility_handler_t() {
         concat!("Alignment of ", stringify!(_cef_accessibility_handler_t))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_accessibility_handler_t>())).base as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_accessibility_handler_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -6838,10 +6517,7 @@ fn bindgen_test_layout__cef_accessibility_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_accessibility_handler_t>())).on_accessibility_tree_change
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_accessibility_handler_t, on_accessibility_tree_change),
         40usize,
         concat!(
             "Offset of field: ",
@@ -6851,10 +6527,7 @@ fn bindgen_test_layout__cef_accessibility_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_accessibility_handler_t>()))
-                .on_accessibility_location_change as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_accessibility_handler_t, on_accessibility_location_change),
         48usize,
         concat!(
             "Offset of field: ",
@@ -6955,7 +6628,7 @@ fn bindgen_test_layout__cef_command_line_t() {
         concat!("Alignment of ", stringify!(_cef_command_line_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_command_line_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_command_line_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -6965,7 +6638,7 @@ fn bindgen_test_layout__cef_command_line_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_command_line_t>())).is_valid as *const _ as usize },
+        ::std::mem::offset_of!(_cef_command_line_t, is_valid),
         40usize,
         concat!(
             "Offset of field: ",
@@ -6975,9 +6648,7 @@ fn bindgen_test_layout__cef_command_line_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_command_line_t>())).is_read_only as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_command_line_t, is_read_only),
         48usize,
         concat!(
             "Offset of field: ",
@@ -6987,7 +6658,7 @@ fn bindgen_test_layout__cef_command_line_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_command_line_t>())).copy as *const _ as usize },
+        ::std::mem::offset_of!(_cef_command_line_t, copy),
         56usize,
         concat!(
             "Offset of field: ",
@@ -6997,9 +6668,7 @@ fn bindgen_test_layout__cef_command_line_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_command_line_t>())).init_from_argv as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_command_line_t, init_from_argv),
         64usize,
         concat!(
             "Offset of field: ",
@@ -7009,9 +6678,7 @@ fn bindgen_test_layout__cef_command_line_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_command_line_t>())).init_from_string as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_command_line_t, init_from_string),
         72usize,
         concat!(
             "Offset of field: ",
@@ -7021,7 +6688,7 @@ fn bindgen_test_layout__cef_command_line_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_command_line_t>())).reset as *const _ as usize },
+        ::std::mem::offset_of!(_cef_command_line_t, reset),
         80usize,
         concat!(
             "Offset of field: ",
@@ -7031,7 +6698,7 @@ fn bindgen_test_layout__cef_command_line_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_command_line_t>())).get_argv as *const _ as usize },
+        ::std::mem::offset_of!(_cef_command_line_t, get_argv),
         88usize,
         concat!(
             "Offset of field: ",
@@ -7041,10 +6708,7 @@ fn bindgen_test_layout__cef_command_line_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_command_line_t>())).get_command_line_string as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_command_line_t, get_command_line_string),
         96usize,
         concat!(
             "Offset of field: ",
@@ -7054,7 +6718,7 @@ fn bindgen_test_layout__cef_command_line_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_command_line_t>())).get_program as *const _ as usize },
+        ::std::mem::offset_of!(_cef_command_line_t, get_program),
         104usize,
         concat!(
             "Offset of field: ",
@@ -7064,7 +6728,7 @@ fn bindgen_test_layout__cef_command_line_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_command_line_t>())).set_program as *const _ as usize },
+        ::std::mem::offset_of!(_cef_command_line_t, set_program),
         112usize,
         concat!(
             "Offset of field: ",
@@ -7074,9 +6738,7 @@ fn bindgen_test_layout__cef_command_line_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_command_line_t>())).has_switches as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_command_line_t, has_switches),
         120usize,
         concat!(
             "Offset of field: ",
@@ -7086,7 +6748,7 @@ fn bindgen_test_layout__cef_command_line_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_command_line_t>())).has_switch as *const _ as usize },
+        ::std::mem::offset_of!(_cef_command_line_t, has_switch),
         128usize,
         concat!(
             "Offset of field: ",
@@ -7096,9 +6758,7 @@ fn bindgen_test_layout__cef_command_line_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_command_line_t>())).get_switch_value as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_command_line_t, get_switch_value),
         136usize,
         concat!(
             "Offset of field: ",
@@ -7108,9 +6768,7 @@ fn bindgen_test_layout__cef_command_line_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_command_line_t>())).get_switches as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_command_line_t, get_switches),
         144usize,
         concat!(
             "Offset of field: ",
@@ -7120,9 +6778,7 @@ fn bindgen_test_layout__cef_command_line_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_command_line_t>())).append_switch as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_command_line_t, append_switch),
         152usize,
         concat!(
             "Offset of field: ",
@@ -7132,10 +6788,7 @@ fn bindgen_test_layout__cef_command_line_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_command_line_t>())).append_switch_with_value as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_command_line_t, append_switch_with_value),
         160usize,
         concat!(
             "Offset of field: ",
@@ -7145,9 +6798,7 @@ fn bindgen_test_layout__cef_command_line_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_command_line_t>())).has_arguments as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_command_line_t, has_arguments),
         168usize,
         concat!(
             "Offset of field: ",
@@ -7157,9 +6808,7 @@ fn bindgen_test_layout__cef_command_line_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_command_line_t>())).get_arguments as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_command_line_t, get_arguments),
         176usize,
         concat!(
             "Offset of field: ",
@@ -7169,9 +6818,7 @@ fn bindgen_test_layout__cef_command_line_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_command_line_t>())).append_argument as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_command_line_t, append_argument),
         184usize,
         concat!(
             "Offset of field: ",
@@ -7181,9 +6828,7 @@ fn bindgen_test_layout__cef_command_line_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_command_line_t>())).prepend_wrapper as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_command_line_t, prepend_wrapper),
         192usize,
         concat!(
             "Offset of field: ",
@@ -7260,9 +6905,7 @@ fn bindgen_test_layout__cef_dev_tools_message_observer_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_dev_tools_message_observer_t>())).base as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_dev_tools_message_observer_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -7272,10 +6915,7 @@ fn bindgen_test_layout__cef_dev_tools_message_observer_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_dev_tools_message_observer_t>())).on_dev_tools_message
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_dev_tools_message_observer_t, on_dev_tools_message),
         40usize,
         concat!(
             "Offset of field: ",
@@ -7285,10 +6925,7 @@ fn bindgen_test_layout__cef_dev_tools_message_observer_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_dev_tools_message_observer_t>())).on_dev_tools_method_result
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_dev_tools_message_observer_t, on_dev_tools_method_result),
         48usize,
         concat!(
             "Offset of field: ",
@@ -7298,10 +6935,7 @@ fn bindgen_test_layout__cef_dev_tools_message_observer_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_dev_tools_message_observer_t>())).on_dev_tools_event
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_dev_tools_message_observer_t, on_dev_tools_event),
         56usize,
         concat!(
             "Offset of field: ",
@@ -7311,10 +6945,7 @@ fn bindgen_test_layout__cef_dev_tools_message_observer_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_dev_tools_message_observer_t>()))
-                .on_dev_tools_agent_attached as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_dev_tools_message_observer_t, on_dev_tools_agent_attached),
         64usize,
         concat!(
             "Offset of field: ",
@@ -7324,10 +6955,7 @@ fn bindgen_test_layout__cef_dev_tools_message_observer_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_dev_tools_message_observer_t>()))
-                .on_dev_tools_agent_detached as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_dev_tools_message_observer_t, on_dev_tools_agent_detached),
         72usize,
         concat!(
             "Offset of field: ",
@@ -7438,7 +7066,7 @@ fn bindgen_test_layout__cef_image_t() {
         concat!("Alignment of ", stringify!(_cef_image_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_image_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_image_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -7448,7 +7076,7 @@ fn bindgen_test_layout__cef_image_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_image_t>())).is_empty as *const _ as usize },
+        ::std::mem::offset_of!(_cef_image_t, is_empty),
         40usize,
         concat!(
             "Offset of field: ",
@@ -7458,7 +7086,7 @@ fn bindgen_test_layout__cef_image_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_image_t>())).is_same as *const _ as usize },
+        ::std::mem::offset_of!(_cef_image_t, is_same),
         48usize,
         concat!(
             "Offset of field: ",
@@ -7468,7 +7096,7 @@ fn bindgen_test_layout__cef_image_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_image_t>())).add_bitmap as *const _ as usize },
+        ::std::mem::offset_of!(_cef_image_t, add_bitmap),
         56usize,
         concat!(
             "Offset of field: ",
@@ -7478,7 +7106,7 @@ fn bindgen_test_layout__cef_image_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_image_t>())).add_png as *const _ as usize },
+        ::std::mem::offset_of!(_cef_image_t, add_png),
         64usize,
         concat!(
             "Offset of field: ",
@@ -7488,7 +7116,7 @@ fn bindgen_test_layout__cef_image_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_image_t>())).add_jpeg as *const _ as usize },
+        ::std::mem::offset_of!(_cef_image_t, add_jpeg),
         72usize,
         concat!(
             "Offset of field: ",
@@ -7498,7 +7126,7 @@ fn bindgen_test_layout__cef_image_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_image_t>())).get_width as *const _ as usize },
+        ::std::mem::offset_of!(_cef_image_t, get_width),
         80usize,
         concat!(
             "Offset of field: ",
@@ -7508,7 +7136,7 @@ fn bindgen_test_layout__cef_image_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_image_t>())).get_height as *const _ as usize },
+        ::std::mem::offset_of!(_cef_image_t, get_height),
         88usize,
         concat!(
             "Offset of field: ",
@@ -7518,7 +7146,7 @@ fn bindgen_test_layout__cef_image_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_image_t>())).has_representation as *const _ as usize },
+        ::std::mem::offset_of!(_cef_image_t, has_representation),
         96usize,
         concat!(
             "Offset of field: ",
@@ -7528,9 +7156,7 @@ fn bindgen_test_layout__cef_image_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_image_t>())).remove_representation as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_image_t, remove_representation),
         104usize,
         concat!(
             "Offset of field: ",
@@ -7540,9 +7166,7 @@ fn bindgen_test_layout__cef_image_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_image_t>())).get_representation_info as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_image_t, get_representation_info),
         112usize,
         concat!(
             "Offset of field: ",
@@ -7552,7 +7176,7 @@ fn bindgen_test_layout__cef_image_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_image_t>())).get_as_bitmap as *const _ as usize },
+        ::std::mem::offset_of!(_cef_image_t, get_as_bitmap),
         120usize,
         concat!(
             "Offset of field: ",
@@ -7562,7 +7186,7 @@ fn bindgen_test_layout__cef_image_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_image_t>())).get_as_png as *const _ as usize },
+        ::std::mem::offset_of!(_cef_image_t, get_as_png),
         128usize,
         concat!(
             "Offset of field: ",
@@ -7572,7 +7196,7 @@ fn bindgen_test_layout__cef_image_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_image_t>())).get_as_jpeg as *const _ as usize },
+        ::std::mem::offset_of!(_cef_image_t, get_as_jpeg),
         136usize,
         concat!(
             "Offset of field: ",
@@ -7626,7 +7250,7 @@ fn bindgen_test_layout__cef_read_handler_t() {
         concat!("Alignment of ", stringify!(_cef_read_handler_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_read_handler_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_read_handler_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -7636,7 +7260,7 @@ fn bindgen_test_layout__cef_read_handler_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_read_handler_t>())).read as *const _ as usize },
+        ::std::mem::offset_of!(_cef_read_handler_t, read),
         40usize,
         concat!(
             "Offset of field: ",
@@ -7646,7 +7270,7 @@ fn bindgen_test_layout__cef_read_handler_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_read_handler_t>())).seek as *const _ as usize },
+        ::std::mem::offset_of!(_cef_read_handler_t, seek),
         48usize,
         concat!(
             "Offset of field: ",
@@ -7656,7 +7280,7 @@ fn bindgen_test_layout__cef_read_handler_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_read_handler_t>())).tell as *const _ as usize },
+        ::std::mem::offset_of!(_cef_read_handler_t, tell),
         56usize,
         concat!(
             "Offset of field: ",
@@ -7666,7 +7290,7 @@ fn bindgen_test_layout__cef_read_handler_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_read_handler_t>())).eof as *const _ as usize },
+        ::std::mem::offset_of!(_cef_read_handler_t, eof),
         64usize,
         concat!(
             "Offset of field: ",
@@ -7676,7 +7300,7 @@ fn bindgen_test_layout__cef_read_handler_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_read_handler_t>())).may_block as *const _ as usize },
+        ::std::mem::offset_of!(_cef_read_handler_t, may_block),
         72usize,
         concat!(
             "Offset of field: ",
@@ -7728,7 +7352,7 @@ fn bindgen_test_layout__cef_stream_reader_t() {
         concat!("Alignment of ", stringify!(_cef_stream_reader_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_stream_reader_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_stream_reader_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -7738,7 +7362,7 @@ fn bindgen_test_layout__cef_stream_reader_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_stream_reader_t>())).read as *const _ as usize },
+        ::std::mem::offset_of!(_cef_stream_reader_t, read),
         40usize,
         concat!(
             "Offset of field: ",
@@ -7748,7 +7372,7 @@ fn bindgen_test_layout__cef_stream_reader_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_stream_reader_t>())).seek as *const _ as usize },
+        ::std::mem::offset_of!(_cef_stream_reader_t, seek),
         48usize,
         concat!(
             "Offset of field: ",
@@ -7758,7 +7382,7 @@ fn bindgen_test_layout__cef_stream_reader_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_stream_reader_t>())).tell as *const _ as usize },
+        ::std::mem::offset_of!(_cef_stream_reader_t, tell),
         56usize,
         concat!(
             "Offset of field: ",
@@ -7768,7 +7392,7 @@ fn bindgen_test_layout__cef_stream_reader_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_stream_reader_t>())).eof as *const _ as usize },
+        ::std::mem::offset_of!(_cef_stream_reader_t, eof),
         64usize,
         concat!(
             "Offset of field: ",
@@ -7778,7 +7402,7 @@ fn bindgen_test_layout__cef_stream_reader_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_stream_reader_t>())).may_block as *const _ as usize },
+        ::std::mem::offset_of!(_cef_stream_reader_t, may_block),
         72usize,
         concat!(
             "Offset of field: ",
@@ -7846,7 +7470,7 @@ fn bindgen_test_layout__cef_write_handler_t() {
         concat!("Alignment of ", stringify!(_cef_write_handler_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_write_handler_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_write_handler_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -7856,7 +7480,7 @@ fn bindgen_test_layout__cef_write_handler_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_write_handler_t>())).write as *const _ as usize },
+        ::std::mem::offset_of!(_cef_write_handler_t, write),
         40usize,
         concat!(
             "Offset of field: ",
@@ -7866,7 +7490,7 @@ fn bindgen_test_layout__cef_write_handler_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_write_handler_t>())).seek as *const _ as usize },
+        ::std::mem::offset_of!(_cef_write_handler_t, seek),
         48usize,
         concat!(
             "Offset of field: ",
@@ -7876,7 +7500,7 @@ fn bindgen_test_layout__cef_write_handler_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_write_handler_t>())).tell as *const _ as usize },
+        ::std::mem::offset_of!(_cef_write_handler_t, tell),
         56usize,
         concat!(
             "Offset of field: ",
@@ -7886,7 +7510,7 @@ fn bindgen_test_layout__cef_write_handler_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_write_handler_t>())).flush as *const _ as usize },
+        ::std::mem::offset_of!(_cef_write_handler_t, flush),
         64usize,
         concat!(
             "Offset of field: ",
@@ -7896,7 +7520,7 @@ fn bindgen_test_layout__cef_write_handler_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_write_handler_t>())).may_block as *const _ as usize },
+        ::std::mem::offset_of!(_cef_write_handler_t, may_block),
         72usize,
         concat!(
             "Offset of field: ",
@@ -7948,7 +7572,7 @@ fn bindgen_test_layout__cef_stream_writer_t() {
         concat!("Alignment of ", stringify!(_cef_stream_writer_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_stream_writer_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_stream_writer_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -7958,7 +7582,7 @@ fn bindgen_test_layout__cef_stream_writer_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_stream_writer_t>())).write as *const _ as usize },
+        ::std::mem::offset_of!(_cef_stream_writer_t, write),
         40usize,
         concat!(
             "Offset of field: ",
@@ -7968,7 +7592,7 @@ fn bindgen_test_layout__cef_stream_writer_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_stream_writer_t>())).seek as *const _ as usize },
+        ::std::mem::offset_of!(_cef_stream_writer_t, seek),
         48usize,
         concat!(
             "Offset of field: ",
@@ -7978,7 +7602,7 @@ fn bindgen_test_layout__cef_stream_writer_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_stream_writer_t>())).tell as *const _ as usize },
+        ::std::mem::offset_of!(_cef_stream_writer_t, tell),
         56usize,
         concat!(
             "Offset of field: ",
@@ -7988,7 +7612,7 @@ fn bindgen_test_layout__cef_stream_writer_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_stream_writer_t>())).flush as *const _ as usize },
+        ::std::mem::offset_of!(_cef_stream_writer_t, flush),
         64usize,
         concat!(
             "Offset of field: ",
@@ -7998,7 +7622,7 @@ fn bindgen_test_layout__cef_stream_writer_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_stream_writer_t>())).may_block as *const _ as usize },
+        ::std::mem::offset_of!(_cef_stream_writer_t, may_block),
         72usize,
         concat!(
             "Offset of field: ",
@@ -8120,7 +7744,7 @@ fn bindgen_test_layout__cef_drag_data_t() {
         concat!("Alignment of ", stringify!(_cef_drag_data_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_drag_data_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_drag_data_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -8130,7 +7754,7 @@ fn bindgen_test_layout__cef_drag_data_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_drag_data_t>())).clone as *const _ as usize },
+        ::std::mem::offset_of!(_cef_drag_data_t, clone),
         40usize,
         concat!(
             "Offset of field: ",
@@ -8140,7 +7764,7 @@ fn bindgen_test_layout__cef_drag_data_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_drag_data_t>())).is_read_only as *const _ as usize },
+        ::std::mem::offset_of!(_cef_drag_data_t, is_read_only),
         48usize,
         concat!(
             "Offset of field: ",
@@ -8150,7 +7774,7 @@ fn bindgen_test_layout__cef_drag_data_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_drag_data_t>())).is_link as *const _ as usize },
+        ::std::mem::offset_of!(_cef_drag_data_t, is_link),
         56usize,
         concat!(
             "Offset of field: ",
@@ -8160,7 +7784,7 @@ fn bindgen_test_layout__cef_drag_data_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_drag_data_t>())).is_fragment as *const _ as usize },
+        ::std::mem::offset_of!(_cef_drag_data_t, is_fragment),
         64usize,
         concat!(
             "Offset of field: ",
@@ -8170,7 +7794,7 @@ fn bindgen_test_layout__cef_drag_data_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_drag_data_t>())).is_file as *const _ as usize },
+        ::std::mem::offset_of!(_cef_drag_data_t, is_file),
         72usize,
         concat!(
             "Offset of field: ",
@@ -8180,7 +7804,7 @@ fn bindgen_test_layout__cef_drag_data_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_drag_data_t>())).get_link_url as *const _ as usize },
+        ::std::mem::offset_of!(_cef_drag_data_t, get_link_url),
         80usize,
         concat!(
             "Offset of field: ",
@@ -8190,7 +7814,7 @@ fn bindgen_test_layout__cef_drag_data_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_drag_data_t>())).get_link_title as *const _ as usize },
+        ::std::mem::offset_of!(_cef_drag_data_t, get_link_title),
         88usize,
         concat!(
             "Offset of field: ",
@@ -8200,9 +7824,7 @@ fn bindgen_test_layout__cef_drag_data_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_drag_data_t>())).get_link_metadata as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_drag_data_t, get_link_metadata),
         96usize,
         concat!(
             "Offset of field: ",
@@ -8212,9 +7834,7 @@ fn bindgen_test_layout__cef_drag_data_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_drag_data_t>())).get_fragment_text as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_drag_data_t, get_fragment_text),
         104usize,
         concat!(
             "Offset of field: ",
@@ -8224,9 +7844,7 @@ fn bindgen_test_layout__cef_drag_data_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_drag_data_t>())).get_fragment_html as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_drag_data_t, get_fragment_html),
         112usize,
         concat!(
             "Offset of field: ",
@@ -8236,9 +7854,7 @@ fn bindgen_test_layout__cef_drag_data_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_drag_data_t>())).get_fragment_base_url as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_drag_data_t, get_fragment_base_url),
         120usize,
         concat!(
             "Offset of field: ",
@@ -8248,7 +7864,7 @@ fn bindgen_test_layout__cef_drag_data_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_drag_data_t>())).get_file_name as *const _ as usize },
+        ::std::mem::offset_of!(_cef_drag_data_t, get_file_name),
         128usize,
         concat!(
             "Offset of field: ",
@@ -8258,9 +7874,7 @@ fn bindgen_test_layout__cef_drag_data_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_drag_data_t>())).get_file_contents as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_drag_data_t, get_file_contents),
         136usize,
         concat!(
             "Offset of field: ",
@@ -8270,7 +7884,7 @@ fn bindgen_test_layout__cef_drag_data_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_drag_data_t>())).get_file_names as *const _ as usize },
+        ::std::mem::offset_of!(_cef_drag_data_t, get_file_names),
         144usize,
         concat!(
             "Offset of field: ",
@@ -8280,7 +7894,7 @@ fn bindgen_test_layout__cef_drag_data_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_drag_data_t>())).set_link_url as *const _ as usize },
+        ::std::mem::offset_of!(_cef_drag_data_t, set_link_url),
         152usize,
         concat!(
             "Offset of field: ",
@@ -8290,7 +7904,7 @@ fn bindgen_test_layout__cef_drag_data_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_drag_data_t>())).set_link_title as *const _ as usize },
+        ::std::mem::offset_of!(_cef_drag_data_t, set_link_title),
         160usize,
         concat!(
             "Offset of field: ",
@@ -8300,9 +7914,7 @@ fn bindgen_test_layout__cef_drag_data_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_drag_data_t>())).set_link_metadata as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_drag_data_t, set_link_metadata),
         168usize,
         concat!(
             "Offset of field: ",
@@ -8312,9 +7924,7 @@ fn bindgen_test_layout__cef_drag_data_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_drag_data_t>())).set_fragment_text as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_drag_data_t, set_fragment_text),
         176usize,
         concat!(
             "Offset of field: ",
@@ -8324,9 +7934,7 @@ fn bindgen_test_layout__cef_drag_data_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_drag_data_t>())).set_fragment_html as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_drag_data_t, set_fragment_html),
         184usize,
         concat!(
             "Offset of field: ",
@@ -8336,9 +7944,7 @@ fn bindgen_test_layout__cef_drag_data_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_drag_data_t>())).set_fragment_base_url as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_drag_data_t, set_fragment_base_url),
         192usize,
         concat!(
             "Offset of field: ",
@@ -8348,9 +7954,7 @@ fn bindgen_test_layout__cef_drag_data_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_drag_data_t>())).reset_file_contents as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_drag_data_t, reset_file_contents),
         200usize,
         concat!(
             "Offset of field: ",
@@ -8360,7 +7964,7 @@ fn bindgen_test_layout__cef_drag_data_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_drag_data_t>())).add_file as *const _ as usize },
+        ::std::mem::offset_of!(_cef_drag_data_t, add_file),
         208usize,
         concat!(
             "Offset of field: ",
@@ -8370,7 +7974,7 @@ fn bindgen_test_layout__cef_drag_data_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_drag_data_t>())).get_image as *const _ as usize },
+        ::std::mem::offset_of!(_cef_drag_data_t, get_image),
         216usize,
         concat!(
             "Offset of field: ",
@@ -8380,9 +7984,7 @@ fn bindgen_test_layout__cef_drag_data_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_drag_data_t>())).get_image_hotspot as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_drag_data_t, get_image_hotspot),
         224usize,
         concat!(
             "Offset of field: ",
@@ -8392,7 +7994,7 @@ fn bindgen_test_layout__cef_drag_data_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_drag_data_t>())).has_image as *const _ as usize },
+        ::std::mem::offset_of!(_cef_drag_data_t, has_image),
         232usize,
         concat!(
             "Offset of field: ",
@@ -8427,7 +8029,7 @@ fn bindgen_test_layout__cef_domvisitor_t() {
         concat!("Alignment of ", stringify!(_cef_domvisitor_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_domvisitor_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_domvisitor_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -8437,7 +8039,7 @@ fn bindgen_test_layout__cef_domvisitor_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_domvisitor_t>())).visit as *const _ as usize },
+        ::std::mem::offset_of!(_cef_domvisitor_t, visit),
         40usize,
         concat!(
             "Offset of field: ",
@@ -8514,7 +8116,7 @@ fn bindgen_test_layout__cef_domdocument_t() {
         concat!("Alignment of ", stringify!(_cef_domdocument_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_domdocument_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_domdocument_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -8524,7 +8126,7 @@ fn bindgen_test_layout__cef_domdocument_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_domdocument_t>())).get_type as *const _ as usize },
+        ::std::mem::offset_of!(_cef_domdocument_t, get_type),
         40usize,
         concat!(
             "Offset of field: ",
@@ -8534,7 +8136,7 @@ fn bindgen_test_layout__cef_domdocument_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_domdocument_t>())).get_document as *const _ as usize },
+        ::std::mem::offset_of!(_cef_domdocument_t, get_document),
         48usize,
         concat!(
             "Offset of field: ",
@@ -8544,7 +8146,7 @@ fn bindgen_test_layout__cef_domdocument_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_domdocument_t>())).get_body as *const _ as usize },
+        ::std::mem::offset_of!(_cef_domdocument_t, get_body),
         56usize,
         concat!(
             "Offset of field: ",
@@ -8554,7 +8156,7 @@ fn bindgen_test_layout__cef_domdocument_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_domdocument_t>())).get_head as *const _ as usize },
+        ::std::mem::offset_of!(_cef_domdocument_t, get_head),
         64usize,
         concat!(
             "Offset of field: ",
@@ -8564,7 +8166,7 @@ fn bindgen_test_layout__cef_domdocument_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_domdocument_t>())).get_title as *const _ as usize },
+        ::std::mem::offset_of!(_cef_domdocument_t, get_title),
         72usize,
         concat!(
             "Offset of field: ",
@@ -8574,9 +8176,7 @@ fn bindgen_test_layout__cef_domdocument_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_domdocument_t>())).get_element_by_id as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_domdocument_t, get_element_by_id),
         80usize,
         concat!(
             "Offset of field: ",
@@ -8586,9 +8186,7 @@ fn bindgen_test_layout__cef_domdocument_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_domdocument_t>())).get_focused_node as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_domdocument_t, get_focused_node),
         88usize,
         concat!(
             "Offset of field: ",
@@ -8598,9 +8196,7 @@ fn bindgen_test_layout__cef_domdocument_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_domdocument_t>())).has_selection as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_domdocument_t, has_selection),
         96usize,
         concat!(
             "Offset of field: ",
@@ -8610,10 +8206,7 @@ fn bindgen_test_layout__cef_domdocument_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_domdocument_t>())).get_selection_start_offset as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_domdocument_t, get_selection_start_offset),
         104usize,
         concat!(
             "Offset of field: ",
@@ -8623,10 +8216,7 @@ fn bindgen_test_layout__cef_domdocument_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_domdocument_t>())).get_selection_end_offset as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_domdocument_t, get_selection_end_offset),
         112usize,
         concat!(
             "Offset of field: ",
@@ -8636,10 +8226,7 @@ fn bindgen_test_layout__cef_domdocument_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_domdocument_t>())).get_selection_as_markup as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_domdocument_t, get_selection_as_markup),
         120usize,
         concat!(
             "Offset of field: ",
@@ -8649,10 +8236,7 @@ fn bindgen_test_layout__cef_domdocument_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_domdocument_t>())).get_selection_as_text as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_domdocument_t, get_selection_as_text),
         128usize,
         concat!(
             "Offset of field: ",
@@ -8662,7 +8246,7 @@ fn bindgen_test_layout__cef_domdocument_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_domdocument_t>())).get_base_url as *const _ as usize },
+        ::std::mem::offset_of!(_cef_domdocument_t, get_base_url),
         136usize,
         concat!(
             "Offset of field: ",
@@ -8672,9 +8256,7 @@ fn bindgen_test_layout__cef_domdocument_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_domdocument_t>())).get_complete_url as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_domdocument_t, get_complete_url),
         144usize,
         concat!(
             "Offset of field: ",
@@ -8796,7 +8378,7 @@ fn bindgen_test_layout__cef_domnode_t() {
         concat!("Alignment of ", stringify!(_cef_domnode_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_domnode_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_domnode_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -8806,7 +8388,7 @@ fn bindgen_test_layout__cef_domnode_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_domnode_t>())).get_type as *const _ as usize },
+        ::std::mem::offset_of!(_cef_domnode_t, get_type),
         40usize,
         concat!(
             "Offset of field: ",
@@ -8816,7 +8398,7 @@ fn bindgen_test_layout__cef_domnode_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_domnode_t>())).is_text as *const _ as usize },
+        ::std::mem::offset_of!(_cef_domnode_t, is_text),
         48usize,
         concat!(
             "Offset of field: ",
@@ -8826,7 +8408,7 @@ fn bindgen_test_layout__cef_domnode_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_domnode_t>())).is_element as *const _ as usize },
+        ::std::mem::offset_of!(_cef_domnode_t, is_element),
         56usize,
         concat!(
             "Offset of field: ",
@@ -8836,7 +8418,7 @@ fn bindgen_test_layout__cef_domnode_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_domnode_t>())).is_editable as *const _ as usize },
+        ::std::mem::offset_of!(_cef_domnode_t, is_editable),
         64usize,
         concat!(
             "Offset of field: ",
@@ -8846,9 +8428,7 @@ fn bindgen_test_layout__cef_domnode_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_domnode_t>())).is_form_control_element as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_domnode_t, is_form_control_element),
         72usize,
         concat!(
             "Offset of field: ",
@@ -8858,10 +8438,7 @@ fn bindgen_test_layout__cef_domnode_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_domnode_t>())).get_form_control_element_type as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_domnode_t, get_form_control_element_type),
         80usize,
         concat!(
             "Offset of field: ",
@@ -8871,7 +8448,7 @@ fn bindgen_test_layout__cef_domnode_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_domnode_t>())).is_same as *const _ as usize },
+        ::std::mem::offset_of!(_cef_domnode_t, is_same),
         88usize,
         concat!(
             "Offset of field: ",
@@ -8881,7 +8458,7 @@ fn bindgen_test_layout__cef_domnode_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_domnode_t>())).get_name as *const _ as usize },
+        ::std::mem::offset_of!(_cef_domnode_t, get_name),
         96usize,
         concat!(
             "Offset of field: ",
@@ -8891,7 +8468,7 @@ fn bindgen_test_layout__cef_domnode_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_domnode_t>())).get_value as *const _ as usize },
+        ::std::mem::offset_of!(_cef_domnode_t, get_value),
         104usize,
         concat!(
             "Offset of field: ",
@@ -8901,7 +8478,7 @@ fn bindgen_test_layout__cef_domnode_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_domnode_t>())).set_value as *const _ as usize },
+        ::std::mem::offset_of!(_cef_domnode_t, set_value),
         112usize,
         concat!(
             "Offset of field: ",
@@ -8911,7 +8488,7 @@ fn bindgen_test_layout__cef_domnode_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_domnode_t>())).get_as_markup as *const _ as usize },
+        ::std::mem::offset_of!(_cef_domnode_t, get_as_markup),
         120usize,
         concat!(
             "Offset of field: ",
@@ -8921,7 +8498,7 @@ fn bindgen_test_layout__cef_domnode_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_domnode_t>())).get_document as *const _ as usize },
+        ::std::mem::offset_of!(_cef_domnode_t, get_document),
         128usize,
         concat!(
             "Offset of field: ",
@@ -8931,7 +8508,7 @@ fn bindgen_test_layout__cef_domnode_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_domnode_t>())).get_parent as *const _ as usize },
+        ::std::mem::offset_of!(_cef_domnode_t, get_parent),
         136usize,
         concat!(
             "Offset of field: ",
@@ -8941,9 +8518,7 @@ fn bindgen_test_layout__cef_domnode_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_domnode_t>())).get_previous_sibling as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_domnode_t, get_previous_sibling),
         144usize,
         concat!(
             "Offset of field: ",
@@ -8953,7 +8528,7 @@ fn bindgen_test_layout__cef_domnode_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_domnode_t>())).get_next_sibling as *const _ as usize },
+        ::std::mem::offset_of!(_cef_domnode_t, get_next_sibling),
         152usize,
         concat!(
             "Offset of field: ",
@@ -8963,7 +8538,7 @@ fn bindgen_test_layout__cef_domnode_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_domnode_t>())).has_children as *const _ as usize },
+        ::std::mem::offset_of!(_cef_domnode_t, has_children),
         160usize,
         concat!(
             "Offset of field: ",
@@ -8973,7 +8548,7 @@ fn bindgen_test_layout__cef_domnode_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_domnode_t>())).get_first_child as *const _ as usize },
+        ::std::mem::offset_of!(_cef_domnode_t, get_first_child),
         168usize,
         concat!(
             "Offset of field: ",
@@ -8983,7 +8558,7 @@ fn bindgen_test_layout__cef_domnode_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_domnode_t>())).get_last_child as *const _ as usize },
+        ::std::mem::offset_of!(_cef_domnode_t, get_last_child),
         176usize,
         concat!(
             "Offset of field: ",
@@ -8993,9 +8568,7 @@ fn bindgen_test_layout__cef_domnode_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_domnode_t>())).get_element_tag_name as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_domnode_t, get_element_tag_name),
         184usize,
         concat!(
             "Offset of field: ",
@@ -9005,9 +8578,7 @@ fn bindgen_test_layout__cef_domnode_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_domnode_t>())).has_element_attributes as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_domnode_t, has_element_attributes),
         192usize,
         concat!(
             "Offset of field: ",
@@ -9017,9 +8588,7 @@ fn bindgen_test_layout__cef_domnode_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_domnode_t>())).has_element_attribute as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_domnode_t, has_element_attribute),
         200usize,
         concat!(
             "Offset of field: ",
@@ -9029,9 +8598,7 @@ fn bindgen_test_layout__cef_domnode_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_domnode_t>())).get_element_attribute as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_domnode_t, get_element_attribute),
         208usize,
         concat!(
             "Offset of field: ",
@@ -9041,9 +8608,7 @@ fn bindgen_test_layout__cef_domnode_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_domnode_t>())).get_element_attributes as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_domnode_t, get_element_attributes),
         216usize,
         concat!(
             "Offset of field: ",
@@ -9053,9 +8618,7 @@ fn bindgen_test_layout__cef_domnode_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_domnode_t>())).set_element_attribute as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_domnode_t, set_element_attribute),
         224usize,
         concat!(
             "Offset of field: ",
@@ -9065,9 +8628,7 @@ fn bindgen_test_layout__cef_domnode_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_domnode_t>())).get_element_inner_text as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_domnode_t, get_element_inner_text),
         232usize,
         concat!(
             "Offset of field: ",
@@ -9077,9 +8638,7 @@ fn bindgen_test_layout__cef_domnode_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_domnode_t>())).get_element_bounds as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_domnode_t, get_element_bounds),
         240usize,
         concat!(
             "Offset of field: ",
@@ -9123,7 +8682,7 @@ fn bindgen_test_layout__cef_process_message_t() {
         concat!("Alignment of ", stringify!(_cef_process_message_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_process_message_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_process_message_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -9133,7 +8692,7 @@ fn bindgen_test_layout__cef_process_message_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_process_message_t>())).is_valid as *const _ as usize },
+        ::std::mem::offset_of!(_cef_process_message_t, is_valid),
         40usize,
         concat!(
             "Offset of field: ",
@@ -9143,9 +8702,7 @@ fn bindgen_test_layout__cef_process_message_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_process_message_t>())).is_read_only as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_process_message_t, is_read_only),
         48usize,
         concat!(
             "Offset of field: ",
@@ -9155,7 +8712,7 @@ fn bindgen_test_layout__cef_process_message_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_process_message_t>())).copy as *const _ as usize },
+        ::std::mem::offset_of!(_cef_process_message_t, copy),
         56usize,
         concat!(
             "Offset of field: ",
@@ -9165,7 +8722,7 @@ fn bindgen_test_layout__cef_process_message_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_process_message_t>())).get_name as *const _ as usize },
+        ::std::mem::offset_of!(_cef_process_message_t, get_name),
         64usize,
         concat!(
             "Offset of field: ",
@@ -9175,10 +8732,7 @@ fn bindgen_test_layout__cef_process_message_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_process_message_t>())).get_argument_list as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_process_message_t, get_argument_list),
         72usize,
         concat!(
             "Offset of field: ",
@@ -9293,7 +8847,7 @@ fn bindgen_test_layout__cef_request_t() {
         concat!("Alignment of ", stringify!(_cef_request_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_request_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_request_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -9303,7 +8857,7 @@ fn bindgen_test_layout__cef_request_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_request_t>())).is_read_only as *const _ as usize },
+        ::std::mem::offset_of!(_cef_request_t, is_read_only),
         40usize,
         concat!(
             "Offset of field: ",
@@ -9313,7 +8867,7 @@ fn bindgen_test_layout__cef_request_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_request_t>())).get_url as *const _ as usize },
+        ::std::mem::offset_of!(_cef_request_t, get_url),
         48usize,
         concat!(
             "Offset of field: ",
@@ -9323,7 +8877,7 @@ fn bindgen_test_layout__cef_request_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_request_t>())).set_url as *const _ as usize },
+        ::std::mem::offset_of!(_cef_request_t, set_url),
         56usize,
         concat!(
             "Offset of field: ",
@@ -9333,7 +8887,7 @@ fn bindgen_test_layout__cef_request_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_request_t>())).get_method as *const _ as usize },
+        ::std::mem::offset_of!(_cef_request_t, get_method),
         64usize,
         concat!(
             "Offset of field: ",
@@ -9343,7 +8897,7 @@ fn bindgen_test_layout__cef_request_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_request_t>())).set_method as *const _ as usize },
+        ::std::mem::offset_of!(_cef_request_t, set_method),
         72usize,
         concat!(
             "Offset of field: ",
@@ -9353,7 +8907,7 @@ fn bindgen_test_layout__cef_request_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_request_t>())).set_referrer as *const _ as usize },
+        ::std::mem::offset_of!(_cef_request_t, set_referrer),
         80usize,
         concat!(
             "Offset of field: ",
@@ -9363,7 +8917,7 @@ fn bindgen_test_layout__cef_request_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_request_t>())).get_referrer_url as *const _ as usize },
+        ::std::mem::offset_of!(_cef_request_t, get_referrer_url),
         88usize,
         concat!(
             "Offset of field: ",
@@ -9373,9 +8927,7 @@ fn bindgen_test_layout__cef_request_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_request_t>())).get_referrer_policy as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_request_t, get_referrer_policy),
         96usize,
         concat!(
             "Offset of field: ",
@@ -9385,7 +8937,7 @@ fn bindgen_test_layout__cef_request_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_request_t>())).get_post_data as *const _ as usize },
+        ::std::mem::offset_of!(_cef_request_t, get_post_data),
         104usize,
         concat!(
             "Offset of field: ",
@@ -9395,7 +8947,7 @@ fn bindgen_test_layout__cef_request_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_request_t>())).set_post_data as *const _ as usize },
+        ::std::mem::offset_of!(_cef_request_t, set_post_data),
         112usize,
         concat!(
             "Offset of field: ",
@@ -9405,7 +8957,7 @@ fn bindgen_test_layout__cef_request_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_request_t>())).get_header_map as *const _ as usize },
+        ::std::mem::offset_of!(_cef_request_t, get_header_map),
         120usize,
         concat!(
             "Offset of field: ",
@@ -9415,7 +8967,7 @@ fn bindgen_test_layout__cef_request_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_request_t>())).set_header_map as *const _ as usize },
+        ::std::mem::offset_of!(_cef_request_t, set_header_map),
         128usize,
         concat!(
             "Offset of field: ",
@@ -9425,9 +8977,7 @@ fn bindgen_test_layout__cef_request_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_request_t>())).get_header_by_name as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_request_t, get_header_by_name),
         136usize,
         concat!(
             "Offset of field: ",
@@ -9437,9 +8987,7 @@ fn bindgen_test_layout__cef_request_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_request_t>())).set_header_by_name as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_request_t, set_header_by_name),
         144usize,
         concat!(
             "Offset of field: ",
@@ -9449,7 +8997,7 @@ fn bindgen_test_layout__cef_request_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_request_t>())).set as *const _ as usize },
+        ::std::mem::offset_of!(_cef_request_t, set),
         152usize,
         concat!(
             "Offset of field: ",
@@ -9459,7 +9007,7 @@ fn bindgen_test_layout__cef_request_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_request_t>())).get_flags as *const _ as usize },
+        ::std::mem::offset_of!(_cef_request_t, get_flags),
         160usize,
         concat!(
             "Offset of field: ",
@@ -9469,7 +9017,7 @@ fn bindgen_test_layout__cef_request_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_request_t>())).set_flags as *const _ as usize },
+        ::std::mem::offset_of!(_cef_request_t, set_flags),
         168usize,
         concat!(
             "Offset of field: ",
@@ -9479,10 +9027,7 @@ fn bindgen_test_layout__cef_request_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_request_t>())).get_first_party_for_cookies as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_request_t, get_first_party_for_cookies),
         176usize,
         concat!(
             "Offset of field: ",
@@ -9492,10 +9037,7 @@ fn bindgen_test_layout__cef_request_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_request_t>())).set_first_party_for_cookies as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_request_t, set_first_party_for_cookies),
         184usize,
         concat!(
             "Offset of field: ",
@@ -9505,9 +9047,7 @@ fn bindgen_test_layout__cef_request_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_request_t>())).get_resource_type as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_request_t, get_resource_type),
         192usize,
         concat!(
             "Offset of field: ",
@@ -9517,9 +9057,7 @@ fn bindgen_test_layout__cef_request_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_request_t>())).get_transition_type as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_request_t, get_transition_type),
         200usize,
         concat!(
             "Offset of field: ",
@@ -9529,7 +9067,7 @@ fn bindgen_test_layout__cef_request_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_request_t>())).get_identifier as *const _ as usize },
+        ::std::mem::offset_of!(_cef_request_t, get_identifier),
         208usize,
         concat!(
             "Offset of field: ",
@@ -9589,7 +9127,7 @@ fn bindgen_test_layout__cef_post_data_t() {
         concat!("Alignment of ", stringify!(_cef_post_data_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_post_data_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_post_data_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -9599,7 +9137,7 @@ fn bindgen_test_layout__cef_post_data_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_post_data_t>())).is_read_only as *const _ as usize },
+        ::std::mem::offset_of!(_cef_post_data_t, is_read_only),
         40usize,
         concat!(
             "Offset of field: ",
@@ -9609,9 +9147,7 @@ fn bindgen_test_layout__cef_post_data_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_post_data_t>())).has_excluded_elements as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_post_data_t, has_excluded_elements),
         48usize,
         concat!(
             "Offset of field: ",
@@ -9621,9 +9157,7 @@ fn bindgen_test_layout__cef_post_data_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_post_data_t>())).get_element_count as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_post_data_t, get_element_count),
         56usize,
         concat!(
             "Offset of field: ",
@@ -9633,7 +9167,7 @@ fn bindgen_test_layout__cef_post_data_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_post_data_t>())).get_elements as *const _ as usize },
+        ::std::mem::offset_of!(_cef_post_data_t, get_elements),
         64usize,
         concat!(
             "Offset of field: ",
@@ -9643,7 +9177,7 @@ fn bindgen_test_layout__cef_post_data_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_post_data_t>())).remove_element as *const _ as usize },
+        ::std::mem::offset_of!(_cef_post_data_t, remove_element),
         72usize,
         concat!(
             "Offset of field: ",
@@ -9653,7 +9187,7 @@ fn bindgen_test_layout__cef_post_data_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_post_data_t>())).add_element as *const _ as usize },
+        ::std::mem::offset_of!(_cef_post_data_t, add_element),
         80usize,
         concat!(
             "Offset of field: ",
@@ -9663,9 +9197,7 @@ fn bindgen_test_layout__cef_post_data_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_post_data_t>())).remove_elements as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_post_data_t, remove_elements),
         88usize,
         concat!(
             "Offset of field: ",
@@ -9729,7 +9261,7 @@ fn bindgen_test_layout__cef_post_data_element_t() {
         concat!("Alignment of ", stringify!(_cef_post_data_element_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_post_data_element_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_post_data_element_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -9739,9 +9271,7 @@ fn bindgen_test_layout__cef_post_data_element_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_post_data_element_t>())).is_read_only as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_post_data_element_t, is_read_only),
         40usize,
         concat!(
             "Offset of field: ",
@@ -9751,9 +9281,7 @@ fn bindgen_test_layout__cef_post_data_element_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_post_data_element_t>())).set_to_empty as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_post_data_element_t, set_to_empty),
         48usize,
         concat!(
             "Offset of field: ",
@@ -9763,9 +9291,7 @@ fn bindgen_test_layout__cef_post_data_element_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_post_data_element_t>())).set_to_file as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_post_data_element_t, set_to_file),
         56usize,
         concat!(
             "Offset of field: ",
@@ -9775,9 +9301,7 @@ fn bindgen_test_layout__cef_post_data_element_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_post_data_element_t>())).set_to_bytes as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_post_data_element_t, set_to_bytes),
         64usize,
         concat!(
             "Offset of field: ",
@@ -9787,9 +9311,7 @@ fn bindgen_test_layout__cef_post_data_element_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_post_data_element_t>())).get_type as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_post_data_element_t, get_type),
         72usize,
         concat!(
             "Offset of field: ",
@@ -9799,9 +9321,7 @@ fn bindgen_test_layout__cef_post_data_element_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_post_data_element_t>())).get_file as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_post_data_element_t, get_file),
         80usize,
         concat!(
             "Offset of field: ",
@@ -9811,10 +9331,7 @@ fn bindgen_test_layout__cef_post_data_element_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_post_data_element_t>())).get_bytes_count as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_post_data_element_t, get_bytes_count),
         88usize,
         concat!(
             "Offset of field: ",
@@ -9824,9 +9341,7 @@ fn bindgen_test_layout__cef_post_data_element_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_post_data_element_t>())).get_bytes as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_post_data_element_t, get_bytes),
         96usize,
         concat!(
             "Offset of field: ",
@@ -9861,7 +9376,7 @@ fn bindgen_test_layout__cef_string_visitor_t() {
         concat!("Alignment of ", stringify!(_cef_string_visitor_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_string_visitor_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_string_visitor_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -9871,7 +9386,7 @@ fn bindgen_test_layout__cef_string_visitor_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_string_visitor_t>())).visit as *const _ as usize },
+        ::std::mem::offset_of!(_cef_string_visitor_t, visit),
         40usize,
         concat!(
             "Offset of field: ",
@@ -9970,7 +9485,7 @@ fn bindgen_test_layout__cef_frame_t() {
         concat!("Alignment of ", stringify!(_cef_frame_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_frame_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_frame_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -9980,7 +9495,7 @@ fn bindgen_test_layout__cef_frame_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_frame_t>())).is_valid as *const _ as usize },
+        ::std::mem::offset_of!(_cef_frame_t, is_valid),
         40usize,
         concat!(
             "Offset of field: ",
@@ -9990,7 +9505,7 @@ fn bindgen_test_layout__cef_frame_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_frame_t>())).undo as *const _ as usize },
+        ::std::mem::offset_of!(_cef_frame_t, undo),
         48usize,
         concat!(
             "Offset of field: ",
@@ -10000,7 +9515,7 @@ fn bindgen_test_layout__cef_frame_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_frame_t>())).redo as *const _ as usize },
+        ::std::mem::offset_of!(_cef_frame_t, redo),
         56usize,
         concat!(
             "Offset of field: ",
@@ -10010,7 +9525,7 @@ fn bindgen_test_layout__cef_frame_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_frame_t>())).cut as *const _ as usize },
+        ::std::mem::offset_of!(_cef_frame_t, cut),
         64usize,
         concat!(
             "Offset of field: ",
@@ -10020,7 +9535,7 @@ fn bindgen_test_layout__cef_frame_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_frame_t>())).copy as *const _ as usize },
+        ::std::mem::offset_of!(_cef_frame_t, copy),
         72usize,
         concat!(
             "Offset of field: ",
@@ -10030,7 +9545,7 @@ fn bindgen_test_layout__cef_frame_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_frame_t>())).paste as *const _ as usize },
+        ::std::mem::offset_of!(_cef_frame_t, paste),
         80usize,
         concat!(
             "Offset of field: ",
@@ -10040,7 +9555,7 @@ fn bindgen_test_layout__cef_frame_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_frame_t>())).del as *const _ as usize },
+        ::std::mem::offset_of!(_cef_frame_t, del),
         88usize,
         concat!(
             "Offset of field: ",
@@ -10050,7 +9565,7 @@ fn bindgen_test_layout__cef_frame_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_frame_t>())).select_all as *const _ as usize },
+        ::std::mem::offset_of!(_cef_frame_t, select_all),
         96usize,
         concat!(
             "Offset of field: ",
@@ -10060,7 +9575,7 @@ fn bindgen_test_layout__cef_frame_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_frame_t>())).view_source as *const _ as usize },
+        ::std::mem::offset_of!(_cef_frame_t, view_source),
         104usize,
         concat!(
             "Offset of field: ",
@@ -10070,7 +9585,7 @@ fn bindgen_test_layout__cef_frame_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_frame_t>())).get_source as *const _ as usize },
+        ::std::mem::offset_of!(_cef_frame_t, get_source),
         112usize,
         concat!(
             "Offset of field: ",
@@ -10080,7 +9595,7 @@ fn bindgen_test_layout__cef_frame_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_frame_t>())).get_text as *const _ as usize },
+        ::std::mem::offset_of!(_cef_frame_t, get_text),
         120usize,
         concat!(
             "Offset of field: ",
@@ -10090,7 +9605,7 @@ fn bindgen_test_layout__cef_frame_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_frame_t>())).load_request as *const _ as usize },
+        ::std::mem::offset_of!(_cef_frame_t, load_request),
         128usize,
         concat!(
             "Offset of field: ",
@@ -10100,7 +9615,7 @@ fn bindgen_test_layout__cef_frame_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_frame_t>())).load_url as *const _ as usize },
+        ::std::mem::offset_of!(_cef_frame_t, load_url),
         136usize,
         concat!(
             "Offset of field: ",
@@ -10110,9 +9625,7 @@ fn bindgen_test_layout__cef_frame_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_frame_t>())).execute_java_script as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_frame_t, execute_java_script),
         144usize,
         concat!(
             "Offset of field: ",
@@ -10122,7 +9635,7 @@ fn bindgen_test_layout__cef_frame_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_frame_t>())).is_main as *const _ as usize },
+        ::std::mem::offset_of!(_cef_frame_t, is_main),
         152usize,
         concat!(
             "Offset of field: ",
@@ -10132,7 +9645,7 @@ fn bindgen_test_layout__cef_frame_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_frame_t>())).is_focused as *const _ as usize },
+        ::std::mem::offset_of!(_cef_frame_t, is_focused),
         160usize,
         concat!(
             "Offset of field: ",
@@ -10142,7 +9655,7 @@ fn bindgen_test_layout__cef_frame_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_frame_t>())).get_name as *const _ as usize },
+        ::std::mem::offset_of!(_cef_frame_t, get_name),
         168usize,
         concat!(
             "Offset of field: ",
@@ -10152,7 +9665,7 @@ fn bindgen_test_layout__cef_frame_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_frame_t>())).get_identifier as *const _ as usize },
+        ::std::mem::offset_of!(_cef_frame_t, get_identifier),
         176usize,
         concat!(
             "Offset of field: ",
@@ -10162,7 +9675,7 @@ fn bindgen_test_layout__cef_frame_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_frame_t>())).get_parent as *const _ as usize },
+        ::std::mem::offset_of!(_cef_frame_t, get_parent),
         184usize,
         concat!(
             "Offset of field: ",
@@ -10172,7 +9685,7 @@ fn bindgen_test_layout__cef_frame_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_frame_t>())).get_url as *const _ as usize },
+        ::std::mem::offset_of!(_cef_frame_t, get_url),
         192usize,
         concat!(
             "Offset of field: ",
@@ -10182,7 +9695,7 @@ fn bindgen_test_layout__cef_frame_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_frame_t>())).get_browser as *const _ as usize },
+        ::std::mem::offset_of!(_cef_frame_t, get_browser),
         200usize,
         concat!(
             "Offset of field: ",
@@ -10192,7 +9705,7 @@ fn bindgen_test_layout__cef_frame_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_frame_t>())).get_v8context as *const _ as usize },
+        ::std::mem::offset_of!(_cef_frame_t, get_v8context),
         208usize,
         concat!(
             "Offset of field: ",
@@ -10202,7 +9715,7 @@ fn bindgen_test_layout__cef_frame_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_frame_t>())).visit_dom as *const _ as usize },
+        ::std::mem::offset_of!(_cef_frame_t, visit_dom),
         216usize,
         concat!(
             "Offset of field: ",
@@ -10212,7 +9725,7 @@ fn bindgen_test_layout__cef_frame_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_frame_t>())).create_urlrequest as *const _ as usize },
+        ::std::mem::offset_of!(_cef_frame_t, create_urlrequest),
         224usize,
         concat!(
             "Offset of field: ",
@@ -10222,9 +9735,7 @@ fn bindgen_test_layout__cef_frame_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_frame_t>())).send_process_message as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_frame_t, send_process_message),
         232usize,
         concat!(
             "Offset of field: ",
@@ -10280,7 +9791,7 @@ fn bindgen_test_layout__cef_x509cert_principal_t() {
         concat!("Alignment of ", stringify!(_cef_x509cert_principal_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_x509cert_principal_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_x509cert_principal_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -10290,10 +9801,7 @@ fn bindgen_test_layout__cef_x509cert_principal_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_x509cert_principal_t>())).get_display_name as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_x509cert_principal_t, get_display_name),
         40usize,
         concat!(
             "Offset of field: ",
@@ -10303,10 +9811,7 @@ fn bindgen_test_layout__cef_x509cert_principal_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_x509cert_principal_t>())).get_common_name as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_x509cert_principal_t, get_common_name),
         48usize,
         concat!(
             "Offset of field: ",
@@ -10316,10 +9821,7 @@ fn bindgen_test_layout__cef_x509cert_principal_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_x509cert_principal_t>())).get_locality_name as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_x509cert_principal_t, get_locality_name),
         56usize,
         concat!(
             "Offset of field: ",
@@ -10329,10 +9831,7 @@ fn bindgen_test_layout__cef_x509cert_principal_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_x509cert_principal_t>())).get_state_or_province_name
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_x509cert_principal_t, get_state_or_province_name),
         64usize,
         concat!(
             "Offset of field: ",
@@ -10342,10 +9841,7 @@ fn bindgen_test_layout__cef_x509cert_principal_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_x509cert_principal_t>())).get_country_name as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_x509cert_principal_t, get_country_name),
         72usize,
         concat!(
             "Offset of field: ",
@@ -10355,10 +9851,7 @@ fn bindgen_test_layout__cef_x509cert_principal_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_x509cert_principal_t>())).get_street_addresses as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_x509cert_principal_t, get_street_addresses),
         80usize,
         concat!(
             "Offset of field: ",
@@ -10368,10 +9861,7 @@ fn bindgen_test_layout__cef_x509cert_principal_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_x509cert_principal_t>())).get_organization_names as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_x509cert_principal_t, get_organization_names),
         88usize,
         concat!(
             "Offset of field: ",
@@ -10381,10 +9871,7 @@ fn bindgen_test_layout__cef_x509cert_principal_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_x509cert_principal_t>())).get_organization_unit_names
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_x509cert_principal_t, get_organization_unit_names),
         96usize,
         concat!(
             "Offset of field: ",
@@ -10394,10 +9881,7 @@ fn bindgen_test_layout__cef_x509cert_principal_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_x509cert_principal_t>())).get_domain_components as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_x509cert_principal_t, get_domain_components),
         104usize,
         concat!(
             "Offset of field: ",
@@ -10463,7 +9947,7 @@ fn bindgen_test_layout__cef_x509certificate_t() {
         concat!("Alignment of ", stringify!(_cef_x509certificate_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_x509certificate_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_x509certificate_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -10473,9 +9957,7 @@ fn bindgen_test_layout__cef_x509certificate_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_x509certificate_t>())).get_subject as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_x509certificate_t, get_subject),
         40usize,
         concat!(
             "Offset of field: ",
@@ -10485,9 +9967,7 @@ fn bindgen_test_layout__cef_x509certificate_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_x509certificate_t>())).get_issuer as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_x509certificate_t, get_issuer),
         48usize,
         concat!(
             "Offset of field: ",
@@ -10497,10 +9977,7 @@ fn bindgen_test_layout__cef_x509certificate_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_x509certificate_t>())).get_serial_number as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_x509certificate_t, get_serial_number),
         56usize,
         concat!(
             "Offset of field: ",
@@ -10510,9 +9987,7 @@ fn bindgen_test_layout__cef_x509certificate_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_x509certificate_t>())).get_valid_start as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_x509certificate_t, get_valid_start),
         64usize,
         concat!(
             "Offset of field: ",
@@ -10522,9 +9997,7 @@ fn bindgen_test_layout__cef_x509certificate_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_x509certificate_t>())).get_valid_expiry as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_x509certificate_t, get_valid_expiry),
         72usize,
         concat!(
             "Offset of field: ",
@@ -10534,9 +10007,7 @@ fn bindgen_test_layout__cef_x509certificate_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_x509certificate_t>())).get_derencoded as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_x509certificate_t, get_derencoded),
         80usize,
         concat!(
             "Offset of field: ",
@@ -10546,9 +10017,7 @@ fn bindgen_test_layout__cef_x509certificate_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_x509certificate_t>())).get_pemencoded as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_x509certificate_t, get_pemencoded),
         88usize,
         concat!(
             "Offset of field: ",
@@ -10558,10 +10027,7 @@ fn bindgen_test_layout__cef_x509certificate_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_x509certificate_t>())).get_issuer_chain_size as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_x509certificate_t, get_issuer_chain_size),
         96usize,
         concat!(
             "Offset of field: ",
@@ -10571,10 +10037,7 @@ fn bindgen_test_layout__cef_x509certificate_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_x509certificate_t>())).get_derencoded_issuer_chain
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_x509certificate_t, get_derencoded_issuer_chain),
         104usize,
         concat!(
             "Offset of field: ",
@@ -10584,10 +10047,7 @@ fn bindgen_test_layout__cef_x509certificate_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_x509certificate_t>())).get_pemencoded_issuer_chain
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_x509certificate_t, get_pemencoded_issuer_chain),
         112usize,
         concat!(
             "Offset of field: ",
@@ -10631,7 +10091,7 @@ fn bindgen_test_layout__cef_sslstatus_t() {
         concat!("Alignment of ", stringify!(_cef_sslstatus_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_sslstatus_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_sslstatus_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -10641,9 +10101,7 @@ fn bindgen_test_layout__cef_sslstatus_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_sslstatus_t>())).is_secure_connection as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_sslstatus_t, is_secure_connection),
         40usize,
         concat!(
             "Offset of field: ",
@@ -10653,9 +10111,7 @@ fn bindgen_test_layout__cef_sslstatus_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_sslstatus_t>())).get_cert_status as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_sslstatus_t, get_cert_status),
         48usize,
         concat!(
             "Offset of field: ",
@@ -10665,7 +10121,7 @@ fn bindgen_test_layout__cef_sslstatus_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_sslstatus_t>())).get_sslversion as *const _ as usize },
+        ::std::mem::offset_of!(_cef_sslstatus_t, get_sslversion),
         56usize,
         concat!(
             "Offset of field: ",
@@ -10675,9 +10131,7 @@ fn bindgen_test_layout__cef_sslstatus_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_sslstatus_t>())).get_content_status as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_sslstatus_t, get_content_status),
         64usize,
         concat!(
             "Offset of field: ",
@@ -10687,9 +10141,7 @@ fn bindgen_test_layout__cef_sslstatus_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_sslstatus_t>())).get_x509certificate as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_sslstatus_t, get_x509certificate),
         72usize,
         concat!(
             "Offset of field: ",
@@ -10748,7 +10200,7 @@ fn bindgen_test_layout__cef_navigation_entry_t() {
         concat!("Alignment of ", stringify!(_cef_navigation_entry_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_navigation_entry_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_navigation_entry_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -10758,9 +10210,7 @@ fn bindgen_test_layout__cef_navigation_entry_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_navigation_entry_t>())).is_valid as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_navigation_entry_t, is_valid),
         40usize,
         concat!(
             "Offset of field: ",
@@ -10770,7 +10220,7 @@ fn bindgen_test_layout__cef_navigation_entry_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_navigation_entry_t>())).get_url as *const _ as usize },
+        ::std::mem::offset_of!(_cef_navigation_entry_t, get_url),
         48usize,
         concat!(
             "Offset of field: ",
@@ -10780,9 +10230,7 @@ fn bindgen_test_layout__cef_navigation_entry_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_navigation_entry_t>())).get_display_url as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_navigation_entry_t, get_display_url),
         56usize,
         concat!(
             "Offset of field: ",
@@ -10792,10 +10240,7 @@ fn bindgen_test_layout__cef_navigation_entry_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_navigation_entry_t>())).get_original_url as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_navigation_entry_t, get_original_url),
         64usize,
         concat!(
             "Offset of field: ",
@@ -10805,9 +10250,7 @@ fn bindgen_test_layout__cef_navigation_entry_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_navigation_entry_t>())).get_title as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_navigation_entry_t, get_title),
         72usize,
         concat!(
             "Offset of field: ",
@@ -10817,10 +10260,7 @@ fn bindgen_test_layout__cef_navigation_entry_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_navigation_entry_t>())).get_transition_type as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_navigation_entry_t, get_transition_type),
         80usize,
         concat!(
             "Offset of field: ",
@@ -10830,9 +10270,7 @@ fn bindgen_test_layout__cef_navigation_entry_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_navigation_entry_t>())).has_post_data as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_navigation_entry_t, has_post_data),
         88usize,
         concat!(
             "Offset of field: ",
@@ -10842,10 +10280,7 @@ fn bindgen_test_layout__cef_navigation_entry_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_navigation_entry_t>())).get_completion_time as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_navigation_entry_t, get_completion_time),
         96usize,
         concat!(
             "Offset of field: ",
@@ -10855,10 +10290,7 @@ fn bindgen_test_layout__cef_navigation_entry_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_navigation_entry_t>())).get_http_status_code as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_navigation_entry_t, get_http_status_code),
         104usize,
         concat!(
             "Offset of field: ",
@@ -10868,9 +10300,7 @@ fn bindgen_test_layout__cef_navigation_entry_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_navigation_entry_t>())).get_sslstatus as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_navigation_entry_t, get_sslstatus),
         112usize,
         concat!(
             "Offset of field: ",
@@ -10899,7 +10329,7 @@ fn bindgen_test_layout__cef_registration_t() {
         concat!("Alignment of ", stringify!(_cef_registration_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_registration_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_registration_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -10930,7 +10360,7 @@ fn bindgen_test_layout__cef_callback_t() {
         concat!("Alignment of ", stringify!(_cef_callback_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_callback_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_callback_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -10940,7 +10370,7 @@ fn bindgen_test_layout__cef_callback_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_callback_t>())).cont as *const _ as usize },
+        ::std::mem::offset_of!(_cef_callback_t, cont),
         40usize,
         concat!(
             "Offset of field: ",
@@ -10950,7 +10380,7 @@ fn bindgen_test_layout__cef_callback_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_callback_t>())).cancel as *const _ as usize },
+        ::std::mem::offset_of!(_cef_callback_t, cancel),
         48usize,
         concat!(
             "Offset of field: ",
@@ -10981,7 +10411,7 @@ fn bindgen_test_layout__cef_completion_callback_t() {
         concat!("Alignment of ", stringify!(_cef_completion_callback_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_completion_callback_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_completion_callback_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -10991,9 +10421,7 @@ fn bindgen_test_layout__cef_completion_callback_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_completion_callback_t>())).on_complete as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_completion_callback_t, on_complete),
         40usize,
         concat!(
             "Offset of field: ",
@@ -11066,7 +10494,7 @@ fn bindgen_test_layout__cef_cookie_manager_t() {
         concat!("Alignment of ", stringify!(_cef_cookie_manager_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_cookie_manager_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_cookie_manager_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -11076,10 +10504,7 @@ fn bindgen_test_layout__cef_cookie_manager_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_cookie_manager_t>())).set_supported_schemes as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_cookie_manager_t, set_supported_schemes),
         40usize,
         concat!(
             "Offset of field: ",
@@ -11089,9 +10514,7 @@ fn bindgen_test_layout__cef_cookie_manager_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_cookie_manager_t>())).visit_all_cookies as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_cookie_manager_t, visit_all_cookies),
         48usize,
         concat!(
             "Offset of field: ",
@@ -11101,9 +10524,7 @@ fn bindgen_test_layout__cef_cookie_manager_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_cookie_manager_t>())).visit_url_cookies as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_cookie_manager_t, visit_url_cookies),
         56usize,
         concat!(
             "Offset of field: ",
@@ -11113,9 +10534,7 @@ fn bindgen_test_layout__cef_cookie_manager_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_cookie_manager_t>())).set_cookie as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_cookie_manager_t, set_cookie),
         64usize,
         concat!(
             "Offset of field: ",
@@ -11125,9 +10544,7 @@ fn bindgen_test_layout__cef_cookie_manager_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_cookie_manager_t>())).delete_cookies as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_cookie_manager_t, delete_cookies),
         72usize,
         concat!(
             "Offset of field: ",
@@ -11137,9 +10554,7 @@ fn bindgen_test_layout__cef_cookie_manager_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_cookie_manager_t>())).flush_store as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_cookie_manager_t, flush_store),
         80usize,
         concat!(
             "Offset of field: ",
@@ -11182,7 +10597,7 @@ fn bindgen_test_layout__cef_cookie_visitor_t() {
         concat!("Alignment of ", stringify!(_cef_cookie_visitor_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_cookie_visitor_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_cookie_visitor_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -11192,7 +10607,7 @@ fn bindgen_test_layout__cef_cookie_visitor_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_cookie_visitor_t>())).visit as *const _ as usize },
+        ::std::mem::offset_of!(_cef_cookie_visitor_t, visit),
         40usize,
         concat!(
             "Offset of field: ",
@@ -11227,7 +10642,7 @@ fn bindgen_test_layout__cef_set_cookie_callback_t() {
         concat!("Alignment of ", stringify!(_cef_set_cookie_callback_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_set_cookie_callback_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_set_cookie_callback_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -11237,9 +10652,7 @@ fn bindgen_test_layout__cef_set_cookie_callback_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_set_cookie_callback_t>())).on_complete as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_set_cookie_callback_t, on_complete),
         40usize,
         concat!(
             "Offset of field: ",
@@ -11274,9 +10687,7 @@ fn bindgen_test_layout__cef_delete_cookies_callback_t() {
         concat!("Alignment of ", stringify!(_cef_delete_cookies_callback_t))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_delete_cookies_callback_t>())).base as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_delete_cookies_callback_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -11286,10 +10697,7 @@ fn bindgen_test_layout__cef_delete_cookies_callback_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_delete_cookies_callback_t>())).on_complete as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_delete_cookies_callback_t, on_complete),
         40usize,
         concat!(
             "Offset of field: ",
@@ -11343,7 +10751,7 @@ fn bindgen_test_layout__cef_extension_t() {
         concat!("Alignment of ", stringify!(_cef_extension_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_extension_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_extension_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -11353,7 +10761,7 @@ fn bindgen_test_layout__cef_extension_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_extension_t>())).get_identifier as *const _ as usize },
+        ::std::mem::offset_of!(_cef_extension_t, get_identifier),
         40usize,
         concat!(
             "Offset of field: ",
@@ -11363,7 +10771,7 @@ fn bindgen_test_layout__cef_extension_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_extension_t>())).get_path as *const _ as usize },
+        ::std::mem::offset_of!(_cef_extension_t, get_path),
         48usize,
         concat!(
             "Offset of field: ",
@@ -11373,7 +10781,7 @@ fn bindgen_test_layout__cef_extension_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_extension_t>())).get_manifest as *const _ as usize },
+        ::std::mem::offset_of!(_cef_extension_t, get_manifest),
         56usize,
         concat!(
             "Offset of field: ",
@@ -11383,7 +10791,7 @@ fn bindgen_test_layout__cef_extension_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_extension_t>())).is_same as *const _ as usize },
+        ::std::mem::offset_of!(_cef_extension_t, is_same),
         64usize,
         concat!(
             "Offset of field: ",
@@ -11393,7 +10801,7 @@ fn bindgen_test_layout__cef_extension_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_extension_t>())).get_handler as *const _ as usize },
+        ::std::mem::offset_of!(_cef_extension_t, get_handler),
         72usize,
         concat!(
             "Offset of field: ",
@@ -11403,9 +10811,7 @@ fn bindgen_test_layout__cef_extension_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_extension_t>())).get_loader_context as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_extension_t, get_loader_context),
         80usize,
         concat!(
             "Offset of field: ",
@@ -11415,7 +10821,7 @@ fn bindgen_test_layout__cef_extension_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_extension_t>())).is_loaded as *const _ as usize },
+        ::std::mem::offset_of!(_cef_extension_t, is_loaded),
         88usize,
         concat!(
             "Offset of field: ",
@@ -11425,7 +10831,7 @@ fn bindgen_test_layout__cef_extension_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_extension_t>())).unload as *const _ as usize },
+        ::std::mem::offset_of!(_cef_extension_t, unload),
         96usize,
         concat!(
             "Offset of field: ",
@@ -11469,10 +10875,7 @@ fn bindgen_test_layout__cef_get_extension_resource_callback_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_get_extension_resource_callback_t>())).base as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_get_extension_resource_callback_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -11482,10 +10885,7 @@ fn bindgen_test_layout__cef_get_extension_resource_callback_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_get_extension_resource_callback_t>())).cont as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_get_extension_resource_callback_t, cont),
         40usize,
         concat!(
             "Offset of field: ",
@@ -11495,10 +10895,7 @@ fn bindgen_test_layout__cef_get_extension_resource_callback_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_get_extension_resource_callback_t>())).cancel as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_get_extension_resource_callback_t, cancel),
         48usize,
         concat!(
             "Offset of field: ",
@@ -11591,7 +10988,7 @@ fn bindgen_test_layout__cef_extension_handler_t() {
         concat!("Alignment of ", stringify!(_cef_extension_handler_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_extension_handler_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_extension_handler_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -11601,10 +10998,7 @@ fn bindgen_test_layout__cef_extension_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_extension_handler_t>())).on_extension_load_failed
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_extension_handler_t, on_extension_load_failed),
         40usize,
         concat!(
             "Offset of field: ",
@@ -11614,10 +11008,7 @@ fn bindgen_test_layout__cef_extension_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_extension_handler_t>())).on_extension_loaded as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_extension_handler_t, on_extension_loaded),
         48usize,
         concat!(
             "Offset of field: ",
@@ -11627,10 +11018,7 @@ fn bindgen_test_layout__cef_extension_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_extension_handler_t>())).on_extension_unloaded as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_extension_handler_t, on_extension_unloaded),
         56usize,
         concat!(
             "Offset of field: ",
@@ -11640,10 +11028,7 @@ fn bindgen_test_layout__cef_extension_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_extension_handler_t>())).on_before_background_browser
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_extension_handler_t, on_before_background_browser),
         64usize,
         concat!(
             "Offset of field: ",
@@ -11653,10 +11038,7 @@ fn bindgen_test_layout__cef_extension_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_extension_handler_t>())).on_before_browser as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_extension_handler_t, on_before_browser),
         72usize,
         concat!(
             "Offset of field: ",
@@ -11666,10 +11048,7 @@ fn bindgen_test_layout__cef_extension_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_extension_handler_t>())).get_active_browser as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_extension_handler_t, get_active_browser),
         80usize,
         concat!(
             "Offset of field: ",
@@ -11679,10 +11058,7 @@ fn bindgen_test_layout__cef_extension_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_extension_handler_t>())).can_access_browser as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_extension_handler_t, can_access_browser),
         88usize,
         concat!(
             "Offset of field: ",
@@ -11692,10 +11068,7 @@ fn bindgen_test_layout__cef_extension_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_extension_handler_t>())).get_extension_resource as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_extension_handler_t, get_extension_resource),
         96usize,
         concat!(
             "Offset of field: ",
@@ -11748,7 +11121,7 @@ fn bindgen_test_layout__cef_media_router_t() {
         concat!("Alignment of ", stringify!(_cef_media_router_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_media_router_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_media_router_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -11758,9 +11131,7 @@ fn bindgen_test_layout__cef_media_router_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_media_router_t>())).add_observer as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_media_router_t, add_observer),
         40usize,
         concat!(
             "Offset of field: ",
@@ -11770,7 +11141,7 @@ fn bindgen_test_layout__cef_media_router_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_media_router_t>())).get_source as *const _ as usize },
+        ::std::mem::offset_of!(_cef_media_router_t, get_source),
         48usize,
         concat!(
             "Offset of field: ",
@@ -11780,10 +11151,7 @@ fn bindgen_test_layout__cef_media_router_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_media_router_t>())).notify_current_sinks as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_media_router_t, notify_current_sinks),
         56usize,
         concat!(
             "Offset of field: ",
@@ -11793,9 +11161,7 @@ fn bindgen_test_layout__cef_media_router_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_media_router_t>())).create_route as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_media_router_t, create_route),
         64usize,
         concat!(
             "Offset of field: ",
@@ -11805,10 +11171,7 @@ fn bindgen_test_layout__cef_media_router_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_media_router_t>())).notify_current_routes as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_media_router_t, notify_current_routes),
         72usize,
         concat!(
             "Offset of field: ",
@@ -11869,7 +11232,7 @@ fn bindgen_test_layout__cef_media_observer_t() {
         concat!("Alignment of ", stringify!(_cef_media_observer_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_media_observer_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_media_observer_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -11879,7 +11242,7 @@ fn bindgen_test_layout__cef_media_observer_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_media_observer_t>())).on_sinks as *const _ as usize },
+        ::std::mem::offset_of!(_cef_media_observer_t, on_sinks),
         40usize,
         concat!(
             "Offset of field: ",
@@ -11889,7 +11252,7 @@ fn bindgen_test_layout__cef_media_observer_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_media_observer_t>())).on_routes as *const _ as usize },
+        ::std::mem::offset_of!(_cef_media_observer_t, on_routes),
         48usize,
         concat!(
             "Offset of field: ",
@@ -11899,10 +11262,7 @@ fn bindgen_test_layout__cef_media_observer_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_media_observer_t>())).on_route_state_changed as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_media_observer_t, on_route_state_changed),
         56usize,
         concat!(
             "Offset of field: ",
@@ -11912,10 +11272,7 @@ fn bindgen_test_layout__cef_media_observer_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_media_observer_t>())).on_route_message_received as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_media_observer_t, on_route_message_received),
         64usize,
         concat!(
             "Offset of field: ",
@@ -11961,7 +11318,7 @@ fn bindgen_test_layout__cef_media_route_t() {
         concat!("Alignment of ", stringify!(_cef_media_route_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_media_route_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_media_route_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -11971,7 +11328,7 @@ fn bindgen_test_layout__cef_media_route_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_media_route_t>())).get_id as *const _ as usize },
+        ::std::mem::offset_of!(_cef_media_route_t, get_id),
         40usize,
         concat!(
             "Offset of field: ",
@@ -11981,7 +11338,7 @@ fn bindgen_test_layout__cef_media_route_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_media_route_t>())).get_source as *const _ as usize },
+        ::std::mem::offset_of!(_cef_media_route_t, get_source),
         48usize,
         concat!(
             "Offset of field: ",
@@ -11991,7 +11348,7 @@ fn bindgen_test_layout__cef_media_route_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_media_route_t>())).get_sink as *const _ as usize },
+        ::std::mem::offset_of!(_cef_media_route_t, get_sink),
         56usize,
         concat!(
             "Offset of field: ",
@@ -12001,9 +11358,7 @@ fn bindgen_test_layout__cef_media_route_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_media_route_t>())).send_route_message as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_media_route_t, send_route_message),
         64usize,
         concat!(
             "Offset of field: ",
@@ -12013,7 +11368,7 @@ fn bindgen_test_layout__cef_media_route_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_media_route_t>())).terminate as *const _ as usize },
+        ::std::mem::offset_of!(_cef_media_route_t, terminate),
         72usize,
         concat!(
             "Offset of field: ",
@@ -12053,9 +11408,7 @@ fn bindgen_test_layout__cef_media_route_create_callback_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_media_route_create_callback_t>())).base as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_media_route_create_callback_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -12065,10 +11418,7 @@ fn bindgen_test_layout__cef_media_route_create_callback_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_media_route_create_callback_t>()))
-                .on_media_route_create_finished as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_media_route_create_callback_t, on_media_route_create_finished),
         40usize,
         concat!(
             "Offset of field: ",
@@ -12124,7 +11474,7 @@ fn bindgen_test_layout__cef_media_sink_t() {
         concat!("Alignment of ", stringify!(_cef_media_sink_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_media_sink_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_media_sink_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -12134,7 +11484,7 @@ fn bindgen_test_layout__cef_media_sink_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_media_sink_t>())).get_id as *const _ as usize },
+        ::std::mem::offset_of!(_cef_media_sink_t, get_id),
         40usize,
         concat!(
             "Offset of field: ",
@@ -12144,7 +11494,7 @@ fn bindgen_test_layout__cef_media_sink_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_media_sink_t>())).is_valid as *const _ as usize },
+        ::std::mem::offset_of!(_cef_media_sink_t, is_valid),
         48usize,
         concat!(
             "Offset of field: ",
@@ -12154,7 +11504,7 @@ fn bindgen_test_layout__cef_media_sink_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_media_sink_t>())).get_name as *const _ as usize },
+        ::std::mem::offset_of!(_cef_media_sink_t, get_name),
         56usize,
         concat!(
             "Offset of field: ",
@@ -12164,9 +11514,7 @@ fn bindgen_test_layout__cef_media_sink_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_media_sink_t>())).get_description as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_media_sink_t, get_description),
         64usize,
         concat!(
             "Offset of field: ",
@@ -12176,7 +11524,7 @@ fn bindgen_test_layout__cef_media_sink_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_media_sink_t>())).get_icon_type as *const _ as usize },
+        ::std::mem::offset_of!(_cef_media_sink_t, get_icon_type),
         72usize,
         concat!(
             "Offset of field: ",
@@ -12186,7 +11534,7 @@ fn bindgen_test_layout__cef_media_sink_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_media_sink_t>())).is_cast_sink as *const _ as usize },
+        ::std::mem::offset_of!(_cef_media_sink_t, is_cast_sink),
         80usize,
         concat!(
             "Offset of field: ",
@@ -12196,7 +11544,7 @@ fn bindgen_test_layout__cef_media_sink_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_media_sink_t>())).is_dial_sink as *const _ as usize },
+        ::std::mem::offset_of!(_cef_media_sink_t, is_dial_sink),
         88usize,
         concat!(
             "Offset of field: ",
@@ -12206,9 +11554,7 @@ fn bindgen_test_layout__cef_media_sink_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_media_sink_t>())).is_compatible_with as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_media_sink_t, is_compatible_with),
         96usize,
         concat!(
             "Offset of field: ",
@@ -12249,7 +11595,7 @@ fn bindgen_test_layout__cef_media_source_t() {
         concat!("Alignment of ", stringify!(_cef_media_source_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_media_source_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_media_source_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -12259,7 +11605,7 @@ fn bindgen_test_layout__cef_media_source_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_media_source_t>())).get_id as *const _ as usize },
+        ::std::mem::offset_of!(_cef_media_source_t, get_id),
         40usize,
         concat!(
             "Offset of field: ",
@@ -12269,7 +11615,7 @@ fn bindgen_test_layout__cef_media_source_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_media_source_t>())).is_valid as *const _ as usize },
+        ::std::mem::offset_of!(_cef_media_source_t, is_valid),
         48usize,
         concat!(
             "Offset of field: ",
@@ -12279,9 +11625,7 @@ fn bindgen_test_layout__cef_media_source_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_media_source_t>())).is_cast_source as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_media_source_t, is_cast_source),
         56usize,
         concat!(
             "Offset of field: ",
@@ -12291,9 +11635,7 @@ fn bindgen_test_layout__cef_media_source_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_media_source_t>())).is_dial_source as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_media_source_t, is_dial_source),
         64usize,
         concat!(
             "Offset of field: ",
@@ -12329,7 +11671,7 @@ fn bindgen_test_layout__cef_resolve_callback_t() {
         concat!("Alignment of ", stringify!(_cef_resolve_callback_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_resolve_callback_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_resolve_callback_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -12339,10 +11681,7 @@ fn bindgen_test_layout__cef_resolve_callback_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_resolve_callback_t>())).on_resolve_completed as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_resolve_callback_t, on_resolve_completed),
         40usize,
         concat!(
             "Offset of field: ",
@@ -12509,7 +11848,7 @@ fn bindgen_test_layout__cef_request_context_t() {
         concat!("Alignment of ", stringify!(_cef_request_context_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_request_context_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_request_context_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -12519,7 +11858,7 @@ fn bindgen_test_layout__cef_request_context_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_request_context_t>())).is_same as *const _ as usize },
+        ::std::mem::offset_of!(_cef_request_context_t, is_same),
         40usize,
         concat!(
             "Offset of field: ",
@@ -12529,9 +11868,7 @@ fn bindgen_test_layout__cef_request_context_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_request_context_t>())).is_sharing_with as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_request_context_t, is_sharing_with),
         48usize,
         concat!(
             "Offset of field: ",
@@ -12541,9 +11878,7 @@ fn bindgen_test_layout__cef_request_context_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_request_context_t>())).is_global as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_request_context_t, is_global),
         56usize,
         concat!(
             "Offset of field: ",
@@ -12553,9 +11888,7 @@ fn bindgen_test_layout__cef_request_context_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_request_context_t>())).get_handler as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_request_context_t, get_handler),
         64usize,
         concat!(
             "Offset of field: ",
@@ -12565,9 +11898,7 @@ fn bindgen_test_layout__cef_request_context_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_request_context_t>())).get_cache_path as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_request_context_t, get_cache_path),
         72usize,
         concat!(
             "Offset of field: ",
@@ -12577,10 +11908,7 @@ fn bindgen_test_layout__cef_request_context_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_request_context_t>())).get_cookie_manager as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_request_context_t, get_cookie_manager),
         80usize,
         concat!(
             "Offset of field: ",
@@ -12590,10 +11918,7 @@ fn bindgen_test_layout__cef_request_context_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_request_context_t>())).register_scheme_handler_factory
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_request_context_t, register_scheme_handler_factory),
         88usize,
         concat!(
             "Offset of field: ",
@@ -12603,10 +11928,7 @@ fn bindgen_test_layout__cef_request_context_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_request_context_t>())).clear_scheme_handler_factories
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_request_context_t, clear_scheme_handler_factories),
         96usize,
         concat!(
             "Offset of field: ",
@@ -12616,10 +11938,7 @@ fn bindgen_test_layout__cef_request_context_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_request_context_t>())).purge_plugin_list_cache as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_request_context_t, purge_plugin_list_cache),
         104usize,
         concat!(
             "Offset of field: ",
@@ -12629,9 +11948,7 @@ fn bindgen_test_layout__cef_request_context_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_request_context_t>())).has_preference as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_request_context_t, has_preference),
         112usize,
         concat!(
             "Offset of field: ",
@@ -12641,9 +11958,7 @@ fn bindgen_test_layout__cef_request_context_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_request_context_t>())).get_preference as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_request_context_t, get_preference),
         120usize,
         concat!(
             "Offset of field: ",
@@ -12653,10 +11968,7 @@ fn bindgen_test_layout__cef_request_context_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_request_context_t>())).get_all_preferences as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_request_context_t, get_all_preferences),
         128usize,
         concat!(
             "Offset of field: ",
@@ -12666,10 +11978,7 @@ fn bindgen_test_layout__cef_request_context_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_request_context_t>())).can_set_preference as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_request_context_t, can_set_preference),
         136usize,
         concat!(
             "Offset of field: ",
@@ -12679,9 +11988,7 @@ fn bindgen_test_layout__cef_request_context_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_request_context_t>())).set_preference as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_request_context_t, set_preference),
         144usize,
         concat!(
             "Offset of field: ",
@@ -12691,10 +11998,7 @@ fn bindgen_test_layout__cef_request_context_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_request_context_t>())).clear_certificate_exceptions
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_request_context_t, clear_certificate_exceptions),
         152usize,
         concat!(
             "Offset of field: ",
@@ -12704,10 +12008,7 @@ fn bindgen_test_layout__cef_request_context_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_request_context_t>())).clear_http_auth_credentials
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_request_context_t, clear_http_auth_credentials),
         160usize,
         concat!(
             "Offset of field: ",
@@ -12717,10 +12018,7 @@ fn bindgen_test_layout__cef_request_context_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_request_context_t>())).close_all_connections as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_request_context_t, close_all_connections),
         168usize,
         concat!(
             "Offset of field: ",
@@ -12730,9 +12028,7 @@ fn bindgen_test_layout__cef_request_context_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_request_context_t>())).resolve_host as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_request_context_t, resolve_host),
         176usize,
         concat!(
             "Offset of field: ",
@@ -12742,9 +12038,7 @@ fn bindgen_test_layout__cef_request_context_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_request_context_t>())).load_extension as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_request_context_t, load_extension),
         184usize,
         concat!(
             "Offset of field: ",
@@ -12754,10 +12048,7 @@ fn bindgen_test_layout__cef_request_context_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_request_context_t>())).did_load_extension as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_request_context_t, did_load_extension),
         192usize,
         concat!(
             "Offset of field: ",
@@ -12767,9 +12058,7 @@ fn bindgen_test_layout__cef_request_context_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_request_context_t>())).has_extension as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_request_context_t, has_extension),
         200usize,
         concat!(
             "Offset of field: ",
@@ -12779,9 +12068,7 @@ fn bindgen_test_layout__cef_request_context_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_request_context_t>())).get_extensions as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_request_context_t, get_extensions),
         208usize,
         concat!(
             "Offset of field: ",
@@ -12791,9 +12078,7 @@ fn bindgen_test_layout__cef_request_context_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_request_context_t>())).get_extension as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_request_context_t, get_extension),
         216usize,
         concat!(
             "Offset of field: ",
@@ -12803,9 +12088,7 @@ fn bindgen_test_layout__cef_request_context_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_request_context_t>())).get_media_router as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_request_context_t, get_media_router),
         224usize,
         concat!(
             "Offset of field: ",
@@ -12909,7 +12192,7 @@ fn bindgen_test_layout__cef_browser_t() {
         concat!("Alignment of ", stringify!(_cef_browser_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_browser_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_browser_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -12919,7 +12202,7 @@ fn bindgen_test_layout__cef_browser_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_browser_t>())).get_host as *const _ as usize },
+        ::std::mem::offset_of!(_cef_browser_t, get_host),
         40usize,
         concat!(
             "Offset of field: ",
@@ -12929,7 +12212,7 @@ fn bindgen_test_layout__cef_browser_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_browser_t>())).can_go_back as *const _ as usize },
+        ::std::mem::offset_of!(_cef_browser_t, can_go_back),
         48usize,
         concat!(
             "Offset of field: ",
@@ -12939,7 +12222,7 @@ fn bindgen_test_layout__cef_browser_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_browser_t>())).go_back as *const _ as usize },
+        ::std::mem::offset_of!(_cef_browser_t, go_back),
         56usize,
         concat!(
             "Offset of field: ",
@@ -12949,7 +12232,7 @@ fn bindgen_test_layout__cef_browser_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_browser_t>())).can_go_forward as *const _ as usize },
+        ::std::mem::offset_of!(_cef_browser_t, can_go_forward),
         64usize,
         concat!(
             "Offset of field: ",
@@ -12959,7 +12242,7 @@ fn bindgen_test_layout__cef_browser_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_browser_t>())).go_forward as *const _ as usize },
+        ::std::mem::offset_of!(_cef_browser_t, go_forward),
         72usize,
         concat!(
             "Offset of field: ",
@@ -12969,7 +12252,7 @@ fn bindgen_test_layout__cef_browser_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_browser_t>())).is_loading as *const _ as usize },
+        ::std::mem::offset_of!(_cef_browser_t, is_loading),
         80usize,
         concat!(
             "Offset of field: ",
@@ -12979,7 +12262,7 @@ fn bindgen_test_layout__cef_browser_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_browser_t>())).reload as *const _ as usize },
+        ::std::mem::offset_of!(_cef_browser_t, reload),
         88usize,
         concat!(
             "Offset of field: ",
@@ -12989,9 +12272,7 @@ fn bindgen_test_layout__cef_browser_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_t>())).reload_ignore_cache as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_t, reload_ignore_cache),
         96usize,
         concat!(
             "Offset of field: ",
@@ -13001,7 +12282,7 @@ fn bindgen_test_layout__cef_browser_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_browser_t>())).stop_load as *const _ as usize },
+        ::std::mem::offset_of!(_cef_browser_t, stop_load),
         104usize,
         concat!(
             "Offset of field: ",
@@ -13011,7 +12292,7 @@ fn bindgen_test_layout__cef_browser_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_browser_t>())).get_identifier as *const _ as usize },
+        ::std::mem::offset_of!(_cef_browser_t, get_identifier),
         112usize,
         concat!(
             "Offset of field: ",
@@ -13021,7 +12302,7 @@ fn bindgen_test_layout__cef_browser_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_browser_t>())).is_same as *const _ as usize },
+        ::std::mem::offset_of!(_cef_browser_t, is_same),
         120usize,
         concat!(
             "Offset of field: ",
@@ -13031,7 +12312,7 @@ fn bindgen_test_layout__cef_browser_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_browser_t>())).is_popup as *const _ as usize },
+        ::std::mem::offset_of!(_cef_browser_t, is_popup),
         128usize,
         concat!(
             "Offset of field: ",
@@ -13041,7 +12322,7 @@ fn bindgen_test_layout__cef_browser_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_browser_t>())).has_document as *const _ as usize },
+        ::std::mem::offset_of!(_cef_browser_t, has_document),
         136usize,
         concat!(
             "Offset of field: ",
@@ -13051,7 +12332,7 @@ fn bindgen_test_layout__cef_browser_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_browser_t>())).get_main_frame as *const _ as usize },
+        ::std::mem::offset_of!(_cef_browser_t, get_main_frame),
         144usize,
         concat!(
             "Offset of field: ",
@@ -13061,9 +12342,7 @@ fn bindgen_test_layout__cef_browser_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_t>())).get_focused_frame as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_t, get_focused_frame),
         152usize,
         concat!(
             "Offset of field: ",
@@ -13073,9 +12352,7 @@ fn bindgen_test_layout__cef_browser_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_t>())).get_frame_byident as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_t, get_frame_byident),
         160usize,
         concat!(
             "Offset of field: ",
@@ -13085,7 +12362,7 @@ fn bindgen_test_layout__cef_browser_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_browser_t>())).get_frame as *const _ as usize },
+        ::std::mem::offset_of!(_cef_browser_t, get_frame),
         168usize,
         concat!(
             "Offset of field: ",
@@ -13095,7 +12372,7 @@ fn bindgen_test_layout__cef_browser_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_browser_t>())).get_frame_count as *const _ as usize },
+        ::std::mem::offset_of!(_cef_browser_t, get_frame_count),
         176usize,
         concat!(
             "Offset of field: ",
@@ -13105,9 +12382,7 @@ fn bindgen_test_layout__cef_browser_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_t>())).get_frame_identifiers as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_t, get_frame_identifiers),
         184usize,
         concat!(
             "Offset of field: ",
@@ -13117,7 +12392,7 @@ fn bindgen_test_layout__cef_browser_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_browser_t>())).get_frame_names as *const _ as usize },
+        ::std::mem::offset_of!(_cef_browser_t, get_frame_names),
         192usize,
         concat!(
             "Offset of field: ",
@@ -13153,9 +12428,7 @@ fn bindgen_test_layout__cef_run_file_dialog_callback_t() {
         concat!("Alignment of ", stringify!(_cef_run_file_dialog_callback_t))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_run_file_dialog_callback_t>())).base as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_run_file_dialog_callback_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -13165,10 +12438,7 @@ fn bindgen_test_layout__cef_run_file_dialog_callback_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_run_file_dialog_callback_t>())).on_file_dialog_dismissed
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_run_file_dialog_callback_t, on_file_dialog_dismissed),
         40usize,
         concat!(
             "Offset of field: ",
@@ -13206,9 +12476,7 @@ fn bindgen_test_layout__cef_navigation_entry_visitor_t() {
         concat!("Alignment of ", stringify!(_cef_navigation_entry_visitor_t))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_navigation_entry_visitor_t>())).base as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_navigation_entry_visitor_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -13218,9 +12486,7 @@ fn bindgen_test_layout__cef_navigation_entry_visitor_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_navigation_entry_visitor_t>())).visit as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_navigation_entry_visitor_t, visit),
         40usize,
         concat!(
             "Offset of field: ",
@@ -13256,7 +12522,7 @@ fn bindgen_test_layout__cef_pdf_print_callback_t() {
         concat!("Alignment of ", stringify!(_cef_pdf_print_callback_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_pdf_print_callback_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_pdf_print_callback_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -13266,10 +12532,7 @@ fn bindgen_test_layout__cef_pdf_print_callback_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_pdf_print_callback_t>())).on_pdf_print_finished as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_pdf_print_callback_t, on_pdf_print_finished),
         40usize,
         concat!(
             "Offset of field: ",
@@ -13306,9 +12569,7 @@ fn bindgen_test_layout__cef_download_image_callback_t() {
         concat!("Alignment of ", stringify!(_cef_download_image_callback_t))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_download_image_callback_t>())).base as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_download_image_callback_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -13318,10 +12579,7 @@ fn bindgen_test_layout__cef_download_image_callback_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_download_image_callback_t>())).on_download_image_finished
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_download_image_callback_t, on_download_image_finished),
         40usize,
         concat!(
             "Offset of field: ",
@@ -13630,7 +12888,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         concat!("Alignment of ", stringify!(_cef_browser_host_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_browser_host_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_browser_host_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -13640,7 +12898,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_browser_host_t>())).get_browser as *const _ as usize },
+        ::std::mem::offset_of!(_cef_browser_host_t, get_browser),
         40usize,
         concat!(
             "Offset of field: ",
@@ -13650,9 +12908,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_host_t>())).close_browser as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_host_t, close_browser),
         48usize,
         concat!(
             "Offset of field: ",
@@ -13662,9 +12918,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_host_t>())).try_close_browser as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_host_t, try_close_browser),
         56usize,
         concat!(
             "Offset of field: ",
@@ -13674,7 +12928,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_browser_host_t>())).set_focus as *const _ as usize },
+        ::std::mem::offset_of!(_cef_browser_host_t, set_focus),
         64usize,
         concat!(
             "Offset of field: ",
@@ -13684,9 +12938,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_host_t>())).get_window_handle as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_host_t, get_window_handle),
         72usize,
         concat!(
             "Offset of field: ",
@@ -13696,10 +12948,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_host_t>())).get_opener_window_handle as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_host_t, get_opener_window_handle),
         80usize,
         concat!(
             "Offset of field: ",
@@ -13709,7 +12958,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_browser_host_t>())).has_view as *const _ as usize },
+        ::std::mem::offset_of!(_cef_browser_host_t, has_view),
         88usize,
         concat!(
             "Offset of field: ",
@@ -13719,7 +12968,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_browser_host_t>())).get_client as *const _ as usize },
+        ::std::mem::offset_of!(_cef_browser_host_t, get_client),
         96usize,
         concat!(
             "Offset of field: ",
@@ -13729,9 +12978,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_host_t>())).get_request_context as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_host_t, get_request_context),
         104usize,
         concat!(
             "Offset of field: ",
@@ -13741,9 +12988,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_host_t>())).get_zoom_level as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_host_t, get_zoom_level),
         112usize,
         concat!(
             "Offset of field: ",
@@ -13753,9 +12998,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_host_t>())).set_zoom_level as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_host_t, set_zoom_level),
         120usize,
         concat!(
             "Offset of field: ",
@@ -13765,9 +13008,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_host_t>())).run_file_dialog as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_host_t, run_file_dialog),
         128usize,
         concat!(
             "Offset of field: ",
@@ -13777,9 +13018,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_host_t>())).start_download as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_host_t, start_download),
         136usize,
         concat!(
             "Offset of field: ",
@@ -13789,9 +13028,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_host_t>())).download_image as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_host_t, download_image),
         144usize,
         concat!(
             "Offset of field: ",
@@ -13801,7 +13038,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_browser_host_t>())).print as *const _ as usize },
+        ::std::mem::offset_of!(_cef_browser_host_t, print),
         152usize,
         concat!(
             "Offset of field: ",
@@ -13811,9 +13048,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_host_t>())).print_to_pdf as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_host_t, print_to_pdf),
         160usize,
         concat!(
             "Offset of field: ",
@@ -13823,7 +13058,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_browser_host_t>())).find as *const _ as usize },
+        ::std::mem::offset_of!(_cef_browser_host_t, find),
         168usize,
         concat!(
             "Offset of field: ",
@@ -13833,9 +13068,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_host_t>())).stop_finding as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_host_t, stop_finding),
         176usize,
         concat!(
             "Offset of field: ",
@@ -13845,9 +13078,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_host_t>())).show_dev_tools as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_host_t, show_dev_tools),
         184usize,
         concat!(
             "Offset of field: ",
@@ -13857,9 +13088,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_host_t>())).close_dev_tools as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_host_t, close_dev_tools),
         192usize,
         concat!(
             "Offset of field: ",
@@ -13869,9 +13098,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_host_t>())).has_dev_tools as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_host_t, has_dev_tools),
         200usize,
         concat!(
             "Offset of field: ",
@@ -13881,10 +13108,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_host_t>())).send_dev_tools_message as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_host_t, send_dev_tools_message),
         208usize,
         concat!(
             "Offset of field: ",
@@ -13894,10 +13118,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_host_t>())).execute_dev_tools_method as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_host_t, execute_dev_tools_method),
         216usize,
         concat!(
             "Offset of field: ",
@@ -13907,10 +13128,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_host_t>())).add_dev_tools_message_observer
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_host_t, add_dev_tools_message_observer),
         224usize,
         concat!(
             "Offset of field: ",
@@ -13920,10 +13138,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_host_t>())).get_navigation_entries as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_host_t, get_navigation_entries),
         232usize,
         concat!(
             "Offset of field: ",
@@ -13933,10 +13148,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_host_t>())).set_mouse_cursor_change_disabled
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_host_t, set_mouse_cursor_change_disabled),
         240usize,
         concat!(
             "Offset of field: ",
@@ -13946,10 +13158,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_host_t>())).is_mouse_cursor_change_disabled
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_host_t, is_mouse_cursor_change_disabled),
         248usize,
         concat!(
             "Offset of field: ",
@@ -13959,9 +13168,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_host_t>())).replace_misspelling as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_host_t, replace_misspelling),
         256usize,
         concat!(
             "Offset of field: ",
@@ -13971,10 +13178,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_host_t>())).add_word_to_dictionary as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_host_t, add_word_to_dictionary),
         264usize,
         concat!(
             "Offset of field: ",
@@ -13984,10 +13188,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_host_t>())).is_window_rendering_disabled as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_host_t, is_window_rendering_disabled),
         272usize,
         concat!(
             "Offset of field: ",
@@ -13997,7 +13198,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_browser_host_t>())).was_resized as *const _ as usize },
+        ::std::mem::offset_of!(_cef_browser_host_t, was_resized),
         280usize,
         concat!(
             "Offset of field: ",
@@ -14007,7 +13208,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_browser_host_t>())).was_hidden as *const _ as usize },
+        ::std::mem::offset_of!(_cef_browser_host_t, was_hidden),
         288usize,
         concat!(
             "Offset of field: ",
@@ -14017,10 +13218,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_host_t>())).notify_screen_info_changed as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_host_t, notify_screen_info_changed),
         296usize,
         concat!(
             "Offset of field: ",
@@ -14030,7 +13228,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_browser_host_t>())).invalidate as *const _ as usize },
+        ::std::mem::offset_of!(_cef_browser_host_t, invalidate),
         304usize,
         concat!(
             "Offset of field: ",
@@ -14040,10 +13238,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_host_t>())).send_external_begin_frame as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_host_t, send_external_begin_frame),
         312usize,
         concat!(
             "Offset of field: ",
@@ -14053,9 +13248,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_host_t>())).send_key_event as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_host_t, send_key_event),
         320usize,
         concat!(
             "Offset of field: ",
@@ -14065,10 +13258,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_host_t>())).send_mouse_click_event as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_host_t, send_mouse_click_event),
         328usize,
         concat!(
             "Offset of field: ",
@@ -14078,10 +13268,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_host_t>())).send_mouse_move_event as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_host_t, send_mouse_move_event),
         336usize,
         concat!(
             "Offset of field: ",
@@ -14091,10 +13278,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_host_t>())).send_mouse_wheel_event as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_host_t, send_mouse_wheel_event),
         344usize,
         concat!(
             "Offset of field: ",
@@ -14104,9 +13288,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_host_t>())).send_touch_event as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_host_t, send_touch_event),
         352usize,
         concat!(
             "Offset of field: ",
@@ -14116,9 +13298,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_host_t>())).send_focus_event as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_host_t, send_focus_event),
         360usize,
         concat!(
             "Offset of field: ",
@@ -14128,10 +13308,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_host_t>())).send_capture_lost_event as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_host_t, send_capture_lost_event),
         368usize,
         concat!(
             "Offset of field: ",
@@ -14141,10 +13318,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_host_t>())).notify_move_or_resize_started
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_host_t, notify_move_or_resize_started),
         376usize,
         concat!(
             "Offset of field: ",
@@ -14154,10 +13328,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_host_t>())).get_windowless_frame_rate as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_host_t, get_windowless_frame_rate),
         384usize,
         concat!(
             "Offset of field: ",
@@ -14167,10 +13338,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_host_t>())).set_windowless_frame_rate as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_host_t, set_windowless_frame_rate),
         392usize,
         concat!(
             "Offset of field: ",
@@ -14180,9 +13348,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_host_t>())).ime_set_composition as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_host_t, ime_set_composition),
         400usize,
         concat!(
             "Offset of field: ",
@@ -14192,9 +13358,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_host_t>())).ime_commit_text as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_host_t, ime_commit_text),
         408usize,
         concat!(
             "Offset of field: ",
@@ -14204,10 +13368,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_host_t>())).ime_finish_composing_text as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_host_t, ime_finish_composing_text),
         416usize,
         concat!(
             "Offset of field: ",
@@ -14217,10 +13378,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_host_t>())).ime_cancel_composition as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_host_t, ime_cancel_composition),
         424usize,
         concat!(
             "Offset of field: ",
@@ -14230,10 +13388,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_host_t>())).drag_target_drag_enter as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_host_t, drag_target_drag_enter),
         432usize,
         concat!(
             "Offset of field: ",
@@ -14243,10 +13398,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_host_t>())).drag_target_drag_over as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_host_t, drag_target_drag_over),
         440usize,
         concat!(
             "Offset of field: ",
@@ -14256,10 +13408,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_host_t>())).drag_target_drag_leave as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_host_t, drag_target_drag_leave),
         448usize,
         concat!(
             "Offset of field: ",
@@ -14269,9 +13418,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_host_t>())).drag_target_drop as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_host_t, drag_target_drop),
         456usize,
         concat!(
             "Offset of field: ",
@@ -14281,10 +13428,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_host_t>())).drag_source_ended_at as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_host_t, drag_source_ended_at),
         464usize,
         concat!(
             "Offset of field: ",
@@ -14294,10 +13438,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_host_t>())).drag_source_system_drag_ended
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_host_t, drag_source_system_drag_ended),
         472usize,
         concat!(
             "Offset of field: ",
@@ -14307,10 +13448,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_host_t>())).get_visible_navigation_entry as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_host_t, get_visible_navigation_entry),
         480usize,
         concat!(
             "Offset of field: ",
@@ -14320,10 +13458,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_host_t>())).set_accessibility_state as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_host_t, set_accessibility_state),
         488usize,
         concat!(
             "Offset of field: ",
@@ -14333,10 +13468,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_host_t>())).set_auto_resize_enabled as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_host_t, set_auto_resize_enabled),
         496usize,
         concat!(
             "Offset of field: ",
@@ -14346,9 +13478,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_host_t>())).get_extension as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_host_t, get_extension),
         504usize,
         concat!(
             "Offset of field: ",
@@ -14358,9 +13488,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_host_t>())).is_background_host as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_host_t, is_background_host),
         512usize,
         concat!(
             "Offset of field: ",
@@ -14370,9 +13498,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_host_t>())).set_audio_muted as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_host_t, set_audio_muted),
         520usize,
         concat!(
             "Offset of field: ",
@@ -14382,9 +13508,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_host_t>())).is_audio_muted as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_host_t, is_audio_muted),
         528usize,
         concat!(
             "Offset of field: ",
@@ -14514,7 +13638,7 @@ fn bindgen_test_layout__cef_print_settings_t() {
         concat!("Alignment of ", stringify!(_cef_print_settings_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_print_settings_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_print_settings_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -14524,7 +13648,7 @@ fn bindgen_test_layout__cef_print_settings_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_print_settings_t>())).is_valid as *const _ as usize },
+        ::std::mem::offset_of!(_cef_print_settings_t, is_valid),
         40usize,
         concat!(
             "Offset of field: ",
@@ -14534,9 +13658,7 @@ fn bindgen_test_layout__cef_print_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_print_settings_t>())).is_read_only as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_print_settings_t, is_read_only),
         48usize,
         concat!(
             "Offset of field: ",
@@ -14546,9 +13668,7 @@ fn bindgen_test_layout__cef_print_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_print_settings_t>())).set_orientation as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_print_settings_t, set_orientation),
         56usize,
         concat!(
             "Offset of field: ",
@@ -14558,9 +13678,7 @@ fn bindgen_test_layout__cef_print_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_print_settings_t>())).is_landscape as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_print_settings_t, is_landscape),
         64usize,
         concat!(
             "Offset of field: ",
@@ -14570,10 +13688,7 @@ fn bindgen_test_layout__cef_print_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_print_settings_t>())).set_printer_printable_area as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_print_settings_t, set_printer_printable_area),
         72usize,
         concat!(
             "Offset of field: ",
@@ -14583,9 +13698,7 @@ fn bindgen_test_layout__cef_print_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_print_settings_t>())).set_device_name as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_print_settings_t, set_device_name),
         80usize,
         concat!(
             "Offset of field: ",
@@ -14595,9 +13708,7 @@ fn bindgen_test_layout__cef_print_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_print_settings_t>())).get_device_name as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_print_settings_t, get_device_name),
         88usize,
         concat!(
             "Offset of field: ",
@@ -14607,7 +13718,7 @@ fn bindgen_test_layout__cef_print_settings_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_print_settings_t>())).set_dpi as *const _ as usize },
+        ::std::mem::offset_of!(_cef_print_settings_t, set_dpi),
         96usize,
         concat!(
             "Offset of field: ",
@@ -14617,7 +13728,7 @@ fn bindgen_test_layout__cef_print_settings_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_print_settings_t>())).get_dpi as *const _ as usize },
+        ::std::mem::offset_of!(_cef_print_settings_t, get_dpi),
         104usize,
         concat!(
             "Offset of field: ",
@@ -14627,9 +13738,7 @@ fn bindgen_test_layout__cef_print_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_print_settings_t>())).set_page_ranges as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_print_settings_t, set_page_ranges),
         112usize,
         concat!(
             "Offset of field: ",
@@ -14639,10 +13748,7 @@ fn bindgen_test_layout__cef_print_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_print_settings_t>())).get_page_ranges_count as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_print_settings_t, get_page_ranges_count),
         120usize,
         concat!(
             "Offset of field: ",
@@ -14652,9 +13758,7 @@ fn bindgen_test_layout__cef_print_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_print_settings_t>())).get_page_ranges as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_print_settings_t, get_page_ranges),
         128usize,
         concat!(
             "Offset of field: ",
@@ -14664,10 +13768,7 @@ fn bindgen_test_layout__cef_print_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_print_settings_t>())).set_selection_only as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_print_settings_t, set_selection_only),
         136usize,
         concat!(
             "Offset of field: ",
@@ -14677,9 +13778,7 @@ fn bindgen_test_layout__cef_print_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_print_settings_t>())).is_selection_only as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_print_settings_t, is_selection_only),
         144usize,
         concat!(
             "Offset of field: ",
@@ -14689,9 +13788,7 @@ fn bindgen_test_layout__cef_print_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_print_settings_t>())).set_collate as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_print_settings_t, set_collate),
         152usize,
         concat!(
             "Offset of field: ",
@@ -14701,9 +13798,7 @@ fn bindgen_test_layout__cef_print_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_print_settings_t>())).will_collate as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_print_settings_t, will_collate),
         160usize,
         concat!(
             "Offset of field: ",
@@ -14713,9 +13808,7 @@ fn bindgen_test_layout__cef_print_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_print_settings_t>())).set_color_model as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_print_settings_t, set_color_model),
         168usize,
         concat!(
             "Offset of field: ",
@@ -14725,9 +13818,7 @@ fn bindgen_test_layout__cef_print_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_print_settings_t>())).get_color_model as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_print_settings_t, get_color_model),
         176usize,
         concat!(
             "Offset of field: ",
@@ -14737,9 +13828,7 @@ fn bindgen_test_layout__cef_print_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_print_settings_t>())).set_copies as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_print_settings_t, set_copies),
         184usize,
         concat!(
             "Offset of field: ",
@@ -14749,9 +13838,7 @@ fn bindgen_test_layout__cef_print_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_print_settings_t>())).get_copies as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_print_settings_t, get_copies),
         192usize,
         concat!(
             "Offset of field: ",
@@ -14761,9 +13848,7 @@ fn bindgen_test_layout__cef_print_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_print_settings_t>())).set_duplex_mode as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_print_settings_t, set_duplex_mode),
         200usize,
         concat!(
             "Offset of field: ",
@@ -14773,9 +13858,7 @@ fn bindgen_test_layout__cef_print_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_print_settings_t>())).get_duplex_mode as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_print_settings_t, get_duplex_mode),
         208usize,
         concat!(
             "Offset of field: ",
@@ -14815,9 +13898,7 @@ fn bindgen_test_layout__cef_print_dialog_callback_t() {
         concat!("Alignment of ", stringify!(_cef_print_dialog_callback_t))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_print_dialog_callback_t>())).base as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_print_dialog_callback_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -14827,9 +13908,7 @@ fn bindgen_test_layout__cef_print_dialog_callback_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_print_dialog_callback_t>())).cont as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_print_dialog_callback_t, cont),
         40usize,
         concat!(
             "Offset of field: ",
@@ -14839,9 +13918,7 @@ fn bindgen_test_layout__cef_print_dialog_callback_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_print_dialog_callback_t>())).cancel as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_print_dialog_callback_t, cancel),
         48usize,
         concat!(
             "Offset of field: ",
@@ -14871,7 +13948,7 @@ fn bindgen_test_layout__cef_print_job_callback_t() {
         concat!("Alignment of ", stringify!(_cef_print_job_callback_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_print_job_callback_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_print_job_callback_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -14881,7 +13958,7 @@ fn bindgen_test_layout__cef_print_job_callback_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_print_job_callback_t>())).cont as *const _ as usize },
+        ::std::mem::offset_of!(_cef_print_job_callback_t, cont),
         40usize,
         concat!(
             "Offset of field: ",
@@ -14947,7 +14024,7 @@ fn bindgen_test_layout__cef_print_handler_t() {
         concat!("Alignment of ", stringify!(_cef_print_handler_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_print_handler_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_print_handler_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -14957,9 +14034,7 @@ fn bindgen_test_layout__cef_print_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_print_handler_t>())).on_print_start as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_print_handler_t, on_print_start),
         40usize,
         concat!(
             "Offset of field: ",
@@ -14969,9 +14044,7 @@ fn bindgen_test_layout__cef_print_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_print_handler_t>())).on_print_settings as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_print_handler_t, on_print_settings),
         48usize,
         concat!(
             "Offset of field: ",
@@ -14981,9 +14054,7 @@ fn bindgen_test_layout__cef_print_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_print_handler_t>())).on_print_dialog as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_print_handler_t, on_print_dialog),
         56usize,
         concat!(
             "Offset of field: ",
@@ -14993,9 +14064,7 @@ fn bindgen_test_layout__cef_print_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_print_handler_t>())).on_print_job as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_print_handler_t, on_print_job),
         64usize,
         concat!(
             "Offset of field: ",
@@ -15005,9 +14074,7 @@ fn bindgen_test_layout__cef_print_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_print_handler_t>())).on_print_reset as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_print_handler_t, on_print_reset),
         72usize,
         concat!(
             "Offset of field: ",
@@ -15017,9 +14084,7 @@ fn bindgen_test_layout__cef_print_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_print_handler_t>())).get_pdf_paper_size as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_print_handler_t, get_pdf_paper_size),
         80usize,
         concat!(
             "Offset of field: ",
@@ -15070,9 +14135,7 @@ fn bindgen_test_layout__cef_browser_process_handler_t() {
         concat!("Alignment of ", stringify!(_cef_browser_process_handler_t))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_process_handler_t>())).base as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_process_handler_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -15082,10 +14145,7 @@ fn bindgen_test_layout__cef_browser_process_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_process_handler_t>())).on_context_initialized
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_process_handler_t, on_context_initialized),
         40usize,
         concat!(
             "Offset of field: ",
@@ -15095,10 +14155,7 @@ fn bindgen_test_layout__cef_browser_process_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_process_handler_t>()))
-                .on_before_child_process_launch as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_process_handler_t, on_before_child_process_launch),
         48usize,
         concat!(
             "Offset of field: ",
@@ -15108,10 +14165,7 @@ fn bindgen_test_layout__cef_browser_process_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_process_handler_t>()))
-                .on_render_process_thread_created as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_process_handler_t, on_render_process_thread_created),
         56usize,
         concat!(
             "Offset of field: ",
@@ -15121,10 +14175,7 @@ fn bindgen_test_layout__cef_browser_process_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_process_handler_t>())).get_print_handler as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_process_handler_t, get_print_handler),
         64usize,
         concat!(
             "Offset of field: ",
@@ -15134,10 +14185,7 @@ fn bindgen_test_layout__cef_browser_process_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_process_handler_t>())).on_schedule_message_pump_work
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_process_handler_t, on_schedule_message_pump_work),
         72usize,
         concat!(
             "Offset of field: ",
@@ -15201,7 +14249,7 @@ fn bindgen_test_layout__cef_load_handler_t() {
         concat!("Alignment of ", stringify!(_cef_load_handler_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_load_handler_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_load_handler_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -15211,10 +14259,7 @@ fn bindgen_test_layout__cef_load_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_load_handler_t>())).on_loading_state_change as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_load_handler_t, on_loading_state_change),
         40usize,
         concat!(
             "Offset of field: ",
@@ -15224,9 +14269,7 @@ fn bindgen_test_layout__cef_load_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_load_handler_t>())).on_load_start as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_load_handler_t, on_load_start),
         48usize,
         concat!(
             "Offset of field: ",
@@ -15236,7 +14279,7 @@ fn bindgen_test_layout__cef_load_handler_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_load_handler_t>())).on_load_end as *const _ as usize },
+        ::std::mem::offset_of!(_cef_load_handler_t, on_load_end),
         56usize,
         concat!(
             "Offset of field: ",
@@ -15246,9 +14289,7 @@ fn bindgen_test_layout__cef_load_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_load_handler_t>())).on_load_error as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_load_handler_t, on_load_error),
         64usize,
         concat!(
             "Offset of field: ",
@@ -15278,7 +14319,7 @@ fn bindgen_test_layout__cef_task_t() {
         concat!("Alignment of ", stringify!(_cef_task_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_task_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_task_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -15288,7 +14329,7 @@ fn bindgen_test_layout__cef_task_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_task_t>())).execute as *const _ as usize },
+        ::std::mem::offset_of!(_cef_task_t, execute),
         40usize,
         concat!(
             "Offset of field: ",
@@ -15345,7 +14386,7 @@ fn bindgen_test_layout__cef_task_runner_t() {
         concat!("Alignment of ", stringify!(_cef_task_runner_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_task_runner_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_task_runner_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -15355,7 +14396,7 @@ fn bindgen_test_layout__cef_task_runner_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_task_runner_t>())).is_same as *const _ as usize },
+        ::std::mem::offset_of!(_cef_task_runner_t, is_same),
         40usize,
         concat!(
             "Offset of field: ",
@@ -15365,10 +14406,7 @@ fn bindgen_test_layout__cef_task_runner_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_task_runner_t>())).belongs_to_current_thread as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_task_runner_t, belongs_to_current_thread),
         48usize,
         concat!(
             "Offset of field: ",
@@ -15378,9 +14416,7 @@ fn bindgen_test_layout__cef_task_runner_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_task_runner_t>())).belongs_to_thread as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_task_runner_t, belongs_to_thread),
         56usize,
         concat!(
             "Offset of field: ",
@@ -15390,7 +14426,7 @@ fn bindgen_test_layout__cef_task_runner_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_task_runner_t>())).post_task as *const _ as usize },
+        ::std::mem::offset_of!(_cef_task_runner_t, post_task),
         64usize,
         concat!(
             "Offset of field: ",
@@ -15400,9 +14436,7 @@ fn bindgen_test_layout__cef_task_runner_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_task_runner_t>())).post_delayed_task as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_task_runner_t, post_delayed_task),
         72usize,
         concat!(
             "Offset of field: ",
@@ -15492,7 +14526,7 @@ fn bindgen_test_layout__cef_v8context_t() {
         concat!("Alignment of ", stringify!(_cef_v8context_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_v8context_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_v8context_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -15502,9 +14536,7 @@ fn bindgen_test_layout__cef_v8context_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_v8context_t>())).get_task_runner as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_v8context_t, get_task_runner),
         40usize,
         concat!(
             "Offset of field: ",
@@ -15514,7 +14546,7 @@ fn bindgen_test_layout__cef_v8context_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_v8context_t>())).is_valid as *const _ as usize },
+        ::std::mem::offset_of!(_cef_v8context_t, is_valid),
         48usize,
         concat!(
             "Offset of field: ",
@@ -15524,7 +14556,7 @@ fn bindgen_test_layout__cef_v8context_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_v8context_t>())).get_browser as *const _ as usize },
+        ::std::mem::offset_of!(_cef_v8context_t, get_browser),
         56usize,
         concat!(
             "Offset of field: ",
@@ -15534,7 +14566,7 @@ fn bindgen_test_layout__cef_v8context_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_v8context_t>())).get_frame as *const _ as usize },
+        ::std::mem::offset_of!(_cef_v8context_t, get_frame),
         64usize,
         concat!(
             "Offset of field: ",
@@ -15544,7 +14576,7 @@ fn bindgen_test_layout__cef_v8context_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_v8context_t>())).get_global as *const _ as usize },
+        ::std::mem::offset_of!(_cef_v8context_t, get_global),
         72usize,
         concat!(
             "Offset of field: ",
@@ -15554,7 +14586,7 @@ fn bindgen_test_layout__cef_v8context_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_v8context_t>())).enter as *const _ as usize },
+        ::std::mem::offset_of!(_cef_v8context_t, enter),
         80usize,
         concat!(
             "Offset of field: ",
@@ -15564,7 +14596,7 @@ fn bindgen_test_layout__cef_v8context_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_v8context_t>())).exit as *const _ as usize },
+        ::std::mem::offset_of!(_cef_v8context_t, exit),
         88usize,
         concat!(
             "Offset of field: ",
@@ -15574,7 +14606,7 @@ fn bindgen_test_layout__cef_v8context_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_v8context_t>())).is_same as *const _ as usize },
+        ::std::mem::offset_of!(_cef_v8context_t, is_same),
         96usize,
         concat!(
             "Offset of field: ",
@@ -15584,7 +14616,7 @@ fn bindgen_test_layout__cef_v8context_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_v8context_t>())).eval as *const _ as usize },
+        ::std::mem::offset_of!(_cef_v8context_t, eval),
         104usize,
         concat!(
             "Offset of field: ",
@@ -15633,7 +14665,7 @@ fn bindgen_test_layout__cef_v8handler_t() {
         concat!("Alignment of ", stringify!(_cef_v8handler_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_v8handler_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_v8handler_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -15643,7 +14675,7 @@ fn bindgen_test_layout__cef_v8handler_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_v8handler_t>())).execute as *const _ as usize },
+        ::std::mem::offset_of!(_cef_v8handler_t, execute),
         40usize,
         concat!(
             "Offset of field: ",
@@ -15690,7 +14722,7 @@ fn bindgen_test_layout__cef_v8accessor_t() {
         concat!("Alignment of ", stringify!(_cef_v8accessor_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_v8accessor_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_v8accessor_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -15700,7 +14732,7 @@ fn bindgen_test_layout__cef_v8accessor_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_v8accessor_t>())).get as *const _ as usize },
+        ::std::mem::offset_of!(_cef_v8accessor_t, get),
         40usize,
         concat!(
             "Offset of field: ",
@@ -15710,7 +14742,7 @@ fn bindgen_test_layout__cef_v8accessor_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_v8accessor_t>())).set as *const _ as usize },
+        ::std::mem::offset_of!(_cef_v8accessor_t, set),
         48usize,
         concat!(
             "Offset of field: ",
@@ -15775,7 +14807,7 @@ fn bindgen_test_layout__cef_v8interceptor_t() {
         concat!("Alignment of ", stringify!(_cef_v8interceptor_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_v8interceptor_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_v8interceptor_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -15785,7 +14817,7 @@ fn bindgen_test_layout__cef_v8interceptor_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_v8interceptor_t>())).get_byname as *const _ as usize },
+        ::std::mem::offset_of!(_cef_v8interceptor_t, get_byname),
         40usize,
         concat!(
             "Offset of field: ",
@@ -15795,9 +14827,7 @@ fn bindgen_test_layout__cef_v8interceptor_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_v8interceptor_t>())).get_byindex as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_v8interceptor_t, get_byindex),
         48usize,
         concat!(
             "Offset of field: ",
@@ -15807,7 +14837,7 @@ fn bindgen_test_layout__cef_v8interceptor_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_v8interceptor_t>())).set_byname as *const _ as usize },
+        ::std::mem::offset_of!(_cef_v8interceptor_t, set_byname),
         56usize,
         concat!(
             "Offset of field: ",
@@ -15817,9 +14847,7 @@ fn bindgen_test_layout__cef_v8interceptor_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_v8interceptor_t>())).set_byindex as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_v8interceptor_t, set_byindex),
         64usize,
         concat!(
             "Offset of field: ",
@@ -15872,7 +14900,7 @@ fn bindgen_test_layout__cef_v8exception_t() {
         concat!("Alignment of ", stringify!(_cef_v8exception_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_v8exception_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_v8exception_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -15882,7 +14910,7 @@ fn bindgen_test_layout__cef_v8exception_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_v8exception_t>())).get_message as *const _ as usize },
+        ::std::mem::offset_of!(_cef_v8exception_t, get_message),
         40usize,
         concat!(
             "Offset of field: ",
@@ -15892,9 +14920,7 @@ fn bindgen_test_layout__cef_v8exception_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_v8exception_t>())).get_source_line as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_v8exception_t, get_source_line),
         48usize,
         concat!(
             "Offset of field: ",
@@ -15904,10 +14930,7 @@ fn bindgen_test_layout__cef_v8exception_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_v8exception_t>())).get_script_resource_name as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_v8exception_t, get_script_resource_name),
         56usize,
         concat!(
             "Offset of field: ",
@@ -15917,9 +14940,7 @@ fn bindgen_test_layout__cef_v8exception_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_v8exception_t>())).get_line_number as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_v8exception_t, get_line_number),
         64usize,
         concat!(
             "Offset of field: ",
@@ -15929,9 +14950,7 @@ fn bindgen_test_layout__cef_v8exception_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_v8exception_t>())).get_start_position as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_v8exception_t, get_start_position),
         72usize,
         concat!(
             "Offset of field: ",
@@ -15941,9 +14960,7 @@ fn bindgen_test_layout__cef_v8exception_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_v8exception_t>())).get_end_position as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_v8exception_t, get_end_position),
         80usize,
         concat!(
             "Offset of field: ",
@@ -15953,9 +14970,7 @@ fn bindgen_test_layout__cef_v8exception_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_v8exception_t>())).get_start_column as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_v8exception_t, get_start_column),
         88usize,
         concat!(
             "Offset of field: ",
@@ -15965,9 +14980,7 @@ fn bindgen_test_layout__cef_v8exception_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_v8exception_t>())).get_end_column as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_v8exception_t, get_end_column),
         96usize,
         concat!(
             "Offset of field: ",
@@ -16008,10 +15021,7 @@ fn bindgen_test_layout__cef_v8array_buffer_release_callback_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_v8array_buffer_release_callback_t>())).base as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_v8array_buffer_release_callback_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -16021,10 +15031,7 @@ fn bindgen_test_layout__cef_v8array_buffer_release_callback_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_v8array_buffer_release_callback_t>())).release_buffer
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_v8array_buffer_release_callback_t, release_buffer),
         40usize,
         concat!(
             "Offset of field: ",
@@ -16250,7 +15257,7 @@ fn bindgen_test_layout__cef_v8value_t() {
         concat!("Alignment of ", stringify!(_cef_v8value_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_v8value_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_v8value_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -16260,7 +15267,7 @@ fn bindgen_test_layout__cef_v8value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_v8value_t>())).is_valid as *const _ as usize },
+        ::std::mem::offset_of!(_cef_v8value_t, is_valid),
         40usize,
         concat!(
             "Offset of field: ",
@@ -16270,7 +15277,7 @@ fn bindgen_test_layout__cef_v8value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_v8value_t>())).is_undefined as *const _ as usize },
+        ::std::mem::offset_of!(_cef_v8value_t, is_undefined),
         48usize,
         concat!(
             "Offset of field: ",
@@ -16280,7 +15287,7 @@ fn bindgen_test_layout__cef_v8value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_v8value_t>())).is_null as *const _ as usize },
+        ::std::mem::offset_of!(_cef_v8value_t, is_null),
         56usize,
         concat!(
             "Offset of field: ",
@@ -16290,7 +15297,7 @@ fn bindgen_test_layout__cef_v8value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_v8value_t>())).is_bool as *const _ as usize },
+        ::std::mem::offset_of!(_cef_v8value_t, is_bool),
         64usize,
         concat!(
             "Offset of field: ",
@@ -16300,7 +15307,7 @@ fn bindgen_test_layout__cef_v8value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_v8value_t>())).is_int as *const _ as usize },
+        ::std::mem::offset_of!(_cef_v8value_t, is_int),
         72usize,
         concat!(
             "Offset of field: ",
@@ -16310,7 +15317,7 @@ fn bindgen_test_layout__cef_v8value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_v8value_t>())).is_uint as *const _ as usize },
+        ::std::mem::offset_of!(_cef_v8value_t, is_uint),
         80usize,
         concat!(
             "Offset of field: ",
@@ -16320,7 +15327,7 @@ fn bindgen_test_layout__cef_v8value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_v8value_t>())).is_double as *const _ as usize },
+        ::std::mem::offset_of!(_cef_v8value_t, is_double),
         88usize,
         concat!(
             "Offset of field: ",
@@ -16330,7 +15337,7 @@ fn bindgen_test_layout__cef_v8value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_v8value_t>())).is_date as *const _ as usize },
+        ::std::mem::offset_of!(_cef_v8value_t, is_date),
         96usize,
         concat!(
             "Offset of field: ",
@@ -16340,7 +15347,7 @@ fn bindgen_test_layout__cef_v8value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_v8value_t>())).is_string as *const _ as usize },
+        ::std::mem::offset_of!(_cef_v8value_t, is_string),
         104usize,
         concat!(
             "Offset of field: ",
@@ -16350,7 +15357,7 @@ fn bindgen_test_layout__cef_v8value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_v8value_t>())).is_object as *const _ as usize },
+        ::std::mem::offset_of!(_cef_v8value_t, is_object),
         112usize,
         concat!(
             "Offset of field: ",
@@ -16360,7 +15367,7 @@ fn bindgen_test_layout__cef_v8value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_v8value_t>())).is_array as *const _ as usize },
+        ::std::mem::offset_of!(_cef_v8value_t, is_array),
         120usize,
         concat!(
             "Offset of field: ",
@@ -16370,7 +15377,7 @@ fn bindgen_test_layout__cef_v8value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_v8value_t>())).is_array_buffer as *const _ as usize },
+        ::std::mem::offset_of!(_cef_v8value_t, is_array_buffer),
         128usize,
         concat!(
             "Offset of field: ",
@@ -16380,7 +15387,7 @@ fn bindgen_test_layout__cef_v8value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_v8value_t>())).is_function as *const _ as usize },
+        ::std::mem::offset_of!(_cef_v8value_t, is_function),
         136usize,
         concat!(
             "Offset of field: ",
@@ -16390,7 +15397,7 @@ fn bindgen_test_layout__cef_v8value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_v8value_t>())).is_same as *const _ as usize },
+        ::std::mem::offset_of!(_cef_v8value_t, is_same),
         144usize,
         concat!(
             "Offset of field: ",
@@ -16400,7 +15407,7 @@ fn bindgen_test_layout__cef_v8value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_v8value_t>())).get_bool_value as *const _ as usize },
+        ::std::mem::offset_of!(_cef_v8value_t, get_bool_value),
         152usize,
         concat!(
             "Offset of field: ",
@@ -16410,7 +15417,7 @@ fn bindgen_test_layout__cef_v8value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_v8value_t>())).get_int_value as *const _ as usize },
+        ::std::mem::offset_of!(_cef_v8value_t, get_int_value),
         160usize,
         concat!(
             "Offset of field: ",
@@ -16420,7 +15427,7 @@ fn bindgen_test_layout__cef_v8value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_v8value_t>())).get_uint_value as *const _ as usize },
+        ::std::mem::offset_of!(_cef_v8value_t, get_uint_value),
         168usize,
         concat!(
             "Offset of field: ",
@@ -16430,7 +15437,7 @@ fn bindgen_test_layout__cef_v8value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_v8value_t>())).get_double_value as *const _ as usize },
+        ::std::mem::offset_of!(_cef_v8value_t, get_double_value),
         176usize,
         concat!(
             "Offset of field: ",
@@ -16440,7 +15447,7 @@ fn bindgen_test_layout__cef_v8value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_v8value_t>())).get_date_value as *const _ as usize },
+        ::std::mem::offset_of!(_cef_v8value_t, get_date_value),
         184usize,
         concat!(
             "Offset of field: ",
@@ -16450,7 +15457,7 @@ fn bindgen_test_layout__cef_v8value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_v8value_t>())).get_string_value as *const _ as usize },
+        ::std::mem::offset_of!(_cef_v8value_t, get_string_value),
         192usize,
         concat!(
             "Offset of field: ",
@@ -16460,7 +15467,7 @@ fn bindgen_test_layout__cef_v8value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_v8value_t>())).is_user_created as *const _ as usize },
+        ::std::mem::offset_of!(_cef_v8value_t, is_user_created),
         200usize,
         concat!(
             "Offset of field: ",
@@ -16470,7 +15477,7 @@ fn bindgen_test_layout__cef_v8value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_v8value_t>())).has_exception as *const _ as usize },
+        ::std::mem::offset_of!(_cef_v8value_t, has_exception),
         208usize,
         concat!(
             "Offset of field: ",
@@ -16480,7 +15487,7 @@ fn bindgen_test_layout__cef_v8value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_v8value_t>())).get_exception as *const _ as usize },
+        ::std::mem::offset_of!(_cef_v8value_t, get_exception),
         216usize,
         concat!(
             "Offset of field: ",
@@ -16490,7 +15497,7 @@ fn bindgen_test_layout__cef_v8value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_v8value_t>())).clear_exception as *const _ as usize },
+        ::std::mem::offset_of!(_cef_v8value_t, clear_exception),
         224usize,
         concat!(
             "Offset of field: ",
@@ -16500,9 +15507,7 @@ fn bindgen_test_layout__cef_v8value_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_v8value_t>())).will_rethrow_exceptions as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_v8value_t, will_rethrow_exceptions),
         232usize,
         concat!(
             "Offset of field: ",
@@ -16512,9 +15517,7 @@ fn bindgen_test_layout__cef_v8value_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_v8value_t>())).set_rethrow_exceptions as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_v8value_t, set_rethrow_exceptions),
         240usize,
         concat!(
             "Offset of field: ",
@@ -16524,7 +15527,7 @@ fn bindgen_test_layout__cef_v8value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_v8value_t>())).has_value_bykey as *const _ as usize },
+        ::std::mem::offset_of!(_cef_v8value_t, has_value_bykey),
         248usize,
         concat!(
             "Offset of field: ",
@@ -16534,9 +15537,7 @@ fn bindgen_test_layout__cef_v8value_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_v8value_t>())).has_value_byindex as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_v8value_t, has_value_byindex),
         256usize,
         concat!(
             "Offset of field: ",
@@ -16546,9 +15547,7 @@ fn bindgen_test_layout__cef_v8value_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_v8value_t>())).delete_value_bykey as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_v8value_t, delete_value_bykey),
         264usize,
         concat!(
             "Offset of field: ",
@@ -16558,9 +15557,7 @@ fn bindgen_test_layout__cef_v8value_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_v8value_t>())).delete_value_byindex as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_v8value_t, delete_value_byindex),
         272usize,
         concat!(
             "Offset of field: ",
@@ -16570,7 +15567,7 @@ fn bindgen_test_layout__cef_v8value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_v8value_t>())).get_value_bykey as *const _ as usize },
+        ::std::mem::offset_of!(_cef_v8value_t, get_value_bykey),
         280usize,
         concat!(
             "Offset of field: ",
@@ -16580,9 +15577,7 @@ fn bindgen_test_layout__cef_v8value_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_v8value_t>())).get_value_byindex as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_v8value_t, get_value_byindex),
         288usize,
         concat!(
             "Offset of field: ",
@@ -16592,7 +15587,7 @@ fn bindgen_test_layout__cef_v8value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_v8value_t>())).set_value_bykey as *const _ as usize },
+        ::std::mem::offset_of!(_cef_v8value_t, set_value_bykey),
         296usize,
         concat!(
             "Offset of field: ",
@@ -16602,9 +15597,7 @@ fn bindgen_test_layout__cef_v8value_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_v8value_t>())).set_value_byindex as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_v8value_t, set_value_byindex),
         304usize,
         concat!(
             "Offset of field: ",
@@ -16614,9 +15607,7 @@ fn bindgen_test_layout__cef_v8value_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_v8value_t>())).set_value_byaccessor as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_v8value_t, set_value_byaccessor),
         312usize,
         concat!(
             "Offset of field: ",
@@ -16626,7 +15617,7 @@ fn bindgen_test_layout__cef_v8value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_v8value_t>())).get_keys as *const _ as usize },
+        ::std::mem::offset_of!(_cef_v8value_t, get_keys),
         320usize,
         concat!(
             "Offset of field: ",
@@ -16636,7 +15627,7 @@ fn bindgen_test_layout__cef_v8value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_v8value_t>())).set_user_data as *const _ as usize },
+        ::std::mem::offset_of!(_cef_v8value_t, set_user_data),
         328usize,
         concat!(
             "Offset of field: ",
@@ -16646,7 +15637,7 @@ fn bindgen_test_layout__cef_v8value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_v8value_t>())).get_user_data as *const _ as usize },
+        ::std::mem::offset_of!(_cef_v8value_t, get_user_data),
         336usize,
         concat!(
             "Offset of field: ",
@@ -16656,10 +15647,7 @@ fn bindgen_test_layout__cef_v8value_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_v8value_t>())).get_externally_allocated_memory as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_v8value_t, get_externally_allocated_memory),
         344usize,
         concat!(
             "Offset of field: ",
@@ -16669,10 +15657,7 @@ fn bindgen_test_layout__cef_v8value_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_v8value_t>())).adjust_externally_allocated_memory
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_v8value_t, adjust_externally_allocated_memory),
         352usize,
         concat!(
             "Offset of field: ",
@@ -16682,7 +15667,7 @@ fn bindgen_test_layout__cef_v8value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_v8value_t>())).get_array_length as *const _ as usize },
+        ::std::mem::offset_of!(_cef_v8value_t, get_array_length),
         360usize,
         concat!(
             "Offset of field: ",
@@ -16692,10 +15677,7 @@ fn bindgen_test_layout__cef_v8value_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_v8value_t>())).get_array_buffer_release_callback as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_v8value_t, get_array_buffer_release_callback),
         368usize,
         concat!(
             "Offset of field: ",
@@ -16705,9 +15687,7 @@ fn bindgen_test_layout__cef_v8value_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_v8value_t>())).neuter_array_buffer as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_v8value_t, neuter_array_buffer),
         376usize,
         concat!(
             "Offset of field: ",
@@ -16717,9 +15697,7 @@ fn bindgen_test_layout__cef_v8value_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_v8value_t>())).get_function_name as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_v8value_t, get_function_name),
         384usize,
         concat!(
             "Offset of field: ",
@@ -16729,9 +15707,7 @@ fn bindgen_test_layout__cef_v8value_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_v8value_t>())).get_function_handler as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_v8value_t, get_function_handler),
         392usize,
         concat!(
             "Offset of field: ",
@@ -16741,7 +15717,7 @@ fn bindgen_test_layout__cef_v8value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_v8value_t>())).execute_function as *const _ as usize },
+        ::std::mem::offset_of!(_cef_v8value_t, execute_function),
         400usize,
         concat!(
             "Offset of field: ",
@@ -16751,10 +15727,7 @@ fn bindgen_test_layout__cef_v8value_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_v8value_t>())).execute_function_with_context as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_v8value_t, execute_function_with_context),
         408usize,
         concat!(
             "Offset of field: ",
@@ -16841,7 +15814,7 @@ fn bindgen_test_layout__cef_v8stack_trace_t() {
         concat!("Alignment of ", stringify!(_cef_v8stack_trace_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_v8stack_trace_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_v8stack_trace_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -16851,7 +15824,7 @@ fn bindgen_test_layout__cef_v8stack_trace_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_v8stack_trace_t>())).is_valid as *const _ as usize },
+        ::std::mem::offset_of!(_cef_v8stack_trace_t, is_valid),
         40usize,
         concat!(
             "Offset of field: ",
@@ -16861,9 +15834,7 @@ fn bindgen_test_layout__cef_v8stack_trace_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_v8stack_trace_t>())).get_frame_count as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_v8stack_trace_t, get_frame_count),
         48usize,
         concat!(
             "Offset of field: ",
@@ -16873,7 +15844,7 @@ fn bindgen_test_layout__cef_v8stack_trace_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_v8stack_trace_t>())).get_frame as *const _ as usize },
+        ::std::mem::offset_of!(_cef_v8stack_trace_t, get_frame),
         56usize,
         concat!(
             "Offset of field: ",
@@ -16931,7 +15902,7 @@ fn bindgen_test_layout__cef_v8stack_frame_t() {
         concat!("Alignment of ", stringify!(_cef_v8stack_frame_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_v8stack_frame_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_v8stack_frame_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -16941,7 +15912,7 @@ fn bindgen_test_layout__cef_v8stack_frame_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_v8stack_frame_t>())).is_valid as *const _ as usize },
+        ::std::mem::offset_of!(_cef_v8stack_frame_t, is_valid),
         40usize,
         concat!(
             "Offset of field: ",
@@ -16951,9 +15922,7 @@ fn bindgen_test_layout__cef_v8stack_frame_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_v8stack_frame_t>())).get_script_name as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_v8stack_frame_t, get_script_name),
         48usize,
         concat!(
             "Offset of field: ",
@@ -16963,10 +15932,7 @@ fn bindgen_test_layout__cef_v8stack_frame_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_v8stack_frame_t>())).get_script_name_or_source_url
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_v8stack_frame_t, get_script_name_or_source_url),
         56usize,
         concat!(
             "Offset of field: ",
@@ -16976,9 +15942,7 @@ fn bindgen_test_layout__cef_v8stack_frame_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_v8stack_frame_t>())).get_function_name as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_v8stack_frame_t, get_function_name),
         64usize,
         concat!(
             "Offset of field: ",
@@ -16988,9 +15952,7 @@ fn bindgen_test_layout__cef_v8stack_frame_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_v8stack_frame_t>())).get_line_number as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_v8stack_frame_t, get_line_number),
         72usize,
         concat!(
             "Offset of field: ",
@@ -17000,7 +15962,7 @@ fn bindgen_test_layout__cef_v8stack_frame_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_v8stack_frame_t>())).get_column as *const _ as usize },
+        ::std::mem::offset_of!(_cef_v8stack_frame_t, get_column),
         80usize,
         concat!(
             "Offset of field: ",
@@ -17010,7 +15972,7 @@ fn bindgen_test_layout__cef_v8stack_frame_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_v8stack_frame_t>())).is_eval as *const _ as usize },
+        ::std::mem::offset_of!(_cef_v8stack_frame_t, is_eval),
         88usize,
         concat!(
             "Offset of field: ",
@@ -17020,9 +15982,7 @@ fn bindgen_test_layout__cef_v8stack_frame_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_v8stack_frame_t>())).is_constructor as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_v8stack_frame_t, is_constructor),
         96usize,
         concat!(
             "Offset of field: ",
@@ -17125,9 +16085,7 @@ fn bindgen_test_layout__cef_render_process_handler_t() {
         concat!("Alignment of ", stringify!(_cef_render_process_handler_t))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_render_process_handler_t>())).base as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_render_process_handler_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -17137,10 +16095,7 @@ fn bindgen_test_layout__cef_render_process_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_render_process_handler_t>())).on_render_thread_created
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_render_process_handler_t, on_render_thread_created),
         40usize,
         concat!(
             "Offset of field: ",
@@ -17150,10 +16105,7 @@ fn bindgen_test_layout__cef_render_process_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_render_process_handler_t>())).on_web_kit_initialized
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_render_process_handler_t, on_web_kit_initialized),
         48usize,
         concat!(
             "Offset of field: ",
@@ -17163,10 +16115,7 @@ fn bindgen_test_layout__cef_render_process_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_render_process_handler_t>())).on_browser_created as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_render_process_handler_t, on_browser_created),
         56usize,
         concat!(
             "Offset of field: ",
@@ -17176,10 +16125,7 @@ fn bindgen_test_layout__cef_render_process_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_render_process_handler_t>())).on_browser_destroyed
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_render_process_handler_t, on_browser_destroyed),
         64usize,
         concat!(
             "Offset of field: ",
@@ -17189,10 +16135,7 @@ fn bindgen_test_layout__cef_render_process_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_render_process_handler_t>())).get_load_handler as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_render_process_handler_t, get_load_handler),
         72usize,
         concat!(
             "Offset of field: ",
@@ -17202,10 +16145,7 @@ fn bindgen_test_layout__cef_render_process_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_render_process_handler_t>())).on_context_created as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_render_process_handler_t, on_context_created),
         80usize,
         concat!(
             "Offset of field: ",
@@ -17215,10 +16155,7 @@ fn bindgen_test_layout__cef_render_process_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_render_process_handler_t>())).on_context_released
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_render_process_handler_t, on_context_released),
         88usize,
         concat!(
             "Offset of field: ",
@@ -17228,10 +16165,7 @@ fn bindgen_test_layout__cef_render_process_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_render_process_handler_t>())).on_uncaught_exception
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_render_process_handler_t, on_uncaught_exception),
         96usize,
         concat!(
             "Offset of field: ",
@@ -17241,10 +16175,7 @@ fn bindgen_test_layout__cef_render_process_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_render_process_handler_t>())).on_focused_node_changed
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_render_process_handler_t, on_focused_node_changed),
         104usize,
         concat!(
             "Offset of field: ",
@@ -17254,10 +16185,7 @@ fn bindgen_test_layout__cef_render_process_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_render_process_handler_t>())).on_process_message_received
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_render_process_handler_t, on_process_message_received),
         112usize,
         concat!(
             "Offset of field: ",
@@ -17310,9 +16238,7 @@ fn bindgen_test_layout__cef_resource_bundle_handler_t() {
         concat!("Alignment of ", stringify!(_cef_resource_bundle_handler_t))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_resource_bundle_handler_t>())).base as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_resource_bundle_handler_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -17322,10 +16248,7 @@ fn bindgen_test_layout__cef_resource_bundle_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_resource_bundle_handler_t>())).get_localized_string
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_resource_bundle_handler_t, get_localized_string),
         40usize,
         concat!(
             "Offset of field: ",
@@ -17335,10 +16258,7 @@ fn bindgen_test_layout__cef_resource_bundle_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_resource_bundle_handler_t>())).get_data_resource as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_resource_bundle_handler_t, get_data_resource),
         48usize,
         concat!(
             "Offset of field: ",
@@ -17348,10 +16268,7 @@ fn bindgen_test_layout__cef_resource_bundle_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_resource_bundle_handler_t>())).get_data_resource_for_scale
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_resource_bundle_handler_t, get_data_resource_for_scale),
         56usize,
         concat!(
             "Offset of field: ",
@@ -17439,7 +16356,7 @@ fn bindgen_test_layout__cef_response_t() {
         concat!("Alignment of ", stringify!(_cef_response_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_response_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_response_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -17449,7 +16366,7 @@ fn bindgen_test_layout__cef_response_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_response_t>())).is_read_only as *const _ as usize },
+        ::std::mem::offset_of!(_cef_response_t, is_read_only),
         40usize,
         concat!(
             "Offset of field: ",
@@ -17459,7 +16376,7 @@ fn bindgen_test_layout__cef_response_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_response_t>())).get_error as *const _ as usize },
+        ::std::mem::offset_of!(_cef_response_t, get_error),
         48usize,
         concat!(
             "Offset of field: ",
@@ -17469,7 +16386,7 @@ fn bindgen_test_layout__cef_response_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_response_t>())).set_error as *const _ as usize },
+        ::std::mem::offset_of!(_cef_response_t, set_error),
         56usize,
         concat!(
             "Offset of field: ",
@@ -17479,7 +16396,7 @@ fn bindgen_test_layout__cef_response_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_response_t>())).get_status as *const _ as usize },
+        ::std::mem::offset_of!(_cef_response_t, get_status),
         64usize,
         concat!(
             "Offset of field: ",
@@ -17489,7 +16406,7 @@ fn bindgen_test_layout__cef_response_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_response_t>())).set_status as *const _ as usize },
+        ::std::mem::offset_of!(_cef_response_t, set_status),
         72usize,
         concat!(
             "Offset of field: ",
@@ -17499,7 +16416,7 @@ fn bindgen_test_layout__cef_response_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_response_t>())).get_status_text as *const _ as usize },
+        ::std::mem::offset_of!(_cef_response_t, get_status_text),
         80usize,
         concat!(
             "Offset of field: ",
@@ -17509,7 +16426,7 @@ fn bindgen_test_layout__cef_response_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_response_t>())).set_status_text as *const _ as usize },
+        ::std::mem::offset_of!(_cef_response_t, set_status_text),
         88usize,
         concat!(
             "Offset of field: ",
@@ -17519,7 +16436,7 @@ fn bindgen_test_layout__cef_response_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_response_t>())).get_mime_type as *const _ as usize },
+        ::std::mem::offset_of!(_cef_response_t, get_mime_type),
         96usize,
         concat!(
             "Offset of field: ",
@@ -17529,7 +16446,7 @@ fn bindgen_test_layout__cef_response_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_response_t>())).set_mime_type as *const _ as usize },
+        ::std::mem::offset_of!(_cef_response_t, set_mime_type),
         104usize,
         concat!(
             "Offset of field: ",
@@ -17539,7 +16456,7 @@ fn bindgen_test_layout__cef_response_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_response_t>())).get_charset as *const _ as usize },
+        ::std::mem::offset_of!(_cef_response_t, get_charset),
         112usize,
         concat!(
             "Offset of field: ",
@@ -17549,7 +16466,7 @@ fn bindgen_test_layout__cef_response_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_response_t>())).set_charset as *const _ as usize },
+        ::std::mem::offset_of!(_cef_response_t, set_charset),
         120usize,
         concat!(
             "Offset of field: ",
@@ -17559,9 +16476,7 @@ fn bindgen_test_layout__cef_response_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_response_t>())).get_header_by_name as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_response_t, get_header_by_name),
         128usize,
         concat!(
             "Offset of field: ",
@@ -17571,9 +16486,7 @@ fn bindgen_test_layout__cef_response_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_response_t>())).set_header_by_name as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_response_t, set_header_by_name),
         136usize,
         concat!(
             "Offset of field: ",
@@ -17583,7 +16496,7 @@ fn bindgen_test_layout__cef_response_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_response_t>())).get_header_map as *const _ as usize },
+        ::std::mem::offset_of!(_cef_response_t, get_header_map),
         144usize,
         concat!(
             "Offset of field: ",
@@ -17593,7 +16506,7 @@ fn bindgen_test_layout__cef_response_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_response_t>())).set_header_map as *const _ as usize },
+        ::std::mem::offset_of!(_cef_response_t, set_header_map),
         152usize,
         concat!(
             "Offset of field: ",
@@ -17603,7 +16516,7 @@ fn bindgen_test_layout__cef_response_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_response_t>())).get_url as *const _ as usize },
+        ::std::mem::offset_of!(_cef_response_t, get_url),
         160usize,
         concat!(
             "Offset of field: ",
@@ -17613,7 +16526,7 @@ fn bindgen_test_layout__cef_response_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_response_t>())).set_url as *const _ as usize },
+        ::std::mem::offset_of!(_cef_response_t, set_url),
         168usize,
         concat!(
             "Offset of field: ",
@@ -17648,9 +16561,7 @@ fn bindgen_test_layout__cef_resource_skip_callback_t() {
         concat!("Alignment of ", stringify!(_cef_resource_skip_callback_t))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_resource_skip_callback_t>())).base as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_resource_skip_callback_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -17660,9 +16571,7 @@ fn bindgen_test_layout__cef_resource_skip_callback_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_resource_skip_callback_t>())).cont as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_resource_skip_callback_t, cont),
         40usize,
         concat!(
             "Offset of field: ",
@@ -17697,9 +16606,7 @@ fn bindgen_test_layout__cef_resource_read_callback_t() {
         concat!("Alignment of ", stringify!(_cef_resource_read_callback_t))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_resource_read_callback_t>())).base as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_resource_read_callback_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -17709,9 +16616,7 @@ fn bindgen_test_layout__cef_resource_read_callback_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_resource_read_callback_t>())).cont as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_resource_read_callback_t, cont),
         40usize,
         concat!(
             "Offset of field: ",
@@ -17790,7 +16695,7 @@ fn bindgen_test_layout__cef_resource_handler_t() {
         concat!("Alignment of ", stringify!(_cef_resource_handler_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_resource_handler_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_resource_handler_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -17800,7 +16705,7 @@ fn bindgen_test_layout__cef_resource_handler_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_resource_handler_t>())).open as *const _ as usize },
+        ::std::mem::offset_of!(_cef_resource_handler_t, open),
         40usize,
         concat!(
             "Offset of field: ",
@@ -17810,9 +16715,7 @@ fn bindgen_test_layout__cef_resource_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_resource_handler_t>())).process_request as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_resource_handler_t, process_request),
         48usize,
         concat!(
             "Offset of field: ",
@@ -17822,10 +16725,7 @@ fn bindgen_test_layout__cef_resource_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_resource_handler_t>())).get_response_headers as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_resource_handler_t, get_response_headers),
         56usize,
         concat!(
             "Offset of field: ",
@@ -17835,7 +16735,7 @@ fn bindgen_test_layout__cef_resource_handler_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_resource_handler_t>())).skip as *const _ as usize },
+        ::std::mem::offset_of!(_cef_resource_handler_t, skip),
         64usize,
         concat!(
             "Offset of field: ",
@@ -17845,7 +16745,7 @@ fn bindgen_test_layout__cef_resource_handler_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_resource_handler_t>())).read as *const _ as usize },
+        ::std::mem::offset_of!(_cef_resource_handler_t, read),
         72usize,
         concat!(
             "Offset of field: ",
@@ -17855,9 +16755,7 @@ fn bindgen_test_layout__cef_resource_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_resource_handler_t>())).read_response as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_resource_handler_t, read_response),
         80usize,
         concat!(
             "Offset of field: ",
@@ -17867,7 +16765,7 @@ fn bindgen_test_layout__cef_resource_handler_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_resource_handler_t>())).cancel as *const _ as usize },
+        ::std::mem::offset_of!(_cef_resource_handler_t, cancel),
         88usize,
         concat!(
             "Offset of field: ",
@@ -17903,7 +16801,7 @@ fn bindgen_test_layout__cef_scheme_registrar_t() {
         concat!("Alignment of ", stringify!(_cef_scheme_registrar_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_scheme_registrar_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_scheme_registrar_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -17913,10 +16811,7 @@ fn bindgen_test_layout__cef_scheme_registrar_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_scheme_registrar_t>())).add_custom_scheme as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_scheme_registrar_t, add_custom_scheme),
         16usize,
         concat!(
             "Offset of field: ",
@@ -17954,9 +16849,7 @@ fn bindgen_test_layout__cef_scheme_handler_factory_t() {
         concat!("Alignment of ", stringify!(_cef_scheme_handler_factory_t))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_scheme_handler_factory_t>())).base as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_scheme_handler_factory_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -17966,9 +16859,7 @@ fn bindgen_test_layout__cef_scheme_handler_factory_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_scheme_handler_factory_t>())).create as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_scheme_handler_factory_t, create),
         40usize,
         concat!(
             "Offset of field: ",
@@ -18026,7 +16917,7 @@ fn bindgen_test_layout__cef_app_t() {
         concat!("Alignment of ", stringify!(_cef_app_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_app_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_app_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -18036,10 +16927,7 @@ fn bindgen_test_layout__cef_app_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_app_t>())).on_before_command_line_processing as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_app_t, on_before_command_line_processing),
         40usize,
         concat!(
             "Offset of field: ",
@@ -18049,9 +16937,7 @@ fn bindgen_test_layout__cef_app_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_app_t>())).on_register_custom_schemes as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_app_t, on_register_custom_schemes),
         48usize,
         concat!(
             "Offset of field: ",
@@ -18061,9 +16947,7 @@ fn bindgen_test_layout__cef_app_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_app_t>())).get_resource_bundle_handler as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_app_t, get_resource_bundle_handler),
         56usize,
         concat!(
             "Offset of field: ",
@@ -18073,9 +16957,7 @@ fn bindgen_test_layout__cef_app_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_app_t>())).get_browser_process_handler as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_app_t, get_browser_process_handler),
         64usize,
         concat!(
             "Offset of field: ",
@@ -18085,9 +16967,7 @@ fn bindgen_test_layout__cef_app_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_app_t>())).get_render_process_handler as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_app_t, get_render_process_handler),
         72usize,
         concat!(
             "Offset of field: ",
@@ -18157,7 +17037,7 @@ fn bindgen_test_layout__cef_auth_callback_t() {
         concat!("Alignment of ", stringify!(_cef_auth_callback_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_auth_callback_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_auth_callback_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -18167,7 +17047,7 @@ fn bindgen_test_layout__cef_auth_callback_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_auth_callback_t>())).cont as *const _ as usize },
+        ::std::mem::offset_of!(_cef_auth_callback_t, cont),
         40usize,
         concat!(
             "Offset of field: ",
@@ -18177,7 +17057,7 @@ fn bindgen_test_layout__cef_auth_callback_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_auth_callback_t>())).cancel as *const _ as usize },
+        ::std::mem::offset_of!(_cef_auth_callback_t, cancel),
         48usize,
         concat!(
             "Offset of field: ",
@@ -18240,7 +17120,7 @@ fn bindgen_test_layout__cef_audio_handler_t() {
         concat!("Alignment of ", stringify!(_cef_audio_handler_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_audio_handler_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_audio_handler_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -18250,10 +17130,7 @@ fn bindgen_test_layout__cef_audio_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_audio_handler_t>())).get_audio_parameters as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_audio_handler_t, get_audio_parameters),
         40usize,
         concat!(
             "Offset of field: ",
@@ -18263,10 +17140,7 @@ fn bindgen_test_layout__cef_audio_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_audio_handler_t>())).on_audio_stream_started as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_audio_handler_t, on_audio_stream_started),
         48usize,
         concat!(
             "Offset of field: ",
@@ -18276,10 +17150,7 @@ fn bindgen_test_layout__cef_audio_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_audio_handler_t>())).on_audio_stream_packet as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_audio_handler_t, on_audio_stream_packet),
         56usize,
         concat!(
             "Offset of field: ",
@@ -18289,10 +17160,7 @@ fn bindgen_test_layout__cef_audio_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_audio_handler_t>())).on_audio_stream_stopped as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_audio_handler_t, on_audio_stream_stopped),
         64usize,
         concat!(
             "Offset of field: ",
@@ -18302,10 +17170,7 @@ fn bindgen_test_layout__cef_audio_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_audio_handler_t>())).on_audio_stream_error as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_audio_handler_t, on_audio_stream_error),
         72usize,
         concat!(
             "Offset of field: ",
@@ -18382,7 +17247,7 @@ fn bindgen_test_layout__cef_menu_model_delegate_t() {
         concat!("Alignment of ", stringify!(_cef_menu_model_delegate_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_menu_model_delegate_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_menu_model_delegate_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -18392,10 +17257,7 @@ fn bindgen_test_layout__cef_menu_model_delegate_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_menu_model_delegate_t>())).execute_command as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_menu_model_delegate_t, execute_command),
         40usize,
         concat!(
             "Offset of field: ",
@@ -18405,10 +17267,7 @@ fn bindgen_test_layout__cef_menu_model_delegate_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_menu_model_delegate_t>())).mouse_outside_menu as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_menu_model_delegate_t, mouse_outside_menu),
         48usize,
         concat!(
             "Offset of field: ",
@@ -18418,10 +17277,7 @@ fn bindgen_test_layout__cef_menu_model_delegate_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_menu_model_delegate_t>())).unhandled_open_submenu
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_menu_model_delegate_t, unhandled_open_submenu),
         56usize,
         concat!(
             "Offset of field: ",
@@ -18431,10 +17287,7 @@ fn bindgen_test_layout__cef_menu_model_delegate_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_menu_model_delegate_t>())).unhandled_close_submenu
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_menu_model_delegate_t, unhandled_close_submenu),
         64usize,
         concat!(
             "Offset of field: ",
@@ -18444,10 +17297,7 @@ fn bindgen_test_layout__cef_menu_model_delegate_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_menu_model_delegate_t>())).menu_will_show as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_menu_model_delegate_t, menu_will_show),
         72usize,
         concat!(
             "Offset of field: ",
@@ -18457,9 +17307,7 @@ fn bindgen_test_layout__cef_menu_model_delegate_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_menu_model_delegate_t>())).menu_closed as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_menu_model_delegate_t, menu_closed),
         80usize,
         concat!(
             "Offset of field: ",
@@ -18469,9 +17317,7 @@ fn bindgen_test_layout__cef_menu_model_delegate_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_menu_model_delegate_t>())).format_label as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_menu_model_delegate_t, format_label),
         88usize,
         concat!(
             "Offset of field: ",
@@ -18875,7 +17721,7 @@ fn bindgen_test_layout__cef_menu_model_t() {
         concat!("Alignment of ", stringify!(_cef_menu_model_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_menu_model_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_menu_model_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -18885,7 +17731,7 @@ fn bindgen_test_layout__cef_menu_model_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_menu_model_t>())).is_sub_menu as *const _ as usize },
+        ::std::mem::offset_of!(_cef_menu_model_t, is_sub_menu),
         40usize,
         concat!(
             "Offset of field: ",
@@ -18895,7 +17741,7 @@ fn bindgen_test_layout__cef_menu_model_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_menu_model_t>())).clear as *const _ as usize },
+        ::std::mem::offset_of!(_cef_menu_model_t, clear),
         48usize,
         concat!(
             "Offset of field: ",
@@ -18905,7 +17751,7 @@ fn bindgen_test_layout__cef_menu_model_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_menu_model_t>())).get_count as *const _ as usize },
+        ::std::mem::offset_of!(_cef_menu_model_t, get_count),
         56usize,
         concat!(
             "Offset of field: ",
@@ -18915,7 +17761,7 @@ fn bindgen_test_layout__cef_menu_model_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_menu_model_t>())).add_separator as *const _ as usize },
+        ::std::mem::offset_of!(_cef_menu_model_t, add_separator),
         64usize,
         concat!(
             "Offset of field: ",
@@ -18925,7 +17771,7 @@ fn bindgen_test_layout__cef_menu_model_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_menu_model_t>())).add_item as *const _ as usize },
+        ::std::mem::offset_of!(_cef_menu_model_t, add_item),
         72usize,
         concat!(
             "Offset of field: ",
@@ -18935,9 +17781,7 @@ fn bindgen_test_layout__cef_menu_model_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_menu_model_t>())).add_check_item as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_menu_model_t, add_check_item),
         80usize,
         concat!(
             "Offset of field: ",
@@ -18947,9 +17791,7 @@ fn bindgen_test_layout__cef_menu_model_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_menu_model_t>())).add_radio_item as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_menu_model_t, add_radio_item),
         88usize,
         concat!(
             "Offset of field: ",
@@ -18959,7 +17801,7 @@ fn bindgen_test_layout__cef_menu_model_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_menu_model_t>())).add_sub_menu as *const _ as usize },
+        ::std::mem::offset_of!(_cef_menu_model_t, add_sub_menu),
         96usize,
         concat!(
             "Offset of field: ",
@@ -18969,9 +17811,7 @@ fn bindgen_test_layout__cef_menu_model_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_menu_model_t>())).insert_separator_at as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_menu_model_t, insert_separator_at),
         104usize,
         concat!(
             "Offset of field: ",
@@ -18981,9 +17821,7 @@ fn bindgen_test_layout__cef_menu_model_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_menu_model_t>())).insert_item_at as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_menu_model_t, insert_item_at),
         112usize,
         concat!(
             "Offset of field: ",
@@ -18993,9 +17831,7 @@ fn bindgen_test_layout__cef_menu_model_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_menu_model_t>())).insert_check_item_at as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_menu_model_t, insert_check_item_at),
         120usize,
         concat!(
             "Offset of field: ",
@@ -19005,9 +17841,7 @@ fn bindgen_test_layout__cef_menu_model_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_menu_model_t>())).insert_radio_item_at as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_menu_model_t, insert_radio_item_at),
         128usize,
         concat!(
             "Offset of field: ",
@@ -19017,9 +17851,7 @@ fn bindgen_test_layout__cef_menu_model_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_menu_model_t>())).insert_sub_menu_at as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_menu_model_t, insert_sub_menu_at),
         136usize,
         concat!(
             "Offset of field: ",
@@ -19029,7 +17861,7 @@ fn bindgen_test_layout__cef_menu_model_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_menu_model_t>())).remove as *const _ as usize },
+        ::std::mem::offset_of!(_cef_menu_model_t, remove),
         144usize,
         concat!(
             "Offset of field: ",
@@ -19039,7 +17871,7 @@ fn bindgen_test_layout__cef_menu_model_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_menu_model_t>())).remove_at as *const _ as usize },
+        ::std::mem::offset_of!(_cef_menu_model_t, remove_at),
         152usize,
         concat!(
             "Offset of field: ",
@@ -19049,7 +17881,7 @@ fn bindgen_test_layout__cef_menu_model_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_menu_model_t>())).get_index_of as *const _ as usize },
+        ::std::mem::offset_of!(_cef_menu_model_t, get_index_of),
         160usize,
         concat!(
             "Offset of field: ",
@@ -19059,9 +17891,7 @@ fn bindgen_test_layout__cef_menu_model_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_menu_model_t>())).get_command_id_at as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_menu_model_t, get_command_id_at),
         168usize,
         concat!(
             "Offset of field: ",
@@ -19071,9 +17901,7 @@ fn bindgen_test_layout__cef_menu_model_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_menu_model_t>())).set_command_id_at as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_menu_model_t, set_command_id_at),
         176usize,
         concat!(
             "Offset of field: ",
@@ -19083,7 +17911,7 @@ fn bindgen_test_layout__cef_menu_model_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_menu_model_t>())).get_label as *const _ as usize },
+        ::std::mem::offset_of!(_cef_menu_model_t, get_label),
         184usize,
         concat!(
             "Offset of field: ",
@@ -19093,7 +17921,7 @@ fn bindgen_test_layout__cef_menu_model_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_menu_model_t>())).get_label_at as *const _ as usize },
+        ::std::mem::offset_of!(_cef_menu_model_t, get_label_at),
         192usize,
         concat!(
             "Offset of field: ",
@@ -19103,7 +17931,7 @@ fn bindgen_test_layout__cef_menu_model_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_menu_model_t>())).set_label as *const _ as usize },
+        ::std::mem::offset_of!(_cef_menu_model_t, set_label),
         200usize,
         concat!(
             "Offset of field: ",
@@ -19113,7 +17941,7 @@ fn bindgen_test_layout__cef_menu_model_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_menu_model_t>())).set_label_at as *const _ as usize },
+        ::std::mem::offset_of!(_cef_menu_model_t, set_label_at),
         208usize,
         concat!(
             "Offset of field: ",
@@ -19123,7 +17951,7 @@ fn bindgen_test_layout__cef_menu_model_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_menu_model_t>())).get_type as *const _ as usize },
+        ::std::mem::offset_of!(_cef_menu_model_t, get_type),
         216usize,
         concat!(
             "Offset of field: ",
@@ -19133,7 +17961,7 @@ fn bindgen_test_layout__cef_menu_model_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_menu_model_t>())).get_type_at as *const _ as usize },
+        ::std::mem::offset_of!(_cef_menu_model_t, get_type_at),
         224usize,
         concat!(
             "Offset of field: ",
@@ -19143,7 +17971,7 @@ fn bindgen_test_layout__cef_menu_model_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_menu_model_t>())).get_group_id as *const _ as usize },
+        ::std::mem::offset_of!(_cef_menu_model_t, get_group_id),
         232usize,
         concat!(
             "Offset of field: ",
@@ -19153,9 +17981,7 @@ fn bindgen_test_layout__cef_menu_model_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_menu_model_t>())).get_group_id_at as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_menu_model_t, get_group_id_at),
         240usize,
         concat!(
             "Offset of field: ",
@@ -19165,7 +17991,7 @@ fn bindgen_test_layout__cef_menu_model_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_menu_model_t>())).set_group_id as *const _ as usize },
+        ::std::mem::offset_of!(_cef_menu_model_t, set_group_id),
         248usize,
         concat!(
             "Offset of field: ",
@@ -19175,9 +18001,7 @@ fn bindgen_test_layout__cef_menu_model_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_menu_model_t>())).set_group_id_at as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_menu_model_t, set_group_id_at),
         256usize,
         concat!(
             "Offset of field: ",
@@ -19187,7 +18011,7 @@ fn bindgen_test_layout__cef_menu_model_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_menu_model_t>())).get_sub_menu as *const _ as usize },
+        ::std::mem::offset_of!(_cef_menu_model_t, get_sub_menu),
         264usize,
         concat!(
             "Offset of field: ",
@@ -19197,9 +18021,7 @@ fn bindgen_test_layout__cef_menu_model_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_menu_model_t>())).get_sub_menu_at as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_menu_model_t, get_sub_menu_at),
         272usize,
         concat!(
             "Offset of field: ",
@@ -19209,7 +18031,7 @@ fn bindgen_test_layout__cef_menu_model_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_menu_model_t>())).is_visible as *const _ as usize },
+        ::std::mem::offset_of!(_cef_menu_model_t, is_visible),
         280usize,
         concat!(
             "Offset of field: ",
@@ -19219,7 +18041,7 @@ fn bindgen_test_layout__cef_menu_model_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_menu_model_t>())).is_visible_at as *const _ as usize },
+        ::std::mem::offset_of!(_cef_menu_model_t, is_visible_at),
         288usize,
         concat!(
             "Offset of field: ",
@@ -19229,7 +18051,7 @@ fn bindgen_test_layout__cef_menu_model_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_menu_model_t>())).set_visible as *const _ as usize },
+        ::std::mem::offset_of!(_cef_menu_model_t, set_visible),
         296usize,
         concat!(
             "Offset of field: ",
@@ -19239,9 +18061,7 @@ fn bindgen_test_layout__cef_menu_model_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_menu_model_t>())).set_visible_at as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_menu_model_t, set_visible_at),
         304usize,
         concat!(
             "Offset of field: ",
@@ -19251,7 +18071,7 @@ fn bindgen_test_layout__cef_menu_model_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_menu_model_t>())).is_enabled as *const _ as usize },
+        ::std::mem::offset_of!(_cef_menu_model_t, is_enabled),
         312usize,
         concat!(
             "Offset of field: ",
@@ -19261,7 +18081,7 @@ fn bindgen_test_layout__cef_menu_model_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_menu_model_t>())).is_enabled_at as *const _ as usize },
+        ::std::mem::offset_of!(_cef_menu_model_t, is_enabled_at),
         320usize,
         concat!(
             "Offset of field: ",
@@ -19271,7 +18091,7 @@ fn bindgen_test_layout__cef_menu_model_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_menu_model_t>())).set_enabled as *const _ as usize },
+        ::std::mem::offset_of!(_cef_menu_model_t, set_enabled),
         328usize,
         concat!(
             "Offset of field: ",
@@ -19281,9 +18101,7 @@ fn bindgen_test_layout__cef_menu_model_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_menu_model_t>())).set_enabled_at as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_menu_model_t, set_enabled_at),
         336usize,
         concat!(
             "Offset of field: ",
@@ -19293,7 +18111,7 @@ fn bindgen_test_layout__cef_menu_model_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_menu_model_t>())).is_checked as *const _ as usize },
+        ::std::mem::offset_of!(_cef_menu_model_t, is_checked),
         344usize,
         concat!(
             "Offset of field: ",
@@ -19303,7 +18121,7 @@ fn bindgen_test_layout__cef_menu_model_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_menu_model_t>())).is_checked_at as *const _ as usize },
+        ::std::mem::offset_of!(_cef_menu_model_t, is_checked_at),
         352usize,
         concat!(
             "Offset of field: ",
@@ -19313,7 +18131,7 @@ fn bindgen_test_layout__cef_menu_model_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_menu_model_t>())).set_checked as *const _ as usize },
+        ::std::mem::offset_of!(_cef_menu_model_t, set_checked),
         360usize,
         concat!(
             "Offset of field: ",
@@ -19323,9 +18141,7 @@ fn bindgen_test_layout__cef_menu_model_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_menu_model_t>())).set_checked_at as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_menu_model_t, set_checked_at),
         368usize,
         concat!(
             "Offset of field: ",
@@ -19335,9 +18151,7 @@ fn bindgen_test_layout__cef_menu_model_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_menu_model_t>())).has_accelerator as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_menu_model_t, has_accelerator),
         376usize,
         concat!(
             "Offset of field: ",
@@ -19347,9 +18161,7 @@ fn bindgen_test_layout__cef_menu_model_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_menu_model_t>())).has_accelerator_at as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_menu_model_t, has_accelerator_at),
         384usize,
         concat!(
             "Offset of field: ",
@@ -19359,9 +18171,7 @@ fn bindgen_test_layout__cef_menu_model_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_menu_model_t>())).set_accelerator as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_menu_model_t, set_accelerator),
         392usize,
         concat!(
             "Offset of field: ",
@@ -19371,9 +18181,7 @@ fn bindgen_test_layout__cef_menu_model_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_menu_model_t>())).set_accelerator_at as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_menu_model_t, set_accelerator_at),
         400usize,
         concat!(
             "Offset of field: ",
@@ -19383,9 +18191,7 @@ fn bindgen_test_layout__cef_menu_model_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_menu_model_t>())).remove_accelerator as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_menu_model_t, remove_accelerator),
         408usize,
         concat!(
             "Offset of field: ",
@@ -19395,9 +18201,7 @@ fn bindgen_test_layout__cef_menu_model_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_menu_model_t>())).remove_accelerator_at as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_menu_model_t, remove_accelerator_at),
         416usize,
         concat!(
             "Offset of field: ",
@@ -19407,9 +18211,7 @@ fn bindgen_test_layout__cef_menu_model_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_menu_model_t>())).get_accelerator as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_menu_model_t, get_accelerator),
         424usize,
         concat!(
             "Offset of field: ",
@@ -19419,9 +18221,7 @@ fn bindgen_test_layout__cef_menu_model_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_menu_model_t>())).get_accelerator_at as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_menu_model_t, get_accelerator_at),
         432usize,
         concat!(
             "Offset of field: ",
@@ -19431,7 +18231,7 @@ fn bindgen_test_layout__cef_menu_model_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_menu_model_t>())).set_color as *const _ as usize },
+        ::std::mem::offset_of!(_cef_menu_model_t, set_color),
         440usize,
         concat!(
             "Offset of field: ",
@@ -19441,7 +18241,7 @@ fn bindgen_test_layout__cef_menu_model_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_menu_model_t>())).set_color_at as *const _ as usize },
+        ::std::mem::offset_of!(_cef_menu_model_t, set_color_at),
         448usize,
         concat!(
             "Offset of field: ",
@@ -19451,7 +18251,7 @@ fn bindgen_test_layout__cef_menu_model_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_menu_model_t>())).get_color as *const _ as usize },
+        ::std::mem::offset_of!(_cef_menu_model_t, get_color),
         456usize,
         concat!(
             "Offset of field: ",
@@ -19461,7 +18261,7 @@ fn bindgen_test_layout__cef_menu_model_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_menu_model_t>())).get_color_at as *const _ as usize },
+        ::std::mem::offset_of!(_cef_menu_model_t, get_color_at),
         464usize,
         concat!(
             "Offset of field: ",
@@ -19471,7 +18271,7 @@ fn bindgen_test_layout__cef_menu_model_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_menu_model_t>())).set_font_list as *const _ as usize },
+        ::std::mem::offset_of!(_cef_menu_model_t, set_font_list),
         472usize,
         concat!(
             "Offset of field: ",
@@ -19481,9 +18281,7 @@ fn bindgen_test_layout__cef_menu_model_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_menu_model_t>())).set_font_list_at as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_menu_model_t, set_font_list_at),
         480usize,
         concat!(
             "Offset of field: ",
@@ -19529,9 +18327,7 @@ fn bindgen_test_layout__cef_run_context_menu_callback_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_run_context_menu_callback_t>())).base as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_run_context_menu_callback_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -19541,9 +18337,7 @@ fn bindgen_test_layout__cef_run_context_menu_callback_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_run_context_menu_callback_t>())).cont as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_run_context_menu_callback_t, cont),
         40usize,
         concat!(
             "Offset of field: ",
@@ -19553,9 +18347,7 @@ fn bindgen_test_layout__cef_run_context_menu_callback_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_run_context_menu_callback_t>())).cancel as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_run_context_menu_callback_t, cancel),
         48usize,
         concat!(
             "Offset of field: ",
@@ -19620,9 +18412,7 @@ fn bindgen_test_layout__cef_context_menu_handler_t() {
         concat!("Alignment of ", stringify!(_cef_context_menu_handler_t))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_context_menu_handler_t>())).base as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_context_menu_handler_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -19632,10 +18422,7 @@ fn bindgen_test_layout__cef_context_menu_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_context_menu_handler_t>())).on_before_context_menu
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_context_menu_handler_t, on_before_context_menu),
         40usize,
         concat!(
             "Offset of field: ",
@@ -19645,10 +18432,7 @@ fn bindgen_test_layout__cef_context_menu_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_context_menu_handler_t>())).run_context_menu as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_context_menu_handler_t, run_context_menu),
         48usize,
         concat!(
             "Offset of field: ",
@@ -19658,10 +18442,7 @@ fn bindgen_test_layout__cef_context_menu_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_context_menu_handler_t>())).on_context_menu_command
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_context_menu_handler_t, on_context_menu_command),
         56usize,
         concat!(
             "Offset of field: ",
@@ -19671,10 +18452,7 @@ fn bindgen_test_layout__cef_context_menu_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_context_menu_handler_t>())).on_context_menu_dismissed
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_context_menu_handler_t, on_context_menu_dismissed),
         64usize,
         concat!(
             "Offset of field: ",
@@ -19777,7 +18555,7 @@ fn bindgen_test_layout__cef_context_menu_params_t() {
         concat!("Alignment of ", stringify!(_cef_context_menu_params_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_context_menu_params_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_context_menu_params_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -19787,9 +18565,7 @@ fn bindgen_test_layout__cef_context_menu_params_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_context_menu_params_t>())).get_xcoord as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_context_menu_params_t, get_xcoord),
         40usize,
         concat!(
             "Offset of field: ",
@@ -19799,9 +18575,7 @@ fn bindgen_test_layout__cef_context_menu_params_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_context_menu_params_t>())).get_ycoord as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_context_menu_params_t, get_ycoord),
         48usize,
         concat!(
             "Offset of field: ",
@@ -19811,10 +18585,7 @@ fn bindgen_test_layout__cef_context_menu_params_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_context_menu_params_t>())).get_type_flags as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_context_menu_params_t, get_type_flags),
         56usize,
         concat!(
             "Offset of field: ",
@@ -19824,9 +18595,7 @@ fn bindgen_test_layout__cef_context_menu_params_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_context_menu_params_t>())).get_link_url as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_context_menu_params_t, get_link_url),
         64usize,
         concat!(
             "Offset of field: ",
@@ -19836,10 +18605,7 @@ fn bindgen_test_layout__cef_context_menu_params_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_context_menu_params_t>())).get_unfiltered_link_url
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_context_menu_params_t, get_unfiltered_link_url),
         72usize,
         concat!(
             "Offset of field: ",
@@ -19849,10 +18615,7 @@ fn bindgen_test_layout__cef_context_menu_params_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_context_menu_params_t>())).get_source_url as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_context_menu_params_t, get_source_url),
         80usize,
         concat!(
             "Offset of field: ",
@@ -19862,10 +18625,7 @@ fn bindgen_test_layout__cef_context_menu_params_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_context_menu_params_t>())).has_image_contents as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_context_menu_params_t, has_image_contents),
         88usize,
         concat!(
             "Offset of field: ",
@@ -19875,10 +18635,7 @@ fn bindgen_test_layout__cef_context_menu_params_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_context_menu_params_t>())).get_title_text as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_context_menu_params_t, get_title_text),
         96usize,
         concat!(
             "Offset of field: ",
@@ -19888,9 +18645,7 @@ fn bindgen_test_layout__cef_context_menu_params_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_context_menu_params_t>())).get_page_url as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_context_menu_params_t, get_page_url),
         104usize,
         concat!(
             "Offset of field: ",
@@ -19900,10 +18655,7 @@ fn bindgen_test_layout__cef_context_menu_params_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_context_menu_params_t>())).get_frame_url as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_context_menu_params_t, get_frame_url),
         112usize,
         concat!(
             "Offset of field: ",
@@ -19913,10 +18665,7 @@ fn bindgen_test_layout__cef_context_menu_params_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_context_menu_params_t>())).get_frame_charset as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_context_menu_params_t, get_frame_charset),
         120usize,
         concat!(
             "Offset of field: ",
@@ -19926,10 +18675,7 @@ fn bindgen_test_layout__cef_context_menu_params_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_context_menu_params_t>())).get_media_type as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_context_menu_params_t, get_media_type),
         128usize,
         concat!(
             "Offset of field: ",
@@ -19939,10 +18685,7 @@ fn bindgen_test_layout__cef_context_menu_params_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_context_menu_params_t>())).get_media_state_flags as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_context_menu_params_t, get_media_state_flags),
         136usize,
         concat!(
             "Offset of field: ",
@@ -19952,10 +18695,7 @@ fn bindgen_test_layout__cef_context_menu_params_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_context_menu_params_t>())).get_selection_text as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_context_menu_params_t, get_selection_text),
         144usize,
         concat!(
             "Offset of field: ",
@@ -19965,10 +18705,7 @@ fn bindgen_test_layout__cef_context_menu_params_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_context_menu_params_t>())).get_misspelled_word as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_context_menu_params_t, get_misspelled_word),
         152usize,
         concat!(
             "Offset of field: ",
@@ -19978,10 +18715,7 @@ fn bindgen_test_layout__cef_context_menu_params_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_context_menu_params_t>())).get_dictionary_suggestions
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_context_menu_params_t, get_dictionary_suggestions),
         160usize,
         concat!(
             "Offset of field: ",
@@ -19991,9 +18725,7 @@ fn bindgen_test_layout__cef_context_menu_params_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_context_menu_params_t>())).is_editable as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_context_menu_params_t, is_editable),
         168usize,
         concat!(
             "Offset of field: ",
@@ -20003,10 +18735,7 @@ fn bindgen_test_layout__cef_context_menu_params_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_context_menu_params_t>())).is_spell_check_enabled
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_context_menu_params_t, is_spell_check_enabled),
         176usize,
         concat!(
             "Offset of field: ",
@@ -20016,10 +18745,7 @@ fn bindgen_test_layout__cef_context_menu_params_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_context_menu_params_t>())).get_edit_state_flags as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_context_menu_params_t, get_edit_state_flags),
         184usize,
         concat!(
             "Offset of field: ",
@@ -20029,10 +18755,7 @@ fn bindgen_test_layout__cef_context_menu_params_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_context_menu_params_t>())).is_custom_menu as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_context_menu_params_t, is_custom_menu),
         192usize,
         concat!(
             "Offset of field: ",
@@ -20042,10 +18765,7 @@ fn bindgen_test_layout__cef_context_menu_params_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_context_menu_params_t>())).is_pepper_menu as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_context_menu_params_t, is_pepper_menu),
         200usize,
         concat!(
             "Offset of field: ",
@@ -20083,9 +18803,7 @@ fn bindgen_test_layout__cef_file_dialog_callback_t() {
         concat!("Alignment of ", stringify!(_cef_file_dialog_callback_t))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_file_dialog_callback_t>())).base as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_file_dialog_callback_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -20095,9 +18813,7 @@ fn bindgen_test_layout__cef_file_dialog_callback_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_file_dialog_callback_t>())).cont as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_file_dialog_callback_t, cont),
         40usize,
         concat!(
             "Offset of field: ",
@@ -20107,9 +18823,7 @@ fn bindgen_test_layout__cef_file_dialog_callback_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_file_dialog_callback_t>())).cancel as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_file_dialog_callback_t, cancel),
         48usize,
         concat!(
             "Offset of field: ",
@@ -20150,7 +18864,7 @@ fn bindgen_test_layout__cef_dialog_handler_t() {
         concat!("Alignment of ", stringify!(_cef_dialog_handler_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_dialog_handler_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_dialog_handler_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -20160,9 +18874,7 @@ fn bindgen_test_layout__cef_dialog_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_dialog_handler_t>())).on_file_dialog as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_dialog_handler_t, on_file_dialog),
         40usize,
         concat!(
             "Offset of field: ",
@@ -20258,7 +18970,7 @@ fn bindgen_test_layout__cef_display_handler_t() {
         concat!("Alignment of ", stringify!(_cef_display_handler_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_display_handler_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_display_handler_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -20268,10 +18980,7 @@ fn bindgen_test_layout__cef_display_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_display_handler_t>())).on_address_change as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_display_handler_t, on_address_change),
         40usize,
         concat!(
             "Offset of field: ",
@@ -20281,9 +18990,7 @@ fn bindgen_test_layout__cef_display_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_display_handler_t>())).on_title_change as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_display_handler_t, on_title_change),
         48usize,
         concat!(
             "Offset of field: ",
@@ -20293,10 +19000,7 @@ fn bindgen_test_layout__cef_display_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_display_handler_t>())).on_favicon_urlchange as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_display_handler_t, on_favicon_urlchange),
         56usize,
         concat!(
             "Offset of field: ",
@@ -20306,10 +19010,7 @@ fn bindgen_test_layout__cef_display_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_display_handler_t>())).on_fullscreen_mode_change as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_display_handler_t, on_fullscreen_mode_change),
         64usize,
         concat!(
             "Offset of field: ",
@@ -20319,9 +19020,7 @@ fn bindgen_test_layout__cef_display_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_display_handler_t>())).on_tooltip as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_display_handler_t, on_tooltip),
         72usize,
         concat!(
             "Offset of field: ",
@@ -20331,10 +19030,7 @@ fn bindgen_test_layout__cef_display_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_display_handler_t>())).on_status_message as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_display_handler_t, on_status_message),
         80usize,
         concat!(
             "Offset of field: ",
@@ -20344,10 +19040,7 @@ fn bindgen_test_layout__cef_display_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_display_handler_t>())).on_console_message as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_display_handler_t, on_console_message),
         88usize,
         concat!(
             "Offset of field: ",
@@ -20357,9 +19050,7 @@ fn bindgen_test_layout__cef_display_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_display_handler_t>())).on_auto_resize as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_display_handler_t, on_auto_resize),
         96usize,
         concat!(
             "Offset of field: ",
@@ -20369,10 +19060,7 @@ fn bindgen_test_layout__cef_display_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_display_handler_t>())).on_loading_progress_change
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_display_handler_t, on_loading_progress_change),
         104usize,
         concat!(
             "Offset of field: ",
@@ -20446,7 +19134,7 @@ fn bindgen_test_layout__cef_download_item_t() {
         concat!("Alignment of ", stringify!(_cef_download_item_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_download_item_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_download_item_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -20456,7 +19144,7 @@ fn bindgen_test_layout__cef_download_item_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_download_item_t>())).is_valid as *const _ as usize },
+        ::std::mem::offset_of!(_cef_download_item_t, is_valid),
         40usize,
         concat!(
             "Offset of field: ",
@@ -20466,9 +19154,7 @@ fn bindgen_test_layout__cef_download_item_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_download_item_t>())).is_in_progress as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_download_item_t, is_in_progress),
         48usize,
         concat!(
             "Offset of field: ",
@@ -20478,9 +19164,7 @@ fn bindgen_test_layout__cef_download_item_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_download_item_t>())).is_complete as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_download_item_t, is_complete),
         56usize,
         concat!(
             "Offset of field: ",
@@ -20490,9 +19174,7 @@ fn bindgen_test_layout__cef_download_item_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_download_item_t>())).is_canceled as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_download_item_t, is_canceled),
         64usize,
         concat!(
             "Offset of field: ",
@@ -20502,9 +19184,7 @@ fn bindgen_test_layout__cef_download_item_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_download_item_t>())).get_current_speed as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_download_item_t, get_current_speed),
         72usize,
         concat!(
             "Offset of field: ",
@@ -20514,10 +19194,7 @@ fn bindgen_test_layout__cef_download_item_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_download_item_t>())).get_percent_complete as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_download_item_t, get_percent_complete),
         80usize,
         concat!(
             "Offset of field: ",
@@ -20527,9 +19204,7 @@ fn bindgen_test_layout__cef_download_item_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_download_item_t>())).get_total_bytes as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_download_item_t, get_total_bytes),
         88usize,
         concat!(
             "Offset of field: ",
@@ -20539,9 +19214,7 @@ fn bindgen_test_layout__cef_download_item_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_download_item_t>())).get_received_bytes as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_download_item_t, get_received_bytes),
         96usize,
         concat!(
             "Offset of field: ",
@@ -20551,9 +19224,7 @@ fn bindgen_test_layout__cef_download_item_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_download_item_t>())).get_start_time as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_download_item_t, get_start_time),
         104usize,
         concat!(
             "Offset of field: ",
@@ -20563,9 +19234,7 @@ fn bindgen_test_layout__cef_download_item_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_download_item_t>())).get_end_time as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_download_item_t, get_end_time),
         112usize,
         concat!(
             "Offset of field: ",
@@ -20575,9 +19244,7 @@ fn bindgen_test_layout__cef_download_item_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_download_item_t>())).get_full_path as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_download_item_t, get_full_path),
         120usize,
         concat!(
             "Offset of field: ",
@@ -20587,7 +19254,7 @@ fn bindgen_test_layout__cef_download_item_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_download_item_t>())).get_id as *const _ as usize },
+        ::std::mem::offset_of!(_cef_download_item_t, get_id),
         128usize,
         concat!(
             "Offset of field: ",
@@ -20597,7 +19264,7 @@ fn bindgen_test_layout__cef_download_item_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_download_item_t>())).get_url as *const _ as usize },
+        ::std::mem::offset_of!(_cef_download_item_t, get_url),
         136usize,
         concat!(
             "Offset of field: ",
@@ -20607,9 +19274,7 @@ fn bindgen_test_layout__cef_download_item_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_download_item_t>())).get_original_url as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_download_item_t, get_original_url),
         144usize,
         concat!(
             "Offset of field: ",
@@ -20619,10 +19284,7 @@ fn bindgen_test_layout__cef_download_item_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_download_item_t>())).get_suggested_file_name as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_download_item_t, get_suggested_file_name),
         152usize,
         concat!(
             "Offset of field: ",
@@ -20632,10 +19294,7 @@ fn bindgen_test_layout__cef_download_item_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_download_item_t>())).get_content_disposition as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_download_item_t, get_content_disposition),
         160usize,
         concat!(
             "Offset of field: ",
@@ -20645,9 +19304,7 @@ fn bindgen_test_layout__cef_download_item_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_download_item_t>())).get_mime_type as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_download_item_t, get_mime_type),
         168usize,
         concat!(
             "Offset of field: ",
@@ -20683,9 +19340,7 @@ fn bindgen_test_layout__cef_before_download_callback_t() {
         concat!("Alignment of ", stringify!(_cef_before_download_callback_t))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_before_download_callback_t>())).base as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_before_download_callback_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -20695,9 +19350,7 @@ fn bindgen_test_layout__cef_before_download_callback_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_before_download_callback_t>())).cont as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_before_download_callback_t, cont),
         40usize,
         concat!(
             "Offset of field: ",
@@ -20732,9 +19385,7 @@ fn bindgen_test_layout__cef_download_item_callback_t() {
         concat!("Alignment of ", stringify!(_cef_download_item_callback_t))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_download_item_callback_t>())).base as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_download_item_callback_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -20744,9 +19395,7 @@ fn bindgen_test_layout__cef_download_item_callback_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_download_item_callback_t>())).cancel as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_download_item_callback_t, cancel),
         40usize,
         concat!(
             "Offset of field: ",
@@ -20756,9 +19405,7 @@ fn bindgen_test_layout__cef_download_item_callback_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_download_item_callback_t>())).pause as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_download_item_callback_t, pause),
         48usize,
         concat!(
             "Offset of field: ",
@@ -20768,9 +19415,7 @@ fn bindgen_test_layout__cef_download_item_callback_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_download_item_callback_t>())).resume as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_download_item_callback_t, resume),
         56usize,
         concat!(
             "Offset of field: ",
@@ -20816,7 +19461,7 @@ fn bindgen_test_layout__cef_download_handler_t() {
         concat!("Alignment of ", stringify!(_cef_download_handler_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_download_handler_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_download_handler_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -20826,10 +19471,7 @@ fn bindgen_test_layout__cef_download_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_download_handler_t>())).on_before_download as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_download_handler_t, on_before_download),
         40usize,
         concat!(
             "Offset of field: ",
@@ -20839,10 +19481,7 @@ fn bindgen_test_layout__cef_download_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_download_handler_t>())).on_download_updated as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_download_handler_t, on_download_updated),
         48usize,
         concat!(
             "Offset of field: ",
@@ -20888,7 +19527,7 @@ fn bindgen_test_layout__cef_drag_handler_t() {
         concat!("Alignment of ", stringify!(_cef_drag_handler_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_drag_handler_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_drag_handler_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -20898,9 +19537,7 @@ fn bindgen_test_layout__cef_drag_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_drag_handler_t>())).on_drag_enter as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_drag_handler_t, on_drag_enter),
         40usize,
         concat!(
             "Offset of field: ",
@@ -20910,10 +19547,7 @@ fn bindgen_test_layout__cef_drag_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_drag_handler_t>())).on_draggable_regions_changed as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_drag_handler_t, on_draggable_regions_changed),
         48usize,
         concat!(
             "Offset of field: ",
@@ -20953,7 +19587,7 @@ fn bindgen_test_layout__cef_find_handler_t() {
         concat!("Alignment of ", stringify!(_cef_find_handler_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_find_handler_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_find_handler_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -20963,9 +19597,7 @@ fn bindgen_test_layout__cef_find_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_find_handler_t>())).on_find_result as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_find_handler_t, on_find_result),
         40usize,
         concat!(
             "Offset of field: ",
@@ -21011,7 +19643,7 @@ fn bindgen_test_layout__cef_focus_handler_t() {
         concat!("Alignment of ", stringify!(_cef_focus_handler_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_focus_handler_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_focus_handler_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -21021,9 +19653,7 @@ fn bindgen_test_layout__cef_focus_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_focus_handler_t>())).on_take_focus as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_focus_handler_t, on_take_focus),
         40usize,
         concat!(
             "Offset of field: ",
@@ -21033,9 +19663,7 @@ fn bindgen_test_layout__cef_focus_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_focus_handler_t>())).on_set_focus as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_focus_handler_t, on_set_focus),
         48usize,
         concat!(
             "Offset of field: ",
@@ -21045,9 +19673,7 @@ fn bindgen_test_layout__cef_focus_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_focus_handler_t>())).on_got_focus as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_focus_handler_t, on_got_focus),
         56usize,
         concat!(
             "Offset of field: ",
@@ -21083,7 +19709,7 @@ fn bindgen_test_layout__cef_jsdialog_callback_t() {
         concat!("Alignment of ", stringify!(_cef_jsdialog_callback_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_jsdialog_callback_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_jsdialog_callback_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -21093,7 +19719,7 @@ fn bindgen_test_layout__cef_jsdialog_callback_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_jsdialog_callback_t>())).cont as *const _ as usize },
+        ::std::mem::offset_of!(_cef_jsdialog_callback_t, cont),
         40usize,
         concat!(
             "Offset of field: ",
@@ -21149,7 +19775,7 @@ fn bindgen_test_layout__cef_jsdialog_handler_t() {
         concat!("Alignment of ", stringify!(_cef_jsdialog_handler_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_jsdialog_handler_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_jsdialog_handler_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -21159,9 +19785,7 @@ fn bindgen_test_layout__cef_jsdialog_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_jsdialog_handler_t>())).on_jsdialog as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_jsdialog_handler_t, on_jsdialog),
         40usize,
         concat!(
             "Offset of field: ",
@@ -21171,10 +19795,7 @@ fn bindgen_test_layout__cef_jsdialog_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_jsdialog_handler_t>())).on_before_unload_dialog as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_jsdialog_handler_t, on_before_unload_dialog),
         48usize,
         concat!(
             "Offset of field: ",
@@ -21184,10 +19805,7 @@ fn bindgen_test_layout__cef_jsdialog_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_jsdialog_handler_t>())).on_reset_dialog_state as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_jsdialog_handler_t, on_reset_dialog_state),
         56usize,
         concat!(
             "Offset of field: ",
@@ -21197,10 +19815,7 @@ fn bindgen_test_layout__cef_jsdialog_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_jsdialog_handler_t>())).on_dialog_closed as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_jsdialog_handler_t, on_dialog_closed),
         64usize,
         concat!(
             "Offset of field: ",
@@ -21246,7 +19861,7 @@ fn bindgen_test_layout__cef_keyboard_handler_t() {
         concat!("Alignment of ", stringify!(_cef_keyboard_handler_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_keyboard_handler_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_keyboard_handler_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -21256,10 +19871,7 @@ fn bindgen_test_layout__cef_keyboard_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_keyboard_handler_t>())).on_pre_key_event as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_keyboard_handler_t, on_pre_key_event),
         40usize,
         concat!(
             "Offset of field: ",
@@ -21269,9 +19881,7 @@ fn bindgen_test_layout__cef_keyboard_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_keyboard_handler_t>())).on_key_event as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_keyboard_handler_t, on_key_event),
         48usize,
         concat!(
             "Offset of field: ",
@@ -21329,7 +19939,7 @@ fn bindgen_test_layout__cef_life_span_handler_t() {
         concat!("Alignment of ", stringify!(_cef_life_span_handler_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_life_span_handler_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_life_span_handler_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -21339,10 +19949,7 @@ fn bindgen_test_layout__cef_life_span_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_life_span_handler_t>())).on_before_popup as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_life_span_handler_t, on_before_popup),
         40usize,
         concat!(
             "Offset of field: ",
@@ -21352,10 +19959,7 @@ fn bindgen_test_layout__cef_life_span_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_life_span_handler_t>())).on_after_created as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_life_span_handler_t, on_after_created),
         48usize,
         concat!(
             "Offset of field: ",
@@ -21365,9 +19969,7 @@ fn bindgen_test_layout__cef_life_span_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_life_span_handler_t>())).do_close as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_life_span_handler_t, do_close),
         56usize,
         concat!(
             "Offset of field: ",
@@ -21377,10 +19979,7 @@ fn bindgen_test_layout__cef_life_span_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_life_span_handler_t>())).on_before_close as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_life_span_handler_t, on_before_close),
         64usize,
         concat!(
             "Offset of field: ",
@@ -21539,7 +20138,7 @@ fn bindgen_test_layout__cef_render_handler_t() {
         concat!("Alignment of ", stringify!(_cef_render_handler_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_render_handler_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_render_handler_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -21549,10 +20148,7 @@ fn bindgen_test_layout__cef_render_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_render_handler_t>())).get_accessibility_handler as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_render_handler_t, get_accessibility_handler),
         40usize,
         concat!(
             "Offset of field: ",
@@ -21562,10 +20158,7 @@ fn bindgen_test_layout__cef_render_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_render_handler_t>())).get_root_screen_rect as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_render_handler_t, get_root_screen_rect),
         48usize,
         concat!(
             "Offset of field: ",
@@ -21575,9 +20168,7 @@ fn bindgen_test_layout__cef_render_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_render_handler_t>())).get_view_rect as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_render_handler_t, get_view_rect),
         56usize,
         concat!(
             "Offset of field: ",
@@ -21587,9 +20178,7 @@ fn bindgen_test_layout__cef_render_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_render_handler_t>())).get_screen_point as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_render_handler_t, get_screen_point),
         64usize,
         concat!(
             "Offset of field: ",
@@ -21599,9 +20188,7 @@ fn bindgen_test_layout__cef_render_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_render_handler_t>())).get_screen_info as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_render_handler_t, get_screen_info),
         72usize,
         concat!(
             "Offset of field: ",
@@ -21611,9 +20198,7 @@ fn bindgen_test_layout__cef_render_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_render_handler_t>())).on_popup_show as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_render_handler_t, on_popup_show),
         80usize,
         concat!(
             "Offset of field: ",
@@ -21623,9 +20208,7 @@ fn bindgen_test_layout__cef_render_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_render_handler_t>())).on_popup_size as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_render_handler_t, on_popup_size),
         88usize,
         concat!(
             "Offset of field: ",
@@ -21635,7 +20218,7 @@ fn bindgen_test_layout__cef_render_handler_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_render_handler_t>())).on_paint as *const _ as usize },
+        ::std::mem::offset_of!(_cef_render_handler_t, on_paint),
         96usize,
         concat!(
             "Offset of field: ",
@@ -21645,10 +20228,7 @@ fn bindgen_test_layout__cef_render_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_render_handler_t>())).on_accelerated_paint as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_render_handler_t, on_accelerated_paint),
         104usize,
         concat!(
             "Offset of field: ",
@@ -21658,9 +20238,7 @@ fn bindgen_test_layout__cef_render_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_render_handler_t>())).on_cursor_change as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_render_handler_t, on_cursor_change),
         112usize,
         concat!(
             "Offset of field: ",
@@ -21670,9 +20248,7 @@ fn bindgen_test_layout__cef_render_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_render_handler_t>())).start_dragging as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_render_handler_t, start_dragging),
         120usize,
         concat!(
             "Offset of field: ",
@@ -21682,10 +20258,7 @@ fn bindgen_test_layout__cef_render_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_render_handler_t>())).update_drag_cursor as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_render_handler_t, update_drag_cursor),
         128usize,
         concat!(
             "Offset of field: ",
@@ -21695,10 +20268,7 @@ fn bindgen_test_layout__cef_render_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_render_handler_t>())).on_scroll_offset_changed as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_render_handler_t, on_scroll_offset_changed),
         136usize,
         concat!(
             "Offset of field: ",
@@ -21708,10 +20278,7 @@ fn bindgen_test_layout__cef_render_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_render_handler_t>())).on_ime_composition_range_changed
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_render_handler_t, on_ime_composition_range_changed),
         144usize,
         concat!(
             "Offset of field: ",
@@ -21721,10 +20288,7 @@ fn bindgen_test_layout__cef_render_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_render_handler_t>())).on_text_selection_changed as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_render_handler_t, on_text_selection_changed),
         152usize,
         concat!(
             "Offset of field: ",
@@ -21734,10 +20298,7 @@ fn bindgen_test_layout__cef_render_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_render_handler_t>())).on_virtual_keyboard_requested
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_render_handler_t, on_virtual_keyboard_requested),
         160usize,
         concat!(
             "Offset of field: ",
@@ -21770,7 +20331,7 @@ fn bindgen_test_layout__cef_request_callback_t() {
         concat!("Alignment of ", stringify!(_cef_request_callback_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_request_callback_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_request_callback_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -21780,7 +20341,7 @@ fn bindgen_test_layout__cef_request_callback_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_request_callback_t>())).cont as *const _ as usize },
+        ::std::mem::offset_of!(_cef_request_callback_t, cont),
         40usize,
         concat!(
             "Offset of field: ",
@@ -21790,7 +20351,7 @@ fn bindgen_test_layout__cef_request_callback_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_request_callback_t>())).cancel as *const _ as usize },
+        ::std::mem::offset_of!(_cef_request_callback_t, cancel),
         48usize,
         concat!(
             "Offset of field: ",
@@ -21833,7 +20394,7 @@ fn bindgen_test_layout__cef_response_filter_t() {
         concat!("Alignment of ", stringify!(_cef_response_filter_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_response_filter_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_response_filter_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -21843,9 +20404,7 @@ fn bindgen_test_layout__cef_response_filter_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_response_filter_t>())).init_filter as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_response_filter_t, init_filter),
         40usize,
         concat!(
             "Offset of field: ",
@@ -21855,7 +20414,7 @@ fn bindgen_test_layout__cef_response_filter_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_response_filter_t>())).filter as *const _ as usize },
+        ::std::mem::offset_of!(_cef_response_filter_t, filter),
         48usize,
         concat!(
             "Offset of field: ",
@@ -21957,9 +20516,7 @@ fn bindgen_test_layout__cef_resource_request_handler_t() {
         concat!("Alignment of ", stringify!(_cef_resource_request_handler_t))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_resource_request_handler_t>())).base as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_resource_request_handler_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -21969,10 +20526,7 @@ fn bindgen_test_layout__cef_resource_request_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_resource_request_handler_t>())).get_cookie_access_filter
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_resource_request_handler_t, get_cookie_access_filter),
         40usize,
         concat!(
             "Offset of field: ",
@@ -21982,10 +20536,7 @@ fn bindgen_test_layout__cef_resource_request_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_resource_request_handler_t>())).on_before_resource_load
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_resource_request_handler_t, on_before_resource_load),
         48usize,
         concat!(
             "Offset of field: ",
@@ -21995,10 +20546,7 @@ fn bindgen_test_layout__cef_resource_request_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_resource_request_handler_t>())).get_resource_handler
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_resource_request_handler_t, get_resource_handler),
         56usize,
         concat!(
             "Offset of field: ",
@@ -22008,10 +20556,7 @@ fn bindgen_test_layout__cef_resource_request_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_resource_request_handler_t>())).on_resource_redirect
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_resource_request_handler_t, on_resource_redirect),
         64usize,
         concat!(
             "Offset of field: ",
@@ -22021,10 +20566,7 @@ fn bindgen_test_layout__cef_resource_request_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_resource_request_handler_t>())).on_resource_response
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_resource_request_handler_t, on_resource_response),
         72usize,
         concat!(
             "Offset of field: ",
@@ -22034,10 +20576,7 @@ fn bindgen_test_layout__cef_resource_request_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_resource_request_handler_t>())).get_resource_response_filter
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_resource_request_handler_t, get_resource_response_filter),
         80usize,
         concat!(
             "Offset of field: ",
@@ -22047,10 +20586,7 @@ fn bindgen_test_layout__cef_resource_request_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_resource_request_handler_t>())).on_resource_load_complete
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_resource_request_handler_t, on_resource_load_complete),
         88usize,
         concat!(
             "Offset of field: ",
@@ -22060,10 +20596,7 @@ fn bindgen_test_layout__cef_resource_request_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_resource_request_handler_t>())).on_protocol_execution
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_resource_request_handler_t, on_protocol_execution),
         96usize,
         concat!(
             "Offset of field: ",
@@ -22111,9 +20644,7 @@ fn bindgen_test_layout__cef_cookie_access_filter_t() {
         concat!("Alignment of ", stringify!(_cef_cookie_access_filter_t))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_cookie_access_filter_t>())).base as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_cookie_access_filter_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -22123,10 +20654,7 @@ fn bindgen_test_layout__cef_cookie_access_filter_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_cookie_access_filter_t>())).can_send_cookie as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_cookie_access_filter_t, can_send_cookie),
         40usize,
         concat!(
             "Offset of field: ",
@@ -22136,10 +20664,7 @@ fn bindgen_test_layout__cef_cookie_access_filter_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_cookie_access_filter_t>())).can_save_cookie as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_cookie_access_filter_t, can_save_cookie),
         48usize,
         concat!(
             "Offset of field: ",
@@ -22174,7 +20699,7 @@ fn bindgen_test_layout__cef_sslinfo_t() {
         concat!("Alignment of ", stringify!(_cef_sslinfo_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_sslinfo_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_sslinfo_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -22184,7 +20709,7 @@ fn bindgen_test_layout__cef_sslinfo_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_sslinfo_t>())).get_cert_status as *const _ as usize },
+        ::std::mem::offset_of!(_cef_sslinfo_t, get_cert_status),
         40usize,
         concat!(
             "Offset of field: ",
@@ -22194,9 +20719,7 @@ fn bindgen_test_layout__cef_sslinfo_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_sslinfo_t>())).get_x509certificate as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_sslinfo_t, get_x509certificate),
         48usize,
         concat!(
             "Offset of field: ",
@@ -22240,10 +20763,7 @@ fn bindgen_test_layout__cef_select_client_certificate_callback_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_select_client_certificate_callback_t>())).base as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_select_client_certificate_callback_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -22253,10 +20773,7 @@ fn bindgen_test_layout__cef_select_client_certificate_callback_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_select_client_certificate_callback_t>())).select as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_select_client_certificate_callback_t, select),
         40usize,
         concat!(
             "Offset of field: ",
@@ -22381,7 +20898,7 @@ fn bindgen_test_layout__cef_request_handler_t() {
         concat!("Alignment of ", stringify!(_cef_request_handler_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_request_handler_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_request_handler_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -22391,9 +20908,7 @@ fn bindgen_test_layout__cef_request_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_request_handler_t>())).on_before_browse as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_request_handler_t, on_before_browse),
         40usize,
         concat!(
             "Offset of field: ",
@@ -22403,10 +20918,7 @@ fn bindgen_test_layout__cef_request_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_request_handler_t>())).on_open_urlfrom_tab as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_request_handler_t, on_open_urlfrom_tab),
         48usize,
         concat!(
             "Offset of field: ",
@@ -22416,10 +20928,7 @@ fn bindgen_test_layout__cef_request_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_request_handler_t>())).get_resource_request_handler
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_request_handler_t, get_resource_request_handler),
         56usize,
         concat!(
             "Offset of field: ",
@@ -22429,10 +20938,7 @@ fn bindgen_test_layout__cef_request_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_request_handler_t>())).get_auth_credentials as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_request_handler_t, get_auth_credentials),
         64usize,
         concat!(
             "Offset of field: ",
@@ -22442,9 +20948,7 @@ fn bindgen_test_layout__cef_request_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_request_handler_t>())).on_quota_request as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_request_handler_t, on_quota_request),
         72usize,
         concat!(
             "Offset of field: ",
@@ -22454,10 +20958,7 @@ fn bindgen_test_layout__cef_request_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_request_handler_t>())).on_certificate_error as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_request_handler_t, on_certificate_error),
         80usize,
         concat!(
             "Offset of field: ",
@@ -22467,10 +20968,7 @@ fn bindgen_test_layout__cef_request_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_request_handler_t>())).on_select_client_certificate
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_request_handler_t, on_select_client_certificate),
         88usize,
         concat!(
             "Offset of field: ",
@@ -22480,10 +20978,7 @@ fn bindgen_test_layout__cef_request_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_request_handler_t>())).on_plugin_crashed as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_request_handler_t, on_plugin_crashed),
         96usize,
         concat!(
             "Offset of field: ",
@@ -22493,10 +20988,7 @@ fn bindgen_test_layout__cef_request_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_request_handler_t>())).on_render_view_ready as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_request_handler_t, on_render_view_ready),
         104usize,
         concat!(
             "Offset of field: ",
@@ -22506,10 +20998,7 @@ fn bindgen_test_layout__cef_request_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_request_handler_t>())).on_render_process_terminated
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_request_handler_t, on_render_process_terminated),
         112usize,
         concat!(
             "Offset of field: ",
@@ -22519,10 +21008,7 @@ fn bindgen_test_layout__cef_request_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_request_handler_t>())).on_document_available_in_main_frame
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_request_handler_t, on_document_available_in_main_frame),
         120usize,
         concat!(
             "Offset of field: ",
@@ -22602,7 +21088,7 @@ fn bindgen_test_layout__cef_client_t() {
         concat!("Alignment of ", stringify!(_cef_client_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_client_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_client_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -22612,7 +21098,7 @@ fn bindgen_test_layout__cef_client_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_client_t>())).get_audio_handler as *const _ as usize },
+        ::std::mem::offset_of!(_cef_client_t, get_audio_handler),
         40usize,
         concat!(
             "Offset of field: ",
@@ -22622,9 +21108,7 @@ fn bindgen_test_layout__cef_client_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_client_t>())).get_context_menu_handler as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_client_t, get_context_menu_handler),
         48usize,
         concat!(
             "Offset of field: ",
@@ -22634,9 +21118,7 @@ fn bindgen_test_layout__cef_client_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_client_t>())).get_dialog_handler as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_client_t, get_dialog_handler),
         56usize,
         concat!(
             "Offset of field: ",
@@ -22646,9 +21128,7 @@ fn bindgen_test_layout__cef_client_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_client_t>())).get_display_handler as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_client_t, get_display_handler),
         64usize,
         concat!(
             "Offset of field: ",
@@ -22658,9 +21138,7 @@ fn bindgen_test_layout__cef_client_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_client_t>())).get_download_handler as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_client_t, get_download_handler),
         72usize,
         concat!(
             "Offset of field: ",
@@ -22670,7 +21148,7 @@ fn bindgen_test_layout__cef_client_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_client_t>())).get_drag_handler as *const _ as usize },
+        ::std::mem::offset_of!(_cef_client_t, get_drag_handler),
         80usize,
         concat!(
             "Offset of field: ",
@@ -22680,7 +21158,7 @@ fn bindgen_test_layout__cef_client_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_client_t>())).get_find_handler as *const _ as usize },
+        ::std::mem::offset_of!(_cef_client_t, get_find_handler),
         88usize,
         concat!(
             "Offset of field: ",
@@ -22690,7 +21168,7 @@ fn bindgen_test_layout__cef_client_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_client_t>())).get_focus_handler as *const _ as usize },
+        ::std::mem::offset_of!(_cef_client_t, get_focus_handler),
         96usize,
         concat!(
             "Offset of field: ",
@@ -22700,9 +21178,7 @@ fn bindgen_test_layout__cef_client_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_client_t>())).get_jsdialog_handler as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_client_t, get_jsdialog_handler),
         104usize,
         concat!(
             "Offset of field: ",
@@ -22712,9 +21188,7 @@ fn bindgen_test_layout__cef_client_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_client_t>())).get_keyboard_handler as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_client_t, get_keyboard_handler),
         112usize,
         concat!(
             "Offset of field: ",
@@ -22724,9 +21198,7 @@ fn bindgen_test_layout__cef_client_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_client_t>())).get_life_span_handler as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_client_t, get_life_span_handler),
         120usize,
         concat!(
             "Offset of field: ",
@@ -22736,7 +21208,7 @@ fn bindgen_test_layout__cef_client_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_client_t>())).get_load_handler as *const _ as usize },
+        ::std::mem::offset_of!(_cef_client_t, get_load_handler),
         128usize,
         concat!(
             "Offset of field: ",
@@ -22746,9 +21218,7 @@ fn bindgen_test_layout__cef_client_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_client_t>())).get_render_handler as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_client_t, get_render_handler),
         136usize,
         concat!(
             "Offset of field: ",
@@ -22758,9 +21228,7 @@ fn bindgen_test_layout__cef_client_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_client_t>())).get_request_handler as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_client_t, get_request_handler),
         144usize,
         concat!(
             "Offset of field: ",
@@ -22770,10 +21238,7 @@ fn bindgen_test_layout__cef_client_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_client_t>())).on_process_message_received as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_client_t, on_process_message_received),
         152usize,
         concat!(
             "Offset of field: ",
@@ -22961,7 +21426,7 @@ fn bindgen_test_layout__cef_web_plugin_info_t() {
         concat!("Alignment of ", stringify!(_cef_web_plugin_info_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_web_plugin_info_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_web_plugin_info_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -22971,7 +21436,7 @@ fn bindgen_test_layout__cef_web_plugin_info_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_web_plugin_info_t>())).get_name as *const _ as usize },
+        ::std::mem::offset_of!(_cef_web_plugin_info_t, get_name),
         40usize,
         concat!(
             "Offset of field: ",
@@ -22981,7 +21446,7 @@ fn bindgen_test_layout__cef_web_plugin_info_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_web_plugin_info_t>())).get_path as *const _ as usize },
+        ::std::mem::offset_of!(_cef_web_plugin_info_t, get_path),
         48usize,
         concat!(
             "Offset of field: ",
@@ -22991,9 +21456,7 @@ fn bindgen_test_layout__cef_web_plugin_info_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_web_plugin_info_t>())).get_version as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_web_plugin_info_t, get_version),
         56usize,
         concat!(
             "Offset of field: ",
@@ -23003,9 +21466,7 @@ fn bindgen_test_layout__cef_web_plugin_info_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_web_plugin_info_t>())).get_description as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_web_plugin_info_t, get_description),
         64usize,
         concat!(
             "Offset of field: ",
@@ -23042,9 +21503,7 @@ fn bindgen_test_layout__cef_web_plugin_info_visitor_t() {
         concat!("Alignment of ", stringify!(_cef_web_plugin_info_visitor_t))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_web_plugin_info_visitor_t>())).base as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_web_plugin_info_visitor_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -23054,9 +21513,7 @@ fn bindgen_test_layout__cef_web_plugin_info_visitor_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_web_plugin_info_visitor_t>())).visit as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_web_plugin_info_visitor_t, visit),
         40usize,
         concat!(
             "Offset of field: ",
@@ -23095,10 +21552,7 @@ fn bindgen_test_layout__cef_web_plugin_unstable_callback_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_web_plugin_unstable_callback_t>())).base as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_web_plugin_unstable_callback_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -23108,10 +21562,7 @@ fn bindgen_test_layout__cef_web_plugin_unstable_callback_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_web_plugin_unstable_callback_t>())).is_unstable as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_web_plugin_unstable_callback_t, is_unstable),
         40usize,
         concat!(
             "Offset of field: ",
@@ -23147,9 +21598,7 @@ fn bindgen_test_layout__cef_register_cdm_callback_t() {
         concat!("Alignment of ", stringify!(_cef_register_cdm_callback_t))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_register_cdm_callback_t>())).base as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_register_cdm_callback_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -23159,10 +21608,7 @@ fn bindgen_test_layout__cef_register_cdm_callback_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_register_cdm_callback_t>())).on_cdm_registration_complete
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_register_cdm_callback_t, on_cdm_registration_complete),
         40usize,
         concat!(
             "Offset of field: ",
@@ -23244,9 +21690,7 @@ fn bindgen_test_layout__cef_request_context_handler_t() {
         concat!("Alignment of ", stringify!(_cef_request_context_handler_t))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_request_context_handler_t>())).base as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_request_context_handler_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -23256,10 +21700,7 @@ fn bindgen_test_layout__cef_request_context_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_request_context_handler_t>()))
-                .on_request_context_initialized as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_request_context_handler_t, on_request_context_initialized),
         40usize,
         concat!(
             "Offset of field: ",
@@ -23269,10 +21710,7 @@ fn bindgen_test_layout__cef_request_context_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_request_context_handler_t>())).on_before_plugin_load
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_request_context_handler_t, on_before_plugin_load),
         48usize,
         concat!(
             "Offset of field: ",
@@ -23282,10 +21720,7 @@ fn bindgen_test_layout__cef_request_context_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_request_context_handler_t>())).get_resource_request_handler
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_request_context_handler_t, get_resource_request_handler),
         56usize,
         concat!(
             "Offset of field: ",
@@ -23337,7 +21772,7 @@ fn bindgen_test_layout__cef_resource_bundle_t() {
         concat!("Alignment of ", stringify!(_cef_resource_bundle_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_resource_bundle_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_resource_bundle_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -23347,10 +21782,7 @@ fn bindgen_test_layout__cef_resource_bundle_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_resource_bundle_t>())).get_localized_string as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_resource_bundle_t, get_localized_string),
         40usize,
         concat!(
             "Offset of field: ",
@@ -23360,10 +21792,7 @@ fn bindgen_test_layout__cef_resource_bundle_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_resource_bundle_t>())).get_data_resource as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_resource_bundle_t, get_data_resource),
         48usize,
         concat!(
             "Offset of field: ",
@@ -23373,10 +21802,7 @@ fn bindgen_test_layout__cef_resource_bundle_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_resource_bundle_t>())).get_data_resource_for_scale
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_resource_bundle_t, get_data_resource_for_scale),
         56usize,
         concat!(
             "Offset of field: ",
@@ -23475,7 +21901,7 @@ fn bindgen_test_layout__cef_server_t() {
         concat!("Alignment of ", stringify!(_cef_server_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_server_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_server_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -23485,7 +21911,7 @@ fn bindgen_test_layout__cef_server_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_server_t>())).get_task_runner as *const _ as usize },
+        ::std::mem::offset_of!(_cef_server_t, get_task_runner),
         40usize,
         concat!(
             "Offset of field: ",
@@ -23495,7 +21921,7 @@ fn bindgen_test_layout__cef_server_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_server_t>())).shutdown as *const _ as usize },
+        ::std::mem::offset_of!(_cef_server_t, shutdown),
         48usize,
         concat!(
             "Offset of field: ",
@@ -23505,7 +21931,7 @@ fn bindgen_test_layout__cef_server_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_server_t>())).is_running as *const _ as usize },
+        ::std::mem::offset_of!(_cef_server_t, is_running),
         56usize,
         concat!(
             "Offset of field: ",
@@ -23515,7 +21941,7 @@ fn bindgen_test_layout__cef_server_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_server_t>())).get_address as *const _ as usize },
+        ::std::mem::offset_of!(_cef_server_t, get_address),
         64usize,
         concat!(
             "Offset of field: ",
@@ -23525,7 +21951,7 @@ fn bindgen_test_layout__cef_server_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_server_t>())).has_connection as *const _ as usize },
+        ::std::mem::offset_of!(_cef_server_t, has_connection),
         72usize,
         concat!(
             "Offset of field: ",
@@ -23535,9 +21961,7 @@ fn bindgen_test_layout__cef_server_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_server_t>())).is_valid_connection as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_server_t, is_valid_connection),
         80usize,
         concat!(
             "Offset of field: ",
@@ -23547,9 +21971,7 @@ fn bindgen_test_layout__cef_server_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_server_t>())).send_http200response as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_server_t, send_http200response),
         88usize,
         concat!(
             "Offset of field: ",
@@ -23559,9 +21981,7 @@ fn bindgen_test_layout__cef_server_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_server_t>())).send_http404response as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_server_t, send_http404response),
         96usize,
         concat!(
             "Offset of field: ",
@@ -23571,9 +21991,7 @@ fn bindgen_test_layout__cef_server_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_server_t>())).send_http500response as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_server_t, send_http500response),
         104usize,
         concat!(
             "Offset of field: ",
@@ -23583,9 +22001,7 @@ fn bindgen_test_layout__cef_server_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_server_t>())).send_http_response as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_server_t, send_http_response),
         112usize,
         concat!(
             "Offset of field: ",
@@ -23595,7 +22011,7 @@ fn bindgen_test_layout__cef_server_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_server_t>())).send_raw_data as *const _ as usize },
+        ::std::mem::offset_of!(_cef_server_t, send_raw_data),
         120usize,
         concat!(
             "Offset of field: ",
@@ -23605,7 +22021,7 @@ fn bindgen_test_layout__cef_server_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_server_t>())).close_connection as *const _ as usize },
+        ::std::mem::offset_of!(_cef_server_t, close_connection),
         128usize,
         concat!(
             "Offset of field: ",
@@ -23615,9 +22031,7 @@ fn bindgen_test_layout__cef_server_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_server_t>())).send_web_socket_message as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_server_t, send_web_socket_message),
         136usize,
         concat!(
             "Offset of field: ",
@@ -23709,7 +22123,7 @@ fn bindgen_test_layout__cef_server_handler_t() {
         concat!("Alignment of ", stringify!(_cef_server_handler_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_server_handler_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_server_handler_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -23719,9 +22133,7 @@ fn bindgen_test_layout__cef_server_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_server_handler_t>())).on_server_created as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_server_handler_t, on_server_created),
         40usize,
         concat!(
             "Offset of field: ",
@@ -23731,10 +22143,7 @@ fn bindgen_test_layout__cef_server_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_server_handler_t>())).on_server_destroyed as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_server_handler_t, on_server_destroyed),
         48usize,
         concat!(
             "Offset of field: ",
@@ -23744,10 +22153,7 @@ fn bindgen_test_layout__cef_server_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_server_handler_t>())).on_client_connected as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_server_handler_t, on_client_connected),
         56usize,
         concat!(
             "Offset of field: ",
@@ -23757,10 +22163,7 @@ fn bindgen_test_layout__cef_server_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_server_handler_t>())).on_client_disconnected as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_server_handler_t, on_client_disconnected),
         64usize,
         concat!(
             "Offset of field: ",
@@ -23770,9 +22173,7 @@ fn bindgen_test_layout__cef_server_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_server_handler_t>())).on_http_request as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_server_handler_t, on_http_request),
         72usize,
         concat!(
             "Offset of field: ",
@@ -23782,10 +22183,7 @@ fn bindgen_test_layout__cef_server_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_server_handler_t>())).on_web_socket_request as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_server_handler_t, on_web_socket_request),
         80usize,
         concat!(
             "Offset of field: ",
@@ -23795,10 +22193,7 @@ fn bindgen_test_layout__cef_server_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_server_handler_t>())).on_web_socket_connected as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_server_handler_t, on_web_socket_connected),
         88usize,
         concat!(
             "Offset of field: ",
@@ -23808,10 +22203,7 @@ fn bindgen_test_layout__cef_server_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_server_handler_t>())).on_web_socket_message as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_server_handler_t, on_web_socket_message),
         96usize,
         concat!(
             "Offset of field: ",
@@ -23860,7 +22252,7 @@ fn bindgen_test_layout__cef_thread_t() {
         concat!("Alignment of ", stringify!(_cef_thread_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_thread_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_thread_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -23870,7 +22262,7 @@ fn bindgen_test_layout__cef_thread_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_thread_t>())).get_task_runner as *const _ as usize },
+        ::std::mem::offset_of!(_cef_thread_t, get_task_runner),
         40usize,
         concat!(
             "Offset of field: ",
@@ -23880,9 +22272,7 @@ fn bindgen_test_layout__cef_thread_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_thread_t>())).get_platform_thread_id as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_thread_t, get_platform_thread_id),
         48usize,
         concat!(
             "Offset of field: ",
@@ -23892,7 +22282,7 @@ fn bindgen_test_layout__cef_thread_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_thread_t>())).stop as *const _ as usize },
+        ::std::mem::offset_of!(_cef_thread_t, stop),
         56usize,
         concat!(
             "Offset of field: ",
@@ -23902,7 +22292,7 @@ fn bindgen_test_layout__cef_thread_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_thread_t>())).is_running as *const _ as usize },
+        ::std::mem::offset_of!(_cef_thread_t, is_running),
         64usize,
         concat!(
             "Offset of field: ",
@@ -23946,9 +22336,7 @@ fn bindgen_test_layout__cef_end_tracing_callback_t() {
         concat!("Alignment of ", stringify!(_cef_end_tracing_callback_t))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_end_tracing_callback_t>())).base as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_end_tracing_callback_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -23958,10 +22346,7 @@ fn bindgen_test_layout__cef_end_tracing_callback_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_end_tracing_callback_t>())).on_end_tracing_complete
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_end_tracing_callback_t, on_end_tracing_complete),
         40usize,
         concat!(
             "Offset of field: ",
@@ -24024,7 +22409,7 @@ fn bindgen_test_layout__cef_urlrequest_t() {
         concat!("Alignment of ", stringify!(_cef_urlrequest_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_urlrequest_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_urlrequest_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -24034,7 +22419,7 @@ fn bindgen_test_layout__cef_urlrequest_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_urlrequest_t>())).get_request as *const _ as usize },
+        ::std::mem::offset_of!(_cef_urlrequest_t, get_request),
         40usize,
         concat!(
             "Offset of field: ",
@@ -24044,7 +22429,7 @@ fn bindgen_test_layout__cef_urlrequest_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_urlrequest_t>())).get_client as *const _ as usize },
+        ::std::mem::offset_of!(_cef_urlrequest_t, get_client),
         48usize,
         concat!(
             "Offset of field: ",
@@ -24054,9 +22439,7 @@ fn bindgen_test_layout__cef_urlrequest_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_urlrequest_t>())).get_request_status as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_urlrequest_t, get_request_status),
         56usize,
         concat!(
             "Offset of field: ",
@@ -24066,9 +22449,7 @@ fn bindgen_test_layout__cef_urlrequest_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_urlrequest_t>())).get_request_error as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_urlrequest_t, get_request_error),
         64usize,
         concat!(
             "Offset of field: ",
@@ -24078,7 +22459,7 @@ fn bindgen_test_layout__cef_urlrequest_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_urlrequest_t>())).get_response as *const _ as usize },
+        ::std::mem::offset_of!(_cef_urlrequest_t, get_response),
         72usize,
         concat!(
             "Offset of field: ",
@@ -24088,9 +22469,7 @@ fn bindgen_test_layout__cef_urlrequest_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_urlrequest_t>())).response_was_cached as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_urlrequest_t, response_was_cached),
         80usize,
         concat!(
             "Offset of field: ",
@@ -24100,7 +22479,7 @@ fn bindgen_test_layout__cef_urlrequest_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_urlrequest_t>())).cancel as *const _ as usize },
+        ::std::mem::offset_of!(_cef_urlrequest_t, cancel),
         88usize,
         concat!(
             "Offset of field: ",
@@ -24174,7 +22553,7 @@ fn bindgen_test_layout__cef_urlrequest_client_t() {
         concat!("Alignment of ", stringify!(_cef_urlrequest_client_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_urlrequest_client_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_urlrequest_client_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -24184,10 +22563,7 @@ fn bindgen_test_layout__cef_urlrequest_client_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_urlrequest_client_t>())).on_request_complete as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_urlrequest_client_t, on_request_complete),
         40usize,
         concat!(
             "Offset of field: ",
@@ -24197,10 +22573,7 @@ fn bindgen_test_layout__cef_urlrequest_client_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_urlrequest_client_t>())).on_upload_progress as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_urlrequest_client_t, on_upload_progress),
         48usize,
         concat!(
             "Offset of field: ",
@@ -24210,10 +22583,7 @@ fn bindgen_test_layout__cef_urlrequest_client_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_urlrequest_client_t>())).on_download_progress as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_urlrequest_client_t, on_download_progress),
         56usize,
         concat!(
             "Offset of field: ",
@@ -24223,10 +22593,7 @@ fn bindgen_test_layout__cef_urlrequest_client_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_urlrequest_client_t>())).on_download_data as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_urlrequest_client_t, on_download_data),
         64usize,
         concat!(
             "Offset of field: ",
@@ -24236,10 +22603,7 @@ fn bindgen_test_layout__cef_urlrequest_client_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_urlrequest_client_t>())).get_auth_credentials as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_urlrequest_client_t, get_auth_credentials),
         72usize,
         concat!(
             "Offset of field: ",
@@ -24280,7 +22644,7 @@ fn bindgen_test_layout__cef_waitable_event_t() {
         concat!("Alignment of ", stringify!(_cef_waitable_event_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_waitable_event_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_waitable_event_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -24290,7 +22654,7 @@ fn bindgen_test_layout__cef_waitable_event_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_waitable_event_t>())).reset as *const _ as usize },
+        ::std::mem::offset_of!(_cef_waitable_event_t, reset),
         40usize,
         concat!(
             "Offset of field: ",
@@ -24300,7 +22664,7 @@ fn bindgen_test_layout__cef_waitable_event_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_waitable_event_t>())).signal as *const _ as usize },
+        ::std::mem::offset_of!(_cef_waitable_event_t, signal),
         48usize,
         concat!(
             "Offset of field: ",
@@ -24310,9 +22674,7 @@ fn bindgen_test_layout__cef_waitable_event_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_waitable_event_t>())).is_signaled as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_waitable_event_t, is_signaled),
         56usize,
         concat!(
             "Offset of field: ",
@@ -24322,7 +22684,7 @@ fn bindgen_test_layout__cef_waitable_event_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_waitable_event_t>())).wait as *const _ as usize },
+        ::std::mem::offset_of!(_cef_waitable_event_t, wait),
         64usize,
         concat!(
             "Offset of field: ",
@@ -24332,9 +22694,7 @@ fn bindgen_test_layout__cef_waitable_event_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_waitable_event_t>())).timed_wait as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_waitable_event_t, timed_wait),
         72usize,
         concat!(
             "Offset of field: ",
@@ -24475,7 +22835,7 @@ fn bindgen_test_layout__cef_xml_reader_t() {
         concat!("Alignment of ", stringify!(_cef_xml_reader_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_xml_reader_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_xml_reader_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -24485,9 +22845,7 @@ fn bindgen_test_layout__cef_xml_reader_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_xml_reader_t>())).move_to_next_node as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_xml_reader_t, move_to_next_node),
         40usize,
         concat!(
             "Offset of field: ",
@@ -24497,7 +22855,7 @@ fn bindgen_test_layout__cef_xml_reader_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_xml_reader_t>())).close as *const _ as usize },
+        ::std::mem::offset_of!(_cef_xml_reader_t, close),
         48usize,
         concat!(
             "Offset of field: ",
@@ -24507,7 +22865,7 @@ fn bindgen_test_layout__cef_xml_reader_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_xml_reader_t>())).has_error as *const _ as usize },
+        ::std::mem::offset_of!(_cef_xml_reader_t, has_error),
         56usize,
         concat!(
             "Offset of field: ",
@@ -24517,7 +22875,7 @@ fn bindgen_test_layout__cef_xml_reader_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_xml_reader_t>())).get_error as *const _ as usize },
+        ::std::mem::offset_of!(_cef_xml_reader_t, get_error),
         64usize,
         concat!(
             "Offset of field: ",
@@ -24527,7 +22885,7 @@ fn bindgen_test_layout__cef_xml_reader_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_xml_reader_t>())).get_type as *const _ as usize },
+        ::std::mem::offset_of!(_cef_xml_reader_t, get_type),
         72usize,
         concat!(
             "Offset of field: ",
@@ -24537,7 +22895,7 @@ fn bindgen_test_layout__cef_xml_reader_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_xml_reader_t>())).get_depth as *const _ as usize },
+        ::std::mem::offset_of!(_cef_xml_reader_t, get_depth),
         80usize,
         concat!(
             "Offset of field: ",
@@ -24547,9 +22905,7 @@ fn bindgen_test_layout__cef_xml_reader_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_xml_reader_t>())).get_local_name as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_xml_reader_t, get_local_name),
         88usize,
         concat!(
             "Offset of field: ",
@@ -24559,7 +22915,7 @@ fn bindgen_test_layout__cef_xml_reader_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_xml_reader_t>())).get_prefix as *const _ as usize },
+        ::std::mem::offset_of!(_cef_xml_reader_t, get_prefix),
         96usize,
         concat!(
             "Offset of field: ",
@@ -24569,9 +22925,7 @@ fn bindgen_test_layout__cef_xml_reader_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_xml_reader_t>())).get_qualified_name as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_xml_reader_t, get_qualified_name),
         104usize,
         concat!(
             "Offset of field: ",
@@ -24581,9 +22935,7 @@ fn bindgen_test_layout__cef_xml_reader_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_xml_reader_t>())).get_namespace_uri as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_xml_reader_t, get_namespace_uri),
         112usize,
         concat!(
             "Offset of field: ",
@@ -24593,7 +22945,7 @@ fn bindgen_test_layout__cef_xml_reader_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_xml_reader_t>())).get_base_uri as *const _ as usize },
+        ::std::mem::offset_of!(_cef_xml_reader_t, get_base_uri),
         120usize,
         concat!(
             "Offset of field: ",
@@ -24603,7 +22955,7 @@ fn bindgen_test_layout__cef_xml_reader_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_xml_reader_t>())).get_xml_lang as *const _ as usize },
+        ::std::mem::offset_of!(_cef_xml_reader_t, get_xml_lang),
         128usize,
         concat!(
             "Offset of field: ",
@@ -24613,9 +22965,7 @@ fn bindgen_test_layout__cef_xml_reader_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_xml_reader_t>())).is_empty_element as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_xml_reader_t, is_empty_element),
         136usize,
         concat!(
             "Offset of field: ",
@@ -24625,7 +22975,7 @@ fn bindgen_test_layout__cef_xml_reader_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_xml_reader_t>())).has_value as *const _ as usize },
+        ::std::mem::offset_of!(_cef_xml_reader_t, has_value),
         144usize,
         concat!(
             "Offset of field: ",
@@ -24635,7 +22985,7 @@ fn bindgen_test_layout__cef_xml_reader_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_xml_reader_t>())).get_value as *const _ as usize },
+        ::std::mem::offset_of!(_cef_xml_reader_t, get_value),
         152usize,
         concat!(
             "Offset of field: ",
@@ -24645,9 +22995,7 @@ fn bindgen_test_layout__cef_xml_reader_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_xml_reader_t>())).has_attributes as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_xml_reader_t, has_attributes),
         160usize,
         concat!(
             "Offset of field: ",
@@ -24657,9 +23005,7 @@ fn bindgen_test_layout__cef_xml_reader_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_xml_reader_t>())).get_attribute_count as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_xml_reader_t, get_attribute_count),
         168usize,
         concat!(
             "Offset of field: ",
@@ -24669,9 +23015,7 @@ fn bindgen_test_layout__cef_xml_reader_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_xml_reader_t>())).get_attribute_byindex as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_xml_reader_t, get_attribute_byindex),
         176usize,
         concat!(
             "Offset of field: ",
@@ -24681,9 +23025,7 @@ fn bindgen_test_layout__cef_xml_reader_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_xml_reader_t>())).get_attribute_byqname as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_xml_reader_t, get_attribute_byqname),
         184usize,
         concat!(
             "Offset of field: ",
@@ -24693,9 +23035,7 @@ fn bindgen_test_layout__cef_xml_reader_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_xml_reader_t>())).get_attribute_bylname as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_xml_reader_t, get_attribute_bylname),
         192usize,
         concat!(
             "Offset of field: ",
@@ -24705,7 +23045,7 @@ fn bindgen_test_layout__cef_xml_reader_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_xml_reader_t>())).get_inner_xml as *const _ as usize },
+        ::std::mem::offset_of!(_cef_xml_reader_t, get_inner_xml),
         200usize,
         concat!(
             "Offset of field: ",
@@ -24715,7 +23055,7 @@ fn bindgen_test_layout__cef_xml_reader_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_xml_reader_t>())).get_outer_xml as *const _ as usize },
+        ::std::mem::offset_of!(_cef_xml_reader_t, get_outer_xml),
         208usize,
         concat!(
             "Offset of field: ",
@@ -24725,9 +23065,7 @@ fn bindgen_test_layout__cef_xml_reader_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_xml_reader_t>())).get_line_number as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_xml_reader_t, get_line_number),
         216usize,
         concat!(
             "Offset of field: ",
@@ -24737,10 +23075,7 @@ fn bindgen_test_layout__cef_xml_reader_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_xml_reader_t>())).move_to_attribute_byindex as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_xml_reader_t, move_to_attribute_byindex),
         224usize,
         concat!(
             "Offset of field: ",
@@ -24750,10 +23085,7 @@ fn bindgen_test_layout__cef_xml_reader_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_xml_reader_t>())).move_to_attribute_byqname as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_xml_reader_t, move_to_attribute_byqname),
         232usize,
         concat!(
             "Offset of field: ",
@@ -24763,10 +23095,7 @@ fn bindgen_test_layout__cef_xml_reader_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_xml_reader_t>())).move_to_attribute_bylname as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_xml_reader_t, move_to_attribute_bylname),
         240usize,
         concat!(
             "Offset of field: ",
@@ -24776,10 +23105,7 @@ fn bindgen_test_layout__cef_xml_reader_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_xml_reader_t>())).move_to_first_attribute as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_xml_reader_t, move_to_first_attribute),
         248usize,
         concat!(
             "Offset of field: ",
@@ -24789,10 +23115,7 @@ fn bindgen_test_layout__cef_xml_reader_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_xml_reader_t>())).move_to_next_attribute as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_xml_reader_t, move_to_next_attribute),
         256usize,
         concat!(
             "Offset of field: ",
@@ -24802,10 +23125,7 @@ fn bindgen_test_layout__cef_xml_reader_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_xml_reader_t>())).move_to_carrying_element as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_xml_reader_t, move_to_carrying_element),
         264usize,
         concat!(
             "Offset of field: ",
@@ -24884,7 +23204,7 @@ fn bindgen_test_layout__cef_zip_reader_t() {
         concat!("Alignment of ", stringify!(_cef_zip_reader_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_zip_reader_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_zip_reader_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -24894,9 +23214,7 @@ fn bindgen_test_layout__cef_zip_reader_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_zip_reader_t>())).move_to_first_file as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_zip_reader_t, move_to_first_file),
         40usize,
         concat!(
             "Offset of field: ",
@@ -24906,9 +23224,7 @@ fn bindgen_test_layout__cef_zip_reader_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_zip_reader_t>())).move_to_next_file as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_zip_reader_t, move_to_next_file),
         48usize,
         concat!(
             "Offset of field: ",
@@ -24918,7 +23234,7 @@ fn bindgen_test_layout__cef_zip_reader_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_zip_reader_t>())).move_to_file as *const _ as usize },
+        ::std::mem::offset_of!(_cef_zip_reader_t, move_to_file),
         56usize,
         concat!(
             "Offset of field: ",
@@ -24928,7 +23244,7 @@ fn bindgen_test_layout__cef_zip_reader_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_zip_reader_t>())).close as *const _ as usize },
+        ::std::mem::offset_of!(_cef_zip_reader_t, close),
         64usize,
         concat!(
             "Offset of field: ",
@@ -24938,7 +23254,7 @@ fn bindgen_test_layout__cef_zip_reader_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_zip_reader_t>())).get_file_name as *const _ as usize },
+        ::std::mem::offset_of!(_cef_zip_reader_t, get_file_name),
         72usize,
         concat!(
             "Offset of field: ",
@@ -24948,7 +23264,7 @@ fn bindgen_test_layout__cef_zip_reader_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_zip_reader_t>())).get_file_size as *const _ as usize },
+        ::std::mem::offset_of!(_cef_zip_reader_t, get_file_size),
         80usize,
         concat!(
             "Offset of field: ",
@@ -24958,10 +23274,7 @@ fn bindgen_test_layout__cef_zip_reader_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_zip_reader_t>())).get_file_last_modified as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_zip_reader_t, get_file_last_modified),
         88usize,
         concat!(
             "Offset of field: ",
@@ -24971,7 +23284,7 @@ fn bindgen_test_layout__cef_zip_reader_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_zip_reader_t>())).open_file as *const _ as usize },
+        ::std::mem::offset_of!(_cef_zip_reader_t, open_file),
         96usize,
         concat!(
             "Offset of field: ",
@@ -24981,7 +23294,7 @@ fn bindgen_test_layout__cef_zip_reader_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_zip_reader_t>())).close_file as *const _ as usize },
+        ::std::mem::offset_of!(_cef_zip_reader_t, close_file),
         104usize,
         concat!(
             "Offset of field: ",
@@ -24991,7 +23304,7 @@ fn bindgen_test_layout__cef_zip_reader_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_zip_reader_t>())).read_file as *const _ as usize },
+        ::std::mem::offset_of!(_cef_zip_reader_t, read_file),
         112usize,
         concat!(
             "Offset of field: ",
@@ -25001,7 +23314,7 @@ fn bindgen_test_layout__cef_zip_reader_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_zip_reader_t>())).tell as *const _ as usize },
+        ::std::mem::offset_of!(_cef_zip_reader_t, tell),
         120usize,
         concat!(
             "Offset of field: ",
@@ -25011,7 +23324,7 @@ fn bindgen_test_layout__cef_zip_reader_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_zip_reader_t>())).eof as *const _ as usize },
+        ::std::mem::offset_of!(_cef_zip_reader_t, eof),
         128usize,
         concat!(
             "Offset of field: ",
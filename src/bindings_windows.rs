@@ -37,7 +37,7 @@ fn bindgen_test_layout__cef_string_wide_t() {
         concat!("Alignment of ", stringify!(_cef_string_wide_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_string_wide_t>())).str_ as *const _ as usize },
+        ::std::mem::offset_of!(_cef_string_wide_t, str_),
         0usize,
         concat!(
             "Offset of field: ",
@@ -47,7 +47,7 @@ fn bindgen_test_layout__cef_string_wide_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_string_wide_t>())).length as *const _ as usize },
+        ::std::mem::offset_of!(_cef_string_wide_t, length),
         8usize,
         concat!(
             "Offset of field: ",
@@ -57,7 +57,7 @@ fn bindgen_test_layout__cef_string_wide_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_string_wide_t>())).dtor as *const _ as usize },
+        ::std::mem::offset_of!(_cef_string_wide_t, dtor),
         16usize,
         concat!(
             "Offset of field: ",
@@ -88,7 +88,7 @@ fn bindgen_test_layout__cef_string_utf8_t() {
         concat!("Alignment of ", stringify!(_cef_string_utf8_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_string_utf8_t>())).str_ as *const _ as usize },
+        ::std::mem::offset_of!(_cef_string_utf8_t, str_),
         0usize,
         concat!(
             "Offset of field: ",
@@ -98,7 +98,7 @@ fn bindgen_test_layout__cef_string_utf8_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_string_utf8_t>())).length as *const _ as usize },
+        ::std::mem::offset_of!(_cef_string_utf8_t, length),
         8usize,
         concat!(
             "Offset of field: ",
@@ -108,7 +108,7 @@ fn bindgen_test_layout__cef_string_utf8_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_string_utf8_t>())).dtor as *const _ as usize },
+        ::std::mem::offset_of!(_cef_string_utf8_t, dtor),
         16usize,
         concat!(
             "Offset of field: ",
@@ -139,7 +139,7 @@ fn bindgen_test_layout__cef_string_utf16_t() {
         concat!("Alignment of ", stringify!(_cef_string_utf16_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_string_utf16_t>())).str_ as *const _ as usize },
+        ::std::mem::offset_of!(_cef_string_utf16_t, str_),
         0usize,
         concat!(
             "Offset of field: ",
@@ -149,7 +149,7 @@ fn bindgen_test_layout__cef_string_utf16_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_string_utf16_t>())).length as *const _ as usize },
+        ::std::mem::offset_of!(_cef_string_utf16_t, length),
         8usize,
         concat!(
             "Offset of field: ",
@@ -159,7 +159,7 @@ fn bindgen_test_layout__cef_string_utf16_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_string_utf16_t>())).dtor as *const _ as usize },
+        ::std::mem::offset_of!(_cef_string_utf16_t, dtor),
         16usize,
         concat!(
             "Offset of field: ",
@@ -457,7 +457,7 @@ fn bindgen_test_layout__cef_time_t() {
         concat!("Alignment of ", stringify!(_cef_time_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_time_t>())).year as *const _ as usize },
+        ::std::mem::offset_of!(_cef_time_t, year),
         0usize,
         concat!(
             "Offset of field: ",
@@ -467,7 +467,7 @@ fn bindgen_test_layout__cef_time_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_time_t>())).month as *const _ as usize },
+        ::std::mem::offset_of!(_cef_time_t, month),
         4usize,
         concat!(
             "Offset of field: ",
@@ -477,7 +477,7 @@ fn bindgen_test_layout__cef_time_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_time_t>())).day_of_week as *const _ as usize },
+        ::std::mem::offset_of!(_cef_time_t, day_of_week),
         8usize,
         concat!(
             "Offset of field: ",
@@ -487,7 +487,7 @@ fn bindgen_test_layout__cef_time_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_time_t>())).day_of_month as *const _ as usize },
+        ::std::mem::offset_of!(_cef_time_t, day_of_month),
         12usize,
         concat!(
             "Offset of field: ",
@@ -497,7 +497,7 @@ fn bindgen_test_layout__cef_time_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_time_t>())).hour as *const _ as usize },
+        ::std::mem::offset_of!(_cef_time_t, hour),
         16usize,
         concat!(
             "Offset of field: ",
@@ -507,7 +507,7 @@ fn bindgen_test_layout__cef_time_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_time_t>())).minute as *const _ as usize },
+        ::std::mem::offset_of!(_cef_time_t, minute),
         20usize,
         concat!(
             "Offset of field: ",
@@ -517,7 +517,7 @@ fn bindgen_test_layout__cef_time_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_time_t>())).second as *const _ as usize },
+        ::std::mem::offset_of!(_cef_time_t, second),
         24usize,
         concat!(
             "Offset of field: ",
@@ -527,7 +527,7 @@ fn bindgen_test_layout__cef_time_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_time_t>())).millisecond as *const _ as usize },
+        ::std::mem::offset_of!(_cef_time_t, millisecond),
         28usize,
         concat!(
             "Offset of field: ",
@@ -592,7 +592,7 @@ fn bindgen_test_layout_tagPOINT() {
         concat!("Alignment of ", stringify!(tagPOINT))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<tagPOINT>())).x as *const _ as usize },
+        ::std::mem::offset_of!(tagPOINT, x),
         0usize,
         concat!(
             "Offset of field: ",
@@ -602,7 +602,7 @@ fn bindgen_test_layout_tagPOINT() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<tagPOINT>())).y as *const _ as usize },
+        ::std::mem::offset_of!(tagPOINT, y),
         4usize,
         concat!(
             "Offset of field: ",
@@ -630,7 +630,7 @@ fn bindgen_test_layout__cef_main_args_t() {
         concat!("Alignment of ", stringify!(_cef_main_args_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_main_args_t>())).instance as *const _ as usize },
+        ::std::mem::offset_of!(_cef_main_args_t, instance),
         0usize,
         concat!(
             "Offset of field: ",
@@ -670,7 +670,7 @@ fn bindgen_test_layout__cef_window_info_t() {
         concat!("Alignment of ", stringify!(_cef_window_info_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_window_info_t>())).ex_style as *const _ as usize },
+        ::std::mem::offset_of!(_cef_window_info_t, ex_style),
         0usize,
         concat!(
             "Offset of field: ",
@@ -680,7 +680,7 @@ fn bindgen_test_layout__cef_window_info_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_window_info_t>())).window_name as *const _ as usize },
+        ::std::mem::offset_of!(_cef_window_info_t, window_name),
         8usize,
         concat!(
             "Offset of field: ",
@@ -690,7 +690,7 @@ fn bindgen_test_layout__cef_window_info_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_window_info_t>())).style as *const _ as usize },
+        ::std::mem::offset_of!(_cef_window_info_t, style),
         32usize,
         concat!(
             "Offset of field: ",
@@ -700,7 +700,7 @@ fn bindgen_test_layout__cef_window_info_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_window_info_t>())).x as *const _ as usize },
+        ::std::mem::offset_of!(_cef_window_info_t, x),
         36usize,
         concat!(
             "Offset of field: ",
@@ -710,7 +710,7 @@ fn bindgen_test_layout__cef_window_info_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_window_info_t>())).y as *const _ as usize },
+        ::std::mem::offset_of!(_cef_window_info_t, y),
         40usize,
         concat!(
             "Offset of field: ",
@@ -720,7 +720,7 @@ fn bindgen_test_layout__cef_window_info_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_window_info_t>())).width as *const _ as usize },
+        ::std::mem::offset_of!(_cef_window_info_t, width),
         44usize,
         concat!(
             "Offset of field: ",
@@ -730,7 +730,7 @@ fn bindgen_test_layout__cef_window_info_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_window_info_t>())).height as *const _ as usize },
+        ::std::mem::offset_of!(_cef_window_info_t, height),
         48usize,
         concat!(
             "Offset of field: ",
@@ -740,9 +740,7 @@ fn bindgen_test_layout__cef_window_info_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_window_info_t>())).parent_window as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_window_info_t, parent_window),
         56usize,
         concat!(
             "Offset of field: ",
@@ -752,7 +750,7 @@ fn bindgen_test_layout__cef_window_info_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_window_info_t>())).menu as *const _ as usize },
+        ::std::mem::offset_of!(_cef_window_info_t, menu),
         64usize,
         concat!(
             "Offset of field: ",
@@ -762,10 +760,7 @@ fn bindgen_test_layout__cef_window_info_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_window_info_t>())).windowless_rendering_enabled as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_window_info_t, windowless_rendering_enabled),
         72usize,
         concat!(
             "Offset of field: ",
@@ -775,10 +770,7 @@ fn bindgen_test_layout__cef_window_info_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_window_info_t>())).shared_texture_enabled as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_window_info_t, shared_texture_enabled),
         76usize,
         concat!(
             "Offset of field: ",
@@ -788,10 +780,7 @@ fn bindgen_test_layout__cef_window_info_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_window_info_t>())).external_begin_frame_enabled as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_window_info_t, external_begin_frame_enabled),
         80usize,
         concat!(
             "Offset of field: ",
@@ -801,7 +790,7 @@ fn bindgen_test_layout__cef_window_info_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_window_info_t>())).window as *const _ as usize },
+        ::std::mem::offset_of!(_cef_window_info_t, window),
         88usize,
         concat!(
             "Offset of field: ",
@@ -876,7 +865,7 @@ fn bindgen_test_layout__cef_settings_t() {
         concat!("Alignment of ", stringify!(_cef_settings_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_settings_t>())).size as *const _ as usize },
+        ::std::mem::offset_of!(_cef_settings_t, size),
         0usize,
         concat!(
             "Offset of field: ",
@@ -886,7 +875,7 @@ fn bindgen_test_layout__cef_settings_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_settings_t>())).no_sandbox as *const _ as usize },
+        ::std::mem::offset_of!(_cef_settings_t, no_sandbox),
         8usize,
         concat!(
             "Offset of field: ",
@@ -896,9 +885,7 @@ fn bindgen_test_layout__cef_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_settings_t>())).browser_subprocess_path as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_settings_t, browser_subprocess_path),
         16usize,
         concat!(
             "Offset of field: ",
@@ -908,9 +895,7 @@ fn bindgen_test_layout__cef_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_settings_t>())).framework_dir_path as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_settings_t, framework_dir_path),
         40usize,
         concat!(
             "Offset of field: ",
@@ -920,9 +905,7 @@ fn bindgen_test_layout__cef_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_settings_t>())).main_bundle_path as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_settings_t, main_bundle_path),
         64usize,
         concat!(
             "Offset of field: ",
@@ -932,10 +915,7 @@ fn bindgen_test_layout__cef_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_settings_t>())).multi_threaded_message_loop as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_settings_t, multi_threaded_message_loop),
         88usize,
         concat!(
             "Offset of field: ",
@@ -945,9 +925,7 @@ fn bindgen_test_layout__cef_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_settings_t>())).external_message_pump as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_settings_t, external_message_pump),
         92usize,
         concat!(
             "Offset of field: ",
@@ -957,10 +935,7 @@ fn bindgen_test_layout__cef_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_settings_t>())).windowless_rendering_enabled as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_settings_t, windowless_rendering_enabled),
         96usize,
         concat!(
             "Offset of field: ",
@@ -970,10 +945,7 @@ fn bindgen_test_layout__cef_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_settings_t>())).command_line_args_disabled as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_settings_t, command_line_args_disabled),
         100usize,
         concat!(
             "Offset of field: ",
@@ -983,7 +955,7 @@ fn bindgen_test_layout__cef_settings_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_settings_t>())).cache_path as *const _ as usize },
+        ::std::mem::offset_of!(_cef_settings_t, cache_path),
         104usize,
         concat!(
             "Offset of field: ",
@@ -993,7 +965,7 @@ fn bindgen_test_layout__cef_settings_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_settings_t>())).root_cache_path as *const _ as usize },
+        ::std::mem::offset_of!(_cef_settings_t, root_cache_path),
         128usize,
         concat!(
             "Offset of field: ",
@@ -1003,7 +975,7 @@ fn bindgen_test_layout__cef_settings_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_settings_t>())).user_data_path as *const _ as usize },
+        ::std::mem::offset_of!(_cef_settings_t, user_data_path),
         152usize,
         concat!(
             "Offset of field: ",
@@ -1013,9 +985,7 @@ fn bindgen_test_layout__cef_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_settings_t>())).persist_session_cookies as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_settings_t, persist_session_cookies),
         176usize,
         concat!(
             "Offset of field: ",
@@ -1025,10 +995,7 @@ fn bindgen_test_layout__cef_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_settings_t>())).persist_user_preferences as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_settings_t, persist_user_preferences),
         180usize,
         concat!(
             "Offset of field: ",
@@ -1038,7 +1005,7 @@ fn bindgen_test_layout__cef_settings_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_settings_t>())).user_agent as *const _ as usize },
+        ::std::mem::offset_of!(_cef_settings_t, user_agent),
         184usize,
         concat!(
             "Offset of field: ",
@@ -1048,7 +1015,7 @@ fn bindgen_test_layout__cef_settings_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_settings_t>())).product_version as *const _ as usize },
+        ::std::mem::offset_of!(_cef_settings_t, product_version),
         208usize,
         concat!(
             "Offset of field: ",
@@ -1058,7 +1025,7 @@ fn bindgen_test_layout__cef_settings_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_settings_t>())).locale as *const _ as usize },
+        ::std::mem::offset_of!(_cef_settings_t, locale),
         232usize,
         concat!(
             "Offset of field: ",
@@ -1068,7 +1035,7 @@ fn bindgen_test_layout__cef_settings_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_settings_t>())).log_file as *const _ as usize },
+        ::std::mem::offset_of!(_cef_settings_t, log_file),
         256usize,
         concat!(
             "Offset of field: ",
@@ -1078,7 +1045,7 @@ fn bindgen_test_layout__cef_settings_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_settings_t>())).log_severity as *const _ as usize },
+        ::std::mem::offset_of!(_cef_settings_t, log_severity),
         280usize,
         concat!(
             "Offset of field: ",
@@ -1088,9 +1055,7 @@ fn bindgen_test_layout__cef_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_settings_t>())).javascript_flags as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_settings_t, javascript_flags),
         288usize,
         concat!(
             "Offset of field: ",
@@ -1100,9 +1065,7 @@ fn bindgen_test_layout__cef_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_settings_t>())).resources_dir_path as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_settings_t, resources_dir_path),
         312usize,
         concat!(
             "Offset of field: ",
@@ -1112,9 +1075,7 @@ fn bindgen_test_layout__cef_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_settings_t>())).locales_dir_path as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_settings_t, locales_dir_path),
         336usize,
         concat!(
             "Offset of field: ",
@@ -1124,9 +1085,7 @@ fn bindgen_test_layout__cef_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_settings_t>())).pack_loading_disabled as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_settings_t, pack_loading_disabled),
         360usize,
         concat!(
             "Offset of field: ",
@@ -1136,9 +1095,7 @@ fn bindgen_test_layout__cef_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_settings_t>())).remote_debugging_port as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_settings_t, remote_debugging_port),
         364usize,
         concat!(
             "Offset of field: ",
@@ -1148,10 +1105,7 @@ fn bindgen_test_layout__cef_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_settings_t>())).uncaught_exception_stack_size as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_settings_t, uncaught_exception_stack_size),
         368usize,
         concat!(
             "Offset of field: ",
@@ -1161,10 +1115,7 @@ fn bindgen_test_layout__cef_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_settings_t>())).ignore_certificate_errors as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_settings_t, ignore_certificate_errors),
         372usize,
         concat!(
             "Offset of field: ",
@@ -1174,9 +1125,7 @@ fn bindgen_test_layout__cef_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_settings_t>())).background_color as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_settings_t, background_color),
         376usize,
         concat!(
             "Offset of field: ",
@@ -1186,9 +1135,7 @@ fn bindgen_test_layout__cef_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_settings_t>())).accept_language_list as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_settings_t, accept_language_list),
         384usize,
         concat!(
             "Offset of field: ",
@@ -1198,10 +1145,7 @@ fn bindgen_test_layout__cef_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_settings_t>())).application_client_id_for_file_scanning
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_settings_t, application_client_id_for_file_scanning),
         408usize,
         concat!(
             "Offset of field: ",
@@ -1235,9 +1179,7 @@ fn bindgen_test_layout__cef_request_context_settings_t() {
         concat!("Alignment of ", stringify!(_cef_request_context_settings_t))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_request_context_settings_t>())).size as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_request_context_settings_t, size),
         0usize,
         concat!(
             "Offset of field: ",
@@ -1247,10 +1189,7 @@ fn bindgen_test_layout__cef_request_context_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_request_context_settings_t>())).cache_path as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_request_context_settings_t, cache_path),
         8usize,
         concat!(
             "Offset of field: ",
@@ -1260,10 +1199,7 @@ fn bindgen_test_layout__cef_request_context_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_request_context_settings_t>())).persist_session_cookies
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_request_context_settings_t, persist_session_cookies),
         32usize,
         concat!(
             "Offset of field: ",
@@ -1273,10 +1209,7 @@ fn bindgen_test_layout__cef_request_context_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_request_context_settings_t>())).persist_user_preferences
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_request_context_settings_t, persist_user_preferences),
         36usize,
         concat!(
             "Offset of field: ",
@@ -1286,10 +1219,7 @@ fn bindgen_test_layout__cef_request_context_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_request_context_settings_t>())).ignore_certificate_errors
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_request_context_settings_t, ignore_certificate_errors),
         40usize,
         concat!(
             "Offset of field: ",
@@ -1299,10 +1229,7 @@ fn bindgen_test_layout__cef_request_context_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_request_context_settings_t>())).accept_language_list
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_request_context_settings_t, accept_language_list),
         48usize,
         concat!(
             "Offset of field: ",
@@ -1362,7 +1289,7 @@ fn bindgen_test_layout__cef_browser_settings_t() {
         concat!("Alignment of ", stringify!(_cef_browser_settings_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_browser_settings_t>())).size as *const _ as usize },
+        ::std::mem::offset_of!(_cef_browser_settings_t, size),
         0usize,
         concat!(
             "Offset of field: ",
@@ -1372,10 +1299,7 @@ fn bindgen_test_layout__cef_browser_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_settings_t>())).windowless_frame_rate as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_settings_t, windowless_frame_rate),
         8usize,
         concat!(
             "Offset of field: ",
@@ -1385,10 +1309,7 @@ fn bindgen_test_layout__cef_browser_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_settings_t>())).standard_font_family as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_settings_t, standard_font_family),
         16usize,
         concat!(
             "Offset of field: ",
@@ -1398,10 +1319,7 @@ fn bindgen_test_layout__cef_browser_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_settings_t>())).fixed_font_family as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_settings_t, fixed_font_family),
         40usize,
         concat!(
             "Offset of field: ",
@@ -1411,10 +1329,7 @@ fn bindgen_test_layout__cef_browser_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_settings_t>())).serif_font_family as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_settings_t, serif_font_family),
         64usize,
         concat!(
             "Offset of field: ",
@@ -1424,10 +1339,7 @@ fn bindgen_test_layout__cef_browser_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_settings_t>())).sans_serif_font_family as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_settings_t, sans_serif_font_family),
         88usize,
         concat!(
             "Offset of field: ",
@@ -1437,10 +1349,7 @@ fn bindgen_test_layout__cef_browser_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_settings_t>())).cursive_font_family as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_settings_t, cursive_font_family),
         112usize,
         concat!(
             "Offset of field: ",
@@ -1450,10 +1359,7 @@ fn bindgen_test_layout__cef_browser_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_settings_t>())).fantasy_font_family as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_settings_t, fantasy_font_family),
         136usize,
         concat!(
             "Offset of field: ",
@@ -1463,10 +1369,7 @@ fn bindgen_test_layout__cef_browser_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_settings_t>())).default_font_size as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_settings_t, default_font_size),
         160usize,
         concat!(
             "Offset of field: ",
@@ -1476,10 +1379,7 @@ fn bindgen_test_layout__cef_browser_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_settings_t>())).default_fixed_font_size as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_settings_t, default_fixed_font_size),
         164usize,
         concat!(
             "Offset of field: ",
@@ -1489,10 +1389,7 @@ fn bindgen_test_layout__cef_browser_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_settings_t>())).minimum_font_size as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_settings_t, minimum_font_size),
         168usize,
         concat!(
             "Offset of field: ",
@@ -1502,10 +1399,7 @@ fn bindgen_test_layout__cef_browser_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_settings_t>())).minimum_logical_font_size
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_settings_t, minimum_logical_font_size),
         172usize,
         concat!(
             "Offset of field: ",
@@ -1515,10 +1409,7 @@ fn bindgen_test_layout__cef_browser_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_settings_t>())).default_encoding as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_settings_t, default_encoding),
         176usize,
         concat!(
             "Offset of field: ",
@@ -1528,9 +1419,7 @@ fn bindgen_test_layout__cef_browser_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_settings_t>())).remote_fonts as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_settings_t, remote_fonts),
         200usize,
         concat!(
             "Offset of field: ",
@@ -1540,9 +1429,7 @@ fn bindgen_test_layout__cef_browser_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_settings_t>())).javascript as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_settings_t, javascript),
         204usize,
         concat!(
             "Offset of field: ",
@@ -1552,10 +1439,7 @@ fn bindgen_test_layout__cef_browser_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_settings_t>())).javascript_close_windows as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_settings_t, javascript_close_windows),
         208usize,
         concat!(
             "Offset of field: ",
@@ -1565,10 +1449,7 @@ fn bindgen_test_layout__cef_browser_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_settings_t>())).javascript_access_clipboard
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_settings_t, javascript_access_clipboard),
         212usize,
         concat!(
             "Offset of field: ",
@@ -1578,10 +1459,7 @@ fn bindgen_test_layout__cef_browser_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_settings_t>())).javascript_dom_paste as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_settings_t, javascript_dom_paste),
         216usize,
         concat!(
             "Offset of field: ",
@@ -1591,7 +1469,7 @@ fn bindgen_test_layout__cef_browser_settings_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_browser_settings_t>())).plugins as *const _ as usize },
+        ::std::mem::offset_of!(_cef_browser_settings_t, plugins),
         220usize,
         concat!(
             "Offset of field: ",
@@ -1601,10 +1479,7 @@ fn bindgen_test_layout__cef_browser_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_settings_t>())).universal_access_from_file_urls
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_settings_t, universal_access_from_file_urls),
         224usize,
         concat!(
             "Offset of field: ",
@@ -1614,10 +1489,7 @@ fn bindgen_test_layout__cef_browser_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_settings_t>())).file_access_from_file_urls
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_settings_t, file_access_from_file_urls),
         228usize,
         concat!(
             "Offset of field: ",
@@ -1627,9 +1499,7 @@ fn bindgen_test_layout__cef_browser_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_settings_t>())).web_security as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_settings_t, web_security),
         232usize,
         concat!(
             "Offset of field: ",
@@ -1639,9 +1509,7 @@ fn bindgen_test_layout__cef_browser_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_settings_t>())).image_loading as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_settings_t, image_loading),
         236usize,
         concat!(
             "Offset of field: ",
@@ -1651,10 +1519,7 @@ fn bindgen_test_layout__cef_browser_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_settings_t>())).image_shrink_standalone_to_fit
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_settings_t, image_shrink_standalone_to_fit),
         240usize,
         concat!(
             "Offset of field: ",
@@ -1664,10 +1529,7 @@ fn bindgen_test_layout__cef_browser_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_settings_t>())).text_area_resize as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_settings_t, text_area_resize),
         244usize,
         concat!(
             "Offset of field: ",
@@ -1677,9 +1539,7 @@ fn bindgen_test_layout__cef_browser_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_settings_t>())).tab_to_links as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_settings_t, tab_to_links),
         248usize,
         concat!(
             "Offset of field: ",
@@ -1689,9 +1549,7 @@ fn bindgen_test_layout__cef_browser_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_settings_t>())).local_storage as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_settings_t, local_storage),
         252usize,
         concat!(
             "Offset of field: ",
@@ -1701,9 +1559,7 @@ fn bindgen_test_layout__cef_browser_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_settings_t>())).databases as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_settings_t, databases),
         256usize,
         concat!(
             "Offset of field: ",
@@ -1713,10 +1569,7 @@ fn bindgen_test_layout__cef_browser_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_settings_t>())).application_cache as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_settings_t, application_cache),
         260usize,
         concat!(
             "Offset of field: ",
@@ -1726,7 +1579,7 @@ fn bindgen_test_layout__cef_browser_settings_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_browser_settings_t>())).webgl as *const _ as usize },
+        ::std::mem::offset_of!(_cef_browser_settings_t, webgl),
         264usize,
         concat!(
             "Offset of field: ",
@@ -1736,10 +1589,7 @@ fn bindgen_test_layout__cef_browser_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_settings_t>())).background_color as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_settings_t, background_color),
         268usize,
         concat!(
             "Offset of field: ",
@@ -1749,10 +1599,7 @@ fn bindgen_test_layout__cef_browser_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_settings_t>())).accept_language_list as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_settings_t, accept_language_list),
         272usize,
         concat!(
             "Offset of field: ",
@@ -1796,7 +1643,7 @@ fn bindgen_test_layout__cef_urlparts_t() {
         concat!("Alignment of ", stringify!(_cef_urlparts_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_urlparts_t>())).spec as *const _ as usize },
+        ::std::mem::offset_of!(_cef_urlparts_t, spec),
         0usize,
         concat!(
             "Offset of field: ",
@@ -1806,7 +1653,7 @@ fn bindgen_test_layout__cef_urlparts_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_urlparts_t>())).scheme as *const _ as usize },
+        ::std::mem::offset_of!(_cef_urlparts_t, scheme),
         24usize,
         concat!(
             "Offset of field: ",
@@ -1816,7 +1663,7 @@ fn bindgen_test_layout__cef_urlparts_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_urlparts_t>())).username as *const _ as usize },
+        ::std::mem::offset_of!(_cef_urlparts_t, username),
         48usize,
         concat!(
             "Offset of field: ",
@@ -1826,7 +1673,7 @@ fn bindgen_test_layout__cef_urlparts_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_urlparts_t>())).password as *const _ as usize },
+        ::std::mem::offset_of!(_cef_urlparts_t, password),
         72usize,
         concat!(
             "Offset of field: ",
@@ -1836,7 +1683,7 @@ fn bindgen_test_layout__cef_urlparts_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_urlparts_t>())).host as *const _ as usize },
+        ::std::mem::offset_of!(_cef_urlparts_t, host),
         96usize,
         concat!(
             "Offset of field: ",
@@ -1846,7 +1693,7 @@ fn bindgen_test_layout__cef_urlparts_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_urlparts_t>())).port as *const _ as usize },
+        ::std::mem::offset_of!(_cef_urlparts_t, port),
         120usize,
         concat!(
             "Offset of field: ",
@@ -1856,7 +1703,7 @@ fn bindgen_test_layout__cef_urlparts_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_urlparts_t>())).origin as *const _ as usize },
+        ::std::mem::offset_of!(_cef_urlparts_t, origin),
         144usize,
         concat!(
             "Offset of field: ",
@@ -1866,7 +1713,7 @@ fn bindgen_test_layout__cef_urlparts_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_urlparts_t>())).path as *const _ as usize },
+        ::std::mem::offset_of!(_cef_urlparts_t, path),
         168usize,
         concat!(
             "Offset of field: ",
@@ -1876,7 +1723,7 @@ fn bindgen_test_layout__cef_urlparts_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_urlparts_t>())).query as *const _ as usize },
+        ::std::mem::offset_of!(_cef_urlparts_t, query),
         192usize,
         concat!(
             "Offset of field: ",
@@ -1886,7 +1733,7 @@ fn bindgen_test_layout__cef_urlparts_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_urlparts_t>())).fragment as *const _ as usize },
+        ::std::mem::offset_of!(_cef_urlparts_t, fragment),
         216usize,
         concat!(
             "Offset of field: ",
@@ -1939,7 +1786,7 @@ fn bindgen_test_layout__cef_cookie_t() {
         concat!("Alignment of ", stringify!(_cef_cookie_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_cookie_t>())).name as *const _ as usize },
+        ::std::mem::offset_of!(_cef_cookie_t, name),
         0usize,
         concat!(
             "Offset of field: ",
@@ -1949,7 +1796,7 @@ fn bindgen_test_layout__cef_cookie_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_cookie_t>())).value as *const _ as usize },
+        ::std::mem::offset_of!(_cef_cookie_t, value),
         24usize,
         concat!(
             "Offset of field: ",
@@ -1959,7 +1806,7 @@ fn bindgen_test_layout__cef_cookie_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_cookie_t>())).domain as *const _ as usize },
+        ::std::mem::offset_of!(_cef_cookie_t, domain),
         48usize,
         concat!(
             "Offset of field: ",
@@ -1969,7 +1816,7 @@ fn bindgen_test_layout__cef_cookie_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_cookie_t>())).path as *const _ as usize },
+        ::std::mem::offset_of!(_cef_cookie_t, path),
         72usize,
         concat!(
             "Offset of field: ",
@@ -1979,7 +1826,7 @@ fn bindgen_test_layout__cef_cookie_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_cookie_t>())).secure as *const _ as usize },
+        ::std::mem::offset_of!(_cef_cookie_t, secure),
         96usize,
         concat!(
             "Offset of field: ",
@@ -1989,7 +1836,7 @@ fn bindgen_test_layout__cef_cookie_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_cookie_t>())).httponly as *const _ as usize },
+        ::std::mem::offset_of!(_cef_cookie_t, httponly),
         100usize,
         concat!(
             "Offset of field: ",
@@ -1999,7 +1846,7 @@ fn bindgen_test_layout__cef_cookie_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_cookie_t>())).creation as *const _ as usize },
+        ::std::mem::offset_of!(_cef_cookie_t, creation),
         104usize,
         concat!(
             "Offset of field: ",
@@ -2009,7 +1856,7 @@ fn bindgen_test_layout__cef_cookie_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_cookie_t>())).last_access as *const _ as usize },
+        ::std::mem::offset_of!(_cef_cookie_t, last_access),
         136usize,
         concat!(
             "Offset of field: ",
@@ -2019,7 +1866,7 @@ fn bindgen_test_layout__cef_cookie_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_cookie_t>())).has_expires as *const _ as usize },
+        ::std::mem::offset_of!(_cef_cookie_t, has_expires),
         168usize,
         concat!(
             "Offset of field: ",
@@ -2029,7 +1876,7 @@ fn bindgen_test_layout__cef_cookie_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_cookie_t>())).expires as *const _ as usize },
+        ::std::mem::offset_of!(_cef_cookie_t, expires),
         172usize,
         concat!(
             "Offset of field: ",
@@ -2039,7 +1886,7 @@ fn bindgen_test_layout__cef_cookie_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_cookie_t>())).same_site as *const _ as usize },
+        ::std::mem::offset_of!(_cef_cookie_t, same_site),
         204usize,
         concat!(
             "Offset of field: ",
@@ -2049,7 +1896,7 @@ fn bindgen_test_layout__cef_cookie_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_cookie_t>())).priority as *const _ as usize },
+        ::std::mem::offset_of!(_cef_cookie_t, priority),
         208usize,
         concat!(
             "Offset of field: ",
@@ -2751,7 +2598,7 @@ fn bindgen_test_layout__cef_point_t() {
         concat!("Alignment of ", stringify!(_cef_point_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_point_t>())).x as *const _ as usize },
+        ::std::mem::offset_of!(_cef_point_t, x),
         0usize,
         concat!(
             "Offset of field: ",
@@ -2761,7 +2608,7 @@ fn bindgen_test_layout__cef_point_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_point_t>())).y as *const _ as usize },
+        ::std::mem::offset_of!(_cef_point_t, y),
         4usize,
         concat!(
             "Offset of field: ",
@@ -2793,7 +2640,7 @@ fn bindgen_test_layout__cef_rect_t() {
         concat!("Alignment of ", stringify!(_cef_rect_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_rect_t>())).x as *const _ as usize },
+        ::std::mem::offset_of!(_cef_rect_t, x),
         0usize,
         concat!(
             "Offset of field: ",
@@ -2803,7 +2650,7 @@ fn bindgen_test_layout__cef_rect_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_rect_t>())).y as *const _ as usize },
+        ::std::mem::offset_of!(_cef_rect_t, y),
         4usize,
         concat!(
             "Offset of field: ",
@@ -2813,7 +2660,7 @@ fn bindgen_test_layout__cef_rect_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_rect_t>())).width as *const _ as usize },
+        ::std::mem::offset_of!(_cef_rect_t, width),
         8usize,
         concat!(
             "Offset of field: ",
@@ -2823,7 +2670,7 @@ fn bindgen_test_layout__cef_rect_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_rect_t>())).height as *const _ as usize },
+        ::std::mem::offset_of!(_cef_rect_t, height),
         12usize,
         concat!(
             "Offset of field: ",
@@ -2853,7 +2700,7 @@ fn bindgen_test_layout__cef_size_t() {
         concat!("Alignment of ", stringify!(_cef_size_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_size_t>())).width as *const _ as usize },
+        ::std::mem::offset_of!(_cef_size_t, width),
         0usize,
         concat!(
             "Offset of field: ",
@@ -2863,7 +2710,7 @@ fn bindgen_test_layout__cef_size_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_size_t>())).height as *const _ as usize },
+        ::std::mem::offset_of!(_cef_size_t, height),
         4usize,
         concat!(
             "Offset of field: ",
@@ -2893,7 +2740,7 @@ fn bindgen_test_layout__cef_range_t() {
         concat!("Alignment of ", stringify!(_cef_range_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_range_t>())).from as *const _ as usize },
+        ::std::mem::offset_of!(_cef_range_t, from),
         0usize,
         concat!(
             "Offset of field: ",
@@ -2903,7 +2750,7 @@ fn bindgen_test_layout__cef_range_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_range_t>())).to as *const _ as usize },
+        ::std::mem::offset_of!(_cef_range_t, to),
         4usize,
         concat!(
             "Offset of field: ",
@@ -2935,7 +2782,7 @@ fn bindgen_test_layout__cef_insets_t() {
         concat!("Alignment of ", stringify!(_cef_insets_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_insets_t>())).top as *const _ as usize },
+        ::std::mem::offset_of!(_cef_insets_t, top),
         0usize,
         concat!(
             "Offset of field: ",
@@ -2945,7 +2792,7 @@ fn bindgen_test_layout__cef_insets_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_insets_t>())).left as *const _ as usize },
+        ::std::mem::offset_of!(_cef_insets_t, left),
         4usize,
         concat!(
             "Offset of field: ",
@@ -2955,7 +2802,7 @@ fn bindgen_test_layout__cef_insets_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_insets_t>())).bottom as *const _ as usize },
+        ::std::mem::offset_of!(_cef_insets_t, bottom),
         8usize,
         concat!(
             "Offset of field: ",
@@ -2965,7 +2812,7 @@ fn bindgen_test_layout__cef_insets_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_insets_t>())).right as *const _ as usize },
+        ::std::mem::offset_of!(_cef_insets_t, right),
         12usize,
         concat!(
             "Offset of field: ",
@@ -2995,7 +2842,7 @@ fn bindgen_test_layout__cef_draggable_region_t() {
         concat!("Alignment of ", stringify!(_cef_draggable_region_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_draggable_region_t>())).bounds as *const _ as usize },
+        ::std::mem::offset_of!(_cef_draggable_region_t, bounds),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3005,9 +2852,7 @@ fn bindgen_test_layout__cef_draggable_region_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_draggable_region_t>())).draggable as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_draggable_region_t, draggable),
         16usize,
         concat!(
             "Offset of field: ",
@@ -3094,9 +2939,7 @@ fn bindgen_test_layout__cef_screen_info_t() {
         concat!("Alignment of ", stringify!(_cef_screen_info_t))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_screen_info_t>())).device_scale_factor as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_screen_info_t, device_scale_factor),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3106,7 +2949,7 @@ fn bindgen_test_layout__cef_screen_info_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_screen_info_t>())).depth as *const _ as usize },
+        ::std::mem::offset_of!(_cef_screen_info_t, depth),
         4usize,
         concat!(
             "Offset of field: ",
@@ -3116,9 +2959,7 @@ fn bindgen_test_layout__cef_screen_info_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_screen_info_t>())).depth_per_component as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_screen_info_t, depth_per_component),
         8usize,
         concat!(
             "Offset of field: ",
@@ -3128,9 +2969,7 @@ fn bindgen_test_layout__cef_screen_info_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_screen_info_t>())).is_monochrome as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_screen_info_t, is_monochrome),
         12usize,
         concat!(
             "Offset of field: ",
@@ -3140,7 +2979,7 @@ fn bindgen_test_layout__cef_screen_info_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_screen_info_t>())).rect as *const _ as usize },
+        ::std::mem::offset_of!(_cef_screen_info_t, rect),
         16usize,
         concat!(
             "Offset of field: ",
@@ -3150,9 +2989,7 @@ fn bindgen_test_layout__cef_screen_info_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_screen_info_t>())).available_rect as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_screen_info_t, available_rect),
         32usize,
         concat!(
             "Offset of field: ",
@@ -3219,7 +3056,7 @@ fn bindgen_test_layout__cef_mouse_event_t() {
         concat!("Alignment of ", stringify!(_cef_mouse_event_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_mouse_event_t>())).x as *const _ as usize },
+        ::std::mem::offset_of!(_cef_mouse_event_t, x),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3229,7 +3066,7 @@ fn bindgen_test_layout__cef_mouse_event_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_mouse_event_t>())).y as *const _ as usize },
+        ::std::mem::offset_of!(_cef_mouse_event_t, y),
         4usize,
         concat!(
             "Offset of field: ",
@@ -3239,7 +3076,7 @@ fn bindgen_test_layout__cef_mouse_event_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_mouse_event_t>())).modifiers as *const _ as usize },
+        ::std::mem::offset_of!(_cef_mouse_event_t, modifiers),
         8usize,
         concat!(
             "Offset of field: ",
@@ -3292,7 +3129,7 @@ fn bindgen_test_layout__cef_touch_event_t() {
         concat!("Alignment of ", stringify!(_cef_touch_event_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_touch_event_t>())).id as *const _ as usize },
+        ::std::mem::offset_of!(_cef_touch_event_t, id),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3302,7 +3139,7 @@ fn bindgen_test_layout__cef_touch_event_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_touch_event_t>())).x as *const _ as usize },
+        ::std::mem::offset_of!(_cef_touch_event_t, x),
         4usize,
         concat!(
             "Offset of field: ",
@@ -3312,7 +3149,7 @@ fn bindgen_test_layout__cef_touch_event_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_touch_event_t>())).y as *const _ as usize },
+        ::std::mem::offset_of!(_cef_touch_event_t, y),
         8usize,
         concat!(
             "Offset of field: ",
@@ -3322,7 +3159,7 @@ fn bindgen_test_layout__cef_touch_event_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_touch_event_t>())).radius_x as *const _ as usize },
+        ::std::mem::offset_of!(_cef_touch_event_t, radius_x),
         12usize,
         concat!(
             "Offset of field: ",
@@ -3332,7 +3169,7 @@ fn bindgen_test_layout__cef_touch_event_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_touch_event_t>())).radius_y as *const _ as usize },
+        ::std::mem::offset_of!(_cef_touch_event_t, radius_y),
         16usize,
         concat!(
             "Offset of field: ",
@@ -3342,9 +3179,7 @@ fn bindgen_test_layout__cef_touch_event_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_touch_event_t>())).rotation_angle as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_touch_event_t, rotation_angle),
         20usize,
         concat!(
             "Offset of field: ",
@@ -3354,7 +3189,7 @@ fn bindgen_test_layout__cef_touch_event_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_touch_event_t>())).pressure as *const _ as usize },
+        ::std::mem::offset_of!(_cef_touch_event_t, pressure),
         24usize,
         concat!(
             "Offset of field: ",
@@ -3364,7 +3199,7 @@ fn bindgen_test_layout__cef_touch_event_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_touch_event_t>())).type_ as *const _ as usize },
+        ::std::mem::offset_of!(_cef_touch_event_t, type_),
         28usize,
         concat!(
             "Offset of field: ",
@@ -3374,7 +3209,7 @@ fn bindgen_test_layout__cef_touch_event_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_touch_event_t>())).modifiers as *const _ as usize },
+        ::std::mem::offset_of!(_cef_touch_event_t, modifiers),
         32usize,
         concat!(
             "Offset of field: ",
@@ -3384,7 +3219,7 @@ fn bindgen_test_layout__cef_touch_event_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_touch_event_t>())).pointer_type as *const _ as usize },
+        ::std::mem::offset_of!(_cef_touch_event_t, pointer_type),
         36usize,
         concat!(
             "Offset of field: ",
@@ -3719,7 +3554,7 @@ fn bindgen_test_layout__cef_key_event_t() {
         concat!("Alignment of ", stringify!(_cef_key_event_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_key_event_t>())).type_ as *const _ as usize },
+        ::std::mem::offset_of!(_cef_key_event_t, type_),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3729,7 +3564,7 @@ fn bindgen_test_layout__cef_key_event_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_key_event_t>())).modifiers as *const _ as usize },
+        ::std::mem::offset_of!(_cef_key_event_t, modifiers),
         4usize,
         concat!(
             "Offset of field: ",
@@ -3739,9 +3574,7 @@ fn bindgen_test_layout__cef_key_event_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_key_event_t>())).windows_key_code as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_key_event_t, windows_key_code),
         8usize,
         concat!(
             "Offset of field: ",
@@ -3751,9 +3584,7 @@ fn bindgen_test_layout__cef_key_event_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_key_event_t>())).native_key_code as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_key_event_t, native_key_code),
         12usize,
         concat!(
             "Offset of field: ",
@@ -3763,7 +3594,7 @@ fn bindgen_test_layout__cef_key_event_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_key_event_t>())).is_system_key as *const _ as usize },
+        ::std::mem::offset_of!(_cef_key_event_t, is_system_key),
         16usize,
         concat!(
             "Offset of field: ",
@@ -3773,7 +3604,7 @@ fn bindgen_test_layout__cef_key_event_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_key_event_t>())).character as *const _ as usize },
+        ::std::mem::offset_of!(_cef_key_event_t, character),
         20usize,
         concat!(
             "Offset of field: ",
@@ -3783,9 +3614,7 @@ fn bindgen_test_layout__cef_key_event_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_key_event_t>())).unmodified_character as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_key_event_t, unmodified_character),
         22usize,
         concat!(
             "Offset of field: ",
@@ -3795,10 +3624,7 @@ fn bindgen_test_layout__cef_key_event_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_key_event_t>())).focus_on_editable_field as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_key_event_t, focus_on_editable_field),
         24usize,
         concat!(
             "Offset of field: ",
@@ -3874,7 +3700,7 @@ fn bindgen_test_layout__cef_popup_features_t() {
         concat!("Alignment of ", stringify!(_cef_popup_features_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_popup_features_t>())).x as *const _ as usize },
+        ::std::mem::offset_of!(_cef_popup_features_t, x),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3884,7 +3710,7 @@ fn bindgen_test_layout__cef_popup_features_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_popup_features_t>())).xSet as *const _ as usize },
+        ::std::mem::offset_of!(_cef_popup_features_t, xSet),
         4usize,
         concat!(
             "Offset of field: ",
@@ -3894,7 +3720,7 @@ fn bindgen_test_layout__cef_popup_features_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_popup_features_t>())).y as *const _ as usize },
+        ::std::mem::offset_of!(_cef_popup_features_t, y),
         8usize,
         concat!(
             "Offset of field: ",
@@ -3904,7 +3730,7 @@ fn bindgen_test_layout__cef_popup_features_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_popup_features_t>())).ySet as *const _ as usize },
+        ::std::mem::offset_of!(_cef_popup_features_t, ySet),
         12usize,
         concat!(
             "Offset of field: ",
@@ -3914,7 +3740,7 @@ fn bindgen_test_layout__cef_popup_features_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_popup_features_t>())).width as *const _ as usize },
+        ::std::mem::offset_of!(_cef_popup_features_t, width),
         16usize,
         concat!(
             "Offset of field: ",
@@ -3924,7 +3750,7 @@ fn bindgen_test_layout__cef_popup_features_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_popup_features_t>())).widthSet as *const _ as usize },
+        ::std::mem::offset_of!(_cef_popup_features_t, widthSet),
         20usize,
         concat!(
             "Offset of field: ",
@@ -3934,7 +3760,7 @@ fn bindgen_test_layout__cef_popup_features_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_popup_features_t>())).height as *const _ as usize },
+        ::std::mem::offset_of!(_cef_popup_features_t, height),
         24usize,
         concat!(
             "Offset of field: ",
@@ -3944,7 +3770,7 @@ fn bindgen_test_layout__cef_popup_features_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_popup_features_t>())).heightSet as *const _ as usize },
+        ::std::mem::offset_of!(_cef_popup_features_t, heightSet),
         28usize,
         concat!(
             "Offset of field: ",
@@ -3954,9 +3780,7 @@ fn bindgen_test_layout__cef_popup_features_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_popup_features_t>())).menuBarVisible as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_popup_features_t, menuBarVisible),
         32usize,
         concat!(
             "Offset of field: ",
@@ -3966,9 +3790,7 @@ fn bindgen_test_layout__cef_popup_features_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_popup_features_t>())).statusBarVisible as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_popup_features_t, statusBarVisible),
         36usize,
         concat!(
             "Offset of field: ",
@@ -3978,9 +3800,7 @@ fn bindgen_test_layout__cef_popup_features_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_popup_features_t>())).toolBarVisible as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_popup_features_t, toolBarVisible),
         40usize,
         concat!(
             "Offset of field: ",
@@ -3990,9 +3810,7 @@ fn bindgen_test_layout__cef_popup_features_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_popup_features_t>())).scrollbarsVisible as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_popup_features_t, scrollbarsVisible),
         44usize,
         concat!(
             "Offset of field: ",
@@ -4207,7 +4025,7 @@ fn bindgen_test_layout__cef_cursor_info_t() {
         concat!("Alignment of ", stringify!(_cef_cursor_info_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_cursor_info_t>())).hotspot as *const _ as usize },
+        ::std::mem::offset_of!(_cef_cursor_info_t, hotspot),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4217,9 +4035,7 @@ fn bindgen_test_layout__cef_cursor_info_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_cursor_info_t>())).image_scale_factor as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_cursor_info_t, image_scale_factor),
         8usize,
         concat!(
             "Offset of field: ",
@@ -4229,7 +4045,7 @@ fn bindgen_test_layout__cef_cursor_info_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_cursor_info_t>())).buffer as *const _ as usize },
+        ::std::mem::offset_of!(_cef_cursor_info_t, buffer),
         16usize,
         concat!(
             "Offset of field: ",
@@ -4239,7 +4055,7 @@ fn bindgen_test_layout__cef_cursor_info_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_cursor_info_t>())).size as *const _ as usize },
+        ::std::mem::offset_of!(_cef_cursor_info_t, size),
         24usize,
         concat!(
             "Offset of field: ",
@@ -4428,10 +4244,7 @@ fn bindgen_test_layout__cef_pdf_print_settings_t() {
         concat!("Alignment of ", stringify!(_cef_pdf_print_settings_t))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_pdf_print_settings_t>())).header_footer_title as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_pdf_print_settings_t, header_footer_title),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4441,10 +4254,7 @@ fn bindgen_test_layout__cef_pdf_print_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_pdf_print_settings_t>())).header_footer_url as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_pdf_print_settings_t, header_footer_url),
         24usize,
         concat!(
             "Offset of field: ",
@@ -4454,9 +4264,7 @@ fn bindgen_test_layout__cef_pdf_print_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_pdf_print_settings_t>())).page_width as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_pdf_print_settings_t, page_width),
         48usize,
         concat!(
             "Offset of field: ",
@@ -4466,9 +4274,7 @@ fn bindgen_test_layout__cef_pdf_print_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_pdf_print_settings_t>())).page_height as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_pdf_print_settings_t, page_height),
         52usize,
         concat!(
             "Offset of field: ",
@@ -4478,9 +4284,7 @@ fn bindgen_test_layout__cef_pdf_print_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_pdf_print_settings_t>())).scale_factor as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_pdf_print_settings_t, scale_factor),
         56usize,
         concat!(
             "Offset of field: ",
@@ -4490,9 +4294,7 @@ fn bindgen_test_layout__cef_pdf_print_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_pdf_print_settings_t>())).margin_top as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_pdf_print_settings_t, margin_top),
         60usize,
         concat!(
             "Offset of field: ",
@@ -4502,9 +4304,7 @@ fn bindgen_test_layout__cef_pdf_print_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_pdf_print_settings_t>())).margin_right as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_pdf_print_settings_t, margin_right),
         64usize,
         concat!(
             "Offset of field: ",
@@ -4514,9 +4314,7 @@ fn bindgen_test_layout__cef_pdf_print_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_pdf_print_settings_t>())).margin_bottom as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_pdf_print_settings_t, margin_bottom),
         68usize,
         concat!(
             "Offset of field: ",
@@ -4526,9 +4324,7 @@ fn bindgen_test_layout__cef_pdf_print_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_pdf_print_settings_t>())).margin_left as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_pdf_print_settings_t, margin_left),
         72usize,
         concat!(
             "Offset of field: ",
@@ -4538,9 +4334,7 @@ fn bindgen_test_layout__cef_pdf_print_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_pdf_print_settings_t>())).margin_type as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_pdf_print_settings_t, margin_type),
         76usize,
         concat!(
             "Offset of field: ",
@@ -4550,10 +4344,7 @@ fn bindgen_test_layout__cef_pdf_print_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_pdf_print_settings_t>())).header_footer_enabled as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_pdf_print_settings_t, header_footer_enabled),
         80usize,
         concat!(
             "Offset of field: ",
@@ -4563,10 +4354,7 @@ fn bindgen_test_layout__cef_pdf_print_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_pdf_print_settings_t>())).selection_only as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_pdf_print_settings_t, selection_only),
         84usize,
         concat!(
             "Offset of field: ",
@@ -4576,9 +4364,7 @@ fn bindgen_test_layout__cef_pdf_print_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_pdf_print_settings_t>())).landscape as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_pdf_print_settings_t, landscape),
         88usize,
         concat!(
             "Offset of field: ",
@@ -4588,10 +4374,7 @@ fn bindgen_test_layout__cef_pdf_print_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_pdf_print_settings_t>())).backgrounds_enabled as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_pdf_print_settings_t, backgrounds_enabled),
         92usize,
         concat!(
             "Offset of field: ",
@@ -4699,9 +4482,7 @@ fn bindgen_test_layout__cef_box_layout_settings_t() {
         concat!("Alignment of ", stringify!(_cef_box_layout_settings_t))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_box_layout_settings_t>())).horizontal as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_box_layout_settings_t, horizontal),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4711,10 +4492,7 @@ fn bindgen_test_layout__cef_box_layout_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_box_layout_settings_t>())).inside_border_horizontal_spacing
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_box_layout_settings_t, inside_border_horizontal_spacing),
         4usize,
         concat!(
             "Offset of field: ",
@@ -4724,10 +4502,7 @@ fn bindgen_test_layout__cef_box_layout_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_box_layout_settings_t>())).inside_border_vertical_spacing
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_box_layout_settings_t, inside_border_vertical_spacing),
         8usize,
         concat!(
             "Offset of field: ",
@@ -4737,10 +4512,7 @@ fn bindgen_test_layout__cef_box_layout_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_box_layout_settings_t>())).inside_border_insets as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_box_layout_settings_t, inside_border_insets),
         12usize,
         concat!(
             "Offset of field: ",
@@ -4750,10 +4522,7 @@ fn bindgen_test_layout__cef_box_layout_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_box_layout_settings_t>())).between_child_spacing as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_box_layout_settings_t, between_child_spacing),
         28usize,
         concat!(
             "Offset of field: ",
@@ -4763,10 +4532,7 @@ fn bindgen_test_layout__cef_box_layout_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_box_layout_settings_t>())).main_axis_alignment as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_box_layout_settings_t, main_axis_alignment),
         32usize,
         concat!(
             "Offset of field: ",
@@ -4776,10 +4542,7 @@ fn bindgen_test_layout__cef_box_layout_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_box_layout_settings_t>())).cross_axis_alignment as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_box_layout_settings_t, cross_axis_alignment),
         36usize,
         concat!(
             "Offset of field: ",
@@ -4789,10 +4552,7 @@ fn bindgen_test_layout__cef_box_layout_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_box_layout_settings_t>())).minimum_cross_axis_size
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_box_layout_settings_t, minimum_cross_axis_size),
         40usize,
         concat!(
             "Offset of field: ",
@@ -4802,9 +4562,7 @@ fn bindgen_test_layout__cef_box_layout_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_box_layout_settings_t>())).default_flex as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_box_layout_settings_t, default_flex),
         44usize,
         concat!(
             "Offset of field: ",
@@ -4984,9 +4742,7 @@ fn bindgen_test_layout__cef_composition_underline_t() {
         concat!("Alignment of ", stringify!(_cef_composition_underline_t))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_composition_underline_t>())).range as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_composition_underline_t, range),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4996,9 +4752,7 @@ fn bindgen_test_layout__cef_composition_underline_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_composition_underline_t>())).color as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_composition_underline_t, color),
         8usize,
         concat!(
             "Offset of field: ",
@@ -5008,10 +4762,7 @@ fn bindgen_test_layout__cef_composition_underline_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_composition_underline_t>())).background_color as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_composition_underline_t, background_color),
         12usize,
         concat!(
             "Offset of field: ",
@@ -5021,9 +4772,7 @@ fn bindgen_test_layout__cef_composition_underline_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_composition_underline_t>())).thick as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_composition_underline_t, thick),
         16usize,
         concat!(
             "Offset of field: ",
@@ -5033,9 +4782,7 @@ fn bindgen_test_layout__cef_composition_underline_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_composition_underline_t>())).style as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_composition_underline_t, style),
         20usize,
         concat!(
             "Offset of field: ",
@@ -5103,9 +4850,7 @@ fn bindgen_test_layout__cef_audio_parameters_t() {
         concat!("Alignment of ", stringify!(_cef_audio_parameters_t))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_audio_parameters_t>())).channel_layout as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_audio_parameters_t, channel_layout),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5115,9 +4860,7 @@ fn bindgen_test_layout__cef_audio_parameters_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_audio_parameters_t>())).sample_rate as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_audio_parameters_t, sample_rate),
         4usize,
         concat!(
             "Offset of field: ",
@@ -5127,10 +4870,7 @@ fn bindgen_test_layout__cef_audio_parameters_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_audio_parameters_t>())).frames_per_buffer as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_audio_parameters_t, frames_per_buffer),
         8usize,
         concat!(
             "Offset of field: ",
@@ -5194,10 +4934,7 @@ fn bindgen_test_layout__cef_media_sink_device_info_t() {
         concat!("Alignment of ", stringify!(_cef_media_sink_device_info_t))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_media_sink_device_info_t>())).ip_address as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_media_sink_device_info_t, ip_address),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5207,9 +4944,7 @@ fn bindgen_test_layout__cef_media_sink_device_info_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_media_sink_device_info_t>())).port as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_media_sink_device_info_t, port),
         24usize,
         concat!(
             "Offset of field: ",
@@ -5219,10 +4954,7 @@ fn bindgen_test_layout__cef_media_sink_device_info_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_media_sink_device_info_t>())).model_name as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_media_sink_device_info_t, model_name),
         32usize,
         concat!(
             "Offset of field: ",
@@ -5270,7 +5002,7 @@ fn bindgen_test_layout__cef_base_ref_counted_t() {
         concat!("Alignment of ", stringify!(_cef_base_ref_counted_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_base_ref_counted_t>())).size as *const _ as usize },
+        ::std::mem::offset_of!(_cef_base_ref_counted_t, size),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5280,7 +5012,7 @@ fn bindgen_test_layout__cef_base_ref_counted_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_base_ref_counted_t>())).add_ref as *const _ as usize },
+        ::std::mem::offset_of!(_cef_base_ref_counted_t, add_ref),
         8usize,
         concat!(
             "Offset of field: ",
@@ -5290,7 +5022,7 @@ fn bindgen_test_layout__cef_base_ref_counted_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_base_ref_counted_t>())).release as *const _ as usize },
+        ::std::mem::offset_of!(_cef_base_ref_counted_t, release),
         16usize,
         concat!(
             "Offset of field: ",
@@ -5300,9 +5032,7 @@ fn bindgen_test_layout__cef_base_ref_counted_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_base_ref_counted_t>())).has_one_ref as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_base_ref_counted_t, has_one_ref),
         24usize,
         concat!(
             "Offset of field: ",
@@ -5312,10 +5042,7 @@ fn bindgen_test_layout__cef_base_ref_counted_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_base_ref_counted_t>())).has_at_least_one_ref as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_base_ref_counted_t, has_at_least_one_ref),
         32usize,
         concat!(
             "Offset of field: ",
@@ -5345,7 +5072,7 @@ fn bindgen_test_layout__cef_base_scoped_t() {
         concat!("Alignment of ", stringify!(_cef_base_scoped_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_base_scoped_t>())).size as *const _ as usize },
+        ::std::mem::offset_of!(_cef_base_scoped_t, size),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5355,7 +5082,7 @@ fn bindgen_test_layout__cef_base_scoped_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_base_scoped_t>())).del as *const _ as usize },
+        ::std::mem::offset_of!(_cef_base_scoped_t, del),
         8usize,
         concat!(
             "Offset of field: ",
@@ -5471,7 +5198,7 @@ fn bindgen_test_layout__cef_value_t() {
         concat!("Alignment of ", stringify!(_cef_value_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_value_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_value_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5481,7 +5208,7 @@ fn bindgen_test_layout__cef_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_value_t>())).is_valid as *const _ as usize },
+        ::std::mem::offset_of!(_cef_value_t, is_valid),
         40usize,
         concat!(
             "Offset of field: ",
@@ -5491,7 +5218,7 @@ fn bindgen_test_layout__cef_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_value_t>())).is_owned as *const _ as usize },
+        ::std::mem::offset_of!(_cef_value_t, is_owned),
         48usize,
         concat!(
             "Offset of field: ",
@@ -5501,7 +5228,7 @@ fn bindgen_test_layout__cef_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_value_t>())).is_read_only as *const _ as usize },
+        ::std::mem::offset_of!(_cef_value_t, is_read_only),
         56usize,
         concat!(
             "Offset of field: ",
@@ -5511,7 +5238,7 @@ fn bindgen_test_layout__cef_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_value_t>())).is_same as *const _ as usize },
+        ::std::mem::offset_of!(_cef_value_t, is_same),
         64usize,
         concat!(
             "Offset of field: ",
@@ -5521,7 +5248,7 @@ fn bindgen_test_layout__cef_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_value_t>())).is_equal as *const _ as usize },
+        ::std::mem::offset_of!(_cef_value_t, is_equal),
         72usize,
         concat!(
             "Offset of field: ",
@@ -5531,7 +5258,7 @@ fn bindgen_test_layout__cef_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_value_t>())).copy as *const _ as usize },
+        ::std::mem::offset_of!(_cef_value_t, copy),
         80usize,
         concat!(
             "Offset of field: ",
@@ -5541,7 +5268,7 @@ fn bindgen_test_layout__cef_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_value_t>())).get_type as *const _ as usize },
+        ::std::mem::offset_of!(_cef_value_t, get_type),
         88usize,
         concat!(
             "Offset of field: ",
@@ -5551,7 +5278,7 @@ fn bindgen_test_layout__cef_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_value_t>())).get_bool as *const _ as usize },
+        ::std::mem::offset_of!(_cef_value_t, get_bool),
         96usize,
         concat!(
             "Offset of field: ",
@@ -5561,7 +5288,7 @@ fn bindgen_test_layout__cef_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_value_t>())).get_int as *const _ as usize },
+        ::std::mem::offset_of!(_cef_value_t, get_int),
         104usize,
         concat!(
             "Offset of field: ",
@@ -5571,7 +5298,7 @@ fn bindgen_test_layout__cef_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_value_t>())).get_double as *const _ as usize },
+        ::std::mem::offset_of!(_cef_value_t, get_double),
         112usize,
         concat!(
             "Offset of field: ",
@@ -5581,7 +5308,7 @@ fn bindgen_test_layout__cef_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_value_t>())).get_string as *const _ as usize },
+        ::std::mem::offset_of!(_cef_value_t, get_string),
         120usize,
         concat!(
             "Offset of field: ",
@@ -5591,7 +5318,7 @@ fn bindgen_test_layout__cef_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_value_t>())).get_binary as *const _ as usize },
+        ::std::mem::offset_of!(_cef_value_t, get_binary),
         128usize,
         concat!(
             "Offset of field: ",
@@ -5601,7 +5328,7 @@ fn bindgen_test_layout__cef_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_value_t>())).get_dictionary as *const _ as usize },
+        ::std::mem::offset_of!(_cef_value_t, get_dictionary),
         136usize,
         concat!(
             "Offset of field: ",
@@ -5611,7 +5338,7 @@ fn bindgen_test_layout__cef_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_value_t>())).get_list as *const _ as usize },
+        ::std::mem::offset_of!(_cef_value_t, get_list),
         144usize,
         concat!(
             "Offset of field: ",
@@ -5621,7 +5348,7 @@ fn bindgen_test_layout__cef_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_value_t>())).set_null as *const _ as usize },
+        ::std::mem::offset_of!(_cef_value_t, set_null),
         152usize,
         concat!(
             "Offset of field: ",
@@ -5631,7 +5358,7 @@ fn bindgen_test_layout__cef_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_value_t>())).set_bool as *const _ as usize },
+        ::std::mem::offset_of!(_cef_value_t, set_bool),
         160usize,
         concat!(
             "Offset of field: ",
@@ -5641,7 +5368,7 @@ fn bindgen_test_layout__cef_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_value_t>())).set_int as *const _ as usize },
+        ::std::mem::offset_of!(_cef_value_t, set_int),
         168usize,
         concat!(
             "Offset of field: ",
@@ -5651,7 +5378,7 @@ fn bindgen_test_layout__cef_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_value_t>())).set_double as *const _ as usize },
+        ::std::mem::offset_of!(_cef_value_t, set_double),
         176usize,
         concat!(
             "Offset of field: ",
@@ -5661,7 +5388,7 @@ fn bindgen_test_layout__cef_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_value_t>())).set_string as *const _ as usize },
+        ::std::mem::offset_of!(_cef_value_t, set_string),
         184usize,
         concat!(
             "Offset of field: ",
@@ -5671,7 +5398,7 @@ fn bindgen_test_layout__cef_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_value_t>())).set_binary as *const _ as usize },
+        ::std::mem::offset_of!(_cef_value_t, set_binary),
         192usize,
         concat!(
             "Offset of field: ",
@@ -5681,7 +5408,7 @@ fn bindgen_test_layout__cef_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_value_t>())).set_dictionary as *const _ as usize },
+        ::std::mem::offset_of!(_cef_value_t, set_dictionary),
         200usize,
         concat!(
             "Offset of field: ",
@@ -5691,7 +5418,7 @@ fn bindgen_test_layout__cef_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_value_t>())).set_list as *const _ as usize },
+        ::std::mem::offset_of!(_cef_value_t, set_list),
         208usize,
         concat!(
             "Offset of field: ",
@@ -5754,7 +5481,7 @@ fn bindgen_test_layout__cef_binary_value_t() {
         concat!("Alignment of ", stringify!(_cef_binary_value_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_binary_value_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_binary_value_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5764,7 +5491,7 @@ fn bindgen_test_layout__cef_binary_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_binary_value_t>())).is_valid as *const _ as usize },
+        ::std::mem::offset_of!(_cef_binary_value_t, is_valid),
         40usize,
         concat!(
             "Offset of field: ",
@@ -5774,7 +5501,7 @@ fn bindgen_test_layout__cef_binary_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_binary_value_t>())).is_owned as *const _ as usize },
+        ::std::mem::offset_of!(_cef_binary_value_t, is_owned),
         48usize,
         concat!(
             "Offset of field: ",
@@ -5784,7 +5511,7 @@ fn bindgen_test_layout__cef_binary_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_binary_value_t>())).is_same as *const _ as usize },
+        ::std::mem::offset_of!(_cef_binary_value_t, is_same),
         56usize,
         concat!(
             "Offset of field: ",
@@ -5794,7 +5521,7 @@ fn bindgen_test_layout__cef_binary_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_binary_value_t>())).is_equal as *const _ as usize },
+        ::std::mem::offset_of!(_cef_binary_value_t, is_equal),
         64usize,
         concat!(
             "Offset of field: ",
@@ -5804,7 +5531,7 @@ fn bindgen_test_layout__cef_binary_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_binary_value_t>())).copy as *const _ as usize },
+        ::std::mem::offset_of!(_cef_binary_value_t, copy),
         72usize,
         concat!(
             "Offset of field: ",
@@ -5814,7 +5541,7 @@ fn bindgen_test_layout__cef_binary_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_binary_value_t>())).get_size as *const _ as usize },
+        ::std::mem::offset_of!(_cef_binary_value_t, get_size),
         80usize,
         concat!(
             "Offset of field: ",
@@ -5824,7 +5551,7 @@ fn bindgen_test_layout__cef_binary_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_binary_value_t>())).get_data as *const _ as usize },
+        ::std::mem::offset_of!(_cef_binary_value_t, get_data),
         88usize,
         concat!(
             "Offset of field: ",
@@ -6022,7 +5749,7 @@ fn bindgen_test_layout__cef_dictionary_value_t() {
         concat!("Alignment of ", stringify!(_cef_dictionary_value_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_dictionary_value_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_dictionary_value_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -6032,9 +5759,7 @@ fn bindgen_test_layout__cef_dictionary_value_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_dictionary_value_t>())).is_valid as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_dictionary_value_t, is_valid),
         40usize,
         concat!(
             "Offset of field: ",
@@ -6044,9 +5769,7 @@ fn bindgen_test_layout__cef_dictionary_value_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_dictionary_value_t>())).is_owned as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_dictionary_value_t, is_owned),
         48usize,
         concat!(
             "Offset of field: ",
@@ -6056,9 +5779,7 @@ fn bindgen_test_layout__cef_dictionary_value_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_dictionary_value_t>())).is_read_only as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_dictionary_value_t, is_read_only),
         56usize,
         concat!(
             "Offset of field: ",
@@ -6068,7 +5789,7 @@ fn bindgen_test_layout__cef_dictionary_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_dictionary_value_t>())).is_same as *const _ as usize },
+        ::std::mem::offset_of!(_cef_dictionary_value_t, is_same),
         64usize,
         concat!(
             "Offset of field: ",
@@ -6078,9 +5799,7 @@ fn bindgen_test_layout__cef_dictionary_value_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_dictionary_value_t>())).is_equal as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_dictionary_value_t, is_equal),
         72usize,
         concat!(
             "Offset of field: ",
@@ -6090,7 +5809,7 @@ fn bindgen_test_layout__cef_dictionary_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_dictionary_value_t>())).copy as *const _ as usize },
+        ::std::mem::offset_of!(_cef_dictionary_value_t, copy),
         80usize,
         concat!(
             "Offset of field: ",
@@ -6100,9 +5819,7 @@ fn bindgen_test_layout__cef_dictionary_value_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_dictionary_value_t>())).get_size as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_dictionary_value_t, get_size),
         88usize,
         concat!(
             "Offset of field: ",
@@ -6112,7 +5829,7 @@ fn bindgen_test_layout__cef_dictionary_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_dictionary_value_t>())).clear as *const _ as usize },
+        ::std::mem::offset_of!(_cef_dictionary_value_t, clear),
         96usize,
         concat!(
             "Offset of field: ",
@@ -6122,7 +5839,7 @@ fn bindgen_test_layout__cef_dictionary_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_dictionary_value_t>())).has_key as *const _ as usize },
+        ::std::mem::offset_of!(_cef_dictionary_value_t, has_key),
         104usize,
         concat!(
             "Offset of field: ",
@@ -6132,9 +5849,7 @@ fn bindgen_test_layout__cef_dictionary_value_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_dictionary_value_t>())).get_keys as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_dictionary_value_t, get_keys),
         112usize,
         concat!(
             "Offset of field: ",
@@ -6144,7 +5859,7 @@ fn bindgen_test_layout__cef_dictionary_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_dictionary_value_t>())).remove as *const _ as usize },
+        ::std::mem::offset_of!(_cef_dictionary_value_t, remove),
         120usize,
         concat!(
             "Offset of field: ",
@@ -6154,9 +5869,7 @@ fn bindgen_test_layout__cef_dictionary_value_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_dictionary_value_t>())).get_type as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_dictionary_value_t, get_type),
         128usize,
         concat!(
             "Offset of field: ",
@@ -6166,9 +5879,7 @@ fn bindgen_test_layout__cef_dictionary_value_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_dictionary_value_t>())).get_value as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_dictionary_value_t, get_value),
         136usize,
         concat!(
             "Offset of field: ",
@@ -6178,9 +5889,7 @@ fn bindgen_test_layout__cef_dictionary_value_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_dictionary_value_t>())).get_bool as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_dictionary_value_t, get_bool),
         144usize,
         concat!(
             "Offset of field: ",
@@ -6190,7 +5899,7 @@ fn bindgen_test_layout__cef_dictionary_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_dictionary_value_t>())).get_int as *const _ as usize },
+        ::std::mem::offset_of!(_cef_dictionary_value_t, get_int),
         152usize,
         concat!(
             "Offset of field: ",
@@ -6200,9 +5909,7 @@ fn bindgen_test_layout__cef_dictionary_value_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_dictionary_value_t>())).get_double as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_dictionary_value_t, get_double),
         160usize,
         concat!(
             "Offset of field: ",
@@ -6212,9 +5919,7 @@ fn bindgen_test_layout__cef_dictionary_value_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_dictionary_value_t>())).get_string as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_dictionary_value_t, get_string),
         168usize,
         concat!(
             "Offset of field: ",
@@ -6224,9 +5929,7 @@ fn bindgen_test_layout__cef_dictionary_value_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_dictionary_value_t>())).get_binary as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_dictionary_value_t, get_binary),
         176usize,
         concat!(
             "Offset of field: ",
@@ -6236,9 +5939,7 @@ fn bindgen_test_layout__cef_dictionary_value_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_dictionary_value_t>())).get_dictionary as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_dictionary_value_t, get_dictionary),
         184usize,
         concat!(
             "Offset of field: ",
@@ -6248,9 +5949,7 @@ fn bindgen_test_layout__cef_dictionary_value_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_dictionary_value_t>())).get_list as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_dictionary_value_t, get_list),
         192usize,
         concat!(
             "Offset of field: ",
@@ -6260,9 +5959,7 @@ fn bindgen_test_layout__cef_dictionary_value_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_dictionary_value_t>())).set_value as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_dictionary_value_t, set_value),
         200usize,
         concat!(
             "Offset of field: ",
@@ -6272,9 +5969,7 @@ fn bindgen_test_layout__cef_dictionary_value_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_dictionary_value_t>())).set_null as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_dictionary_value_t, set_null),
         208usize,
         concat!(
             "Offset of field: ",
@@ -6284,9 +5979,7 @@ fn bindgen_test_layout__cef_dictionary_value_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_dictionary_value_t>())).set_bool as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_dictionary_value_t, set_bool),
         216usize,
         concat!(
             "Offset of field: ",
@@ -6296,7 +5989,7 @@ fn bindgen_test_layout__cef_dictionary_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_dictionary_value_t>())).set_int as *const _ as usize },
+        ::std::mem::offset_of!(_cef_dictionary_value_t, set_int),
         224usize,
         concat!(
             "Offset of field: ",
@@ -6306,9 +5999,7 @@ fn bindgen_test_layout__cef_dictionary_value_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_dictionary_value_t>())).set_double as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_dictionary_value_t, set_double),
         232usize,
         concat!(
             "Offset of field: ",
@@ -6318,9 +6009,7 @@ fn bindgen_test_layout__cef_dictionary_value_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_dictionary_value_t>())).set_string as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_dictionary_value_t, set_string),
         240usize,
         concat!(
             "Offset of field: ",
@@ -6330,9 +6019,7 @@ fn bindgen_test_layout__cef_dictionary_value_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_dictionary_value_t>())).set_binary as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_dictionary_value_t, set_binary),
         248usize,
         concat!(
             "Offset of field: ",
@@ -6342,9 +6029,7 @@ fn bindgen_test_layout__cef_dictionary_value_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_dictionary_value_t>())).set_dictionary as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_dictionary_value_t, set_dictionary),
         256usize,
         concat!(
             "Offset of field: ",
@@ -6354,9 +6039,7 @@ fn bindgen_test_layout__cef_dictionary_value_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_dictionary_value_t>())).set_list as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_dictionary_value_t, set_list),
         264usize,
         concat!(
             "Offset of field: ",
@@ -6515,7 +6198,7 @@ fn bindgen_test_layout__cef_list_value_t() {
         concat!("Alignment of ", stringify!(_cef_list_value_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_list_value_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_list_value_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -6525,7 +6208,7 @@ fn bindgen_test_layout__cef_list_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_list_value_t>())).is_valid as *const _ as usize },
+        ::std::mem::offset_of!(_cef_list_value_t, is_valid),
         40usize,
         concat!(
             "Offset of field: ",
@@ -6535,7 +6218,7 @@ fn bindgen_test_layout__cef_list_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_list_value_t>())).is_owned as *const _ as usize },
+        ::std::mem::offset_of!(_cef_list_value_t, is_owned),
         48usize,
         concat!(
             "Offset of field: ",
@@ -6545,7 +6228,7 @@ fn bindgen_test_layout__cef_list_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_list_value_t>())).is_read_only as *const _ as usize },
+        ::std::mem::offset_of!(_cef_list_value_t, is_read_only),
         56usize,
         concat!(
             "Offset of field: ",
@@ -6555,7 +6238,7 @@ fn bindgen_test_layout__cef_list_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_list_value_t>())).is_same as *const _ as usize },
+        ::std::mem::offset_of!(_cef_list_value_t, is_same),
         64usize,
         concat!(
             "Offset of field: ",
@@ -6565,7 +6248,7 @@ fn bindgen_test_layout__cef_list_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_list_value_t>())).is_equal as *const _ as usize },
+        ::std::mem::offset_of!(_cef_list_value_t, is_equal),
         72usize,
         concat!(
             "Offset of field: ",
@@ -6575,7 +6258,7 @@ fn bindgen_test_layout__cef_list_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_list_value_t>())).copy as *const _ as usize },
+        ::std::mem::offset_of!(_cef_list_value_t, copy),
         80usize,
         concat!(
             "Offset of field: ",
@@ -6585,7 +6268,7 @@ fn bindgen_test_layout__cef_list_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_list_value_t>())).set_size as *const _ as usize },
+        ::std::mem::offset_of!(_cef_list_value_t, set_size),
         88usize,
         concat!(
             "Offset of field: ",
@@ -6595,7 +6278,7 @@ fn bindgen_test_layout__cef_list_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_list_value_t>())).get_size as *const _ as usize },
+        ::std::mem::offset_of!(_cef_list_value_t, get_size),
         96usize,
         concat!(
             "Offset of field: ",
@@ -6605,7 +6288,7 @@ fn bindgen_test_layout__cef_list_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_list_value_t>())).clear as *const _ as usize },
+        ::std::mem::offset_of!(_cef_list_value_t, clear),
         104usize,
         concat!(
             "Offset of field: ",
@@ -6615,7 +6298,7 @@ fn bindgen_test_layout__cef_list_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_list_value_t>())).remove as *const _ as usize },
+        ::std::mem::offset_of!(_cef_list_value_t, remove),
         112usize,
         concat!(
             "Offset of field: ",
@@ -6625,7 +6308,7 @@ fn bindgen_test_layout__cef_list_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_list_value_t>())).get_type as *const _ as usize },
+        ::std::mem::offset_of!(_cef_list_value_t, get_type),
         120usize,
         concat!(
             "Offset of field: ",
@@ -6635,7 +6318,7 @@ fn bindgen_test_layout__cef_list_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_list_value_t>())).get_value as *const _ as usize },
+        ::std::mem::offset_of!(_cef_list_value_t, get_value),
         128usize,
         concat!(
             "Offset of field: ",
@@ -6645,7 +6328,7 @@ fn bindgen_test_layout__cef_list_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_list_value_t>())).get_bool as *const _ as usize },
+        ::std::mem::offset_of!(_cef_list_value_t, get_bool),
         136usize,
         concat!(
             "Offset of field: ",
@@ -6655,7 +6338,7 @@ fn bindgen_test_layout__cef_list_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_list_value_t>())).get_int as *const _ as usize },
+        ::std::mem::offset_of!(_cef_list_value_t, get_int),
         144usize,
         concat!(
             "Offset of field: ",
@@ -6665,7 +6348,7 @@ fn bindgen_test_layout__cef_list_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_list_value_t>())).get_double as *const _ as usize },
+        ::std::mem::offset_of!(_cef_list_value_t, get_double),
         152usize,
         concat!(
             "Offset of field: ",
@@ -6675,7 +6358,7 @@ fn bindgen_test_layout__cef_list_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_list_value_t>())).get_string as *const _ as usize },
+        ::std::mem::offset_of!(_cef_list_value_t, get_string),
         160usize,
         concat!(
             "Offset of field: ",
@@ -6685,7 +6368,7 @@ fn bindgen_test_layout__cef_list_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_list_value_t>())).get_binary as *const _ as usize },
+        ::std::mem::offset_of!(_cef_list_value_t, get_binary),
         168usize,
         concat!(
             "Offset of field: ",
@@ -6695,9 +6378,7 @@ fn bindgen_test_layout__cef_list_value_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_list_value_t>())).get_dictionary as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_list_value_t, get_dictionary),
         176usize,
         concat!(
             "Offset of field: ",
@@ -6707,7 +6388,7 @@ fn bindgen_test_layout__cef_list_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_list_value_t>())).get_list as *const _ as usize },
+        ::std::mem::offset_of!(_cef_list_value_t, get_list),
         184usize,
         concat!(
             "Offset of field: ",
@@ -6717,7 +6398,7 @@ fn bindgen_test_layout__cef_list_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_list_value_t>())).set_value as *const _ as usize },
+        ::std::mem::offset_of!(_cef_list_value_t, set_value),
         192usize,
         concat!(
             "Offset of field: ",
@@ -6727,7 +6408,7 @@ fn bindgen_test_layout__cef_list_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_list_value_t>())).set_null as *const _ as usize },
+        ::std::mem::offset_of!(_cef_list_value_t, set_null),
         200usize,
         concat!(
             "Offset of field: ",
@@ -6737,7 +6418,7 @@ fn bindgen_test_layout__cef_list_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_list_value_t>())).set_bool as *const _ as usize },
+        ::std::mem::offset_of!(_cef_list_value_t, set_bool),
         208usize,
         concat!(
             "Offset of field: ",
@@ -6747,7 +6428,7 @@ fn bindgen_test_layout__cef_list_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_list_value_t>())).set_int as *const _ as usize },
+        ::std::mem::offset_of!(_cef_list_value_t, set_int),
         216usize,
         concat!(
             "Offset of field: ",
@@ -6757,7 +6438,7 @@ fn bindgen_test_layout__cef_list_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_list_value_t>())).set_double as *const _ as usize },
+        ::std::mem::offset_of!(_cef_list_value_t, set_double),
         224usize,
         concat!(
             "Offset of field: ",
@@ -6767,7 +6448,7 @@ fn bindgen_test_layout__cef_list_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_list_value_t>())).set_string as *const _ as usize },
+        ::std::mem::offset_of!(_cef_list_value_t, set_string),
         232usize,
         concat!(
             "Offset of field: ",
@@ -6777,7 +6458,7 @@ fn bindgen_test_layout__cef_list_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_list_value_t>())).set_binary as *const _ as usize },
+        ::std::mem::offset_of!(_cef_list_value_t, set_binary),
         240usize,
         concat!(
             "Offset of field: ",
@@ -6787,9 +6468,7 @@ fn bindgen_test_layout__cef_list_value_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_list_value_t>())).set_dictionary as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_list_value_t, set_dictionary),
         248usize,
         concat!(
             "Offset of field: ",
@@ -6799,7 +6478,7 @@ fn bindgen_test_layout__cef_list_value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_list_value_t>())).set_list as *const _ as usize },
+        ::std::mem::offset_of!(_cef_list_value_t, set_list),
         256usize,
         concat!(
             "Offset of field: ",
@@ -6837,9 +6516,7 @@ fn bindgen_test_layout__cef_accessibility_handler_t() {
         concat!("Alignment of ", stringify!(_cef_accessibility_handler_t))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_accessibility_handler_t>())).base as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_accessibility_handler_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -6849,10 +6526,7 @@ fn bindgen_test_layout__cef_accessibility_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_accessibility_handler_t>())).on_accessibility_tree_change
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_accessibility_handler_t, on_accessibility_tree_change),
         40usize,
         concat!(
             "Offset of field: ",
@@ -6862,10 +6536,7 @@ fn bindgen_test_layout__cef_accessibility_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_accessibility_handler_t>()))
-                .on_accessibility_location_change as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_accessibility_handler_t, on_accessibility_location_change),
         48usize,
         concat!(
             "Offset of field: ",
@@ -6966,7 +6637,7 @@ fn bindgen_test_layout__cef_command_line_t() {
         concat!("Alignment of ", stringify!(_cef_command_line_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_command_line_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_command_line_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -6976,7 +6647,7 @@ fn bindgen_test_layout__cef_command_line_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_command_line_t>())).is_valid as *const _ as usize },
+        ::std::mem::offset_of!(_cef_command_line_t, is_valid),
         40usize,
         concat!(
             "Offset of field: ",
@@ -6986,9 +6657,7 @@ fn bindgen_test_layout__cef_command_line_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_command_line_t>())).is_read_only as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_command_line_t, is_read_only),
         48usize,
         concat!(
             "Offset of field: ",
@@ -6998,7 +6667,7 @@ fn bindgen_test_layout__cef_command_line_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_command_line_t>())).copy as *const _ as usize },
+        ::std::mem::offset_of!(_cef_command_line_t, copy),
         56usize,
         concat!(
             "Offset of field: ",
@@ -7008,9 +6677,7 @@ fn bindgen_test_layout__cef_command_line_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_command_line_t>())).init_from_argv as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_command_line_t, init_from_argv),
         64usize,
         concat!(
             "Offset of field: ",
@@ -7020,9 +6687,7 @@ fn bindgen_test_layout__cef_command_line_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_command_line_t>())).init_from_string as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_command_line_t, init_from_string),
         72usize,
         concat!(
             "Offset of field: ",
@@ -7032,7 +6697,7 @@ fn bindgen_test_layout__cef_command_line_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_command_line_t>())).reset as *const _ as usize },
+        ::std::mem::offset_of!(_cef_command_line_t, reset),
         80usize,
         concat!(
             "Offset of field: ",
@@ -7042,7 +6707,7 @@ fn bindgen_test_layout__cef_command_line_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_command_line_t>())).get_argv as *const _ as usize },
+        ::std::mem::offset_of!(_cef_command_line_t, get_argv),
         88usize,
         concat!(
             "Offset of field: ",
@@ -7052,10 +6717,7 @@ fn bindgen_test_layout__cef_command_line_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_command_line_t>())).get_command_line_string as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_command_line_t, get_command_line_string),
         96usize,
         concat!(
             "Offset of field: ",
@@ -7065,7 +6727,7 @@ fn bindgen_test_layout__cef_command_line_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_command_line_t>())).get_program as *const _ as usize },
+        ::std::mem::offset_of!(_cef_command_line_t, get_program),
         104usize,
         concat!(
             "Offset of field: ",
@@ -7075,7 +6737,7 @@ fn bindgen_test_layout__cef_command_line_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_command_line_t>())).set_program as *const _ as usize },
+        ::std::mem::offset_of!(_cef_command_line_t, set_program),
         112usize,
         concat!(
             "Offset of field: ",
@@ -7085,9 +6747,7 @@ fn bindgen_test_layout__cef_command_line_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_command_line_t>())).has_switches as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_command_line_t, has_switches),
         120usize,
         concat!(
             "Offset of field: ",
@@ -7097,7 +6757,7 @@ fn bindgen_test_layout__cef_command_line_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_command_line_t>())).has_switch as *const _ as usize },
+        ::std::mem::offset_of!(_cef_command_line_t, has_switch),
         128usize,
         concat!(
             "Offset of field: ",
@@ -7107,9 +6767,7 @@ fn bindgen_test_layout__cef_command_line_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_command_line_t>())).get_switch_value as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_command_line_t, get_switch_value),
         136usize,
         concat!(
             "Offset of field: ",
@@ -7119,9 +6777,7 @@ fn bindgen_test_layout__cef_command_line_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_command_line_t>())).get_switches as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_command_line_t, get_switches),
         144usize,
         concat!(
             "Offset of field: ",
@@ -7131,9 +6787,7 @@ fn bindgen_test_layout__cef_command_line_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_command_line_t>())).append_switch as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_command_line_t, append_switch),
         152usize,
         concat!(
             "Offset of field: ",
@@ -7143,10 +6797,7 @@ fn bindgen_test_layout__cef_command_line_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_command_line_t>())).append_switch_with_value as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_command_line_t, append_switch_with_value),
         160usize,
         concat!(
             "Offset of field: ",
@@ -7156,9 +6807,7 @@ fn bindgen_test_layout__cef_command_line_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_command_line_t>())).has_arguments as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_command_line_t, has_arguments),
         168usize,
         concat!(
             "Offset of field: ",
@@ -7168,9 +6817,7 @@ fn bindgen_test_layout__cef_command_line_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_command_line_t>())).get_arguments as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_command_line_t, get_arguments),
         176usize,
         concat!(
             "Offset of field: ",
@@ -7180,9 +6827,7 @@ fn bindgen_test_layout__cef_command_line_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_command_line_t>())).append_argument as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_command_line_t, append_argument),
         184usize,
         concat!(
             "Offset of field: ",
@@ -7192,9 +6837,7 @@ fn bindgen_test_layout__cef_command_line_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_command_line_t>())).prepend_wrapper as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_command_line_t, prepend_wrapper),
         192usize,
         concat!(
             "Offset of field: ",
@@ -7271,9 +6914,7 @@ fn bindgen_test_layout__cef_dev_tools_message_observer_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_dev_tools_message_observer_t>())).base as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_dev_tools_message_observer_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -7283,10 +6924,7 @@ fn bindgen_test_layout__cef_dev_tools_message_observer_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_dev_tools_message_observer_t>())).on_dev_tools_message
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_dev_tools_message_observer_t, on_dev_tools_message),
         40usize,
         concat!(
             "Offset of field: ",
@@ -7296,10 +6934,7 @@ fn bindgen_test_layout__cef_dev_tools_message_observer_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_dev_tools_message_observer_t>())).on_dev_tools_method_result
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_dev_tools_message_observer_t, on_dev_tools_method_result),
         48usize,
         concat!(
             "Offset of field: ",
@@ -7309,10 +6944,7 @@ fn bindgen_test_layout__cef_dev_tools_message_observer_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_dev_tools_message_observer_t>())).on_dev_tools_event
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_dev_tools_message_observer_t, on_dev_tools_event),
         56usize,
         concat!(
             "Offset of field: ",
@@ -7322,10 +6954,7 @@ fn bindgen_test_layout__cef_dev_tools_message_observer_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_dev_tools_message_observer_t>()))
-                .on_dev_tools_agent_attached as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_dev_tools_message_observer_t, on_dev_tools_agent_attached),
         64usize,
         concat!(
             "Offset of field: ",
@@ -7335,10 +6964,7 @@ fn bindgen_test_layout__cef_dev_tools_message_observer_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_dev_tools_message_observer_t>()))
-                .on_dev_tools_agent_detached as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_dev_tools_message_observer_t, on_dev_tools_agent_detached),
         72usize,
         concat!(
             "Offset of field: ",
@@ -7449,7 +7075,7 @@ fn bindgen_test_layout__cef_image_t() {
         concat!("Alignment of ", stringify!(_cef_image_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_image_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_image_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -7459,7 +7085,7 @@ fn bindgen_test_layout__cef_image_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_image_t>())).is_empty as *const _ as usize },
+        ::std::mem::offset_of!(_cef_image_t, is_empty),
         40usize,
         concat!(
             "Offset of field: ",
@@ -7469,7 +7095,7 @@ fn bindgen_test_layout__cef_image_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_image_t>())).is_same as *const _ as usize },
+        ::std::mem::offset_of!(_cef_image_t, is_same),
         48usize,
         concat!(
             "Offset of field: ",
@@ -7479,7 +7105,7 @@ fn bindgen_test_layout__cef_image_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_image_t>())).add_bitmap as *const _ as usize },
+        ::std::mem::offset_of!(_cef_image_t, add_bitmap),
         56usize,
         concat!(
             "Offset of field: ",
@@ -7489,7 +7115,7 @@ fn bindgen_test_layout__cef_image_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_image_t>())).add_png as *const _ as usize },
+        ::std::mem::offset_of!(_cef_image_t, add_png),
         64usize,
         concat!(
             "Offset of field: ",
@@ -7499,7 +7125,7 @@ fn bindgen_test_layout__cef_image_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_image_t>())).add_jpeg as *const _ as usize },
+        ::std::mem::offset_of!(_cef_image_t, add_jpeg),
         72usize,
         concat!(
             "Offset of field: ",
@@ -7509,7 +7135,7 @@ fn bindgen_test_layout__cef_image_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_image_t>())).get_width as *const _ as usize },
+        ::std::mem::offset_of!(_cef_image_t, get_width),
         80usize,
         concat!(
             "Offset of field: ",
@@ -7519,7 +7145,7 @@ fn bindgen_test_layout__cef_image_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_image_t>())).get_height as *const _ as usize },
+        ::std::mem::offset_of!(_cef_image_t, get_height),
         88usize,
         concat!(
             "Offset of field: ",
@@ -7529,7 +7155,7 @@ fn bindgen_test_layout__cef_image_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_image_t>())).has_representation as *const _ as usize },
+        ::std::mem::offset_of!(_cef_image_t, has_representation),
         96usize,
         concat!(
             "Offset of field: ",
@@ -7539,9 +7165,7 @@ fn bindgen_test_layout__cef_image_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_image_t>())).remove_representation as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_image_t, remove_representation),
         104usize,
         concat!(
             "Offset of field: ",
@@ -7551,9 +7175,7 @@ fn bindgen_test_layout__cef_image_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_image_t>())).get_representation_info as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_image_t, get_representation_info),
         112usize,
         concat!(
             "Offset of field: ",
@@ -7563,7 +7185,7 @@ fn bindgen_test_layout__cef_image_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_image_t>())).get_as_bitmap as *const _ as usize },
+        ::std::mem::offset_of!(_cef_image_t, get_as_bitmap),
         120usize,
         concat!(
             "Offset of field: ",
@@ -7573,7 +7195,7 @@ fn bindgen_test_layout__cef_image_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_image_t>())).get_as_png as *const _ as usize },
+        ::std::mem::offset_of!(_cef_image_t, get_as_png),
         128usize,
         concat!(
             "Offset of field: ",
@@ -7583,7 +7205,7 @@ fn bindgen_test_layout__cef_image_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_image_t>())).get_as_jpeg as *const _ as usize },
+        ::std::mem::offset_of!(_cef_image_t, get_as_jpeg),
         136usize,
         concat!(
             "Offset of field: ",
@@ -7637,7 +7259,7 @@ fn bindgen_test_layout__cef_read_handler_t() {
         concat!("Alignment of ", stringify!(_cef_read_handler_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_read_handler_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_read_handler_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -7647,7 +7269,7 @@ fn bindgen_test_layout__cef_read_handler_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_read_handler_t>())).read as *const _ as usize },
+        ::std::mem::offset_of!(_cef_read_handler_t, read),
         40usize,
         concat!(
             "Offset of field: ",
@@ -7657,7 +7279,7 @@ fn bindgen_test_layout__cef_read_handler_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_read_handler_t>())).seek as *const _ as usize },
+        ::std::mem::offset_of!(_cef_read_handler_t, seek),
         48usize,
         concat!(
             "Offset of field: ",
@@ -7667,7 +7289,7 @@ fn bindgen_test_layout__cef_read_handler_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_read_handler_t>())).tell as *const _ as usize },
+        ::std::mem::offset_of!(_cef_read_handler_t, tell),
         56usize,
         concat!(
             "Offset of field: ",
@@ -7677,7 +7299,7 @@ fn bindgen_test_layout__cef_read_handler_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_read_handler_t>())).eof as *const _ as usize },
+        ::std::mem::offset_of!(_cef_read_handler_t, eof),
         64usize,
         concat!(
             "Offset of field: ",
@@ -7687,7 +7309,7 @@ fn bindgen_test_layout__cef_read_handler_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_read_handler_t>())).may_block as *const _ as usize },
+        ::std::mem::offset_of!(_cef_read_handler_t, may_block),
         72usize,
         concat!(
             "Offset of field: ",
@@ -7739,7 +7361,7 @@ fn bindgen_test_layout__cef_stream_reader_t() {
         concat!("Alignment of ", stringify!(_cef_stream_reader_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_stream_reader_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_stream_reader_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -7749,7 +7371,7 @@ fn bindgen_test_layout__cef_stream_reader_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_stream_reader_t>())).read as *const _ as usize },
+        ::std::mem::offset_of!(_cef_stream_reader_t, read),
         40usize,
         concat!(
             "Offset of field: ",
@@ -7759,7 +7381,7 @@ fn bindgen_test_layout__cef_stream_reader_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_stream_reader_t>())).seek as *const _ as usize },
+        ::std::mem::offset_of!(_cef_stream_reader_t, seek),
         48usize,
         concat!(
             "Offset of field: ",
@@ -7769,7 +7391,7 @@ fn bindgen_test_layout__cef_stream_reader_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_stream_reader_t>())).tell as *const _ as usize },
+        ::std::mem::offset_of!(_cef_stream_reader_t, tell),
         56usize,
         concat!(
             "Offset of field: ",
@@ -7779,7 +7401,7 @@ fn bindgen_test_layout__cef_stream_reader_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_stream_reader_t>())).eof as *const _ as usize },
+        ::std::mem::offset_of!(_cef_stream_reader_t, eof),
         64usize,
         concat!(
             "Offset of field: ",
@@ -7789,7 +7411,7 @@ fn bindgen_test_layout__cef_stream_reader_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_stream_reader_t>())).may_block as *const _ as usize },
+        ::std::mem::offset_of!(_cef_stream_reader_t, may_block),
         72usize,
         concat!(
             "Offset of field: ",
@@ -7857,7 +7479,7 @@ fn bindgen_test_layout__cef_write_handler_t() {
         concat!("Alignment of ", stringify!(_cef_write_handler_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_write_handler_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_write_handler_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -7867,7 +7489,7 @@ fn bindgen_test_layout__cef_write_handler_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_write_handler_t>())).write as *const _ as usize },
+        ::std::mem::offset_of!(_cef_write_handler_t, write),
         40usize,
         concat!(
             "Offset of field: ",
@@ -7877,7 +7499,7 @@ fn bindgen_test_layout__cef_write_handler_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_write_handler_t>())).seek as *const _ as usize },
+        ::std::mem::offset_of!(_cef_write_handler_t, seek),
         48usize,
         concat!(
             "Offset of field: ",
@@ -7887,7 +7509,7 @@ fn bindgen_test_layout__cef_write_handler_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_write_handler_t>())).tell as *const _ as usize },
+        ::std::mem::offset_of!(_cef_write_handler_t, tell),
         56usize,
         concat!(
             "Offset of field: ",
@@ -7897,7 +7519,7 @@ fn bindgen_test_layout__cef_write_handler_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_write_handler_t>())).flush as *const _ as usize },
+        ::std::mem::offset_of!(_cef_write_handler_t, flush),
         64usize,
         concat!(
             "Offset of field: ",
@@ -7907,7 +7529,7 @@ fn bindgen_test_layout__cef_write_handler_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_write_handler_t>())).may_block as *const _ as usize },
+        ::std::mem::offset_of!(_cef_write_handler_t, may_block),
         72usize,
         concat!(
             "Offset of field: ",
@@ -7959,7 +7581,7 @@ fn bindgen_test_layout__cef_stream_writer_t() {
         concat!("Alignment of ", stringify!(_cef_stream_writer_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_stream_writer_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_stream_writer_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -7969,7 +7591,7 @@ fn bindgen_test_layout__cef_stream_writer_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_stream_writer_t>())).write as *const _ as usize },
+        ::std::mem::offset_of!(_cef_stream_writer_t, write),
         40usize,
         concat!(
             "Offset of field: ",
@@ -7979,7 +7601,7 @@ fn bindgen_test_layout__cef_stream_writer_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_stream_writer_t>())).seek as *const _ as usize },
+        ::std::mem::offset_of!(_cef_stream_writer_t, seek),
         48usize,
         concat!(
             "Offset of field: ",
@@ -7989,7 +7611,7 @@ fn bindgen_test_layout__cef_stream_writer_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_stream_writer_t>())).tell as *const _ as usize },
+        ::std::mem::offset_of!(_cef_stream_writer_t, tell),
         56usize,
         concat!(
             "Offset of field: ",
@@ -7999,7 +7621,7 @@ fn bindgen_test_layout__cef_stream_writer_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_stream_writer_t>())).flush as *const _ as usize },
+        ::std::mem::offset_of!(_cef_stream_writer_t, flush),
         64usize,
         concat!(
             "Offset of field: ",
@@ -8009,7 +7631,7 @@ fn bindgen_test_layout__cef_stream_writer_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_stream_writer_t>())).may_block as *const _ as usize },
+        ::std::mem::offset_of!(_cef_stream_writer_t, may_block),
         72usize,
         concat!(
             "Offset of field: ",
@@ -8131,7 +7753,7 @@ fn bindgen_test_layout__cef_drag_data_t() {
         concat!("Alignment of ", stringify!(_cef_drag_data_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_drag_data_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_drag_data_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -8141,7 +7763,7 @@ fn bindgen_test_layout__cef_drag_data_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_drag_data_t>())).clone as *const _ as usize },
+        ::std::mem::offset_of!(_cef_drag_data_t, clone),
         40usize,
         concat!(
             "Offset of field: ",
@@ -8151,7 +7773,7 @@ fn bindgen_test_layout__cef_drag_data_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_drag_data_t>())).is_read_only as *const _ as usize },
+        ::std::mem::offset_of!(_cef_drag_data_t, is_read_only),
         48usize,
         concat!(
             "Offset of field: ",
@@ -8161,7 +7783,7 @@ fn bindgen_test_layout__cef_drag_data_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_drag_data_t>())).is_link as *const _ as usize },
+        ::std::mem::offset_of!(_cef_drag_data_t, is_link),
         56usize,
         concat!(
             "Offset of field: ",
@@ -8171,7 +7793,7 @@ fn bindgen_test_layout__cef_drag_data_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_drag_data_t>())).is_fragment as *const _ as usize },
+        ::std::mem::offset_of!(_cef_drag_data_t, is_fragment),
         64usize,
         concat!(
             "Offset of field: ",
@@ -8181,7 +7803,7 @@ fn bindgen_test_layout__cef_drag_data_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_drag_data_t>())).is_file as *const _ as usize },
+        ::std::mem::offset_of!(_cef_drag_data_t, is_file),
         72usize,
         concat!(
             "Offset of field: ",
@@ -8191,7 +7813,7 @@ fn bindgen_test_layout__cef_drag_data_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_drag_data_t>())).get_link_url as *const _ as usize },
+        ::std::mem::offset_of!(_cef_drag_data_t, get_link_url),
         80usize,
         concat!(
             "Offset of field: ",
@@ -8201,7 +7823,7 @@ fn bindgen_test_layout__cef_drag_data_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_drag_data_t>())).get_link_title as *const _ as usize },
+        ::std::mem::offset_of!(_cef_drag_data_t, get_link_title),
         88usize,
         concat!(
             "Offset of field: ",
@@ -8211,9 +7833,7 @@ fn bindgen_test_layout__cef_drag_data_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_drag_data_t>())).get_link_metadata as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_drag_data_t, get_link_metadata),
         96usize,
         concat!(
             "Offset of field: ",
@@ -8223,9 +7843,7 @@ fn bindgen_test_layout__cef_drag_data_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_drag_data_t>())).get_fragment_text as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_drag_data_t, get_fragment_text),
         104usize,
         concat!(
             "Offset of field: ",
@@ -8235,9 +7853,7 @@ fn bindgen_test_layout__cef_drag_data_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_drag_data_t>())).get_fragment_html as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_drag_data_t, get_fragment_html),
         112usize,
         concat!(
             "Offset of field: ",
@@ -8247,9 +7863,7 @@ fn bindgen_test_layout__cef_drag_data_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_drag_data_t>())).get_fragment_base_url as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_drag_data_t, get_fragment_base_url),
         120usize,
         concat!(
             "Offset of field: ",
@@ -8259,7 +7873,7 @@ fn bindgen_test_layout__cef_drag_data_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_drag_data_t>())).get_file_name as *const _ as usize },
+        ::std::mem::offset_of!(_cef_drag_data_t, get_file_name),
         128usize,
         concat!(
             "Offset of field: ",
@@ -8269,9 +7883,7 @@ fn bindgen_test_layout__cef_drag_data_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_drag_data_t>())).get_file_contents as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_drag_data_t, get_file_contents),
         136usize,
         concat!(
             "Offset of field: ",
@@ -8281,7 +7893,7 @@ fn bindgen_test_layout__cef_drag_data_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_drag_data_t>())).get_file_names as *const _ as usize },
+        ::std::mem::offset_of!(_cef_drag_data_t, get_file_names),
         144usize,
         concat!(
             "Offset of field: ",
@@ -8291,7 +7903,7 @@ fn bindgen_test_layout__cef_drag_data_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_drag_data_t>())).set_link_url as *const _ as usize },
+        ::std::mem::offset_of!(_cef_drag_data_t, set_link_url),
         152usize,
         concat!(
             "Offset of field: ",
@@ -8301,7 +7913,7 @@ fn bindgen_test_layout__cef_drag_data_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_drag_data_t>())).set_link_title as *const _ as usize },
+        ::std::mem::offset_of!(_cef_drag_data_t, set_link_title),
         160usize,
         concat!(
             "Offset of field: ",
@@ -8311,9 +7923,7 @@ fn bindgen_test_layout__cef_drag_data_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_drag_data_t>())).set_link_metadata as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_drag_data_t, set_link_metadata),
         168usize,
         concat!(
             "Offset of field: ",
@@ -8323,9 +7933,7 @@ fn bindgen_test_layout__cef_drag_data_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_drag_data_t>())).set_fragment_text as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_drag_data_t, set_fragment_text),
         176usize,
         concat!(
             "Offset of field: ",
@@ -8335,9 +7943,7 @@ fn bindgen_test_layout__cef_drag_data_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_drag_data_t>())).set_fragment_html as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_drag_data_t, set_fragment_html),
         184usize,
         concat!(
             "Offset of field: ",
@@ -8347,9 +7953,7 @@ fn bindgen_test_layout__cef_drag_data_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_drag_data_t>())).set_fragment_base_url as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_drag_data_t, set_fragment_base_url),
         192usize,
         concat!(
             "Offset of field: ",
@@ -8359,9 +7963,7 @@ fn bindgen_test_layout__cef_drag_data_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_drag_data_t>())).reset_file_contents as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_drag_data_t, reset_file_contents),
         200usize,
         concat!(
             "Offset of field: ",
@@ -8371,7 +7973,7 @@ fn bindgen_test_layout__cef_drag_data_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_drag_data_t>())).add_file as *const _ as usize },
+        ::std::mem::offset_of!(_cef_drag_data_t, add_file),
         208usize,
         concat!(
             "Offset of field: ",
@@ -8381,7 +7983,7 @@ fn bindgen_test_layout__cef_drag_data_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_drag_data_t>())).get_image as *const _ as usize },
+        ::std::mem::offset_of!(_cef_drag_data_t, get_image),
         216usize,
         concat!(
             "Offset of field: ",
@@ -8391,9 +7993,7 @@ fn bindgen_test_layout__cef_drag_data_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_drag_data_t>())).get_image_hotspot as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_drag_data_t, get_image_hotspot),
         224usize,
         concat!(
             "Offset of field: ",
@@ -8403,7 +8003,7 @@ fn bindgen_test_layout__cef_drag_data_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_drag_data_t>())).has_image as *const _ as usize },
+        ::std::mem::offset_of!(_cef_drag_data_t, has_image),
         232usize,
         concat!(
             "Offset of field: ",
@@ -8438,7 +8038,7 @@ fn bindgen_test_layout__cef_domvisitor_t() {
         concat!("Alignment of ", stringify!(_cef_domvisitor_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_domvisitor_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_domvisitor_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -8448,7 +8048,7 @@ fn bindgen_test_layout__cef_domvisitor_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_domvisitor_t>())).visit as *const _ as usize },
+        ::std::mem::offset_of!(_cef_domvisitor_t, visit),
         40usize,
         concat!(
             "Offset of field: ",
@@ -8525,7 +8125,7 @@ fn bindgen_test_layout__cef_domdocument_t() {
         concat!("Alignment of ", stringify!(_cef_domdocument_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_domdocument_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_domdocument_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -8535,7 +8135,7 @@ fn bindgen_test_layout__cef_domdocument_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_domdocument_t>())).get_type as *const _ as usize },
+        ::std::mem::offset_of!(_cef_domdocument_t, get_type),
         40usize,
         concat!(
             "Offset of field: ",
@@ -8545,7 +8145,7 @@ fn bindgen_test_layout__cef_domdocument_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_domdocument_t>())).get_document as *const _ as usize },
+        ::std::mem::offset_of!(_cef_domdocument_t, get_document),
         48usize,
         concat!(
             "Offset of field: ",
@@ -8555,7 +8155,7 @@ fn bindgen_test_layout__cef_domdocument_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_domdocument_t>())).get_body as *const _ as usize },
+        ::std::mem::offset_of!(_cef_domdocument_t, get_body),
         56usize,
         concat!(
             "Offset of field: ",
@@ -8565,7 +8165,7 @@ fn bindgen_test_layout__cef_domdocument_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_domdocument_t>())).get_head as *const _ as usize },
+        ::std::mem::offset_of!(_cef_domdocument_t, get_head),
         64usize,
         concat!(
             "Offset of field: ",
@@ -8575,7 +8175,7 @@ fn bindgen_test_layout__cef_domdocument_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_domdocument_t>())).get_title as *const _ as usize },
+        ::std::mem::offset_of!(_cef_domdocument_t, get_title),
         72usize,
         concat!(
             "Offset of field: ",
@@ -8585,9 +8185,7 @@ fn bindgen_test_layout__cef_domdocument_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_domdocument_t>())).get_element_by_id as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_domdocument_t, get_element_by_id),
         80usize,
         concat!(
             "Offset of field: ",
@@ -8597,9 +8195,7 @@ fn bindgen_test_layout__cef_domdocument_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_domdocument_t>())).get_focused_node as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_domdocument_t, get_focused_node),
         88usize,
         concat!(
             "Offset of field: ",
@@ -8609,9 +8205,7 @@ fn bindgen_test_layout__cef_domdocument_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_domdocument_t>())).has_selection as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_domdocument_t, has_selection),
         96usize,
         concat!(
             "Offset of field: ",
@@ -8621,10 +8215,7 @@ fn bindgen_test_layout__cef_domdocument_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_domdocument_t>())).get_selection_start_offset as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_domdocument_t, get_selection_start_offset),
         104usize,
         concat!(
             "Offset of field: ",
@@ -8634,10 +8225,7 @@ fn bindgen_test_layout__cef_domdocument_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_domdocument_t>())).get_selection_end_offset as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_domdocument_t, get_selection_end_offset),
         112usize,
         concat!(
             "Offset of field: ",
@@ -8647,10 +8235,7 @@ fn bindgen_test_layout__cef_domdocument_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_domdocument_t>())).get_selection_as_markup as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_domdocument_t, get_selection_as_markup),
         120usize,
         concat!(
             "Offset of field: ",
@@ -8660,10 +8245,7 @@ fn bindgen_test_layout__cef_domdocument_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_domdocument_t>())).get_selection_as_text as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_domdocument_t, get_selection_as_text),
         128usize,
         concat!(
             "Offset of field: ",
@@ -8673,7 +8255,7 @@ fn bindgen_test_layout__cef_domdocument_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_domdocument_t>())).get_base_url as *const _ as usize },
+        ::std::mem::offset_of!(_cef_domdocument_t, get_base_url),
         136usize,
         concat!(
             "Offset of field: ",
@@ -8683,9 +8265,7 @@ fn bindgen_test_layout__cef_domdocument_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_domdocument_t>())).get_complete_url as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_domdocument_t, get_complete_url),
         144usize,
         concat!(
             "Offset of field: ",
@@ -8807,7 +8387,7 @@ fn bindgen_test_layout__cef_domnode_t() {
         concat!("Alignment of ", stringify!(_cef_domnode_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_domnode_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_domnode_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -8817,7 +8397,7 @@ fn bindgen_test_layout__cef_domnode_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_domnode_t>())).get_type as *const _ as usize },
+        ::std::mem::offset_of!(_cef_domnode_t, get_type),
         40usize,
         concat!(
             "Offset of field: ",
@@ -8827,7 +8407,7 @@ fn bindgen_test_layout__cef_domnode_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_domnode_t>())).is_text as *const _ as usize },
+        ::std::mem::offset_of!(_cef_domnode_t, is_text),
         48usize,
         concat!(
             "Offset of field: ",
@@ -8837,7 +8417,7 @@ fn bindgen_test_layout__cef_domnode_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_domnode_t>())).is_element as *const _ as usize },
+        ::std::mem::offset_of!(_cef_domnode_t, is_element),
         56usize,
         concat!(
             "Offset of field: ",
@@ -8847,7 +8427,7 @@ fn bindgen_test_layout__cef_domnode_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_domnode_t>())).is_editable as *const _ as usize },
+        ::std::mem::offset_of!(_cef_domnode_t, is_editable),
         64usize,
         concat!(
             "Offset of field: ",
@@ -8857,9 +8437,7 @@ fn bindgen_test_layout__cef_domnode_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_domnode_t>())).is_form_control_element as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_domnode_t, is_form_control_element),
         72usize,
         concat!(
             "Offset of field: ",
@@ -8869,10 +8447,7 @@ fn bindgen_test_layout__cef_domnode_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_domnode_t>())).get_form_control_element_type as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_domnode_t, get_form_control_element_type),
         80usize,
         concat!(
             "Offset of field: ",
@@ -8882,7 +8457,7 @@ fn bindgen_test_layout__cef_domnode_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_domnode_t>())).is_same as *const _ as usize },
+        ::std::mem::offset_of!(_cef_domnode_t, is_same),
         88usize,
         concat!(
             "Offset of field: ",
@@ -8892,7 +8467,7 @@ fn bindgen_test_layout__cef_domnode_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_domnode_t>())).get_name as *const _ as usize },
+        ::std::mem::offset_of!(_cef_domnode_t, get_name),
         96usize,
         concat!(
             "Offset of field: ",
@@ -8902,7 +8477,7 @@ fn bindgen_test_layout__cef_domnode_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_domnode_t>())).get_value as *const _ as usize },
+        ::std::mem::offset_of!(_cef_domnode_t, get_value),
         104usize,
         concat!(
             "Offset of field: ",
@@ -8912,7 +8487,7 @@ fn bindgen_test_layout__cef_domnode_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_domnode_t>())).set_value as *const _ as usize },
+        ::std::mem::offset_of!(_cef_domnode_t, set_value),
         112usize,
         concat!(
             "Offset of field: ",
@@ -8922,7 +8497,7 @@ fn bindgen_test_layout__cef_domnode_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_domnode_t>())).get_as_markup as *const _ as usize },
+        ::std::mem::offset_of!(_cef_domnode_t, get_as_markup),
         120usize,
         concat!(
             "Offset of field: ",
@@ -8932,7 +8507,7 @@ fn bindgen_test_layout__cef_domnode_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_domnode_t>())).get_document as *const _ as usize },
+        ::std::mem::offset_of!(_cef_domnode_t, get_document),
         128usize,
         concat!(
             "Offset of field: ",
@@ -8942,7 +8517,7 @@ fn bindgen_test_layout__cef_domnode_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_domnode_t>())).get_parent as *const _ as usize },
+        ::std::mem::offset_of!(_cef_domnode_t, get_parent),
         136usize,
         concat!(
             "Offset of field: ",
@@ -8952,9 +8527,7 @@ fn bindgen_test_layout__cef_domnode_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_domnode_t>())).get_previous_sibling as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_domnode_t, get_previous_sibling),
         144usize,
         concat!(
             "Offset of field: ",
@@ -8964,7 +8537,7 @@ fn bindgen_test_layout__cef_domnode_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_domnode_t>())).get_next_sibling as *const _ as usize },
+        ::std::mem::offset_of!(_cef_domnode_t, get_next_sibling),
         152usize,
         concat!(
             "Offset of field: ",
@@ -8974,7 +8547,7 @@ fn bindgen_test_layout__cef_domnode_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_domnode_t>())).has_children as *const _ as usize },
+        ::std::mem::offset_of!(_cef_domnode_t, has_children),
         160usize,
         concat!(
             "Offset of field: ",
@@ -8984,7 +8557,7 @@ fn bindgen_test_layout__cef_domnode_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_domnode_t>())).get_first_child as *const _ as usize },
+        ::std::mem::offset_of!(_cef_domnode_t, get_first_child),
         168usize,
         concat!(
             "Offset of field: ",
@@ -8994,7 +8567,7 @@ fn bindgen_test_layout__cef_domnode_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_domnode_t>())).get_last_child as *const _ as usize },
+        ::std::mem::offset_of!(_cef_domnode_t, get_last_child),
         176usize,
         concat!(
             "Offset of field: ",
@@ -9004,9 +8577,7 @@ fn bindgen_test_layout__cef_domnode_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_domnode_t>())).get_element_tag_name as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_domnode_t, get_element_tag_name),
         184usize,
         concat!(
             "Offset of field: ",
@@ -9016,9 +8587,7 @@ fn bindgen_test_layout__cef_domnode_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_domnode_t>())).has_element_attributes as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_domnode_t, has_element_attributes),
         192usize,
         concat!(
             "Offset of field: ",
@@ -9028,9 +8597,7 @@ fn bindgen_test_layout__cef_domnode_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_domnode_t>())).has_element_attribute as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_domnode_t, has_element_attribute),
         200usize,
         concat!(
             "Offset of field: ",
@@ -9040,9 +8607,7 @@ fn bindgen_test_layout__cef_domnode_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_domnode_t>())).get_element_attribute as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_domnode_t, get_element_attribute),
         208usize,
         concat!(
             "Offset of field: ",
@@ -9052,9 +8617,7 @@ fn bindgen_test_layout__cef_domnode_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_domnode_t>())).get_element_attributes as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_domnode_t, get_element_attributes),
         216usize,
         concat!(
             "Offset of field: ",
@@ -9064,9 +8627,7 @@ fn bindgen_test_layout__cef_domnode_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_domnode_t>())).set_element_attribute as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_domnode_t, set_element_attribute),
         224usize,
         concat!(
             "Offset of field: ",
@@ -9076,9 +8637,7 @@ fn bindgen_test_layout__cef_domnode_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_domnode_t>())).get_element_inner_text as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_domnode_t, get_element_inner_text),
         232usize,
         concat!(
             "Offset of field: ",
@@ -9088,9 +8647,7 @@ fn bindgen_test_layout__cef_domnode_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_domnode_t>())).get_element_bounds as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_domnode_t, get_element_bounds),
         240usize,
         concat!(
             "Offset of field: ",
@@ -9134,7 +8691,7 @@ fn bindgen_test_layout__cef_process_message_t() {
         concat!("Alignment of ", stringify!(_cef_process_message_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_process_message_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_process_message_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -9144,7 +8701,7 @@ fn bindgen_test_layout__cef_process_message_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_process_message_t>())).is_valid as *const _ as usize },
+        ::std::mem::offset_of!(_cef_process_message_t, is_valid),
         40usize,
         concat!(
             "Offset of field: ",
@@ -9154,9 +8711,7 @@ fn bindgen_test_layout__cef_process_message_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_process_message_t>())).is_read_only as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_process_message_t, is_read_only),
         48usize,
         concat!(
             "Offset of field: ",
@@ -9166,7 +8721,7 @@ fn bindgen_test_layout__cef_process_message_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_process_message_t>())).copy as *const _ as usize },
+        ::std::mem::offset_of!(_cef_process_message_t, copy),
         56usize,
         concat!(
             "Offset of field: ",
@@ -9176,7 +8731,7 @@ fn bindgen_test_layout__cef_process_message_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_process_message_t>())).get_name as *const _ as usize },
+        ::std::mem::offset_of!(_cef_process_message_t, get_name),
         64usize,
         concat!(
             "Offset of field: ",
@@ -9186,10 +8741,7 @@ fn bindgen_test_layout__cef_process_message_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_process_message_t>())).get_argument_list as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_process_message_t, get_argument_list),
         72usize,
         concat!(
             "Offset of field: ",
@@ -9304,7 +8856,7 @@ fn bindgen_test_layout__cef_request_t() {
         concat!("Alignment of ", stringify!(_cef_request_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_request_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_request_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -9314,7 +8866,7 @@ fn bindgen_test_layout__cef_request_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_request_t>())).is_read_only as *const _ as usize },
+        ::std::mem::offset_of!(_cef_request_t, is_read_only),
         40usize,
         concat!(
             "Offset of field: ",
@@ -9324,7 +8876,7 @@ fn bindgen_test_layout__cef_request_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_request_t>())).get_url as *const _ as usize },
+        ::std::mem::offset_of!(_cef_request_t, get_url),
         48usize,
         concat!(
             "Offset of field: ",
@@ -9334,7 +8886,7 @@ fn bindgen_test_layout__cef_request_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_request_t>())).set_url as *const _ as usize },
+        ::std::mem::offset_of!(_cef_request_t, set_url),
         56usize,
         concat!(
             "Offset of field: ",
@@ -9344,7 +8896,7 @@ fn bindgen_test_layout__cef_request_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_request_t>())).get_method as *const _ as usize },
+        ::std::mem::offset_of!(_cef_request_t, get_method),
         64usize,
         concat!(
             "Offset of field: ",
@@ -9354,7 +8906,7 @@ fn bindgen_test_layout__cef_request_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_request_t>())).set_method as *const _ as usize },
+        ::std::mem::offset_of!(_cef_request_t, set_method),
         72usize,
         concat!(
             "Offset of field: ",
@@ -9364,7 +8916,7 @@ fn bindgen_test_layout__cef_request_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_request_t>())).set_referrer as *const _ as usize },
+        ::std::mem::offset_of!(_cef_request_t, set_referrer),
         80usize,
         concat!(
             "Offset of field: ",
@@ -9374,7 +8926,7 @@ fn bindgen_test_layout__cef_request_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_request_t>())).get_referrer_url as *const _ as usize },
+        ::std::mem::offset_of!(_cef_request_t, get_referrer_url),
         88usize,
         concat!(
             "Offset of field: ",
@@ -9384,9 +8936,7 @@ fn bindgen_test_layout__cef_request_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_request_t>())).get_referrer_policy as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_request_t, get_referrer_policy),
         96usize,
         concat!(
             "Offset of field: ",
@@ -9396,7 +8946,7 @@ fn bindgen_test_layout__cef_request_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_request_t>())).get_post_data as *const _ as usize },
+        ::std::mem::offset_of!(_cef_request_t, get_post_data),
         104usize,
         concat!(
             "Offset of field: ",
@@ -9406,7 +8956,7 @@ fn bindgen_test_layout__cef_request_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_request_t>())).set_post_data as *const _ as usize },
+        ::std::mem::offset_of!(_cef_request_t, set_post_data),
         112usize,
         concat!(
             "Offset of field: ",
@@ -9416,7 +8966,7 @@ fn bindgen_test_layout__cef_request_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_request_t>())).get_header_map as *const _ as usize },
+        ::std::mem::offset_of!(_cef_request_t, get_header_map),
         120usize,
         concat!(
             "Offset of field: ",
@@ -9426,7 +8976,7 @@ fn bindgen_test_layout__cef_request_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_request_t>())).set_header_map as *const _ as usize },
+        ::std::mem::offset_of!(_cef_request_t, set_header_map),
         128usize,
         concat!(
             "Offset of field: ",
@@ -9436,9 +8986,7 @@ fn bindgen_test_layout__cef_request_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_request_t>())).get_header_by_name as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_request_t, get_header_by_name),
         136usize,
         concat!(
             "Offset of field: ",
@@ -9448,9 +8996,7 @@ fn bindgen_test_layout__cef_request_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_request_t>())).set_header_by_name as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_request_t, set_header_by_name),
         144usize,
         concat!(
             "Offset of field: ",
@@ -9460,7 +9006,7 @@ fn bindgen_test_layout__cef_request_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_request_t>())).set as *const _ as usize },
+        ::std::mem::offset_of!(_cef_request_t, set),
         152usize,
         concat!(
             "Offset of field: ",
@@ -9470,7 +9016,7 @@ fn bindgen_test_layout__cef_request_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_request_t>())).get_flags as *const _ as usize },
+        ::std::mem::offset_of!(_cef_request_t, get_flags),
         160usize,
         concat!(
             "Offset of field: ",
@@ -9480,7 +9026,7 @@ fn bindgen_test_layout__cef_request_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_request_t>())).set_flags as *const _ as usize },
+        ::std::mem::offset_of!(_cef_request_t, set_flags),
         168usize,
         concat!(
             "Offset of field: ",
@@ -9490,10 +9036,7 @@ fn bindgen_test_layout__cef_request_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_request_t>())).get_first_party_for_cookies as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_request_t, get_first_party_for_cookies),
         176usize,
         concat!(
             "Offset of field: ",
@@ -9503,10 +9046,7 @@ fn bindgen_test_layout__cef_request_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_request_t>())).set_first_party_for_cookies as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_request_t, set_first_party_for_cookies),
         184usize,
         concat!(
             "Offset of field: ",
@@ -9516,9 +9056,7 @@ fn bindgen_test_layout__cef_request_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_request_t>())).get_resource_type as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_request_t, get_resource_type),
         192usize,
         concat!(
             "Offset of field: ",
@@ -9528,9 +9066,7 @@ fn bindgen_test_layout__cef_request_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_request_t>())).get_transition_type as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_request_t, get_transition_type),
         200usize,
         concat!(
             "Offset of field: ",
@@ -9540,7 +9076,7 @@ fn bindgen_test_layout__cef_request_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_request_t>())).get_identifier as *const _ as usize },
+        ::std::mem::offset_of!(_cef_request_t, get_identifier),
         208usize,
         concat!(
             "Offset of field: ",
@@ -9600,7 +9136,7 @@ fn bindgen_test_layout__cef_post_data_t() {
         concat!("Alignment of ", stringify!(_cef_post_data_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_post_data_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_post_data_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -9610,7 +9146,7 @@ fn bindgen_test_layout__cef_post_data_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_post_data_t>())).is_read_only as *const _ as usize },
+        ::std::mem::offset_of!(_cef_post_data_t, is_read_only),
         40usize,
         concat!(
             "Offset of field: ",
@@ -9620,9 +9156,7 @@ fn bindgen_test_layout__cef_post_data_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_post_data_t>())).has_excluded_elements as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_post_data_t, has_excluded_elements),
         48usize,
         concat!(
             "Offset of field: ",
@@ -9632,9 +9166,7 @@ fn bindgen_test_layout__cef_post_data_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_post_data_t>())).get_element_count as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_post_data_t, get_element_count),
         56usize,
         concat!(
             "Offset of field: ",
@@ -9644,7 +9176,7 @@ fn bindgen_test_layout__cef_post_data_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_post_data_t>())).get_elements as *const _ as usize },
+        ::std::mem::offset_of!(_cef_post_data_t, get_elements),
         64usize,
         concat!(
             "Offset of field: ",
@@ -9654,7 +9186,7 @@ fn bindgen_test_layout__cef_post_data_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_post_data_t>())).remove_element as *const _ as usize },
+        ::std::mem::offset_of!(_cef_post_data_t, remove_element),
         72usize,
         concat!(
             "Offset of field: ",
@@ -9664,7 +9196,7 @@ fn bindgen_test_layout__cef_post_data_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_post_data_t>())).add_element as *const _ as usize },
+        ::std::mem::offset_of!(_cef_post_data_t, add_element),
         80usize,
         concat!(
             "Offset of field: ",
@@ -9674,9 +9206,7 @@ fn bindgen_test_layout__cef_post_data_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_post_data_t>())).remove_elements as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_post_data_t, remove_elements),
         88usize,
         concat!(
             "Offset of field: ",
@@ -9740,7 +9270,7 @@ fn bindgen_test_layout__cef_post_data_element_t() {
         concat!("Alignment of ", stringify!(_cef_post_data_element_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_post_data_element_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_post_data_element_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -9750,9 +9280,7 @@ fn bindgen_test_layout__cef_post_data_element_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_post_data_element_t>())).is_read_only as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_post_data_element_t, is_read_only),
         40usize,
         concat!(
             "Offset of field: ",
@@ -9762,9 +9290,7 @@ fn bindgen_test_layout__cef_post_data_element_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_post_data_element_t>())).set_to_empty as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_post_data_element_t, set_to_empty),
         48usize,
         concat!(
             "Offset of field: ",
@@ -9774,9 +9300,7 @@ fn bindgen_test_layout__cef_post_data_element_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_post_data_element_t>())).set_to_file as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_post_data_element_t, set_to_file),
         56usize,
         concat!(
             "Offset of field: ",
@@ -9786,9 +9310,7 @@ fn bindgen_test_layout__cef_post_data_element_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_post_data_element_t>())).set_to_bytes as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_post_data_element_t, set_to_bytes),
         64usize,
         concat!(
             "Offset of field: ",
@@ -9798,9 +9320,7 @@ fn bindgen_test_layout__cef_post_data_element_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_post_data_element_t>())).get_type as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_post_data_element_t, get_type),
         72usize,
         concat!(
             "Offset of field: ",
@@ -9810,9 +9330,7 @@ fn bindgen_test_layout__cef_post_data_element_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_post_data_element_t>())).get_file as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_post_data_element_t, get_file),
         80usize,
         concat!(
             "Offset of field: ",
@@ -9822,10 +9340,7 @@ fn bindgen_test_layout__cef_post_data_element_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_post_data_element_t>())).get_bytes_count as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_post_data_element_t, get_bytes_count),
         88usize,
         concat!(
             "Offset of field: ",
@@ -9835,9 +9350,7 @@ fn bindgen_test_layout__cef_post_data_element_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_post_data_element_t>())).get_bytes as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_post_data_element_t, get_bytes),
         96usize,
         concat!(
             "Offset of field: ",
@@ -9872,7 +9385,7 @@ fn bindgen_test_layout__cef_string_visitor_t() {
         concat!("Alignment of ", stringify!(_cef_string_visitor_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_string_visitor_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_string_visitor_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -9882,7 +9395,7 @@ fn bindgen_test_layout__cef_string_visitor_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_string_visitor_t>())).visit as *const _ as usize },
+        ::std::mem::offset_of!(_cef_string_visitor_t, visit),
         40usize,
         concat!(
             "Offset of field: ",
@@ -9981,7 +9494,7 @@ fn bindgen_test_layout__cef_frame_t() {
         concat!("Alignment of ", stringify!(_cef_frame_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_frame_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_frame_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -9991,7 +9504,7 @@ fn bindgen_test_layout__cef_frame_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_frame_t>())).is_valid as *const _ as usize },
+        ::std::mem::offset_of!(_cef_frame_t, is_valid),
         40usize,
         concat!(
             "Offset of field: ",
@@ -10001,7 +9514,7 @@ fn bindgen_test_layout__cef_frame_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_frame_t>())).undo as *const _ as usize },
+        ::std::mem::offset_of!(_cef_frame_t, undo),
         48usize,
         concat!(
             "Offset of field: ",
@@ -10011,7 +9524,7 @@ fn bindgen_test_layout__cef_frame_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_frame_t>())).redo as *const _ as usize },
+        ::std::mem::offset_of!(_cef_frame_t, redo),
         56usize,
         concat!(
             "Offset of field: ",
@@ -10021,7 +9534,7 @@ fn bindgen_test_layout__cef_frame_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_frame_t>())).cut as *const _ as usize },
+        ::std::mem::offset_of!(_cef_frame_t, cut),
         64usize,
         concat!(
             "Offset of field: ",
@@ -10031,7 +9544,7 @@ fn bindgen_test_layout__cef_frame_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_frame_t>())).copy as *const _ as usize },
+        ::std::mem::offset_of!(_cef_frame_t, copy),
         72usize,
         concat!(
             "Offset of field: ",
@@ -10041,7 +9554,7 @@ fn bindgen_test_layout__cef_frame_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_frame_t>())).paste as *const _ as usize },
+        ::std::mem::offset_of!(_cef_frame_t, paste),
         80usize,
         concat!(
             "Offset of field: ",
@@ -10051,7 +9564,7 @@ fn bindgen_test_layout__cef_frame_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_frame_t>())).del as *const _ as usize },
+        ::std::mem::offset_of!(_cef_frame_t, del),
         88usize,
         concat!(
             "Offset of field: ",
@@ -10061,7 +9574,7 @@ fn bindgen_test_layout__cef_frame_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_frame_t>())).select_all as *const _ as usize },
+        ::std::mem::offset_of!(_cef_frame_t, select_all),
         96usize,
         concat!(
             "Offset of field: ",
@@ -10071,7 +9584,7 @@ fn bindgen_test_layout__cef_frame_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_frame_t>())).view_source as *const _ as usize },
+        ::std::mem::offset_of!(_cef_frame_t, view_source),
         104usize,
         concat!(
             "Offset of field: ",
@@ -10081,7 +9594,7 @@ fn bindgen_test_layout__cef_frame_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_frame_t>())).get_source as *const _ as usize },
+        ::std::mem::offset_of!(_cef_frame_t, get_source),
         112usize,
         concat!(
             "Offset of field: ",
@@ -10091,7 +9604,7 @@ fn bindgen_test_layout__cef_frame_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_frame_t>())).get_text as *const _ as usize },
+        ::std::mem::offset_of!(_cef_frame_t, get_text),
         120usize,
         concat!(
             "Offset of field: ",
@@ -10101,7 +9614,7 @@ fn bindgen_test_layout__cef_frame_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_frame_t>())).load_request as *const _ as usize },
+        ::std::mem::offset_of!(_cef_frame_t, load_request),
         128usize,
         concat!(
             "Offset of field: ",
@@ -10111,7 +9624,7 @@ fn bindgen_test_layout__cef_frame_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_frame_t>())).load_url as *const _ as usize },
+        ::std::mem::offset_of!(_cef_frame_t, load_url),
         136usize,
         concat!(
             "Offset of field: ",
@@ -10121,9 +9634,7 @@ fn bindgen_test_layout__cef_frame_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_frame_t>())).execute_java_script as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_frame_t, execute_java_script),
         144usize,
         concat!(
             "Offset of field: ",
@@ -10133,7 +9644,7 @@ fn bindgen_test_layout__cef_frame_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_frame_t>())).is_main as *const _ as usize },
+        ::std::mem::offset_of!(_cef_frame_t, is_main),
         152usize,
         concat!(
             "Offset of field: ",
@@ -10143,7 +9654,7 @@ fn bindgen_test_layout__cef_frame_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_frame_t>())).is_focused as *const _ as usize },
+        ::std::mem::offset_of!(_cef_frame_t, is_focused),
         160usize,
         concat!(
             "Offset of field: ",
@@ -10153,7 +9664,7 @@ fn bindgen_test_layout__cef_frame_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_frame_t>())).get_name as *const _ as usize },
+        ::std::mem::offset_of!(_cef_frame_t, get_name),
         168usize,
         concat!(
             "Offset of field: ",
@@ -10163,7 +9674,7 @@ fn bindgen_test_layout__cef_frame_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_frame_t>())).get_identifier as *const _ as usize },
+        ::std::mem::offset_of!(_cef_frame_t, get_identifier),
         176usize,
         concat!(
             "Offset of field: ",
@@ -10173,7 +9684,7 @@ fn bindgen_test_layout__cef_frame_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_frame_t>())).get_parent as *const _ as usize },
+        ::std::mem::offset_of!(_cef_frame_t, get_parent),
         184usize,
         concat!(
             "Offset of field: ",
@@ -10183,7 +9694,7 @@ fn bindgen_test_layout__cef_frame_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_frame_t>())).get_url as *const _ as usize },
+        ::std::mem::offset_of!(_cef_frame_t, get_url),
         192usize,
         concat!(
             "Offset of field: ",
@@ -10193,7 +9704,7 @@ fn bindgen_test_layout__cef_frame_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_frame_t>())).get_browser as *const _ as usize },
+        ::std::mem::offset_of!(_cef_frame_t, get_browser),
         200usize,
         concat!(
             "Offset of field: ",
@@ -10203,7 +9714,7 @@ fn bindgen_test_layout__cef_frame_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_frame_t>())).get_v8context as *const _ as usize },
+        ::std::mem::offset_of!(_cef_frame_t, get_v8context),
         208usize,
         concat!(
             "Offset of field: ",
@@ -10213,7 +9724,7 @@ fn bindgen_test_layout__cef_frame_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_frame_t>())).visit_dom as *const _ as usize },
+        ::std::mem::offset_of!(_cef_frame_t, visit_dom),
         216usize,
         concat!(
             "Offset of field: ",
@@ -10223,7 +9734,7 @@ fn bindgen_test_layout__cef_frame_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_frame_t>())).create_urlrequest as *const _ as usize },
+        ::std::mem::offset_of!(_cef_frame_t, create_urlrequest),
         224usize,
         concat!(
             "Offset of field: ",
@@ -10233,9 +9744,7 @@ fn bindgen_test_layout__cef_frame_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_frame_t>())).send_process_message as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_frame_t, send_process_message),
         232usize,
         concat!(
             "Offset of field: ",
@@ -10291,7 +9800,7 @@ fn bindgen_test_layout__cef_x509cert_principal_t() {
         concat!("Alignment of ", stringify!(_cef_x509cert_principal_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_x509cert_principal_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_x509cert_principal_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -10301,10 +9810,7 @@ fn bindgen_test_layout__cef_x509cert_principal_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_x509cert_principal_t>())).get_display_name as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_x509cert_principal_t, get_display_name),
         40usize,
         concat!(
             "Offset of field: ",
@@ -10314,10 +9820,7 @@ fn bindgen_test_layout__cef_x509cert_principal_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_x509cert_principal_t>())).get_common_name as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_x509cert_principal_t, get_common_name),
         48usize,
         concat!(
             "Offset of field: ",
@@ -10327,10 +9830,7 @@ fn bindgen_test_layout__cef_x509cert_principal_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_x509cert_principal_t>())).get_locality_name as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_x509cert_principal_t, get_locality_name),
         56usize,
         concat!(
             "Offset of field: ",
@@ -10340,10 +9840,7 @@ fn bindgen_test_layout__cef_x509cert_principal_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_x509cert_principal_t>())).get_state_or_province_name
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_x509cert_principal_t, get_state_or_province_name),
         64usize,
         concat!(
             "Offset of field: ",
@@ -10353,10 +9850,7 @@ fn bindgen_test_layout__cef_x509cert_principal_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_x509cert_principal_t>())).get_country_name as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_x509cert_principal_t, get_country_name),
         72usize,
         concat!(
             "Offset of field: ",
@@ -10366,10 +9860,7 @@ fn bindgen_test_layout__cef_x509cert_principal_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_x509cert_principal_t>())).get_street_addresses as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_x509cert_principal_t, get_street_addresses),
         80usize,
         concat!(
             "Offset of field: ",
@@ -10379,10 +9870,7 @@ fn bindgen_test_layout__cef_x509cert_principal_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_x509cert_principal_t>())).get_organization_names as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_x509cert_principal_t, get_organization_names),
         88usize,
         concat!(
             "Offset of field: ",
@@ -10392,10 +9880,7 @@ fn bindgen_test_layout__cef_x509cert_principal_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_x509cert_principal_t>())).get_organization_unit_names
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_x509cert_principal_t, get_organization_unit_names),
         96usize,
         concat!(
             "Offset of field: ",
@@ -10405,10 +9890,7 @@ fn bindgen_test_layout__cef_x509cert_principal_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_x509cert_principal_t>())).get_domain_components as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_x509cert_principal_t, get_domain_components),
         104usize,
         concat!(
             "Offset of field: ",
@@ -10474,7 +9956,7 @@ fn bindgen_test_layout__cef_x509certificate_t() {
         concat!("Alignment of ", stringify!(_cef_x509certificate_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_x509certificate_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_x509certificate_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -10484,9 +9966,7 @@ fn bindgen_test_layout__cef_x509certificate_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_x509certificate_t>())).get_subject as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_x509certificate_t, get_subject),
         40usize,
         concat!(
             "Offset of field: ",
@@ -10496,9 +9976,7 @@ fn bindgen_test_layout__cef_x509certificate_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_x509certificate_t>())).get_issuer as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_x509certificate_t, get_issuer),
         48usize,
         concat!(
             "Offset of field: ",
@@ -10508,10 +9986,7 @@ fn bindgen_test_layout__cef_x509certificate_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_x509certificate_t>())).get_serial_number as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_x509certificate_t, get_serial_number),
         56usize,
         concat!(
             "Offset of field: ",
@@ -10521,9 +9996,7 @@ fn bindgen_test_layout__cef_x509certificate_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_x509certificate_t>())).get_valid_start as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_x509certificate_t, get_valid_start),
         64usize,
         concat!(
             "Offset of field: ",
@@ -10533,9 +10006,7 @@ fn bindgen_test_layout__cef_x509certificate_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_x509certificate_t>())).get_valid_expiry as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_x509certificate_t, get_valid_expiry),
         72usize,
         concat!(
             "Offset of field: ",
@@ -10545,9 +10016,7 @@ fn bindgen_test_layout__cef_x509certificate_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_x509certificate_t>())).get_derencoded as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_x509certificate_t, get_derencoded),
         80usize,
         concat!(
             "Offset of field: ",
@@ -10557,9 +10026,7 @@ fn bindgen_test_layout__cef_x509certificate_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_x509certificate_t>())).get_pemencoded as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_x509certificate_t, get_pemencoded),
         88usize,
         concat!(
             "Offset of field: ",
@@ -10569,10 +10036,7 @@ fn bindgen_test_layout__cef_x509certificate_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_x509certificate_t>())).get_issuer_chain_size as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_x509certificate_t, get_issuer_chain_size),
         96usize,
         concat!(
             "Offset of field: ",
@@ -10582,10 +10046,7 @@ fn bindgen_test_layout__cef_x509certificate_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_x509certificate_t>())).get_derencoded_issuer_chain
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_x509certificate_t, get_derencoded_issuer_chain),
         104usize,
         concat!(
             "Offset of field: ",
@@ -10595,10 +10056,7 @@ fn bindgen_test_layout__cef_x509certificate_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_x509certificate_t>())).get_pemencoded_issuer_chain
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_x509certificate_t, get_pemencoded_issuer_chain),
         112usize,
         concat!(
             "Offset of field: ",
@@ -10642,7 +10100,7 @@ fn bindgen_test_layout__cef_sslstatus_t() {
         concat!("Alignment of ", stringify!(_cef_sslstatus_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_sslstatus_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_sslstatus_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -10652,9 +10110,7 @@ fn bindgen_test_layout__cef_sslstatus_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_sslstatus_t>())).is_secure_connection as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_sslstatus_t, is_secure_connection),
         40usize,
         concat!(
             "Offset of field: ",
@@ -10664,9 +10120,7 @@ fn bindgen_test_layout__cef_sslstatus_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_sslstatus_t>())).get_cert_status as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_sslstatus_t, get_cert_status),
         48usize,
         concat!(
             "Offset of field: ",
@@ -10676,7 +10130,7 @@ fn bindgen_test_layout__cef_sslstatus_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_sslstatus_t>())).get_sslversion as *const _ as usize },
+        ::std::mem::offset_of!(_cef_sslstatus_t, get_sslversion),
         56usize,
         concat!(
             "Offset of field: ",
@@ -10686,9 +10140,7 @@ fn bindgen_test_layout__cef_sslstatus_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_sslstatus_t>())).get_content_status as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_sslstatus_t, get_content_status),
         64usize,
         concat!(
             "Offset of field: ",
@@ -10698,9 +10150,7 @@ fn bindgen_test_layout__cef_sslstatus_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_sslstatus_t>())).get_x509certificate as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_sslstatus_t, get_x509certificate),
         72usize,
         concat!(
             "Offset of field: ",
@@ -10759,7 +10209,7 @@ fn bindgen_test_layout__cef_navigation_entry_t() {
         concat!("Alignment of ", stringify!(_cef_navigation_entry_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_navigation_entry_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_navigation_entry_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -10769,9 +10219,7 @@ fn bindgen_test_layout__cef_navigation_entry_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_navigation_entry_t>())).is_valid as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_navigation_entry_t, is_valid),
         40usize,
         concat!(
             "Offset of field: ",
@@ -10781,7 +10229,7 @@ fn bindgen_test_layout__cef_navigation_entry_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_navigation_entry_t>())).get_url as *const _ as usize },
+        ::std::mem::offset_of!(_cef_navigation_entry_t, get_url),
         48usize,
         concat!(
             "Offset of field: ",
@@ -10791,9 +10239,7 @@ fn bindgen_test_layout__cef_navigation_entry_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_navigation_entry_t>())).get_display_url as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_navigation_entry_t, get_display_url),
         56usize,
         concat!(
             "Offset of field: ",
@@ -10803,10 +10249,7 @@ fn bindgen_test_layout__cef_navigation_entry_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_navigation_entry_t>())).get_original_url as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_navigation_entry_t, get_original_url),
         64usize,
         concat!(
             "Offset of field: ",
@@ -10816,9 +10259,7 @@ fn bindgen_test_layout__cef_navigation_entry_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_navigation_entry_t>())).get_title as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_navigation_entry_t, get_title),
         72usize,
         concat!(
             "Offset of field: ",
@@ -10828,10 +10269,7 @@ fn bindgen_test_layout__cef_navigation_entry_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_navigation_entry_t>())).get_transition_type as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_navigation_entry_t, get_transition_type),
         80usize,
         concat!(
             "Offset of field: ",
@@ -10841,9 +10279,7 @@ fn bindgen_test_layout__cef_navigation_entry_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_navigation_entry_t>())).has_post_data as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_navigation_entry_t, has_post_data),
         88usize,
         concat!(
             "Offset of field: ",
@@ -10853,10 +10289,7 @@ fn bindgen_test_layout__cef_navigation_entry_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_navigation_entry_t>())).get_completion_time as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_navigation_entry_t, get_completion_time),
         96usize,
         concat!(
             "Offset of field: ",
@@ -10866,10 +10299,7 @@ fn bindgen_test_layout__cef_navigation_entry_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_navigation_entry_t>())).get_http_status_code as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_navigation_entry_t, get_http_status_code),
         104usize,
         concat!(
             "Offset of field: ",
@@ -10879,9 +10309,7 @@ fn bindgen_test_layout__cef_navigation_entry_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_navigation_entry_t>())).get_sslstatus as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_navigation_entry_t, get_sslstatus),
         112usize,
         concat!(
             "Offset of field: ",
@@ -10910,7 +10338,7 @@ fn bindgen_test_layout__cef_registration_t() {
         concat!("Alignment of ", stringify!(_cef_registration_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_registration_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_registration_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -10941,7 +10369,7 @@ fn bindgen_test_layout__cef_callback_t() {
         concat!("Alignment of ", stringify!(_cef_callback_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_callback_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_callback_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -10951,7 +10379,7 @@ fn bindgen_test_layout__cef_callback_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_callback_t>())).cont as *const _ as usize },
+        ::std::mem::offset_of!(_cef_callback_t, cont),
         40usize,
         concat!(
             "Offset of field: ",
@@ -10961,7 +10389,7 @@ fn bindgen_test_layout__cef_callback_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_callback_t>())).cancel as *const _ as usize },
+        ::std::mem::offset_of!(_cef_callback_t, cancel),
         48usize,
         concat!(
             "Offset of field: ",
@@ -10992,7 +10420,7 @@ fn bindgen_test_layout__cef_completion_callback_t() {
         concat!("Alignment of ", stringify!(_cef_completion_callback_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_completion_callback_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_completion_callback_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -11002,9 +10430,7 @@ fn bindgen_test_layout__cef_completion_callback_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_completion_callback_t>())).on_complete as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_completion_callback_t, on_complete),
         40usize,
         concat!(
             "Offset of field: ",
@@ -11077,7 +10503,7 @@ fn bindgen_test_layout__cef_cookie_manager_t() {
         concat!("Alignment of ", stringify!(_cef_cookie_manager_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_cookie_manager_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_cookie_manager_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -11087,10 +10513,7 @@ fn bindgen_test_layout__cef_cookie_manager_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_cookie_manager_t>())).set_supported_schemes as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_cookie_manager_t, set_supported_schemes),
         40usize,
         concat!(
             "Offset of field: ",
@@ -11100,9 +10523,7 @@ fn bindgen_test_layout__cef_cookie_manager_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_cookie_manager_t>())).visit_all_cookies as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_cookie_manager_t, visit_all_cookies),
         48usize,
         concat!(
             "Offset of field: ",
@@ -11112,9 +10533,7 @@ fn bindgen_test_layout__cef_cookie_manager_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_cookie_manager_t>())).visit_url_cookies as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_cookie_manager_t, visit_url_cookies),
         56usize,
         concat!(
             "Offset of field: ",
@@ -11124,9 +10543,7 @@ fn bindgen_test_layout__cef_cookie_manager_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_cookie_manager_t>())).set_cookie as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_cookie_manager_t, set_cookie),
         64usize,
         concat!(
             "Offset of field: ",
@@ -11136,9 +10553,7 @@ fn bindgen_test_layout__cef_cookie_manager_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_cookie_manager_t>())).delete_cookies as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_cookie_manager_t, delete_cookies),
         72usize,
         concat!(
             "Offset of field: ",
@@ -11148,9 +10563,7 @@ fn bindgen_test_layout__cef_cookie_manager_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_cookie_manager_t>())).flush_store as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_cookie_manager_t, flush_store),
         80usize,
         concat!(
             "Offset of field: ",
@@ -11193,7 +10606,7 @@ fn bindgen_test_layout__cef_cookie_visitor_t() {
         concat!("Alignment of ", stringify!(_cef_cookie_visitor_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_cookie_visitor_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_cookie_visitor_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -11203,7 +10616,7 @@ fn bindgen_test_layout__cef_cookie_visitor_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_cookie_visitor_t>())).visit as *const _ as usize },
+        ::std::mem::offset_of!(_cef_cookie_visitor_t, visit),
         40usize,
         concat!(
             "Offset of field: ",
@@ -11238,7 +10651,7 @@ fn bindgen_test_layout__cef_set_cookie_callback_t() {
         concat!("Alignment of ", stringify!(_cef_set_cookie_callback_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_set_cookie_callback_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_set_cookie_callback_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -11248,9 +10661,7 @@ fn bindgen_test_layout__cef_set_cookie_callback_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_set_cookie_callback_t>())).on_complete as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_set_cookie_callback_t, on_complete),
         40usize,
         concat!(
             "Offset of field: ",
@@ -11285,9 +10696,7 @@ fn bindgen_test_layout__cef_delete_cookies_callback_t() {
         concat!("Alignment of ", stringify!(_cef_delete_cookies_callback_t))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_delete_cookies_callback_t>())).base as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_delete_cookies_callback_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -11297,10 +10706,7 @@ fn bindgen_test_layout__cef_delete_cookies_callback_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_delete_cookies_callback_t>())).on_complete as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_delete_cookies_callback_t, on_complete),
         40usize,
         concat!(
             "Offset of field: ",
@@ -11354,7 +10760,7 @@ fn bindgen_test_layout__cef_extension_t() {
         concat!("Alignment of ", stringify!(_cef_extension_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_extension_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_extension_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -11364,7 +10770,7 @@ fn bindgen_test_layout__cef_extension_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_extension_t>())).get_identifier as *const _ as usize },
+        ::std::mem::offset_of!(_cef_extension_t, get_identifier),
         40usize,
         concat!(
             "Offset of field: ",
@@ -11374,7 +10780,7 @@ fn bindgen_test_layout__cef_extension_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_extension_t>())).get_path as *const _ as usize },
+        ::std::mem::offset_of!(_cef_extension_t, get_path),
         48usize,
         concat!(
             "Offset of field: ",
@@ -11384,7 +10790,7 @@ fn bindgen_test_layout__cef_extension_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_extension_t>())).get_manifest as *const _ as usize },
+        ::std::mem::offset_of!(_cef_extension_t, get_manifest),
         56usize,
         concat!(
             "Offset of field: ",
@@ -11394,7 +10800,7 @@ fn bindgen_test_layout__cef_extension_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_extension_t>())).is_same as *const _ as usize },
+        ::std::mem::offset_of!(_cef_extension_t, is_same),
         64usize,
         concat!(
             "Offset of field: ",
@@ -11404,7 +10810,7 @@ fn bindgen_test_layout__cef_extension_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_extension_t>())).get_handler as *const _ as usize },
+        ::std::mem::offset_of!(_cef_extension_t, get_handler),
         72usize,
         concat!(
             "Offset of field: ",
@@ -11414,9 +10820,7 @@ fn bindgen_test_layout__cef_extension_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_extension_t>())).get_loader_context as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_extension_t, get_loader_context),
         80usize,
         concat!(
             "Offset of field: ",
@@ -11426,7 +10830,7 @@ fn bindgen_test_layout__cef_extension_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_extension_t>())).is_loaded as *const _ as usize },
+        ::std::mem::offset_of!(_cef_extension_t, is_loaded),
         88usize,
         concat!(
             "Offset of field: ",
@@ -11436,7 +10840,7 @@ fn bindgen_test_layout__cef_extension_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_extension_t>())).unload as *const _ as usize },
+        ::std::mem::offset_of!(_cef_extension_t, unload),
         96usize,
         concat!(
             "Offset of field: ",
@@ -11480,10 +10884,7 @@ fn bindgen_test_layout__cef_get_extension_resource_callback_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_get_extension_resource_callback_t>())).base as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_get_extension_resource_callback_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -11493,10 +10894,7 @@ fn bindgen_test_layout__cef_get_extension_resource_callback_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_get_extension_resource_callback_t>())).cont as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_get_extension_resource_callback_t, cont),
         40usize,
         concat!(
             "Offset of field: ",
@@ -11506,10 +10904,7 @@ fn bindgen_test_layout__cef_get_extension_resource_callback_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_get_extension_resource_callback_t>())).cancel as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_get_extension_resource_callback_t, cancel),
         48usize,
         concat!(
             "Offset of field: ",
@@ -11602,7 +10997,7 @@ fn bindgen_test_layout__cef_extension_handler_t() {
         concat!("Alignment of ", stringify!(_cef_extension_handler_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_extension_handler_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_extension_handler_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -11612,10 +11007,7 @@ fn bindgen_test_layout__cef_extension_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_extension_handler_t>())).on_extension_load_failed
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_extension_handler_t, on_extension_load_failed),
         40usize,
         concat!(
             "Offset of field: ",
@@ -11625,10 +11017,7 @@ fn bindgen_test_layout__cef_extension_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_extension_handler_t>())).on_extension_loaded as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_extension_handler_t, on_extension_loaded),
         48usize,
         concat!(
             "Offset of field: ",
@@ -11638,10 +11027,7 @@ fn bindgen_test_layout__cef_extension_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_extension_handler_t>())).on_extension_unloaded as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_extension_handler_t, on_extension_unloaded),
         56usize,
         concat!(
             "Offset of field: ",
@@ -11651,10 +11037,7 @@ fn bindgen_test_layout__cef_extension_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_extension_handler_t>())).on_before_background_browser
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_extension_handler_t, on_before_background_browser),
         64usize,
         concat!(
             "Offset of field: ",
@@ -11664,10 +11047,7 @@ fn bindgen_test_layout__cef_extension_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_extension_handler_t>())).on_before_browser as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_extension_handler_t, on_before_browser),
         72usize,
         concat!(
             "Offset of field: ",
@@ -11677,10 +11057,7 @@ fn bindgen_test_layout__cef_extension_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_extension_handler_t>())).get_active_browser as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_extension_handler_t, get_active_browser),
         80usize,
         concat!(
             "Offset of field: ",
@@ -11690,10 +11067,7 @@ fn bindgen_test_layout__cef_extension_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_extension_handler_t>())).can_access_browser as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_extension_handler_t, can_access_browser),
         88usize,
         concat!(
             "Offset of field: ",
@@ -11703,10 +11077,7 @@ fn bindgen_test_layout__cef_extension_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_extension_handler_t>())).get_extension_resource as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_extension_handler_t, get_extension_resource),
         96usize,
         concat!(
             "Offset of field: ",
@@ -11759,7 +11130,7 @@ fn bindgen_test_layout__cef_media_router_t() {
         concat!("Alignment of ", stringify!(_cef_media_router_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_media_router_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_media_router_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -11769,9 +11140,7 @@ fn bindgen_test_layout__cef_media_router_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_media_router_t>())).add_observer as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_media_router_t, add_observer),
         40usize,
         concat!(
             "Offset of field: ",
@@ -11781,7 +11150,7 @@ fn bindgen_test_layout__cef_media_router_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_media_router_t>())).get_source as *const _ as usize },
+        ::std::mem::offset_of!(_cef_media_router_t, get_source),
         48usize,
         concat!(
             "Offset of field: ",
@@ -11791,10 +11160,7 @@ fn bindgen_test_layout__cef_media_router_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_media_router_t>())).notify_current_sinks as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_media_router_t, notify_current_sinks),
         56usize,
         concat!(
             "Offset of field: ",
@@ -11804,9 +11170,7 @@ fn bindgen_test_layout__cef_media_router_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_media_router_t>())).create_route as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_media_router_t, create_route),
         64usize,
         concat!(
             "Offset of field: ",
@@ -11816,10 +11180,7 @@ fn bindgen_test_layout__cef_media_router_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_media_router_t>())).notify_current_routes as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_media_router_t, notify_current_routes),
         72usize,
         concat!(
             "Offset of field: ",
@@ -11880,7 +11241,7 @@ fn bindgen_test_layout__cef_media_observer_t() {
         concat!("Alignment of ", stringify!(_cef_media_observer_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_media_observer_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_media_observer_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -11890,7 +11251,7 @@ fn bindgen_test_layout__cef_media_observer_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_media_observer_t>())).on_sinks as *const _ as usize },
+        ::std::mem::offset_of!(_cef_media_observer_t, on_sinks),
         40usize,
         concat!(
             "Offset of field: ",
@@ -11900,7 +11261,7 @@ fn bindgen_test_layout__cef_media_observer_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_media_observer_t>())).on_routes as *const _ as usize },
+        ::std::mem::offset_of!(_cef_media_observer_t, on_routes),
         48usize,
         concat!(
             "Offset of field: ",
@@ -11910,10 +11271,7 @@ fn bindgen_test_layout__cef_media_observer_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_media_observer_t>())).on_route_state_changed as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_media_observer_t, on_route_state_changed),
         56usize,
         concat!(
             "Offset of field: ",
@@ -11923,10 +11281,7 @@ fn bindgen_test_layout__cef_media_observer_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_media_observer_t>())).on_route_message_received as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_media_observer_t, on_route_message_received),
         64usize,
         concat!(
             "Offset of field: ",
@@ -11972,7 +11327,7 @@ fn bindgen_test_layout__cef_media_route_t() {
         concat!("Alignment of ", stringify!(_cef_media_route_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_media_route_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_media_route_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -11982,7 +11337,7 @@ fn bindgen_test_layout__cef_media_route_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_media_route_t>())).get_id as *const _ as usize },
+        ::std::mem::offset_of!(_cef_media_route_t, get_id),
         40usize,
         concat!(
             "Offset of field: ",
@@ -11992,7 +11347,7 @@ fn bindgen_test_layout__cef_media_route_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_media_route_t>())).get_source as *const _ as usize },
+        ::std::mem::offset_of!(_cef_media_route_t, get_source),
         48usize,
         concat!(
             "Offset of field: ",
@@ -12002,7 +11357,7 @@ fn bindgen_test_layout__cef_media_route_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_media_route_t>())).get_sink as *const _ as usize },
+        ::std::mem::offset_of!(_cef_media_route_t, get_sink),
         56usize,
         concat!(
             "Offset of field: ",
@@ -12012,9 +11367,7 @@ fn bindgen_test_layout__cef_media_route_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_media_route_t>())).send_route_message as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_media_route_t, send_route_message),
         64usize,
         concat!(
             "Offset of field: ",
@@ -12024,7 +11377,7 @@ fn bindgen_test_layout__cef_media_route_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_media_route_t>())).terminate as *const _ as usize },
+        ::std::mem::offset_of!(_cef_media_route_t, terminate),
         72usize,
         concat!(
             "Offset of field: ",
@@ -12064,9 +11417,7 @@ fn bindgen_test_layout__cef_media_route_create_callback_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_media_route_create_callback_t>())).base as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_media_route_create_callback_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -12076,10 +11427,7 @@ fn bindgen_test_layout__cef_media_route_create_callback_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_media_route_create_callback_t>()))
-                .on_media_route_create_finished as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_media_route_create_callback_t, on_media_route_create_finished),
         40usize,
         concat!(
             "Offset of field: ",
@@ -12141,7 +11489,7 @@ fn bindgen_test_layout__cef_media_sink_t() {
         concat!("Alignment of ", stringify!(_cef_media_sink_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_media_sink_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_media_sink_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -12151,7 +11499,7 @@ fn bindgen_test_layout__cef_media_sink_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_media_sink_t>())).get_id as *const _ as usize },
+        ::std::mem::offset_of!(_cef_media_sink_t, get_id),
         40usize,
         concat!(
             "Offset of field: ",
@@ -12161,7 +11509,7 @@ fn bindgen_test_layout__cef_media_sink_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_media_sink_t>())).is_valid as *const _ as usize },
+        ::std::mem::offset_of!(_cef_media_sink_t, is_valid),
         48usize,
         concat!(
             "Offset of field: ",
@@ -12171,7 +11519,7 @@ fn bindgen_test_layout__cef_media_sink_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_media_sink_t>())).get_name as *const _ as usize },
+        ::std::mem::offset_of!(_cef_media_sink_t, get_name),
         56usize,
         concat!(
             "Offset of field: ",
@@ -12181,9 +11529,7 @@ fn bindgen_test_layout__cef_media_sink_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_media_sink_t>())).get_description as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_media_sink_t, get_description),
         64usize,
         concat!(
             "Offset of field: ",
@@ -12193,7 +11539,7 @@ fn bindgen_test_layout__cef_media_sink_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_media_sink_t>())).get_icon_type as *const _ as usize },
+        ::std::mem::offset_of!(_cef_media_sink_t, get_icon_type),
         72usize,
         concat!(
             "Offset of field: ",
@@ -12203,9 +11549,7 @@ fn bindgen_test_layout__cef_media_sink_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_media_sink_t>())).get_device_info as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_media_sink_t, get_device_info),
         80usize,
         concat!(
             "Offset of field: ",
@@ -12215,7 +11559,7 @@ fn bindgen_test_layout__cef_media_sink_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_media_sink_t>())).is_cast_sink as *const _ as usize },
+        ::std::mem::offset_of!(_cef_media_sink_t, is_cast_sink),
         88usize,
         concat!(
             "Offset of field: ",
@@ -12225,7 +11569,7 @@ fn bindgen_test_layout__cef_media_sink_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_media_sink_t>())).is_dial_sink as *const _ as usize },
+        ::std::mem::offset_of!(_cef_media_sink_t, is_dial_sink),
         96usize,
         concat!(
             "Offset of field: ",
@@ -12235,9 +11579,7 @@ fn bindgen_test_layout__cef_media_sink_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_media_sink_t>())).is_compatible_with as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_media_sink_t, is_compatible_with),
         104usize,
         concat!(
             "Offset of field: ",
@@ -12278,10 +11620,7 @@ fn bindgen_test_layout__cef_media_sink_device_info_callback_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_media_sink_device_info_callback_t>())).base as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_media_sink_device_info_callback_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -12291,10 +11630,7 @@ fn bindgen_test_layout__cef_media_sink_device_info_callback_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_media_sink_device_info_callback_t>()))
-                .on_media_sink_device_info as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_media_sink_device_info_callback_t, on_media_sink_device_info),
         40usize,
         concat!(
             "Offset of field: ",
@@ -12335,7 +11671,7 @@ fn bindgen_test_layout__cef_media_source_t() {
         concat!("Alignment of ", stringify!(_cef_media_source_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_media_source_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_media_source_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -12345,7 +11681,7 @@ fn bindgen_test_layout__cef_media_source_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_media_source_t>())).get_id as *const _ as usize },
+        ::std::mem::offset_of!(_cef_media_source_t, get_id),
         40usize,
         concat!(
             "Offset of field: ",
@@ -12355,7 +11691,7 @@ fn bindgen_test_layout__cef_media_source_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_media_source_t>())).is_valid as *const _ as usize },
+        ::std::mem::offset_of!(_cef_media_source_t, is_valid),
         48usize,
         concat!(
             "Offset of field: ",
@@ -12365,9 +11701,7 @@ fn bindgen_test_layout__cef_media_source_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_media_source_t>())).is_cast_source as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_media_source_t, is_cast_source),
         56usize,
         concat!(
             "Offset of field: ",
@@ -12377,9 +11711,7 @@ fn bindgen_test_layout__cef_media_source_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_media_source_t>())).is_dial_source as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_media_source_t, is_dial_source),
         64usize,
         concat!(
             "Offset of field: ",
@@ -12415,7 +11747,7 @@ fn bindgen_test_layout__cef_resolve_callback_t() {
         concat!("Alignment of ", stringify!(_cef_resolve_callback_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_resolve_callback_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_resolve_callback_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -12425,10 +11757,7 @@ fn bindgen_test_layout__cef_resolve_callback_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_resolve_callback_t>())).on_resolve_completed as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_resolve_callback_t, on_resolve_completed),
         40usize,
         concat!(
             "Offset of field: ",
@@ -12595,7 +11924,7 @@ fn bindgen_test_layout__cef_request_context_t() {
         concat!("Alignment of ", stringify!(_cef_request_context_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_request_context_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_request_context_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -12605,7 +11934,7 @@ fn bindgen_test_layout__cef_request_context_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_request_context_t>())).is_same as *const _ as usize },
+        ::std::mem::offset_of!(_cef_request_context_t, is_same),
         40usize,
         concat!(
             "Offset of field: ",
@@ -12615,9 +11944,7 @@ fn bindgen_test_layout__cef_request_context_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_request_context_t>())).is_sharing_with as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_request_context_t, is_sharing_with),
         48usize,
         concat!(
             "Offset of field: ",
@@ -12627,9 +11954,7 @@ fn bindgen_test_layout__cef_request_context_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_request_context_t>())).is_global as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_request_context_t, is_global),
         56usize,
         concat!(
             "Offset of field: ",
@@ -12639,9 +11964,7 @@ fn bindgen_test_layout__cef_request_context_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_request_context_t>())).get_handler as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_request_context_t, get_handler),
         64usize,
         concat!(
             "Offset of field: ",
@@ -12651,9 +11974,7 @@ fn bindgen_test_layout__cef_request_context_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_request_context_t>())).get_cache_path as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_request_context_t, get_cache_path),
         72usize,
         concat!(
             "Offset of field: ",
@@ -12663,10 +11984,7 @@ fn bindgen_test_layout__cef_request_context_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_request_context_t>())).get_cookie_manager as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_request_context_t, get_cookie_manager),
         80usize,
         concat!(
             "Offset of field: ",
@@ -12676,10 +11994,7 @@ fn bindgen_test_layout__cef_request_context_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_request_context_t>())).register_scheme_handler_factory
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_request_context_t, register_scheme_handler_factory),
         88usize,
         concat!(
             "Offset of field: ",
@@ -12689,10 +12004,7 @@ fn bindgen_test_layout__cef_request_context_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_request_context_t>())).clear_scheme_handler_factories
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_request_context_t, clear_scheme_handler_factories),
         96usize,
         concat!(
             "Offset of field: ",
@@ -12702,10 +12014,7 @@ fn bindgen_test_layout__cef_request_context_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_request_context_t>())).purge_plugin_list_cache as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_request_context_t, purge_plugin_list_cache),
         104usize,
         concat!(
             "Offset of field: ",
@@ -12715,9 +12024,7 @@ fn bindgen_test_layout__cef_request_context_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_request_context_t>())).has_preference as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_request_context_t, has_preference),
         112usize,
         concat!(
             "Offset of field: ",
@@ -12727,9 +12034,7 @@ fn bindgen_test_layout__cef_request_context_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_request_context_t>())).get_preference as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_request_context_t, get_preference),
         120usize,
         concat!(
             "Offset of field: ",
@@ -12739,10 +12044,7 @@ fn bindgen_test_layout__cef_request_context_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_request_context_t>())).get_all_preferences as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_request_context_t, get_all_preferences),
         128usize,
         concat!(
             "Offset of field: ",
@@ -12752,10 +12054,7 @@ fn bindgen_test_layout__cef_request_context_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_request_context_t>())).can_set_preference as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_request_context_t, can_set_preference),
         136usize,
         concat!(
             "Offset of field: ",
@@ -12765,9 +12064,7 @@ fn bindgen_test_layout__cef_request_context_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_request_context_t>())).set_preference as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_request_context_t, set_preference),
         144usize,
         concat!(
             "Offset of field: ",
@@ -12777,10 +12074,7 @@ fn bindgen_test_layout__cef_request_context_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_request_context_t>())).clear_certificate_exceptions
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_request_context_t, clear_certificate_exceptions),
         152usize,
         concat!(
             "Offset of field: ",
@@ -12790,10 +12084,7 @@ fn bindgen_test_layout__cef_request_context_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_request_context_t>())).clear_http_auth_credentials
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_request_context_t, clear_http_auth_credentials),
         160usize,
         concat!(
             "Offset of field: ",
@@ -12803,10 +12094,7 @@ fn bindgen_test_layout__cef_request_context_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_request_context_t>())).close_all_connections as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_request_context_t, close_all_connections),
         168usize,
         concat!(
             "Offset of field: ",
@@ -12816,9 +12104,7 @@ fn bindgen_test_layout__cef_request_context_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_request_context_t>())).resolve_host as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_request_context_t, resolve_host),
         176usize,
         concat!(
             "Offset of field: ",
@@ -12828,9 +12114,7 @@ fn bindgen_test_layout__cef_request_context_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_request_context_t>())).load_extension as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_request_context_t, load_extension),
         184usize,
         concat!(
             "Offset of field: ",
@@ -12840,10 +12124,7 @@ fn bindgen_test_layout__cef_request_context_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_request_context_t>())).did_load_extension as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_request_context_t, did_load_extension),
         192usize,
         concat!(
             "Offset of field: ",
@@ -12853,9 +12134,7 @@ fn bindgen_test_layout__cef_request_context_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_request_context_t>())).has_extension as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_request_context_t, has_extension),
         200usize,
         concat!(
             "Offset of field: ",
@@ -12865,9 +12144,7 @@ fn bindgen_test_layout__cef_request_context_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_request_context_t>())).get_extensions as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_request_context_t, get_extensions),
         208usize,
         concat!(
             "Offset of field: ",
@@ -12877,9 +12154,7 @@ fn bindgen_test_layout__cef_request_context_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_request_context_t>())).get_extension as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_request_context_t, get_extension),
         216usize,
         concat!(
             "Offset of field: ",
@@ -12889,9 +12164,7 @@ fn bindgen_test_layout__cef_request_context_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_request_context_t>())).get_media_router as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_request_context_t, get_media_router),
         224usize,
         concat!(
             "Offset of field: ",
@@ -12995,7 +12268,7 @@ fn bindgen_test_layout__cef_browser_t() {
         concat!("Alignment of ", stringify!(_cef_browser_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_browser_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_browser_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -13005,7 +12278,7 @@ fn bindgen_test_layout__cef_browser_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_browser_t>())).get_host as *const _ as usize },
+        ::std::mem::offset_of!(_cef_browser_t, get_host),
         40usize,
         concat!(
             "Offset of field: ",
@@ -13015,7 +12288,7 @@ fn bindgen_test_layout__cef_browser_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_browser_t>())).can_go_back as *const _ as usize },
+        ::std::mem::offset_of!(_cef_browser_t, can_go_back),
         48usize,
         concat!(
             "Offset of field: ",
@@ -13025,7 +12298,7 @@ fn bindgen_test_layout__cef_browser_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_browser_t>())).go_back as *const _ as usize },
+        ::std::mem::offset_of!(_cef_browser_t, go_back),
         56usize,
         concat!(
             "Offset of field: ",
@@ -13035,7 +12308,7 @@ fn bindgen_test_layout__cef_browser_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_browser_t>())).can_go_forward as *const _ as usize },
+        ::std::mem::offset_of!(_cef_browser_t, can_go_forward),
         64usize,
         concat!(
             "Offset of field: ",
@@ -13045,7 +12318,7 @@ fn bindgen_test_layout__cef_browser_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_browser_t>())).go_forward as *const _ as usize },
+        ::std::mem::offset_of!(_cef_browser_t, go_forward),
         72usize,
         concat!(
             "Offset of field: ",
@@ -13055,7 +12328,7 @@ fn bindgen_test_layout__cef_browser_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_browser_t>())).is_loading as *const _ as usize },
+        ::std::mem::offset_of!(_cef_browser_t, is_loading),
         80usize,
         concat!(
             "Offset of field: ",
@@ -13065,7 +12338,7 @@ fn bindgen_test_layout__cef_browser_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_browser_t>())).reload as *const _ as usize },
+        ::std::mem::offset_of!(_cef_browser_t, reload),
         88usize,
         concat!(
             "Offset of field: ",
@@ -13075,9 +12348,7 @@ fn bindgen_test_layout__cef_browser_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_t>())).reload_ignore_cache as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_t, reload_ignore_cache),
         96usize,
         concat!(
             "Offset of field: ",
@@ -13087,7 +12358,7 @@ fn bindgen_test_layout__cef_browser_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_browser_t>())).stop_load as *const _ as usize },
+        ::std::mem::offset_of!(_cef_browser_t, stop_load),
         104usize,
         concat!(
             "Offset of field: ",
@@ -13097,7 +12368,7 @@ fn bindgen_test_layout__cef_browser_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_browser_t>())).get_identifier as *const _ as usize },
+        ::std::mem::offset_of!(_cef_browser_t, get_identifier),
         112usize,
         concat!(
             "Offset of field: ",
@@ -13107,7 +12378,7 @@ fn bindgen_test_layout__cef_browser_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_browser_t>())).is_same as *const _ as usize },
+        ::std::mem::offset_of!(_cef_browser_t, is_same),
         120usize,
         concat!(
             "Offset of field: ",
@@ -13117,7 +12388,7 @@ fn bindgen_test_layout__cef_browser_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_browser_t>())).is_popup as *const _ as usize },
+        ::std::mem::offset_of!(_cef_browser_t, is_popup),
         128usize,
         concat!(
             "Offset of field: ",
@@ -13127,7 +12398,7 @@ fn bindgen_test_layout__cef_browser_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_browser_t>())).has_document as *const _ as usize },
+        ::std::mem::offset_of!(_cef_browser_t, has_document),
         136usize,
         concat!(
             "Offset of field: ",
@@ -13137,7 +12408,7 @@ fn bindgen_test_layout__cef_browser_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_browser_t>())).get_main_frame as *const _ as usize },
+        ::std::mem::offset_of!(_cef_browser_t, get_main_frame),
         144usize,
         concat!(
             "Offset of field: ",
@@ -13147,9 +12418,7 @@ fn bindgen_test_layout__cef_browser_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_t>())).get_focused_frame as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_t, get_focused_frame),
         152usize,
         concat!(
             "Offset of field: ",
@@ -13159,9 +12428,7 @@ fn bindgen_test_layout__cef_browser_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_t>())).get_frame_byident as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_t, get_frame_byident),
         160usize,
         concat!(
             "Offset of field: ",
@@ -13171,7 +12438,7 @@ fn bindgen_test_layout__cef_browser_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_browser_t>())).get_frame as *const _ as usize },
+        ::std::mem::offset_of!(_cef_browser_t, get_frame),
         168usize,
         concat!(
             "Offset of field: ",
@@ -13181,7 +12448,7 @@ fn bindgen_test_layout__cef_browser_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_browser_t>())).get_frame_count as *const _ as usize },
+        ::std::mem::offset_of!(_cef_browser_t, get_frame_count),
         176usize,
         concat!(
             "Offset of field: ",
@@ -13191,9 +12458,7 @@ fn bindgen_test_layout__cef_browser_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_t>())).get_frame_identifiers as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_t, get_frame_identifiers),
         184usize,
         concat!(
             "Offset of field: ",
@@ -13203,7 +12468,7 @@ fn bindgen_test_layout__cef_browser_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_browser_t>())).get_frame_names as *const _ as usize },
+        ::std::mem::offset_of!(_cef_browser_t, get_frame_names),
         192usize,
         concat!(
             "Offset of field: ",
@@ -13239,9 +12504,7 @@ fn bindgen_test_layout__cef_run_file_dialog_callback_t() {
         concat!("Alignment of ", stringify!(_cef_run_file_dialog_callback_t))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_run_file_dialog_callback_t>())).base as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_run_file_dialog_callback_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -13251,10 +12514,7 @@ fn bindgen_test_layout__cef_run_file_dialog_callback_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_run_file_dialog_callback_t>())).on_file_dialog_dismissed
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_run_file_dialog_callback_t, on_file_dialog_dismissed),
         40usize,
         concat!(
             "Offset of field: ",
@@ -13292,9 +12552,7 @@ fn bindgen_test_layout__cef_navigation_entry_visitor_t() {
         concat!("Alignment of ", stringify!(_cef_navigation_entry_visitor_t))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_navigation_entry_visitor_t>())).base as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_navigation_entry_visitor_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -13304,9 +12562,7 @@ fn bindgen_test_layout__cef_navigation_entry_visitor_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_navigation_entry_visitor_t>())).visit as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_navigation_entry_visitor_t, visit),
         40usize,
         concat!(
             "Offset of field: ",
@@ -13342,7 +12598,7 @@ fn bindgen_test_layout__cef_pdf_print_callback_t() {
         concat!("Alignment of ", stringify!(_cef_pdf_print_callback_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_pdf_print_callback_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_pdf_print_callback_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -13352,10 +12608,7 @@ fn bindgen_test_layout__cef_pdf_print_callback_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_pdf_print_callback_t>())).on_pdf_print_finished as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_pdf_print_callback_t, on_pdf_print_finished),
         40usize,
         concat!(
             "Offset of field: ",
@@ -13392,9 +12645,7 @@ fn bindgen_test_layout__cef_download_image_callback_t() {
         concat!("Alignment of ", stringify!(_cef_download_image_callback_t))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_download_image_callback_t>())).base as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_download_image_callback_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -13404,10 +12655,7 @@ fn bindgen_test_layout__cef_download_image_callback_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_download_image_callback_t>())).on_download_image_finished
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_download_image_callback_t, on_download_image_finished),
         40usize,
         concat!(
             "Offset of field: ",
@@ -13714,7 +12962,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         concat!("Alignment of ", stringify!(_cef_browser_host_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_browser_host_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_browser_host_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -13724,7 +12972,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_browser_host_t>())).get_browser as *const _ as usize },
+        ::std::mem::offset_of!(_cef_browser_host_t, get_browser),
         40usize,
         concat!(
             "Offset of field: ",
@@ -13734,9 +12982,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_host_t>())).close_browser as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_host_t, close_browser),
         48usize,
         concat!(
             "Offset of field: ",
@@ -13746,9 +12992,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_host_t>())).try_close_browser as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_host_t, try_close_browser),
         56usize,
         concat!(
             "Offset of field: ",
@@ -13758,7 +13002,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_browser_host_t>())).set_focus as *const _ as usize },
+        ::std::mem::offset_of!(_cef_browser_host_t, set_focus),
         64usize,
         concat!(
             "Offset of field: ",
@@ -13768,9 +13012,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_host_t>())).get_window_handle as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_host_t, get_window_handle),
         72usize,
         concat!(
             "Offset of field: ",
@@ -13780,10 +13022,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_host_t>())).get_opener_window_handle as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_host_t, get_opener_window_handle),
         80usize,
         concat!(
             "Offset of field: ",
@@ -13793,7 +13032,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_browser_host_t>())).has_view as *const _ as usize },
+        ::std::mem::offset_of!(_cef_browser_host_t, has_view),
         88usize,
         concat!(
             "Offset of field: ",
@@ -13803,7 +13042,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_browser_host_t>())).get_client as *const _ as usize },
+        ::std::mem::offset_of!(_cef_browser_host_t, get_client),
         96usize,
         concat!(
             "Offset of field: ",
@@ -13813,9 +13052,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_host_t>())).get_request_context as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_host_t, get_request_context),
         104usize,
         concat!(
             "Offset of field: ",
@@ -13825,9 +13062,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_host_t>())).get_zoom_level as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_host_t, get_zoom_level),
         112usize,
         concat!(
             "Offset of field: ",
@@ -13837,9 +13072,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_host_t>())).set_zoom_level as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_host_t, set_zoom_level),
         120usize,
         concat!(
             "Offset of field: ",
@@ -13849,9 +13082,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_host_t>())).run_file_dialog as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_host_t, run_file_dialog),
         128usize,
         concat!(
             "Offset of field: ",
@@ -13861,9 +13092,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_host_t>())).start_download as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_host_t, start_download),
         136usize,
         concat!(
             "Offset of field: ",
@@ -13873,9 +13102,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_host_t>())).download_image as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_host_t, download_image),
         144usize,
         concat!(
             "Offset of field: ",
@@ -13885,7 +13112,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_browser_host_t>())).print as *const _ as usize },
+        ::std::mem::offset_of!(_cef_browser_host_t, print),
         152usize,
         concat!(
             "Offset of field: ",
@@ -13895,9 +13122,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_host_t>())).print_to_pdf as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_host_t, print_to_pdf),
         160usize,
         concat!(
             "Offset of field: ",
@@ -13907,7 +13132,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_browser_host_t>())).find as *const _ as usize },
+        ::std::mem::offset_of!(_cef_browser_host_t, find),
         168usize,
         concat!(
             "Offset of field: ",
@@ -13917,9 +13142,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_host_t>())).stop_finding as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_host_t, stop_finding),
         176usize,
         concat!(
             "Offset of field: ",
@@ -13929,9 +13152,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_host_t>())).show_dev_tools as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_host_t, show_dev_tools),
         184usize,
         concat!(
             "Offset of field: ",
@@ -13941,9 +13162,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_host_t>())).close_dev_tools as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_host_t, close_dev_tools),
         192usize,
         concat!(
             "Offset of field: ",
@@ -13953,9 +13172,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_host_t>())).has_dev_tools as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_host_t, has_dev_tools),
         200usize,
         concat!(
             "Offset of field: ",
@@ -13965,10 +13182,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_host_t>())).send_dev_tools_message as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_host_t, send_dev_tools_message),
         208usize,
         concat!(
             "Offset of field: ",
@@ -13978,10 +13192,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_host_t>())).execute_dev_tools_method as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_host_t, execute_dev_tools_method),
         216usize,
         concat!(
             "Offset of field: ",
@@ -13991,10 +13202,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_host_t>())).add_dev_tools_message_observer
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_host_t, add_dev_tools_message_observer),
         224usize,
         concat!(
             "Offset of field: ",
@@ -14004,10 +13212,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_host_t>())).get_navigation_entries as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_host_t, get_navigation_entries),
         232usize,
         concat!(
             "Offset of field: ",
@@ -14017,10 +13222,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_host_t>())).set_mouse_cursor_change_disabled
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_host_t, set_mouse_cursor_change_disabled),
         240usize,
         concat!(
             "Offset of field: ",
@@ -14030,10 +13232,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_host_t>())).is_mouse_cursor_change_disabled
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_host_t, is_mouse_cursor_change_disabled),
         248usize,
         concat!(
             "Offset of field: ",
@@ -14043,9 +13242,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_host_t>())).replace_misspelling as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_host_t, replace_misspelling),
         256usize,
         concat!(
             "Offset of field: ",
@@ -14055,10 +13252,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_host_t>())).add_word_to_dictionary as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_host_t, add_word_to_dictionary),
         264usize,
         concat!(
             "Offset of field: ",
@@ -14068,10 +13262,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_host_t>())).is_window_rendering_disabled as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_host_t, is_window_rendering_disabled),
         272usize,
         concat!(
             "Offset of field: ",
@@ -14081,7 +13272,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_browser_host_t>())).was_resized as *const _ as usize },
+        ::std::mem::offset_of!(_cef_browser_host_t, was_resized),
         280usize,
         concat!(
             "Offset of field: ",
@@ -14091,7 +13282,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_browser_host_t>())).was_hidden as *const _ as usize },
+        ::std::mem::offset_of!(_cef_browser_host_t, was_hidden),
         288usize,
         concat!(
             "Offset of field: ",
@@ -14101,10 +13292,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_host_t>())).notify_screen_info_changed as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_host_t, notify_screen_info_changed),
         296usize,
         concat!(
             "Offset of field: ",
@@ -14114,7 +13302,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_browser_host_t>())).invalidate as *const _ as usize },
+        ::std::mem::offset_of!(_cef_browser_host_t, invalidate),
         304usize,
         concat!(
             "Offset of field: ",
@@ -14124,10 +13312,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_host_t>())).send_external_begin_frame as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_host_t, send_external_begin_frame),
         312usize,
         concat!(
             "Offset of field: ",
@@ -14137,9 +13322,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_host_t>())).send_key_event as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_host_t, send_key_event),
         320usize,
         concat!(
             "Offset of field: ",
@@ -14149,10 +13332,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_host_t>())).send_mouse_click_event as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_host_t, send_mouse_click_event),
         328usize,
         concat!(
             "Offset of field: ",
@@ -14162,10 +13342,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_host_t>())).send_mouse_move_event as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_host_t, send_mouse_move_event),
         336usize,
         concat!(
             "Offset of field: ",
@@ -14175,10 +13352,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_host_t>())).send_mouse_wheel_event as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_host_t, send_mouse_wheel_event),
         344usize,
         concat!(
             "Offset of field: ",
@@ -14188,9 +13362,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_host_t>())).send_touch_event as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_host_t, send_touch_event),
         352usize,
         concat!(
             "Offset of field: ",
@@ -14200,9 +13372,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_host_t>())).send_focus_event as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_host_t, send_focus_event),
         360usize,
         concat!(
             "Offset of field: ",
@@ -14212,10 +13382,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_host_t>())).send_capture_lost_event as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_host_t, send_capture_lost_event),
         368usize,
         concat!(
             "Offset of field: ",
@@ -14225,10 +13392,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_host_t>())).notify_move_or_resize_started
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_host_t, notify_move_or_resize_started),
         376usize,
         concat!(
             "Offset of field: ",
@@ -14238,10 +13402,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_host_t>())).get_windowless_frame_rate as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_host_t, get_windowless_frame_rate),
         384usize,
         concat!(
             "Offset of field: ",
@@ -14251,10 +13412,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_host_t>())).set_windowless_frame_rate as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_host_t, set_windowless_frame_rate),
         392usize,
         concat!(
             "Offset of field: ",
@@ -14264,9 +13422,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_host_t>())).ime_set_composition as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_host_t, ime_set_composition),
         400usize,
         concat!(
             "Offset of field: ",
@@ -14276,9 +13432,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_host_t>())).ime_commit_text as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_host_t, ime_commit_text),
         408usize,
         concat!(
             "Offset of field: ",
@@ -14288,10 +13442,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_host_t>())).ime_finish_composing_text as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_host_t, ime_finish_composing_text),
         416usize,
         concat!(
             "Offset of field: ",
@@ -14301,10 +13452,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_host_t>())).ime_cancel_composition as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_host_t, ime_cancel_composition),
         424usize,
         concat!(
             "Offset of field: ",
@@ -14314,10 +13462,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_host_t>())).drag_target_drag_enter as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_host_t, drag_target_drag_enter),
         432usize,
         concat!(
             "Offset of field: ",
@@ -14327,10 +13472,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_host_t>())).drag_target_drag_over as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_host_t, drag_target_drag_over),
         440usize,
         concat!(
             "Offset of field: ",
@@ -14340,10 +13482,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_host_t>())).drag_target_drag_leave as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_host_t, drag_target_drag_leave),
         448usize,
         concat!(
             "Offset of field: ",
@@ -14353,9 +13492,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_host_t>())).drag_target_drop as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_host_t, drag_target_drop),
         456usize,
         concat!(
             "Offset of field: ",
@@ -14365,10 +13502,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_host_t>())).drag_source_ended_at as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_host_t, drag_source_ended_at),
         464usize,
         concat!(
             "Offset of field: ",
@@ -14378,10 +13512,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_host_t>())).drag_source_system_drag_ended
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_host_t, drag_source_system_drag_ended),
         472usize,
         concat!(
             "Offset of field: ",
@@ -14391,10 +13522,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_host_t>())).get_visible_navigation_entry as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_host_t, get_visible_navigation_entry),
         480usize,
         concat!(
             "Offset of field: ",
@@ -14404,10 +13532,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_host_t>())).set_accessibility_state as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_host_t, set_accessibility_state),
         488usize,
         concat!(
             "Offset of field: ",
@@ -14417,10 +13542,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_host_t>())).set_auto_resize_enabled as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_host_t, set_auto_resize_enabled),
         496usize,
         concat!(
             "Offset of field: ",
@@ -14430,9 +13552,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_host_t>())).get_extension as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_host_t, get_extension),
         504usize,
         concat!(
             "Offset of field: ",
@@ -14442,9 +13562,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_host_t>())).is_background_host as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_host_t, is_background_host),
         512usize,
         concat!(
             "Offset of field: ",
@@ -14454,9 +13572,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_host_t>())).set_audio_muted as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_host_t, set_audio_muted),
         520usize,
         concat!(
             "Offset of field: ",
@@ -14466,9 +13582,7 @@ fn bindgen_test_layout__cef_browser_host_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_host_t>())).is_audio_muted as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_host_t, is_audio_muted),
         528usize,
         concat!(
             "Offset of field: ",
@@ -14598,7 +13712,7 @@ fn bindgen_test_layout__cef_print_settings_t() {
         concat!("Alignment of ", stringify!(_cef_print_settings_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_print_settings_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_print_settings_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -14608,7 +13722,7 @@ fn bindgen_test_layout__cef_print_settings_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_print_settings_t>())).is_valid as *const _ as usize },
+        ::std::mem::offset_of!(_cef_print_settings_t, is_valid),
         40usize,
         concat!(
             "Offset of field: ",
@@ -14618,9 +13732,7 @@ fn bindgen_test_layout__cef_print_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_print_settings_t>())).is_read_only as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_print_settings_t, is_read_only),
         48usize,
         concat!(
             "Offset of field: ",
@@ -14630,9 +13742,7 @@ fn bindgen_test_layout__cef_print_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_print_settings_t>())).set_orientation as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_print_settings_t, set_orientation),
         56usize,
         concat!(
             "Offset of field: ",
@@ -14642,9 +13752,7 @@ fn bindgen_test_layout__cef_print_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_print_settings_t>())).is_landscape as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_print_settings_t, is_landscape),
         64usize,
         concat!(
             "Offset of field: ",
@@ -14654,10 +13762,7 @@ fn bindgen_test_layout__cef_print_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_print_settings_t>())).set_printer_printable_area as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_print_settings_t, set_printer_printable_area),
         72usize,
         concat!(
             "Offset of field: ",
@@ -14667,9 +13772,7 @@ fn bindgen_test_layout__cef_print_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_print_settings_t>())).set_device_name as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_print_settings_t, set_device_name),
         80usize,
         concat!(
             "Offset of field: ",
@@ -14679,9 +13782,7 @@ fn bindgen_test_layout__cef_print_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_print_settings_t>())).get_device_name as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_print_settings_t, get_device_name),
         88usize,
         concat!(
             "Offset of field: ",
@@ -14691,7 +13792,7 @@ fn bindgen_test_layout__cef_print_settings_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_print_settings_t>())).set_dpi as *const _ as usize },
+        ::std::mem::offset_of!(_cef_print_settings_t, set_dpi),
         96usize,
         concat!(
             "Offset of field: ",
@@ -14701,7 +13802,7 @@ fn bindgen_test_layout__cef_print_settings_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_print_settings_t>())).get_dpi as *const _ as usize },
+        ::std::mem::offset_of!(_cef_print_settings_t, get_dpi),
         104usize,
         concat!(
             "Offset of field: ",
@@ -14711,9 +13812,7 @@ fn bindgen_test_layout__cef_print_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_print_settings_t>())).set_page_ranges as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_print_settings_t, set_page_ranges),
         112usize,
         concat!(
             "Offset of field: ",
@@ -14723,10 +13822,7 @@ fn bindgen_test_layout__cef_print_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_print_settings_t>())).get_page_ranges_count as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_print_settings_t, get_page_ranges_count),
         120usize,
         concat!(
             "Offset of field: ",
@@ -14736,9 +13832,7 @@ fn bindgen_test_layout__cef_print_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_print_settings_t>())).get_page_ranges as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_print_settings_t, get_page_ranges),
         128usize,
         concat!(
             "Offset of field: ",
@@ -14748,10 +13842,7 @@ fn bindgen_test_layout__cef_print_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_print_settings_t>())).set_selection_only as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_print_settings_t, set_selection_only),
         136usize,
         concat!(
             "Offset of field: ",
@@ -14761,9 +13852,7 @@ fn bindgen_test_layout__cef_print_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_print_settings_t>())).is_selection_only as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_print_settings_t, is_selection_only),
         144usize,
         concat!(
             "Offset of field: ",
@@ -14773,9 +13862,7 @@ fn bindgen_test_layout__cef_print_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_print_settings_t>())).set_collate as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_print_settings_t, set_collate),
         152usize,
         concat!(
             "Offset of field: ",
@@ -14785,9 +13872,7 @@ fn bindgen_test_layout__cef_print_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_print_settings_t>())).will_collate as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_print_settings_t, will_collate),
         160usize,
         concat!(
             "Offset of field: ",
@@ -14797,9 +13882,7 @@ fn bindgen_test_layout__cef_print_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_print_settings_t>())).set_color_model as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_print_settings_t, set_color_model),
         168usize,
         concat!(
             "Offset of field: ",
@@ -14809,9 +13892,7 @@ fn bindgen_test_layout__cef_print_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_print_settings_t>())).get_color_model as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_print_settings_t, get_color_model),
         176usize,
         concat!(
             "Offset of field: ",
@@ -14821,9 +13902,7 @@ fn bindgen_test_layout__cef_print_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_print_settings_t>())).set_copies as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_print_settings_t, set_copies),
         184usize,
         concat!(
             "Offset of field: ",
@@ -14833,9 +13912,7 @@ fn bindgen_test_layout__cef_print_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_print_settings_t>())).get_copies as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_print_settings_t, get_copies),
         192usize,
         concat!(
             "Offset of field: ",
@@ -14845,9 +13922,7 @@ fn bindgen_test_layout__cef_print_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_print_settings_t>())).set_duplex_mode as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_print_settings_t, set_duplex_mode),
         200usize,
         concat!(
             "Offset of field: ",
@@ -14857,9 +13932,7 @@ fn bindgen_test_layout__cef_print_settings_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_print_settings_t>())).get_duplex_mode as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_print_settings_t, get_duplex_mode),
         208usize,
         concat!(
             "Offset of field: ",
@@ -14899,9 +13972,7 @@ fn bindgen_test_layout__cef_print_dialog_callback_t() {
         concat!("Alignment of ", stringify!(_cef_print_dialog_callback_t))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_print_dialog_callback_t>())).base as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_print_dialog_callback_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -14911,9 +13982,7 @@ fn bindgen_test_layout__cef_print_dialog_callback_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_print_dialog_callback_t>())).cont as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_print_dialog_callback_t, cont),
         40usize,
         concat!(
             "Offset of field: ",
@@ -14923,9 +13992,7 @@ fn bindgen_test_layout__cef_print_dialog_callback_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_print_dialog_callback_t>())).cancel as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_print_dialog_callback_t, cancel),
         48usize,
         concat!(
             "Offset of field: ",
@@ -14955,7 +14022,7 @@ fn bindgen_test_layout__cef_print_job_callback_t() {
         concat!("Alignment of ", stringify!(_cef_print_job_callback_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_print_job_callback_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_print_job_callback_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -14965,7 +14032,7 @@ fn bindgen_test_layout__cef_print_job_callback_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_print_job_callback_t>())).cont as *const _ as usize },
+        ::std::mem::offset_of!(_cef_print_job_callback_t, cont),
         40usize,
         concat!(
             "Offset of field: ",
@@ -15031,7 +14098,7 @@ fn bindgen_test_layout__cef_print_handler_t() {
         concat!("Alignment of ", stringify!(_cef_print_handler_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_print_handler_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_print_handler_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -15041,9 +14108,7 @@ fn bindgen_test_layout__cef_print_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_print_handler_t>())).on_print_start as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_print_handler_t, on_print_start),
         40usize,
         concat!(
             "Offset of field: ",
@@ -15053,9 +14118,7 @@ fn bindgen_test_layout__cef_print_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_print_handler_t>())).on_print_settings as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_print_handler_t, on_print_settings),
         48usize,
         concat!(
             "Offset of field: ",
@@ -15065,9 +14128,7 @@ fn bindgen_test_layout__cef_print_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_print_handler_t>())).on_print_dialog as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_print_handler_t, on_print_dialog),
         56usize,
         concat!(
             "Offset of field: ",
@@ -15077,9 +14138,7 @@ fn bindgen_test_layout__cef_print_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_print_handler_t>())).on_print_job as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_print_handler_t, on_print_job),
         64usize,
         concat!(
             "Offset of field: ",
@@ -15089,9 +14148,7 @@ fn bindgen_test_layout__cef_print_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_print_handler_t>())).on_print_reset as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_print_handler_t, on_print_reset),
         72usize,
         concat!(
             "Offset of field: ",
@@ -15101,9 +14158,7 @@ fn bindgen_test_layout__cef_print_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_print_handler_t>())).get_pdf_paper_size as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_print_handler_t, get_pdf_paper_size),
         80usize,
         concat!(
             "Offset of field: ",
@@ -15154,9 +14209,7 @@ fn bindgen_test_layout__cef_browser_process_handler_t() {
         concat!("Alignment of ", stringify!(_cef_browser_process_handler_t))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_process_handler_t>())).base as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_process_handler_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -15166,10 +14219,7 @@ fn bindgen_test_layout__cef_browser_process_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_process_handler_t>())).on_context_initialized
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_process_handler_t, on_context_initialized),
         40usize,
         concat!(
             "Offset of field: ",
@@ -15179,10 +14229,7 @@ fn bindgen_test_layout__cef_browser_process_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_process_handler_t>()))
-                .on_before_child_process_launch as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_process_handler_t, on_before_child_process_launch),
         48usize,
         concat!(
             "Offset of field: ",
@@ -15192,10 +14239,7 @@ fn bindgen_test_layout__cef_browser_process_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_process_handler_t>()))
-                .on_render_process_thread_created as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_process_handler_t, on_render_process_thread_created),
         56usize,
         concat!(
             "Offset of field: ",
@@ -15205,10 +14249,7 @@ fn bindgen_test_layout__cef_browser_process_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_process_handler_t>())).get_print_handler as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_process_handler_t, get_print_handler),
         64usize,
         concat!(
             "Offset of field: ",
@@ -15218,10 +14259,7 @@ fn bindgen_test_layout__cef_browser_process_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_browser_process_handler_t>())).on_schedule_message_pump_work
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_browser_process_handler_t, on_schedule_message_pump_work),
         72usize,
         concat!(
             "Offset of field: ",
@@ -15285,7 +14323,7 @@ fn bindgen_test_layout__cef_load_handler_t() {
         concat!("Alignment of ", stringify!(_cef_load_handler_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_load_handler_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_load_handler_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -15295,10 +14333,7 @@ fn bindgen_test_layout__cef_load_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_load_handler_t>())).on_loading_state_change as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_load_handler_t, on_loading_state_change),
         40usize,
         concat!(
             "Offset of field: ",
@@ -15308,9 +14343,7 @@ fn bindgen_test_layout__cef_load_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_load_handler_t>())).on_load_start as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_load_handler_t, on_load_start),
         48usize,
         concat!(
             "Offset of field: ",
@@ -15320,7 +14353,7 @@ fn bindgen_test_layout__cef_load_handler_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_load_handler_t>())).on_load_end as *const _ as usize },
+        ::std::mem::offset_of!(_cef_load_handler_t, on_load_end),
         56usize,
         concat!(
             "Offset of field: ",
@@ -15330,9 +14363,7 @@ fn bindgen_test_layout__cef_load_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_load_handler_t>())).on_load_error as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_load_handler_t, on_load_error),
         64usize,
         concat!(
             "Offset of field: ",
@@ -15362,7 +14393,7 @@ fn bindgen_test_layout__cef_task_t() {
         concat!("Alignment of ", stringify!(_cef_task_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_task_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_task_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -15372,7 +14403,7 @@ fn bindgen_test_layout__cef_task_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_task_t>())).execute as *const _ as usize },
+        ::std::mem::offset_of!(_cef_task_t, execute),
         40usize,
         concat!(
             "Offset of field: ",
@@ -15429,7 +14460,7 @@ fn bindgen_test_layout__cef_task_runner_t() {
         concat!("Alignment of ", stringify!(_cef_task_runner_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_task_runner_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_task_runner_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -15439,7 +14470,7 @@ fn bindgen_test_layout__cef_task_runner_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_task_runner_t>())).is_same as *const _ as usize },
+        ::std::mem::offset_of!(_cef_task_runner_t, is_same),
         40usize,
         concat!(
             "Offset of field: ",
@@ -15449,10 +14480,7 @@ fn bindgen_test_layout__cef_task_runner_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_task_runner_t>())).belongs_to_current_thread as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_task_runner_t, belongs_to_current_thread),
         48usize,
         concat!(
             "Offset of field: ",
@@ -15462,9 +14490,7 @@ fn bindgen_test_layout__cef_task_runner_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_task_runner_t>())).belongs_to_thread as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_task_runner_t, belongs_to_thread),
         56usize,
         concat!(
             "Offset of field: ",
@@ -15474,7 +14500,7 @@ fn bindgen_test_layout__cef_task_runner_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_task_runner_t>())).post_task as *const _ as usize },
+        ::std::mem::offset_of!(_cef_task_runner_t, post_task),
         64usize,
         concat!(
             "Offset of field: ",
@@ -15484,9 +14510,7 @@ fn bindgen_test_layout__cef_task_runner_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_task_runner_t>())).post_delayed_task as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_task_runner_t, post_delayed_task),
         72usize,
         concat!(
             "Offset of field: ",
@@ -15576,7 +14600,7 @@ fn bindgen_test_layout__cef_v8context_t() {
         concat!("Alignment of ", stringify!(_cef_v8context_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_v8context_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_v8context_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -15586,9 +14610,7 @@ fn bindgen_test_layout__cef_v8context_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_v8context_t>())).get_task_runner as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_v8context_t, get_task_runner),
         40usize,
         concat!(
             "Offset of field: ",
@@ -15598,7 +14620,7 @@ fn bindgen_test_layout__cef_v8context_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_v8context_t>())).is_valid as *const _ as usize },
+        ::std::mem::offset_of!(_cef_v8context_t, is_valid),
         48usize,
         concat!(
             "Offset of field: ",
@@ -15608,7 +14630,7 @@ fn bindgen_test_layout__cef_v8context_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_v8context_t>())).get_browser as *const _ as usize },
+        ::std::mem::offset_of!(_cef_v8context_t, get_browser),
         56usize,
         concat!(
             "Offset of field: ",
@@ -15618,7 +14640,7 @@ fn bindgen_test_layout__cef_v8context_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_v8context_t>())).get_frame as *const _ as usize },
+        ::std::mem::offset_of!(_cef_v8context_t, get_frame),
         64usize,
         concat!(
             "Offset of field: ",
@@ -15628,7 +14650,7 @@ fn bindgen_test_layout__cef_v8context_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_v8context_t>())).get_global as *const _ as usize },
+        ::std::mem::offset_of!(_cef_v8context_t, get_global),
         72usize,
         concat!(
             "Offset of field: ",
@@ -15638,7 +14660,7 @@ fn bindgen_test_layout__cef_v8context_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_v8context_t>())).enter as *const _ as usize },
+        ::std::mem::offset_of!(_cef_v8context_t, enter),
         80usize,
         concat!(
             "Offset of field: ",
@@ -15648,7 +14670,7 @@ fn bindgen_test_layout__cef_v8context_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_v8context_t>())).exit as *const _ as usize },
+        ::std::mem::offset_of!(_cef_v8context_t, exit),
         88usize,
         concat!(
             "Offset of field: ",
@@ -15658,7 +14680,7 @@ fn bindgen_test_layout__cef_v8context_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_v8context_t>())).is_same as *const _ as usize },
+        ::std::mem::offset_of!(_cef_v8context_t, is_same),
         96usize,
         concat!(
             "Offset of field: ",
@@ -15668,7 +14690,7 @@ fn bindgen_test_layout__cef_v8context_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_v8context_t>())).eval as *const _ as usize },
+        ::std::mem::offset_of!(_cef_v8context_t, eval),
         104usize,
         concat!(
             "Offset of field: ",
@@ -15717,7 +14739,7 @@ fn bindgen_test_layout__cef_v8handler_t() {
         concat!("Alignment of ", stringify!(_cef_v8handler_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_v8handler_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_v8handler_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -15727,7 +14749,7 @@ fn bindgen_test_layout__cef_v8handler_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_v8handler_t>())).execute as *const _ as usize },
+        ::std::mem::offset_of!(_cef_v8handler_t, execute),
         40usize,
         concat!(
             "Offset of field: ",
@@ -15774,7 +14796,7 @@ fn bindgen_test_layout__cef_v8accessor_t() {
         concat!("Alignment of ", stringify!(_cef_v8accessor_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_v8accessor_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_v8accessor_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -15784,7 +14806,7 @@ fn bindgen_test_layout__cef_v8accessor_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_v8accessor_t>())).get as *const _ as usize },
+        ::std::mem::offset_of!(_cef_v8accessor_t, get),
         40usize,
         concat!(
             "Offset of field: ",
@@ -15794,7 +14816,7 @@ fn bindgen_test_layout__cef_v8accessor_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_v8accessor_t>())).set as *const _ as usize },
+        ::std::mem::offset_of!(_cef_v8accessor_t, set),
         48usize,
         concat!(
             "Offset of field: ",
@@ -15859,7 +14881,7 @@ fn bindgen_test_layout__cef_v8interceptor_t() {
         concat!("Alignment of ", stringify!(_cef_v8interceptor_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_v8interceptor_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_v8interceptor_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -15869,7 +14891,7 @@ fn bindgen_test_layout__cef_v8interceptor_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_v8interceptor_t>())).get_byname as *const _ as usize },
+        ::std::mem::offset_of!(_cef_v8interceptor_t, get_byname),
         40usize,
         concat!(
             "Offset of field: ",
@@ -15879,9 +14901,7 @@ fn bindgen_test_layout__cef_v8interceptor_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_v8interceptor_t>())).get_byindex as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_v8interceptor_t, get_byindex),
         48usize,
         concat!(
             "Offset of field: ",
@@ -15891,7 +14911,7 @@ fn bindgen_test_layout__cef_v8interceptor_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_v8interceptor_t>())).set_byname as *const _ as usize },
+        ::std::mem::offset_of!(_cef_v8interceptor_t, set_byname),
         56usize,
         concat!(
             "Offset of field: ",
@@ -15901,9 +14921,7 @@ fn bindgen_test_layout__cef_v8interceptor_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_v8interceptor_t>())).set_byindex as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_v8interceptor_t, set_byindex),
         64usize,
         concat!(
             "Offset of field: ",
@@ -15956,7 +14974,7 @@ fn bindgen_test_layout__cef_v8exception_t() {
         concat!("Alignment of ", stringify!(_cef_v8exception_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_v8exception_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_v8exception_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -15966,7 +14984,7 @@ fn bindgen_test_layout__cef_v8exception_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_v8exception_t>())).get_message as *const _ as usize },
+        ::std::mem::offset_of!(_cef_v8exception_t, get_message),
         40usize,
         concat!(
             "Offset of field: ",
@@ -15976,9 +14994,7 @@ fn bindgen_test_layout__cef_v8exception_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_v8exception_t>())).get_source_line as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_v8exception_t, get_source_line),
         48usize,
         concat!(
             "Offset of field: ",
@@ -15988,10 +15004,7 @@ fn bindgen_test_layout__cef_v8exception_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_v8exception_t>())).get_script_resource_name as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_v8exception_t, get_script_resource_name),
         56usize,
         concat!(
             "Offset of field: ",
@@ -16001,9 +15014,7 @@ fn bindgen_test_layout__cef_v8exception_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_v8exception_t>())).get_line_number as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_v8exception_t, get_line_number),
         64usize,
         concat!(
             "Offset of field: ",
@@ -16013,9 +15024,7 @@ fn bindgen_test_layout__cef_v8exception_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_v8exception_t>())).get_start_position as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_v8exception_t, get_start_position),
         72usize,
         concat!(
             "Offset of field: ",
@@ -16025,9 +15034,7 @@ fn bindgen_test_layout__cef_v8exception_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_v8exception_t>())).get_end_position as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_v8exception_t, get_end_position),
         80usize,
         concat!(
             "Offset of field: ",
@@ -16037,9 +15044,7 @@ fn bindgen_test_layout__cef_v8exception_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_v8exception_t>())).get_start_column as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_v8exception_t, get_start_column),
         88usize,
         concat!(
             "Offset of field: ",
@@ -16049,9 +15054,7 @@ fn bindgen_test_layout__cef_v8exception_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_v8exception_t>())).get_end_column as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_v8exception_t, get_end_column),
         96usize,
         concat!(
             "Offset of field: ",
@@ -16092,10 +15095,7 @@ fn bindgen_test_layout__cef_v8array_buffer_release_callback_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_v8array_buffer_release_callback_t>())).base as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_v8array_buffer_release_callback_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -16105,10 +15105,7 @@ fn bindgen_test_layout__cef_v8array_buffer_release_callback_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_v8array_buffer_release_callback_t>())).release_buffer
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_v8array_buffer_release_callback_t, release_buffer),
         40usize,
         concat!(
             "Offset of field: ",
@@ -16334,7 +15331,7 @@ fn bindgen_test_layout__cef_v8value_t() {
         concat!("Alignment of ", stringify!(_cef_v8value_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_v8value_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_v8value_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -16344,7 +15341,7 @@ fn bindgen_test_layout__cef_v8value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_v8value_t>())).is_valid as *const _ as usize },
+        ::std::mem::offset_of!(_cef_v8value_t, is_valid),
         40usize,
         concat!(
             "Offset of field: ",
@@ -16354,7 +15351,7 @@ fn bindgen_test_layout__cef_v8value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_v8value_t>())).is_undefined as *const _ as usize },
+        ::std::mem::offset_of!(_cef_v8value_t, is_undefined),
         48usize,
         concat!(
             "Offset of field: ",
@@ -16364,7 +15361,7 @@ fn bindgen_test_layout__cef_v8value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_v8value_t>())).is_null as *const _ as usize },
+        ::std::mem::offset_of!(_cef_v8value_t, is_null),
         56usize,
         concat!(
             "Offset of field: ",
@@ -16374,7 +15371,7 @@ fn bindgen_test_layout__cef_v8value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_v8value_t>())).is_bool as *const _ as usize },
+        ::std::mem::offset_of!(_cef_v8value_t, is_bool),
         64usize,
         concat!(
             "Offset of field: ",
@@ -16384,7 +15381,7 @@ fn bindgen_test_layout__cef_v8value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_v8value_t>())).is_int as *const _ as usize },
+        ::std::mem::offset_of!(_cef_v8value_t, is_int),
         72usize,
         concat!(
             "Offset of field: ",
@@ -16394,7 +15391,7 @@ fn bindgen_test_layout__cef_v8value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_v8value_t>())).is_uint as *const _ as usize },
+        ::std::mem::offset_of!(_cef_v8value_t, is_uint),
         80usize,
         concat!(
             "Offset of field: ",
@@ -16404,7 +15401,7 @@ fn bindgen_test_layout__cef_v8value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_v8value_t>())).is_double as *const _ as usize },
+        ::std::mem::offset_of!(_cef_v8value_t, is_double),
         88usize,
         concat!(
             "Offset of field: ",
@@ -16414,7 +15411,7 @@ fn bindgen_test_layout__cef_v8value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_v8value_t>())).is_date as *const _ as usize },
+        ::std::mem::offset_of!(_cef_v8value_t, is_date),
         96usize,
         concat!(
             "Offset of field: ",
@@ -16424,7 +15421,7 @@ fn bindgen_test_layout__cef_v8value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_v8value_t>())).is_string as *const _ as usize },
+        ::std::mem::offset_of!(_cef_v8value_t, is_string),
         104usize,
         concat!(
             "Offset of field: ",
@@ -16434,7 +15431,7 @@ fn bindgen_test_layout__cef_v8value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_v8value_t>())).is_object as *const _ as usize },
+        ::std::mem::offset_of!(_cef_v8value_t, is_object),
         112usize,
         concat!(
             "Offset of field: ",
@@ -16444,7 +15441,7 @@ fn bindgen_test_layout__cef_v8value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_v8value_t>())).is_array as *const _ as usize },
+        ::std::mem::offset_of!(_cef_v8value_t, is_array),
         120usize,
         concat!(
             "Offset of field: ",
@@ -16454,7 +15451,7 @@ fn bindgen_test_layout__cef_v8value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_v8value_t>())).is_array_buffer as *const _ as usize },
+        ::std::mem::offset_of!(_cef_v8value_t, is_array_buffer),
         128usize,
         concat!(
             "Offset of field: ",
@@ -16464,7 +15461,7 @@ fn bindgen_test_layout__cef_v8value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_v8value_t>())).is_function as *const _ as usize },
+        ::std::mem::offset_of!(_cef_v8value_t, is_function),
         136usize,
         concat!(
             "Offset of field: ",
@@ -16474,7 +15471,7 @@ fn bindgen_test_layout__cef_v8value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_v8value_t>())).is_same as *const _ as usize },
+        ::std::mem::offset_of!(_cef_v8value_t, is_same),
         144usize,
         concat!(
             "Offset of field: ",
@@ -16484,7 +15481,7 @@ fn bindgen_test_layout__cef_v8value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_v8value_t>())).get_bool_value as *const _ as usize },
+        ::std::mem::offset_of!(_cef_v8value_t, get_bool_value),
         152usize,
         concat!(
             "Offset of field: ",
@@ -16494,7 +15491,7 @@ fn bindgen_test_layout__cef_v8value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_v8value_t>())).get_int_value as *const _ as usize },
+        ::std::mem::offset_of!(_cef_v8value_t, get_int_value),
         160usize,
         concat!(
             "Offset of field: ",
@@ -16504,7 +15501,7 @@ fn bindgen_test_layout__cef_v8value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_v8value_t>())).get_uint_value as *const _ as usize },
+        ::std::mem::offset_of!(_cef_v8value_t, get_uint_value),
         168usize,
         concat!(
             "Offset of field: ",
@@ -16514,7 +15511,7 @@ fn bindgen_test_layout__cef_v8value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_v8value_t>())).get_double_value as *const _ as usize },
+        ::std::mem::offset_of!(_cef_v8value_t, get_double_value),
         176usize,
         concat!(
             "Offset of field: ",
@@ -16524,7 +15521,7 @@ fn bindgen_test_layout__cef_v8value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_v8value_t>())).get_date_value as *const _ as usize },
+        ::std::mem::offset_of!(_cef_v8value_t, get_date_value),
         184usize,
         concat!(
             "Offset of field: ",
@@ -16534,7 +15531,7 @@ fn bindgen_test_layout__cef_v8value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_v8value_t>())).get_string_value as *const _ as usize },
+        ::std::mem::offset_of!(_cef_v8value_t, get_string_value),
         192usize,
         concat!(
             "Offset of field: ",
@@ -16544,7 +15541,7 @@ fn bindgen_test_layout__cef_v8value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_v8value_t>())).is_user_created as *const _ as usize },
+        ::std::mem::offset_of!(_cef_v8value_t, is_user_created),
         200usize,
         concat!(
             "Offset of field: ",
@@ -16554,7 +15551,7 @@ fn bindgen_test_layout__cef_v8value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_v8value_t>())).has_exception as *const _ as usize },
+        ::std::mem::offset_of!(_cef_v8value_t, has_exception),
         208usize,
         concat!(
             "Offset of field: ",
@@ -16564,7 +15561,7 @@ fn bindgen_test_layout__cef_v8value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_v8value_t>())).get_exception as *const _ as usize },
+        ::std::mem::offset_of!(_cef_v8value_t, get_exception),
         216usize,
         concat!(
             "Offset of field: ",
@@ -16574,7 +15571,7 @@ fn bindgen_test_layout__cef_v8value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_v8value_t>())).clear_exception as *const _ as usize },
+        ::std::mem::offset_of!(_cef_v8value_t, clear_exception),
         224usize,
         concat!(
             "Offset of field: ",
@@ -16584,9 +15581,7 @@ fn bindgen_test_layout__cef_v8value_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_v8value_t>())).will_rethrow_exceptions as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_v8value_t, will_rethrow_exceptions),
         232usize,
         concat!(
             "Offset of field: ",
@@ -16596,9 +15591,7 @@ fn bindgen_test_layout__cef_v8value_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_v8value_t>())).set_rethrow_exceptions as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_v8value_t, set_rethrow_exceptions),
         240usize,
         concat!(
             "Offset of field: ",
@@ -16608,7 +15601,7 @@ fn bindgen_test_layout__cef_v8value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_v8value_t>())).has_value_bykey as *const _ as usize },
+        ::std::mem::offset_of!(_cef_v8value_t, has_value_bykey),
         248usize,
         concat!(
             "Offset of field: ",
@@ -16618,9 +15611,7 @@ fn bindgen_test_layout__cef_v8value_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_v8value_t>())).has_value_byindex as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_v8value_t, has_value_byindex),
         256usize,
         concat!(
             "Offset of field: ",
@@ -16630,9 +15621,7 @@ fn bindgen_test_layout__cef_v8value_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_v8value_t>())).delete_value_bykey as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_v8value_t, delete_value_bykey),
         264usize,
         concat!(
             "Offset of field: ",
@@ -16642,9 +15631,7 @@ fn bindgen_test_layout__cef_v8value_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_v8value_t>())).delete_value_byindex as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_v8value_t, delete_value_byindex),
         272usize,
         concat!(
             "Offset of field: ",
@@ -16654,7 +15641,7 @@ fn bindgen_test_layout__cef_v8value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_v8value_t>())).get_value_bykey as *const _ as usize },
+        ::std::mem::offset_of!(_cef_v8value_t, get_value_bykey),
         280usize,
         concat!(
             "Offset of field: ",
@@ -16664,9 +15651,7 @@ fn bindgen_test_layout__cef_v8value_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_v8value_t>())).get_value_byindex as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_v8value_t, get_value_byindex),
         288usize,
         concat!(
             "Offset of field: ",
@@ -16676,7 +15661,7 @@ fn bindgen_test_layout__cef_v8value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_v8value_t>())).set_value_bykey as *const _ as usize },
+        ::std::mem::offset_of!(_cef_v8value_t, set_value_bykey),
         296usize,
         concat!(
             "Offset of field: ",
@@ -16686,9 +15671,7 @@ fn bindgen_test_layout__cef_v8value_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_v8value_t>())).set_value_byindex as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_v8value_t, set_value_byindex),
         304usize,
         concat!(
             "Offset of field: ",
@@ -16698,9 +15681,7 @@ fn bindgen_test_layout__cef_v8value_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_v8value_t>())).set_value_byaccessor as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_v8value_t, set_value_byaccessor),
         312usize,
         concat!(
             "Offset of field: ",
@@ -16710,7 +15691,7 @@ fn bindgen_test_layout__cef_v8value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_v8value_t>())).get_keys as *const _ as usize },
+        ::std::mem::offset_of!(_cef_v8value_t, get_keys),
         320usize,
         concat!(
             "Offset of field: ",
@@ -16720,7 +15701,7 @@ fn bindgen_test_layout__cef_v8value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_v8value_t>())).set_user_data as *const _ as usize },
+        ::std::mem::offset_of!(_cef_v8value_t, set_user_data),
         328usize,
         concat!(
             "Offset of field: ",
@@ -16730,7 +15711,7 @@ fn bindgen_test_layout__cef_v8value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_v8value_t>())).get_user_data as *const _ as usize },
+        ::std::mem::offset_of!(_cef_v8value_t, get_user_data),
         336usize,
         concat!(
             "Offset of field: ",
@@ -16740,10 +15721,7 @@ fn bindgen_test_layout__cef_v8value_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_v8value_t>())).get_externally_allocated_memory as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_v8value_t, get_externally_allocated_memory),
         344usize,
         concat!(
             "Offset of field: ",
@@ -16753,10 +15731,7 @@ fn bindgen_test_layout__cef_v8value_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_v8value_t>())).adjust_externally_allocated_memory
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_v8value_t, adjust_externally_allocated_memory),
         352usize,
         concat!(
             "Offset of field: ",
@@ -16766,7 +15741,7 @@ fn bindgen_test_layout__cef_v8value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_v8value_t>())).get_array_length as *const _ as usize },
+        ::std::mem::offset_of!(_cef_v8value_t, get_array_length),
         360usize,
         concat!(
             "Offset of field: ",
@@ -16776,10 +15751,7 @@ fn bindgen_test_layout__cef_v8value_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_v8value_t>())).get_array_buffer_release_callback as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_v8value_t, get_array_buffer_release_callback),
         368usize,
         concat!(
             "Offset of field: ",
@@ -16789,9 +15761,7 @@ fn bindgen_test_layout__cef_v8value_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_v8value_t>())).neuter_array_buffer as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_v8value_t, neuter_array_buffer),
         376usize,
         concat!(
             "Offset of field: ",
@@ -16801,9 +15771,7 @@ fn bindgen_test_layout__cef_v8value_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_v8value_t>())).get_function_name as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_v8value_t, get_function_name),
         384usize,
         concat!(
             "Offset of field: ",
@@ -16813,9 +15781,7 @@ fn bindgen_test_layout__cef_v8value_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_v8value_t>())).get_function_handler as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_v8value_t, get_function_handler),
         392usize,
         concat!(
             "Offset of field: ",
@@ -16825,7 +15791,7 @@ fn bindgen_test_layout__cef_v8value_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_v8value_t>())).execute_function as *const _ as usize },
+        ::std::mem::offset_of!(_cef_v8value_t, execute_function),
         400usize,
         concat!(
             "Offset of field: ",
@@ -16835,10 +15801,7 @@ fn bindgen_test_layout__cef_v8value_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_v8value_t>())).execute_function_with_context as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_v8value_t, execute_function_with_context),
         408usize,
         concat!(
             "Offset of field: ",
@@ -16925,7 +15888,7 @@ fn bindgen_test_layout__cef_v8stack_trace_t() {
         concat!("Alignment of ", stringify!(_cef_v8stack_trace_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_v8stack_trace_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_v8stack_trace_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -16935,7 +15898,7 @@ fn bindgen_test_layout__cef_v8stack_trace_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_v8stack_trace_t>())).is_valid as *const _ as usize },
+        ::std::mem::offset_of!(_cef_v8stack_trace_t, is_valid),
         40usize,
         concat!(
             "Offset of field: ",
@@ -16945,9 +15908,7 @@ fn bindgen_test_layout__cef_v8stack_trace_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_v8stack_trace_t>())).get_frame_count as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_v8stack_trace_t, get_frame_count),
         48usize,
         concat!(
             "Offset of field: ",
@@ -16957,7 +15918,7 @@ fn bindgen_test_layout__cef_v8stack_trace_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_v8stack_trace_t>())).get_frame as *const _ as usize },
+        ::std::mem::offset_of!(_cef_v8stack_trace_t, get_frame),
         56usize,
         concat!(
             "Offset of field: ",
@@ -17015,7 +15976,7 @@ fn bindgen_test_layout__cef_v8stack_frame_t() {
         concat!("Alignment of ", stringify!(_cef_v8stack_frame_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_v8stack_frame_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_v8stack_frame_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -17025,7 +15986,7 @@ fn bindgen_test_layout__cef_v8stack_frame_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_v8stack_frame_t>())).is_valid as *const _ as usize },
+        ::std::mem::offset_of!(_cef_v8stack_frame_t, is_valid),
         40usize,
         concat!(
             "Offset of field: ",
@@ -17035,9 +15996,7 @@ fn bindgen_test_layout__cef_v8stack_frame_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_v8stack_frame_t>())).get_script_name as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_v8stack_frame_t, get_script_name),
         48usize,
         concat!(
             "Offset of field: ",
@@ -17047,10 +16006,7 @@ fn bindgen_test_layout__cef_v8stack_frame_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_v8stack_frame_t>())).get_script_name_or_source_url
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_v8stack_frame_t, get_script_name_or_source_url),
         56usize,
         concat!(
             "Offset of field: ",
@@ -17060,9 +16016,7 @@ fn bindgen_test_layout__cef_v8stack_frame_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_v8stack_frame_t>())).get_function_name as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_v8stack_frame_t, get_function_name),
         64usize,
         concat!(
             "Offset of field: ",
@@ -17072,9 +16026,7 @@ fn bindgen_test_layout__cef_v8stack_frame_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_v8stack_frame_t>())).get_line_number as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_v8stack_frame_t, get_line_number),
         72usize,
         concat!(
             "Offset of field: ",
@@ -17084,7 +16036,7 @@ fn bindgen_test_layout__cef_v8stack_frame_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_v8stack_frame_t>())).get_column as *const _ as usize },
+        ::std::mem::offset_of!(_cef_v8stack_frame_t, get_column),
         80usize,
         concat!(
             "Offset of field: ",
@@ -17094,7 +16046,7 @@ fn bindgen_test_layout__cef_v8stack_frame_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_v8stack_frame_t>())).is_eval as *const _ as usize },
+        ::std::mem::offset_of!(_cef_v8stack_frame_t, is_eval),
         88usize,
         concat!(
             "Offset of field: ",
@@ -17104,9 +16056,7 @@ fn bindgen_test_layout__cef_v8stack_frame_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_v8stack_frame_t>())).is_constructor as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_v8stack_frame_t, is_constructor),
         96usize,
         concat!(
             "Offset of field: ",
@@ -17209,9 +16159,7 @@ fn bindgen_test_layout__cef_render_process_handler_t() {
         concat!("Alignment of ", stringify!(_cef_render_process_handler_t))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_render_process_handler_t>())).base as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_render_process_handler_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -17221,10 +16169,7 @@ fn bindgen_test_layout__cef_render_process_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_render_process_handler_t>())).on_render_thread_created
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_render_process_handler_t, on_render_thread_created),
         40usize,
         concat!(
             "Offset of field: ",
@@ -17234,10 +16179,7 @@ fn bindgen_test_layout__cef_render_process_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_render_process_handler_t>())).on_web_kit_initialized
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_render_process_handler_t, on_web_kit_initialized),
         48usize,
         concat!(
             "Offset of field: ",
@@ -17247,10 +16189,7 @@ fn bindgen_test_layout__cef_render_process_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_render_process_handler_t>())).on_browser_created as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_render_process_handler_t, on_browser_created),
         56usize,
         concat!(
             "Offset of field: ",
@@ -17260,10 +16199,7 @@ fn bindgen_test_layout__cef_render_process_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_render_process_handler_t>())).on_browser_destroyed
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_render_process_handler_t, on_browser_destroyed),
         64usize,
         concat!(
             "Offset of field: ",
@@ -17273,10 +16209,7 @@ fn bindgen_test_layout__cef_render_process_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_render_process_handler_t>())).get_load_handler as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_render_process_handler_t, get_load_handler),
         72usize,
         concat!(
             "Offset of field: ",
@@ -17286,10 +16219,7 @@ fn bindgen_test_layout__cef_render_process_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_render_process_handler_t>())).on_context_created as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_render_process_handler_t, on_context_created),
         80usize,
         concat!(
             "Offset of field: ",
@@ -17299,10 +16229,7 @@ fn bindgen_test_layout__cef_render_process_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_render_process_handler_t>())).on_context_released
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_render_process_handler_t, on_context_released),
         88usize,
         concat!(
             "Offset of field: ",
@@ -17312,10 +16239,7 @@ fn bindgen_test_layout__cef_render_process_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_render_process_handler_t>())).on_uncaught_exception
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_render_process_handler_t, on_uncaught_exception),
         96usize,
         concat!(
             "Offset of field: ",
@@ -17325,10 +16249,7 @@ fn bindgen_test_layout__cef_render_process_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_render_process_handler_t>())).on_focused_node_changed
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_render_process_handler_t, on_focused_node_changed),
         104usize,
         concat!(
             "Offset of field: ",
@@ -17338,10 +16259,7 @@ fn bindgen_test_layout__cef_render_process_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_render_process_handler_t>())).on_process_message_received
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_render_process_handler_t, on_process_message_received),
         112usize,
         concat!(
             "Offset of field: ",
@@ -17394,9 +16312,7 @@ fn bindgen_test_layout__cef_resource_bundle_handler_t() {
         concat!("Alignment of ", stringify!(_cef_resource_bundle_handler_t))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_resource_bundle_handler_t>())).base as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_resource_bundle_handler_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -17406,10 +16322,7 @@ fn bindgen_test_layout__cef_resource_bundle_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_resource_bundle_handler_t>())).get_localized_string
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_resource_bundle_handler_t, get_localized_string),
         40usize,
         concat!(
             "Offset of field: ",
@@ -17419,10 +16332,7 @@ fn bindgen_test_layout__cef_resource_bundle_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_resource_bundle_handler_t>())).get_data_resource as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_resource_bundle_handler_t, get_data_resource),
         48usize,
         concat!(
             "Offset of field: ",
@@ -17432,10 +16342,7 @@ fn bindgen_test_layout__cef_resource_bundle_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_resource_bundle_handler_t>())).get_data_resource_for_scale
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_resource_bundle_handler_t, get_data_resource_for_scale),
         56usize,
         concat!(
             "Offset of field: ",
@@ -17523,7 +16430,7 @@ fn bindgen_test_layout__cef_response_t() {
         concat!("Alignment of ", stringify!(_cef_response_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_response_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_response_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -17533,7 +16440,7 @@ fn bindgen_test_layout__cef_response_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_response_t>())).is_read_only as *const _ as usize },
+        ::std::mem::offset_of!(_cef_response_t, is_read_only),
         40usize,
         concat!(
             "Offset of field: ",
@@ -17543,7 +16450,7 @@ fn bindgen_test_layout__cef_response_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_response_t>())).get_error as *const _ as usize },
+        ::std::mem::offset_of!(_cef_response_t, get_error),
         48usize,
         concat!(
             "Offset of field: ",
@@ -17553,7 +16460,7 @@ fn bindgen_test_layout__cef_response_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_response_t>())).set_error as *const _ as usize },
+        ::std::mem::offset_of!(_cef_response_t, set_error),
         56usize,
         concat!(
             "Offset of field: ",
@@ -17563,7 +16470,7 @@ fn bindgen_test_layout__cef_response_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_response_t>())).get_status as *const _ as usize },
+        ::std::mem::offset_of!(_cef_response_t, get_status),
         64usize,
         concat!(
             "Offset of field: ",
@@ -17573,7 +16480,7 @@ fn bindgen_test_layout__cef_response_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_response_t>())).set_status as *const _ as usize },
+        ::std::mem::offset_of!(_cef_response_t, set_status),
         72usize,
         concat!(
             "Offset of field: ",
@@ -17583,7 +16490,7 @@ fn bindgen_test_layout__cef_response_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_response_t>())).get_status_text as *const _ as usize },
+        ::std::mem::offset_of!(_cef_response_t, get_status_text),
         80usize,
         concat!(
             "Offset of field: ",
@@ -17593,7 +16500,7 @@ fn bindgen_test_layout__cef_response_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_response_t>())).set_status_text as *const _ as usize },
+        ::std::mem::offset_of!(_cef_response_t, set_status_text),
         88usize,
         concat!(
             "Offset of field: ",
@@ -17603,7 +16510,7 @@ fn bindgen_test_layout__cef_response_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_response_t>())).get_mime_type as *const _ as usize },
+        ::std::mem::offset_of!(_cef_response_t, get_mime_type),
         96usize,
         concat!(
             "Offset of field: ",
@@ -17613,7 +16520,7 @@ fn bindgen_test_layout__cef_response_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_response_t>())).set_mime_type as *const _ as usize },
+        ::std::mem::offset_of!(_cef_response_t, set_mime_type),
         104usize,
         concat!(
             "Offset of field: ",
@@ -17623,7 +16530,7 @@ fn bindgen_test_layout__cef_response_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_response_t>())).get_charset as *const _ as usize },
+        ::std::mem::offset_of!(_cef_response_t, get_charset),
         112usize,
         concat!(
             "Offset of field: ",
@@ -17633,7 +16540,7 @@ fn bindgen_test_layout__cef_response_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_response_t>())).set_charset as *const _ as usize },
+        ::std::mem::offset_of!(_cef_response_t, set_charset),
         120usize,
         concat!(
             "Offset of field: ",
@@ -17643,9 +16550,7 @@ fn bindgen_test_layout__cef_response_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_response_t>())).get_header_by_name as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_response_t, get_header_by_name),
         128usize,
         concat!(
             "Offset of field: ",
@@ -17655,9 +16560,7 @@ fn bindgen_test_layout__cef_response_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_response_t>())).set_header_by_name as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_response_t, set_header_by_name),
         136usize,
         concat!(
             "Offset of field: ",
@@ -17667,7 +16570,7 @@ fn bindgen_test_layout__cef_response_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_response_t>())).get_header_map as *const _ as usize },
+        ::std::mem::offset_of!(_cef_response_t, get_header_map),
         144usize,
         concat!(
             "Offset of field: ",
@@ -17677,7 +16580,7 @@ fn bindgen_test_layout__cef_response_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_response_t>())).set_header_map as *const _ as usize },
+        ::std::mem::offset_of!(_cef_response_t, set_header_map),
         152usize,
         concat!(
             "Offset of field: ",
@@ -17687,7 +16590,7 @@ fn bindgen_test_layout__cef_response_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_response_t>())).get_url as *const _ as usize },
+        ::std::mem::offset_of!(_cef_response_t, get_url),
         160usize,
         concat!(
             "Offset of field: ",
@@ -17697,7 +16600,7 @@ fn bindgen_test_layout__cef_response_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_response_t>())).set_url as *const _ as usize },
+        ::std::mem::offset_of!(_cef_response_t, set_url),
         168usize,
         concat!(
             "Offset of field: ",
@@ -17732,9 +16635,7 @@ fn bindgen_test_layout__cef_resource_skip_callback_t() {
         concat!("Alignment of ", stringify!(_cef_resource_skip_callback_t))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_resource_skip_callback_t>())).base as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_resource_skip_callback_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -17744,9 +16645,7 @@ fn bindgen_test_layout__cef_resource_skip_callback_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_resource_skip_callback_t>())).cont as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_resource_skip_callback_t, cont),
         40usize,
         concat!(
             "Offset of field: ",
@@ -17781,9 +16680,7 @@ fn bindgen_test_layout__cef_resource_read_callback_t() {
         concat!("Alignment of ", stringify!(_cef_resource_read_callback_t))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_resource_read_callback_t>())).base as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_resource_read_callback_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -17793,9 +16690,7 @@ fn bindgen_test_layout__cef_resource_read_callback_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_resource_read_callback_t>())).cont as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_resource_read_callback_t, cont),
         40usize,
         concat!(
             "Offset of field: ",
@@ -17874,7 +16769,7 @@ fn bindgen_test_layout__cef_resource_handler_t() {
         concat!("Alignment of ", stringify!(_cef_resource_handler_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_resource_handler_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_resource_handler_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -17884,7 +16779,7 @@ fn bindgen_test_layout__cef_resource_handler_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_resource_handler_t>())).open as *const _ as usize },
+        ::std::mem::offset_of!(_cef_resource_handler_t, open),
         40usize,
         concat!(
             "Offset of field: ",
@@ -17894,9 +16789,7 @@ fn bindgen_test_layout__cef_resource_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_resource_handler_t>())).process_request as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_resource_handler_t, process_request),
         48usize,
         concat!(
             "Offset of field: ",
@@ -17906,10 +16799,7 @@ fn bindgen_test_layout__cef_resource_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_resource_handler_t>())).get_response_headers as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_resource_handler_t, get_response_headers),
         56usize,
         concat!(
             "Offset of field: ",
@@ -17919,7 +16809,7 @@ fn bindgen_test_layout__cef_resource_handler_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_resource_handler_t>())).skip as *const _ as usize },
+        ::std::mem::offset_of!(_cef_resource_handler_t, skip),
         64usize,
         concat!(
             "Offset of field: ",
@@ -17929,7 +16819,7 @@ fn bindgen_test_layout__cef_resource_handler_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_resource_handler_t>())).read as *const _ as usize },
+        ::std::mem::offset_of!(_cef_resource_handler_t, read),
         72usize,
         concat!(
             "Offset of field: ",
@@ -17939,9 +16829,7 @@ fn bindgen_test_layout__cef_resource_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_resource_handler_t>())).read_response as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_resource_handler_t, read_response),
         80usize,
         concat!(
             "Offset of field: ",
@@ -17951,7 +16839,7 @@ fn bindgen_test_layout__cef_resource_handler_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_resource_handler_t>())).cancel as *const _ as usize },
+        ::std::mem::offset_of!(_cef_resource_handler_t, cancel),
         88usize,
         concat!(
             "Offset of field: ",
@@ -17987,7 +16875,7 @@ fn bindgen_test_layout__cef_scheme_registrar_t() {
         concat!("Alignment of ", stringify!(_cef_scheme_registrar_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_scheme_registrar_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_scheme_registrar_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -17997,10 +16885,7 @@ fn bindgen_test_layout__cef_scheme_registrar_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_scheme_registrar_t>())).add_custom_scheme as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_scheme_registrar_t, add_custom_scheme),
         16usize,
         concat!(
             "Offset of field: ",
@@ -18038,9 +16923,7 @@ fn bindgen_test_layout__cef_scheme_handler_factory_t() {
         concat!("Alignment of ", stringify!(_cef_scheme_handler_factory_t))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_scheme_handler_factory_t>())).base as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_scheme_handler_factory_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -18050,9 +16933,7 @@ fn bindgen_test_layout__cef_scheme_handler_factory_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_scheme_handler_factory_t>())).create as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_scheme_handler_factory_t, create),
         40usize,
         concat!(
             "Offset of field: ",
@@ -18110,7 +16991,7 @@ fn bindgen_test_layout__cef_app_t() {
         concat!("Alignment of ", stringify!(_cef_app_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_app_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_app_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -18120,10 +17001,7 @@ fn bindgen_test_layout__cef_app_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_app_t>())).on_before_command_line_processing as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_app_t, on_before_command_line_processing),
         40usize,
         concat!(
             "Offset of field: ",
@@ -18133,9 +17011,7 @@ fn bindgen_test_layout__cef_app_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_app_t>())).on_register_custom_schemes as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_app_t, on_register_custom_schemes),
         48usize,
         concat!(
             "Offset of field: ",
@@ -18145,9 +17021,7 @@ fn bindgen_test_layout__cef_app_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_app_t>())).get_resource_bundle_handler as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_app_t, get_resource_bundle_handler),
         56usize,
         concat!(
             "Offset of field: ",
@@ -18157,9 +17031,7 @@ fn bindgen_test_layout__cef_app_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_app_t>())).get_browser_process_handler as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_app_t, get_browser_process_handler),
         64usize,
         concat!(
             "Offset of field: ",
@@ -18169,9 +17041,7 @@ fn bindgen_test_layout__cef_app_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_app_t>())).get_render_process_handler as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_app_t, get_render_process_handler),
         72usize,
         concat!(
             "Offset of field: ",
@@ -18241,7 +17111,7 @@ fn bindgen_test_layout__cef_auth_callback_t() {
         concat!("Alignment of ", stringify!(_cef_auth_callback_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_auth_callback_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_auth_callback_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -18251,7 +17121,7 @@ fn bindgen_test_layout__cef_auth_callback_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_auth_callback_t>())).cont as *const _ as usize },
+        ::std::mem::offset_of!(_cef_auth_callback_t, cont),
         40usize,
         concat!(
             "Offset of field: ",
@@ -18261,7 +17131,7 @@ fn bindgen_test_layout__cef_auth_callback_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_auth_callback_t>())).cancel as *const _ as usize },
+        ::std::mem::offset_of!(_cef_auth_callback_t, cancel),
         48usize,
         concat!(
             "Offset of field: ",
@@ -18324,7 +17194,7 @@ fn bindgen_test_layout__cef_audio_handler_t() {
         concat!("Alignment of ", stringify!(_cef_audio_handler_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_audio_handler_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_audio_handler_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -18334,10 +17204,7 @@ fn bindgen_test_layout__cef_audio_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_audio_handler_t>())).get_audio_parameters as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_audio_handler_t, get_audio_parameters),
         40usize,
         concat!(
             "Offset of field: ",
@@ -18347,10 +17214,7 @@ fn bindgen_test_layout__cef_audio_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_audio_handler_t>())).on_audio_stream_started as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_audio_handler_t, on_audio_stream_started),
         48usize,
         concat!(
             "Offset of field: ",
@@ -18360,10 +17224,7 @@ fn bindgen_test_layout__cef_audio_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_audio_handler_t>())).on_audio_stream_packet as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_audio_handler_t, on_audio_stream_packet),
         56usize,
         concat!(
             "Offset of field: ",
@@ -18373,10 +17234,7 @@ fn bindgen_test_layout__cef_audio_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_audio_handler_t>())).on_audio_stream_stopped as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_audio_handler_t, on_audio_stream_stopped),
         64usize,
         concat!(
             "Offset of field: ",
@@ -18386,10 +17244,7 @@ fn bindgen_test_layout__cef_audio_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_audio_handler_t>())).on_audio_stream_error as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_audio_handler_t, on_audio_stream_error),
         72usize,
         concat!(
             "Offset of field: ",
@@ -18466,7 +17321,7 @@ fn bindgen_test_layout__cef_menu_model_delegate_t() {
         concat!("Alignment of ", stringify!(_cef_menu_model_delegate_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_menu_model_delegate_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_menu_model_delegate_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -18476,10 +17331,7 @@ fn bindgen_test_layout__cef_menu_model_delegate_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_menu_model_delegate_t>())).execute_command as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_menu_model_delegate_t, execute_command),
         40usize,
         concat!(
             "Offset of field: ",
@@ -18489,10 +17341,7 @@ fn bindgen_test_layout__cef_menu_model_delegate_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_menu_model_delegate_t>())).mouse_outside_menu as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_menu_model_delegate_t, mouse_outside_menu),
         48usize,
         concat!(
             "Offset of field: ",
@@ -18502,10 +17351,7 @@ fn bindgen_test_layout__cef_menu_model_delegate_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_menu_model_delegate_t>())).unhandled_open_submenu
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_menu_model_delegate_t, unhandled_open_submenu),
         56usize,
         concat!(
             "Offset of field: ",
@@ -18515,10 +17361,7 @@ fn bindgen_test_layout__cef_menu_model_delegate_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_menu_model_delegate_t>())).unhandled_close_submenu
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_menu_model_delegate_t, unhandled_close_submenu),
         64usize,
         concat!(
             "Offset of field: ",
@@ -18528,10 +17371,7 @@ fn bindgen_test_layout__cef_menu_model_delegate_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_menu_model_delegate_t>())).menu_will_show as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_menu_model_delegate_t, menu_will_show),
         72usize,
         concat!(
             "Offset of field: ",
@@ -18541,9 +17381,7 @@ fn bindgen_test_layout__cef_menu_model_delegate_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_menu_model_delegate_t>())).menu_closed as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_menu_model_delegate_t, menu_closed),
         80usize,
         concat!(
             "Offset of field: ",
@@ -18553,9 +17391,7 @@ fn bindgen_test_layout__cef_menu_model_delegate_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_menu_model_delegate_t>())).format_label as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_menu_model_delegate_t, format_label),
         88usize,
         concat!(
             "Offset of field: ",
@@ -18959,7 +17795,7 @@ fn bindgen_test_layout__cef_menu_model_t() {
         concat!("Alignment of ", stringify!(_cef_menu_model_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_menu_model_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_menu_model_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -18969,7 +17805,7 @@ fn bindgen_test_layout__cef_menu_model_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_menu_model_t>())).is_sub_menu as *const _ as usize },
+        ::std::mem::offset_of!(_cef_menu_model_t, is_sub_menu),
         40usize,
         concat!(
             "Offset of field: ",
@@ -18979,7 +17815,7 @@ fn bindgen_test_layout__cef_menu_model_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_menu_model_t>())).clear as *const _ as usize },
+        ::std::mem::offset_of!(_cef_menu_model_t, clear),
         48usize,
         concat!(
             "Offset of field: ",
@@ -18989,7 +17825,7 @@ fn bindgen_test_layout__cef_menu_model_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_menu_model_t>())).get_count as *const _ as usize },
+        ::std::mem::offset_of!(_cef_menu_model_t, get_count),
         56usize,
         concat!(
             "Offset of field: ",
@@ -18999,7 +17835,7 @@ fn bindgen_test_layout__cef_menu_model_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_menu_model_t>())).add_separator as *const _ as usize },
+        ::std::mem::offset_of!(_cef_menu_model_t, add_separator),
         64usize,
         concat!(
             "Offset of field: ",
@@ -19009,7 +17845,7 @@ fn bindgen_test_layout__cef_menu_model_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_menu_model_t>())).add_item as *const _ as usize },
+        ::std::mem::offset_of!(_cef_menu_model_t, add_item),
         72usize,
         concat!(
             "Offset of field: ",
@@ -19019,9 +17855,7 @@ fn bindgen_test_layout__cef_menu_model_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_menu_model_t>())).add_check_item as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_menu_model_t, add_check_item),
         80usize,
         concat!(
             "Offset of field: ",
@@ -19031,9 +17865,7 @@ fn bindgen_test_layout__cef_menu_model_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_menu_model_t>())).add_radio_item as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_menu_model_t, add_radio_item),
         88usize,
         concat!(
             "Offset of field: ",
@@ -19043,7 +17875,7 @@ fn bindgen_test_layout__cef_menu_model_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_menu_model_t>())).add_sub_menu as *const _ as usize },
+        ::std::mem::offset_of!(_cef_menu_model_t, add_sub_menu),
         96usize,
         concat!(
             "Offset of field: ",
@@ -19053,9 +17885,7 @@ fn bindgen_test_layout__cef_menu_model_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_menu_model_t>())).insert_separator_at as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_menu_model_t, insert_separator_at),
         104usize,
         concat!(
             "Offset of field: ",
@@ -19065,9 +17895,7 @@ fn bindgen_test_layout__cef_menu_model_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_menu_model_t>())).insert_item_at as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_menu_model_t, insert_item_at),
         112usize,
         concat!(
             "Offset of field: ",
@@ -19077,9 +17905,7 @@ fn bindgen_test_layout__cef_menu_model_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_menu_model_t>())).insert_check_item_at as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_menu_model_t, insert_check_item_at),
         120usize,
         concat!(
             "Offset of field: ",
@@ -19089,9 +17915,7 @@ fn bindgen_test_layout__cef_menu_model_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_menu_model_t>())).insert_radio_item_at as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_menu_model_t, insert_radio_item_at),
         128usize,
         concat!(
             "Offset of field: ",
@@ -19101,9 +17925,7 @@ fn bindgen_test_layout__cef_menu_model_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_menu_model_t>())).insert_sub_menu_at as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_menu_model_t, insert_sub_menu_at),
         136usize,
         concat!(
             "Offset of field: ",
@@ -19113,7 +17935,7 @@ fn bindgen_test_layout__cef_menu_model_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_menu_model_t>())).remove as *const _ as usize },
+        ::std::mem::offset_of!(_cef_menu_model_t, remove),
         144usize,
         concat!(
             "Offset of field: ",
@@ -19123,7 +17945,7 @@ fn bindgen_test_layout__cef_menu_model_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_menu_model_t>())).remove_at as *const _ as usize },
+        ::std::mem::offset_of!(_cef_menu_model_t, remove_at),
         152usize,
         concat!(
             "Offset of field: ",
@@ -19133,7 +17955,7 @@ fn bindgen_test_layout__cef_menu_model_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_menu_model_t>())).get_index_of as *const _ as usize },
+        ::std::mem::offset_of!(_cef_menu_model_t, get_index_of),
         160usize,
         concat!(
             "Offset of field: ",
@@ -19143,9 +17965,7 @@ fn bindgen_test_layout__cef_menu_model_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_menu_model_t>())).get_command_id_at as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_menu_model_t, get_command_id_at),
         168usize,
         concat!(
             "Offset of field: ",
@@ -19155,9 +17975,7 @@ fn bindgen_test_layout__cef_menu_model_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_menu_model_t>())).set_command_id_at as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_menu_model_t, set_command_id_at),
         176usize,
         concat!(
             "Offset of field: ",
@@ -19167,7 +17985,7 @@ fn bindgen_test_layout__cef_menu_model_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_menu_model_t>())).get_label as *const _ as usize },
+        ::std::mem::offset_of!(_cef_menu_model_t, get_label),
         184usize,
         concat!(
             "Offset of field: ",
@@ -19177,7 +17995,7 @@ fn bindgen_test_layout__cef_menu_model_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_menu_model_t>())).get_label_at as *const _ as usize },
+        ::std::mem::offset_of!(_cef_menu_model_t, get_label_at),
         192usize,
         concat!(
             "Offset of field: ",
@@ -19187,7 +18005,7 @@ fn bindgen_test_layout__cef_menu_model_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_menu_model_t>())).set_label as *const _ as usize },
+        ::std::mem::offset_of!(_cef_menu_model_t, set_label),
         200usize,
         concat!(
             "Offset of field: ",
@@ -19197,7 +18015,7 @@ fn bindgen_test_layout__cef_menu_model_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_menu_model_t>())).set_label_at as *const _ as usize },
+        ::std::mem::offset_of!(_cef_menu_model_t, set_label_at),
         208usize,
         concat!(
             "Offset of field: ",
@@ -19207,7 +18025,7 @@ fn bindgen_test_layout__cef_menu_model_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_menu_model_t>())).get_type as *const _ as usize },
+        ::std::mem::offset_of!(_cef_menu_model_t, get_type),
         216usize,
         concat!(
             "Offset of field: ",
@@ -19217,7 +18035,7 @@ fn bindgen_test_layout__cef_menu_model_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_menu_model_t>())).get_type_at as *const _ as usize },
+        ::std::mem::offset_of!(_cef_menu_model_t, get_type_at),
         224usize,
         concat!(
             "Offset of field: ",
@@ -19227,7 +18045,7 @@ fn bindgen_test_layout__cef_menu_model_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_menu_model_t>())).get_group_id as *const _ as usize },
+        ::std::mem::offset_of!(_cef_menu_model_t, get_group_id),
         232usize,
         concat!(
             "Offset of field: ",
@@ -19237,9 +18055,7 @@ fn bindgen_test_layout__cef_menu_model_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_menu_model_t>())).get_group_id_at as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_menu_model_t, get_group_id_at),
         240usize,
         concat!(
             "Offset of field: ",
@@ -19249,7 +18065,7 @@ fn bindgen_test_layout__cef_menu_model_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_menu_model_t>())).set_group_id as *const _ as usize },
+        ::std::mem::offset_of!(_cef_menu_model_t, set_group_id),
         248usize,
         concat!(
             "Offset of field: ",
@@ -19259,9 +18075,7 @@ fn bindgen_test_layout__cef_menu_model_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_menu_model_t>())).set_group_id_at as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_menu_model_t, set_group_id_at),
         256usize,
         concat!(
             "Offset of field: ",
@@ -19271,7 +18085,7 @@ fn bindgen_test_layout__cef_menu_model_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_menu_model_t>())).get_sub_menu as *const _ as usize },
+        ::std::mem::offset_of!(_cef_menu_model_t, get_sub_menu),
         264usize,
         concat!(
             "Offset of field: ",
@@ -19281,9 +18095,7 @@ fn bindgen_test_layout__cef_menu_model_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_menu_model_t>())).get_sub_menu_at as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_menu_model_t, get_sub_menu_at),
         272usize,
         concat!(
             "Offset of field: ",
@@ -19293,7 +18105,7 @@ fn bindgen_test_layout__cef_menu_model_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_menu_model_t>())).is_visible as *const _ as usize },
+        ::std::mem::offset_of!(_cef_menu_model_t, is_visible),
         280usize,
         concat!(
             "Offset of field: ",
@@ -19303,7 +18115,7 @@ fn bindgen_test_layout__cef_menu_model_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_menu_model_t>())).is_visible_at as *const _ as usize },
+        ::std::mem::offset_of!(_cef_menu_model_t, is_visible_at),
         288usize,
         concat!(
             "Offset of field: ",
@@ -19313,7 +18125,7 @@ fn bindgen_test_layout__cef_menu_model_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_menu_model_t>())).set_visible as *const _ as usize },
+        ::std::mem::offset_of!(_cef_menu_model_t, set_visible),
         296usize,
         concat!(
             "Offset of field: ",
@@ -19323,9 +18135,7 @@ fn bindgen_test_layout__cef_menu_model_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_menu_model_t>())).set_visible_at as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_menu_model_t, set_visible_at),
         304usize,
         concat!(
             "Offset of field: ",
@@ -19335,7 +18145,7 @@ fn bindgen_test_layout__cef_menu_model_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_menu_model_t>())).is_enabled as *const _ as usize },
+        ::std::mem::offset_of!(_cef_menu_model_t, is_enabled),
         312usize,
         concat!(
             "Offset of field: ",
@@ -19345,7 +18155,7 @@ fn bindgen_test_layout__cef_menu_model_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_menu_model_t>())).is_enabled_at as *const _ as usize },
+        ::std::mem::offset_of!(_cef_menu_model_t, is_enabled_at),
         320usize,
         concat!(
             "Offset of field: ",
@@ -19355,7 +18165,7 @@ fn bindgen_test_layout__cef_menu_model_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_menu_model_t>())).set_enabled as *const _ as usize },
+        ::std::mem::offset_of!(_cef_menu_model_t, set_enabled),
         328usize,
         concat!(
             "Offset of field: ",
@@ -19365,9 +18175,7 @@ fn bindgen_test_layout__cef_menu_model_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_menu_model_t>())).set_enabled_at as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_menu_model_t, set_enabled_at),
         336usize,
         concat!(
             "Offset of field: ",
@@ -19377,7 +18185,7 @@ fn bindgen_test_layout__cef_menu_model_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_menu_model_t>())).is_checked as *const _ as usize },
+        ::std::mem::offset_of!(_cef_menu_model_t, is_checked),
         344usize,
         concat!(
             "Offset of field: ",
@@ -19387,7 +18195,7 @@ fn bindgen_test_layout__cef_menu_model_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_menu_model_t>())).is_checked_at as *const _ as usize },
+        ::std::mem::offset_of!(_cef_menu_model_t, is_checked_at),
         352usize,
         concat!(
             "Offset of field: ",
@@ -19397,7 +18205,7 @@ fn bindgen_test_layout__cef_menu_model_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_menu_model_t>())).set_checked as *const _ as usize },
+        ::std::mem::offset_of!(_cef_menu_model_t, set_checked),
         360usize,
         concat!(
             "Offset of field: ",
@@ -19407,9 +18215,7 @@ fn bindgen_test_layout__cef_menu_model_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_menu_model_t>())).set_checked_at as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_menu_model_t, set_checked_at),
         368usize,
         concat!(
             "Offset of field: ",
@@ -19419,9 +18225,7 @@ fn bindgen_test_layout__cef_menu_model_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_menu_model_t>())).has_accelerator as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_menu_model_t, has_accelerator),
         376usize,
         concat!(
             "Offset of field: ",
@@ -19431,9 +18235,7 @@ fn bindgen_test_layout__cef_menu_model_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_menu_model_t>())).has_accelerator_at as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_menu_model_t, has_accelerator_at),
         384usize,
         concat!(
             "Offset of field: ",
@@ -19443,9 +18245,7 @@ fn bindgen_test_layout__cef_menu_model_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_menu_model_t>())).set_accelerator as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_menu_model_t, set_accelerator),
         392usize,
         concat!(
             "Offset of field: ",
@@ -19455,9 +18255,7 @@ fn bindgen_test_layout__cef_menu_model_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_menu_model_t>())).set_accelerator_at as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_menu_model_t, set_accelerator_at),
         400usize,
         concat!(
             "Offset of field: ",
@@ -19467,9 +18265,7 @@ fn bindgen_test_layout__cef_menu_model_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_menu_model_t>())).remove_accelerator as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_menu_model_t, remove_accelerator),
         408usize,
         concat!(
             "Offset of field: ",
@@ -19479,9 +18275,7 @@ fn bindgen_test_layout__cef_menu_model_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_menu_model_t>())).remove_accelerator_at as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_menu_model_t, remove_accelerator_at),
         416usize,
         concat!(
             "Offset of field: ",
@@ -19491,9 +18285,7 @@ fn bindgen_test_layout__cef_menu_model_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_menu_model_t>())).get_accelerator as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_menu_model_t, get_accelerator),
         424usize,
         concat!(
             "Offset of field: ",
@@ -19503,9 +18295,7 @@ fn bindgen_test_layout__cef_menu_model_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_menu_model_t>())).get_accelerator_at as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_menu_model_t, get_accelerator_at),
         432usize,
         concat!(
             "Offset of field: ",
@@ -19515,7 +18305,7 @@ fn bindgen_test_layout__cef_menu_model_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_menu_model_t>())).set_color as *const _ as usize },
+        ::std::mem::offset_of!(_cef_menu_model_t, set_color),
         440usize,
         concat!(
             "Offset of field: ",
@@ -19525,7 +18315,7 @@ fn bindgen_test_layout__cef_menu_model_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_menu_model_t>())).set_color_at as *const _ as usize },
+        ::std::mem::offset_of!(_cef_menu_model_t, set_color_at),
         448usize,
         concat!(
             "Offset of field: ",
@@ -19535,7 +18325,7 @@ fn bindgen_test_layout__cef_menu_model_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_menu_model_t>())).get_color as *const _ as usize },
+        ::std::mem::offset_of!(_cef_menu_model_t, get_color),
         456usize,
         concat!(
             "Offset of field: ",
@@ -19545,7 +18335,7 @@ fn bindgen_test_layout__cef_menu_model_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_menu_model_t>())).get_color_at as *const _ as usize },
+        ::std::mem::offset_of!(_cef_menu_model_t, get_color_at),
         464usize,
         concat!(
             "Offset of field: ",
@@ -19555,7 +18345,7 @@ fn bindgen_test_layout__cef_menu_model_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_menu_model_t>())).set_font_list as *const _ as usize },
+        ::std::mem::offset_of!(_cef_menu_model_t, set_font_list),
         472usize,
         concat!(
             "Offset of field: ",
@@ -19565,9 +18355,7 @@ fn bindgen_test_layout__cef_menu_model_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_menu_model_t>())).set_font_list_at as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_menu_model_t, set_font_list_at),
         480usize,
         concat!(
             "Offset of field: ",
@@ -19613,9 +18401,7 @@ fn bindgen_test_layout__cef_run_context_menu_callback_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_run_context_menu_callback_t>())).base as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_run_context_menu_callback_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -19625,9 +18411,7 @@ fn bindgen_test_layout__cef_run_context_menu_callback_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_run_context_menu_callback_t>())).cont as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_run_context_menu_callback_t, cont),
         40usize,
         concat!(
             "Offset of field: ",
@@ -19637,9 +18421,7 @@ fn bindgen_test_layout__cef_run_context_menu_callback_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_run_context_menu_callback_t>())).cancel as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_run_context_menu_callback_t, cancel),
         48usize,
         concat!(
             "Offset of field: ",
@@ -19704,9 +18486,7 @@ fn bindgen_test_layout__cef_context_menu_handler_t() {
         concat!("Alignment of ", stringify!(_cef_context_menu_handler_t))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_context_menu_handler_t>())).base as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_context_menu_handler_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -19716,10 +18496,7 @@ fn bindgen_test_layout__cef_context_menu_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_context_menu_handler_t>())).on_before_context_menu
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_context_menu_handler_t, on_before_context_menu),
         40usize,
         concat!(
             "Offset of field: ",
@@ -19729,10 +18506,7 @@ fn bindgen_test_layout__cef_context_menu_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_context_menu_handler_t>())).run_context_menu as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_context_menu_handler_t, run_context_menu),
         48usize,
         concat!(
             "Offset of field: ",
@@ -19742,10 +18516,7 @@ fn bindgen_test_layout__cef_context_menu_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_context_menu_handler_t>())).on_context_menu_command
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_context_menu_handler_t, on_context_menu_command),
         56usize,
         concat!(
             "Offset of field: ",
@@ -19755,10 +18526,7 @@ fn bindgen_test_layout__cef_context_menu_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_context_menu_handler_t>())).on_context_menu_dismissed
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_context_menu_handler_t, on_context_menu_dismissed),
         64usize,
         concat!(
             "Offset of field: ",
@@ -19861,7 +18629,7 @@ fn bindgen_test_layout__cef_context_menu_params_t() {
         concat!("Alignment of ", stringify!(_cef_context_menu_params_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_context_menu_params_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_context_menu_params_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -19871,9 +18639,7 @@ fn bindgen_test_layout__cef_context_menu_params_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_context_menu_params_t>())).get_xcoord as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_context_menu_params_t, get_xcoord),
         40usize,
         concat!(
             "Offset of field: ",
@@ -19883,9 +18649,7 @@ fn bindgen_test_layout__cef_context_menu_params_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_context_menu_params_t>())).get_ycoord as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_context_menu_params_t, get_ycoord),
         48usize,
         concat!(
             "Offset of field: ",
@@ -19895,10 +18659,7 @@ fn bindgen_test_layout__cef_context_menu_params_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_context_menu_params_t>())).get_type_flags as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_context_menu_params_t, get_type_flags),
         56usize,
         concat!(
             "Offset of field: ",
@@ -19908,9 +18669,7 @@ fn bindgen_test_layout__cef_context_menu_params_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_context_menu_params_t>())).get_link_url as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_context_menu_params_t, get_link_url),
         64usize,
         concat!(
             "Offset of field: ",
@@ -19920,10 +18679,7 @@ fn bindgen_test_layout__cef_context_menu_params_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_context_menu_params_t>())).get_unfiltered_link_url
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_context_menu_params_t, get_unfiltered_link_url),
         72usize,
         concat!(
             "Offset of field: ",
@@ -19933,10 +18689,7 @@ fn bindgen_test_layout__cef_context_menu_params_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_context_menu_params_t>())).get_source_url as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_context_menu_params_t, get_source_url),
         80usize,
         concat!(
             "Offset of field: ",
@@ -19946,10 +18699,7 @@ fn bindgen_test_layout__cef_context_menu_params_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_context_menu_params_t>())).has_image_contents as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_context_menu_params_t, has_image_contents),
         88usize,
         concat!(
             "Offset of field: ",
@@ -19959,10 +18709,7 @@ fn bindgen_test_layout__cef_context_menu_params_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_context_menu_params_t>())).get_title_text as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_context_menu_params_t, get_title_text),
         96usize,
         concat!(
             "Offset of field: ",
@@ -19972,9 +18719,7 @@ fn bindgen_test_layout__cef_context_menu_params_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_context_menu_params_t>())).get_page_url as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_context_menu_params_t, get_page_url),
         104usize,
         concat!(
             "Offset of field: ",
@@ -19984,10 +18729,7 @@ fn bindgen_test_layout__cef_context_menu_params_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_context_menu_params_t>())).get_frame_url as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_context_menu_params_t, get_frame_url),
         112usize,
         concat!(
             "Offset of field: ",
@@ -19997,10 +18739,7 @@ fn bindgen_test_layout__cef_context_menu_params_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_context_menu_params_t>())).get_frame_charset as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_context_menu_params_t, get_frame_charset),
         120usize,
         concat!(
             "Offset of field: ",
@@ -20010,10 +18749,7 @@ fn bindgen_test_layout__cef_context_menu_params_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_context_menu_params_t>())).get_media_type as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_context_menu_params_t, get_media_type),
         128usize,
         concat!(
             "Offset of field: ",
@@ -20023,10 +18759,7 @@ fn bindgen_test_layout__cef_context_menu_params_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_context_menu_params_t>())).get_media_state_flags as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_context_menu_params_t, get_media_state_flags),
         136usize,
         concat!(
             "Offset of field: ",
@@ -20036,10 +18769,7 @@ fn bindgen_test_layout__cef_context_menu_params_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_context_menu_params_t>())).get_selection_text as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_context_menu_params_t, get_selection_text),
         144usize,
         concat!(
             "Offset of field: ",
@@ -20049,10 +18779,7 @@ fn bindgen_test_layout__cef_context_menu_params_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_context_menu_params_t>())).get_misspelled_word as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_context_menu_params_t, get_misspelled_word),
         152usize,
         concat!(
             "Offset of field: ",
@@ -20062,10 +18789,7 @@ fn bindgen_test_layout__cef_context_menu_params_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_context_menu_params_t>())).get_dictionary_suggestions
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_context_menu_params_t, get_dictionary_suggestions),
         160usize,
         concat!(
             "Offset of field: ",
@@ -20075,9 +18799,7 @@ fn bindgen_test_layout__cef_context_menu_params_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_context_menu_params_t>())).is_editable as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_context_menu_params_t, is_editable),
         168usize,
         concat!(
             "Offset of field: ",
@@ -20087,10 +18809,7 @@ fn bindgen_test_layout__cef_context_menu_params_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_context_menu_params_t>())).is_spell_check_enabled
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_context_menu_params_t, is_spell_check_enabled),
         176usize,
         concat!(
             "Offset of field: ",
@@ -20100,10 +18819,7 @@ fn bindgen_test_layout__cef_context_menu_params_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_context_menu_params_t>())).get_edit_state_flags as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_context_menu_params_t, get_edit_state_flags),
         184usize,
         concat!(
             "Offset of field: ",
@@ -20113,10 +18829,7 @@ fn bindgen_test_layout__cef_context_menu_params_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_context_menu_params_t>())).is_custom_menu as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_context_menu_params_t, is_custom_menu),
         192usize,
         concat!(
             "Offset of field: ",
@@ -20126,10 +18839,7 @@ fn bindgen_test_layout__cef_context_menu_params_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_context_menu_params_t>())).is_pepper_menu as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_context_menu_params_t, is_pepper_menu),
         200usize,
         concat!(
             "Offset of field: ",
@@ -20167,9 +18877,7 @@ fn bindgen_test_layout__cef_file_dialog_callback_t() {
         concat!("Alignment of ", stringify!(_cef_file_dialog_callback_t))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_file_dialog_callback_t>())).base as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_file_dialog_callback_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -20179,9 +18887,7 @@ fn bindgen_test_layout__cef_file_dialog_callback_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_file_dialog_callback_t>())).cont as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_file_dialog_callback_t, cont),
         40usize,
         concat!(
             "Offset of field: ",
@@ -20191,9 +18897,7 @@ fn bindgen_test_layout__cef_file_dialog_callback_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_file_dialog_callback_t>())).cancel as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_file_dialog_callback_t, cancel),
         48usize,
         concat!(
             "Offset of field: ",
@@ -20234,7 +18938,7 @@ fn bindgen_test_layout__cef_dialog_handler_t() {
         concat!("Alignment of ", stringify!(_cef_dialog_handler_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_dialog_handler_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_dialog_handler_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -20244,9 +18948,7 @@ fn bindgen_test_layout__cef_dialog_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_dialog_handler_t>())).on_file_dialog as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_dialog_handler_t, on_file_dialog),
         40usize,
         concat!(
             "Offset of field: ",
@@ -20342,7 +19044,7 @@ fn bindgen_test_layout__cef_display_handler_t() {
         concat!("Alignment of ", stringify!(_cef_display_handler_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_display_handler_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_display_handler_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -20352,10 +19054,7 @@ fn bindgen_test_layout__cef_display_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_display_handler_t>())).on_address_change as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_display_handler_t, on_address_change),
         40usize,
         concat!(
             "Offset of field: ",
@@ -20365,9 +19064,7 @@ fn bindgen_test_layout__cef_display_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_display_handler_t>())).on_title_change as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_display_handler_t, on_title_change),
         48usize,
         concat!(
             "Offset of field: ",
@@ -20377,10 +19074,7 @@ fn bindgen_test_layout__cef_display_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_display_handler_t>())).on_favicon_urlchange as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_display_handler_t, on_favicon_urlchange),
         56usize,
         concat!(
             "Offset of field: ",
@@ -20390,10 +19084,7 @@ fn bindgen_test_layout__cef_display_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_display_handler_t>())).on_fullscreen_mode_change as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_display_handler_t, on_fullscreen_mode_change),
         64usize,
         concat!(
             "Offset of field: ",
@@ -20403,9 +19094,7 @@ fn bindgen_test_layout__cef_display_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_display_handler_t>())).on_tooltip as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_display_handler_t, on_tooltip),
         72usize,
         concat!(
             "Offset of field: ",
@@ -20415,10 +19104,7 @@ fn bindgen_test_layout__cef_display_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_display_handler_t>())).on_status_message as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_display_handler_t, on_status_message),
         80usize,
         concat!(
             "Offset of field: ",
@@ -20428,10 +19114,7 @@ fn bindgen_test_layout__cef_display_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_display_handler_t>())).on_console_message as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_display_handler_t, on_console_message),
         88usize,
         concat!(
             "Offset of field: ",
@@ -20441,9 +19124,7 @@ fn bindgen_test_layout__cef_display_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_display_handler_t>())).on_auto_resize as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_display_handler_t, on_auto_resize),
         96usize,
         concat!(
             "Offset of field: ",
@@ -20453,10 +19134,7 @@ fn bindgen_test_layout__cef_display_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_display_handler_t>())).on_loading_progress_change
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_display_handler_t, on_loading_progress_change),
         104usize,
         concat!(
             "Offset of field: ",
@@ -20530,7 +19208,7 @@ fn bindgen_test_layout__cef_download_item_t() {
         concat!("Alignment of ", stringify!(_cef_download_item_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_download_item_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_download_item_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -20540,7 +19218,7 @@ fn bindgen_test_layout__cef_download_item_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_download_item_t>())).is_valid as *const _ as usize },
+        ::std::mem::offset_of!(_cef_download_item_t, is_valid),
         40usize,
         concat!(
             "Offset of field: ",
@@ -20550,9 +19228,7 @@ fn bindgen_test_layout__cef_download_item_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_download_item_t>())).is_in_progress as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_download_item_t, is_in_progress),
         48usize,
         concat!(
             "Offset of field: ",
@@ -20562,9 +19238,7 @@ fn bindgen_test_layout__cef_download_item_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_download_item_t>())).is_complete as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_download_item_t, is_complete),
         56usize,
         concat!(
             "Offset of field: ",
@@ -20574,9 +19248,7 @@ fn bindgen_test_layout__cef_download_item_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_download_item_t>())).is_canceled as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_download_item_t, is_canceled),
         64usize,
         concat!(
             "Offset of field: ",
@@ -20586,9 +19258,7 @@ fn bindgen_test_layout__cef_download_item_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_download_item_t>())).get_current_speed as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_download_item_t, get_current_speed),
         72usize,
         concat!(
             "Offset of field: ",
@@ -20598,10 +19268,7 @@ fn bindgen_test_layout__cef_download_item_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_download_item_t>())).get_percent_complete as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_download_item_t, get_percent_complete),
         80usize,
         concat!(
             "Offset of field: ",
@@ -20611,9 +19278,7 @@ fn bindgen_test_layout__cef_download_item_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_download_item_t>())).get_total_bytes as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_download_item_t, get_total_bytes),
         88usize,
         concat!(
             "Offset of field: ",
@@ -20623,9 +19288,7 @@ fn bindgen_test_layout__cef_download_item_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_download_item_t>())).get_received_bytes as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_download_item_t, get_received_bytes),
         96usize,
         concat!(
             "Offset of field: ",
@@ -20635,9 +19298,7 @@ fn bindgen_test_layout__cef_download_item_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_download_item_t>())).get_start_time as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_download_item_t, get_start_time),
         104usize,
         concat!(
             "Offset of field: ",
@@ -20647,9 +19308,7 @@ fn bindgen_test_layout__cef_download_item_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_download_item_t>())).get_end_time as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_download_item_t, get_end_time),
         112usize,
         concat!(
             "Offset of field: ",
@@ -20659,9 +19318,7 @@ fn bindgen_test_layout__cef_download_item_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_download_item_t>())).get_full_path as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_download_item_t, get_full_path),
         120usize,
         concat!(
             "Offset of field: ",
@@ -20671,7 +19328,7 @@ fn bindgen_test_layout__cef_download_item_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_download_item_t>())).get_id as *const _ as usize },
+        ::std::mem::offset_of!(_cef_download_item_t, get_id),
         128usize,
         concat!(
             "Offset of field: ",
@@ -20681,7 +19338,7 @@ fn bindgen_test_layout__cef_download_item_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_download_item_t>())).get_url as *const _ as usize },
+        ::std::mem::offset_of!(_cef_download_item_t, get_url),
         136usize,
         concat!(
             "Offset of field: ",
@@ -20691,9 +19348,7 @@ fn bindgen_test_layout__cef_download_item_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_download_item_t>())).get_original_url as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_download_item_t, get_original_url),
         144usize,
         concat!(
             "Offset of field: ",
@@ -20703,10 +19358,7 @@ fn bindgen_test_layout__cef_download_item_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_download_item_t>())).get_suggested_file_name as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_download_item_t, get_suggested_file_name),
         152usize,
         concat!(
             "Offset of field: ",
@@ -20716,10 +19368,7 @@ fn bindgen_test_layout__cef_download_item_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_download_item_t>())).get_content_disposition as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_download_item_t, get_content_disposition),
         160usize,
         concat!(
             "Offset of field: ",
@@ -20729,9 +19378,7 @@ fn bindgen_test_layout__cef_download_item_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_download_item_t>())).get_mime_type as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_download_item_t, get_mime_type),
         168usize,
         concat!(
             "Offset of field: ",
@@ -20767,9 +19414,7 @@ fn bindgen_test_layout__cef_before_download_callback_t() {
         concat!("Alignment of ", stringify!(_cef_before_download_callback_t))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_before_download_callback_t>())).base as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_before_download_callback_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -20779,9 +19424,7 @@ fn bindgen_test_layout__cef_before_download_callback_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_before_download_callback_t>())).cont as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_before_download_callback_t, cont),
         40usize,
         concat!(
             "Offset of field: ",
@@ -20816,9 +19459,7 @@ fn bindgen_test_layout__cef_download_item_callback_t() {
         concat!("Alignment of ", stringify!(_cef_download_item_callback_t))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_download_item_callback_t>())).base as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_download_item_callback_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -20828,9 +19469,7 @@ fn bindgen_test_layout__cef_download_item_callback_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_download_item_callback_t>())).cancel as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_download_item_callback_t, cancel),
         40usize,
         concat!(
             "Offset of field: ",
@@ -20840,9 +19479,7 @@ fn bindgen_test_layout__cef_download_item_callback_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_download_item_callback_t>())).pause as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_download_item_callback_t, pause),
         48usize,
         concat!(
             "Offset of field: ",
@@ -20852,9 +19489,7 @@ fn bindgen_test_layout__cef_download_item_callback_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_download_item_callback_t>())).resume as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_download_item_callback_t, resume),
         56usize,
         concat!(
             "Offset of field: ",
@@ -20900,7 +19535,7 @@ fn bindgen_test_layout__cef_download_handler_t() {
         concat!("Alignment of ", stringify!(_cef_download_handler_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_download_handler_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_download_handler_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -20910,10 +19545,7 @@ fn bindgen_test_layout__cef_download_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_download_handler_t>())).on_before_download as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_download_handler_t, on_before_download),
         40usize,
         concat!(
             "Offset of field: ",
@@ -20923,10 +19555,7 @@ fn bindgen_test_layout__cef_download_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_download_handler_t>())).on_download_updated as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_download_handler_t, on_download_updated),
         48usize,
         concat!(
             "Offset of field: ",
@@ -20972,7 +19601,7 @@ fn bindgen_test_layout__cef_drag_handler_t() {
         concat!("Alignment of ", stringify!(_cef_drag_handler_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_drag_handler_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_drag_handler_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -20982,9 +19611,7 @@ fn bindgen_test_layout__cef_drag_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_drag_handler_t>())).on_drag_enter as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_drag_handler_t, on_drag_enter),
         40usize,
         concat!(
             "Offset of field: ",
@@ -20994,10 +19621,7 @@ fn bindgen_test_layout__cef_drag_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_drag_handler_t>())).on_draggable_regions_changed as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_drag_handler_t, on_draggable_regions_changed),
         48usize,
         concat!(
             "Offset of field: ",
@@ -21037,7 +19661,7 @@ fn bindgen_test_layout__cef_find_handler_t() {
         concat!("Alignment of ", stringify!(_cef_find_handler_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_find_handler_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_find_handler_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -21047,9 +19671,7 @@ fn bindgen_test_layout__cef_find_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_find_handler_t>())).on_find_result as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_find_handler_t, on_find_result),
         40usize,
         concat!(
             "Offset of field: ",
@@ -21095,7 +19717,7 @@ fn bindgen_test_layout__cef_focus_handler_t() {
         concat!("Alignment of ", stringify!(_cef_focus_handler_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_focus_handler_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_focus_handler_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -21105,9 +19727,7 @@ fn bindgen_test_layout__cef_focus_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_focus_handler_t>())).on_take_focus as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_focus_handler_t, on_take_focus),
         40usize,
         concat!(
             "Offset of field: ",
@@ -21117,9 +19737,7 @@ fn bindgen_test_layout__cef_focus_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_focus_handler_t>())).on_set_focus as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_focus_handler_t, on_set_focus),
         48usize,
         concat!(
             "Offset of field: ",
@@ -21129,9 +19747,7 @@ fn bindgen_test_layout__cef_focus_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_focus_handler_t>())).on_got_focus as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_focus_handler_t, on_got_focus),
         56usize,
         concat!(
             "Offset of field: ",
@@ -21167,7 +19783,7 @@ fn bindgen_test_layout__cef_jsdialog_callback_t() {
         concat!("Alignment of ", stringify!(_cef_jsdialog_callback_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_jsdialog_callback_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_jsdialog_callback_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -21177,7 +19793,7 @@ fn bindgen_test_layout__cef_jsdialog_callback_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_jsdialog_callback_t>())).cont as *const _ as usize },
+        ::std::mem::offset_of!(_cef_jsdialog_callback_t, cont),
         40usize,
         concat!(
             "Offset of field: ",
@@ -21233,7 +19849,7 @@ fn bindgen_test_layout__cef_jsdialog_handler_t() {
         concat!("Alignment of ", stringify!(_cef_jsdialog_handler_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_jsdialog_handler_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_jsdialog_handler_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -21243,9 +19859,7 @@ fn bindgen_test_layout__cef_jsdialog_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_jsdialog_handler_t>())).on_jsdialog as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_jsdialog_handler_t, on_jsdialog),
         40usize,
         concat!(
             "Offset of field: ",
@@ -21255,10 +19869,7 @@ fn bindgen_test_layout__cef_jsdialog_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_jsdialog_handler_t>())).on_before_unload_dialog as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_jsdialog_handler_t, on_before_unload_dialog),
         48usize,
         concat!(
             "Offset of field: ",
@@ -21268,10 +19879,7 @@ fn bindgen_test_layout__cef_jsdialog_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_jsdialog_handler_t>())).on_reset_dialog_state as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_jsdialog_handler_t, on_reset_dialog_state),
         56usize,
         concat!(
             "Offset of field: ",
@@ -21281,10 +19889,7 @@ fn bindgen_test_layout__cef_jsdialog_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_jsdialog_handler_t>())).on_dialog_closed as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_jsdialog_handler_t, on_dialog_closed),
         64usize,
         concat!(
             "Offset of field: ",
@@ -21330,7 +19935,7 @@ fn bindgen_test_layout__cef_keyboard_handler_t() {
         concat!("Alignment of ", stringify!(_cef_keyboard_handler_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_keyboard_handler_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_keyboard_handler_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -21340,10 +19945,7 @@ fn bindgen_test_layout__cef_keyboard_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_keyboard_handler_t>())).on_pre_key_event as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_keyboard_handler_t, on_pre_key_event),
         40usize,
         concat!(
             "Offset of field: ",
@@ -21353,9 +19955,7 @@ fn bindgen_test_layout__cef_keyboard_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_keyboard_handler_t>())).on_key_event as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_keyboard_handler_t, on_key_event),
         48usize,
         concat!(
             "Offset of field: ",
@@ -21413,7 +20013,7 @@ fn bindgen_test_layout__cef_life_span_handler_t() {
         concat!("Alignment of ", stringify!(_cef_life_span_handler_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_life_span_handler_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_life_span_handler_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -21423,10 +20023,7 @@ fn bindgen_test_layout__cef_life_span_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_life_span_handler_t>())).on_before_popup as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_life_span_handler_t, on_before_popup),
         40usize,
         concat!(
             "Offset of field: ",
@@ -21436,10 +20033,7 @@ fn bindgen_test_layout__cef_life_span_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_life_span_handler_t>())).on_after_created as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_life_span_handler_t, on_after_created),
         48usize,
         concat!(
             "Offset of field: ",
@@ -21449,9 +20043,7 @@ fn bindgen_test_layout__cef_life_span_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_life_span_handler_t>())).do_close as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_life_span_handler_t, do_close),
         56usize,
         concat!(
             "Offset of field: ",
@@ -21461,10 +20053,7 @@ fn bindgen_test_layout__cef_life_span_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_life_span_handler_t>())).on_before_close as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_life_span_handler_t, on_before_close),
         64usize,
         concat!(
             "Offset of field: ",
@@ -21623,7 +20212,7 @@ fn bindgen_test_layout__cef_render_handler_t() {
         concat!("Alignment of ", stringify!(_cef_render_handler_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_render_handler_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_render_handler_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -21633,10 +20222,7 @@ fn bindgen_test_layout__cef_render_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_render_handler_t>())).get_accessibility_handler as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_render_handler_t, get_accessibility_handler),
         40usize,
         concat!(
             "Offset of field: ",
@@ -21646,10 +20232,7 @@ fn bindgen_test_layout__cef_render_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_render_handler_t>())).get_root_screen_rect as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_render_handler_t, get_root_screen_rect),
         48usize,
         concat!(
             "Offset of field: ",
@@ -21659,9 +20242,7 @@ fn bindgen_test_layout__cef_render_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_render_handler_t>())).get_view_rect as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_render_handler_t, get_view_rect),
         56usize,
         concat!(
             "Offset of field: ",
@@ -21671,9 +20252,7 @@ fn bindgen_test_layout__cef_render_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_render_handler_t>())).get_screen_point as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_render_handler_t, get_screen_point),
         64usize,
         concat!(
             "Offset of field: ",
@@ -21683,9 +20262,7 @@ fn bindgen_test_layout__cef_render_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_render_handler_t>())).get_screen_info as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_render_handler_t, get_screen_info),
         72usize,
         concat!(
             "Offset of field: ",
@@ -21695,9 +20272,7 @@ fn bindgen_test_layout__cef_render_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_render_handler_t>())).on_popup_show as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_render_handler_t, on_popup_show),
         80usize,
         concat!(
             "Offset of field: ",
@@ -21707,9 +20282,7 @@ fn bindgen_test_layout__cef_render_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_render_handler_t>())).on_popup_size as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_render_handler_t, on_popup_size),
         88usize,
         concat!(
             "Offset of field: ",
@@ -21719,7 +20292,7 @@ fn bindgen_test_layout__cef_render_handler_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_render_handler_t>())).on_paint as *const _ as usize },
+        ::std::mem::offset_of!(_cef_render_handler_t, on_paint),
         96usize,
         concat!(
             "Offset of field: ",
@@ -21729,10 +20302,7 @@ fn bindgen_test_layout__cef_render_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_render_handler_t>())).on_accelerated_paint as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_render_handler_t, on_accelerated_paint),
         104usize,
         concat!(
             "Offset of field: ",
@@ -21742,9 +20312,7 @@ fn bindgen_test_layout__cef_render_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_render_handler_t>())).on_cursor_change as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_render_handler_t, on_cursor_change),
         112usize,
         concat!(
             "Offset of field: ",
@@ -21754,9 +20322,7 @@ fn bindgen_test_layout__cef_render_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_render_handler_t>())).start_dragging as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_render_handler_t, start_dragging),
         120usize,
         concat!(
             "Offset of field: ",
@@ -21766,10 +20332,7 @@ fn bindgen_test_layout__cef_render_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_render_handler_t>())).update_drag_cursor as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_render_handler_t, update_drag_cursor),
         128usize,
         concat!(
             "Offset of field: ",
@@ -21779,10 +20342,7 @@ fn bindgen_test_layout__cef_render_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_render_handler_t>())).on_scroll_offset_changed as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_render_handler_t, on_scroll_offset_changed),
         136usize,
         concat!(
             "Offset of field: ",
@@ -21792,10 +20352,7 @@ fn bindgen_test_layout__cef_render_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_render_handler_t>())).on_ime_composition_range_changed
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_render_handler_t, on_ime_composition_range_changed),
         144usize,
         concat!(
             "Offset of field: ",
@@ -21805,10 +20362,7 @@ fn bindgen_test_layout__cef_render_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_render_handler_t>())).on_text_selection_changed as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_render_handler_t, on_text_selection_changed),
         152usize,
         concat!(
             "Offset of field: ",
@@ -21818,10 +20372,7 @@ fn bindgen_test_layout__cef_render_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_render_handler_t>())).on_virtual_keyboard_requested
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_render_handler_t, on_virtual_keyboard_requested),
         160usize,
         concat!(
             "Offset of field: ",
@@ -21854,7 +20405,7 @@ fn bindgen_test_layout__cef_request_callback_t() {
         concat!("Alignment of ", stringify!(_cef_request_callback_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_request_callback_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_request_callback_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -21864,7 +20415,7 @@ fn bindgen_test_layout__cef_request_callback_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_request_callback_t>())).cont as *const _ as usize },
+        ::std::mem::offset_of!(_cef_request_callback_t, cont),
         40usize,
         concat!(
             "Offset of field: ",
@@ -21874,7 +20425,7 @@ fn bindgen_test_layout__cef_request_callback_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_request_callback_t>())).cancel as *const _ as usize },
+        ::std::mem::offset_of!(_cef_request_callback_t, cancel),
         48usize,
         concat!(
             "Offset of field: ",
@@ -21917,7 +20468,7 @@ fn bindgen_test_layout__cef_response_filter_t() {
         concat!("Alignment of ", stringify!(_cef_response_filter_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_response_filter_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_response_filter_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -21927,9 +20478,7 @@ fn bindgen_test_layout__cef_response_filter_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_response_filter_t>())).init_filter as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_response_filter_t, init_filter),
         40usize,
         concat!(
             "Offset of field: ",
@@ -21939,7 +20488,7 @@ fn bindgen_test_layout__cef_response_filter_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_response_filter_t>())).filter as *const _ as usize },
+        ::std::mem::offset_of!(_cef_response_filter_t, filter),
         48usize,
         concat!(
             "Offset of field: ",
@@ -22041,9 +20590,7 @@ fn bindgen_test_layout__cef_resource_request_handler_t() {
         concat!("Alignment of ", stringify!(_cef_resource_request_handler_t))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_resource_request_handler_t>())).base as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_resource_request_handler_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -22053,10 +20600,7 @@ fn bindgen_test_layout__cef_resource_request_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_resource_request_handler_t>())).get_cookie_access_filter
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_resource_request_handler_t, get_cookie_access_filter),
         40usize,
         concat!(
             "Offset of field: ",
@@ -22066,10 +20610,7 @@ fn bindgen_test_layout__cef_resource_request_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_resource_request_handler_t>())).on_before_resource_load
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_resource_request_handler_t, on_before_resource_load),
         48usize,
         concat!(
             "Offset of field: ",
@@ -22079,10 +20620,7 @@ fn bindgen_test_layout__cef_resource_request_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_resource_request_handler_t>())).get_resource_handler
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_resource_request_handler_t, get_resource_handler),
         56usize,
         concat!(
             "Offset of field: ",
@@ -22092,10 +20630,7 @@ fn bindgen_test_layout__cef_resource_request_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_resource_request_handler_t>())).on_resource_redirect
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_resource_request_handler_t, on_resource_redirect),
         64usize,
         concat!(
             "Offset of field: ",
@@ -22105,10 +20640,7 @@ fn bindgen_test_layout__cef_resource_request_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_resource_request_handler_t>())).on_resource_response
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_resource_request_handler_t, on_resource_response),
         72usize,
         concat!(
             "Offset of field: ",
@@ -22118,10 +20650,7 @@ fn bindgen_test_layout__cef_resource_request_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_resource_request_handler_t>())).get_resource_response_filter
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_resource_request_handler_t, get_resource_response_filter),
         80usize,
         concat!(
             "Offset of field: ",
@@ -22131,10 +20660,7 @@ fn bindgen_test_layout__cef_resource_request_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_resource_request_handler_t>())).on_resource_load_complete
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_resource_request_handler_t, on_resource_load_complete),
         88usize,
         concat!(
             "Offset of field: ",
@@ -22144,10 +20670,7 @@ fn bindgen_test_layout__cef_resource_request_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_resource_request_handler_t>())).on_protocol_execution
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_resource_request_handler_t, on_protocol_execution),
         96usize,
         concat!(
             "Offset of field: ",
@@ -22195,9 +20718,7 @@ fn bindgen_test_layout__cef_cookie_access_filter_t() {
         concat!("Alignment of ", stringify!(_cef_cookie_access_filter_t))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_cookie_access_filter_t>())).base as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_cookie_access_filter_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -22207,10 +20728,7 @@ fn bindgen_test_layout__cef_cookie_access_filter_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_cookie_access_filter_t>())).can_send_cookie as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_cookie_access_filter_t, can_send_cookie),
         40usize,
         concat!(
             "Offset of field: ",
@@ -22220,10 +20738,7 @@ fn bindgen_test_layout__cef_cookie_access_filter_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_cookie_access_filter_t>())).can_save_cookie as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_cookie_access_filter_t, can_save_cookie),
         48usize,
         concat!(
             "Offset of field: ",
@@ -22258,7 +20773,7 @@ fn bindgen_test_layout__cef_sslinfo_t() {
         concat!("Alignment of ", stringify!(_cef_sslinfo_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_sslinfo_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_sslinfo_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -22268,7 +20783,7 @@ fn bindgen_test_layout__cef_sslinfo_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_sslinfo_t>())).get_cert_status as *const _ as usize },
+        ::std::mem::offset_of!(_cef_sslinfo_t, get_cert_status),
         40usize,
         concat!(
             "Offset of field: ",
@@ -22278,9 +20793,7 @@ fn bindgen_test_layout__cef_sslinfo_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_sslinfo_t>())).get_x509certificate as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_sslinfo_t, get_x509certificate),
         48usize,
         concat!(
             "Offset of field: ",
@@ -22324,10 +20837,7 @@ fn bindgen_test_layout__cef_select_client_certificate_callback_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_select_client_certificate_callback_t>())).base as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_select_client_certificate_callback_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -22337,10 +20847,7 @@ fn bindgen_test_layout__cef_select_client_certificate_callback_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_select_client_certificate_callback_t>())).select as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_select_client_certificate_callback_t, select),
         40usize,
         concat!(
             "Offset of field: ",
@@ -22465,7 +20972,7 @@ fn bindgen_test_layout__cef_request_handler_t() {
         concat!("Alignment of ", stringify!(_cef_request_handler_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_request_handler_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_request_handler_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -22475,9 +20982,7 @@ fn bindgen_test_layout__cef_request_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_request_handler_t>())).on_before_browse as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_request_handler_t, on_before_browse),
         40usize,
         concat!(
             "Offset of field: ",
@@ -22487,10 +20992,7 @@ fn bindgen_test_layout__cef_request_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_request_handler_t>())).on_open_urlfrom_tab as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_request_handler_t, on_open_urlfrom_tab),
         48usize,
         concat!(
             "Offset of field: ",
@@ -22500,10 +21002,7 @@ fn bindgen_test_layout__cef_request_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_request_handler_t>())).get_resource_request_handler
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_request_handler_t, get_resource_request_handler),
         56usize,
         concat!(
             "Offset of field: ",
@@ -22513,10 +21012,7 @@ fn bindgen_test_layout__cef_request_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_request_handler_t>())).get_auth_credentials as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_request_handler_t, get_auth_credentials),
         64usize,
         concat!(
             "Offset of field: ",
@@ -22526,9 +21022,7 @@ fn bindgen_test_layout__cef_request_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_request_handler_t>())).on_quota_request as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_request_handler_t, on_quota_request),
         72usize,
         concat!(
             "Offset of field: ",
@@ -22538,10 +21032,7 @@ fn bindgen_test_layout__cef_request_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_request_handler_t>())).on_certificate_error as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_request_handler_t, on_certificate_error),
         80usize,
         concat!(
             "Offset of field: ",
@@ -22551,10 +21042,7 @@ fn bindgen_test_layout__cef_request_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_request_handler_t>())).on_select_client_certificate
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_request_handler_t, on_select_client_certificate),
         88usize,
         concat!(
             "Offset of field: ",
@@ -22564,10 +21052,7 @@ fn bindgen_test_layout__cef_request_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_request_handler_t>())).on_plugin_crashed as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_request_handler_t, on_plugin_crashed),
         96usize,
         concat!(
             "Offset of field: ",
@@ -22577,10 +21062,7 @@ fn bindgen_test_layout__cef_request_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_request_handler_t>())).on_render_view_ready as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_request_handler_t, on_render_view_ready),
         104usize,
         concat!(
             "Offset of field: ",
@@ -22590,10 +21072,7 @@ fn bindgen_test_layout__cef_request_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_request_handler_t>())).on_render_process_terminated
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_request_handler_t, on_render_process_terminated),
         112usize,
         concat!(
             "Offset of field: ",
@@ -22603,10 +21082,7 @@ fn bindgen_test_layout__cef_request_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_request_handler_t>())).on_document_available_in_main_frame
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_request_handler_t, on_document_available_in_main_frame),
         120usize,
         concat!(
             "Offset of field: ",
@@ -22686,7 +21162,7 @@ fn bindgen_test_layout__cef_client_t() {
         concat!("Alignment of ", stringify!(_cef_client_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_client_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_client_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -22696,7 +21172,7 @@ fn bindgen_test_layout__cef_client_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_client_t>())).get_audio_handler as *const _ as usize },
+        ::std::mem::offset_of!(_cef_client_t, get_audio_handler),
         40usize,
         concat!(
             "Offset of field: ",
@@ -22706,9 +21182,7 @@ fn bindgen_test_layout__cef_client_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_client_t>())).get_context_menu_handler as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_client_t, get_context_menu_handler),
         48usize,
         concat!(
             "Offset of field: ",
@@ -22718,9 +21192,7 @@ fn bindgen_test_layout__cef_client_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_client_t>())).get_dialog_handler as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_client_t, get_dialog_handler),
         56usize,
         concat!(
             "Offset of field: ",
@@ -22730,9 +21202,7 @@ fn bindgen_test_layout__cef_client_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_client_t>())).get_display_handler as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_client_t, get_display_handler),
         64usize,
         concat!(
             "Offset of field: ",
@@ -22742,9 +21212,7 @@ fn bindgen_test_layout__cef_client_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_client_t>())).get_download_handler as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_client_t, get_download_handler),
         72usize,
         concat!(
             "Offset of field: ",
@@ -22754,7 +21222,7 @@ fn bindgen_test_layout__cef_client_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_client_t>())).get_drag_handler as *const _ as usize },
+        ::std::mem::offset_of!(_cef_client_t, get_drag_handler),
         80usize,
         concat!(
             "Offset of field: ",
@@ -22764,7 +21232,7 @@ fn bindgen_test_layout__cef_client_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_client_t>())).get_find_handler as *const _ as usize },
+        ::std::mem::offset_of!(_cef_client_t, get_find_handler),
         88usize,
         concat!(
             "Offset of field: ",
@@ -22774,7 +21242,7 @@ fn bindgen_test_layout__cef_client_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_client_t>())).get_focus_handler as *const _ as usize },
+        ::std::mem::offset_of!(_cef_client_t, get_focus_handler),
         96usize,
         concat!(
             "Offset of field: ",
@@ -22784,9 +21252,7 @@ fn bindgen_test_layout__cef_client_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_client_t>())).get_jsdialog_handler as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_client_t, get_jsdialog_handler),
         104usize,
         concat!(
             "Offset of field: ",
@@ -22796,9 +21262,7 @@ fn bindgen_test_layout__cef_client_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_client_t>())).get_keyboard_handler as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_client_t, get_keyboard_handler),
         112usize,
         concat!(
             "Offset of field: ",
@@ -22808,9 +21272,7 @@ fn bindgen_test_layout__cef_client_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_client_t>())).get_life_span_handler as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_client_t, get_life_span_handler),
         120usize,
         concat!(
             "Offset of field: ",
@@ -22820,7 +21282,7 @@ fn bindgen_test_layout__cef_client_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_client_t>())).get_load_handler as *const _ as usize },
+        ::std::mem::offset_of!(_cef_client_t, get_load_handler),
         128usize,
         concat!(
             "Offset of field: ",
@@ -22830,9 +21292,7 @@ fn bindgen_test_layout__cef_client_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_client_t>())).get_render_handler as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_client_t, get_render_handler),
         136usize,
         concat!(
             "Offset of field: ",
@@ -22842,9 +21302,7 @@ fn bindgen_test_layout__cef_client_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_client_t>())).get_request_handler as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_client_t, get_request_handler),
         144usize,
         concat!(
             "Offset of field: ",
@@ -22854,10 +21312,7 @@ fn bindgen_test_layout__cef_client_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_client_t>())).on_process_message_received as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_client_t, on_process_message_received),
         152usize,
         concat!(
             "Offset of field: ",
@@ -23045,7 +21500,7 @@ fn bindgen_test_layout__cef_web_plugin_info_t() {
         concat!("Alignment of ", stringify!(_cef_web_plugin_info_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_web_plugin_info_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_web_plugin_info_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -23055,7 +21510,7 @@ fn bindgen_test_layout__cef_web_plugin_info_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_web_plugin_info_t>())).get_name as *const _ as usize },
+        ::std::mem::offset_of!(_cef_web_plugin_info_t, get_name),
         40usize,
         concat!(
             "Offset of field: ",
@@ -23065,7 +21520,7 @@ fn bindgen_test_layout__cef_web_plugin_info_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_web_plugin_info_t>())).get_path as *const _ as usize },
+        ::std::mem::offset_of!(_cef_web_plugin_info_t, get_path),
         48usize,
         concat!(
             "Offset of field: ",
@@ -23075,9 +21530,7 @@ fn bindgen_test_layout__cef_web_plugin_info_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_web_plugin_info_t>())).get_version as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_web_plugin_info_t, get_version),
         56usize,
         concat!(
             "Offset of field: ",
@@ -23087,9 +21540,7 @@ fn bindgen_test_layout__cef_web_plugin_info_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_web_plugin_info_t>())).get_description as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_web_plugin_info_t, get_description),
         64usize,
         concat!(
             "Offset of field: ",
@@ -23126,9 +21577,7 @@ fn bindgen_test_layout__cef_web_plugin_info_visitor_t() {
         concat!("Alignment of ", stringify!(_cef_web_plugin_info_visitor_t))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_web_plugin_info_visitor_t>())).base as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_web_plugin_info_visitor_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -23138,9 +21587,7 @@ fn bindgen_test_layout__cef_web_plugin_info_visitor_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_web_plugin_info_visitor_t>())).visit as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_web_plugin_info_visitor_t, visit),
         40usize,
         concat!(
             "Offset of field: ",
@@ -23179,10 +21626,7 @@ fn bindgen_test_layout__cef_web_plugin_unstable_callback_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_web_plugin_unstable_callback_t>())).base as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_web_plugin_unstable_callback_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -23192,10 +21636,7 @@ fn bindgen_test_layout__cef_web_plugin_unstable_callback_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_web_plugin_unstable_callback_t>())).is_unstable as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_web_plugin_unstable_callback_t, is_unstable),
         40usize,
         concat!(
             "Offset of field: ",
@@ -23231,9 +21672,7 @@ fn bindgen_test_layout__cef_register_cdm_callback_t() {
         concat!("Alignment of ", stringify!(_cef_register_cdm_callback_t))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_register_cdm_callback_t>())).base as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_register_cdm_callback_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -23243,10 +21682,7 @@ fn bindgen_test_layout__cef_register_cdm_callback_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_register_cdm_callback_t>())).on_cdm_registration_complete
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_register_cdm_callback_t, on_cdm_registration_complete),
         40usize,
         concat!(
             "Offset of field: ",
@@ -23328,9 +21764,7 @@ fn bindgen_test_layout__cef_request_context_handler_t() {
         concat!("Alignment of ", stringify!(_cef_request_context_handler_t))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_request_context_handler_t>())).base as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_request_context_handler_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -23340,10 +21774,7 @@ fn bindgen_test_layout__cef_request_context_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_request_context_handler_t>()))
-                .on_request_context_initialized as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_request_context_handler_t, on_request_context_initialized),
         40usize,
         concat!(
             "Offset of field: ",
@@ -23353,10 +21784,7 @@ fn bindgen_test_layout__cef_request_context_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_request_context_handler_t>())).on_before_plugin_load
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_request_context_handler_t, on_before_plugin_load),
         48usize,
         concat!(
             "Offset of field: ",
@@ -23366,10 +21794,7 @@ fn bindgen_test_layout__cef_request_context_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_request_context_handler_t>())).get_resource_request_handler
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_request_context_handler_t, get_resource_request_handler),
         56usize,
         concat!(
             "Offset of field: ",
@@ -23417,7 +21842,7 @@ fn bindgen_test_layout__cef_resource_bundle_t() {
         concat!("Alignment of ", stringify!(_cef_resource_bundle_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_resource_bundle_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_resource_bundle_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -23427,10 +21852,7 @@ fn bindgen_test_layout__cef_resource_bundle_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_resource_bundle_t>())).get_localized_string as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_resource_bundle_t, get_localized_string),
         40usize,
         concat!(
             "Offset of field: ",
@@ -23440,10 +21862,7 @@ fn bindgen_test_layout__cef_resource_bundle_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_resource_bundle_t>())).get_data_resource as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_resource_bundle_t, get_data_resource),
         48usize,
         concat!(
             "Offset of field: ",
@@ -23453,10 +21872,7 @@ fn bindgen_test_layout__cef_resource_bundle_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_resource_bundle_t>())).get_data_resource_for_scale
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_resource_bundle_t, get_data_resource_for_scale),
         56usize,
         concat!(
             "Offset of field: ",
@@ -23555,7 +21971,7 @@ fn bindgen_test_layout__cef_server_t() {
         concat!("Alignment of ", stringify!(_cef_server_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_server_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_server_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -23565,7 +21981,7 @@ fn bindgen_test_layout__cef_server_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_server_t>())).get_task_runner as *const _ as usize },
+        ::std::mem::offset_of!(_cef_server_t, get_task_runner),
         40usize,
         concat!(
             "Offset of field: ",
@@ -23575,7 +21991,7 @@ fn bindgen_test_layout__cef_server_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_server_t>())).shutdown as *const _ as usize },
+        ::std::mem::offset_of!(_cef_server_t, shutdown),
         48usize,
         concat!(
             "Offset of field: ",
@@ -23585,7 +22001,7 @@ fn bindgen_test_layout__cef_server_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_server_t>())).is_running as *const _ as usize },
+        ::std::mem::offset_of!(_cef_server_t, is_running),
         56usize,
         concat!(
             "Offset of field: ",
@@ -23595,7 +22011,7 @@ fn bindgen_test_layout__cef_server_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_server_t>())).get_address as *const _ as usize },
+        ::std::mem::offset_of!(_cef_server_t, get_address),
         64usize,
         concat!(
             "Offset of field: ",
@@ -23605,7 +22021,7 @@ fn bindgen_test_layout__cef_server_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_server_t>())).has_connection as *const _ as usize },
+        ::std::mem::offset_of!(_cef_server_t, has_connection),
         72usize,
         concat!(
             "Offset of field: ",
@@ -23615,9 +22031,7 @@ fn bindgen_test_layout__cef_server_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_server_t>())).is_valid_connection as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_server_t, is_valid_connection),
         80usize,
         concat!(
             "Offset of field: ",
@@ -23627,9 +22041,7 @@ fn bindgen_test_layout__cef_server_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_server_t>())).send_http200response as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_server_t, send_http200response),
         88usize,
         concat!(
             "Offset of field: ",
@@ -23639,9 +22051,7 @@ fn bindgen_test_layout__cef_server_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_server_t>())).send_http404response as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_server_t, send_http404response),
         96usize,
         concat!(
             "Offset of field: ",
@@ -23651,9 +22061,7 @@ fn bindgen_test_layout__cef_server_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_server_t>())).send_http500response as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_server_t, send_http500response),
         104usize,
         concat!(
             "Offset of field: ",
@@ -23663,9 +22071,7 @@ fn bindgen_test_layout__cef_server_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_server_t>())).send_http_response as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_server_t, send_http_response),
         112usize,
         concat!(
             "Offset of field: ",
@@ -23675,7 +22081,7 @@ fn bindgen_test_layout__cef_server_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_server_t>())).send_raw_data as *const _ as usize },
+        ::std::mem::offset_of!(_cef_server_t, send_raw_data),
         120usize,
         concat!(
             "Offset of field: ",
@@ -23685,7 +22091,7 @@ fn bindgen_test_layout__cef_server_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_server_t>())).close_connection as *const _ as usize },
+        ::std::mem::offset_of!(_cef_server_t, close_connection),
         128usize,
         concat!(
             "Offset of field: ",
@@ -23695,9 +22101,7 @@ fn bindgen_test_layout__cef_server_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_server_t>())).send_web_socket_message as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_server_t, send_web_socket_message),
         136usize,
         concat!(
             "Offset of field: ",
@@ -23789,7 +22193,7 @@ fn bindgen_test_layout__cef_server_handler_t() {
         concat!("Alignment of ", stringify!(_cef_server_handler_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_server_handler_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_server_handler_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -23799,9 +22203,7 @@ fn bindgen_test_layout__cef_server_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_server_handler_t>())).on_server_created as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_server_handler_t, on_server_created),
         40usize,
         concat!(
             "Offset of field: ",
@@ -23811,10 +22213,7 @@ fn bindgen_test_layout__cef_server_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_server_handler_t>())).on_server_destroyed as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_server_handler_t, on_server_destroyed),
         48usize,
         concat!(
             "Offset of field: ",
@@ -23824,10 +22223,7 @@ fn bindgen_test_layout__cef_server_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_server_handler_t>())).on_client_connected as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_server_handler_t, on_client_connected),
         56usize,
         concat!(
             "Offset of field: ",
@@ -23837,10 +22233,7 @@ fn bindgen_test_layout__cef_server_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_server_handler_t>())).on_client_disconnected as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_server_handler_t, on_client_disconnected),
         64usize,
         concat!(
             "Offset of field: ",
@@ -23850,9 +22243,7 @@ fn bindgen_test_layout__cef_server_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_server_handler_t>())).on_http_request as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_server_handler_t, on_http_request),
         72usize,
         concat!(
             "Offset of field: ",
@@ -23862,10 +22253,7 @@ fn bindgen_test_layout__cef_server_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_server_handler_t>())).on_web_socket_request as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_server_handler_t, on_web_socket_request),
         80usize,
         concat!(
             "Offset of field: ",
@@ -23875,10 +22263,7 @@ fn bindgen_test_layout__cef_server_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_server_handler_t>())).on_web_socket_connected as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_server_handler_t, on_web_socket_connected),
         88usize,
         concat!(
             "Offset of field: ",
@@ -23888,10 +22273,7 @@ fn bindgen_test_layout__cef_server_handler_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_server_handler_t>())).on_web_socket_message as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_server_handler_t, on_web_socket_message),
         96usize,
         concat!(
             "Offset of field: ",
@@ -23938,7 +22320,7 @@ fn bindgen_test_layout__cef_thread_t() {
         concat!("Alignment of ", stringify!(_cef_thread_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_thread_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_thread_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -23948,7 +22330,7 @@ fn bindgen_test_layout__cef_thread_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_thread_t>())).get_task_runner as *const _ as usize },
+        ::std::mem::offset_of!(_cef_thread_t, get_task_runner),
         40usize,
         concat!(
             "Offset of field: ",
@@ -23958,9 +22340,7 @@ fn bindgen_test_layout__cef_thread_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_thread_t>())).get_platform_thread_id as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_thread_t, get_platform_thread_id),
         48usize,
         concat!(
             "Offset of field: ",
@@ -23970,7 +22350,7 @@ fn bindgen_test_layout__cef_thread_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_thread_t>())).stop as *const _ as usize },
+        ::std::mem::offset_of!(_cef_thread_t, stop),
         56usize,
         concat!(
             "Offset of field: ",
@@ -23980,7 +22360,7 @@ fn bindgen_test_layout__cef_thread_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_thread_t>())).is_running as *const _ as usize },
+        ::std::mem::offset_of!(_cef_thread_t, is_running),
         64usize,
         concat!(
             "Offset of field: ",
@@ -24024,9 +22404,7 @@ fn bindgen_test_layout__cef_end_tracing_callback_t() {
         concat!("Alignment of ", stringify!(_cef_end_tracing_callback_t))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_end_tracing_callback_t>())).base as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_end_tracing_callback_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -24036,10 +22414,7 @@ fn bindgen_test_layout__cef_end_tracing_callback_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_end_tracing_callback_t>())).on_end_tracing_complete
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_end_tracing_callback_t, on_end_tracing_complete),
         40usize,
         concat!(
             "Offset of field: ",
@@ -24102,7 +22477,7 @@ fn bindgen_test_layout__cef_urlrequest_t() {
         concat!("Alignment of ", stringify!(_cef_urlrequest_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_urlrequest_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_urlrequest_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -24112,7 +22487,7 @@ fn bindgen_test_layout__cef_urlrequest_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_urlrequest_t>())).get_request as *const _ as usize },
+        ::std::mem::offset_of!(_cef_urlrequest_t, get_request),
         40usize,
         concat!(
             "Offset of field: ",
@@ -24122,7 +22497,7 @@ fn bindgen_test_layout__cef_urlrequest_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_urlrequest_t>())).get_client as *const _ as usize },
+        ::std::mem::offset_of!(_cef_urlrequest_t, get_client),
         48usize,
         concat!(
             "Offset of field: ",
@@ -24132,9 +22507,7 @@ fn bindgen_test_layout__cef_urlrequest_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_urlrequest_t>())).get_request_status as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_urlrequest_t, get_request_status),
         56usize,
         concat!(
             "Offset of field: ",
@@ -24144,9 +22517,7 @@ fn bindgen_test_layout__cef_urlrequest_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_urlrequest_t>())).get_request_error as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_urlrequest_t, get_request_error),
         64usize,
         concat!(
             "Offset of field: ",
@@ -24156,7 +22527,7 @@ fn bindgen_test_layout__cef_urlrequest_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_urlrequest_t>())).get_response as *const _ as usize },
+        ::std::mem::offset_of!(_cef_urlrequest_t, get_response),
         72usize,
         concat!(
             "Offset of field: ",
@@ -24166,9 +22537,7 @@ fn bindgen_test_layout__cef_urlrequest_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_urlrequest_t>())).response_was_cached as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_urlrequest_t, response_was_cached),
         80usize,
         concat!(
             "Offset of field: ",
@@ -24178,7 +22547,7 @@ fn bindgen_test_layout__cef_urlrequest_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_urlrequest_t>())).cancel as *const _ as usize },
+        ::std::mem::offset_of!(_cef_urlrequest_t, cancel),
         88usize,
         concat!(
             "Offset of field: ",
@@ -24252,7 +22621,7 @@ fn bindgen_test_layout__cef_urlrequest_client_t() {
         concat!("Alignment of ", stringify!(_cef_urlrequest_client_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_urlrequest_client_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_urlrequest_client_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -24262,10 +22631,7 @@ fn bindgen_test_layout__cef_urlrequest_client_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_urlrequest_client_t>())).on_request_complete as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_urlrequest_client_t, on_request_complete),
         40usize,
         concat!(
             "Offset of field: ",
@@ -24275,10 +22641,7 @@ fn bindgen_test_layout__cef_urlrequest_client_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_urlrequest_client_t>())).on_upload_progress as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_urlrequest_client_t, on_upload_progress),
         48usize,
         concat!(
             "Offset of field: ",
@@ -24288,10 +22651,7 @@ fn bindgen_test_layout__cef_urlrequest_client_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_urlrequest_client_t>())).on_download_progress as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_urlrequest_client_t, on_download_progress),
         56usize,
         concat!(
             "Offset of field: ",
@@ -24301,10 +22661,7 @@ fn bindgen_test_layout__cef_urlrequest_client_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_urlrequest_client_t>())).on_download_data as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_urlrequest_client_t, on_download_data),
         64usize,
         concat!(
             "Offset of field: ",
@@ -24314,10 +22671,7 @@ fn bindgen_test_layout__cef_urlrequest_client_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_urlrequest_client_t>())).get_auth_credentials as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_urlrequest_client_t, get_auth_credentials),
         72usize,
         concat!(
             "Offset of field: ",
@@ -24358,7 +22712,7 @@ fn bindgen_test_layout__cef_waitable_event_t() {
         concat!("Alignment of ", stringify!(_cef_waitable_event_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_waitable_event_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_waitable_event_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -24368,7 +22722,7 @@ fn bindgen_test_layout__cef_waitable_event_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_waitable_event_t>())).reset as *const _ as usize },
+        ::std::mem::offset_of!(_cef_waitable_event_t, reset),
         40usize,
         concat!(
             "Offset of field: ",
@@ -24378,7 +22732,7 @@ fn bindgen_test_layout__cef_waitable_event_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_waitable_event_t>())).signal as *const _ as usize },
+        ::std::mem::offset_of!(_cef_waitable_event_t, signal),
         48usize,
         concat!(
             "Offset of field: ",
@@ -24388,9 +22742,7 @@ fn bindgen_test_layout__cef_waitable_event_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_waitable_event_t>())).is_signaled as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_waitable_event_t, is_signaled),
         56usize,
         concat!(
             "Offset of field: ",
@@ -24400,7 +22752,7 @@ fn bindgen_test_layout__cef_waitable_event_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_waitable_event_t>())).wait as *const _ as usize },
+        ::std::mem::offset_of!(_cef_waitable_event_t, wait),
         64usize,
         concat!(
             "Offset of field: ",
@@ -24410,9 +22762,7 @@ fn bindgen_test_layout__cef_waitable_event_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_waitable_event_t>())).timed_wait as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_waitable_event_t, timed_wait),
         72usize,
         concat!(
             "Offset of field: ",
@@ -24553,7 +22903,7 @@ fn bindgen_test_layout__cef_xml_reader_t() {
         concat!("Alignment of ", stringify!(_cef_xml_reader_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_xml_reader_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_xml_reader_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -24563,9 +22913,7 @@ fn bindgen_test_layout__cef_xml_reader_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_xml_reader_t>())).move_to_next_node as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_xml_reader_t, move_to_next_node),
         40usize,
         concat!(
             "Offset of field: ",
@@ -24575,7 +22923,7 @@ fn bindgen_test_layout__cef_xml_reader_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_xml_reader_t>())).close as *const _ as usize },
+        ::std::mem::offset_of!(_cef_xml_reader_t, close),
         48usize,
         concat!(
             "Offset of field: ",
@@ -24585,7 +22933,7 @@ fn bindgen_test_layout__cef_xml_reader_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_xml_reader_t>())).has_error as *const _ as usize },
+        ::std::mem::offset_of!(_cef_xml_reader_t, has_error),
         56usize,
         concat!(
             "Offset of field: ",
@@ -24595,7 +22943,7 @@ fn bindgen_test_layout__cef_xml_reader_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_xml_reader_t>())).get_error as *const _ as usize },
+        ::std::mem::offset_of!(_cef_xml_reader_t, get_error),
         64usize,
         concat!(
             "Offset of field: ",
@@ -24605,7 +22953,7 @@ fn bindgen_test_layout__cef_xml_reader_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_xml_reader_t>())).get_type as *const _ as usize },
+        ::std::mem::offset_of!(_cef_xml_reader_t, get_type),
         72usize,
         concat!(
             "Offset of field: ",
@@ -24615,7 +22963,7 @@ fn bindgen_test_layout__cef_xml_reader_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_xml_reader_t>())).get_depth as *const _ as usize },
+        ::std::mem::offset_of!(_cef_xml_reader_t, get_depth),
         80usize,
         concat!(
             "Offset of field: ",
@@ -24625,9 +22973,7 @@ fn bindgen_test_layout__cef_xml_reader_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_xml_reader_t>())).get_local_name as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_xml_reader_t, get_local_name),
         88usize,
         concat!(
             "Offset of field: ",
@@ -24637,7 +22983,7 @@ fn bindgen_test_layout__cef_xml_reader_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_xml_reader_t>())).get_prefix as *const _ as usize },
+        ::std::mem::offset_of!(_cef_xml_reader_t, get_prefix),
         96usize,
         concat!(
             "Offset of field: ",
@@ -24647,9 +22993,7 @@ fn bindgen_test_layout__cef_xml_reader_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_xml_reader_t>())).get_qualified_name as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_xml_reader_t, get_qualified_name),
         104usize,
         concat!(
             "Offset of field: ",
@@ -24659,9 +23003,7 @@ fn bindgen_test_layout__cef_xml_reader_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_xml_reader_t>())).get_namespace_uri as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_xml_reader_t, get_namespace_uri),
         112usize,
         concat!(
             "Offset of field: ",
@@ -24671,7 +23013,7 @@ fn bindgen_test_layout__cef_xml_reader_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_xml_reader_t>())).get_base_uri as *const _ as usize },
+        ::std::mem::offset_of!(_cef_xml_reader_t, get_base_uri),
         120usize,
         concat!(
             "Offset of field: ",
@@ -24681,7 +23023,7 @@ fn bindgen_test_layout__cef_xml_reader_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_xml_reader_t>())).get_xml_lang as *const _ as usize },
+        ::std::mem::offset_of!(_cef_xml_reader_t, get_xml_lang),
         128usize,
         concat!(
             "Offset of field: ",
@@ -24691,9 +23033,7 @@ fn bindgen_test_layout__cef_xml_reader_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_xml_reader_t>())).is_empty_element as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_xml_reader_t, is_empty_element),
         136usize,
         concat!(
             "Offset of field: ",
@@ -24703,7 +23043,7 @@ fn bindgen_test_layout__cef_xml_reader_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_xml_reader_t>())).has_value as *const _ as usize },
+        ::std::mem::offset_of!(_cef_xml_reader_t, has_value),
         144usize,
         concat!(
             "Offset of field: ",
@@ -24713,7 +23053,7 @@ fn bindgen_test_layout__cef_xml_reader_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_xml_reader_t>())).get_value as *const _ as usize },
+        ::std::mem::offset_of!(_cef_xml_reader_t, get_value),
         152usize,
         concat!(
             "Offset of field: ",
@@ -24723,9 +23063,7 @@ fn bindgen_test_layout__cef_xml_reader_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_xml_reader_t>())).has_attributes as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_xml_reader_t, has_attributes),
         160usize,
         concat!(
             "Offset of field: ",
@@ -24735,9 +23073,7 @@ fn bindgen_test_layout__cef_xml_reader_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_xml_reader_t>())).get_attribute_count as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_xml_reader_t, get_attribute_count),
         168usize,
         concat!(
             "Offset of field: ",
@@ -24747,9 +23083,7 @@ fn bindgen_test_layout__cef_xml_reader_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_xml_reader_t>())).get_attribute_byindex as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_xml_reader_t, get_attribute_byindex),
         176usize,
         concat!(
             "Offset of field: ",
@@ -24759,9 +23093,7 @@ fn bindgen_test_layout__cef_xml_reader_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_xml_reader_t>())).get_attribute_byqname as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_xml_reader_t, get_attribute_byqname),
         184usize,
         concat!(
             "Offset of field: ",
@@ -24771,9 +23103,7 @@ fn bindgen_test_layout__cef_xml_reader_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_xml_reader_t>())).get_attribute_bylname as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_xml_reader_t, get_attribute_bylname),
         192usize,
         concat!(
             "Offset of field: ",
@@ -24783,7 +23113,7 @@ fn bindgen_test_layout__cef_xml_reader_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_xml_reader_t>())).get_inner_xml as *const _ as usize },
+        ::std::mem::offset_of!(_cef_xml_reader_t, get_inner_xml),
         200usize,
         concat!(
             "Offset of field: ",
@@ -24793,7 +23123,7 @@ fn bindgen_test_layout__cef_xml_reader_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_xml_reader_t>())).get_outer_xml as *const _ as usize },
+        ::std::mem::offset_of!(_cef_xml_reader_t, get_outer_xml),
         208usize,
         concat!(
             "Offset of field: ",
@@ -24803,9 +23133,7 @@ fn bindgen_test_layout__cef_xml_reader_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_xml_reader_t>())).get_line_number as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_xml_reader_t, get_line_number),
         216usize,
         concat!(
             "Offset of field: ",
@@ -24815,10 +23143,7 @@ fn bindgen_test_layout__cef_xml_reader_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_xml_reader_t>())).move_to_attribute_byindex as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_xml_reader_t, move_to_attribute_byindex),
         224usize,
         concat!(
             "Offset of field: ",
@@ -24828,10 +23153,7 @@ fn bindgen_test_layout__cef_xml_reader_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_xml_reader_t>())).move_to_attribute_byqname as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_xml_reader_t, move_to_attribute_byqname),
         232usize,
         concat!(
             "Offset of field: ",
@@ -24841,10 +23163,7 @@ fn bindgen_test_layout__cef_xml_reader_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_xml_reader_t>())).move_to_attribute_bylname as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_xml_reader_t, move_to_attribute_bylname),
         240usize,
         concat!(
             "Offset of field: ",
@@ -24854,10 +23173,7 @@ fn bindgen_test_layout__cef_xml_reader_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_xml_reader_t>())).move_to_first_attribute as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_xml_reader_t, move_to_first_attribute),
         248usize,
         concat!(
             "Offset of field: ",
@@ -24867,10 +23183,7 @@ fn bindgen_test_layout__cef_xml_reader_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_xml_reader_t>())).move_to_next_attribute as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_xml_reader_t, move_to_next_attribute),
         256usize,
         concat!(
             "Offset of field: ",
@@ -24880,10 +23193,7 @@ fn bindgen_test_layout__cef_xml_reader_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_xml_reader_t>())).move_to_carrying_element as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_xml_reader_t, move_to_carrying_element),
         264usize,
         concat!(
             "Offset of field: ",
@@ -24962,7 +23272,7 @@ fn bindgen_test_layout__cef_zip_reader_t() {
         concat!("Alignment of ", stringify!(_cef_zip_reader_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_zip_reader_t>())).base as *const _ as usize },
+        ::std::mem::offset_of!(_cef_zip_reader_t, base),
         0usize,
         concat!(
             "Offset of field: ",
@@ -24972,9 +23282,7 @@ fn bindgen_test_layout__cef_zip_reader_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_zip_reader_t>())).move_to_first_file as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_zip_reader_t, move_to_first_file),
         40usize,
         concat!(
             "Offset of field: ",
@@ -24984,9 +23292,7 @@ fn bindgen_test_layout__cef_zip_reader_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_zip_reader_t>())).move_to_next_file as *const _ as usize
-        },
+        ::std::mem::offset_of!(_cef_zip_reader_t, move_to_next_file),
         48usize,
         concat!(
             "Offset of field: ",
@@ -24996,7 +23302,7 @@ fn bindgen_test_layout__cef_zip_reader_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_zip_reader_t>())).move_to_file as *const _ as usize },
+        ::std::mem::offset_of!(_cef_zip_reader_t, move_to_file),
         56usize,
         concat!(
             "Offset of field: ",
@@ -25006,7 +23312,7 @@ fn bindgen_test_layout__cef_zip_reader_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_zip_reader_t>())).close as *const _ as usize },
+        ::std::mem::offset_of!(_cef_zip_reader_t, close),
         64usize,
         concat!(
             "Offset of field: ",
@@ -25016,7 +23322,7 @@ fn bindgen_test_layout__cef_zip_reader_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_zip_reader_t>())).get_file_name as *const _ as usize },
+        ::std::mem::offset_of!(_cef_zip_reader_t, get_file_name),
         72usize,
         concat!(
             "Offset of field: ",
@@ -25026,7 +23332,7 @@ fn bindgen_test_layout__cef_zip_reader_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_zip_reader_t>())).get_file_size as *const _ as usize },
+        ::std::mem::offset_of!(_cef_zip_reader_t, get_file_size),
         80usize,
         concat!(
             "Offset of field: ",
@@ -25036,10 +23342,7 @@ fn bindgen_test_layout__cef_zip_reader_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<_cef_zip_reader_t>())).get_file_last_modified as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(_cef_zip_reader_t, get_file_last_modified),
         88usize,
         concat!(
             "Offset of field: ",
@@ -25049,7 +23352,7 @@ fn bindgen_test_layout__cef_zip_reader_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_zip_reader_t>())).open_file as *const _ as usize },
+        ::std::mem::offset_of!(_cef_zip_reader_t, open_file),
         96usize,
         concat!(
             "Offset of field: ",
@@ -25059,7 +23362,7 @@ fn bindgen_test_layout__cef_zip_reader_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_zip_reader_t>())).close_file as *const _ as usize },
+        ::std::mem::offset_of!(_cef_zip_reader_t, close_file),
         104usize,
         concat!(
             "Offset of field: ",
@@ -25069,7 +23372,7 @@ fn bindgen_test_layout__cef_zip_reader_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_zip_reader_t>())).read_file as *const _ as usize },
+        ::std::mem::offset_of!(_cef_zip_reader_t, read_file),
         112usize,
         concat!(
             "Offset of field: ",
@@ -25079,7 +23382,7 @@ fn bindgen_test_layout__cef_zip_reader_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_zip_reader_t>())).tell as *const _ as usize },
+        ::std::mem::offset_of!(_cef_zip_reader_t, tell),
         120usize,
         concat!(
             "Offset of field: ",
@@ -25089,7 +23392,7 @@ fn bindgen_test_layout__cef_zip_reader_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<_cef_zip_reader_t>())).eof as *const _ as usize },
+        ::std::mem::offset_of!(_cef_zip_reader_t, eof),
         128usize,
         concat!(
             "Offset of field: ",
@@ -1,14 +1,24 @@
-#[cfg(target_os = "windows")]
+// The bindings are shared between x86_64 and aarch64, whose C layouts agree. There are none for
+// 32-bit targets yet. They're generated, so clippy's lints about how types are written don't apply.
+#[cfg(all(target_os = "windows", target_pointer_width = "64"))]
+#[allow(clippy::type_complexity)]
 mod bindings_windows;
-#[cfg(target_os = "windows")]
+#[cfg(all(target_os = "windows", target_pointer_width = "64"))]
 pub use bindings_windows::*;
 
-#[cfg(target_os = "linux")]
+#[cfg(all(target_os = "linux", target_pointer_width = "64"))]
+#[allow(clippy::type_complexity)]
 mod bindings_linux;
-#[cfg(target_os = "linux")]
+#[cfg(all(target_os = "linux", target_pointer_width = "64"))]
 pub use bindings_linux::*;
 
 #[cfg(target_os = "macos")]
+#[allow(clippy::type_complexity)]
 mod bindings_macos;
 #[cfg(target_os = "macos")]
 pub use bindings_macos::*;
+
+#[cfg(feature = "dynamic-loading")]
+mod library;
+#[cfg(feature = "dynamic-loading")]
+pub use library::*;
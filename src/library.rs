@@ -0,0 +1,91 @@
+#![allow(non_snake_case)]
+
+use crate::*;
+use std::{error, fmt, path::{Path, PathBuf}};
+
+/// Why [`CefLibrary::load`] failed.
+#[derive(Debug)]
+pub enum LoadError {
+    /// The library doesn't exist, or it or one of the libraries it depends on couldn't be loaded.
+    Open { path: PathBuf, source: libloading::Error },
+    /// The library doesn't export a function of the bindings, e.g. because it's from a CEF version
+    /// with another API.
+    MissingSymbol { path: PathBuf, symbol: &'static str, source: libloading::Error },
+}
+
+impl fmt::Display for LoadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LoadError::Open { path, source } => write!(f, "could not load CEF from {}: {}", path.display(), source),
+            LoadError::MissingSymbol { path, symbol, .. } => write!(
+                f,
+                "{} does not export {}, it may be from a CEF version with another API",
+                path.display(),
+                symbol,
+            ),
+        }
+    }
+}
+
+impl error::Error for LoadError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            LoadError::Open { source, .. } | LoadError::MissingSymbol { source, .. } => Some(source),
+        }
+    }
+}
+
+// Defines `CefLibrary` from the function declarations of the bindings, which the build script
+// passes to it.
+macro_rules! cef_library {
+    ($($name:ident($($arg:ident: $ty:ty),* $(,)?) $(-> $ret:ty)?;)*) => {
+        /// The functions libcef exports, looked up at runtime rather than linked. This lets the
+        /// application pick the CEF installation, and report a missing one instead of failing to
+        /// start. The functions of the bindings themselves aren't linked with the `dynamic-loading`
+        /// feature, so calls must go through this.
+        pub struct CefLibrary {
+            $($name: unsafe extern "C" fn($($ty),*) $(-> $ret)?,)*
+            // Unloads the library once the function pointers above can't be used anymore.
+            _library: libloading::Library,
+        }
+
+        impl CefLibrary {
+            /// Loads libcef from `path`, e.g. `libcef.so`, `libcef.dll`, or the `Chromium Embedded
+            /// Framework` binary inside the framework on macOS, and looks up all of its functions.
+            ///
+            /// # Safety
+            ///
+            /// Loading runs the library's initialization routines, so `path` must be a CEF library
+            /// of the version the bindings were generated from.
+            pub unsafe fn load(path: impl AsRef<Path>) -> Result<CefLibrary, LoadError> {
+                let path = path.as_ref();
+                let library = libloading::Library::new(path)
+                    .map_err(|source| LoadError::Open { path: path.to_owned(), source })?;
+                Ok(CefLibrary {
+                    $($name: *library
+                        .get(concat!(stringify!($name), "\0").as_bytes())
+                        .map_err(|source| LoadError::MissingSymbol {
+                            path: path.to_owned(),
+                            symbol: stringify!($name),
+                            source,
+                        })?,)*
+                    _library: library,
+                })
+            }
+
+            $(
+                #[doc = concat!("Calls `", stringify!($name), "` of the loaded library.")]
+                ///
+                /// # Safety
+                ///
+                /// The same as for calling the function of the bindings.
+                #[allow(clippy::too_many_arguments)]
+                pub unsafe fn $name(&self, $($arg: $ty),*) $(-> $ret)? {
+                    (self.$name)($($arg),*)
+                }
+            )*
+        }
+    };
+}
+
+include!(concat!(env!("OUT_DIR"), "/cef_library.rs"));
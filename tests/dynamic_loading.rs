@@ -0,0 +1,23 @@
+// Run with `cargo test --features dynamic-loading`. Loading an actual CEF library is left to the
+// applications, since the test executables aren't set up to find one.
+#![cfg(all(target_os = "linux", feature = "dynamic-loading"))]
+
+use cef_sys::{CefLibrary, LoadError};
+
+#[test]
+fn reports_missing_library() {
+    let err = unsafe { CefLibrary::load("/nonexistent/libcef.so") }.err().unwrap();
+    assert!(matches!(err, LoadError::Open { .. }), "{:?}", err);
+    assert!(err.to_string().starts_with("could not load CEF from /nonexistent/libcef.so: "), "{}", err);
+}
+
+#[test]
+fn reports_missing_symbol() {
+    // Any library without the CEF functions.
+    let err = unsafe { CefLibrary::load("libc.so.6") }.err().unwrap();
+    match &err {
+        LoadError::MissingSymbol { symbol, .. } => assert!(symbol.starts_with("cef_"), "{}", symbol),
+        _ => panic!("{:?}", err),
+    }
+    assert!(err.to_string().starts_with("libc.so.6 does not export cef_"), "{}", err);
+}
@@ -0,0 +1,70 @@
+// Run with `cargo test --features rpath`. Link arguments of the build script apply to this test's
+// own executable, so it checks the RUNPATH the linker wrote into itself. It doesn't call into CEF,
+// since nothing puts libcef.so next to test executables in target/<profile>/deps.
+#![cfg(all(target_os = "linux", target_pointer_width = "64", feature = "rpath"))]
+
+use std::{convert::TryInto, fs};
+
+const SHT_DYNAMIC: u32 = 6;
+const DT_NULL: u64 = 0;
+const DT_RPATH: u64 = 15;
+const DT_RUNPATH: u64 = 29;
+
+fn u16_at(bytes: &[u8], offset: usize) -> u16 {
+    u16::from_le_bytes(bytes[offset..offset + 2].try_into().unwrap())
+}
+
+fn u32_at(bytes: &[u8], offset: usize) -> u32 {
+    u32::from_le_bytes(bytes[offset..offset + 4].try_into().unwrap())
+}
+
+fn u64_at(bytes: &[u8], offset: usize) -> u64 {
+    u64::from_le_bytes(bytes[offset..offset + 8].try_into().unwrap())
+}
+
+// Returns the (tag, value) pairs of the dynamic section of a little-endian ELF64 file, with string
+// values looked up in the string table it links to.
+fn dynamic_strings(elf: &[u8]) -> Vec<(u64, String)> {
+    assert_eq!(&elf[..6], b"\x7fELF\x02\x01", "not a little-endian ELF64 file");
+    let section_headers = u64_at(elf, 0x28) as usize;
+    let section_header_size = u16_at(elf, 0x3a) as usize;
+    let section_count = u16_at(elf, 0x3c) as usize;
+    let section = |index: usize| &elf[section_headers + index * section_header_size..];
+
+    let dynamic = (0..section_count)
+        .map(section)
+        .find(|header| u32_at(header, 0x04) == SHT_DYNAMIC)
+        .expect("executable is not dynamically linked");
+    let strings_offset = u64_at(section(u32_at(dynamic, 0x28) as usize), 0x18) as usize;
+    let string_at = |offset: usize| {
+        let bytes = &elf[strings_offset + offset..];
+        let end = bytes.iter().position(|&b| b == 0).unwrap();
+        String::from_utf8_lossy(&bytes[..end]).into_owned()
+    };
+
+    let (offset, size) = (u64_at(dynamic, 0x18) as usize, u64_at(dynamic, 0x20) as usize);
+    elf[offset..offset + size]
+        .chunks_exact(16)
+        .map(|entry| (u64_at(entry, 0), u64_at(entry, 8)))
+        .take_while(|&(tag, _)| tag != DT_NULL)
+        .filter(|&(tag, _)| tag == DT_RPATH || tag == DT_RUNPATH)
+        .map(|(tag, value)| (tag, string_at(value as usize)))
+        .collect()
+}
+
+#[test]
+fn executables_get_an_origin_runpath() {
+    let elf = fs::read(std::env::current_exe().unwrap()).unwrap();
+    let entries = dynamic_strings(&elf);
+    let runpath = entries.iter()
+        .find(|(tag, _)| *tag == DT_RUNPATH)
+        .map(|(_, runpath)| runpath)
+        .unwrap_or_else(|| panic!("no RUNPATH in {:?}", entries));
+    let mut dirs = runpath.split(':');
+    assert_eq!(dirs.next(), Some("$ORIGIN"));
+    if let Ok(subdir) = std::env::var("CEF_SYS_RUNTIME_SUBDIR") {
+        assert_eq!(dirs.next().map(str::to_owned), Some(format!("$ORIGIN/{}", subdir)));
+    }
+    // RUNPATH rather than RPATH, so that LD_LIBRARY_PATH still takes precedence.
+    assert!(entries.iter().all(|(tag, _)| *tag != DT_RPATH), "unexpected RPATH in {:?}", entries);
+}